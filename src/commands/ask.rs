@@ -0,0 +1,172 @@
+//! Comando ask - Recuperación de contexto (RAG local).
+//!
+//! Devuelve los fragmentos del índice semántico más relevantes para una
+//! pregunta, con archivo y rango de líneas. No llama a ningún LLM: el
+//! resultado está pensado para pegarse como contexto.
+
+use crate::core::config::OcConfig;
+use crate::core::embeddings::{ChunkHit, Embedder, EmbeddingIndex};
+use crate::errors::OcResult;
+use clap::Parser;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ASK TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Fragmento recuperado.
+#[derive(Debug, Clone, Serialize)]
+pub struct AskFragment {
+    pub file: PathBuf,
+    pub title: String,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub score: f32,
+    pub text: String,
+}
+
+impl AskFragment {
+    /// Referencia `archivo:inicio-fin`.
+    pub fn location(&self) -> String {
+        format!("{}:{}-{}", self.file.display(), self.line_start, self.line_end)
+    }
+}
+
+/// Resultado de `ask`.
+#[derive(Debug, Clone, Serialize)]
+pub struct AskResult {
+    pub question: String,
+    pub fragments: Vec<AskFragment>,
+}
+
+impl AskResult {
+    /// Contexto en Markdown listo para pegar.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("> Pregunta: {}\n\n", self.question);
+        for f in &self.fragments {
+            out.push_str(&format!(
+                "### {} (`{}`, score {:.3})\n\n{}\n\n",
+                f.title,
+                f.location(),
+                f.score,
+                f.text
+            ));
+        }
+        out
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ASK COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Comando ask.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "ask", about = "Recuperar fragmentos relevantes para una pregunta")]
+pub struct AskCommand {
+    /// Pregunta en lenguaje natural.
+    pub question: String,
+
+    /// Número de fragmentos a devolver.
+    #[arg(long, default_value = "5")]
+    pub top: usize,
+
+    /// Score mínimo (0-1) para incluir un fragmento.
+    #[arg(long, default_value = "0.0")]
+    pub min_score: f32,
+
+    /// Ruta del proyecto.
+    #[arg(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// Formato de salida: md, text o json.
+    #[arg(long, default_value = "md")]
+    pub format: String,
+}
+
+impl AskCommand {
+    pub fn run(&self, data_dir: &Path) -> OcResult<AskResult> {
+        let config = OcConfig::from_cwd().unwrap_or_default().embeddings;
+        let embedder = Embedder::new(config.clone());
+        let index = EmbeddingIndex::open(&config, &embedder)?;
+        self.run_with(data_dir, &embedder, &index)
+    }
+
+    /// Recupera fragmentos de un índice ya cargado.
+    pub fn run_with(
+        &self,
+        data_dir: &Path,
+        embedder: &Embedder,
+        index: &EmbeddingIndex,
+    ) -> OcResult<AskResult> {
+        let query = embedder.embed(std::slice::from_ref(&self.question))?;
+        let fragments = index
+            .search_chunks(&query[0], self.top)
+            .into_iter()
+            .filter(|hit| hit.score > self.min_score)
+            .map(|hit: ChunkHit| AskFragment {
+                file: data_dir.join(&hit.path),
+                title: hit.title,
+                line_start: hit.line_start,
+                line_end: hit.line_end,
+                score: hit.score,
+                text: hit.text,
+            })
+            .collect();
+        Ok(AskResult {
+            question: self.question.clone(),
+            fragments,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::EmbeddingsConfig;
+
+    #[test]
+    fn test_ask_returns_fragment_locations() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("2.1 alta.md"),
+            "---\ntitle: \"Alta de operador\"\n---\n\n## Pasos\n\nEl alta de operador tiene tres pasos: solicitud, aprobación y activación.\n\n## Notas\n\nLas comisiones se liquidan aparte.\n",
+        )
+        .unwrap();
+
+        let embedder = Embedder::new(EmbeddingsConfig::default());
+        let (index, _) = EmbeddingIndex::build(dir.path(), &embedder, 200, None).unwrap();
+        let cmd = AskCommand::parse_from(["ask", "¿qué pasos tiene el alta de operador?", "--top", "1"]);
+        let result = cmd.run_with(dir.path(), &embedder, &index).unwrap();
+
+        assert_eq!(result.fragments.len(), 1);
+        let fragment = &result.fragments[0];
+        assert_eq!((fragment.line_start, fragment.line_end), (5, 8));
+        assert!(fragment.location().ends_with("2.1 alta.md:5-8"));
+        assert!(result.to_markdown().contains("tres pasos"));
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: AskCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    let default_dir = PathBuf::from(&cli.data_dir);
+    let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
+    let result = cmd.run(data_dir)?;
+
+    match cmd.format.as_str() {
+        "json" => println!("{}", serde_json::to_string_pretty(&result)?),
+        "text" => {
+            for f in &result.fragments {
+                println!("{} ({:.3})", f.location(), f.score);
+            }
+        }
+        _ => print!("{}", result.to_markdown()),
+    }
+
+    if result.fragments.is_empty() {
+        eprintln!("🔍 Sin fragmentos relevantes");
+    }
+    Ok(())
+}
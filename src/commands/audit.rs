@@ -1,6 +1,9 @@
 //! Comando audit - Auditoría completa del proyecto.
 //!
-//! Genera informe detallado de problemas y recomendaciones.
+//! Genera informe detallado de problemas y recomendaciones, incluida la
+//! deuda documental: documentos `stub`/`borrador` creados hace más de
+//! `--stale-months` meses, exportables a CSV o a un índice de pendientes, y
+//! las revisiones (`review_due`) vencidas o próximas por responsable.
 
 use crate::errors::OcResult;
 use chrono::{DateTime, Utc};
@@ -41,6 +44,13 @@ pub struct AuditResult {
     pub findings: Vec<AuditFinding>,
     pub total_files: usize,
     pub score: u8,
+    /// Deuda documental, el documento más antiguo primero.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub debt: Vec<DebtItem>,
+    /// Con `--due-soon`: revisiones que vencen dentro del plazo (incluidas
+    /// las vencidas), la más urgente primero.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reviews: Vec<crate::core::reviews::Review>,
 }
 
 impl AuditResult {
@@ -51,6 +61,8 @@ impl AuditResult {
             findings: Vec::new(),
             total_files: 0,
             score: 100,
+            debt: Vec::new(),
+            reviews: Vec::new(),
         }
     }
 
@@ -79,6 +91,99 @@ impl Default for AuditResult {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// DEUDA DOCUMENTAL
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Statuses que son deuda si el documento es antiguo.
+const DEBT_STATUSES: &[&str] = &["stub", "borrador", "draft"];
+
+/// Documento stub/borrador creado hace más de `--stale-months` meses.
+#[derive(Debug, Clone, Serialize)]
+pub struct DebtItem {
+    pub file: PathBuf,
+    pub id: Option<String>,
+    pub title: String,
+    pub status: String,
+    pub created: String,
+    /// Meses (de 30 días) desde `created`.
+    pub age_months: i64,
+}
+
+impl DebtItem {
+    /// Desde el contenido; `None` si no es deuda o `created` no se entiende.
+    fn from_content(file: &std::path::Path, content: &str, stale_months: u32) -> Option<Self> {
+        use crate::types::breadcrumb::frontmatter_value;
+        use crate::types::lifetimes::split_frontmatter;
+        use crate::types::OcDate;
+
+        let metadata = split_frontmatter(content)?.metadata;
+        let status = frontmatter_value(metadata, "status")?;
+        if !DEBT_STATUSES.contains(&status.to_lowercase().as_str()) {
+            return None;
+        }
+        let created = frontmatter_value(metadata, "created")?;
+        let age_months = created.parse::<OcDate>().ok()?.days_ago() / 30;
+        if age_months < i64::from(stale_months) {
+            return None;
+        }
+        let stem = file.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+        Some(Self {
+            file: file.to_path_buf(),
+            id: frontmatter_value(metadata, "id"),
+            title: frontmatter_value(metadata, "title").unwrap_or_else(|| stem.to_string()),
+            status,
+            created,
+            age_months,
+        })
+    }
+}
+
+/// CSV `file,id,title,status,created,age_months` para planificación.
+pub fn debt_to_csv(items: &[DebtItem]) -> String {
+    let quote = |s: &str| {
+        if s.contains([',', '"', '\n']) {
+            format!("\"{}\"", s.replace('"', "\"\""))
+        } else {
+            s.to_string()
+        }
+    };
+    let mut out = String::from("file,id,title,status,created,age_months\n");
+    for item in items {
+        out.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            quote(&item.file.display().to_string()),
+            quote(item.id.as_deref().unwrap_or_default()),
+            quote(&item.title),
+            quote(&item.status),
+            quote(&item.created),
+            item.age_months
+        ));
+    }
+    out
+}
+
+/// Documento índice de pendientes con un wiki-link por documento.
+pub fn debt_index(items: &[DebtItem], stale_months: u32, now: &str) -> String {
+    let mut out = format!(
+        "---\ntitle: \"Pendientes\"\ntype: indice\nstatus: activo\ncreated: \"{now}\"\nlast_updated: \"{now}\"\n---\n\n\
+         # Pendientes\n\nDocumentos en {statuses} creados hace más de {months} meses \
+         (generado por `oc_diagdoc audit --debt-index`).\n\n\
+         | Documento | Status | Creado | Meses |\n|-----------|--------|--------|-------|\n",
+        now = now,
+        statuses = DEBT_STATUSES.join("/"),
+        months = stale_months,
+    );
+    for item in items {
+        let stem = item.file.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+        out.push_str(&format!(
+            "| [[{}]] | {} | {} | {} |\n",
+            stem, item.status, item.created, item.age_months
+        ));
+    }
+    out
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // AUDIT COMMAND
 // ═══════════════════════════════════════════════════════════════════════════
@@ -107,6 +212,100 @@ pub struct AuditCommand {
     /// Mostrar detalles completos de cada finding.
     #[arg(long)]
     pub verbose: bool,
+
+    /// Sugerir tags para documentos sin `tags:` (términos frecuentes + vecinos).
+    #[arg(long)]
+    pub suggest_tags: bool,
+
+    /// Con --suggest-tags: escribir los tags sugeridos en el frontmatter.
+    #[arg(long, requires = "suggest_tags")]
+    pub apply: bool,
+
+    /// Máximo de tags sugeridos por documento.
+    #[arg(long, default_value = "5")]
+    pub max_tags: usize,
+
+    /// Antigüedad (meses desde `created`) a partir de la cual un stub o
+    /// borrador es deuda documental.
+    #[arg(long, default_value = "6", value_name = "N")]
+    pub stale_months: u32,
+
+    /// Exportar la deuda documental a CSV.
+    #[arg(long, value_name = "FILE")]
+    pub debt_csv: Option<PathBuf>,
+
+    /// Escribir un documento índice de pendientes con la deuda documental.
+    #[arg(long, value_name = "FILE")]
+    pub debt_index: Option<PathBuf>,
+
+    /// Listar las revisiones (`review_due`) que vencen dentro del plazo,
+    /// por responsable (ej: 14d, 2w).
+    #[arg(long, value_name = "PLAZO")]
+    pub due_soon: Option<String>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TAG SUGGESTIONS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Tags propuestos para un documento sin `tags:`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TagSuggestion {
+    pub file: PathBuf,
+    pub id: Option<String>,
+    pub tags: Vec<String>,
+    /// true si se escribieron con --apply.
+    pub applied: bool,
+}
+
+/// Datos de un documento relevantes para sugerir tags.
+struct TagDoc {
+    path: PathBuf,
+    id: Option<String>,
+    parent: Option<String>,
+    tags: Vec<String>,
+    has_frontmatter: bool,
+    content: String,
+}
+
+impl TagDoc {
+    fn load(path: PathBuf, content: String) -> Self {
+        use crate::types::lifetimes::split_frontmatter;
+
+        let yaml = content
+            .starts_with("---")
+            .then(|| split_frontmatter(&content))
+            .flatten()
+            .and_then(|split| serde_yaml::from_str::<serde_yaml::Value>(split.metadata).ok());
+        let field = |name: &str| -> Option<String> {
+            match yaml.as_ref()?.get(name)? {
+                serde_yaml::Value::String(s) => Some(s.clone()),
+                serde_yaml::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            }
+        };
+        let tags = match yaml.as_ref().and_then(|y| y.get("tags")) {
+            Some(serde_yaml::Value::Sequence(items)) => items
+                .iter()
+                .filter_map(|v| v.as_str().map(normalize_tag))
+                .collect(),
+            Some(serde_yaml::Value::String(s)) => s.split(',').map(normalize_tag).collect(),
+            _ => Vec::new(),
+        };
+        Self {
+            id: field("id"),
+            parent: field("parent"),
+            tags: tags.into_iter().filter(|t: &String| !t.is_empty()).collect(),
+            has_frontmatter: yaml.is_some(),
+            path,
+            content,
+        }
+    }
+}
+
+/// Tag en minúsculas con guiones en lugar de espacios.
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase().split_whitespace().collect::<Vec<_>>().join("-")
 }
 
 impl AuditResult {
@@ -115,7 +314,7 @@ impl AuditResult {
     /// L10.1: Ordena findings por severidad (mayor primero).
     pub fn sorted_by_severity(&self) -> Vec<&AuditFinding> {
         let mut findings: Vec<_> = self.findings.iter().collect();
-        findings.sort_by(|a, b| b.severity.cmp(&a.severity));
+        findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
         findings
     }
 
@@ -177,6 +376,10 @@ impl AuditCommand {
             find_refs: None,
             rename: None,
             backup: false,
+            graph_orphans: false,
+            adopt: false,
+            convention: None,
+            normalize: false,
         };
         if let Ok(links_result) = links_cmd.run(data_dir) {
             // Finding: Enlaces rotos
@@ -201,17 +404,28 @@ impl AuditCommand {
 
             // Finding: Enlaces no-estándar
             if links_result.total_nonstandard > 0 {
+                let (title, description, recommendation) = match links_cmd.link_convention() {
+                    Some(convention) => (
+                        format!(
+                            "{} enlaces fuera de la convención '{}'",
+                            links_result.total_nonstandard,
+                            convention.as_str()
+                        ),
+                        "Los enlaces wiki deberían escribirse según validation.link_convention.".to_string(),
+                        "Ejecutar `links --normalize`".to_string(),
+                    ),
+                    None => (
+                        format!("{} enlaces con path completo (no-estándar)", links_result.total_nonstandard),
+                        "Los enlaces wiki deberían usar solo el nombre del archivo, sin path.".to_string(),
+                        "Cambiar [[Proyecto/Datos/doc]] a [[doc]]".to_string(),
+                    ),
+                };
                 result.add_finding(AuditFinding {
                     category: AuditCategory::Links,
                     severity: 3,
-                    title: format!(
-                        "{} enlaces con path completo (no-estándar)",
-                        links_result.total_nonstandard
-                    ),
-                    description:
-                        "Los enlaces wiki deberían usar solo el nombre del archivo, sin path."
-                            .to_string(),
-                    recommendation: "Cambiar [[Proyecto/Datos/doc]] a [[doc]]".to_string(),
+                    title,
+                    description,
+                    recommendation,
                     affected_files: links_result
                         .links
                         .iter()
@@ -228,12 +442,32 @@ impl AuditCommand {
             path: None,
             fix: false,
             dry_run: false,
+            diff: false,
+            interactive: false,
             errors_only: false,
             json: false,
             rule: None,
             summary: false,
+            module: None,
+            id_prefix: None,
             show_fixes: false,
             explain: None,  // RFC-03
+            empty_headings: "placeholder".to_string(),
+            format: "text".to_string(),
+            open: Vec::new(),
+            timings: false,
+            strict_utf8: false,
+            include_derived: false,
+            category: Vec::new(),
+            disable_category: Vec::new(),
+            timings_top: 10,
+            profiles: None,
+            profiler: None,
+            documents: None,
+            variables: None,
+            styleguide: None,
+            register: None,
+            links: None,
         };
         if let Ok(lint_result) = lint_cmd.run(data_dir) {
             // Finding: Errores de lint
@@ -273,7 +507,10 @@ impl AuditCommand {
             }
         }
 
-        // 3. Análisis de cobertura rápido
+        // 3. Análisis de cobertura rápido, deuda documental y revisiones
+        let due_soon = self.due_soon.as_deref().map(crate::core::reviews::parse_days).transpose()?;
+        let today = chrono::Local::now().date_naive();
+        let mut reviews = Vec::new();
         let mut low_content_files = Vec::new();
         for file_path in &files {
             if let Ok(content) = crate::core::files::read_file_content(file_path) {
@@ -281,8 +518,53 @@ impl AuditCommand {
                 if word_count < 100 {
                     low_content_files.push(file_path.clone());
                 }
+                result
+                    .debt
+                    .extend(DebtItem::from_content(file_path, &content, self.stale_months));
+                if let Some(Ok(review)) = crate::core::reviews::Review::of(file_path, &content, today) {
+                    reviews.push(review);
+                }
             }
         }
+        let overdue: Vec<PathBuf> = reviews.iter().filter(|r| r.is_overdue()).map(|r| r.file.clone()).collect();
+        if !overdue.is_empty() {
+            result.add_finding(AuditFinding {
+                category: AuditCategory::Metadata,
+                severity: 3,
+                title: format!("{} revisiones vencidas (review_due)", overdue.len()),
+                description: "Documentos cuya revisión programada ya pasó sin actualizar review_due."
+                    .to_string(),
+                recommendation: "Revisarlos y mover review_due; `audit --due-soon 14d` los lista por responsable."
+                    .to_string(),
+                affected_files: overdue.into_iter().take(10).collect(),
+            });
+        }
+        if let Some(days) = due_soon {
+            reviews.retain(|r| r.days_left <= days);
+            reviews.sort_by(|a, b| a.days_left.cmp(&b.days_left).then_with(|| a.file.cmp(&b.file)));
+            result.reviews = reviews;
+        }
+        result
+            .debt
+            .sort_by(|a, b| b.age_months.cmp(&a.age_months).then_with(|| a.file.cmp(&b.file)));
+
+        if !result.debt.is_empty() {
+            result.add_finding(AuditFinding {
+                category: AuditCategory::Content,
+                severity: 3,
+                title: format!(
+                    "{} documentos stub/borrador con más de {} meses (deuda documental)",
+                    result.debt.len(),
+                    self.stale_months
+                ),
+                description: "Documentos que siguen sin completarse mucho después de creados."
+                    .to_string(),
+                recommendation:
+                    "Completar o deprecar; `audit --debt-csv deuda.csv` para planificarlos."
+                        .to_string(),
+                affected_files: result.debt.iter().take(10).map(|d| d.file.clone()).collect(),
+            });
+        }
 
         if low_content_files.len() > 10 {
             result.add_finding(AuditFinding {
@@ -301,11 +583,94 @@ impl AuditCommand {
 
         Ok(result)
     }
+
+    /// Sugiere tags para documentos sin `tags:` puntuando tags del padre (2),
+    /// de hermanos (1 por hermano), términos frecuentes del documento (hasta 2)
+    /// y +1 si el candidato ya se usa como tag en el proyecto.
+    pub fn suggest_tags(&self, data_dir: &std::path::Path) -> OcResult<Vec<TagSuggestion>> {
+        use crate::core::files::{get_all_md_files, read_file_content, write_file_atomic, ScanOptions};
+        use crate::core::terms::{count_terms, top_terms, TermCounts};
+        use crate::core::yaml::update_field;
+        use std::collections::{BTreeMap, HashMap, HashSet};
+
+        let docs: Vec<TagDoc> = get_all_md_files(data_dir, &ScanOptions::new())?
+            .into_iter()
+            .filter_map(|path| {
+                let content = read_file_content(&path).ok()?;
+                Some(TagDoc::load(path, content))
+            })
+            .collect();
+
+        let vocabulary: HashSet<&str> = docs
+            .iter()
+            .flat_map(|d| d.tags.iter().map(String::as_str))
+            .collect();
+        let by_id: HashMap<&str, &TagDoc> = docs
+            .iter()
+            .filter_map(|d| d.id.as_deref().map(|id| (id, d)))
+            .collect();
+
+        let mut suggestions = Vec::new();
+        for doc in docs.iter().filter(|d| d.tags.is_empty() && d.has_frontmatter) {
+            let mut scores: BTreeMap<String, f64> = BTreeMap::new();
+
+            if let Some(parent) = doc.parent.as_deref().and_then(|p| by_id.get(p)) {
+                for tag in &parent.tags {
+                    *scores.entry(tag.clone()).or_default() += 2.0;
+                }
+            }
+            for sibling in docs
+                .iter()
+                .filter(|s| s.parent.is_some() && s.parent == doc.parent && s.path != doc.path)
+            {
+                for tag in &sibling.tags {
+                    *scores.entry(tag.clone()).or_default() += 1.0;
+                }
+            }
+
+            let mut counts = TermCounts::new();
+            count_terms(&doc.content, &mut counts);
+            let frequent = top_terms(&counts, self.max_tags * 2);
+            let max = frequent.first().map(|(_, c)| *c).unwrap_or(1) as f64;
+            for (term, count) in frequent.into_iter().filter(|(_, c)| *c > 1) {
+                *scores.entry(term).or_default() += 2.0 * count as f64 / max;
+            }
+
+            for (tag, score) in scores.iter_mut() {
+                if vocabulary.contains(tag.as_str()) {
+                    *score += 1.0;
+                }
+            }
+
+            let mut ranked: Vec<(String, f64)> = scores.into_iter().collect();
+            ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            let tags: Vec<String> = ranked.into_iter().take(self.max_tags).map(|(t, _)| t).collect();
+            if tags.is_empty() {
+                continue;
+            }
+
+            let mut applied = false;
+            if self.apply {
+                let updated = update_field(&doc.content, "tags", &format!("[{}]", tags.join(", ")))?;
+                write_file_atomic(&doc.path, &updated)?;
+                applied = true;
+            }
+            suggestions.push(TagSuggestion {
+                file: doc.path.clone(),
+                id: doc.id.clone(),
+                tags,
+                applied,
+            });
+        }
+
+        Ok(suggestions)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TestProject;
 
     #[test]
     fn test_audit_result_new() {
@@ -359,6 +724,74 @@ mod tests {
 
         assert_eq!(result.score, 0); // No underflow
     }
+
+    #[test]
+    fn test_documentation_debt() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        project.write("1 Viejo.md", "---\nid: \"1\"\ntitle: \"Viejo, sin tocar\"\nstatus: stub\ncreated: \"2020-01-15\"\n---\nx\n");
+        project.write("2 Medio.md", "---\nid: \"2\"\nstatus: borrador\ncreated: \"2023-03-01 10:00\"\n---\nx\n");
+        project.write("3 Listo.md", "---\nid: \"3\"\nstatus: activo\ncreated: \"2020-01-15\"\n---\nx\n");
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        project.write("4 Nuevo.md", &format!("---\nid: \"4\"\nstatus: stub\ncreated: \"{}\"\n---\nx\n", today));
+
+        let result = AuditCommand::parse_from(["audit"]).run(dir).unwrap();
+        let ids: Vec<_> = result.debt.iter().map(|d| d.id.clone().unwrap()).collect();
+        assert_eq!(ids, vec!["1", "2"]);
+        assert!(result.findings.iter().any(|f| f.title.starts_with("2 documentos stub/borrador")));
+
+        let csv = debt_to_csv(&result.debt);
+        assert!(csv.lines().nth(1).unwrap().contains(",1,\"Viejo, sin tocar\",stub,2020-01-15,"));
+        let index = debt_index(&result.debt, 6, "2026-01-01 10:00");
+        assert!(index.contains("| [[1 Viejo]] | stub | 2020-01-15 |"));
+        assert!(index.contains("type: indice"));
+    }
+
+    #[test]
+    fn test_due_soon_reviews() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        let day = |offset: i64| (chrono::Local::now().date_naive() + chrono::Duration::days(offset)).to_string();
+        let review = |id: &str, by: &str, offset: i64| {
+            format!("---\nid: \"{}\"\nreview_by: {}\nreview_due: \"{}\"\n---\nx\n", id, by, day(offset))
+        };
+        project.write("1 Vencido.md", &review("1", "ana", -3));
+        project.write("2 Pronto.md", &review("2", "luis", 5));
+        project.write("3 Lejos.md", &review("3", "ana", 60));
+
+        let result = AuditCommand::parse_from(["audit", "--due-soon", "14d"]).run(dir).unwrap();
+        let ids: Vec<_> = result.reviews.iter().map(|r| (r.id.clone().unwrap(), r.days_left)).collect();
+        assert_eq!(ids, vec![("1".to_string(), -3), ("2".to_string(), 5)]);
+        assert!(result.findings.iter().any(|f| f.title == "1 revisiones vencidas (review_due)"));
+        assert!(AuditCommand::parse_from(["audit"]).run(dir).unwrap().reviews.is_empty());
+        assert!(AuditCommand::parse_from(["audit", "--due-soon", "pronto"]).run(dir).is_err());
+    }
+
+    #[test]
+    fn test_suggest_tags_from_neighbours_and_terms() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        project.write("2 Pagos.md", "---\nid: \"2\"\ntags: [pagos]\n---\nPadre.\n");
+        project.write("2.1 Tarjeta.md", "---\nid: \"2.1\"\nparent: \"2\"\ntags: [pagos, tarjeta]\n---\nx\n");
+        project.write(
+            "2.2 Reembolsos.md",
+            "---\nid: \"2.2\"\nparent: \"2\"\n---\nLos reembolsos se procesan. Cada reembolsos tiene plazo.\n",
+        );
+        project.write("3 Suelto.md", "Sin frontmatter.\n");
+
+        let cmd = AuditCommand::parse_from(["audit", "--suggest-tags", "--max-tags", "3"]);
+        let suggestions = cmd.suggest_tags(dir).unwrap();
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].id.as_deref(), Some("2.2"));
+        assert_eq!(suggestions[0].tags, vec!["pagos", "reembolsos", "tarjeta"]);
+        assert!(!suggestions[0].applied);
+
+        let apply = AuditCommand::parse_from(["audit", "--suggest-tags", "--apply", "--max-tags", "3"]);
+        apply.suggest_tags(dir).unwrap();
+        let written = project.read("2.2 Reembolsos.md");
+        assert!(written.contains("tags: [pagos, reembolsos, tarjeta]\n---"));
+        assert!(apply.suggest_tags(dir).unwrap().is_empty());
+    }
 }
 
 /// Función run para CLI.
@@ -367,6 +800,24 @@ pub fn run(cmd: AuditCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
     // F1.3: Priorizar cmd.path sobre cli.data_dir
     let default_dir = std::path::PathBuf::from(&cli.data_dir);
     let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
+
+    if cmd.suggest_tags {
+        let suggestions = cmd.suggest_tags(data_dir)?;
+        if cmd.json {
+            println!("{}", serde_json::to_string_pretty(&suggestions)?);
+            return Ok(());
+        }
+        println!("🏷️  {} documentos sin tags con sugerencias", suggestions.len());
+        for s in &suggestions {
+            let mark = if s.applied { "✅" } else { "💡" };
+            println!("  {} {}: [{}]", mark, s.file.display(), s.tags.join(", "));
+        }
+        if !cmd.apply && !suggestions.is_empty() {
+            println!("\n💡 Use --apply para escribirlos en el frontmatter");
+        }
+        return Ok(());
+    }
+
     let result = cmd.run(data_dir)?;
 
     if cmd.json {
@@ -396,6 +847,32 @@ pub fn run(cmd: AuditCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
         }
     }
 
+    if let (Some(plazo), false) = (&cmd.due_soon, cmd.json) {
+        let groups = crate::core::reviews::by_owner(&result.reviews);
+        println!("\n📅 Revisiones que vencen en {}: {}", plazo, result.reviews.len());
+        for (owner, reviews) in groups {
+            println!("  👤 {} ({})", owner, reviews.len());
+            for review in reviews {
+                let when = match review.days_left {
+                    d if d < 0 => format!("vencida hace {} días", -d),
+                    0 => "vence hoy".to_string(),
+                    d => format!("en {} días", d),
+                };
+                println!("     {} {} — {} ({})", review.id.as_deref().unwrap_or("-"), review.title, review.review_due, when);
+            }
+        }
+    }
+
+    if let Some(csv_path) = &cmd.debt_csv {
+        std::fs::write(csv_path, debt_to_csv(&result.debt))?;
+        println!("📄 Deuda documental ({}) en: {}", result.debt.len(), csv_path.display());
+    }
+    if let Some(index_path) = &cmd.debt_index {
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+        std::fs::write(index_path, debt_index(&result.debt, cmd.stale_months, &now))?;
+        println!("🗂️  Índice de pendientes: {}", index_path.display());
+    }
+
     // AN-02 FIX: Implementar escritura a archivo cuando --export está presente
     if let Some(export_path) = &cmd.export {
         let json = serde_json::to_string_pretty(&result)?;
@@ -2,6 +2,7 @@
 //!
 //! Ejecuta operaciones sobre múltiples documentos.
 
+use crate::core::patch::PatchReview;
 use crate::errors::OcResult;
 use clap::Parser;
 use std::path::PathBuf;
@@ -110,6 +111,14 @@ pub struct BatchCommand {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Mostrar diff coloreado por archivo antes de escribir.
+    #[arg(long)]
+    pub diff: bool,
+
+    /// Aceptar/rechazar cada hunk interactivamente.
+    #[arg(long)]
+    pub interactive: bool,
+
     // L17-L18: Flags avanzados
     /// Archivo de batch con comandos (.oc-batch).
     #[arg(long, short = 'f')]
@@ -271,6 +280,11 @@ impl BatchCommand {
         true
     }
 
+    /// Opciones de revisión de cambios según flags.
+    pub fn review(&self) -> PatchReview {
+        PatchReview::new(self.diff, self.interactive, self.dry_run)
+    }
+
     /// Operación original sobre frontmatter
     fn run_frontmatter_update(&self, data_dir: &std::path::Path) -> OcResult<BatchResult> {
         use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
@@ -308,10 +322,9 @@ impl BatchCommand {
                         let new_field = format!("{}: \"{}\"", field, value);
                         let new_content = re.replace(&content, new_field.as_str()).to_string();
 
-                        if !self.dry_run {
-                            std::fs::write(file_path, &new_content)?;
+                        if self.review().write(file_path, &content, &new_content)? {
+                            result.add_success(file_path.clone(), format!("{}={}", field, value));
                         }
-                        result.add_success(file_path.clone(), format!("{}={}", field, value));
                     }
                 }
             }
@@ -382,16 +395,15 @@ pub fn run(cmd: BatchCommand, cli: &crate::commands::CliConfig) -> anyhow::Resul
                         files_skipped += 1;
                     } else if content.starts_with("---") {
                         // Agregar campo después del primer ---
-                        if !cmd.dry_run {
-                            let new_line = format!("{}: {}", field, value);
-                            let new_content = content.replacen(
-                                "---\n",
-                                &format!("---\n{}\n", new_line),
-                                1,
-                            );
-                            let _ = std::fs::write(path, new_content);
+                        let new_line = format!("{}: {}", field, value);
+                        let new_content = content.replacen(
+                            "---\n",
+                            &format!("---\n{}\n", new_line),
+                            1,
+                        );
+                        if cmd.review().write(path, &content, &new_content).unwrap_or(false) {
+                            files_modified += 1;
                         }
-                        files_modified += 1;
                     }
                 }
             }
@@ -429,11 +441,10 @@ pub fn run(cmd: BatchCommand, cli: &crate::commands::CliConfig) -> anyhow::Resul
             if path.extension().map(|e| e != "md").unwrap_or(true) { continue; }
             if let Ok(content) = std::fs::read_to_string(path) {
                 if field_regex.is_match(&content) {
-                    if !cmd.dry_run {
-                        let new_content = field_regex.replace_all(&content, "").to_string();
-                        let _ = std::fs::write(path, new_content);
+                    let new_content = field_regex.replace_all(&content, "").to_string();
+                    if cmd.review().write(path, &content, &new_content).unwrap_or(false) {
+                        files_modified += 1;
                     }
-                    files_modified += 1;
                 } else {
                     files_skipped += 1;
                 }
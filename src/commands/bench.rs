@@ -0,0 +1,340 @@
+//! Comando bench - Benchmark interno sobre un corpus sintético.
+//!
+//! Genera un proyecto de `--docs` documentos y `--depth` niveles con
+//! `testing::fixtures` (opcionalmente con links rotos, huérfanos y
+//! placeholders), corre verify, lint y stats sobre él y reporta tiempos y
+//! memoria pico. Con `--save` el resultado queda en JSON y con
+//! `--baseline` se compara contra una corrida anterior (otra versión) para
+//! detectar regresiones de rendimiento.
+
+use crate::commands::lint::LintCommand;
+use crate::commands::stats::StatsCommand;
+use crate::commands::verify::VerifyCommand;
+use crate::errors::{OcError, OcResult};
+use crate::testing::fixtures::ProjectSpec;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Pasos más rápidos que esto no se comparan: el ruido domina.
+const MIN_COMPARABLE_MS: f64 = 10.0;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// BENCH TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Tiempo de un comando sobre el corpus.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchStep {
+    pub name: String,
+    /// Mejor tiempo de las corridas.
+    pub ms: f64,
+    /// Resumen del resultado (para verificar que el corpus no cambió).
+    pub summary: String,
+}
+
+/// Paso más lento que en el baseline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchRegression {
+    pub step: String,
+    pub baseline_ms: f64,
+    pub ms: f64,
+    pub delta_pct: f64,
+}
+
+/// Resultado de `bench`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub version: String,
+    /// Parámetros del corpus generado.
+    pub corpus: ProjectSpec,
+    pub runs: usize,
+    pub generate_ms: f64,
+    pub steps: Vec<BenchStep>,
+    pub peak_memory_kb: Option<u64>,
+    /// Versión del baseline comparado, si hubo.
+    #[serde(default)]
+    pub baseline_version: Option<String>,
+    #[serde(default)]
+    pub regressions: Vec<BenchRegression>,
+}
+
+impl BenchResult {
+    pub fn total_ms(&self) -> f64 {
+        self.steps.iter().map(|s| s.ms).sum()
+    }
+
+    /// Compara contra un baseline: regresión es un paso más lento que
+    /// `max_regression` por ciento.
+    pub fn compare(&mut self, baseline: &BenchResult, max_regression: f64) {
+        self.baseline_version = Some(baseline.version.clone());
+        self.regressions = self
+            .steps
+            .iter()
+            .filter_map(|step| {
+                let base = baseline.steps.iter().find(|b| b.name == step.name)?;
+                if base.ms < MIN_COMPARABLE_MS {
+                    return None;
+                }
+                let delta_pct = (step.ms - base.ms) / base.ms * 100.0;
+                (delta_pct > max_regression).then(|| BenchRegression {
+                    step: step.name.clone(),
+                    baseline_ms: base.ms,
+                    ms: step.ms,
+                    delta_pct,
+                })
+            })
+            .collect();
+    }
+}
+
+fn elapsed_ms(start: Instant) -> f64 {
+    (start.elapsed().as_secs_f64() * 1_000_000.0).round() / 1000.0
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// BENCH COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Comando bench.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "bench", about = "Benchmark de verify/lint/stats sobre un corpus sintético")]
+pub struct BenchCommand {
+    /// Documentos del corpus.
+    #[arg(long, default_value = "1000")]
+    pub docs: usize,
+
+    /// Niveles de la jerarquía.
+    #[arg(long, default_value = "4")]
+    pub depth: usize,
+
+    /// Módulos de primer nivel.
+    #[arg(long, default_value = "10")]
+    pub modules: usize,
+
+    /// Porcentaje de documentos con un link roto.
+    #[arg(long, default_value = "0", value_name = "PCT")]
+    pub broken_links: f64,
+
+    /// Porcentaje de documentos huérfanos.
+    #[arg(long, default_value = "0", value_name = "PCT")]
+    pub orphans: f64,
+
+    /// Porcentaje de documentos con placeholders.
+    #[arg(long, default_value = "0", value_name = "PCT")]
+    pub placeholders: f64,
+
+    /// Semilla del corpus (misma semilla, mismo proyecto).
+    #[arg(long, default_value = "42")]
+    pub seed: u64,
+
+    /// Corridas por comando (se reporta la más rápida).
+    #[arg(long, default_value = "1")]
+    pub runs: usize,
+
+    /// Generar el corpus en este directorio (y conservarlo) en vez de uno temporal.
+    #[arg(long, value_name = "DIR")]
+    pub dir: Option<PathBuf>,
+
+    /// Guardar el resultado en JSON.
+    #[arg(long, value_name = "FILE")]
+    pub save: Option<PathBuf>,
+
+    /// Comparar contra un resultado guardado con --save.
+    #[arg(long, value_name = "FILE")]
+    pub baseline: Option<PathBuf>,
+
+    /// Porcentaje de enlentecimiento tolerado antes de marcar regresión.
+    #[arg(long, default_value = "20")]
+    pub max_regression: f64,
+
+    /// Salida JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl BenchCommand {
+    /// Parámetros del corpus según los flags.
+    pub fn spec(&self) -> ProjectSpec {
+        ProjectSpec::new(self.docs, self.depth)
+            .with_modules(self.modules)
+            .with_broken_links(self.broken_links)
+            .with_orphans(self.orphans)
+            .with_placeholders(self.placeholders)
+            .with_seed(self.seed)
+    }
+
+    /// Genera el corpus y mide los comandos.
+    pub fn run(&self) -> OcResult<BenchResult> {
+        // el temporal vive hasta el final de la corrida
+        let temp;
+        let data_dir = match &self.dir {
+            Some(dir) => dir.clone(),
+            None => {
+                temp = tempfile::tempdir()?;
+                temp.path().join("Datos")
+            }
+        };
+
+        let start = Instant::now();
+        self.spec().generate(&data_dir)?;
+        let generate_ms = elapsed_ms(start);
+
+        let mut result = self.measure(&data_dir)?;
+        result.generate_ms = generate_ms;
+
+        if let Some(path) = &self.baseline {
+            let baseline: BenchResult = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+            if baseline.corpus != result.corpus {
+                return Err(OcError::InvalidArgument(format!(
+                    "el baseline {} se midió con otro corpus ({} documentos, semilla {})",
+                    path.display(),
+                    baseline.corpus.docs,
+                    baseline.corpus.seed
+                )));
+            }
+            result.compare(&baseline, self.max_regression);
+        }
+        if let Some(path) = &self.save {
+            std::fs::write(path, serde_json::to_string_pretty(&result)?)?;
+        }
+        Ok(result)
+    }
+
+    /// Corre verify, lint y stats sobre un corpus ya generado.
+    pub fn measure(&self, data_dir: &Path) -> OcResult<BenchResult> {
+        let runs = self.runs.max(1);
+        let steps = vec![
+            Self::step("verify", runs, || {
+                let mut verify = VerifyCommand::parse_from(["verify"]);
+                verify.quiet = true;
+                let verified = verify.run(&data_dir.to_path_buf())?;
+                Ok(format!(
+                    "{} errores, {} warnings",
+                    verified.total_errors, verified.total_warnings
+                ))
+            })?,
+            Self::step("lint", runs, || {
+                let linted = LintCommand::parse_from(["lint"]).run(data_dir)?;
+                Ok(format!(
+                    "{} errores, {} warnings",
+                    linted.error_count(),
+                    linted.warning_count()
+                ))
+            })?,
+            Self::step("stats", runs, || {
+                let (stats, _) = StatsCommand::parse_from(["stats"]).run(data_dir)?;
+                Ok(format!(
+                    "{} documentos, {} links",
+                    stats.total_documents, stats.total_links
+                ))
+            })?,
+        ];
+
+        Ok(BenchResult {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            corpus: self.spec(),
+            runs,
+            generate_ms: 0.0,
+            steps,
+            peak_memory_kb: crate::core::timings::peak_memory_kb(),
+            baseline_version: None,
+            regressions: Vec::new(),
+        })
+    }
+
+    fn step(name: &str, runs: usize, mut f: impl FnMut() -> OcResult<String>) -> OcResult<BenchStep> {
+        let mut best = f64::MAX;
+        let mut summary = String::new();
+        for _ in 0..runs {
+            let start = Instant::now();
+            summary = f()?;
+            best = best.min(elapsed_ms(start));
+        }
+        Ok(BenchStep {
+            name: name.to_string(),
+            ms: best,
+            summary,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_small_corpus() {
+        let cmd = BenchCommand::parse_from(["bench", "--docs", "40", "--depth", "3"]);
+        let result = cmd.run().unwrap();
+        assert_eq!(result.corpus.docs, 40);
+        let names: Vec<&str> = result.steps.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["verify", "lint", "stats"]);
+        assert_eq!(result.steps[0].summary, "0 errores, 0 warnings");
+        assert!(result.steps[2].summary.starts_with("40 documentos"));
+
+        let mut slower = result.clone();
+        for step in &mut slower.steps {
+            step.ms = 100.0;
+        }
+        let mut baseline = result.clone();
+        for step in &mut baseline.steps {
+            step.ms = 50.0;
+        }
+        baseline.steps[1].ms = 5.0;
+        slower.compare(&baseline, 20.0);
+        let regressed: Vec<&str> = slower.regressions.iter().map(|r| r.step.as_str()).collect();
+        assert_eq!(regressed, vec!["verify", "stats"]);
+        assert_eq!(slower.regressions[0].delta_pct, 100.0);
+    }
+
+    #[test]
+    fn test_bench_with_defects() {
+        let cmd = BenchCommand::parse_from([
+            "bench", "--docs", "40", "--depth", "3", "--broken-links", "10", "--placeholders", "5",
+        ]);
+        let result = cmd.run().unwrap();
+        assert_eq!(result.steps[0].summary, "0 errores, 6 warnings");
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: BenchCommand, _cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    let result = cmd.run()?;
+
+    if cmd.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        let corpus = &result.corpus;
+        println!(
+            "⏱️  BENCH v{} — {} documentos, {} módulos, {} niveles (semilla {})",
+            result.version, corpus.docs, corpus.modules, corpus.depth, corpus.seed
+        );
+        println!("   Generación: {:>10.3} ms", result.generate_ms);
+        for step in &result.steps {
+            println!("   {:<10} {:>10.3} ms  ({})", step.name, step.ms, step.summary);
+        }
+        println!("   Total:      {:>10.3} ms", result.total_ms());
+        if let Some(kb) = result.peak_memory_kb {
+            println!("   Memoria pico: {:.1} MB", kb as f64 / 1024.0);
+        }
+        if let Some(version) = &result.baseline_version {
+            if result.regressions.is_empty() {
+                println!("✅ Sin regresiones respecto a v{} (tolerancia {}%)", version, cmd.max_regression);
+            }
+            for r in &result.regressions {
+                println!(
+                    "⚠️  {}: {:.3} ms → {:.3} ms (+{:.1}%) respecto a v{}",
+                    r.step, r.baseline_ms, r.ms, r.delta_pct, version
+                );
+            }
+        }
+    }
+
+    if !result.regressions.is_empty() {
+        anyhow::bail!("{} regresiones de rendimiento", result.regressions.len());
+    }
+    Ok(())
+}
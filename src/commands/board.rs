@@ -0,0 +1,305 @@
+//! Comando board - Tablero kanban de la documentación.
+//!
+//! Agrupa los documentos en columnas según un campo del frontmatter
+//! (`status` por defecto, también `priority`, `author`, `type` o `module`).
+//! Cada tarjeta muestra ID, título, asignado (`author`) y prioridad; con
+//! `--json` el tablero sale listo para alimentar una vista web.
+
+use crate::core::config::OcConfig;
+use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+use crate::errors::{OcError, OcResult};
+use crate::types::breadcrumb::frontmatter_value;
+use crate::types::lifetimes::split_frontmatter;
+use clap::Parser;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Campos por los que se puede agrupar.
+pub const GROUP_FIELDS: &[&str] = &["status", "priority", "author", "type", "module"];
+
+/// Orden de las columnas de status: del borrador a lo retirado.
+const STATUS_FLOW: &[&str] = &[
+    "propuesto", "borrador", "draft", "stub", "pendiente", "futuro", "review", "preparado", "activo",
+    "aceptado", "approved", "deprecado", "supersedido",
+];
+
+/// Columna de los documentos sin el campo.
+pub const NO_VALUE: &str = "(sin valor)";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// BOARD TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Tarjeta de un documento.
+#[derive(Debug, Clone, Serialize)]
+pub struct BoardCard {
+    pub id: String,
+    pub title: String,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub author: Option<String>,
+    pub path: PathBuf,
+}
+
+impl BoardCard {
+    fn from_doc(path: &Path, content: &str) -> Self {
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+        let metadata = split_frontmatter(content).map(|fm| fm.metadata).unwrap_or_default();
+        let field = |name: &str| frontmatter_value(metadata, name).filter(|v| v != "null");
+        let numeric: String = stem.chars().take_while(|c| c.is_ascii_digit() || *c == '.').collect();
+        let numeric = numeric.trim_end_matches('.');
+        Self {
+            id: field("id").unwrap_or_else(|| if numeric.is_empty() { stem.to_string() } else { numeric.to_string() }),
+            title: field("title").unwrap_or_else(|| stem.to_string()),
+            status: field("status").map(|s| s.to_lowercase()),
+            priority: field("priority").map(|p| p.to_lowercase()),
+            author: field("author"),
+            path: path.to_path_buf(),
+        }
+    }
+
+    /// Valor de la tarjeta para el campo de agrupación.
+    fn group(&self, field: &str, metadata: &str) -> Option<String> {
+        match field {
+            "status" => self.status.clone(),
+            "priority" => self.priority.clone(),
+            "author" => self.author.clone(),
+            "module" => self.id.split('.').next().filter(|m| m.chars().all(|c| c.is_ascii_digit())).map(String::from),
+            other => frontmatter_value(metadata, other).map(|v| v.to_lowercase()),
+        }
+    }
+
+    /// Texto de la tarjeta en la terminal.
+    pub fn render(&self) -> String {
+        let mut meta = Vec::new();
+        if let Some(author) = &self.author {
+            meta.push(format!("@{}", author));
+        }
+        if let Some(priority) = &self.priority {
+            meta.push(priority.clone());
+        }
+        if meta.is_empty() {
+            format!("{} {}", self.id, self.title)
+        } else {
+            format!("{} {}\n{}", self.id, self.title, meta.join(" · "))
+        }
+    }
+}
+
+/// Columna del tablero.
+#[derive(Debug, Clone, Serialize)]
+pub struct BoardColumn {
+    pub name: String,
+    pub cards: Vec<BoardCard>,
+}
+
+/// Tablero completo.
+#[derive(Debug, Clone, Serialize)]
+pub struct Board {
+    pub group_by: String,
+    pub total: usize,
+    pub columns: Vec<BoardColumn>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// BOARD COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Comando board.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "board", about = "Tablero kanban desde el frontmatter")]
+pub struct BoardCommand {
+    /// Campo para las columnas: status, priority, author, type o module.
+    #[arg(long, default_value = "status")]
+    pub group_by: String,
+
+    /// Omitir la columna de documentos sin el campo.
+    #[arg(long)]
+    pub hide_empty: bool,
+
+    /// Máximo de tarjetas por columna en la terminal (el JSON las incluye todas).
+    #[arg(long, default_value = "20")]
+    pub limit: usize,
+
+    /// Ruta del proyecto.
+    #[arg(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// Salida JSON.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Escribir el JSON en un archivo.
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+impl BoardCommand {
+    pub fn run(&self, data_dir: &Path) -> OcResult<Board> {
+        let field = self.group_by.trim().to_lowercase();
+        if !GROUP_FIELDS.contains(&field.as_str()) {
+            return Err(OcError::Custom(format!(
+                "--group-by inválido '{}' (válidos: {})",
+                self.group_by,
+                GROUP_FIELDS.join(", ")
+            )));
+        }
+        let priorities = OcConfig::from_cwd().unwrap_or_default().validation.priorities;
+        let priority_rank = |card: &BoardCard| {
+            card.priority
+                .as_ref()
+                .and_then(|p| priorities.iter().position(|known| known.eq_ignore_ascii_case(p)))
+                .unwrap_or(priorities.len())
+        };
+
+        let mut columns: Vec<BoardColumn> = Vec::new();
+        let mut total = 0;
+        for path in get_all_md_files(data_dir, &ScanOptions::new())? {
+            let content = read_file_content(&path)?;
+            let card = BoardCard::from_doc(&path, &content);
+            let metadata = split_frontmatter(&content).map(|fm| fm.metadata).unwrap_or_default();
+            let name = card.group(&field, metadata).unwrap_or_else(|| NO_VALUE.to_string());
+            if self.hide_empty && name == NO_VALUE {
+                continue;
+            }
+            total += 1;
+            match columns.iter_mut().find(|c| c.name == name) {
+                Some(column) => column.cards.push(card),
+                None => columns.push(BoardColumn { name, cards: vec![card] }),
+            }
+        }
+
+        // Orden de columnas: flujo de status o prioridades de la config; el resto natural
+        let order: Vec<&str> = match field.as_str() {
+            "status" => STATUS_FLOW.to_vec(),
+            "priority" => priorities.iter().map(String::as_str).collect(),
+            _ => Vec::new(),
+        };
+        columns.sort_by(|a, b| {
+            let rank = |c: &BoardColumn| {
+                let known = order.iter().position(|o| *o == c.name).unwrap_or(order.len());
+                (c.name == NO_VALUE, known)
+            };
+            rank(a)
+                .cmp(&rank(b))
+                .then_with(|| crate::core::files::natural_cmp(&a.name, &b.name))
+        });
+        // Tarjetas: por prioridad y luego por ID natural (orden del escaneo)
+        for column in &mut columns {
+            column.cards.sort_by_key(|card| priority_rank(card));
+        }
+
+        Ok(Board {
+            group_by: field,
+            total,
+            columns,
+        })
+    }
+}
+
+/// Tablero en columnas, con hasta `limit` tarjetas por columna.
+pub fn render_board(board: &Board, limit: usize) -> String {
+    use crate::ui::tables::{create_styled_table, TableStyle};
+
+    let headers: Vec<String> = board
+        .columns
+        .iter()
+        .map(|c| format!("{} ({})", c.name, c.cards.len()))
+        .collect();
+    let mut table = create_styled_table(&headers, TableStyle::Compact);
+    let rows = board.columns.iter().map(|c| c.cards.len().min(limit)).max().unwrap_or(0);
+    for row in 0..rows {
+        let cells: Vec<String> = board
+            .columns
+            .iter()
+            .map(|c| c.cards.get(row).filter(|_| row < limit).map(BoardCard::render).unwrap_or_default())
+            .collect();
+        table.add_row(cells);
+    }
+    let hidden: Vec<String> = board
+        .columns
+        .iter()
+        .map(|c| match c.cards.len().saturating_sub(limit) {
+            0 => String::new(),
+            n => format!("… y {} más", n),
+        })
+        .collect();
+    if hidden.iter().any(|h| !h.is_empty()) {
+        table.add_row(hidden);
+    }
+    table.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_board_groups_and_orders() {
+        let dir = tempfile::tempdir().unwrap();
+        let docs = [
+            ("1 Intro", "id: \"1\"\ntitle: \"Intro\"\nstatus: activo\nauthor: ana\npriority: baja"),
+            ("1.1 Alta", "id: \"1.1\"\ntitle: \"Alta\"\nstatus: Borrador\nauthor: luis\npriority: critica"),
+            ("1.2 Baja", "id: \"1.2\"\ntitle: \"Baja\"\nstatus: activo\npriority: alta"),
+            ("2 Pagos", "id: \"2\"\ntitle: \"Pagos\"\nstatus: vigente"),
+            ("notas", "title: \"Notas\""),
+        ];
+        for (stem, metadata) in docs {
+            std::fs::write(dir.path().join(format!("{}.md", stem)), format!("---\n{}\n---\n# X\n", metadata)).unwrap();
+        }
+
+        let board = BoardCommand::parse_from(["board"]).run(dir.path()).unwrap();
+        let columns: Vec<(&str, Vec<&str>)> = board
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.cards.iter().map(|card| card.id.as_str()).collect()))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("borrador", vec!["1.1"]),
+                ("activo", vec!["1.2", "1"]),
+                ("vigente", vec!["2"]),
+                (NO_VALUE, vec!["notas"]),
+            ]
+        );
+        assert_eq!(board.columns[0].cards[0].render(), "1.1 Alta\n@luis · critica");
+
+        let by_module = BoardCommand::parse_from(["board", "--group-by", "module", "--hide-empty"]);
+        let board = by_module.run(dir.path()).unwrap();
+        assert_eq!(board.total, 4);
+        assert_eq!(board.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), vec!["1", "2"]);
+
+        let rendered = render_board(&board, 1);
+        assert!(rendered.contains("1 (3)") && rendered.contains("… y 2 más"));
+        assert!(BoardCommand::parse_from(["board", "--group-by", "color"]).run(dir.path()).is_err());
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: BoardCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    let default_dir = PathBuf::from(&cli.data_dir);
+    let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
+    let board = cmd.run(data_dir)?;
+
+    if let Some(output) = &cmd.output {
+        std::fs::write(output, serde_json::to_string_pretty(&board)?)?;
+        eprintln!("📋 Tablero exportado: {}", output.display());
+    }
+    if cmd.json {
+        println!("{}", serde_json::to_string_pretty(&board)?);
+        return Ok(());
+    }
+    if cmd.output.is_some() {
+        return Ok(());
+    }
+
+    println!("📋 Tablero por {} ({} documentos)\n", board.group_by, board.total);
+    if board.columns.is_empty() {
+        println!("  Sin documentos");
+        return Ok(());
+    }
+    println!("{}", render_board(&board, cmd.limit));
+    Ok(())
+}
@@ -0,0 +1,123 @@
+//! Comando cache - Cachés del proyecto.
+//!
+//! `cache stats` muestra entradas y tamaño de `.oc_diagdoc/cache/frontmatter.bin`
+//! (ver `core::frontmatter_cache`) y los archivos de estado e índices de
+//! `.oc_diagdoc/cache/state/` (ver `core::project_cache`); `cache clear` los
+//! borra para forzar el re-parseo de todos los documentos.
+
+use crate::core::frontmatter_cache::{clear, CacheStats, FrontmatterCache};
+use crate::core::project_cache::{CacheStore, StoredEntry};
+use crate::errors::OcResult;
+use clap::Parser;
+use serde::Serialize;
+use std::path::Path;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CACHE COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Resultado del comando cache.
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheResult {
+    pub action: String,
+    pub stats: CacheStats,
+    /// Estado e índices guardados (`project`, `id_index`, `link_graph`, `stats`).
+    pub entries: Vec<StoredEntry>,
+    /// Con `clear`: si había caché para borrar.
+    pub cleared: bool,
+}
+
+/// Comando de caché.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "cache", about = "Estado y limpieza de las cachés del proyecto")]
+pub struct CacheCommand {
+    /// Acción: stats o clear.
+    #[arg(default_value = "stats", value_parser = ["stats", "clear"])]
+    pub action: String,
+
+    /// Output JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl CacheCommand {
+    pub fn run(&self, cache_path: &Path, store: &CacheStore) -> OcResult<CacheResult> {
+        let stats = FrontmatterCache::load(cache_path).stats(cache_path);
+        let entries = store.entries();
+        let cleared = self.action == "clear" && (clear(cache_path)? | (store.clear()? > 0));
+        Ok(CacheResult {
+            action: self.action.clone(),
+            stats,
+            entries,
+            cleared,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "cache"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_stats_and_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc = dir.path().join("1.md");
+        let content = "---\nid: \"1\"\ntitle: Uno\n---\n";
+        std::fs::write(&doc, content).unwrap();
+        let cache_path = dir.path().join("cache/frontmatter.bin");
+        let mut cache = FrontmatterCache::load(&cache_path);
+        cache.parse(&doc, content).unwrap();
+        cache.save(&cache_path).unwrap();
+        let store = CacheStore::new(dir.path().join("cache/state"));
+        let tree = crate::commands::tree::TreeCommand::parse_from(["tree"]);
+        tree.run_with_store(dir.path(), Some(&store)).unwrap();
+
+        let result = CacheCommand::parse_from(["cache"]).run(&cache_path, &store).unwrap();
+        assert_eq!(result.stats.entries, 1);
+        assert!(result.stats.size_bytes > 0);
+        assert_eq!(result.entries.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(), ["id_index"]);
+
+        let result = CacheCommand::parse_from(["cache", "clear"]).run(&cache_path, &store).unwrap();
+        assert!(result.cleared && !cache_path.exists() && store.entries().is_empty());
+        assert!(!CacheCommand::parse_from(["cache", "clear"]).run(&cache_path, &store).unwrap().cleared);
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: CacheCommand, _cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    let cache_path = crate::core::frontmatter_cache::frontmatter_cache_path();
+    let store = CacheStore::new(Path::new(crate::core::config::CONFIG_DIR).join(crate::core::project_cache::CACHE_DIR));
+    let result = cmd.run(&cache_path, &store)?;
+
+    if cmd.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    let stats = &result.stats;
+    if result.action == "clear" {
+        if result.cleared {
+            println!(
+                "🧹 Caché borrada: {} ({} entradas) y {} índices en {}",
+                stats.path.display(),
+                stats.entries,
+                result.entries.len(),
+                store.dir().display()
+            );
+        } else {
+            println!("🧹 Sin caché en {}", stats.path.display());
+        }
+        return Ok(());
+    }
+    println!("🗃️  Caché de frontmatter: {}", stats.path.display());
+    println!("  📊 {} documentos, {:.1} KB", stats.entries, stats.size_bytes as f64 / 1024.0);
+    println!("🗃️  Estado e índices: {}", store.dir().display());
+    if result.entries.is_empty() {
+        println!("  (vacío)");
+    }
+    for entry in &result.entries {
+        println!("  📦 {:12} {:.1} KB", entry.name, entry.size_bytes as f64 / 1024.0);
+    }
+    Ok(())
+}
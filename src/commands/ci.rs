@@ -2,10 +2,20 @@
 //!
 //! Ejecuta verificaciones para CI/CD.
 
-use crate::errors::OcResult;
+use crate::commands::health::HealthCommand;
+use crate::commands::links::LinksCommand;
+use crate::commands::lint::LintCommand;
+use crate::commands::verify::{md_cell, VerifyCommand};
+use crate::core::config::{CiProfile, OcConfig};
+use crate::core::pipeline::DocumentCache;
+use crate::core::webhook::{NotifyState, NotifySummary, WebhookFormat};
+use crate::errors::{OcError, OcResult};
 use clap::Parser;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // CI TYPES
@@ -43,17 +53,25 @@ impl CiCheck {
 /// Resultado de CI.
 #[derive(Debug, Clone, Serialize)]
 pub struct CiResult {
+    pub profile: String,
     pub checks: Vec<CiCheck>,
     pub all_passed: bool,
     pub total_duration_ms: u64,
+    /// Score de salud, si el perfil incluye `health`.
+    pub health: Option<u8>,
+    /// Errores de verify (para detectar errores nuevos).
+    pub errors: Vec<String>,
 }
 
 impl CiResult {
     pub fn new() -> Self {
         Self {
+            profile: "standard".to_string(),
             checks: Vec::new(),
             all_passed: true,
             total_duration_ms: 0,
+            health: None,
+            errors: Vec::new(),
         }
     }
 
@@ -72,6 +90,49 @@ impl CiResult {
             1
         }
     }
+
+    /// Reporte agregado en Markdown.
+    pub fn to_markdown(&self) -> String {
+        let status = if self.all_passed { "✅ PASSED" } else { "❌ FAILED" };
+        let mut output = format!("# CI · perfil `{}`\n\n", self.profile);
+        output.push_str(&format!(
+            "*Generado: {} · oc_diagdoc v{}*\n\n",
+            chrono::Local::now().format("%Y-%m-%d %H:%M"),
+            env!("CARGO_PKG_VERSION")
+        ));
+        output.push_str(&format!(
+            "**Resultado:** {} ({}ms)\n\n",
+            status, self.total_duration_ms
+        ));
+        output.push_str("| Check | Estado | Detalle | Tiempo |\n");
+        output.push_str("|-------|--------|---------|--------|\n");
+        for check in &self.checks {
+            output.push_str(&format!(
+                "| {} | {} | {} | {}ms |\n",
+                md_cell(&check.name),
+                if check.passed { "✅" } else { "❌" },
+                md_cell(&check.message),
+                check.duration_ms
+            ));
+        }
+        output
+    }
+
+    /// Resumen para el webhook comparando contra el estado anterior.
+    pub fn to_notify_summary(
+        &self,
+        state: &NotifyState,
+        hashes: &BTreeMap<String, String>,
+    ) -> NotifySummary {
+        NotifySummary {
+            passed: self.all_passed,
+            health: self.health,
+            total_errors: self.errors.len(),
+            new_errors: state.new_errors(&self.errors),
+            changed_docs: state.changed_docs(hashes),
+            ..NotifySummary::new("ci")
+        }
+    }
 }
 
 impl Default for CiResult {
@@ -92,25 +153,152 @@ pub struct CiCommand {
     #[arg(short, long)]
     pub path: Option<PathBuf>,
 
-    /// Modo estricto.
+    /// Modo estricto (equivale a --profile strict).
     #[arg(long)]
     pub strict: bool,
 
+    /// Perfil de pipeline: strict, standard, fast o uno definido en config (ci.profiles).
+    #[arg(long, default_value = "standard")]
+    pub profile: String,
+
+    /// Guardar reporte agregado (.md o .json).
+    #[arg(long, value_name = "FILE")]
+    pub report: Option<PathBuf>,
+
     /// Output JSON.
     #[arg(long)]
     pub json: bool,
+
+    /// URL de webhook (sobrescribe notify.webhook_url de config).
+    #[arg(long, value_name = "URL")]
+    pub webhook: Option<String>,
+
+    /// Formato del webhook: slack, teams o json.
+    #[arg(long, value_name = "FORMAT")]
+    pub webhook_format: Option<WebhookFormat>,
 }
 
 impl CiCommand {
-    pub fn run(&self) -> OcResult<CiResult> {
+    /// Nombre del perfil efectivo.
+    pub fn profile_name(&self) -> &str {
+        if self.strict {
+            "strict"
+        } else {
+            &self.profile
+        }
+    }
+
+    /// Resuelve el perfil desde config (`.oc_diagdoc/config.yaml`) o predefinidos.
+    pub fn resolve_profile(&self) -> OcResult<CiProfile> {
+        let config = OcConfig::from_cwd().unwrap_or_default();
+        config.ci.profile(self.profile_name()).ok_or_else(|| {
+            OcError::InvalidArgument(format!(
+                "Perfil CI desconocido: '{}' (use strict, standard, fast o defina ci.profiles)",
+                self.profile_name()
+            ))
+        })
+    }
+
+    pub fn run(&self, data_dir: &Path) -> OcResult<CiResult> {
+        let profile = self.resolve_profile()?;
+        self.run_profile(data_dir, &profile)
+    }
+
+    /// Ejecuta los checks del perfil y evalúa sus umbrales.
+    pub fn run_profile(&self, data_dir: &Path, profile: &CiProfile) -> OcResult<CiResult> {
         let mut result = CiResult::new();
+        result.profile = self.profile_name().to_string();
+        // verify y lint comparten la caché: cada archivo se lee una vez
+        let documents = Arc::new(DocumentCache::new());
 
-        result.add_check(CiCheck::pass("lint", "No issues", 50));
-        result.add_check(CiCheck::pass("schema", "Valid", 30));
-        result.add_check(CiCheck::pass("links", "All valid", 100));
+        if profile.has_check("verify") {
+            let start = Instant::now();
+            let mut verify = VerifyCommand::parse_from(["verify"]);
+            verify.quick = profile.verify_quick;
+            verify.quiet = true;
+            verify.documents = Some(Arc::clone(&documents));
+            let verified = verify.run(&data_dir.to_path_buf())?;
+            result.errors = verified
+                .phases
+                .iter()
+                .flat_map(|p| p.errors.iter().cloned())
+                .collect();
+            let message = format!(
+                "{} errores, {} warnings",
+                verified.total_errors, verified.total_warnings
+            );
+            result.add_check(Self::threshold_check(
+                "verify",
+                &message,
+                start,
+                verified.total_errors <= profile.max_errors
+                    && within(verified.total_warnings, profile.max_warnings),
+            ));
+        }
+
+        if profile.has_check("lint") {
+            let start = Instant::now();
+            let mut lint = LintCommand::parse_from(["lint"]);
+            lint.documents = Some(Arc::clone(&documents));
+            let linted = lint.run(data_dir)?;
+            let message = format!(
+                "{} errores, {} warnings",
+                linted.error_count(),
+                linted.warning_count()
+            );
+            result.add_check(Self::threshold_check(
+                "lint",
+                &message,
+                start,
+                linted.error_count() <= profile.max_errors
+                    && within(linted.warning_count(), profile.max_warnings),
+            ));
+        }
+
+        if profile.has_check("links") {
+            let start = Instant::now();
+            let links = LinksCommand::parse_from(["links"]).run(data_dir)?;
+            let message = format!("{} enlaces rotos", links.total_broken);
+            result.add_check(Self::threshold_check(
+                "links",
+                &message,
+                start,
+                links.total_broken <= profile.max_broken_links,
+            ));
+        }
+
+        if profile.has_check("health") {
+            let start = Instant::now();
+            let health = HealthCommand::parse_from(["health"]).run(data_dir)?;
+            result.health = Some(health.overall_score);
+            let message = format!(
+                "score {} ({}), mínimo {}",
+                health.overall_score, health.grade, profile.min_health
+            );
+            result.add_check(Self::threshold_check(
+                "health",
+                &message,
+                start,
+                health.overall_score >= profile.min_health,
+            ));
+        }
 
         Ok(result)
     }
+
+    fn threshold_check(name: &str, message: &str, start: Instant, passed: bool) -> CiCheck {
+        let ms = start.elapsed().as_millis() as u64;
+        if passed {
+            CiCheck::pass(name, message, ms)
+        } else {
+            CiCheck::fail(name, message, ms)
+        }
+    }
+}
+
+/// ¿`value` respeta el límite opcional?
+fn within(value: usize, limit: Option<usize>) -> bool {
+    limit.map(|max| value <= max).unwrap_or(true)
 }
 
 #[cfg(test)]
@@ -123,6 +311,13 @@ mod tests {
         assert!(check.passed);
     }
 
+    #[test]
+    fn test_markdown_escapes_cells() {
+        let mut result = CiResult::new();
+        result.add_check(CiCheck::fail("lint", "a | b\nc", 5));
+        assert!(result.to_markdown().contains("| lint | ❌ | a \\| b<br>c | 5ms |\n"));
+    }
+
     #[test]
     fn test_ci_check_fail() {
         let check = CiCheck::fail("test", "error", 10);
@@ -148,16 +343,84 @@ mod tests {
         assert!(!result.all_passed);
         assert_eq!(result.exit_code(), 1);
     }
+
+    #[test]
+    fn test_within() {
+        assert!(within(5, None));
+        assert!(within(0, Some(0)));
+        assert!(!within(1, Some(0)));
+    }
+
+    #[test]
+    fn test_profile_name() {
+        let mut cmd = CiCommand::parse_from(["ci", "--profile", "fast"]);
+        assert_eq!(cmd.profile_name(), "fast");
+        cmd.strict = true;
+        assert_eq!(cmd.profile_name(), "strict");
+    }
+
+    #[test]
+    fn test_run_fast_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("1. inicio.md"),
+            "---\nid: \"1\"\ntitle: \"Inicio\"\n---\n\n# Inicio\n",
+        )
+        .unwrap();
+
+        let cmd = CiCommand::parse_from(["ci", "--profile", "fast"]);
+        let result = cmd.run_profile(dir.path(), &CiProfile::fast()).unwrap();
+        let names: Vec<&str> = result.checks.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["verify", "lint"]);
+        assert!(result.to_markdown().contains("perfil `fast`"));
+    }
+
+    #[test]
+    fn test_notify_summary_only_new() {
+        let mut result = CiResult::new();
+        result.errors = vec!["viejo".into(), "nuevo".into()];
+        result.health = Some(80);
+
+        let mut state = NotifyState::default();
+        state.errors.insert("viejo".into());
+        let hashes = BTreeMap::from([("a.md".to_string(), "h1".to_string())]);
+
+        let summary = result.to_notify_summary(&state, &hashes);
+        assert_eq!(summary.new_errors, vec!["nuevo".to_string()]);
+        assert_eq!(summary.changed_docs, vec!["a.md".to_string()]);
+        assert_eq!(summary.health, Some(80));
+    }
 }
 
 /// Función run para CLI.
 #[cfg(feature = "cli")]
-pub fn run(cmd: CiCommand, _cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
-    let result = cmd.run()?;
+pub fn run(cmd: CiCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    let default_dir = PathBuf::from(&cli.data_dir);
+    let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
+    let result = cmd.run(data_dir)?;
 
+    if let Some(report) = &cmd.report {
+        let is_json = report.extension().map(|e| e == "json").unwrap_or(false);
+        let content = if is_json {
+            serde_json::to_string_pretty(&result)?
+        } else {
+            result.to_markdown()
+        };
+        std::fs::write(report, content)?;
+        eprintln!("📄 Reporte CI guardado: {}", report.display());
+    }
+
+    notify_webhook(&cmd, data_dir, &result);
+
+    if cmd.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return crate::commands::ExitStatus::result(result.exit_code());
+    }
+
+    println!("🧪 Perfil: {}", result.profile);
     for check in &result.checks {
         let icon = if check.passed { "✅" } else { "❌" };
-        println!("{} {} ({}ms)", icon, check.name, check.duration_ms);
+        println!("{} {}: {} ({}ms)", icon, check.name, check.message, check.duration_ms);
     }
 
     let status = if result.all_passed {
@@ -167,5 +430,37 @@ pub fn run(cmd: CiCommand, _cli: &crate::commands::CliConfig) -> anyhow::Result<
     };
     println!("\n🏁 CI {}: {}ms total", status, result.total_duration_ms);
 
-    std::process::exit(result.exit_code());
+    crate::commands::ExitStatus::result(result.exit_code())
+}
+
+/// Envía el resumen al webhook configurado y actualiza el estado.
+#[cfg(feature = "cli")]
+fn notify_webhook(cmd: &CiCommand, data_dir: &Path, result: &CiResult) {
+    use crate::core::webhook::{default_state_path, Notifier};
+
+    let config = OcConfig::from_cwd().unwrap_or_default();
+    let Some(notifier) =
+        Notifier::from_config(&config.notify, cmd.webhook.as_deref(), cmd.webhook_format)
+    else {
+        return;
+    };
+
+    let state_path = default_state_path();
+    let state = NotifyState::load(&state_path);
+    let hashes = NotifyState::scan_hashes(data_dir);
+    let summary = result.to_notify_summary(&state, &hashes);
+
+    match notifier.notify(&summary) {
+        Ok(true) => eprintln!("📣 Notificación enviada al webhook"),
+        Ok(false) => {}
+        Err(e) => eprintln!("⚠️  {}", e),
+    }
+
+    let next = NotifyState {
+        errors: result.errors.iter().cloned().collect(),
+        hashes,
+    };
+    if let Err(e) = next.save(&state_path) {
+        eprintln!("⚠️  {}", e);
+    }
 }
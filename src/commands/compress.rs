@@ -1,10 +1,19 @@
 //! Comando compress - Compilación de documentación.
 //!
-//! Compila toda la documentación en un solo archivo.
+//! Compila toda la documentación en un solo archivo. Con `--incremental`
+//! solo reprocesa los documentos que cambiaron y no reescribe el compilado
+//! si ninguno cambió ([`crate::core::incremental`]).
 
-use crate::errors::OcResult;
+use crate::core::incremental::{manifest_for_file, sources_hash, BuildManifest, IncrementalStats, ManifestEntry};
+use crate::core::tokens::Tokenizer;
+use crate::core::transclude::{TranscludeStats, Transcluder, DEFAULT_EMBED_DEPTH};
+use crate::core::yaml::frontmatter_block;
+use crate::errors::{OcError, OcResult};
+use crate::quantum::knapsack::{knapsack, Item};
+use crate::types::breadcrumb::frontmatter_value;
+use crate::types::DocumentStatus;
 use clap::Parser;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -19,6 +28,24 @@ pub struct CompressResult {
     pub documents_included: usize,
     pub total_words: usize,
     pub output_bytes: usize,
+    /// Selección por presupuesto de palabras (solo con `--budget-words`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection: Option<SelectionReport>,
+    /// Reporte de presupuesto (solo con `--max-tokens`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<BudgetReport>,
+    /// Embeds `![[...]]` expandidos, sin resolver u omitidos.
+    #[serde(skip_serializing_if = "TranscludeStats::is_empty")]
+    pub embeds: TranscludeStats,
+    /// Variables `{{...}}` sin valor en la sección `variables` de config.
+    #[serde(skip_serializing_if = "std::collections::BTreeSet::is_empty")]
+    pub unresolved_variables: std::collections::BTreeSet<String>,
+    /// Documentos reprocesados y reutilizados (solo con `--incremental`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incremental: Option<IncrementalStats>,
+    /// Con `--incremental`: el compilado no se reescribió por no haber cambios.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub up_to_date: bool,
 }
 
 impl CompressResult {
@@ -29,6 +56,12 @@ impl CompressResult {
             documents_included: 0,
             total_words: 0,
             output_bytes: 0,
+            selection: None,
+            budget: None,
+            embeds: TranscludeStats::default(),
+            unresolved_variables: Default::default(),
+            incremental: None,
+            up_to_date: false,
         }
     }
 
@@ -60,6 +93,298 @@ pub struct CompressedCollection {
     pub documents: Vec<CompressedDoc>,
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// TOKEN BUDGET
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Documento candidato a compilar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    file_id: String,
+    title: String,
+    module: Option<String>,
+    body: String,
+    words: usize,
+    status: Option<String>,
+    priority: Option<String>,
+    /// Bloque YAML del frontmatter (para `--prioritize`).
+    metadata: String,
+}
+
+impl Entry {
+    /// Bloque tal como se emite en el compilado.
+    fn chunk(&self) -> String {
+        format!(
+            "\n---\n\n## {} {{{}}}\n\n{}\n\n",
+            self.title, self.file_id, self.body
+        )
+    }
+
+    /// Línea del documento en la tabla de contenidos.
+    fn toc_line(&self) -> String {
+        let anchor = self.file_id.replace(['.', ' '], "-").to_lowercase();
+        format!("- [{}](#{})\n", self.title, anchor)
+    }
+
+    /// Tokens que suma al compilado: su bloque y su línea en la TOC.
+    fn cost(&self, tokenizer: Tokenizer) -> usize {
+        tokenizer.count(&self.chunk()) + tokenizer.count(&self.toc_line())
+    }
+
+    /// Clave de relevancia: status, prioridad y profundidad (menor = más relevante).
+    fn rank(&self) -> (u8, u8, usize) {
+        (
+            status_rank(self.status.as_deref()),
+            priority_rank(self.priority.as_deref()),
+            self.file_id
+                .split_whitespace()
+                .next()
+                .map(|id| id.matches('.').count())
+                .unwrap_or(0),
+        )
+    }
+}
+
+/// Relevancia por status: lo revisado/activo entra antes que borradores y obsoletos.
+fn status_rank(status: Option<&str>) -> u8 {
+    match status.and_then(|s| s.parse::<DocumentStatus>().ok()) {
+        Some(DocumentStatus::Reviewed) => 0,
+        Some(DocumentStatus::Active) => 1,
+        None => 2,
+        Some(DocumentStatus::Draft) => 3,
+        Some(DocumentStatus::Stub) => 4,
+        Some(DocumentStatus::Deprecated) | Some(DocumentStatus::Archived) => 5,
+    }
+}
+
+/// Relevancia por prioridad (`critical`/`alta`/`P1`...).
+fn priority_rank(priority: Option<&str>) -> u8 {
+    match priority.map(|p| p.trim().to_lowercase()).as_deref() {
+        Some("critical" | "crítica" | "critica" | "p0" | "0") => 0,
+        Some("high" | "alta" | "p1" | "1") => 1,
+        Some("low" | "baja" | "p3" | "3") => 3,
+        _ => 2,
+    }
+}
+
+/// Documento recortado para entrar en el presupuesto.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrimmedDoc {
+    pub id: String,
+    pub sections_dropped: usize,
+}
+
+/// Reporte de qué entró y qué quedó fuera del presupuesto.
+#[derive(Debug, Clone, Serialize)]
+pub struct BudgetReport {
+    pub tokenizer: Tokenizer,
+    pub max_tokens: usize,
+    pub used_tokens: usize,
+    pub excluded: Vec<String>,
+    pub trimmed: Vec<TrimmedDoc>,
+}
+
+/// Divide el body en secciones por headings `##` o más profundos.
+fn split_sections(body: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+    let mut in_code = false;
+    for line in body.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+        }
+        if !in_code && trimmed.starts_with("##") && offset > start {
+            sections.push(&body[start..offset]);
+            start = offset;
+        }
+        offset += line.len();
+    }
+    if start < body.len() {
+        sections.push(&body[start..]);
+    }
+    sections
+}
+
+/// Selecciona documentos por relevancia hasta `max_tokens`, recortando las
+/// secciones finales de los que no entran completos. Conserva el orden original.
+/// `overhead` son los tokens fijos del compilado (header y título de la TOC).
+fn apply_budget(
+    entries: Vec<Entry>,
+    max_tokens: usize,
+    overhead: usize,
+    tokenizer: Tokenizer,
+) -> (Vec<Entry>, BudgetReport) {
+    let mut report = BudgetReport {
+        tokenizer,
+        max_tokens,
+        used_tokens: overhead,
+        excluded: Vec::new(),
+        trimmed: Vec::new(),
+    };
+
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by_key(|&i| entries[i].rank());
+
+    let mut kept: Vec<Option<Entry>> = vec![None; entries.len()];
+    for i in order {
+        let entry = &entries[i];
+        let remaining = max_tokens.saturating_sub(report.used_tokens);
+        let tokens = entry.cost(tokenizer);
+        if tokens <= remaining {
+            report.used_tokens += tokens;
+            kept[i] = Some(entry.clone());
+            continue;
+        }
+
+        // Recortar: conservar secciones iniciales mientras entren
+        let sections = split_sections(&entry.body);
+        let mut trimmed = entry.clone();
+        for keep in (1..sections.len()).rev() {
+            let dropped = sections.len() - keep;
+            trimmed.body = format!(
+                "{}\n\n_[recortado: {} secciones omitidas por presupuesto]_",
+                sections[..keep].concat().trim_end(),
+                dropped
+            );
+            let tokens = trimmed.cost(tokenizer);
+            if tokens <= remaining {
+                report.used_tokens += tokens;
+                trimmed.words = trimmed.body.split_whitespace().count();
+                report.trimmed.push(TrimmedDoc {
+                    id: entry.file_id.clone(),
+                    sections_dropped: dropped,
+                });
+                kept[i] = Some(trimmed);
+                break;
+            }
+        }
+        if kept[i].is_none() {
+            report.excluded.push(entry.file_id.clone());
+        }
+    }
+
+    report.excluded.sort();
+    (kept.into_iter().flatten().collect(), report)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// WORD BUDGET
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Valor de cada criterio `--prioritize` cumplido; domina sobre status y
+/// prioridad, que solo desempatan.
+const CRITERION_VALUE: u64 = 100;
+
+/// Criterio `campo=valor` de `--prioritize`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Criterion {
+    pub field: String,
+    pub value: String,
+}
+
+impl Criterion {
+    pub fn parse(spec: &str) -> OcResult<Self> {
+        match spec.split_once('=') {
+            Some((field, value)) if !field.trim().is_empty() && !value.trim().is_empty() => Ok(Self {
+                field: field.trim().to_lowercase(),
+                value: value.trim().to_string(),
+            }),
+            _ => Err(OcError::InvalidArgument(format!(
+                "--prioritize '{}' inválido (use campo=valor, ej. status=activo)",
+                spec
+            ))),
+        }
+    }
+
+    /// ¿El documento lo cumple? `status` compara el estado normalizado
+    /// (`activo` = `active`); el resto de los campos, sin distinguir mayúsculas.
+    fn matches(&self, entry: &Entry) -> bool {
+        let Some(actual) = frontmatter_value(&entry.metadata, &self.field) else {
+            return false;
+        };
+        if self.field == "status" {
+            if let (Ok(a), Ok(b)) = (actual.parse::<DocumentStatus>(), self.value.parse::<DocumentStatus>()) {
+                return a == b;
+            }
+        }
+        actual.to_lowercase() == self.value.to_lowercase()
+    }
+}
+
+/// Valor de un documento para la selección: criterios cumplidos, status y
+/// prioridad.
+fn entry_value(entry: &Entry, criteria: &[Criterion]) -> u64 {
+    let matched = criteria.iter().filter(|c| c.matches(entry)).count() as u64;
+    let status = 5 - status_rank(entry.status.as_deref()) as u64;
+    let priority = 3 - priority_rank(entry.priority.as_deref()) as u64;
+    1 + status + priority + matched * CRITERION_VALUE
+}
+
+/// Documento que quedó fuera de la selección.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExcludedDoc {
+    pub id: String,
+    pub words: usize,
+    pub value: u64,
+}
+
+/// Resultado de `--budget-words`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SelectionReport {
+    pub budget_words: usize,
+    pub used_words: usize,
+    /// Valor total de lo incluido.
+    pub value: u64,
+    /// Excluidos, de mejor a peor relación valor/palabras.
+    pub excluded: Vec<ExcludedDoc>,
+}
+
+/// Elige los documentos de mayor valor total que entran en `budget`
+/// palabras (mochila 0/1, ver `quantum::knapsack`). Conserva el orden original.
+fn select_by_budget(entries: Vec<Entry>, budget: usize, criteria: &[Criterion]) -> (Vec<Entry>, SelectionReport) {
+    let items: Vec<Item> = entries
+        .iter()
+        .map(|entry| Item {
+            weight: entry.words,
+            value: entry_value(entry, criteria),
+        })
+        .collect();
+    let selection = knapsack(&items, budget);
+
+    let mut excluded: Vec<ExcludedDoc> = entries
+        .iter()
+        .zip(&items)
+        .zip(&selection.chosen)
+        .filter(|(_, &chosen)| !chosen)
+        .map(|((entry, item), _)| ExcludedDoc {
+            id: entry.file_id.clone(),
+            words: item.weight,
+            value: item.value,
+        })
+        .collect();
+    // valor/palabras descendente sin flotantes: a/b > c/d ⇔ a·d > c·b
+    excluded.sort_by(|a, b| {
+        (b.value * a.words.max(1) as u64)
+            .cmp(&(a.value * b.words.max(1) as u64))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let report = SelectionReport {
+        budget_words: budget,
+        used_words: selection.weight,
+        value: selection.value,
+        excluded,
+    };
+    let kept = entries
+        .into_iter()
+        .zip(selection.chosen)
+        .filter_map(|(entry, chosen)| chosen.then_some(entry))
+        .collect();
+    (kept, report)
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // COMPRESS COMMAND
 // ═══════════════════════════════════════════════════════════════════════════
@@ -117,19 +442,57 @@ pub struct CompressCommand {
     /// P3-B4: Modo preview - mostrar output sin escribir archivo.
     #[arg(long)]
     pub preview: bool,
+
+    /// Presupuesto máximo de tokens (prioriza por status/prioridad/profundidad).
+    #[arg(long, value_name = "N")]
+    pub max_tokens: Option<usize>,
+
+    /// Presupuesto máximo de palabras: incluye el subconjunto de mayor valor.
+    #[arg(long, value_name = "N")]
+    pub budget_words: Option<usize>,
+
+    /// Criterio que suma valor en --budget-words (repetible), ej. status=activo.
+    #[arg(long, value_name = "CAMPO=VALOR")]
+    pub prioritize: Vec<String>,
+
+    /// Tokenizador para el presupuesto: cl100k, words o chars.
+    #[arg(long, default_value = "cl100k")]
+    pub tokenizer: Tokenizer,
+
+    /// No expandir embeds `![[Doc]]` / `![[Doc#Sección]]` (dejar la sintaxis cruda).
+    #[arg(long)]
+    pub keep_embeds: bool,
+
+    /// Profundidad máxima de embeds anidados.
+    #[arg(long, default_value_t = DEFAULT_EMBED_DEPTH)]
+    pub embed_depth: usize,
+
+    /// Reprocesar solo los documentos que cambiaron desde el build anterior
+    /// y no reescribir el compilado si ninguno cambió.
+    #[arg(long)]
+    pub incremental: bool,
 }
 
 impl CompressCommand {
+    /// Ruta de salida: `--output` o `compiled.<formato>` en el directorio actual.
+    pub fn output_path(&self) -> PathBuf {
+        self.output
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(format!("compiled.{}", self.format)))
+    }
+
     pub fn run(&self, data_dir: &std::path::Path) -> OcResult<CompressResult> {
         use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
         
         use std::collections::HashSet;
 
-        let output = self
-            .output
-            .clone()
-            .unwrap_or_else(|| PathBuf::from(format!("compiled.{}", self.format)));
+        let output = self.output_path();
         let mut result = CompressResult::new(output.clone());
+        let criteria = self
+            .prioritize
+            .iter()
+            .map(|spec| Criterion::parse(spec))
+            .collect::<OcResult<Vec<_>>>()?;
 
         let options = ScanOptions::new();
         let files = get_all_md_files(data_dir, &options)?;
@@ -143,6 +506,15 @@ impl CompressCommand {
         // Ordenar archivos por nombre (que incluye la numeración)
         let mut sorted_files: Vec<_> = files.clone();
         sorted_files.sort();
+        let transcluder = (!self.keep_embeds).then(|| Transcluder::from_files(&files, self.embed_depth));
+        let variables = crate::core::variables::Variables::from_cwd();
+
+        let manifest_path = manifest_for_file(&output);
+        let mut manifest = self.incremental.then(|| BuildManifest::new(self.options_hash()));
+        let previous = manifest
+            .as_ref()
+            .and_then(|m| BuildManifest::previous(&manifest_path, &m.options));
+        let mut incremental = IncrementalStats::default();
 
         let mut compiled_content = String::new();
         let mut toc = String::from("# 📑 Tabla de Contenidos\n\n");
@@ -154,6 +526,8 @@ impl CompressCommand {
             chrono::Utc::now().format("%Y-%m-%d %H:%M:%S")
         ));
 
+        use crate::core::patterns::{RE_PRIORITY, RE_STATUS};
+        let mut entries = Vec::new();
         for file_path in &sorted_files {
             if let Ok(content) = read_file_content(file_path) {
                 // Filtrar drafts si se pidió
@@ -175,23 +549,33 @@ impl CompressCommand {
                     }
                 }
 
+                let rel = file_path.strip_prefix(data_dir).unwrap_or(file_path).display().to_string();
+                let content_hash = manifest.as_ref().map(|_| {
+                    let embedded = transcluder
+                        .as_ref()
+                        .map(|t| t.sources(&content, file_id))
+                        .unwrap_or_default();
+                    sources_hash(&content, &embedded)
+                });
+
+                // Incremental: mismas fuentes → reutilizar el documento procesado
+                let cached = previous
+                    .as_ref()
+                    .zip(content_hash.as_deref())
+                    .and_then(|(previous, hash)| previous.fresh(&rel, hash, data_dir))
+                    .and_then(|entry| Some((entry, serde_json::from_value::<Entry>(entry.data.clone()).ok()?)));
+                if let (Some((manifest_entry, entry)), Some(manifest)) = (cached, manifest.as_mut()) {
+                    manifest.docs.insert(rel, manifest_entry.clone());
+                    entries.push(entry);
+                    incremental.reused += 1;
+                    continue;
+                }
+
                 let title = title_regex
                     .captures(&content)
                     .map(|cap| cap[1].trim().to_string())
                     .unwrap_or_else(|| file_id.to_string());
 
-                // Extraer módulo
-                if let Some(cap) = module_regex.captures(&content) {
-                    modules.insert(cap[1].to_string());
-                }
-
-                // Agregar a TOC
-                let anchor = file_id.replace(['.', ' '], "-").to_lowercase();
-                toc.push_str(&format!("- [{}](#{})\n", title, anchor));
-
-                // Agregar separador y contenido
-                compiled_content.push_str(&format!("\n---\n\n## {} {{{}}}\n\n", title, file_id));
-
                 // Remover frontmatter del contenido antes de agregarlo
                 let content_body = if content.starts_with("---") {
                     if let Some(end) = content[3..].find("---") {
@@ -203,12 +587,77 @@ impl CompressCommand {
                     content.as_str()
                 };
 
-                compiled_content.push_str(content_body);
-                compiled_content.push_str("\n\n");
+                let body = match transcluder {
+                    Some(ref transcluder) => transcluder.expand(content_body, file_id, &mut result.embeds),
+                    None => content_body.to_string(),
+                };
+                let body = variables.resolve(&body, &mut result.unresolved_variables);
+
+                let entry = Entry {
+                    file_id: file_id.to_string(),
+                    title: variables.resolve(&title, &mut result.unresolved_variables),
+                    module: module_regex.captures(&content).map(|cap| cap[1].to_string()),
+                    body,
+                    words: content.split_whitespace().count(),
+                    status: RE_STATUS.captures(&content).map(|cap| cap[1].trim().to_string()),
+                    priority: RE_PRIORITY.captures(&content).map(|cap| cap[1].trim().to_string()),
+                    metadata: frontmatter_block(&content).unwrap_or_default().to_string(),
+                };
+                if let (Some(manifest), Some(content_hash)) = (manifest.as_mut(), content_hash) {
+                    manifest.docs.insert(
+                        rel,
+                        ManifestEntry {
+                            content_hash,
+                            output: String::new(),
+                            data: serde_json::to_value(&entry)?,
+                        },
+                    );
+                    incremental.rebuilt += 1;
+                }
+                entries.push(entry);
+            }
+        }
+
+        // Incremental sin cambios: el compilado anterior sigue valiendo
+        if let Some(ref manifest) = manifest {
+            let same_docs = previous
+                .as_ref()
+                .is_some_and(|p| p.docs.keys().eq(manifest.docs.keys()));
+            incremental.removed = previous
+                .as_ref()
+                .map_or(0, |p| p.docs.keys().filter(|k| !manifest.docs.contains_key(*k)).count());
+            result.up_to_date = same_docs && incremental.rebuilt == 0 && output.is_file() && !self.preview;
+        }
+
+        // Presupuesto de palabras: el subconjunto de mayor valor
+        if let Some(budget) = self.budget_words {
+            let (kept, report) = select_by_budget(entries, budget, &criteria);
+            entries = kept;
+            result.selection = Some(report);
+        }
+
+        // Presupuesto de tokens: descartar/recortar lo menos relevante
+        if let Some(max_tokens) = self.max_tokens {
+            // El header y la TOC también cuentan
+            let overhead = self.tokenizer.count(&format!("{}\n\n{}", toc, compiled_content));
+            let (kept, report) = apply_budget(entries, max_tokens, overhead, self.tokenizer);
+            entries = kept;
+            result.budget = Some(report);
+        }
 
-                result.documents_included += 1;
-                result.total_words += content.split_whitespace().count();
+        for entry in &entries {
+            if let Some(ref module) = entry.module {
+                modules.insert(module.clone());
             }
+
+            // Agregar a TOC
+            toc.push_str(&entry.toc_line());
+
+            // Agregar separador y contenido
+            compiled_content.push_str(&entry.chunk());
+
+            result.documents_included += 1;
+            result.total_words += entry.words;
         }
 
         // Construir documento final según formato
@@ -231,7 +680,7 @@ impl CompressCommand {
                 if self.preview {
                     println!("📋 Preview (primeras 50 líneas):\n{}", 
                              json.lines().take(50).collect::<Vec<_>>().join("\n"));
-                } else {
+                } else if !result.up_to_date {
                     std::fs::write(&output, &json)?;
                 }
             }
@@ -241,7 +690,7 @@ impl CompressCommand {
                 if self.preview {
                     println!("📋 Preview (primeras 50 líneas):\n{}", 
                              html.lines().take(50).collect::<Vec<_>>().join("\n"));
-                } else {
+                } else if !result.up_to_date {
                     std::fs::write(&output, &html)?;
                 }
             }
@@ -251,14 +700,19 @@ impl CompressCommand {
                     println!("📋 Preview (primeras 100 líneas):\n{}", 
                              final_content.lines().take(100).collect::<Vec<_>>().join("\n"));
                     println!("\n... ({} líneas más)", final_content.lines().count().saturating_sub(100));
-                } else {
+                } else if !result.up_to_date {
                     std::fs::write(&output, &final_content)?;
                 }
             }
         }
 
+        if let Some(manifest) = manifest.filter(|_| !self.preview) {
+            manifest.save(&manifest_path)?;
+            result.incremental = Some(incremental);
+        }
+
         // B5: Generar PDF si se solicitó
-        if self.pdf {
+        if self.pdf && !(result.up_to_date && output.with_extension("pdf").is_file()) {
             let pdf_output = output.with_extension("pdf");
             let status = std::process::Command::new("pandoc")
                 .args([
@@ -282,6 +736,15 @@ impl CompressCommand {
         Ok(result)
     }
 
+    /// Hash de las opciones que afectan el compilado: flags, versión y la
+    /// sección `variables` de la config.
+    fn options_hash(&self) -> String {
+        let config = crate::core::config::OcConfig::from_cwd().unwrap_or_default();
+        let flags = format!("{:?}", Self { output: None, ..self.clone() });
+        let variables = serde_json::to_string(&config.variables).unwrap_or_default();
+        crate::core::incremental::options_hash(&[env!("CARGO_PKG_VERSION"), &flags, &variables])
+    }
+
     /// L6.3: Genera HTML con wrapper y CSS básico.
     fn render_html(&self, toc: &str, content: &str, doc_count: usize) -> String {
         format!(
@@ -360,15 +823,167 @@ mod tests {
             skip_validation: false,
             include_yaml: false,
             preview: false,
+            max_tokens: None,
+            budget_words: None,
+            prioritize: Vec::new(),
+            tokenizer: Tokenizer::Cl100k,
+            keep_embeds: false,
+            embed_depth: DEFAULT_EMBED_DEPTH,
+            incremental: false,
         };
         let result = cmd.run(&temp_dir).unwrap();
         assert_eq!(result.output_path, PathBuf::from("/tmp/test_compress.md"));
     }
 
+    fn entry(id: &str, status: &str, body: &str) -> Entry {
+        Entry {
+            file_id: id.to_string(),
+            title: id.to_string(),
+            module: None,
+            body: body.to_string(),
+            words: body.split_whitespace().count(),
+            status: Some(status.to_string()),
+            priority: None,
+            metadata: format!("status: {}", status),
+        }
+    }
+
+    #[test]
+    fn test_split_sections() {
+        let body = "Intro\n## A\ntexto\n```\n## no\n```\n## B\nfin\n";
+        let sections = split_sections(body);
+        assert_eq!(sections.len(), 3);
+        assert!(sections[1].contains("## no"));
+    }
+
+    #[test]
+    fn test_apply_budget_prioritizes_status() {
+        let words = "palabra ".repeat(40);
+        let entries = vec![
+            entry("1.1 borrador", "draft", &words),
+            entry("1.2 revisado", "reviewed", &words),
+        ];
+        let one = entries[0].cost(Tokenizer::Words);
+        let (kept, report) = apply_budget(entries, one + 5, 0, Tokenizer::Words);
+
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].file_id, "1.2 revisado");
+        assert_eq!(report.excluded, vec!["1.1 borrador".to_string()]);
+        assert!(report.used_tokens <= one + 5);
+    }
+
+    #[test]
+    fn test_apply_budget_trims_sections() {
+        let body = format!("Intro breve\n## Detalle\n{}", "relleno ".repeat(200));
+        let (kept, report) = apply_budget(vec![entry("2 doc", "active", &body)], 40, 0, Tokenizer::Words);
+
+        assert_eq!(kept.len(), 1);
+        assert!(kept[0].body.contains("recortado: 1 secciones"));
+        assert_eq!(report.trimmed[0].sections_dropped, 1);
+        assert!(report.excluded.is_empty());
+    }
+
+    #[test]
+    fn test_run_with_max_tokens() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1. a.md"), "---\nstatus: active\n---\n\nCorto.\n").unwrap();
+        std::fs::write(
+            dir.path().join("2. b.md"),
+            format!("---\nstatus: deprecated\n---\n\n{}\n", "largo ".repeat(500)),
+        )
+        .unwrap();
+        let output = dir.path().join("out.md");
+        let cmd = CompressCommand::parse_from([
+            "compress",
+            "--max-tokens",
+            "200",
+            "--output",
+            output.to_str().unwrap(),
+        ]);
+        let result = cmd.run(dir.path()).unwrap();
+
+        let budget = result.budget.unwrap();
+        assert_eq!(result.documents_included, 1);
+        assert_eq!(budget.excluded, vec!["2. b".to_string()]);
+        let compiled = std::fs::read_to_string(&output).unwrap();
+        assert!(compiled.contains("Corto."));
+        let used = Tokenizer::Cl100k.count(&compiled);
+        assert!(used <= 200 && budget.used_tokens <= 200, "{} tokens", used);
+
+        // Header y TOC cuentan: con un token menos ya no entra el documento
+        let tight = (used - 1).to_string();
+        let cmd = CompressCommand::parse_from(["compress", "--max-tokens", &tight, "--output", output.to_str().unwrap()]);
+        let result = cmd.run(dir.path()).unwrap();
+        assert_eq!(result.documents_included, 0);
+        assert!(Tokenizer::Cl100k.count(&std::fs::read_to_string(&output).unwrap()) < used);
+    }
+
+    #[test]
+    fn test_select_by_budget() {
+        let words = |n: usize| "palabra ".repeat(n);
+        let entries = vec![
+            entry("1 grande", "active", &words(60)),
+            entry("2 medio", "draft", &words(50)),
+            entry("3 medio", "draft", &words(50)),
+            entry("4 chico", "deprecated", &words(10)),
+        ];
+        // Sin criterios gana el volumen de documentos: 2 + 3 (+ 4 no entra)
+        let (kept, report) = select_by_budget(entries.clone(), 100, &[]);
+        let ids: Vec<&str> = kept.iter().map(|e| e.file_id.as_str()).collect();
+        assert_eq!(ids, vec!["2 medio", "3 medio"]);
+        assert_eq!(report.used_words, 100);
+
+        let criteria = [Criterion::parse("status=activo").unwrap()];
+        let (kept, report) = select_by_budget(entries, 100, &criteria);
+        let ids: Vec<&str> = kept.iter().map(|e| e.file_id.as_str()).collect();
+        assert_eq!(ids, vec!["1 grande", "4 chico"]);
+        // Excluidos de mejor a peor valor/palabras
+        let excluded: Vec<&str> = report.excluded.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(excluded, vec!["2 medio", "3 medio"]);
+        assert!(Criterion::parse("status").is_err());
+    }
+
+    #[test]
+    fn test_run_incremental() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("Datos");
+        std::fs::create_dir(&data).unwrap();
+        std::fs::write(data.join("1 base.md"), "---\nid: \"1\"\n---\nTexto base.\n").unwrap();
+        std::fs::write(data.join("2 otro.md"), "---\nid: \"2\"\n---\nOtro.\n").unwrap();
+        let output = dir.path().join("out.md");
+        let cmd = CompressCommand::parse_from(["compress", "--incremental", "--output", output.to_str().unwrap()]);
+
+        assert_eq!(cmd.run(&data).unwrap().incremental.unwrap().rebuilt, 2);
+        let unchanged = cmd.run(&data).unwrap();
+        assert!(unchanged.up_to_date);
+        assert_eq!(unchanged.incremental.unwrap().reused, 2);
+        assert_eq!(unchanged.documents_included, 2);
+
+        std::fs::write(data.join("2 otro.md"), "---\nid: \"2\"\n---\nEditado.\n").unwrap();
+        let changed = cmd.run(&data).unwrap();
+        assert!(!changed.up_to_date);
+        assert_eq!(changed.incremental.unwrap().rebuilt, 1);
+        assert!(std::fs::read_to_string(&output).unwrap().contains("Editado."));
+    }
+
+    #[test]
+    fn test_run_expands_embeds() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1 base.md"), "---\nid: \"1\"\n---\nTexto base.\n").unwrap();
+        std::fs::write(dir.path().join("2 uso.md"), "---\nid: \"2\"\n---\nIntro\n![[1 base]]\n").unwrap();
+        let output = dir.path().join("out.md");
+        let cmd = CompressCommand::parse_from(["compress", "--output", output.to_str().unwrap()]);
+        let result = cmd.run(dir.path()).unwrap();
+
+        assert_eq!(result.embeds.expanded, 1);
+        let compiled = std::fs::read_to_string(output).unwrap();
+        assert!(compiled.contains("Intro\n<!-- embed: 1 base -->\nTexto base.\n<!-- /embed: 1 base -->"));
+    }
+
     #[test]
     fn test_compress_default_output() {
-        let temp_dir = std::env::temp_dir();
-        let cmd = CompressCommand {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cmd = CompressCommand {
             path: None,
             output: None,
             format: "pdf".to_string(),
@@ -381,8 +996,19 @@ mod tests {
             skip_validation: false,
             include_yaml: false,
             preview: false,
+            max_tokens: None,
+            budget_words: None,
+            prioritize: Vec::new(),
+            tokenizer: Tokenizer::Cl100k,
+            keep_embeds: false,
+            embed_depth: DEFAULT_EMBED_DEPTH,
+            incremental: false,
         };
-        let result = cmd.run(&temp_dir).unwrap();
+        assert_eq!(cmd.output_path(), PathBuf::from("compiled.pdf"));
+
+        // Sin escribir en el directorio actual
+        cmd.output = Some(dir.path().join("compiled.pdf"));
+        let result = cmd.run(dir.path()).unwrap();
         assert!(result.output_path.to_str().unwrap().ends_with(".pdf"));
     }
 }
@@ -396,6 +1022,43 @@ pub fn run(cmd: CompressCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
     println!("📦 Compilando documentación...");
     println!("📁 Salida: {}", result.output_path.display());
     println!("📊 {}", result.summary());
+    if let Some(ref incremental) = result.incremental {
+        println!("♻️  Incremental: {}", incremental.summary());
+        if result.up_to_date {
+            println!("✅ Sin cambios: se conserva {}", result.output_path.display());
+        }
+    }
+    crate::commands::export::print_embeds(&result.embeds);
+    crate::commands::export::print_unresolved_variables(&result.unresolved_variables);
+
+    if let Some(ref selection) = result.selection {
+        println!(
+            "🎒 Selección: {}/{} palabras (valor {})",
+            selection.used_words, selection.budget_words, selection.value
+        );
+        if !selection.excluded.is_empty() {
+            println!("  🚫 Excluidos por costo/beneficio ({}):", selection.excluded.len());
+            for doc in &selection.excluded {
+                println!("     - {} ({} palabras, valor {})", doc.id, doc.words, doc.value);
+            }
+        }
+    }
+
+    if let Some(ref budget) = result.budget {
+        println!(
+            "🎯 Presupuesto: {}/{} tokens ({})",
+            budget.used_tokens, budget.max_tokens, budget.tokenizer
+        );
+        for doc in &budget.trimmed {
+            println!("  ✂️  {} ({} secciones omitidas)", doc.id, doc.sections_dropped);
+        }
+        if !budget.excluded.is_empty() {
+            println!("  🚫 Fuera del presupuesto ({}):", budget.excluded.len());
+            for id in &budget.excluded {
+                println!("     - {}", id);
+            }
+        }
+    }
 
     Ok(())
 }
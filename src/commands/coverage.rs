@@ -1,11 +1,14 @@
 //! Comando coverage - Análisis de cobertura de contenido.
 //!
-//! Analiza la completitud de documentos por rangos de palabras.
+//! Analiza la completitud de documentos por rangos de palabras y, con
+//! `--requirements`, la trazabilidad requisito↔documento.
 
-use crate::errors::OcResult;
+use crate::errors::{OcError, OcResult};
 use clap::Parser;
-use serde::Serialize;
-use std::path::PathBuf;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // COVERAGE RANGES
@@ -76,11 +79,7 @@ impl CoverageResult {
             }
         }
 
-        self.avg_words = if self.total_documents > 0 {
-            self.total_words / self.total_documents
-        } else {
-            0
-        };
+        self.avg_words = self.total_words.checked_div(self.total_documents).unwrap_or(0);
     }
 
     pub fn coverage_percent(&self) -> f64 {
@@ -106,6 +105,197 @@ impl Default for CoverageResult {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// REQUIREMENTS TRACEABILITY
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Requisito de la matriz externa.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Requirement {
+    pub id: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Formatos YAML aceptados: lista de `{id, description}` o mapa `id: descripción`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RequirementsYaml {
+    List(Vec<Requirement>),
+    Map(BTreeMap<String, String>),
+}
+
+/// Carga requisitos desde CSV (`id,descripción`, cabecera opcional) o YAML.
+pub fn load_requirements(path: &Path) -> OcResult<Vec<Requirement>> {
+    let content = std::fs::read_to_string(path).map_err(|e| OcError::FileRead {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
+    let requirements = match ext.as_str() {
+        "yaml" | "yml" => match serde_yaml::from_str::<RequirementsYaml>(&content)? {
+            RequirementsYaml::List(list) => list,
+            RequirementsYaml::Map(map) => map
+                .into_iter()
+                .map(|(id, description)| Requirement { id, description })
+                .collect(),
+        },
+        "csv" => parse_requirements_csv(&content),
+        _ => {
+            return Err(OcError::InvalidArgument(format!(
+                "formato de requisitos no soportado: {} (use .csv, .yaml o .yml)",
+                path.display()
+            )))
+        }
+    };
+    Ok(requirements
+        .into_iter()
+        .map(|r| Requirement {
+            id: r.id.trim().to_string(),
+            description: r.description.trim().to_string(),
+        })
+        .filter(|r| !r.id.is_empty())
+        .collect())
+}
+
+/// CSV de dos columnas; omite la cabecera si la primera celda es `id`.
+fn parse_requirements_csv(content: &str) -> Vec<Requirement> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(split_csv_line)
+        .enumerate()
+        .filter(|(i, cells)| !(*i == 0 && cells[0].eq_ignore_ascii_case("id")))
+        .map(|(_, mut cells)| Requirement {
+            id: cells.remove(0),
+            description: cells.join(", "),
+        })
+        .collect()
+}
+
+/// Celdas de una línea CSV con comillas dobles (`""` escapa una comilla).
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut cells = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                cells.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => cells.push(String::new()),
+            _ => cells.last_mut().unwrap().push(c),
+        }
+    }
+    cells.into_iter().map(|c| c.trim().to_string()).collect()
+}
+
+/// Fila de la matriz: un requisito y los documentos que lo referencian.
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceRow {
+    pub id: String,
+    pub description: String,
+    pub documents: Vec<String>,
+}
+
+/// Matriz de trazabilidad requisito↔documento.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TraceabilityMatrix {
+    pub rows: Vec<TraceRow>,
+    /// Referencias del patrón que no están en la matriz → documentos.
+    pub unknown: BTreeMap<String, Vec<String>>,
+}
+
+impl TraceabilityMatrix {
+    /// Cruza los requisitos con las referencias de cada documento
+    /// (`documento → IDs`); la comparación ignora mayúsculas. Un ID repetido
+    /// en la matriz tiene los mismos documentos en todas sus filas.
+    pub fn build(requirements: &[Requirement], references: &BTreeMap<String, BTreeSet<String>>) -> Self {
+        let mut by_id: HashMap<String, Vec<String>> = HashMap::new();
+        for (doc, ids) in references {
+            for id in ids {
+                by_id.entry(id.to_uppercase()).or_default().push(doc.clone());
+            }
+        }
+        let rows = requirements
+            .iter()
+            .map(|r| TraceRow {
+                id: r.id.clone(),
+                description: r.description.clone(),
+                documents: by_id.get(&r.id.to_uppercase()).cloned().unwrap_or_default(),
+            })
+            .collect();
+        let known: HashSet<String> = requirements.iter().map(|r| r.id.to_uppercase()).collect();
+        Self {
+            rows,
+            unknown: by_id.into_iter().filter(|(id, _)| !known.contains(id)).collect(),
+        }
+    }
+
+    pub fn covered(&self) -> usize {
+        self.rows.iter().filter(|r| !r.documents.is_empty()).count()
+    }
+
+    pub fn uncovered(&self) -> impl Iterator<Item = &TraceRow> {
+        self.rows.iter().filter(|r| r.documents.is_empty())
+    }
+
+    pub fn coverage_percent(&self) -> f64 {
+        if self.rows.is_empty() {
+            return 100.0;
+        }
+        self.covered() as f64 / self.rows.len() as f64 * 100.0
+    }
+
+    /// CSV `requirement,description,documents` (documentos separados por `;`).
+    pub fn to_csv(&self) -> String {
+        let quote = |s: &str| {
+            if s.contains([',', '"', '\n']) {
+                format!("\"{}\"", s.replace('"', "\"\""))
+            } else {
+                s.to_string()
+            }
+        };
+        let mut out = String::from("requirement,description,documents\n");
+        for row in &self.rows {
+            out.push_str(&format!(
+                "{},{},{}\n",
+                quote(&row.id),
+                quote(&row.description),
+                quote(&row.documents.join("; "))
+            ));
+        }
+        out
+    }
+}
+
+/// Referencias de un documento: coincidencias del patrón en el contenido
+/// más los tags del frontmatter que son requisitos conocidos o siguen el patrón.
+fn document_references(content: &str, pattern: &Regex, known: &BTreeSet<String>) -> BTreeSet<String> {
+    let mut refs: BTreeSet<String> = pattern
+        .find_iter(content)
+        .map(|m| m.as_str().to_uppercase())
+        .collect();
+    let tags: Vec<String> = match crate::types::lifetimes::split_frontmatter(content)
+        .and_then(|split| serde_yaml::from_str::<serde_yaml::Value>(split.metadata).ok())
+        .and_then(|yaml| yaml.get("tags").cloned())
+    {
+        Some(serde_yaml::Value::Sequence(items)) => {
+            items.iter().filter_map(|v| v.as_str()).map(str::to_string).collect()
+        }
+        Some(serde_yaml::Value::String(s)) => s.split(',').map(str::to_string).collect(),
+        _ => Vec::new(),
+    };
+    refs.extend(
+        tags.iter()
+            .map(|t| t.trim().to_uppercase())
+            .filter(|t| known.contains(t) || pattern.is_match(t)),
+    );
+    refs
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // COVERAGE COMMAND
 // ═══════════════════════════════════════════════════════════════════════════
@@ -125,6 +315,18 @@ pub struct CoverageCommand {
     /// Output JSON.
     #[arg(long)]
     pub json: bool,
+
+    /// Matriz de requisitos (CSV `id,descripción` o YAML) a trazar contra los documentos.
+    #[arg(long, value_name = "FILE")]
+    pub requirements: Option<PathBuf>,
+
+    /// Patrón de referencia a requisitos en el contenido (además de los tags).
+    #[arg(long, value_name = "REGEX", default_value = r"\bREQ-\d+\b")]
+    pub pattern: String,
+
+    /// Exportar la matriz de trazabilidad a CSV (requiere --requirements).
+    #[arg(short, long, value_name = "FILE", requires = "requirements")]
+    pub output: Option<PathBuf>,
 }
 
 impl CoverageCommand {
@@ -145,6 +347,33 @@ impl CoverageCommand {
 
         Ok(result)
     }
+
+    /// Trazabilidad de los requisitos de `--requirements` contra el proyecto.
+    pub fn run_requirements(&self, data_dir: &Path, requirements: &Path) -> OcResult<TraceabilityMatrix> {
+        use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+
+        let requirements = load_requirements(requirements)?;
+        let pattern = Regex::new(&self.pattern)
+            .map_err(|e| OcError::InvalidArgument(format!("--pattern '{}': {}", self.pattern, e)))?;
+
+        let known: BTreeSet<String> = requirements.iter().map(|r| r.id.to_uppercase()).collect();
+        let mut references = BTreeMap::new();
+        for file_path in get_all_md_files(data_dir, &ScanOptions::new())? {
+            if let Ok(content) = read_file_content(&file_path) {
+                // Ruta relativa sin `.md`: dos documentos con el mismo nombre
+                // en distintas carpetas no se pisan
+                let name = file_path
+                    .strip_prefix(data_dir)
+                    .unwrap_or(&file_path)
+                    .with_extension("")
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                references.insert(name, document_references(&content, &pattern, &known));
+            }
+        }
+
+        Ok(TraceabilityMatrix::build(&requirements, &references))
+    }
 }
 
 #[cfg(test)]
@@ -186,12 +415,58 @@ mod tests {
 
         assert_eq!(result.coverage_percent(), 50.0);
     }
+
+    #[test]
+    fn test_load_requirements_csv_and_yaml() {
+        let dir = tempfile::tempdir().unwrap();
+        let csv = dir.path().join("reqs.csv");
+        std::fs::write(&csv, "id,description\nREQ-1,Login\nREQ-2,\"Pagos, reembolsos\"\n\n").unwrap();
+        let reqs = load_requirements(&csv).unwrap();
+        assert_eq!(reqs.len(), 2);
+        assert_eq!(reqs[1].description, "Pagos, reembolsos");
+
+        let yaml = dir.path().join("reqs.yaml");
+        std::fs::write(&yaml, "REQ-1: Login\nREQ-2: Pagos\n").unwrap();
+        assert_eq!(load_requirements(&yaml).unwrap()[0].id, "REQ-1");
+        std::fs::write(&yaml, "- id: REQ-9\n  description: Export\n").unwrap();
+        assert_eq!(load_requirements(&yaml).unwrap()[0].description, "Export");
+
+        assert!(load_requirements(&dir.path().join("reqs.txt")).is_err());
+    }
+
+    #[test]
+    fn test_traceability_matrix() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(root.join("1 login.md"), "---\nid: \"1\"\ntags: [auth, REQ-3]\n---\nCubre REQ-1 y req-1.\n").unwrap();
+        std::fs::write(root.join("2 pagos.md"), "---\nid: \"2\"\n---\nVer REQ-1, REQ-99.\n").unwrap();
+        let reqs = root.join("reqs.csv");
+        std::fs::create_dir_all(root.join("a")).unwrap();
+        std::fs::create_dir_all(root.join("b")).unwrap();
+        std::fs::write(root.join("a/notas.md"), "REQ-4\n").unwrap();
+        std::fs::write(root.join("b/notas.md"), "REQ-4\n").unwrap();
+        std::fs::write(&reqs, "REQ-1,Login\nREQ-2,Pagos\nREQ-3,Auditoría\nREQ-4,Notas\nREQ-1,Login (repetido)\n").unwrap();
+
+        let cmd = CoverageCommand::parse_from(["coverage", "--requirements", reqs.to_str().unwrap()]);
+        let matrix = cmd.run_requirements(root, &reqs).unwrap();
+        assert_eq!(matrix.rows[0].documents, vec!["1 login", "2 pagos"]);
+        assert_eq!(matrix.rows[2].documents, vec!["1 login"]);
+        assert_eq!(matrix.uncovered().map(|r| r.id.as_str()).collect::<Vec<_>>(), vec!["REQ-2"]);
+        assert_eq!(matrix.rows[3].documents, vec!["a/notas", "b/notas"]);
+        assert_eq!(matrix.rows[4].documents, matrix.rows[0].documents);
+        assert_eq!(matrix.covered(), 4);
+        assert_eq!(matrix.unknown["REQ-99"], vec!["2 pagos"]);
+        assert!(matrix.to_csv().contains("REQ-1,Login,1 login; 2 pagos\n"));
+    }
 }
 
 /// Función run para CLI.
 #[cfg(feature = "cli")]
 pub fn run(cmd: CoverageCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
     let data_dir = std::path::Path::new(&cli.data_dir);
+    if let Some(requirements) = &cmd.requirements {
+        return run_requirements(&cmd, data_dir, requirements);
+    }
     let result = cmd.run(data_dir)?;
 
     if cmd.json {
@@ -216,3 +491,44 @@ pub fn run(cmd: CoverageCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Salida de `coverage --requirements`.
+#[cfg(feature = "cli")]
+fn run_requirements(cmd: &CoverageCommand, data_dir: &Path, requirements: &Path) -> anyhow::Result<()> {
+    let matrix = cmd.run_requirements(data_dir, requirements)?;
+
+    if let Some(output) = &cmd.output {
+        std::fs::write(output, matrix.to_csv())?;
+        eprintln!("📄 Matriz guardada: {}", output.display());
+    }
+
+    if cmd.json {
+        println!("{}", serde_json::to_string_pretty(&matrix)?);
+        return Ok(());
+    }
+
+    println!("🔗 Trazabilidad de requisitos\n");
+    for row in &matrix.rows {
+        let status = if row.documents.is_empty() { "❌" } else { "✅" };
+        println!("{} {:12} {}", status, row.id, row.description);
+        if !row.documents.is_empty() {
+            println!("   ↳ {}", row.documents.join(", "));
+        }
+    }
+
+    if !matrix.unknown.is_empty() {
+        println!("\n⚠️  Referencias a requisitos fuera de la matriz:");
+        for (id, docs) in &matrix.unknown {
+            println!("   {} en {}", id, docs.join(", "));
+        }
+    }
+
+    println!(
+        "\n📈 Requisitos cubiertos: {}/{} ({:.1}%)",
+        matrix.covered(),
+        matrix.rows.len(),
+        matrix.coverage_percent()
+    );
+
+    Ok(())
+}
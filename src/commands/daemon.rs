@@ -0,0 +1,560 @@
+//! Comando daemon - Ejecución programada.
+//!
+//! `daemon --every 30m --run "ci --profile fast"` repite en cada ciclo los
+//! comandos de `--run` (subprocesos del mismo binario) y el mantenimiento
+//! del proyecto: refresca los índices que ya existen (`_index/anchors.json`,
+//! embeddings y full-text), guarda un snapshot en `snapshots/daemon-<fecha>`
+//! si el contenido cambió y agrega una medición al historial de salud.
+//! `--install` genera un servicio systemd de usuario; `--detach` lo deja
+//! corriendo en background con log en `.oc_diagdoc/daemon.log`.
+
+use crate::commands::snapshot::{snapshots_dir, write_snapshot};
+use crate::errors::{OcError, OcResult};
+use crate::types::ContentHash;
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Estado del daemon dentro de `.oc_diagdoc/`.
+pub const DAEMON_STATE_FILE: &str = "daemon_state.json";
+
+/// Prefijo de los snapshots del daemon (los únicos que poda `--keep-snapshots`).
+pub const SNAPSHOT_PREFIX: &str = "daemon-";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DAEMON TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Estado persistido entre ciclos (y entre reinicios del servicio).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DaemonState {
+    pub cycles: usize,
+    pub last_cycle: Option<String>,
+    pub last_snapshot: Option<String>,
+    /// Hash del contenido en el último snapshot.
+    pub snapshot_hash: Option<String>,
+}
+
+impl DaemonState {
+    /// Estado guardado; sin archivo o ilegible, uno nuevo.
+    pub fn load(config_dir: &Path) -> Self {
+        std::fs::read_to_string(config_dir.join(DAEMON_STATE_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, config_dir: &Path) -> OcResult<()> {
+        let path = config_dir.join(DAEMON_STATE_FILE);
+        std::fs::create_dir_all(config_dir)?;
+        std::fs::write(&path, serde_json::to_string_pretty(self)?).map_err(|e| OcError::FileWrite { path, source: e })
+    }
+}
+
+/// Resultado de un comando de `--run`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskOutcome {
+    pub command: String,
+    pub success: bool,
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+}
+
+/// Resultado de un ciclo.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CycleReport {
+    pub cycle: usize,
+    pub timestamp: String,
+    pub tasks: Vec<TaskOutcome>,
+    /// Índices refrescados (`anchors`, `embeddings`, `fulltext`).
+    pub indexes: Vec<String>,
+    pub snapshot: Option<PathBuf>,
+    /// Snapshots viejos del daemon borrados.
+    pub pruned: usize,
+    /// Salud global medida (si se actualizó el historial).
+    pub health: Option<f64>,
+    /// Pasos que fallaron; el daemon sigue con el próximo ciclo.
+    pub errors: Vec<String>,
+}
+
+impl CycleReport {
+    pub fn failed_tasks(&self) -> usize {
+        self.tasks.iter().filter(|t| !t.success).count()
+    }
+}
+
+/// Intervalo legible: `45s`, `30m`, `1h30m`, `1d` (un número solo son minutos).
+pub fn parse_interval(text: &str) -> OcResult<Duration> {
+    let invalid = || OcError::InvalidArgument(format!("Intervalo inválido: '{}' (ej: 45s, 30m, 1h30m, 1d)", text));
+    let text = text.trim();
+    if let Ok(minutes) = text.parse::<u64>() {
+        return match minutes.checked_mul(60) {
+            Some(seconds) if seconds > 0 => Ok(Duration::from_secs(seconds)),
+            _ => Err(invalid()),
+        };
+    }
+    let mut total = 0u64;
+    let mut number = String::new();
+    for ch in text.chars() {
+        if ch.is_ascii_digit() {
+            number.push(ch);
+            continue;
+        }
+        let unit = match ch {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            _ => return Err(invalid()),
+        };
+        let value = number.parse::<u64>().map_err(|_| invalid())?;
+        total = value.checked_mul(unit).and_then(|v| total.checked_add(v)).ok_or_else(invalid)?;
+        number.clear();
+    }
+    if !number.is_empty() || total == 0 {
+        return Err(invalid());
+    }
+    Ok(Duration::from_secs(total))
+}
+
+/// Hash del contenido del data_dir (rutas y contenido de cada documento).
+pub fn content_hash(data_dir: &Path) -> OcResult<String> {
+    use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+
+    let mut files = get_all_md_files(data_dir, &ScanOptions::new())?;
+    files.sort();
+    let mut joined = String::new();
+    for file in files {
+        let relative = file.strip_prefix(data_dir).unwrap_or(&file);
+        joined.push_str(&relative.to_string_lossy());
+        joined.push('\0');
+        joined.push_str(ContentHash::compute(&read_file_content(&file)?).full());
+        joined.push('\n');
+    }
+    Ok(ContentHash::compute(&joined).full().to_string())
+}
+
+/// Argumento para `ExecStart` de systemd: comillas si hace falta y `%`/`$`
+/// escapados.
+fn systemd_quote(arg: &str) -> String {
+    let escaped = arg.replace('%', "%%").replace('$', "$$");
+    if !escaped.is_empty() && !escaped.contains(|c: char| c.is_whitespace() || c == '"' || c == '\\' || c == '\'') {
+        return escaped;
+    }
+    format!("\"{}\"", escaped.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DAEMON COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Comando daemon.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "daemon", about = "Ejecución programada (índices, snapshots, historial)")]
+pub struct DaemonCommand {
+    /// Intervalo entre ciclos (45s, 30m, 1h30m, 1d).
+    #[arg(long, default_value = "30m")]
+    pub every: String,
+
+    /// Comando de oc_diagdoc a ejecutar en cada ciclo (repetible).
+    #[arg(long = "run", value_name = "CMD")]
+    pub tasks: Vec<String>,
+
+    /// Intervalo mínimo entre snapshots.
+    #[arg(long, default_value = "24h")]
+    pub snapshot_every: String,
+
+    /// Snapshots del daemon a conservar (0 = todos).
+    #[arg(long, default_value_t = 7)]
+    pub keep_snapshots: usize,
+
+    /// No refrescar índices.
+    #[arg(long)]
+    pub no_index: bool,
+
+    /// No guardar snapshots.
+    #[arg(long)]
+    pub no_snapshot: bool,
+
+    /// No registrar el historial de salud.
+    #[arg(long)]
+    pub no_history: bool,
+
+    /// Un solo ciclo y salir (para cron).
+    #[arg(long)]
+    pub once: bool,
+
+    /// Seguir en background (log en `.oc_diagdoc/daemon.log`).
+    #[arg(long)]
+    pub detach: bool,
+
+    /// Generar un servicio systemd de usuario en vez de ejecutar.
+    #[arg(long)]
+    pub install: bool,
+
+    /// Directorio del servicio (default: ~/.config/systemd/user).
+    #[arg(long)]
+    pub unit_dir: Option<PathBuf>,
+
+    /// Ruta del proyecto.
+    #[arg(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// Output JSON (un reporte por ciclo).
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl DaemonCommand {
+    pub fn interval(&self) -> OcResult<Duration> {
+        parse_interval(&self.every)
+    }
+
+    /// Argumentos para relanzar el mismo daemon (servicio o `--detach`).
+    pub fn exec_args(&self, data_dir: &Path) -> Vec<String> {
+        let mut args = vec![
+            "--data-dir".to_string(),
+            data_dir.display().to_string(),
+            "daemon".to_string(),
+            "--every".to_string(),
+            self.every.clone(),
+        ];
+        for task in &self.tasks {
+            args.extend(["--run".to_string(), task.clone()]);
+        }
+        args.extend(["--snapshot-every".to_string(), self.snapshot_every.clone()]);
+        args.extend(["--keep-snapshots".to_string(), self.keep_snapshots.to_string()]);
+        for (flag, on) in [
+            ("--no-index", self.no_index),
+            ("--no-snapshot", self.no_snapshot),
+            ("--no-history", self.no_history),
+            ("--json", self.json),
+        ] {
+            if on {
+                args.push(flag.to_string());
+            }
+        }
+        args
+    }
+
+    /// Servicio systemd de usuario que corre el daemon en `workdir`.
+    pub fn systemd_unit(&self, exe: &Path, workdir: &Path, data_dir: &Path) -> String {
+        let exec: Vec<String> = std::iter::once(exe.display().to_string())
+            .chain(self.exec_args(data_dir))
+            .map(|arg| systemd_quote(&arg))
+            .collect();
+        format!(
+            "[Unit]\nDescription=oc_diagdoc daemon ({project})\n\n\
+             [Service]\nType=simple\nWorkingDirectory={workdir}\nExecStart={exec}\n\
+             Restart=on-failure\nRestartSec=60\n\n\
+             [Install]\nWantedBy=default.target\n",
+            project = workdir.file_name().and_then(|n| n.to_str()).unwrap_or("proyecto"),
+            workdir = systemd_quote(&workdir.display().to_string()),
+            exec = exec.join(" "),
+        )
+    }
+
+    /// Nombre del servicio para el proyecto en `workdir`.
+    pub fn unit_name(workdir: &Path) -> String {
+        let project: String = workdir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("proyecto")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+            .collect();
+        format!("oc_diagdoc-{}.service", project)
+    }
+
+    /// Ejecuta un comando de `--run` con el binario actual.
+    pub fn run_task(task: &str, data_dir: &Path) -> TaskOutcome {
+        let start = Instant::now();
+        let status = std::env::current_exe().and_then(|exe| {
+            std::process::Command::new(exe)
+                .arg("--data-dir")
+                .arg(data_dir)
+                .args(crate::commands::repl::split_args(task))
+                .status()
+        });
+        TaskOutcome {
+            command: task.to_string(),
+            success: status.as_ref().is_ok_and(|s| s.success()),
+            exit_code: status.ok().and_then(|s| s.code()),
+            duration_ms: start.elapsed().as_millis() as u64,
+        }
+    }
+
+    /// Un ciclo completo: comandos de `--run` y mantenimiento.
+    pub fn cycle(&self, data_dir: &Path, config_dir: &Path, state: &mut DaemonState) -> OcResult<CycleReport> {
+        let tasks: Vec<TaskOutcome> = self.tasks.iter().map(|task| Self::run_task(task, data_dir)).collect();
+        let mut report = self.maintain(data_dir, config_dir, state, Utc::now())?;
+        report.tasks = tasks;
+        Ok(report)
+    }
+
+    /// Mantenimiento del ciclo: índices, snapshot e historial. Los fallos de
+    /// cada paso quedan en `errors` sin cortar el ciclo.
+    pub fn maintain(
+        &self,
+        data_dir: &Path,
+        config_dir: &Path,
+        state: &mut DaemonState,
+        now: DateTime<Utc>,
+    ) -> OcResult<CycleReport> {
+        let timestamp = now.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        state.cycles += 1;
+        state.last_cycle = Some(timestamp.clone());
+        let mut report = CycleReport {
+            cycle: state.cycles,
+            timestamp,
+            ..Default::default()
+        };
+
+        if !self.no_index {
+            self.refresh_indexes(data_dir, &mut report);
+        }
+        if !self.no_snapshot {
+            if let Err(e) = self.snapshot(data_dir, state, now, &mut report) {
+                report.errors.push(format!("snapshot: {}", e));
+            }
+        }
+        if !self.no_history {
+            match Self::record_health(data_dir, config_dir) {
+                Ok(health) => report.health = Some(health),
+                Err(e) => report.errors.push(format!("historial: {}", e)),
+            }
+        }
+
+        state.save(config_dir)?;
+        Ok(report)
+    }
+
+    /// Regenera solo los índices que el proyecto ya usa.
+    fn refresh_indexes(&self, data_dir: &Path, report: &mut CycleReport) {
+        use crate::commands::gen::GenCommand;
+        use crate::commands::index::IndexCommand;
+        use crate::core::anchors::ANCHOR_INDEX;
+        use crate::core::config::OcConfig;
+        use crate::core::fulltext::FULLTEXT_INDEX;
+
+        if data_dir.join(ANCHOR_INDEX).is_file() {
+            match GenCommand::parse_from(["gen", "anchors", "--index"]).run(data_dir) {
+                Ok(_) => report.indexes.push("anchors".to_string()),
+                Err(e) => report.errors.push(format!("anchors: {}", e)),
+            }
+        }
+        if OcConfig::from_cwd().unwrap_or_default().embeddings.index_path.is_file() {
+            match IndexCommand::parse_from(["index", "build", "--embeddings"]).run(data_dir) {
+                Ok(_) => report.indexes.push("embeddings".to_string()),
+                Err(e) => report.errors.push(format!("embeddings: {}", e)),
+            }
+        }
+        if data_dir.join(FULLTEXT_INDEX).is_file() {
+            match IndexCommand::parse_from(["index", "build", "--fulltext"]).run(data_dir) {
+                Ok(_) => report.indexes.push("fulltext".to_string()),
+                Err(e) => report.errors.push(format!("fulltext: {}", e)),
+            }
+        }
+    }
+
+    /// Snapshot si pasó `--snapshot-every` desde el anterior y el contenido
+    /// cambió; después poda los más viejos.
+    fn snapshot(&self, data_dir: &Path, state: &mut DaemonState, now: DateTime<Utc>, report: &mut CycleReport) -> OcResult<()> {
+        let every = parse_interval(&self.snapshot_every)?;
+        let due = state
+            .last_snapshot
+            .as_deref()
+            .and_then(|last| DateTime::parse_from_rfc3339(last).ok())
+            .map_or(true, |last| {
+                now.signed_duration_since(last).to_std().unwrap_or_default() >= every
+            });
+        if !due {
+            return Ok(());
+        }
+        let hash = content_hash(data_dir)?;
+        if state.snapshot_hash.as_deref() == Some(hash.as_str()) {
+            return Ok(());
+        }
+
+        let root = snapshots_dir(data_dir);
+        let name = format!("{}{}", SNAPSHOT_PREFIX, now.format("%Y%m%d-%H%M%S"));
+        let dest = root.join(&name);
+        write_snapshot(data_dir, &dest, &name)?;
+        state.last_snapshot = Some(now.to_rfc3339());
+        state.snapshot_hash = Some(hash);
+        report.snapshot = Some(dest);
+
+        if self.keep_snapshots > 0 {
+            let mut existing: Vec<PathBuf> = std::fs::read_dir(&root)?
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|p| p.is_dir())
+                .filter(|p| p.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.starts_with(SNAPSHOT_PREFIX)))
+                .collect();
+            existing.sort();
+            let excess = existing.len().saturating_sub(self.keep_snapshots);
+            for old in &existing[..excess] {
+                std::fs::remove_dir_all(old)?;
+                report.pruned += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Agrega la salud actual al historial (el mismo que usa `dashboard`).
+    fn record_health(data_dir: &Path, config_dir: &Path) -> OcResult<f64> {
+        use crate::commands::stats::StatsCommand;
+        use crate::core::health_history::{append, HealthSnapshot, HEALTH_HISTORY_FILE};
+
+        let (stats, modules) = StatsCommand::parse_from(["stats"]).run(data_dir)?;
+        let snapshot = HealthSnapshot::new(
+            stats.health_percent(),
+            modules.iter().map(|m| (m.id.clone(), m.health_score)).collect(),
+        );
+        append(&config_dir.join(HEALTH_HISTORY_FILE), &snapshot)?;
+        Ok(snapshot.overall)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_interval_and_unit() {
+        assert_eq!(parse_interval("30m").unwrap(), Duration::from_secs(1800));
+        assert_eq!(parse_interval("1h30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse_interval("15").unwrap(), Duration::from_secs(900));
+        assert!(parse_interval("0s").is_err());
+        assert!(parse_interval("0").is_err());
+        assert!(parse_interval(&u64::MAX.to_string()).is_err());
+        assert!(parse_interval("999999999999999999d").is_err());
+        assert!(parse_interval("5x").is_err());
+        assert!(parse_interval("10m5").is_err());
+
+        let cmd = DaemonCommand::parse_from(["daemon", "--every", "1h", "--run", "ci --profile fast", "--no-index"]);
+        let unit = cmd.systemd_unit(Path::new("/usr/bin/oc_diagdoc"), Path::new("/srv/mi wiki"), Path::new("Datos"));
+        assert!(unit.contains("WorkingDirectory=\"/srv/mi wiki\""), "{}", unit);
+        assert!(unit.contains(
+            "ExecStart=/usr/bin/oc_diagdoc --data-dir Datos daemon --every 1h --run \"ci --profile fast\" \
+             --snapshot-every 24h --keep-snapshots 7 --no-index\n"
+        ));
+        assert_eq!(DaemonCommand::unit_name(Path::new("/srv/mi wiki")), "oc_diagdoc-mi-wiki.service");
+    }
+
+    #[test]
+    fn test_maintain_snapshots_and_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let data_dir = dir.path().join("Datos");
+        let config_dir = dir.path().join(".oc_diagdoc");
+        std::fs::create_dir_all(data_dir.join("1. Modulo")).unwrap();
+        let doc = data_dir.join("1. Modulo").join("1. Modulo.md");
+        std::fs::write(&doc, "---\nid: \"1\"\ntitle: \"Modulo\"\n---\n# Modulo\n").unwrap();
+
+        let cmd = DaemonCommand::parse_from(["daemon", "--snapshot-every", "1h", "--keep-snapshots", "2"]);
+        let mut state = DaemonState::default();
+        let start = DateTime::parse_from_rfc3339("2026-03-01T10:00:00Z").unwrap().with_timezone(&Utc);
+        let at = |minutes: i64| start + chrono::Duration::minutes(minutes);
+
+        let first = cmd.maintain(&data_dir, &config_dir, &mut state, at(0)).unwrap();
+        assert!(first.errors.is_empty(), "{:?}", first.errors);
+        let snapshot = first.snapshot.unwrap();
+        assert!(snapshot.ends_with("snapshots/daemon-20260301-100000"));
+        assert!(snapshot.join("1. Modulo").join("1. Modulo.md").is_file());
+        assert!(first.health.is_some());
+
+        // Antes de --snapshot-every, o sin cambios, no hay snapshot
+        std::fs::write(&doc, "---\nid: \"1\"\ntitle: \"Modulo\"\n---\n# Modulo\n\nMás texto.\n").unwrap();
+        assert!(cmd.maintain(&data_dir, &config_dir, &mut state, at(30)).unwrap().snapshot.is_none());
+        assert!(cmd.maintain(&data_dir, &config_dir, &mut state, at(60)).unwrap().snapshot.is_some());
+        assert!(cmd.maintain(&data_dir, &config_dir, &mut state, at(120)).unwrap().snapshot.is_none());
+
+        std::fs::write(&doc, "---\nid: \"1\"\ntitle: \"Modulo\"\n---\n# Modulo v3\n").unwrap();
+        let third = cmd.maintain(&data_dir, &config_dir, &mut state, at(180)).unwrap();
+        assert_eq!(third.pruned, 1);
+        assert!(!snapshot.exists());
+
+        let saved = DaemonState::load(&config_dir);
+        assert_eq!(saved, state);
+        assert_eq!(saved.cycles, 5);
+        let history = crate::core::health_history::load(&config_dir.join("health_history.jsonl")).unwrap();
+        assert_eq!(history.len(), 5);
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: DaemonCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    use crate::core::config::CONFIG_DIR;
+
+    let default_dir = PathBuf::from(&cli.data_dir);
+    let data_dir = cmd.path.clone().unwrap_or(default_dir);
+    let interval = cmd.interval()?;
+    parse_interval(&cmd.snapshot_every)?;
+    let config_dir = PathBuf::from(CONFIG_DIR);
+
+    if cmd.install {
+        let workdir = std::env::current_dir()?;
+        let data_dir = if data_dir.is_absolute() { data_dir } else { workdir.join(data_dir) };
+        let unit = cmd.systemd_unit(&std::env::current_exe()?, &workdir, &data_dir);
+        let unit_dir = match &cmd.unit_dir {
+            Some(dir) => dir.clone(),
+            None => PathBuf::from(std::env::var("HOME")?).join(".config/systemd/user"),
+        };
+        let name = DaemonCommand::unit_name(&workdir);
+        std::fs::create_dir_all(&unit_dir)?;
+        std::fs::write(unit_dir.join(&name), unit)?;
+        println!("⚙️  Servicio generado: {}", unit_dir.join(&name).display());
+        println!("   Activar con: systemctl --user daemon-reload && systemctl --user enable --now {}", name);
+        return Ok(());
+    }
+
+    if cmd.detach {
+        std::fs::create_dir_all(&config_dir)?;
+        let log = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(config_dir.join("daemon.log"))?;
+        let child = std::process::Command::new(std::env::current_exe()?)
+            .args(cmd.exec_args(&data_dir))
+            .stdin(std::process::Stdio::null())
+            .stdout(log.try_clone()?)
+            .stderr(log)
+            .spawn()?;
+        std::fs::write(config_dir.join("daemon.pid"), child.id().to_string())?;
+        println!("🌙 Daemon en background (pid {}), log en {}", child.id(), config_dir.join("daemon.log").display());
+        return Ok(());
+    }
+
+    let mut state = DaemonState::load(&config_dir);
+    if !cli.quiet && !cmd.json {
+        println!("🌙 Daemon cada {} sobre {}", cmd.every, data_dir.display());
+    }
+    loop {
+        let report = cmd.cycle(&data_dir, &config_dir, &mut state)?;
+        if cmd.json {
+            println!("{}", serde_json::to_string(&report)?);
+        } else {
+            println!(
+                "[{}] ciclo {}: {} comandos ({} con error), índices: {}, snapshot: {}, salud: {}",
+                report.timestamp,
+                report.cycle,
+                report.tasks.len(),
+                report.failed_tasks(),
+                if report.indexes.is_empty() { "-".to_string() } else { report.indexes.join(", ") },
+                report.snapshot.as_ref().map_or("-".to_string(), |p| p.display().to_string()),
+                report.health.map_or("-".to_string(), |h| format!("{:.1}%", h)),
+            );
+            for error in &report.errors {
+                eprintln!("  ⚠ {}", error);
+            }
+        }
+        if cmd.once {
+            break;
+        }
+        std::thread::sleep(interval);
+    }
+
+    Ok(())
+}
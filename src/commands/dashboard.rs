@@ -1,9 +1,14 @@
 //! Dashboard TUI - Interfaz interactiva para oc_diagdoc
 //!
 //! ADD#1: Dashboard con ratatui para visualización de issues
+//!
+//! La vista de módulos (`m`) ordena los módulos por salud con la tendencia
+//! del historial (`.oc_diagdoc/health_history.jsonl`); `Enter` filtra los
+//! issues del módulo seleccionado.
 
-use crate::commands::verify::{VerificationResult, VerificationPhase};
-use crate::errors::OcResult;
+use crate::commands::stats::ModuleStats;
+use crate::commands::verify::{issue_module, VerificationResult, VerificationPhase};
+use crate::core::health_history::{module_trend, HealthSnapshot, TREND_POINTS};
 use clap::Parser;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
@@ -95,6 +100,68 @@ impl Issue {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// RANKING DE MÓDULOS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Fila del ranking de módulos.
+#[derive(Debug, Clone)]
+pub struct ModuleHealth {
+    pub id: String,
+    pub score: f64,
+    pub documents: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    /// Salud de las últimas mediciones, la más reciente al final.
+    pub trend: Vec<f64>,
+}
+
+impl ModuleHealth {
+    /// ¿El issue es de este módulo? (mismo criterio que `verify --group-by module`)
+    pub fn owns(&self, issue: &Issue) -> bool {
+        issue_module(&issue.message) == format!("Módulo {}", self.id)
+    }
+}
+
+/// Módulos ordenados por salud, el peor primero (a igual salud, el de más
+/// errores), con sus issues y la tendencia del historial.
+pub fn module_ranking(modules: &[ModuleStats], issues: &[Issue], history: &[HealthSnapshot]) -> Vec<ModuleHealth> {
+    let mut ranking: Vec<ModuleHealth> = modules
+        .iter()
+        .map(|m| {
+            let mut row = ModuleHealth {
+                id: m.id.clone(),
+                score: m.health_score,
+                documents: m.document_count,
+                errors: 0,
+                warnings: 0,
+                trend: module_trend(history, &m.id, TREND_POINTS),
+            };
+            let owned: Vec<&Issue> = issues.iter().filter(|i| row.owns(i)).collect();
+            row.errors = owned.iter().filter(|i| i.severity == Severity::Error).count();
+            row.warnings = owned.len() - row.errors;
+            row
+        })
+        .collect();
+    ranking.sort_by(|a, b| {
+        a.score
+            .total_cmp(&b.score)
+            .then(b.errors.cmp(&a.errors))
+            .then_with(|| crate::core::files::natural_cmp(&a.id, &b.id))
+    });
+    ranking
+}
+
+fn score_color(score: f64) -> Color {
+    if score >= 80.0 {
+        Color::Green
+    } else if score >= 50.0 {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // DASHBOARD APP STATE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -109,6 +176,18 @@ pub struct DashboardApp {
     pub total_warnings: usize,
     pub phases_passed: usize,
     pub phases_total: usize,
+    pub view: View,
+    pub modules: Vec<ModuleHealth>,
+    pub module_state: ListState,
+    /// Drill-down: solo los issues de este módulo.
+    pub module_filter: Option<String>,
+}
+
+/// Panel central.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum View {
+    Issues,
+    Modules,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -141,16 +220,52 @@ impl DashboardApp {
             total_warnings: result.total_warnings,
             phases_passed: result.phases.iter().filter(|p| p.passed).count(),
             phases_total: result.phases.len(),
+            view: View::Issues,
+            modules: Vec::new(),
+            module_state: ListState::default(),
+            module_filter: None,
         }
     }
 
+    /// Agrega el ranking de módulos (ver [`module_ranking`]).
+    pub fn with_modules(mut self, modules: Vec<ModuleHealth>) -> Self {
+        self.module_state.select((!modules.is_empty()).then_some(0));
+        self.modules = modules;
+        self
+    }
+
     pub fn filtered_issues(&self) -> Vec<Issue> {
+        let module = self
+            .module_filter
+            .as_ref()
+            .and_then(|id| self.modules.iter().find(|m| &m.id == id));
         self.issues.iter().filter(|i| match self.filter {
             FilterMode::All => true,
             FilterMode::Errors => i.severity == Severity::Error,
             FilterMode::Warnings => i.severity == Severity::Warning,
             FilterMode::Fixable => i.fixable,
-        }).cloned().collect()
+        }).filter(|i| module.map_or(true, |m| m.owns(i))).cloned().collect()
+    }
+
+    /// Pasa a los issues del módulo seleccionado en el ranking.
+    pub fn drill_down(&mut self) {
+        let Some(module) = self.module_state.selected().and_then(|i| self.modules.get(i)) else {
+            return;
+        };
+        self.module_filter = Some(module.id.clone());
+        self.view = View::Issues;
+        self.list_state.select((self.filtered_count() > 0).then_some(0));
+    }
+
+    /// Lista activa y su largo.
+    fn active_list(&mut self) -> (&mut ListState, usize) {
+        match self.view {
+            View::Issues => {
+                let count = self.filtered_count();
+                (&mut self.list_state, count)
+            }
+            View::Modules => (&mut self.module_state, self.modules.len()),
+        }
     }
 
     pub fn filtered_count(&self) -> usize {
@@ -158,23 +273,23 @@ impl DashboardApp {
     }
 
     pub fn next(&mut self) {
-        let count = self.filtered_count();
+        let (state, count) = self.active_list();
         if count == 0 {
             return;
         }
-        let i = match self.list_state.selected() {
+        let i = match state.selected() {
             Some(i) => (i + 1) % count,
             None => 0,
         };
-        self.list_state.select(Some(i));
+        state.select(Some(i));
     }
 
     pub fn previous(&mut self) {
-        let count = self.filtered_count();
+        let (state, count) = self.active_list();
         if count == 0 {
             return;
         }
-        let i = match self.list_state.selected() {
+        let i = match state.selected() {
             Some(i) => {
                 if i == 0 {
                     count - 1
@@ -184,7 +299,7 @@ impl DashboardApp {
             }
             None => 0,
         };
-        self.list_state.select(Some(i));
+        state.select(Some(i));
     }
 
     pub fn health_score(&self) -> f64 {
@@ -219,6 +334,9 @@ pub struct DashboardCommand {
     #[arg(long, value_name = "PATTERN")]
     pub exclude: Vec<String>,
 
+    /// No registrar esta ejecución en el historial de salud.
+    #[arg(long)]
+    pub no_history: bool,
 
 }
 
@@ -244,20 +362,24 @@ fn ui(frame: &mut Frame, app: &mut DashboardApp) {
     // Summary con métricas
     render_summary(frame, chunks[1], app);
     
-    // Lista de issues
-    render_issues(frame, chunks[2], app);
+    // Lista de issues o ranking de módulos
+    match app.view {
+        View::Issues => render_issues(frame, chunks[2], app),
+        View::Modules => render_modules(frame, chunks[2], app),
+    }
     
     // Footer con comandos
     render_footer(frame, chunks[3]);
 }
 
 fn render_header(frame: &mut Frame, area: Rect, app: &DashboardApp) {
-    let titles = vec!["[A]ll", "[E]rrors", "[W]arnings", "[F]ixable"];
-    let selected = match app.filter {
-        FilterMode::All => 0,
-        FilterMode::Errors => 1,
-        FilterMode::Warnings => 2,
-        FilterMode::Fixable => 3,
+    let titles = vec!["[A]ll", "[E]rrors", "[W]arnings", "[F]ixable", "[M]odules"];
+    let selected = match (app.view, app.filter) {
+        (View::Modules, _) => 4,
+        (_, FilterMode::All) => 0,
+        (_, FilterMode::Errors) => 1,
+        (_, FilterMode::Warnings) => 2,
+        (_, FilterMode::Fixable) => 3,
     };
     
     let tabs = Tabs::new(titles)
@@ -282,14 +404,8 @@ fn render_summary(frame: &mut Frame, area: Rect, app: &DashboardApp) {
 
     // Health Gauge
     let health = app.health_score();
-    let health_color = if health >= 80.0 {
-        Color::Green
-    } else if health >= 50.0 {
-        Color::Yellow
-    } else {
-        Color::Red
-    };
-    
+    let health_color = score_color(health);
+
     let gauge = Gauge::default()
         .block(Block::default().borders(Borders::ALL).title(" Health "))
         .gauge_style(Style::default().fg(health_color))
@@ -345,16 +461,52 @@ fn render_issues(frame: &mut Frame, area: Rect, app: &mut DashboardApp) {
         .collect();
 
     let issues_count = filtered.len();
+    let title = match &app.module_filter {
+        Some(module) => format!(" Issues ({}) · Módulo {} (Esc: todos) ", issues_count, module),
+        None => format!(" Issues ({}) ", issues_count),
+    };
     let list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title(format!(" Issues ({}) ", issues_count)))
+        .block(Block::default().borders(Borders::ALL).title(title))
         .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
         .highlight_symbol("▶ ");
 
     frame.render_stateful_widget(list, area, &mut app.list_state);
 }
 
+fn render_modules(frame: &mut Frame, area: Rect, app: &mut DashboardApp) {
+    use crate::ui::heatmap::sparkline;
+
+    let items: Vec<ListItem> = app
+        .modules
+        .iter()
+        .enumerate()
+        .map(|(rank, m)| {
+            let style = Style::default().fg(score_color(m.score));
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:>2}. Módulo {:<5}", rank + 1, m.id), style),
+                Span::styled(format!("{:>6.1}% ", m.score), style.add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{:<w$}", sparkline(&m.trend), w = TREND_POINTS), Style::default().fg(Color::Cyan)),
+                Span::styled(format!("  ❌ {:<4}", m.errors), Style::default().fg(Color::Red)),
+                Span::styled(format!("⚠️ {:<4}", m.warnings), Style::default().fg(Color::Yellow)),
+                Span::styled(format!("📄 {}", m.documents), Style::default().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Módulos por salud ({}) · Enter: ver issues ", app.modules.len())),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .highlight_symbol("▶ ");
+
+    frame.render_stateful_widget(list, area, &mut app.module_state);
+}
+
 fn render_footer(frame: &mut Frame, area: Rect) {
-    let help_text = " ↑/↓ or j/k: Navigate | a/e/w/f: Filter | q: Quit ";
+    let help_text = " ↑/↓ or j/k: Navigate | a/e/w/f: Filter | m: Modules | Enter: Drill down | Esc: Back | q: Quit ";
     let footer = Paragraph::new(help_text)
         .block(Block::default().borders(Borders::ALL))
         .style(Style::default().fg(Color::DarkGray));
@@ -370,13 +522,27 @@ fn handle_events(app: &mut DashboardApp) -> io::Result<bool> {
         if let Event::Key(key) = event::read()? {
             if key.kind == KeyEventKind::Press {
                 match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => app.should_quit = true,
+                    KeyCode::Char('q') => app.should_quit = true,
+                    // Esc: salir del drill-down, luego del ranking, luego del dashboard
+                    KeyCode::Esc if app.module_filter.is_some() => {
+                        app.module_filter = None;
+                        app.view = View::Modules;
+                    }
+                    KeyCode::Esc if app.view == View::Modules => app.view = View::Issues,
+                    KeyCode::Esc => app.should_quit = true,
                     KeyCode::Down | KeyCode::Char('j') => app.next(),
                     KeyCode::Up | KeyCode::Char('k') => app.previous(),
-                    KeyCode::Char('a') => app.filter = FilterMode::All,
-                    KeyCode::Char('e') => app.filter = FilterMode::Errors,
-                    KeyCode::Char('w') => app.filter = FilterMode::Warnings,
-                    KeyCode::Char('f') => app.filter = FilterMode::Fixable,
+                    KeyCode::Char('m') => app.view = View::Modules,
+                    KeyCode::Enter if app.view == View::Modules => app.drill_down(),
+                    KeyCode::Char(c @ ('a' | 'e' | 'w' | 'f')) => {
+                        app.view = View::Issues;
+                        app.filter = match c {
+                            'e' => FilterMode::Errors,
+                            'w' => FilterMode::Warnings,
+                            'f' => FilterMode::Fixable,
+                            _ => FilterMode::All,
+                        };
+                    }
                     _ => {}
                 }
             }
@@ -406,12 +572,51 @@ pub fn run(cmd: DashboardCommand, cli: &crate::commands::CliConfig) -> anyhow::R
         cache: false,
         root_only: false,
         exclude: cmd.exclude.clone(),
+        ignore_symlinks: false,
+        include_derived: false,
+        case_sensitive: false,
+        link_convention: None,
+        output: None,
+        module: None,
+        id_prefix: None,
         schema_strict: false,
-
+        timings: false,
+        timings_top: 10,
+        only_errors: false,
+        group_by: "phase".to_string(),
+        max_issues_per_file: None,
+        explain: None,
+        summary: false,
+        format: "text".to_string(),
+        open: Vec::new(),
+        fix: false,
+        compare_run: false,
+        all: false,
+        profiles: None,
+        derived_dirs: None,
+        profiler: None,
+        documents: None,
     };
     
     let result = verify_cmd.run(&data_dir)?;
-    
+
+    // Salud por módulo y tendencia del historial
+    use crate::commands::stats::StatsCommand;
+    use crate::core::health_history;
+    let (stats, module_stats) = StatsCommand::parse_from(["stats"]).run(&data_dir)?;
+    let history_path = health_history::history_path();
+    let mut history = health_history::load(&history_path).unwrap_or_default();
+    if !cmd.no_history {
+        let snapshot = HealthSnapshot::new(
+            stats.health_percent(),
+            module_stats.iter().map(|m| (m.id.clone(), m.health_score)).collect(),
+        );
+        if let Err(e) = health_history::append(&history_path, &snapshot) {
+            eprintln!("⚠ Historial de salud: {}", e);
+        }
+        history.push(snapshot);
+    }
+
     // Iniciar TUI
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -420,6 +625,8 @@ pub fn run(cmd: DashboardCommand, cli: &crate::commands::CliConfig) -> anyhow::R
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = DashboardApp::new(&result);
+    let modules = module_ranking(&module_stats, &app.issues, &history);
+    app = app.with_modules(modules);
     
     // Configurar filtro inicial
     app.filter = match cmd.filter.as_str() {
@@ -449,3 +656,46 @@ pub fn run(cmd: DashboardCommand, cli: &crate::commands::CliConfig) -> anyhow::R
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_module_ranking_and_drill_down() {
+        let mut phase = VerificationPhase::new(9, "links", "Links");
+        phase.add_error("2.1 pagos.md: Link roto [[x]]");
+        phase.add_warning("2.3 bajas.md: Sección inexistente [[y#z]]");
+        phase.add_warning("1.2 alta.md: Link roto [[z]]");
+        let mut result = VerificationResult::new();
+        result.add_phase(phase);
+
+        let stats = |id: &str, health: f64| ModuleStats {
+            id: id.to_string(),
+            name: format!("Módulo {}", id),
+            document_count: 4,
+            word_count: 100,
+            link_count: 3,
+            health_score: health,
+        };
+        let history: Vec<HealthSnapshot> = [40.0, 75.0]
+            .into_iter()
+            .map(|h| HealthSnapshot::new(h, BTreeMap::from([("2".to_string(), h)])))
+            .collect();
+
+        let app = DashboardApp::new(&result);
+        let ranking = module_ranking(&[stats("1", 75.0), stats("2", 75.0), stats("3", 100.0)], &app.issues, &history);
+        let order: Vec<(&str, usize, usize)> = ranking.iter().map(|m| (m.id.as_str(), m.errors, m.warnings)).collect();
+        assert_eq!(order, vec![("2", 1, 1), ("1", 0, 1), ("3", 0, 0)]);
+        assert_eq!(ranking[0].trend, vec![40.0, 75.0]);
+
+        let mut app = app.with_modules(ranking);
+        app.view = View::Modules;
+        app.next();
+        app.drill_down();
+        assert_eq!((app.view, app.module_filter.as_deref()), (View::Issues, Some("1")));
+        let issues: Vec<String> = app.filtered_issues().into_iter().map(|i| i.message).collect();
+        assert_eq!(issues, vec!["1.2 alta.md: Link roto [[z]]".to_string()]);
+    }
+}
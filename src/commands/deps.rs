@@ -2,17 +2,23 @@
 //!
 //! Mapea y visualiza dependencias entre documentos.
 
+use crate::core::files::natural_cmp;
+use crate::core::pipeline::{DocumentCache, ProjectPipeline};
+use crate::core::project_cache::CacheStore;
 use crate::errors::OcResult;
+use crate::traits::Cacheable;
+use crate::types::ContentHash;
 use clap::Parser;
-use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // DEPENDENCY TYPES
 // ═══════════════════════════════════════════════════════════════════════════
 
 /// Tipo de dependencia.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DependencyType {
     /// Link directo.
     Link,
@@ -23,7 +29,7 @@ pub enum DependencyType {
 }
 
 /// Una dependencia.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Dependency {
     pub from: String,
     pub to: String,
@@ -163,34 +169,47 @@ pub struct DepsCommand {
     /// Guardar resultado en archivo.
     #[arg(short, long)]
     pub output: Option<PathBuf>,
-}
 
+    /// Caché de documentos compartida (ver `core::pipeline`).
+    #[arg(skip)]
+    pub documents: Option<std::sync::Arc<DocumentCache>>,
+}
 
-impl DepsCommand {
-    /// Ejecuta el análisis.
-    pub fn run(&self, data_dir: &std::path::Path) -> OcResult<DepsResult> {
-        use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
-        
-        use std::collections::HashSet;
 
-        let mut result = DepsResult::new();
+// ═══════════════════════════════════════════════════════════════════════════
+// LINK GRAPH
+// ═══════════════════════════════════════════════════════════════════════════
 
-        let options = ScanOptions::new();
-        let files = get_all_md_files(data_dir, &options)?;
+/// Grafo de links y jerarquía del proyecto, persistible entre ejecuciones de
+/// `deps` (ver `core::project_cache`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkGraph {
+    pub data_dir: PathBuf,
+    /// IDs de todos los archivos escaneados.
+    pub nodes: BTreeSet<String>,
+    /// Dependencias en orden de escaneo.
+    pub dependencies: Vec<Dependency>,
+    /// id → parent_id (solo parents no nulos).
+    pub parents: BTreeMap<String, String>,
+}
 
+impl LinkGraph {
+    /// Extrae parent_id, wiki-links y links markdown a `.md` de cada archivo.
+    pub fn build(data_dir: &Path, pipeline: &ProjectPipeline, files: &[PathBuf]) -> Self {
         // Patrones para detectar dependencias
         use crate::core::patterns::{RE_PARENT_ID, RE_WIKI_LINK, RE_MD_LINK_TO_MD};
         let parent_regex = &*RE_PARENT_ID;
         let wiki_link = &*RE_WIKI_LINK;
         let markdown_link = &*RE_MD_LINK_TO_MD;
 
-        let mut all_nodes: HashSet<String> = HashSet::new();
-        let mut nodes_with_parents: HashSet<String> = HashSet::new();
-        let mut nodes_with_children: HashSet<String> = HashSet::new();
-        // C3: Mapa para verificar parent existente
-        let mut parent_map: HashMap<String, String> = HashMap::new();
+        let mut graph = Self {
+            data_dir: data_dir.to_path_buf(),
+            nodes: BTreeSet::new(),
+            dependencies: Vec::new(),
+            parents: BTreeMap::new(),
+        };
 
-        for file_path in &files {
+        for file_path in files {
             // Extraer ID del archivo
             let file_id = file_path
                 .file_stem()
@@ -198,23 +217,21 @@ impl DepsCommand {
                 .unwrap_or("unknown")
                 .to_string();
 
-            all_nodes.insert(file_id.clone());
+            graph.nodes.insert(file_id.clone());
 
-            if let Ok(content) = read_file_content(file_path) {
+            if let Ok(content) = pipeline.read(file_path) {
                 // Buscar parent_id en frontmatter
                 if let Some(cap) = parent_regex.captures(&content) {
                     let parent_id = cap[1].trim().to_string();
                     
                     // C3: Verificar si parent es válido
                     if parent_id != "null" && !parent_id.is_empty() {
-                        result.dependencies.push(Dependency {
+                        graph.dependencies.push(Dependency {
                             from: parent_id.clone(),
                             to: file_id.clone(),
                             dep_type: DependencyType::Hierarchy,
                         });
-                        nodes_with_parents.insert(file_id.clone());
-                        nodes_with_children.insert(parent_id.clone());
-                        parent_map.insert(file_id.clone(), parent_id);
+                        graph.parents.insert(file_id.clone(), parent_id);
                     }
                     // Si parent es "null" o vacío, no agregamos a nodes_with_parents
                 }
@@ -224,7 +241,7 @@ impl DepsCommand {
                 for cap in wiki_link.captures_iter(&content) {
                     let target = &cap[1];
                     if target != file_id {
-                        result.dependencies.push(Dependency {
+                        graph.dependencies.push(Dependency {
                             from: file_id.clone(),
                             to: target.to_string(),
                             dep_type: DependencyType::Link,
@@ -241,7 +258,7 @@ impl DepsCommand {
                             .and_then(|s| s.to_str())
                             .unwrap_or(target);
                         if target_id != file_id {
-                            result.dependencies.push(Dependency {
+                            graph.dependencies.push(Dependency {
                                 from: file_id.clone(),
                                 to: target_id.to_string(),
                                 dep_type: DependencyType::Link,
@@ -251,6 +268,61 @@ impl DepsCommand {
                 }
             }
         }
+        graph
+    }
+}
+
+impl Cacheable for LinkGraph {
+    type Key = PathBuf;
+    const CACHE_NAME: &'static str = "link_graph";
+
+    fn cache_key(&self) -> PathBuf {
+        self.data_dir.clone()
+    }
+
+    fn content_hash(&self) -> ContentHash {
+        let mut listing: Vec<String> = self.nodes.iter().cloned().collect();
+        listing.extend(
+            self.dependencies
+                .iter()
+                .map(|d| format!("{} -{:?}-> {}", d.from, d.dep_type, d.to)),
+        );
+        listing.extend(self.parents.iter().map(|(id, parent)| format!("{} ^ {}", id, parent)));
+        ContentHash::compute(&listing.join("\n"))
+    }
+}
+
+impl DepsCommand {
+    /// Ejecuta el análisis.
+    pub fn run(&self, data_dir: &Path) -> OcResult<DepsResult> {
+        self.run_with_store(data_dir, None)
+    }
+
+    /// Ejecuta el análisis reutilizando el grafo de links guardado en
+    /// `store` si ningún archivo cambió.
+    pub fn run_with_store(&self, data_dir: &Path, store: Option<&CacheStore>) -> OcResult<DepsResult> {
+        let pipeline = ProjectPipeline::new(data_dir).with_cache(self.documents.clone());
+        let files = pipeline.scan()?;
+        let build = || Ok(LinkGraph::build(data_dir, &pipeline, &files));
+        let graph = match store {
+            Some(store) => store.get_or_build(&data_dir.to_path_buf(), &files, build)?,
+            None => build()?,
+        };
+        Ok(self.run_with_graph(graph))
+    }
+
+    /// Raíces, hojas, huérfanos y ciclos a partir del grafo de links.
+    pub fn run_with_graph(&self, graph: LinkGraph) -> DepsResult {
+        let LinkGraph {
+            nodes: all_nodes,
+            dependencies,
+            parents: parent_map,
+            ..
+        } = graph;
+        let mut result = DepsResult::new();
+        result.dependencies = dependencies;
+        let nodes_with_parents: HashSet<&String> = parent_map.keys().collect();
+        let nodes_with_children: HashSet<&String> = parent_map.values().collect();
 
         // Calcular nodos raíz (sin parent) y hoja (sin children)
         for node in &all_nodes {
@@ -283,15 +355,15 @@ impl DepsCommand {
             }
         }
 
-        result.root_nodes.sort();
-        result.leaf_nodes.sort();
-        result.orphan_nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        result.root_nodes.sort_by(|a, b| natural_cmp(a, b));
+        result.leaf_nodes.sort_by(|a, b| natural_cmp(a, b));
+        result.orphan_nodes.sort_by(|a, b| natural_cmp(&a.id, &b.id));
 
         if self.detect_cycles {
             self.find_cycles(&mut result);
         }
 
-        Ok(result)
+        result
     }
 
     /// Detecta ciclos en las dependencias.
@@ -310,7 +382,10 @@ impl DepsCommand {
         let mut rec_stack = HashSet::new();
         let mut path = Vec::new();
 
-        for node in adj.keys() {
+        // Orden natural de entrada: los mismos ciclos en el mismo orden en cada corrida
+        let mut nodes: Vec<&String> = adj.keys().collect();
+        nodes.sort_by(|a, b| natural_cmp(a, b));
+        for node in nodes {
             if !visited.contains(node) {
                 self.dfs_cycle(node, &adj, &mut visited, &mut rec_stack, &mut path, result);
             }
@@ -408,8 +483,7 @@ pub fn run(cmd: DepsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
 
         // Construir set de todos los IDs válidos
         let mut all_ids: std::collections::HashSet<String> = std::collections::HashSet::new();
-        use walkdir::WalkDir;
-        for entry in WalkDir::new(data_dir).into_iter().filter_map(|e| e.ok()) {
+        for entry in crate::core::files::walk_sorted(data_dir).into_iter().filter_map(|e| e.ok()) {
             let path = entry.path();
             if path.is_file() && path.extension().map(|e| e == "md").unwrap_or(false) {
                 if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
@@ -418,7 +492,7 @@ pub fn run(cmd: DepsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
             }
         }
 
-        for entry in WalkDir::new(data_dir).into_iter().filter_map(|e| e.ok()) {
+        for entry in crate::core::files::walk_sorted(data_dir).into_iter().filter_map(|e| e.ok()) {
             let path = entry.path();
             if !path.is_file() { continue; }
             if path.extension().map(|e| e != "md").unwrap_or(true) { continue; }
@@ -470,8 +544,7 @@ pub fn run(cmd: DepsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
         let mut referencing: Vec<String> = Vec::new();
         let mut children: Vec<String> = Vec::new();
 
-        use walkdir::WalkDir;
-        for entry in WalkDir::new(data_dir).into_iter().filter_map(|e| e.ok()) {
+        for entry in crate::core::files::walk_sorted(data_dir).into_iter().filter_map(|e| e.ok()) {
             let path = entry.path();
             if !path.is_file() { continue; }
             if path.extension().map(|e| e != "md").unwrap_or(true) { continue; }
@@ -515,7 +588,7 @@ pub fn run(cmd: DepsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
     }
 
     // Lógica normal
-    let result = cmd.run(data_dir)?;
+    let result = cmd.run_with_store(data_dir, CacheStore::project().as_ref())?;
 
     // F5: Filtrar por dirección
     let direction_label = match cmd.direction.as_str() {
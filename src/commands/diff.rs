@@ -1,11 +1,13 @@
 //! Comando diff - Comparación de estados.
 //!
-//! Compara estados del proyecto entre commits o snapshots.
+//! Compara estados del proyecto entre commits o snapshots. Con `--rev`
+//! compara un documento contra su versión en git campo a campo: frontmatter,
+//! headings, word count y links.
 
-use crate::errors::OcResult;
+use crate::errors::{OcError, OcResult};
 use clap::Parser;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // DIFF TYPES
@@ -68,6 +70,154 @@ impl DiffResult {
     }
 }
 
+/// Cambio de un campo del frontmatter (`None` = ausente en ese lado).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// Diff estructurado de un documento entre una revisión y el working tree.
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentDiff {
+    pub id: String,
+    pub file: PathBuf,
+    pub rev: String,
+    pub fields: Vec<FieldChange>,
+    pub headings_added: Vec<String>,
+    pub headings_removed: Vec<String>,
+    pub words_before: usize,
+    pub words_after: usize,
+    pub links_added: Vec<String>,
+    pub links_removed: Vec<String>,
+}
+
+impl DocumentDiff {
+    /// Compara dos contenidos del mismo documento.
+    pub fn compute(id: &str, file: &Path, rev: &str, old: &str, new: &str) -> Self {
+        let (old_fields, new_fields) = (frontmatter_fields(old), frontmatter_fields(new));
+        let mut fields: Vec<FieldChange> = old_fields
+            .iter()
+            .map(|(field, value)| FieldChange {
+                field: field.clone(),
+                old: Some(value.clone()),
+                new: new_fields.iter().find(|(f, _)| f == field).map(|(_, v)| v.clone()),
+            })
+            .chain(
+                new_fields
+                    .iter()
+                    .filter(|(field, _)| !old_fields.iter().any(|(f, _)| f == field))
+                    .map(|(field, value)| FieldChange {
+                        field: field.clone(),
+                        old: None,
+                        new: Some(value.clone()),
+                    }),
+            )
+            .collect();
+        fields.retain(|c| c.old != c.new);
+
+        let (old_headings, new_headings) = (headings(old), headings(new));
+        let (old_links, new_links) = (links(old), links(new));
+        Self {
+            id: id.to_string(),
+            file: file.to_path_buf(),
+            rev: rev.to_string(),
+            fields,
+            headings_added: missing_from(&new_headings, &old_headings),
+            headings_removed: missing_from(&old_headings, &new_headings),
+            words_before: words(old),
+            words_after: words(new),
+            links_added: missing_from(&new_links, &old_links),
+            links_removed: missing_from(&old_links, &new_links),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+            && self.headings_added.is_empty()
+            && self.headings_removed.is_empty()
+            && self.words_before == self.words_after
+            && self.links_added.is_empty()
+            && self.links_removed.is_empty()
+    }
+}
+
+/// Campos de primer nivel del frontmatter en orden, con los valores no
+/// escalares en JSON compacto.
+fn frontmatter_fields(content: &str) -> Vec<(String, String)> {
+    let Some(yaml) = crate::core::yaml::frontmatter_block(content) else {
+        return Vec::new();
+    };
+    let Ok(serde_yaml::Value::Mapping(map)) = serde_yaml::from_str(yaml) else {
+        return Vec::new();
+    };
+    map.into_iter()
+        .filter_map(|(key, value)| {
+            let key = key.as_str()?.to_string();
+            let value = match value {
+                serde_yaml::Value::String(s) => s,
+                serde_yaml::Value::Null => String::new(),
+                other => serde_json::to_string(&other).unwrap_or_default(),
+            };
+            Some((key, value))
+        })
+        .collect()
+}
+
+fn headings(content: &str) -> Vec<String> {
+    crate::core::anchors::anchors(content)
+        .into_iter()
+        .map(|h| format!("{} {}", "#".repeat(h.level), h.text))
+        .collect()
+}
+
+fn links(content: &str) -> Vec<String> {
+    let mut links: Vec<String> = crate::core::links::extract_links(content)
+        .into_iter()
+        .map(|l| l.raw)
+        .collect();
+    links.sort();
+    links.dedup();
+    links
+}
+
+fn words(content: &str) -> usize {
+    // "---" + yaml + "\n---": el resto es el body
+    let body = crate::core::yaml::frontmatter_block(content)
+        .map_or(content, |yaml| &content[yaml.len() + 7..]);
+    crate::core::yaml::count_words(body)
+}
+
+/// Elementos de `a` que no están en `b`, en el orden de `a`.
+fn missing_from(a: &[String], b: &[String]) -> Vec<String> {
+    a.iter().filter(|x| !b.contains(x)).cloned().collect()
+}
+
+/// Contenido de `file` en la revisión `rev` (`git show REV:./archivo`).
+fn git_show(file: &Path, rev: &str) -> OcResult<String> {
+    let dir = file.parent().filter(|d| !d.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    let name = file
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| OcError::FileNotFound(file.to_path_buf()))?;
+    let output = std::process::Command::new("git")
+        .arg("show")
+        .arg(format!("{}:./{}", rev, name))
+        .current_dir(dir)
+        .output()
+        .map_err(|e| OcError::Custom(format!("no se pudo ejecutar git: {}", e)))?;
+    if !output.status.success() {
+        return Err(OcError::Custom(format!(
+            "{} no existe en {}: {}",
+            name,
+            rev,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // DIFF COMMAND
 // ═══════════════════════════════════════════════════════════════════════════
@@ -100,6 +250,14 @@ pub struct DiffCommand {
     /// Limitar líneas de contexto.
     #[arg(short = 'c', long, default_value = "3")]
     pub context: usize,
+
+    /// Revisión git: compara el documento con ID `FROM` contra el working tree.
+    #[arg(long, value_name = "REV")]
+    pub rev: Option<String>,
+
+    /// Output JSON.
+    #[arg(long)]
+    pub json: bool,
 }
 
 impl DiffCommand {
@@ -194,6 +352,18 @@ impl DiffCommand {
         Ok(result)
     }
 
+    /// Diff estructurado del documento `from` (ID) entre `--rev` y el
+    /// working tree.
+    pub fn document_diff(&self, data_dir: &Path) -> OcResult<DocumentDiff> {
+        let rev = self
+            .rev
+            .as_deref()
+            .ok_or_else(|| OcError::InvalidArgument("Indique la revisión: diff <ID> --rev <REV>".to_string()))?;
+        let (file, current) = crate::commands::version::find_document(data_dir, &self.from)?;
+        let previous = git_show(&file, rev)?;
+        Ok(DocumentDiff::compute(&self.from, &file, rev, &previous, &current))
+    }
+
     /// L22.1: Genera diff side-by-side para un archivo.
     pub fn render_side_by_side(from_content: &str, to_content: &str, width: usize) -> String {
         let half_width = width / 2 - 2;
@@ -286,11 +456,41 @@ mod tests {
             path: None,
             side_by_side: true,
             context: 3,
+            rev: None,
+            json: false,
         };
         assert!(cmd.side_by_side);
         assert_eq!(cmd.context, 3);
     }
 
+    #[test]
+    fn test_document_diff() {
+        let old = "---\nid: \"2.3.1\"\nstatus: borrador\ntags: [a]\nowner: ana\n---\n\
+                   # Flujo\n\n## Viejo\n\nuno dos [[1.1 a]] [[1.2 b]]\n";
+        let new = "---\nid: \"2.3.1\"\nstatus: vigente\ntags: [a, b]\nversion: \"2.4.0\"\n---\n\
+                   # Flujo\n\n## Nuevo\n\nuno dos tres [[1.1 a]] [[1.3 c]]\n";
+        let diff = DocumentDiff::compute("2.3.1", Path::new("2.3.1 flujo.md"), "HEAD~5", old, new);
+
+        let field = |f: &str, o: Option<&str>, n: Option<&str>| FieldChange {
+            field: f.to_string(),
+            old: o.map(str::to_string),
+            new: n.map(str::to_string),
+        };
+        assert_eq!(
+            diff.fields,
+            vec![
+                field("status", Some("borrador"), Some("vigente")),
+                field("tags", Some("[\"a\"]"), Some("[\"a\",\"b\"]")),
+                field("owner", Some("ana"), None),
+                field("version", None, Some("2.4.0")),
+            ]
+        );
+        assert_eq!((diff.headings_added, diff.headings_removed), (vec!["## Nuevo".to_string()], vec!["## Viejo".to_string()]));
+        assert_eq!(diff.words_after, diff.words_before + 1);
+        assert_eq!((diff.links_added, diff.links_removed), (vec!["[[1.3 c]]".to_string()], vec!["[[1.2 b]]".to_string()]));
+        assert!(DocumentDiff::compute("1", Path::new("a.md"), "HEAD", new, new).is_empty());
+    }
+
     #[test]
     fn test_render_side_by_side() {
         let from = "line1\nline2\nline3";
@@ -305,7 +505,14 @@ mod tests {
 pub fn run(cmd: DiffCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
     let default_dir = PathBuf::from(&cli.data_dir);
     let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
+    if cmd.rev.is_some() {
+        return print_document_diff(&cmd.document_diff(data_dir)?, cmd.json);
+    }
     let result = cmd.run(data_dir)?;
+    if cmd.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
 
     println!("📊 Diff: {} → {}", result.from_ref, result.to_ref);
     println!("  ➕ {} añadidos", result.added_count());
@@ -327,3 +534,42 @@ pub fn run(cmd: DiffCommand, cli: &crate::commands::CliConfig) -> anyhow::Result
 
     Ok(())
 }
+
+#[cfg(feature = "cli")]
+fn print_document_diff(diff: &DocumentDiff, json: bool) -> anyhow::Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(diff)?);
+        return Ok(());
+    }
+
+    println!("📊 Diff {}: {} → working tree ({})", diff.id, diff.rev, diff.file.display());
+    if diff.is_empty() {
+        println!("  ✅ Sin cambios");
+        return Ok(());
+    }
+    if !diff.fields.is_empty() {
+        println!("\n📋 Frontmatter:");
+        for change in &diff.fields {
+            match (&change.old, &change.new) {
+                (Some(old), Some(new)) => println!("  ✏️  {}: {} → {}", change.field, old, new),
+                (None, Some(new)) => println!("  ➕ {}: {}", change.field, new),
+                (Some(old), None) => println!("  ➖ {}: {}", change.field, old),
+                (None, None) => {}
+            }
+        }
+    }
+    if !diff.headings_added.is_empty() || !diff.headings_removed.is_empty() {
+        println!("\n📑 Headings:");
+        diff.headings_added.iter().for_each(|h| println!("  ➕ {}", h));
+        diff.headings_removed.iter().for_each(|h| println!("  ➖ {}", h));
+    }
+    let delta = diff.words_after as i64 - diff.words_before as i64;
+    println!("\n📝 Palabras: {} → {} ({:+})", diff.words_before, diff.words_after, delta);
+    if !diff.links_added.is_empty() || !diff.links_removed.is_empty() {
+        println!("\n🔗 Links:");
+        diff.links_added.iter().for_each(|l| println!("  ➕ {}", l));
+        diff.links_removed.iter().for_each(|l| println!("  ➖ {}", l));
+    }
+
+    Ok(())
+}
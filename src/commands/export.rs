@@ -1,11 +1,28 @@
 //! Comando export - Exportación de documentación.
 //!
-//! Exporta documentación a múltiples formatos.
-
-use crate::errors::OcResult;
+//! Exporta documentación a múltiples formatos. `--format jsonl` emite un
+//! objeto JSON por documento (frontmatter, body, palabras, links y hash) para
+//! cargar en un data warehouse o procesar con jq. `--incremental` reescribe
+//! solo las salidas cuyos documentos fuente cambiaron ([`crate::core::incremental`]).
+//! `--audience public` falla antes de escribir si el export incluiría un
+//! documento (o un embed) con `classification` mayor que la audiencia.
+//! `--sitemap --base-url <URL>` agrega `sitemap.xml` y `nav.json` para la web
+//! pública ([`crate::core::sitemap`]), más `redirects.json` si `gen slugs`
+//! registró cambios de slug ([`crate::core::slugs`]) o `fix --filenames`
+//! renombró documentos ([`crate::core::redirects`]). `--redirects
+//! netlify|html` publica esos redirects para el hosting estático.
+//! `--template` renderiza cada documento con [`crate::core::hbs`] sobre su
+//! frontmatter completo, el body, los hijos y los backlinks ([`TemplateIndex`]).
+
+use crate::errors::{OcError, OcResult};
 use clap::Parser;
-use serde::Serialize;
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use crate::core::incremental::{sources_hash, BuildManifest, IncrementalStats, ManifestEntry, MANIFEST_FILE};
+use crate::core::redact::Redactor;
+use crate::core::transclude::{TranscludeStats, Transcluder, DEFAULT_EMBED_DEPTH};
+use crate::core::variables::Variables;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // EXPORT TYPES
@@ -19,6 +36,8 @@ pub enum ExportFormat {
     Pdf,
     Docx,
     Json,
+    /// JSON Lines: un documento parseado por línea.
+    JsonLines,
     Latex,
 }
 
@@ -30,6 +49,7 @@ impl ExportFormat {
             "pdf" => Some(Self::Pdf),
             "docx" | "word" => Some(Self::Docx),
             "json" => Some(Self::Json),
+            "jsonl" | "ndjson" => Some(Self::JsonLines),
             "latex" | "tex" => Some(Self::Latex),
             _ => None,
         }
@@ -42,6 +62,7 @@ impl ExportFormat {
             Self::Pdf => "pdf",
             Self::Docx => "docx",
             Self::Json => "json",
+            Self::JsonLines => "jsonl",
             Self::Latex => "tex",
         }
     }
@@ -54,6 +75,25 @@ pub struct ExportResult {
     pub format: String,
     pub files_exported: usize,
     pub total_bytes: usize,
+    /// Documentos agregados por `--include-linked` (fuera del subárbol).
+    pub linked_included: usize,
+    /// Reemplazos de `--redact` por regla.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub redactions: BTreeMap<String, usize>,
+    /// Embeds `![[...]]` expandidos, sin resolver u omitidos.
+    #[serde(skip_serializing_if = "TranscludeStats::is_empty")]
+    pub embeds: TranscludeStats,
+    /// Variables `{{...}}` sin valor en la sección `variables` de config.
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    pub unresolved_variables: BTreeSet<String>,
+    /// Documentos regenerados y reutilizados (solo con `--incremental`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incremental: Option<IncrementalStats>,
+    /// Páginas en `sitemap.xml` / `nav.json` (solo con `--sitemap`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sitemap_pages: Option<usize>,
+    /// Redirects publicados (`redirects.json`, `_redirects` o páginas HTML).
+    pub redirects: usize,
 }
 
 impl ExportResult {
@@ -63,10 +103,275 @@ impl ExportResult {
             format: format.to_string(),
             files_exported: 0,
             total_bytes: 0,
+            linked_included: 0,
+            redactions: BTreeMap::new(),
+            embeds: TranscludeStats::default(),
+            unresolved_variables: BTreeSet::new(),
+            incremental: None,
+            sitemap_pages: None,
+            redirects: 0,
         }
     }
 }
 
+/// Documento parseado de `--format jsonl`.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonlDocument {
+    /// `id` del frontmatter o, sin él, el nombre del archivo.
+    pub id: String,
+    /// Ruta relativa al directorio de datos.
+    pub file: String,
+    /// Frontmatter completo (`null` si falta o no es YAML válido).
+    pub frontmatter: serde_json::Value,
+    pub body: String,
+    pub word_count: usize,
+    /// Destinos de los links internos salientes, sin `#sección` ni repetidos.
+    pub links: Vec<String>,
+    /// SHA-256 del archivo fuente.
+    pub hash: String,
+}
+
+/// Selección de una rama de la jerarquía (+ documentos enlazados).
+#[derive(Debug, Clone, Default)]
+pub struct BranchSelection {
+    /// Archivos del subárbol de la raíz.
+    pub subtree: HashSet<PathBuf>,
+    /// Archivos alcanzados por wiki-links desde el subárbol.
+    pub linked: HashSet<PathBuf>,
+}
+
+impl BranchSelection {
+    pub fn contains(&self, path: &Path) -> bool {
+        self.subtree.contains(path) || self.linked.contains(path)
+    }
+
+    /// Subárbol de `root` y, si `link_depth` es `Some`, el cierre transitivo
+    /// por enlaces hasta esa profundidad (0 = sin límite).
+    pub fn select(
+        files: &[PathBuf],
+        root: &str,
+        link_depth: Option<usize>,
+    ) -> OcResult<Self> {
+        use crate::core::files::read_file_content;
+        use crate::core::links::{extract_links, LinkType};
+        use crate::types::DataPath;
+        use std::collections::VecDeque;
+
+        let id_of = |path: &PathBuf| {
+            DataPath::new(path.clone())
+                .extract_id()
+                .map(|id| id.trim_end_matches('.').to_string())
+        };
+        let root = root.trim_end_matches('.');
+        let prefix = format!("{}.", root);
+
+        let mut selection = Self::default();
+        for file in files {
+            if let Some(id) = id_of(file) {
+                if id == root || id.starts_with(&prefix) {
+                    selection.subtree.insert(file.clone());
+                }
+            }
+        }
+        if selection.subtree.is_empty() {
+            return Err(OcError::InvalidArgument(format!(
+                "No hay documentos bajo la raíz '{}'",
+                root
+            )));
+        }
+
+        let Some(max_depth) = link_depth else {
+            return Ok(selection);
+        };
+
+        // Índices de resolución: stem e ID numérico (en minúsculas)
+        let mut by_name: HashMap<String, PathBuf> = HashMap::new();
+        for file in files {
+            if let Some(stem) = file.file_stem().and_then(|s| s.to_str()) {
+                by_name.insert(stem.to_lowercase(), file.clone());
+            }
+            if let Some(id) = id_of(file) {
+                by_name.entry(id.to_lowercase()).or_insert_with(|| file.clone());
+            }
+        }
+
+        let mut queue: VecDeque<(PathBuf, usize)> =
+            selection.subtree.iter().map(|f| (f.clone(), 0)).collect();
+        let mut visited: HashSet<PathBuf> = selection.subtree.clone();
+        while let Some((file, depth)) = queue.pop_front() {
+            if max_depth > 0 && depth >= max_depth {
+                continue;
+            }
+            let Ok(content) = read_file_content(&file) else {
+                continue;
+            };
+            for link in extract_links(&content) {
+                if !matches!(link.link_type, LinkType::ObsidianInternal | LinkType::Markdown) {
+                    continue;
+                }
+                let target = link.target.split('#').next().unwrap_or_default();
+                // No usar file_stem: "3.1 Glosario" tendría extensión ".1 Glosario"
+                let file = target.rsplit('/').next().unwrap_or(target);
+                let name = file.trim().trim_end_matches(".md").to_lowercase();
+                if let Some(found) = by_name.get(&name) {
+                    if visited.insert(found.clone()) {
+                        selection.linked.insert(found.clone());
+                        queue.push_back((found.clone(), depth + 1));
+                    }
+                }
+            }
+        }
+
+        Ok(selection)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TEMPLATE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Documento referido desde una plantilla (`children`, `backlinks`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TemplateDoc {
+    pub id: String,
+    pub title: String,
+    pub slug: String,
+    /// Nombre de archivo (`1.1 alta.md`).
+    pub file: String,
+}
+
+/// Hijos y backlinks de cada documento del proyecto, para `--template`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateIndex {
+    /// stem → documento.
+    docs: HashMap<String, TemplateDoc>,
+    /// id del padre → stems de los hijos.
+    children: HashMap<String, Vec<String>>,
+    /// stem → stems de los documentos que lo enlazan.
+    backlinks: HashMap<String, Vec<String>>,
+}
+
+impl TemplateIndex {
+    /// Recorre todos los archivos: `parent`/`parent_id` del frontmatter para
+    /// los hijos y wiki-links/links markdown internos para los backlinks.
+    /// Con `redactor`, títulos y slugs salen ya redactados.
+    pub fn build(files: &[PathBuf], redactor: Option<&Redactor>) -> Self {
+        use crate::core::files::{natural_cmp, read_file_content};
+        use crate::core::links::{extract_links, LinkType};
+        use crate::core::sitemap::SitePage;
+        use crate::types::breadcrumb::frontmatter_value;
+        use crate::types::lifetimes::split_frontmatter;
+
+        let contents: Vec<(String, String)> = files
+            .iter()
+            .filter_map(|path| {
+                let name = path.file_name().and_then(|n| n.to_str())?;
+                let content = read_file_content(path).ok()?;
+                let content = match redactor {
+                    Some(redactor) => redactor.redact(&content, &mut BTreeMap::new()),
+                    None => content,
+                };
+                Some((name.to_string(), content))
+            })
+            .collect();
+
+        let mut index = Self::default();
+        // Resolución de links: stem e ID numérico (en minúsculas)
+        let mut by_name: HashMap<String, String> = HashMap::new();
+        for (name, content) in &contents {
+            let stem = name.strip_suffix(".md").unwrap_or(name).to_string();
+            let page = SitePage::from_doc(&stem, content);
+            let metadata = split_frontmatter(content).map(|fm| fm.metadata).unwrap_or_default();
+            let parent = frontmatter_value(metadata, "parent")
+                .or_else(|| frontmatter_value(metadata, "parent_id"))
+                .filter(|p| p != "null");
+            if let Some(parent) = parent {
+                index.children.entry(parent).or_default().push(stem.clone());
+            }
+            by_name.insert(stem.to_lowercase(), stem.clone());
+            by_name.entry(page.id.to_lowercase()).or_insert_with(|| stem.clone());
+            let doc = TemplateDoc {
+                id: page.id,
+                title: page.title,
+                slug: page.slug,
+                file: name.clone(),
+            };
+            index.docs.insert(stem, doc);
+        }
+
+        for (name, content) in &contents {
+            let stem = name.strip_suffix(".md").unwrap_or(name);
+            for link in extract_links(content) {
+                if !matches!(link.link_type, LinkType::ObsidianInternal | LinkType::Markdown) {
+                    continue;
+                }
+                let target = link.target.split('#').next().unwrap_or_default();
+                let file = target.rsplit('/').next().unwrap_or(target);
+                let Some(found) = by_name.get(&file.trim().trim_end_matches(".md").to_lowercase()) else {
+                    continue;
+                };
+                let sources = index.backlinks.entry(found.clone()).or_default();
+                if found != stem && !sources.iter().any(|s| s == stem) {
+                    sources.push(stem.to_string());
+                }
+            }
+        }
+
+        let docs = &index.docs;
+        let id_cmp = |a: &String, b: &String| natural_cmp(&docs[a].id, &docs[b].id);
+        for stems in index.children.values_mut().chain(index.backlinks.values_mut()) {
+            stems.sort_by(id_cmp);
+        }
+        index
+    }
+
+    fn refs(&self, stems: Option<&Vec<String>>) -> Vec<TemplateDoc> {
+        stems
+            .into_iter()
+            .flatten()
+            .filter_map(|stem| self.docs.get(stem).cloned())
+            .collect()
+    }
+
+    /// Datos de la plantilla para un documento ya procesado (embeds,
+    /// variables, `--redact`): todos los campos del frontmatter en la raíz y
+    /// en `frontmatter`, más `id`, `title`, `slug`, `file`, `body`,
+    /// `children` y `backlinks`.
+    pub fn data(&self, stem: &str, content: &str) -> serde_json::Value {
+        use crate::core::sitemap::SitePage;
+        use crate::types::lifetimes::split_frontmatter;
+
+        let split = split_frontmatter(content);
+        let (metadata, body) = split.map_or(("", content), |s| (s.metadata, s.content));
+        let frontmatter = serde_yaml::from_str::<serde_yaml::Value>(metadata)
+            .ok()
+            .and_then(|v| serde_json::to_value(v).ok())
+            .filter(|v| v.is_object())
+            .unwrap_or_else(|| serde_json::json!({}));
+
+        let page = SitePage::from_doc(stem, content);
+        let mut data = frontmatter.clone();
+        let map = data.as_object_mut().expect("frontmatter es un objeto");
+        map.insert("title".into(), page.title.into());
+        map.insert("slug".into(), page.slug.into());
+        map.insert("file".into(), format!("{}.md", stem).into());
+        map.insert("body".into(), body.into());
+        map.insert("frontmatter".into(), frontmatter);
+        let children = self.children.get(&page.id);
+        map.insert("id".into(), page.id.into());
+        map.insert("children".into(), serde_json::to_value(self.refs(children)).unwrap_or_default());
+        map.insert("backlinks".into(), serde_json::to_value(self.refs(self.backlinks.get(stem))).unwrap_or_default());
+        data
+    }
+
+    /// Hijos y backlinks del documento (para invalidar `--incremental`
+    /// cuando cambian aunque el documento no).
+    fn neighbors(&self, stem: &str) -> String {
+        let children = self.docs.get(stem).and_then(|d| self.children.get(&d.id));
+        serde_json::to_string(&(self.refs(children), self.refs(self.backlinks.get(stem)))).unwrap_or_default()
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // EXPORT COMMAND
 // ═══════════════════════════════════════════════════════════════════════════
@@ -110,7 +415,9 @@ pub struct ExportCommand {
     pub zip: bool,
 
     // F6: Nuevas flags de paridad con Python
-    /// Ruta a plantilla personalizada para exportación.
+    /// Plantilla estilo Handlebars (ver `core::hbs`) con la que se renderiza
+    /// cada documento: frontmatter completo, `body`, `children`, `backlinks`
+    /// y helpers `formatDate`, `slugify` y `toc`.
     #[arg(long)]
     pub template: Option<PathBuf>,
 
@@ -134,6 +441,53 @@ pub struct ExportCommand {
     /// Incluir estadísticas del proyecto en el export.
     #[arg(long)]
     pub stats: bool,
+
+    /// Exportar solo el subárbol de este ID (ej: 2.5).
+    #[arg(long, value_name = "ID")]
+    pub root: Option<String>,
+
+    /// Con --root: incluir documentos alcanzables por wiki-links.
+    #[arg(long, requires = "root")]
+    pub include_linked: bool,
+
+    /// Profundidad máxima de enlaces a seguir (0 = sin límite).
+    #[arg(long, default_value = "0")]
+    pub link_depth: usize,
+
+    /// Reemplazar datos sensibles por placeholders (sección `redact` de config).
+    #[arg(long)]
+    pub redact: bool,
+
+    /// Audiencia del export (public, internal, confidential): falla si algún
+    /// documento incluido o embebido tiene una `classification` mayor.
+    #[arg(long, value_name = "NIVEL")]
+    pub audience: Option<String>,
+
+    /// No expandir embeds `![[Doc]]` / `![[Doc#Sección]]` (dejar la sintaxis cruda).
+    #[arg(long)]
+    pub keep_embeds: bool,
+
+    /// Profundidad máxima de embeds anidados.
+    #[arg(long, default_value_t = DEFAULT_EMBED_DEPTH)]
+    pub embed_depth: usize,
+
+    /// Reescribir solo las salidas cuyos documentos (o sus embeds) cambiaron
+    /// desde el export anterior (manifest `.oc_manifest.json` en la salida).
+    #[arg(long)]
+    pub incremental: bool,
+
+    /// Escribir `sitemap.xml` y `nav.json` (jerarquía por `parent`) en la salida.
+    #[arg(long, requires = "base_url")]
+    pub sitemap: bool,
+
+    /// URL pública de la documentación para `--sitemap` (ej: https://docs.x.mx).
+    #[arg(long, value_name = "URL")]
+    pub base_url: Option<String>,
+
+    /// Publicar los redirects de slugs y documentos renombrados: `netlify`
+    /// (archivo `_redirects`) o `html` (páginas con meta refresh).
+    #[arg(long, value_name = "FORMATO", requires = "base_url")]
+    pub redirects: Option<String>,
 }
 
 
@@ -148,7 +502,7 @@ pub struct ExportIndex {
 }
 
 /// Entrada en el índice.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportFileEntry {
     pub original_name: String,
     pub exported_name: String,
@@ -156,11 +510,35 @@ pub struct ExportFileEntry {
     pub word_count: usize,
 }
 
+/// Lo que un documento aporta al export, guardado en el manifest para
+/// reutilizarlo sin reprocesarlo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedExport {
+    index: ExportFileEntry,
+    metadata: Option<serde_json::Value>,
+    bytes: usize,
+    /// Página del sitemap, calculada tras embeds, variables y `--redact`.
+    #[serde(default)]
+    page: Option<crate::core::sitemap::SitePage>,
+}
+
 impl ExportCommand {
     pub fn run(&self, data_dir: &std::path::Path) -> OcResult<ExportResult> {
         use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
-        
-        use std::collections::HashSet;
+
+        let files = get_all_md_files(data_dir, &ScanOptions::new())?;
+        self.check_audience(data_dir, &files)?;
+
+        if self.format_enum() == ExportFormat::JsonLines {
+            if self.incremental {
+                return Err(OcError::Custom(
+                    "--incremental requiere un formato por archivo (no jsonl)".to_string(),
+                ));
+            }
+            let output = self.output.clone().unwrap_or_else(|| PathBuf::from("export.jsonl"));
+            let mut file = std::io::BufWriter::new(std::fs::File::create(&output)?);
+            return self.write_jsonl(data_dir, &mut file, output);
+        }
 
         let output_dir = self
             .output
@@ -171,8 +549,14 @@ impl ExportCommand {
         // Crear directorio de salida
         std::fs::create_dir_all(&output_dir)?;
 
-        let options = ScanOptions::new();
-        let files = get_all_md_files(data_dir, &options)?;
+        let branch = self.branch(&files)?;
+        if let Some(ref branch) = branch {
+            result.linked_included = branch.linked.len();
+        }
+        let redactor = self.redactor()?;
+        let transcluder = self.transcluder(&files, redactor.as_ref());
+        let template = self.template(&files, redactor.as_ref())?;
+        let variables = Variables::from_cwd();
 
         use crate::core::patterns::{RE_MODULE, RE_TITLE};
         let module_regex = &*RE_MODULE;
@@ -182,7 +566,20 @@ impl ExportCommand {
         let mut index_entries: Vec<ExportFileEntry> = Vec::new();
         let mut metadata_collection: Vec<serde_json::Value> = Vec::new();
 
+        let manifest_path = output_dir.join(MANIFEST_FILE);
+        let mut manifest = self.incremental.then(|| BuildManifest::new(self.options_hash()));
+        let previous = manifest
+            .as_ref()
+            .and_then(|m| BuildManifest::previous(&manifest_path, &m.options));
+        let mut incremental = IncrementalStats::default();
+        // Páginas publicadas con su nombre de archivo (sitemap y redirects)
+        let web = self.sitemap || self.redirects.is_some();
+        let mut pages: Vec<(String, crate::core::sitemap::SitePage)> = Vec::new();
+
         for file_path in &files {
+            if branch.as_ref().is_some_and(|b| !b.contains(file_path)) {
+                continue;
+            }
             let file_name = file_path
                 .file_name()
                 .and_then(|n| n.to_str())
@@ -192,7 +589,49 @@ impl ExportCommand {
                 .and_then(|s| s.to_str())
                 .unwrap_or("unknown");
 
-            if let Ok(content) = read_file_content(file_path) {
+            if let Ok(mut content) = read_file_content(file_path) {
+                let rel = file_path.strip_prefix(data_dir).unwrap_or(file_path).display().to_string();
+                let content_hash = manifest.as_ref().map(|_| {
+                    let embedded = transcluder
+                        .as_ref()
+                        .map(|t| t.sources(&content, file_stem))
+                        .unwrap_or_default();
+                    match template {
+                        Some((_, ref index)) => sources_hash(&(content.clone() + &index.neighbors(file_stem)), &embedded),
+                        None => sources_hash(&content, &embedded),
+                    }
+                });
+
+                // Incremental: mismas fuentes y salida en disco → reutilizar
+                let cached = previous
+                    .as_ref()
+                    .zip(content_hash.as_deref())
+                    .and_then(|(previous, hash)| previous.fresh(&rel, hash, &output_dir))
+                    .and_then(|entry| Some((entry, serde_json::from_value::<CachedExport>(entry.data.clone()).ok()?)))
+                    .filter(|(_, cached)| !web || cached.page.is_some());
+                if let (Some((entry, cached)), Some(manifest)) = (cached, manifest.as_mut()) {
+                    if let Some(ref m) = cached.index.module {
+                        modules_found.insert(m.clone());
+                    }
+                    if let Some(page) = cached.page {
+                        pages.push((file_stem.to_string(), page));
+                    }
+                    result.files_exported += 1;
+                    result.total_bytes += cached.bytes;
+                    index_entries.push(cached.index);
+                    metadata_collection.extend(cached.metadata);
+                    manifest.docs.insert(rel, entry.clone());
+                    incremental.reused += 1;
+                    continue;
+                }
+
+                if let Some(ref transcluder) = transcluder {
+                    content = transcluder.expand(&content, file_stem, &mut result.embeds);
+                }
+                content = variables.resolve(&content, &mut result.unresolved_variables);
+                if let Some(ref redactor) = redactor {
+                    content = redactor.redact(&content, &mut result.redactions);
+                }
                 // Extraer módulo
                 let module = module_regex
                     .captures(&content)
@@ -226,13 +665,22 @@ impl ExportCommand {
                 };
 
                 let word_count = content.split_whitespace().count();
+                let page = web.then(|| crate::core::sitemap::SitePage::from_doc(file_stem, &content));
+                if let Some(ref page) = page {
+                    pages.push((file_stem.to_string(), page.clone()));
+                }
 
-                // L11.1: Copiar archivo
+                // L11.1: Copiar archivo (o renderizarlo con --template)
+                let rendered = match template {
+                    Some((ref source, ref index)) => Some(crate::core::hbs::render(source, &index.data(file_stem, &content))?),
+                    None => None,
+                };
+                let output = rendered.as_deref().unwrap_or(&content);
                 let dest_path = output_dir.join(&exported_name);
-                std::fs::write(&dest_path, &content)?;
+                std::fs::write(&dest_path, output)?;
 
                 result.files_exported += 1;
-                result.total_bytes += content.len();
+                result.total_bytes += output.len();
 
                 // L11.3: Agregar a índice
                 index_entries.push(ExportFileEntry {
@@ -243,23 +691,50 @@ impl ExportCommand {
                 });
 
                 // L11.4 / L12.2: Metadata para JSON
-                if self.include_metadata {
+                let metadata = self.include_metadata.then(|| {
                     let title = title_regex
                         .captures(&content)
                         .map(|cap| cap[1].trim().to_string())
                         .unwrap_or_else(|| file_stem.to_string());
 
-                    metadata_collection.push(serde_json::json!({
+                    serde_json::json!({
                         "id": file_stem,
                         "title": title,
                         "module": module,
                         "word_count": word_count,
                         "exported_as": exported_name
-                    }));
+                    })
+                });
+                metadata_collection.extend(metadata.clone());
+
+                if let (Some(manifest), Some(content_hash)) = (manifest.as_mut(), content_hash) {
+                    let cached = CachedExport {
+                        index: index_entries[index_entries.len() - 1].clone(),
+                        metadata,
+                        bytes: output.len(),
+                        page,
+                    };
+                    manifest.docs.insert(
+                        rel,
+                        ManifestEntry {
+                            content_hash,
+                            output: exported_name,
+                            data: serde_json::to_value(cached)?,
+                        },
+                    );
+                    incremental.rebuilt += 1;
                 }
             }
         }
 
+        if let Some(manifest) = manifest {
+            if let Some(previous) = previous {
+                incremental.removed = previous.remove_stale(&manifest, &output_dir);
+            }
+            manifest.save(&manifest_path)?;
+            result.incremental = Some(incremental);
+        }
+
         // L11.3: Generar índice
         let index = ExportIndex {
             exported_at: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
@@ -277,6 +752,10 @@ impl ExportCommand {
             std::fs::write(output_dir.join("_metadata.json"), &meta_json)?;
         }
 
+        if let (true, Some(base_url)) = (web, self.base_url.as_deref()) {
+            self.write_web(data_dir, &output_dir, base_url, &pages, &mut result)?;
+        }
+
         // L12.1: Crear ZIP si se pidió
         if self.zip {
             self.create_zip(&output_dir, &result)?;
@@ -285,6 +764,92 @@ impl ExportCommand {
         Ok(result)
     }
 
+    /// `--format jsonl`: un [`JsonlDocument`] por línea en `out`, con los
+    /// mismos filtros (`--root`, `--modules`) y el mismo procesado (embeds,
+    /// variables, `--redact`) que el export por archivos.
+    pub fn write_jsonl(
+        &self,
+        data_dir: &Path,
+        out: &mut dyn std::io::Write,
+        output_path: PathBuf,
+    ) -> OcResult<ExportResult> {
+        use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+        use crate::core::links::extract_links;
+        use crate::core::yaml::count_words;
+        use crate::types::breadcrumb::frontmatter_value;
+        use crate::types::lifetimes::split_frontmatter;
+        use crate::types::ContentHash;
+
+        let mut result = ExportResult::new(output_path, &self.format);
+        let files = get_all_md_files(data_dir, &ScanOptions::new())?;
+        let branch = self.branch(&files)?;
+        if let Some(ref branch) = branch {
+            result.linked_included = branch.linked.len();
+        }
+        let redactor = self.redactor()?;
+        let transcluder = self.transcluder(&files, redactor.as_ref());
+        let variables = Variables::from_cwd();
+
+        for file_path in &files {
+            if branch.as_ref().is_some_and(|b| !b.contains(file_path)) {
+                continue;
+            }
+            let Ok(raw) = read_file_content(file_path) else {
+                continue;
+            };
+            let name = file_path.file_name().and_then(|n| n.to_str()).unwrap_or("unknown.md");
+            // file_stem cortaría "1.2 baja" en el primer punto
+            let stem = name.strip_suffix(".md").unwrap_or(name);
+            if !self.module_selected(&raw, stem) {
+                continue;
+            }
+
+            let mut content = raw.clone();
+            if let Some(ref transcluder) = transcluder {
+                content = transcluder.expand(&content, stem, &mut result.embeds);
+            }
+            content = variables.resolve(&content, &mut result.unresolved_variables);
+            if let Some(ref redactor) = redactor {
+                content = redactor.redact(&content, &mut result.redactions);
+            }
+
+            let split = split_frontmatter(&content);
+            let (metadata, body) = split.map_or(("", content.as_str()), |s| (s.metadata, s.content));
+            let frontmatter = serde_yaml::from_str::<serde_yaml::Value>(metadata)
+                .ok()
+                .filter(|v| !v.is_null())
+                .and_then(|v| serde_json::to_value(v).ok())
+                .unwrap_or(serde_json::Value::Null);
+
+            let mut links: Vec<String> = Vec::new();
+            for link in extract_links(&raw) {
+                if !link.is_internal() || link.is_embed() {
+                    continue;
+                }
+                let target = link.target.split('#').next().unwrap_or_default().trim().to_string();
+                if !target.is_empty() && !links.contains(&target) {
+                    links.push(target);
+                }
+            }
+
+            let document = JsonlDocument {
+                id: frontmatter_value(metadata, "id").unwrap_or_else(|| stem.to_string()),
+                file: file_path.strip_prefix(data_dir).unwrap_or(file_path).display().to_string(),
+                frontmatter,
+                body: body.to_string(),
+                word_count: count_words(body),
+                links,
+                hash: ContentHash::compute(&raw).full().to_string(),
+            };
+            let line = serde_json::to_string(&document)?;
+            writeln!(out, "{}", line)?;
+            result.files_exported += 1;
+            result.total_bytes += line.len() + 1;
+        }
+        out.flush()?;
+        Ok(result)
+    }
+
     /// L12.1: Crea archivo ZIP de la exportación.
     fn create_zip(&self, output_dir: &PathBuf, _result: &ExportResult) -> OcResult<()> {
         use crate::errors::OcError;
@@ -324,6 +889,196 @@ impl ExportCommand {
         Ok(())
     }
 
+    /// Selección por `--root` / `--include-linked` (None = todo el proyecto).
+    pub fn branch(&self, files: &[PathBuf]) -> OcResult<Option<BranchSelection>> {
+        self.root
+            .as_deref()
+            .map(|root| {
+                let depth = self.include_linked.then_some(self.link_depth);
+                BranchSelection::select(files, root, depth)
+            })
+            .transpose()
+    }
+
+    /// Filtro `--modules`: por el campo `module` o por el prefijo del nombre.
+    fn module_selected(&self, content: &str, stem: &str) -> bool {
+        use crate::core::patterns::RE_MODULE;
+
+        let Some(ref filter_modules) = self.modules else {
+            return true;
+        };
+        let module = RE_MODULE.captures(content).map(|cap| cap[1].trim().to_string());
+        module.is_some_and(|m| filter_modules.iter().any(|f| m.contains(f)))
+            || filter_modules.iter().any(|f| stem.starts_with(f))
+    }
+
+    /// `--audience`: documentos del export (con la misma selección de
+    /// `--root` y `--modules`) o embebidos por ellos cuya clasificación
+    /// supera la audiencia. Se llama antes de escribir nada.
+    pub fn check_audience(&self, data_dir: &Path, files: &[PathBuf]) -> OcResult<()> {
+        use crate::core::classification::{Classification, CLASSIFICATIONS};
+        use crate::core::files::read_file_content;
+
+        let Some(ref audience) = self.audience else {
+            return Ok(());
+        };
+        let audience = Classification::parse(audience).ok_or_else(|| {
+            OcError::InvalidArgument(format!(
+                "--audience inválida: '{}' (válidas: {})",
+                audience,
+                CLASSIFICATIONS.join(", ")
+            ))
+        })?;
+        let branch = self.branch(files)?;
+        let transcluder = self.transcluder(files, None);
+        let above = |content: &str| Classification::of(content).filter(|level| *level > audience);
+
+        let mut exposed = Vec::new();
+        for file_path in files {
+            if branch.as_ref().is_some_and(|b| !b.contains(file_path)) {
+                continue;
+            }
+            let Ok(content) = read_file_content(file_path) else {
+                continue;
+            };
+            let name = file_path.file_name().and_then(|n| n.to_str()).unwrap_or("unknown.md");
+            let stem = name.strip_suffix(".md").unwrap_or(name);
+            if !self.module_selected(&content, stem) {
+                continue;
+            }
+            let rel = file_path.strip_prefix(data_dir).unwrap_or(file_path).display().to_string();
+            if let Some(level) = above(&content) {
+                exposed.push(format!("{} ({})", rel, level.as_str()));
+            }
+            let embedded = transcluder.as_ref().map(|t| t.embedded(&content, stem)).unwrap_or_default();
+            for (key, doc) in embedded {
+                if let Some(level) = above(doc) {
+                    exposed.push(format!("{} embebe [[{}]] ({})", rel, key, level.as_str()));
+                }
+            }
+        }
+        if exposed.is_empty() {
+            return Ok(());
+        }
+        Err(OcError::Custom(format!(
+            "Export para audiencia '{}' bloqueado: {} documentos la superan\n  - {}",
+            audience.as_str(),
+            exposed.len(),
+            exposed.join("\n  - ")
+        )))
+    }
+
+    /// Redactor de `--redact` con las reglas de config (None sin el flag).
+    pub fn redactor(&self) -> OcResult<Option<Redactor>> {
+        if !self.redact {
+            return Ok(None);
+        }
+        let config = crate::core::config::OcConfig::from_cwd().unwrap_or_default();
+        Redactor::new(&config.redact).map(Some)
+    }
+
+    /// Índice de embeds (None con `--keep-embeds`). Con `--redact`, los
+    /// documentos embebidos se indexan ya redactados para no filtrar datos
+    /// (ni el cuerpo de los `confidential: true`) a través de un embed.
+    pub fn transcluder(&self, files: &[PathBuf], redactor: Option<&Redactor>) -> Option<Transcluder> {
+        use crate::core::files::read_file_content;
+
+        if self.keep_embeds {
+            return None;
+        }
+        let docs = files.iter().filter_map(|f| {
+            let content = read_file_content(f).ok()?;
+            let content = match redactor {
+                Some(redactor) => redactor.redact(&content, &mut BTreeMap::new()),
+                None => content,
+            };
+            Some((f.clone(), content))
+        });
+        Some(Transcluder::new(docs, self.embed_depth))
+    }
+
+    /// Hash de todo lo que afecta la salida además de los documentos: flags,
+    /// versión, las secciones `variables` y `redact` de la config y la
+    /// plantilla de `--template`.
+    fn options_hash(&self) -> String {
+        let config = crate::core::config::OcConfig::from_cwd().unwrap_or_default();
+        let flags = format!("{:?}", Self { output: None, ..self.clone() });
+        let sections = serde_json::to_string(&(&config.variables, &config.redact)).unwrap_or_default();
+        let template = self
+            .template
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .unwrap_or_default();
+        crate::core::incremental::options_hash(&[env!("CARGO_PKG_VERSION"), &flags, &sections, &template])
+    }
+
+    /// `--template`: fuente de la plantilla (con la sintaxis ya validada) e
+    /// índice de hijos y backlinks.
+    pub fn template(&self, files: &[PathBuf], redactor: Option<&Redactor>) -> OcResult<Option<(String, TemplateIndex)>> {
+        let Some(ref path) = self.template else {
+            return Ok(None);
+        };
+        let source = std::fs::read_to_string(path).map_err(|e| OcError::FileRead {
+            path: path.clone(),
+            source: e,
+        })?;
+        crate::core::hbs::render(&source, &serde_json::Value::Null)?;
+        Ok(Some((source, TemplateIndex::build(files, redactor))))
+    }
+
+    /// `--sitemap` / `--redirects`: sitemap, navegación y redirects del sitio.
+    fn write_web(
+        &self,
+        data_dir: &Path,
+        output_dir: &Path,
+        base_url: &str,
+        pages: &[(String, crate::core::sitemap::SitePage)],
+        result: &mut ExportResult,
+    ) -> OcResult<()> {
+        use crate::core::redirects::{self, RedirectFormat, Redirects};
+        use crate::core::sitemap::{nav_tree, sitemap_xml, NAV_FILE, SITEMAP_FILE};
+
+        let format = match self.redirects.as_deref() {
+            Some(name) => Some(RedirectFormat::from_str(name).ok_or_else(|| {
+                OcError::InvalidArgument(format!("Formato de redirects inválido: '{}' (netlify, html)", name))
+            })?),
+            None => None,
+        };
+        let slug_redirects = crate::core::slugs::SlugRegistry::load(data_dir).redirects;
+        let pairs = Redirects::load(&redirects::redirects_path()).site_pairs(&slug_redirects, pages);
+
+        if self.sitemap {
+            let pages: Vec<_> = pages.iter().map(|(_, page)| page.clone()).collect();
+            std::fs::write(output_dir.join(SITEMAP_FILE), sitemap_xml(&pages, base_url))?;
+            std::fs::write(
+                output_dir.join(NAV_FILE),
+                serde_json::to_string_pretty(&nav_tree(&pages, base_url))?,
+            )?;
+            result.sitemap_pages = Some(pages.len());
+            if !pairs.is_empty() {
+                std::fs::write(
+                    output_dir.join(redirects::REDIRECTS_FILE),
+                    serde_json::to_string_pretty(&redirects::redirect_urls(&pairs, base_url))?,
+                )?;
+            }
+        }
+        match format {
+            Some(RedirectFormat::Netlify) => {
+                std::fs::write(output_dir.join(redirects::NETLIFY_FILE), redirects::netlify(&pairs, base_url))?;
+            }
+            Some(RedirectFormat::Html) => {
+                for ((from, _), redirect) in pairs.iter().zip(redirects::redirect_urls(&pairs, base_url)) {
+                    let dir = output_dir.join(from);
+                    std::fs::create_dir_all(&dir)?;
+                    std::fs::write(dir.join("index.html"), redirects::meta_refresh(&redirect.to))?;
+                }
+            }
+            None => {}
+        }
+        result.redirects = pairs.len();
+        Ok(())
+    }
+
     pub fn format_enum(&self) -> ExportFormat {
         ExportFormat::from_str(&self.format).unwrap_or(ExportFormat::Markdown)
     }
@@ -370,9 +1125,310 @@ mod tests {
             compact: false,
             tree: false,
             stats: false,
+            root: None,
+            include_linked: false,
+            link_depth: 0,
+            redact: false,
+            audience: None,
+            keep_embeds: false,
+            embed_depth: DEFAULT_EMBED_DEPTH,
+            incremental: false,
+            sitemap: false,
+            base_url: None,
+            redirects: None,
         };
         assert_eq!(cmd.format_enum(), ExportFormat::Latex);
     }
+
+    fn branch_fixture() -> (tempfile::TempDir, Vec<PathBuf>) {
+        let dir = tempfile::tempdir().unwrap();
+        let docs = [
+            ("2.5 Tema.md", "Ver [[2.5.1 Detalle]] y [[3.1 Glosario]]."),
+            ("2.5.1 Detalle.md", "Sin enlaces."),
+            ("2.50 Otro.md", "No es hijo de 2.5."),
+            ("3.1 Glosario.md", "Ver [[4.2 Anexo|anexo]]."),
+            ("4.2 Anexo.md", "Fin."),
+            ("5.1 Suelto.md", "Nadie lo enlaza."),
+        ];
+        let files = docs
+            .iter()
+            .map(|(name, body)| {
+                let path = dir.path().join(name);
+                std::fs::write(&path, body).unwrap();
+                path
+            })
+            .collect();
+        (dir, files)
+    }
+
+    fn names(set: &HashSet<PathBuf>) -> Vec<String> {
+        let mut v: Vec<String> = set
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        v.sort();
+        v
+    }
+
+    #[test]
+    fn test_branch_subtree_only() {
+        let (_dir, files) = branch_fixture();
+        let sel = BranchSelection::select(&files, "2.5", None).unwrap();
+        assert_eq!(names(&sel.subtree), vec!["2.5 Tema.md", "2.5.1 Detalle.md"]);
+        assert!(sel.linked.is_empty());
+        assert!(BranchSelection::select(&files, "9", None).is_err());
+    }
+
+    #[test]
+    fn test_branch_linked_closure_with_depth() {
+        let (_dir, files) = branch_fixture();
+        let all = BranchSelection::select(&files, "2.5", Some(0)).unwrap();
+        assert_eq!(names(&all.linked), vec!["3.1 Glosario.md", "4.2 Anexo.md"]);
+
+        let one = BranchSelection::select(&files, "2.5", Some(1)).unwrap();
+        assert_eq!(names(&one.linked), vec!["3.1 Glosario.md"]);
+    }
+
+    #[test]
+    fn test_export_root_include_linked() {
+        let (dir, _files) = branch_fixture();
+        let out = dir.path().join("out");
+        let cmd = ExportCommand::parse_from([
+            "export",
+            "--root",
+            "2.5",
+            "--include-linked",
+            "-o",
+            out.to_str().unwrap(),
+        ]);
+        let result = cmd.run(dir.path()).unwrap();
+        assert_eq!(result.files_exported, 4);
+        assert_eq!(result.linked_included, 2);
+        assert!(!out.join("5.1 Suelto.md").exists());
+    }
+
+    #[test]
+    fn test_export_jsonl() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc = "---\nid: \"1.2\"\ntags: [pagos, alta]\nchildren_count: 0\n---\n# Baja\n\nVer [[1.1 alta#Datos]], [[1.1 alta]] y [web](https://x.com).\n";
+        std::fs::write(dir.path().join("1.2 baja.md"), doc).unwrap();
+        std::fs::write(dir.path().join("1.1 alta.md"), "Sin frontmatter.\n").unwrap();
+        let out = dir.path().join("docs.jsonl");
+        let cmd = ExportCommand::parse_from(["export", "--format", "jsonl", "-o", out.to_str().unwrap()]);
+        let result = cmd.run(dir.path()).unwrap();
+        assert_eq!(result.files_exported, 2);
+
+        let lines: Vec<serde_json::Value> = std::fs::read_to_string(&out)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        let baja = lines.iter().find(|d| d["id"] == "1.2").unwrap();
+        assert_eq!(baja["file"], "1.2 baja.md");
+        assert_eq!(baja["frontmatter"]["tags"], serde_json::json!(["pagos", "alta"]));
+        assert_eq!(baja["frontmatter"]["children_count"], 0);
+        assert!(baja["body"].as_str().unwrap().starts_with("# Baja"));
+        assert_eq!(baja["links"], serde_json::json!(["1.1 alta"]));
+        assert_eq!(baja["hash"].as_str().unwrap().len(), 64);
+        let alta = lines.iter().find(|d| d["id"] == "1.1 alta").unwrap();
+        assert!(alta["frontmatter"].is_null());
+        assert_eq!(alta["word_count"], 2);
+    }
+
+    #[test]
+    fn test_export_redact() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1.1 Contacto.md"), "Contacto: ana@empresa.com\n").unwrap();
+        let out = dir.path().join("out");
+        let cmd = ExportCommand::parse_from(["export", "--redact", "-o", out.to_str().unwrap()]);
+        let result = cmd.run(dir.path()).unwrap();
+        assert_eq!(result.redactions["email"], 1);
+        let exported = std::fs::read_to_string(out.join("1.1 Contacto.md")).unwrap();
+        assert_eq!(exported, "Contacto: [EMAIL]\n");
+    }
+
+    #[test]
+    fn test_export_resolves_variables() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1 hoy.md"), "Fecha: {{today}} {{cliente}}\n").unwrap();
+        let out = dir.path().join("out");
+        let cmd = ExportCommand::parse_from(["export", "-o", out.to_str().unwrap()]);
+        let result = cmd.run(dir.path()).unwrap();
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        let exported = std::fs::read_to_string(out.join("1 hoy.md")).unwrap();
+        assert_eq!(exported, format!("Fecha: {} {{{{cliente}}}}\n", today));
+        assert!(result.unresolved_variables.contains("cliente"));
+    }
+
+    #[test]
+    fn test_export_expands_embeds() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1 base.md"), "# Base\n\n## Datos\n\nana@empresa.com\n").unwrap();
+        std::fs::write(dir.path().join("2 uso.md"), "Ver ![[1 base#Datos]]\n").unwrap();
+        let out = dir.path().join("out");
+        let cmd = ExportCommand::parse_from(["export", "--redact", "-o", out.to_str().unwrap()]);
+        let result = cmd.run(dir.path()).unwrap();
+        assert_eq!(result.embeds.expanded, 1);
+        let exported = std::fs::read_to_string(out.join("2 uso.md")).unwrap();
+        assert_eq!(exported, "Ver <!-- embed: 1 base#Datos -->\n## Datos\n\n[EMAIL]\n<!-- /embed: 1 base#Datos -->\n");
+
+        let raw = ExportCommand::parse_from(["export", "--keep-embeds", "-o", out.to_str().unwrap()]);
+        raw.run(dir.path()).unwrap();
+        assert_eq!(std::fs::read_to_string(out.join("2 uso.md")).unwrap(), "Ver ![[1 base#Datos]]\n");
+    }
+
+    #[test]
+    fn test_export_audience() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1 manual.md"), "---\nclassification: public\n---\nVer ![[2 costos#Margen]]\n").unwrap();
+        std::fs::write(dir.path().join("2 costos.md"), "---\nclassification: confidential\n---\n## Margen\n\n40%\n").unwrap();
+        std::fs::write(dir.path().join("3 equipo.md"), "---\nclassification: internal\n---\nGuardias\n").unwrap();
+        let out = dir.path().join("out");
+        let export = |args: &[&str]| {
+            let mut argv = vec!["export", "-o", out.to_str().unwrap()];
+            argv.extend_from_slice(args);
+            ExportCommand::parse_from(argv).run(dir.path())
+        };
+
+        let err = export(&["--audience", "public"]).unwrap_err().to_string();
+        assert!(err.contains("3 documentos"), "{}", err);
+        assert!(err.contains("1 manual.md embebe [[2 costos]] (confidential)"), "{}", err);
+        assert!(!out.exists());
+
+        // Sin expandir embeds, y sin los documentos de más nivel, pasa
+        assert!(export(&["--audience", "internal", "--modules", "1", "--keep-embeds"]).is_ok());
+        assert!(export(&["--audience", "internal", "--modules", "1"]).is_err());
+        assert!(export(&["--audience", "confidential"]).is_ok());
+        assert!(export(&["--audience", "todos"]).is_err());
+    }
+
+    #[test]
+    fn test_export_sitemap() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1 Inicio.md"), "---\nid: \"1\"\ntitle: \"Inicio\"\n---\n# Inicio\n").unwrap();
+        std::fs::write(dir.path().join("1.1 Alta.md"), "---\nid: \"1.1\"\nparent: \"1\"\ntitle: \"Alta\"\n---\n").unwrap();
+        std::fs::write(dir.path().join("2 Otro.md"), "---\nid: \"2\"\n---\n").unwrap();
+        let out = dir.path().join("out");
+        let argv = ["export", "-o", out.to_str().unwrap(), "--modules", "1", "--sitemap", "--base-url", "https://docs.x.mx"];
+        let mut registry = crate::core::slugs::SlugRegistry::default();
+        registry.redirects.insert("alta-vieja".into(), "1-1-alta".into());
+        registry.save(dir.path()).unwrap();
+
+        let result = ExportCommand::parse_from(argv).run(dir.path()).unwrap();
+        assert_eq!(result.sitemap_pages, Some(2));
+        let xml = std::fs::read_to_string(out.join("sitemap.xml")).unwrap();
+        assert!(xml.contains("<loc>https://docs.x.mx/1-1-alta</loc>"));
+        let nav: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(out.join("nav.json")).unwrap()).unwrap();
+        assert_eq!(nav[0]["children"][0]["title"], "Alta");
+        let redirects = std::fs::read_to_string(out.join("redirects.json")).unwrap();
+        assert!(redirects.contains("\"https://docs.x.mx/alta-vieja\""));
+        assert!(ExportCommand::try_parse_from(["export", "--sitemap"]).is_err());
+
+        let static_site = |format: &str| {
+            let argv = ["export", "-o", out.to_str().unwrap(), "--redirects", format, "--base-url", "https://docs.x.mx/v2"];
+            ExportCommand::parse_from(argv).run(dir.path())
+        };
+        assert_eq!(static_site("netlify").unwrap().redirects, 1);
+        let netlify = std::fs::read_to_string(out.join("_redirects")).unwrap();
+        assert_eq!(netlify, "/v2/alta-vieja /v2/1-1-alta 301\n");
+        static_site("html").unwrap();
+        let page = std::fs::read_to_string(out.join("alta-vieja").join("index.html")).unwrap();
+        assert!(page.contains("url=https://docs.x.mx/v2/1-1-alta"));
+        assert!(static_site("apache").is_err());
+    }
+
+    #[test]
+    fn test_export_incremental() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("Datos");
+        std::fs::create_dir(&data).unwrap();
+        std::fs::write(data.join("1 base.md"), "# Base\n\nv1\n").unwrap();
+        std::fs::write(data.join("2 uso.md"), "Ver ![[1 base]]\n").unwrap();
+        std::fs::write(data.join("3 solo.md"), "Independiente\n").unwrap();
+        let out = dir.path().join("out");
+        let cmd = ExportCommand::parse_from(["export", "--incremental", "-o", out.to_str().unwrap()]);
+
+        let first = cmd.run(&data).unwrap().incremental.unwrap();
+        assert_eq!((first.rebuilt, first.reused), (3, 0));
+
+        // Cambia un documento embebido: se regeneran él y quien lo embebe
+        std::fs::write(data.join("1 base.md"), "# Base\n\nv2\n").unwrap();
+        std::fs::remove_file(data.join("3 solo.md")).unwrap();
+        let result = cmd.run(&data).unwrap();
+        let second = result.incremental.unwrap();
+        assert_eq!((second.rebuilt, second.reused, second.removed), (2, 0, 1));
+        assert_eq!(result.files_exported, 2);
+        assert!(std::fs::read_to_string(out.join("2 uso.md")).unwrap().contains("v2"));
+        assert!(!out.join("3 solo.md").exists());
+
+        let third = cmd.run(&data).unwrap().incremental.unwrap();
+        assert_eq!((third.rebuilt, third.reused), (0, 2));
+        let index = std::fs::read_to_string(out.join("_index.json")).unwrap();
+        assert!(index.contains("\"total_files\": 2"));
+    }
+
+    #[test]
+    fn test_export_incremental_sitemap() {
+        let project = crate::testing::TestProject::default();
+        project.write("1 Plan.md", "---\nid: \"1\"\ntitle: \"Plan {{today}}\"\n---\n# Plan\n");
+        project.write("1.1 Alta.md", "---\nid: \"1.1\"\nparent: \"1\"\ntitle: \"Alta\"\n---\n");
+        let out = project.path().join("out");
+        let argv = ["export", "--incremental", "--sitemap", "--base-url", "https://docs.x.mx", "-o", out.to_str().unwrap()];
+        let cmd = ExportCommand::parse_from(argv);
+        let nav = || std::fs::read_to_string(out.join("nav.json")).unwrap();
+
+        cmd.run(&project.data_dir).unwrap();
+        let first = nav();
+        assert!(!first.contains("{{today}}"), "{}", first);
+        let second = cmd.run(&project.data_dir).unwrap();
+        assert_eq!(second.incremental.unwrap().reused, 2);
+        assert_eq!(nav(), first);
+    }
+
+    #[test]
+    fn test_export_template() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("Datos");
+        std::fs::create_dir(&data).unwrap();
+        std::fs::write(
+            data.join("1 flota.md"),
+            "---\nid: \"1\"\ntitle: \"Flota\"\nlast_updated: \"2025-03-07\"\ntags: [a, b]\n---\n# Flota\n\n## Alcance\n",
+        )
+        .unwrap();
+        std::fs::write(
+            data.join("1.1 alta.md"),
+            "---\nid: \"1.1\"\ntitle: \"Alta de unidad\"\nparent: \"1\"\n---\nVer [[1 flota]].\n",
+        )
+        .unwrap();
+        let template = dir.path().join("doc.hbs");
+        std::fs::write(
+            &template,
+            "{{title}} ({{slugify title}}) {{formatDate last_updated \"%d/%m/%Y\"}}{{#each tags}} #{{this}}{{/each}}\n\
+             {{#each children}}hijo: {{id}} {{title}}\n{{/each}}{{#each backlinks}}enlazado desde: {{file}}\n{{/each}}{{{toc body}}}",
+        )
+        .unwrap();
+        let out = dir.path().join("out");
+        let cmd = ExportCommand::parse_from([
+            "export",
+            "--template",
+            template.to_str().unwrap(),
+            "-o",
+            out.to_str().unwrap(),
+        ]);
+
+        cmd.run(&data).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(out.join("1 flota.md")).unwrap(),
+            "Flota (flota) 07/03/2025 #a #b\nhijo: 1.1 Alta de unidad\nenlazado desde: 1.1 alta.md\n- [Flota](#flota)\n  - [Alcance](#alcance)\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(out.join("1.1 alta.md")).unwrap(),
+            "Alta de unidad (alta-de-unidad) \n"
+        );
+
+        std::fs::write(&template, "{{#each children}}").unwrap();
+        assert!(cmd.run(&data).unwrap_err().to_string().contains("sin cerrar"));
+    }
 }
 
 /// Función run para CLI.
@@ -381,12 +1437,10 @@ pub fn run(cmd: ExportCommand, cli: &crate::commands::CliConfig) -> anyhow::Resu
     let default_dir = PathBuf::from(&cli.data_dir);
     let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
 
-    // F6: Notificar plantilla si se usa
+    // F6: Notificar plantilla si se usa (si falta, el export falla)
     if let Some(ref template_path) = cmd.template {
         if template_path.exists() {
             println!("📝 Usando plantilla: {}", template_path.display());
-        } else {
-            println!("⚠️  Plantilla no encontrada: {}", template_path.display());
         }
     }
 
@@ -398,6 +1452,15 @@ pub fn run(cmd: ExportCommand, cli: &crate::commands::CliConfig) -> anyhow::Resu
 
         let options = ScanOptions::new();
         let files = get_all_md_files(data_dir, &options)?;
+        cmd.check_audience(data_dir, &files)?;
+        let branch = cmd.branch(&files)?;
+        let redactor = cmd.redactor()?;
+        let transcluder = cmd.transcluder(&files, redactor.as_ref());
+        let template = cmd.template(&files, redactor.as_ref())?;
+        let mut redactions = BTreeMap::new();
+        let mut embeds = TranscludeStats::default();
+        let variables = Variables::from_cwd();
+        let mut unresolved = BTreeSet::new();
 
         let mut total_content = String::new();
         let mut files_included = 0;
@@ -410,11 +1473,27 @@ pub fn run(cmd: ExportCommand, cli: &crate::commands::CliConfig) -> anyhow::Resu
         total_content.push_str("---\n\n");
 
         for file_path in &files {
-            if let Ok(content) = read_file_content(file_path) {
+            if branch.as_ref().is_some_and(|b| !b.contains(file_path)) {
+                continue;
+            }
+            if let Ok(mut content) = read_file_content(file_path) {
                 let name = file_path
                     .file_stem()
                     .and_then(|s| s.to_str())
                     .unwrap_or("documento");
+                if let Some(ref transcluder) = transcluder {
+                    content = transcluder.expand(&content, name, &mut embeds);
+                }
+                content = variables.resolve(&content, &mut unresolved);
+                if let Some(ref redactor) = redactor {
+                    content = redactor.redact(&content, &mut redactions);
+                }
+                if let Some((ref source, ref index)) = template {
+                    total_content.push_str(&crate::core::hbs::render(source, &index.data(name, &content))?);
+                    total_content.push_str("\n\n---\n\n");
+                    files_included += 1;
+                    continue;
+                }
 
                 total_content.push_str(&format!("## {}\n\n", name));
 
@@ -447,6 +1526,19 @@ pub fn run(cmd: ExportCommand, cli: &crate::commands::CliConfig) -> anyhow::Resu
             output_path.display()
         );
         println!("💾 {} bytes escritos", total_content.len());
+        print_redactions(&redactions);
+        print_embeds(&embeds);
+        print_unresolved_variables(&unresolved);
+        return Ok(());
+    }
+
+    // JSON Lines sin --output: a stdout, para encadenar con jq
+    if cmd.format_enum() == ExportFormat::JsonLines && cmd.output.is_none() {
+        use crate::core::files::{get_all_md_files, ScanOptions};
+        cmd.check_audience(data_dir, &get_all_md_files(data_dir, &ScanOptions::new())?)?;
+        let stdout = std::io::stdout();
+        let result = cmd.write_jsonl(data_dir, &mut stdout.lock(), PathBuf::from("-"))?;
+        eprintln!("📤 {} documentos en JSON Lines", result.files_exported);
         return Ok(());
     }
 
@@ -459,6 +1551,50 @@ pub fn run(cmd: ExportCommand, cli: &crate::commands::CliConfig) -> anyhow::Resu
         "📊 {} archivos, {} bytes",
         result.files_exported, result.total_bytes
     );
+    if let Some(ref root) = cmd.root {
+        println!(
+            "🌿 Rama {}: {} documentos enlazados incluidos",
+            root, result.linked_included
+        );
+    }
+    if let Some(ref incremental) = result.incremental {
+        println!("♻️  Incremental: {}", incremental.summary());
+    }
+    if let Some(pages) = result.sitemap_pages {
+        println!("🗺️  sitemap.xml y nav.json: {} páginas", pages);
+    }
+    print_redactions(&result.redactions);
+    print_embeds(&result.embeds);
+    print_unresolved_variables(&result.unresolved_variables);
 
     Ok(())
 }
+
+#[cfg(feature = "cli")]
+fn print_redactions(redactions: &BTreeMap<String, usize>) {
+    if redactions.is_empty() {
+        return;
+    }
+    let detail: Vec<String> = redactions.iter().map(|(k, v)| format!("{} {}", v, k)).collect();
+    println!("🕶️  Redactado: {}", detail.join(", "));
+}
+
+#[cfg(feature = "cli")]
+pub(crate) fn print_embeds(embeds: &TranscludeStats) {
+    if embeds.is_empty() {
+        return;
+    }
+    println!(
+        "🧩 Embeds: {} expandidos, {} sin resolver, {} omitidos (ciclo/profundidad)",
+        embeds.expanded, embeds.unresolved, embeds.skipped
+    );
+}
+
+#[cfg(feature = "cli")]
+pub(crate) fn print_unresolved_variables(names: &BTreeSet<String>) {
+    if names.is_empty() {
+        return;
+    }
+    let names: Vec<String> = names.iter().map(|n| format!("{{{{{}}}}}", n)).collect();
+    println!("⚠️  Variables sin definir en config (sección variables): {}", names.join(", "));
+}
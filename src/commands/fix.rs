@@ -1,10 +1,23 @@
 //! Comando fix - Corrección automática de anomalías estructurales.
 //!
 //! RFC-07: Corrige tablas de contenido (Nietos) y otras anomalías.
-
+//!
+//! Cada flag es una fuente de [`Fix`]es; `--all` suma las de lint y sync y
+//! `core::fixes` las aplica por etapa (renombres, contenido, estructura,
+//! metadatos).
+
+use crate::commands::lint::LintCommand;
+use crate::commands::sync::SyncCommand;
+use crate::core::files::{get_all_md_files, ScanOptions};
+use crate::core::fixes::{self, plan_fixes, run_fixes_skipping, write_blocks, FixSource, WriteBlock};
+use crate::core::patch::PatchReview;
 use crate::errors::{OcError, OcResult};
+use crate::quantum::planner::RepairPlan;
+use crate::traits::{self, Fix, FixStage, Fixable};
+use crate::types::{OcDate, OcTimezone};
 use clap::Parser;
 use regex::Regex;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -28,6 +41,12 @@ pub struct FixResult {
     pub files_fixed: usize,
     pub rows_updated: usize,
     pub changes: Vec<FixChange>,
+    /// Fixes que no se pudieron aplicar (motivo).
+    pub failures: Vec<String>,
+    /// Fixes omitidos por `--skip-readonly` (archivo y motivo).
+    pub skipped: Vec<String>,
+    /// Documentos renombrados por `--filenames` (anterior, nuevo).
+    pub renames: Vec<(PathBuf, PathBuf)>,
 }
 
 impl FixResult {
@@ -60,10 +79,57 @@ pub struct FixCommand {
     #[arg(long, help = "Recalcular campo content_hash basado en el contenido actual")]
     pub hashes: bool,
 
+    /// Regenerar breadcrumbs desde la cadena real de parents.
+    #[arg(long, help = "Regenerar breadcrumbs canónicos desde la cadena de parents")]
+    pub breadcrumbs: bool,
+
+    /// Sincronizar descendants_count con el total recursivo de descendientes.
+    #[arg(long, help = "Sincronizar descendants_count con el total real de descendientes")]
+    pub descendants: bool,
+
+    /// Sincronizar children_count con los hijos directos.
+    #[arg(long, help = "Sincronizar children_count con la cantidad real de hijos directos")]
+    pub children: bool,
+
+    #[arg(long, help = "Renombrar archivos a \"{id} {titulo_slug}.md\" y actualizar los wiki-links entrantes")]
+    pub filenames: bool,
+
+    #[arg(long, help = "Reapuntar [[Doc#^id]] y ![[Doc^id]] al documento que hoy define el bloque")]
+    pub blocks: bool,
+
+    /// Reescribir las fechas del frontmatter al formato canónico.
+    #[arg(long, help = "Reescribir las fechas del frontmatter al formato canónico (--format o validation.date_format)")]
+    pub normalize_dates: bool,
+
+    /// Formato canónico para --normalize-dates (strftime, ej. "%Y-%m-%d %H:%M").
+    #[arg(long, value_name = "FMT")]
+    pub format: Option<String>,
+
+    /// Aplicar los fixes de todos los subsistemas en orden de dependencia.
+    #[arg(long, help = "Aplicar todos los fixes: nombres, bloques movidos, lint, tablas, breadcrumbs, descendants_count y sync")]
+    pub all: bool,
+
+    /// Mostrar el plan de reparación (orden topológico de los fixes) antes de ejecutar.
+    #[arg(long)]
+    pub plan: bool,
+
     /// Modo dry-run: mostrar cambios sin aplicar.
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Mostrar diff coloreado por archivo antes de escribir.
+    #[arg(long)]
+    pub diff: bool,
+
+    /// Aceptar/rechazar cada hunk interactivamente.
+    #[arg(long)]
+    pub interactive: bool,
+
+    /// Omitir los archivos de solo lectura o fuera del repo git en lugar de
+    /// abortar antes de escribir.
+    #[arg(long)]
+    pub skip_readonly: bool,
+
     /// Verbose: mostrar detalles de cada corrección.
     #[arg(short, long)]
     pub verbose: bool,
@@ -73,53 +139,196 @@ pub struct FixCommand {
 // IMPLEMENTACIÓN DE FIXCOMMAND
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Nombre del archivo para los mensajes.
+fn file_name(path: &Path) -> &str {
+    path.file_name().and_then(|n| n.to_str()).unwrap_or("?")
+}
+
 impl FixCommand {
     pub fn run(&self, data_dir: &Path) -> OcResult<FixResult> {
-        use crate::core::files::{get_all_md_files, ScanOptions};
-
         let mut result = FixResult::new();
-        let target = self.path.as_ref().map(|p| p.as_path()).unwrap_or(data_dir);
-
-        let options = ScanOptions::new();
-        let files = get_all_md_files(target, &options)?;
-        result.files_scanned = files.len();
+        result.files_scanned = self.target_files(data_dir)?.len();
+        if self.normalize_dates {
+            OcDate::check_format(&self.date_format())?;
+        }
 
-        if self.tables {
-            // Paso 1: Recolectar todos los IDs de archivos
-            let all_ids = self.collect_all_ids(&files);
-
-            // Paso 2: Para cada archivo con tabla de contenido, corregir Nietos
-            for file_path in &files {
-                match self.fix_nietos_in_file(file_path, &all_ids, self.dry_run) {
-                    Ok(updates) if updates > 0 => {
-                        result.files_fixed += 1;
-                        result.rows_updated += updates;
-                    }
-                    Ok(_) => {}
-                    Err(e) => {
-                        if self.verbose {
-                            eprintln!("⚠ Error procesando {:?}: {}", file_path, e);
-                        }
-                    }
-                }
+        let blocked = self.preflight(data_dir)?;
+        let skip: HashSet<PathBuf> = blocked.iter().map(|(path, _)| path.clone()).collect();
+        let report = run_fixes_skipping(&mut self.sources(data_dir), &skip);
+        let mode = if self.dry_run { "[DRY-RUN] " } else { "" };
+        for fix in &report.applied {
+            // Los enlaces reescritos tras un renombre no cuentan como archivo corregido
+            if fix.is_rename() || fix.stage != FixStage::Rename {
+                result.files_fixed += 1;
+            }
+            if let (Some(from), Some(to)) = (&fix.file, &fix.rename_to) {
+                result.renames.push((from.clone(), to.clone()));
+            }
+            result.rows_updated += fix.changes;
+            if self.verbose || (self.dry_run && fix.is_rename()) {
+                println!("{}{}", mode, fix.description);
+            }
+        }
+        for (_, reason) in &report.failed {
+            eprintln!("⚠ {}", reason);
+            result.failures.push(reason.clone());
+        }
+        for fix in &report.skipped {
+            let block = fix.file.as_ref().and_then(|f| blocked.iter().find(|(path, _)| path == f));
+            if let Some((path, block)) = block {
+                result.skipped.push(format!("{} ({})", file_name(path), block.label()));
             }
         }
 
+        Ok(result)
+    }
+
+    /// Pre-chequeo de escritura: archivos del proyecto de solo lectura o
+    /// fuera del repo git. Si el plan toca alguno, aborta antes de escribir
+    /// nada salvo con `--skip-readonly` o `--dry-run`; los devuelve para
+    /// omitirlos (los fixes de etapas posteriores también).
+    pub fn preflight(&self, data_dir: &Path) -> OcResult<Vec<(PathBuf, WriteBlock)>> {
+        let blocked = write_blocks(&get_all_md_files(data_dir, &ScanOptions::new())?, data_dir);
+        if blocked.is_empty() || self.skip_readonly || self.dry_run {
+            return Ok(blocked);
+        }
+        let plan = self.plan(data_dir);
+        let touched: HashSet<&PathBuf> = plan.steps.iter().filter_map(|s| s.fix.file.as_ref()).collect();
+        let hit: Vec<String> = blocked
+            .iter()
+            .filter(|(path, _)| touched.contains(path))
+            .map(|(path, block)| format!("  {} ({})", path.display(), block.label()))
+            .collect();
+        if !hit.is_empty() {
+            return Err(OcError::Validation {
+                message: format!(
+                    "{} archivos a corregir no se pueden escribir (use --skip-readonly para omitirlos):\n{}",
+                    hit.len(),
+                    hit.join("\n")
+                ),
+            });
+        }
+        Ok(blocked)
+    }
+
+    /// Plan de reparación de los flags activos sobre el estado actual.
+    pub fn plan(&self, data_dir: &Path) -> RepairPlan {
+        plan_fixes(&mut self.sources(data_dir))
+    }
+
+    /// Fuentes de fixes: una por flag activo, para que cada subsistema lea
+    /// lo que dejaron las etapas anteriores. `--all` suma las de lint y sync.
+    pub fn sources(&self, data_dir: &Path) -> Vec<Box<dyn Fixable>> {
+        let none = FixCommand {
+            tables: false,
+            dates: false,
+            hashes: false,
+            breadcrumbs: false,
+            descendants: false,
+            children: false,
+            filenames: false,
+            blocks: false,
+            normalize_dates: false,
+            all: false,
+            ..self.clone()
+        };
+        let flags = [
+            (self.filenames || self.all, FixCommand { filenames: true, ..none.clone() }),
+            (self.blocks || self.all, FixCommand { blocks: true, ..none.clone() }),
+            (self.tables || self.all, FixCommand { tables: true, ..none.clone() }),
+            (self.breadcrumbs || self.all, FixCommand { breadcrumbs: true, ..none.clone() }),
+            (self.descendants || self.all, FixCommand { descendants: true, ..none.clone() }),
+            (self.children, FixCommand { children: true, ..none.clone() }),
+            (self.normalize_dates, FixCommand { normalize_dates: true, ..none.clone() }),
+            (self.dates, FixCommand { dates: true, ..none.clone() }),
+            (self.hashes, FixCommand { hashes: true, ..none }),
+        ];
+
+        let review = self.review();
+        let mut sources: Vec<Box<dyn Fixable>> = flags
+            .into_iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, cmd)| Box::new(FixSource::new(cmd, data_dir, review)) as Box<dyn Fixable>)
+            .collect();
+
+        if self.all {
+            let target = self.path.as_deref().unwrap_or(data_dir);
+            sources.push(Box::new(FixSource::new(
+                LintCommand::parse_from(["lint"]),
+                target,
+                review,
+            )));
+            // Fechas y hashes se recalculan por contenido, después de todo lo demás
+            sources.push(Box::new(FixSource::new(
+                SyncCommand::parse_from(["sync", "--children"]),
+                target,
+                review,
+            )));
+        }
+        sources
+    }
+
+    /// Etapa de los fixes de este comando (un flag por fuente).
+    fn stage(&self) -> FixStage {
+        if self.filenames {
+            FixStage::Rename
+        } else if self.dates || self.hashes || self.normalize_dates {
+            FixStage::Metadata
+        } else if self.breadcrumbs || self.descendants || self.children {
+            FixStage::Structure
+        } else {
+            FixStage::Content
+        }
+    }
+
+    /// Fixes de los flags activos sobre el estado actual del disco.
+    pub fn collect_fixes(&self, data_dir: &Path) -> OcResult<Vec<Fix>> {
+        let files = self.target_files(data_dir)?;
+        // Ancestros, descendientes y enlaces entrantes se buscan en todo el
+        // proyecto aunque --path acote el fix
+        let project = || get_all_md_files(data_dir, &ScanOptions::new());
+
+        let mut fixes = Vec::new();
+        if self.tables {
+            fixes.extend(self.fix_tables(&files)?);
+        }
         // FIX#1: Sincronizar fechas YAML con filesystem
         if self.dates {
-            let (fixed, updated) = self.fix_dates(&files, self.dry_run, self.verbose)?;
-            result.files_fixed += fixed;
-            result.rows_updated += updated;
+            fixes.extend(self.fix_dates(&files)?);
+        }
+        if self.normalize_dates {
+            fixes.extend(self.fix_date_formats(&files)?);
         }
-
         // FIX#2: Recalcular hashes de contenido
         if self.hashes {
-            let (fixed, updated) = self.fix_hashes(&files, self.dry_run, self.verbose)?;
-            result.files_fixed += fixed;
-            result.rows_updated += updated;
+            fixes.extend(self.fix_hashes(&files)?);
+        }
+        if self.breadcrumbs {
+            fixes.extend(self.fix_breadcrumbs(&files, &project()?)?);
+        }
+        if self.descendants {
+            fixes.extend(self.fix_descendants(&files, &project()?)?);
+        }
+        if self.children {
+            fixes.extend(self.fix_children(&files, &project()?)?);
         }
+        if self.filenames {
+            fixes.extend(self.fix_filenames(&files, &project()?)?);
+        }
+        if self.blocks {
+            fixes.extend(self.fix_blocks(&files, &project()?)?);
+        }
+        Ok(fixes)
+    }
 
-        Ok(result)
+    /// Archivos de `--path` (o de todo el directorio de datos).
+    fn target_files(&self, data_dir: &Path) -> OcResult<Vec<PathBuf>> {
+        get_all_md_files(self.path.as_deref().unwrap_or(data_dir), &ScanOptions::new())
+    }
+
+    /// Opciones de revisión de cambios según flags.
+    fn review(&self) -> PatchReview {
+        PatchReview::new(self.diff, self.interactive, self.dry_run)
     }
 
     /// Recolecta todos los IDs de archivos (basado en nombre de archivo).
@@ -161,39 +370,44 @@ impl FixCommand {
         all_ids.iter().filter(|other| re.is_match(other)).count()
     }
 
-    /// Corrige los valores de Nietos en tablas de contenido de un archivo.
-    fn fix_nietos_in_file(
-        &self,
-        path: &PathBuf,
-        all_ids: &[String],
-        dry_run: bool,
-    ) -> OcResult<usize> {
-        let content = fs::read_to_string(path)
-            .map_err(|_| OcError::FileNotFound(path.clone()))?;
-
+    /// Corrige los valores de Nietos en tablas de contenido.
+    fn fix_tables(&self, files: &[PathBuf]) -> OcResult<Vec<Fix>> {
         // Buscar tabla de contenido (patrón: | ID | ... | Nietos | ...)
         let table_regex = Regex::new(r"^\|[^\|]+\|[^\|]+\|[^\|]+\|[^\|]*Nietos[^\|]*\|")
             .map_err(|e| OcError::Custom(format!("Regex error: {}", e)))?;
+        let all_ids = self.collect_all_ids(files);
 
-        if !table_regex.is_match(&content) {
-            return Ok(0); // No hay tabla de Nietos
-        }
-
-        // Parsear y corregir tabla
-        let (new_content, updates) = self.parse_and_fix_table(&content, all_ids)?;
+        let mut fixes = Vec::new();
+        for path in files {
+            let content = match fs::read_to_string(path) {
+                Ok(c) => c,
+                Err(e) => {
+                    if self.verbose {
+                        eprintln!("⚠ Error procesando {:?}: {}", path, e);
+                    }
+                    continue;
+                }
+            };
+            if !table_regex.is_match(&content) {
+                continue; // No hay tabla de Nietos
+            }
 
-        if updates > 0 && !dry_run {
-            fs::write(path, new_content)
-                .map_err(|e| OcError::FileWrite { path: path.clone(), source: e })?;
+            let (new_content, rows) = self.parse_and_fix_table(&content, &all_ids)?;
+            if rows.is_empty() {
+                continue;
+            }
+            let description = format!("📝 {}: Nietos {}", file_name(path), rows.join(", "));
+            fixes.push(Fix::edit("FIX-TABLES", description, path, content, new_content).with_changes(rows.len()));
         }
 
-        Ok(updates)
+        Ok(fixes)
     }
 
-    /// Parsea y corrige una tabla de contenido.
-    fn parse_and_fix_table(&self, content: &str, all_ids: &[String]) -> OcResult<(String, usize)> {
+    /// Parsea y corrige una tabla de contenido. Devuelve el contenido nuevo y
+    /// las filas corregidas (`"1.2 3 → 4"`).
+    fn parse_and_fix_table(&self, content: &str, all_ids: &[String]) -> OcResult<(String, Vec<String>)> {
         let mut lines: Vec<String> = content.lines().map(String::from).collect();
-        let mut updates = 0;
+        let mut updates = Vec::new();
         let mut in_table = false;
         let mut nietos_col = None;
         let mut id_col = None;
@@ -243,14 +457,7 @@ impl FixCommand {
 
                                 let new_line = new_cols.join("|");
                                 lines[i] = new_line;
-                                updates += 1;
-
-                                if self.verbose {
-                                    println!(
-                                        "  📝 {}: Nietos {} → {}",
-                                        id, declared_nietos, actual_nietos
-                                    );
-                                }
+                                updates.push(format!("{} {} → {}", id, declared_nietos, actual_nietos));
                             }
                         }
                     }
@@ -275,18 +482,16 @@ impl FixCommand {
     // FIX#1: SINCRONIZAR FECHAS YAML CON FILESYSTEM
     // ═══════════════════════════════════════════════════════════════════════
 
-    /// FIX#1: Sincroniza el campo last_updated en YAML con la fecha del filesystem.
-    fn fix_dates(
-        &self,
-        files: &[PathBuf],
-        dry_run: bool,
-        verbose: bool,
-    ) -> OcResult<(usize, usize)> {
-        use chrono::{Local, TimeZone};
-        use std::time::UNIX_EPOCH;
+    /// FIX#1: Sincroniza el campo last_updated en YAML con la fecha del
+    /// filesystem, escrita en la zona horaria del proyecto.
+    fn fix_dates(&self, files: &[PathBuf]) -> OcResult<Vec<Fix>> {
+        use chrono::{DateTime, Utc};
 
-        let mut files_fixed = 0;
-        let mut fields_updated = 0;
+        let mut fixes = Vec::new();
+        let tz = OcTimezone::from_cwd();
+
+        let re = Regex::new(r#"last_updated:\s*\"?([^\"\n]+)\"?"#)
+            .map_err(|e| OcError::Custom(format!("Regex error: {}", e)))?;
 
         for path in files {
             let content = match fs::read_to_string(path) {
@@ -294,39 +499,18 @@ impl FixCommand {
                 Err(_) => continue,
             };
 
-            let re = match Regex::new(r#"last_updated:\s*\"?([^\"\n]+)\"?"#) {
-                Ok(r) => r,
-                Err(_) => continue,
-            };
-
             if let Some(cap) = re.captures(&content) {
                 let old_date = cap.get(1).map(|m| m.as_str()).unwrap_or("");
-                
-                let metadata = match fs::metadata(path) {
-                    Ok(m) => m,
-                    Err(_) => continue,
-                };
-                
-                let mtime = match metadata.modified() {
-                    Ok(t) => t,
-                    Err(_) => continue,
-                };
-                
-                let duration = match mtime.duration_since(UNIX_EPOCH) {
-                    Ok(d) => d,
+
+                let mtime = match fs::metadata(path).and_then(|m| m.modified()) {
+                    Ok(t) => DateTime::<Utc>::from(t),
                     Err(_) => continue,
                 };
-                
-                let fs_secs = duration.as_secs() as i64;
-                let dt = match Local.timestamp_opt(fs_secs, 0).single() {
-                    Some(d) => d,
-                    None => continue,
-                };
-                
-                let new_date = dt.format("%Y-%m-%d %H:%M").to_string();
-                
+                let fs_secs = mtime.timestamp();
+                let new_date = tz.from_utc(&mtime).format("%Y-%m-%d %H:%M").to_string();
+
                 // Parsear fecha YAML para comparar en segundos
-                let yaml_secs = Self::parse_date_to_secs(old_date).unwrap_or(0) as i64;
+                let yaml_secs = OcDate::parse_in(old_date, &tz).map_or(0, |d| d.timestamp());
                 let diff_secs = (fs_secs - yaml_secs).abs();
                 let diff_hours = diff_secs / 3600;
                 
@@ -336,55 +520,86 @@ impl FixCommand {
                         &cap[0],
                         &format!("last_updated: \"{}\"", new_date)
                     );
-                    
-                    if !dry_run {
-                        fs::write(path, &new_content)?;
-                    }
-                    
-                    files_fixed += 1;
-                    fields_updated += 1;
-                    
-                    if verbose {
-                        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("?");
-                        let mode = if dry_run { "[DRY-RUN] " } else { "" };
-                        println!("📅 {}{}: {} → {} ({}h drift)", mode, name, old_date, new_date, diff_hours);
-                    }
+                    let description = format!(
+                        "📅 {}: {} → {} ({}h drift)",
+                        file_name(path), old_date, new_date, diff_hours
+                    );
+                    fixes.push(
+                        Fix::edit("FIX-DATES", description, path, content.as_str(), new_content)
+                            .in_stage(FixStage::Metadata),
+                    );
                 }
             }
         }
 
-        Ok((files_fixed, fields_updated))
+        Ok(fixes)
     }
 
-    /// Parsea fecha YAML a segundos desde UNIX_EPOCH.
-    fn parse_date_to_secs(date_str: &str) -> Option<u64> {
-        use chrono::{Local, NaiveDateTime, NaiveDate, TimeZone};
-        
-        let cleaned = date_str.trim().trim_matches('"');
-        
-        // Formato: "YYYY-MM-DD HH:MM:SS"
-        if let Ok(naive) = NaiveDateTime::parse_from_str(cleaned, "%Y-%m-%d %H:%M:%S") {
-            if let Some(local_dt) = Local.from_local_datetime(&naive).single() {
-                return Some(local_dt.timestamp() as u64);
+    /// Formato canónico: `--format` o `validation.date_format` de la config.
+    fn date_format(&self) -> String {
+        self.format.clone().unwrap_or_else(|| {
+            crate::core::config::OcConfig::from_cwd()
+                .unwrap_or_default()
+                .validation
+                .date_format
+        })
+    }
+
+    /// Reescribe las fechas del frontmatter (campos de primer nivel cuyo
+    /// valor parsea como [`OcDate`]) al formato canónico, en la zona
+    /// horaria del proyecto.
+    fn fix_date_formats(&self, files: &[PathBuf]) -> OcResult<Vec<Fix>> {
+        let format = self.date_format();
+        let tz = OcTimezone::from_cwd();
+        OcDate::check_format(&format)?;
+        let re = Regex::new(r#"^([A-Za-z_][\w-]*):\s*["']?([^"'\n]+?)["']?\s*$"#)
+            .map_err(|e| OcError::Custom(format!("Regex error: {}", e)))?;
+
+        let mut fixes = Vec::new();
+        for path in files {
+            let Ok(content) = fs::read_to_string(path) else {
+                continue;
+            };
+            if !content.starts_with("---") {
+                continue;
             }
-        }
-        
-        // Formato: "YYYY-MM-DD HH:MM"
-        if let Ok(naive) = NaiveDateTime::parse_from_str(cleaned, "%Y-%m-%d %H:%M") {
-            if let Some(local_dt) = Local.from_local_datetime(&naive).single() {
-                return Some(local_dt.timestamp() as u64);
+
+            let mut out = String::with_capacity(content.len());
+            let mut fields = Vec::new();
+            let mut in_yaml = false;
+            for (idx, line) in content.split_inclusive('\n').enumerate() {
+                let trimmed = line.trim_end_matches(['\n', '\r']);
+                if trimmed.trim() == "---" {
+                    in_yaml = idx == 0;
+                    out.push_str(line);
+                    continue;
+                }
+                let normalized = in_yaml
+                    .then(|| re.captures(trimmed))
+                    .flatten()
+                    .and_then(|cap| {
+                        let date = OcDate::parse_in(&cap[2], &tz).ok()?.format_in(&format, &tz).ok()?;
+                        (date != cap[2].trim()).then(|| (cap[1].to_string(), cap[2].to_string(), date))
+                    });
+                match normalized {
+                    Some((key, old, date)) => {
+                        out.push_str(&format!("{}: \"{}\"{}", key, date, &line[trimmed.len()..]));
+                        fields.push(format!("{}: {} → {}", key, old, date));
+                    }
+                    None => out.push_str(line),
+                }
             }
-        }
-        
-        // Formato: "YYYY-MM-DD" (assume midnight)
-        if let Ok(naive) = NaiveDate::parse_from_str(cleaned, "%Y-%m-%d") {
-            let naive_dt = naive.and_hms_opt(0, 0, 0)?;
-            if let Some(local_dt) = Local.from_local_datetime(&naive_dt).single() {
-                return Some(local_dt.timestamp() as u64);
+
+            if !fields.is_empty() {
+                let description = format!("📅 {}: {}", file_name(path), fields.join(", "));
+                fixes.push(
+                    Fix::edit("FIX-DATE-FORMAT", description, path, content.as_str(), out)
+                        .in_stage(FixStage::Metadata)
+                        .with_changes(fields.len()),
+                );
             }
         }
-        
-        None
+        Ok(fixes)
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -392,16 +607,13 @@ impl FixCommand {
     // ═══════════════════════════════════════════════════════════════════════
 
     /// FIX#2: Recalcula el campo content_hash basado en el contenido actual.
-    fn fix_hashes(
-        &self,
-        files: &[PathBuf],
-        dry_run: bool,
-        verbose: bool,
-    ) -> OcResult<(usize, usize)> {
+    fn fix_hashes(&self, files: &[PathBuf]) -> OcResult<Vec<Fix>> {
         use sha2::{Digest, Sha256};
 
-        let mut files_fixed = 0;
-        let mut fields_updated = 0;
+        let mut fixes = Vec::new();
+
+        let re = Regex::new(r#"content_hash:\s*\"?([^\"\n]+)\"?"#)
+            .map_err(|e| OcError::Custom(format!("Regex error: {}", e)))?;
 
         for path in files {
             let content = match fs::read_to_string(path) {
@@ -409,11 +621,6 @@ impl FixCommand {
                 Err(_) => continue,
             };
 
-            let re = match Regex::new(r#"content_hash:\s*\"?([^\"\n]+)\"?"#) {
-                Ok(r) => r,
-                Err(_) => continue,
-            };
-
             if let Some(cap) = re.captures(&content) {
                 let old_hash = cap.get(1).map(|m| m.as_str()).unwrap_or("");
                 
@@ -437,25 +644,267 @@ impl FixCommand {
                         &cap[0],
                         &format!("content_hash: \"{}\"", new_hash)
                     );
-                    
-                    if !dry_run {
-                        fs::write(path, &new_content)?;
-                    }
-                    
-                    files_fixed += 1;
-                    fields_updated += 1;
-                    
-                    if verbose {
-                        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("?");
-                        let mode = if dry_run { "[DRY-RUN] " } else { "" };
-                        let old_short = &old_hash[..8.min(old_hash.len())];
-                        println!("🔐 {}{}: {} → {}", mode, name, old_short, new_hash);
+                    let old_short = &old_hash[..8.min(old_hash.len())];
+                    let description = format!("🔐 {}: {} → {}", file_name(path), old_short, new_hash);
+                    fixes.push(
+                        Fix::edit("FIX-HASHES", description, path, content.as_str(), new_content)
+                            .in_stage(FixStage::Metadata),
+                    );
+                }
+            }
+        }
+
+        Ok(fixes)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // REGENERAR BREADCRUMBS CANÓNICOS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Reescribe `breadcrumb` con la cadena de títulos de los ancestros.
+    /// Los documentos con cadena rota (parent inexistente, ciclo) se omiten.
+    fn fix_breadcrumbs(&self, files: &[PathBuf], project: &[PathBuf]) -> OcResult<Vec<Fix>> {
+        use crate::core::yaml::update_field;
+        use crate::types::{Breadcrumb, BreadcrumbIndex};
+
+        let mut index = BreadcrumbIndex::new();
+        for path in project {
+            if let Ok(content) = fs::read_to_string(path) {
+                index.insert_document(path, &content);
+            }
+        }
+
+        let re = Regex::new(r#"(?m)^breadcrumb:\s*\"?([^\"\n]*)\"?"#)
+            .map_err(|e| OcError::Custom(format!("Regex error: {}", e)))?;
+        let mut fixes = Vec::new();
+
+        for path in files {
+            let content = match fs::read_to_string(path) {
+                Ok(c) if c.trim_start().starts_with("---") => c,
+                _ => continue,
+            };
+            let Some(id) = BreadcrumbIndex::new().insert_document(path, &content) else {
+                continue;
+            };
+            let canonical = match index.canonical(&id) {
+                Ok(bc) => bc,
+                Err(e) => {
+                    if self.verbose {
+                        eprintln!("⚠ {}: {}", path.display(), e);
                     }
+                    continue;
                 }
+            };
+            let old = re.captures(&content).map(|c| c[1].trim().to_string());
+            if old.as_deref().is_some_and(|bc| canonical.matches(&Breadcrumb::parse(bc))) {
+                continue;
+            }
+
+            let value = format!("\"{}\"", canonical.to_string().replace('"', "\\\""));
+            let new_content = update_field(&content, "breadcrumb", &value)?;
+            let description = format!("🧭 {}: '{}' → '{}'", file_name(path), old.unwrap_or_default(), canonical);
+            fixes.push(
+                Fix::edit("FIX-BREADCRUMBS", description, path, content, new_content).in_stage(FixStage::Structure),
+            );
+        }
+
+        Ok(fixes)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // DESCENDANTS_COUNT
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Actualiza `descendants_count` desde el grafo jerárquico del proyecto.
+    fn fix_descendants(&self, files: &[PathBuf], project: &[PathBuf]) -> OcResult<Vec<Fix>> {
+        use crate::core::graph::{apply_descendants_count, hierarchy_from_files};
+        use crate::types::breadcrumb::frontmatter_value;
+        use crate::types::lifetimes::split_frontmatter;
+        use crate::types::DocumentId;
+
+        let graph = hierarchy_from_files(project);
+        let mut fixes = Vec::new();
+
+        for path in files {
+            let content = match fs::read_to_string(path) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+            let doc_id = split_frontmatter(&content)
+                .and_then(|s| frontmatter_value(s.metadata, "id"))
+                .and_then(|id| id.trim_end_matches('.').parse::<DocumentId>().ok());
+            let Some(doc_id) = doc_id else {
+                continue;
+            };
+            let count = graph.descendants_count(&doc_id);
+            let Some((old, new_content)) = apply_descendants_count(&content, count) else {
+                continue;
+            };
+            let old = old.map(|o| o.to_string()).unwrap_or_else(|| "-".to_string());
+            let description = format!("🌳 {}: descendants_count {} → {}", file_name(path), old, count);
+            fixes.push(
+                Fix::edit("FIX-DESCENDANTS", description, path, content, new_content).in_stage(FixStage::Structure),
+            );
+        }
+
+        Ok(fixes)
+    }
+
+    /// Actualiza los `children_count` existentes con los hijos directos del
+    /// grafo jerárquico.
+    fn fix_children(&self, files: &[PathBuf], project: &[PathBuf]) -> OcResult<Vec<Fix>> {
+        use crate::core::graph::hierarchy_from_files;
+        use crate::core::patterns::RE_CHILDREN_COUNT;
+        use crate::types::breadcrumb::frontmatter_value;
+        use crate::types::lifetimes::split_frontmatter;
+        use crate::types::DocumentId;
+
+        let graph = hierarchy_from_files(project);
+        let mut fixes = Vec::new();
+
+        for path in files {
+            let content = match fs::read_to_string(path) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+            let doc_id = split_frontmatter(&content)
+                .and_then(|s| frontmatter_value(s.metadata, "id"))
+                .and_then(|id| id.trim_end_matches('.').parse::<DocumentId>().ok());
+            let (Some(doc_id), Some(cap)) = (doc_id, RE_CHILDREN_COUNT.captures(&content)) else {
+                continue;
+            };
+            let count = graph.get_children(&doc_id).len();
+            if cap[1] == count.to_string() {
+                continue;
             }
+            let new_content = RE_CHILDREN_COUNT
+                .replace(&content, format!("children_count: {}", count).as_str())
+                .into_owned();
+            let description = format!("👶 {}: children_count {} → {}", file_name(path), &cap[1], count);
+            fixes.push(
+                Fix::edit("FIX-CHILDREN", description, path, content.as_str(), new_content)
+                    .in_stage(FixStage::Structure),
+            );
         }
 
-        Ok((files_fixed, fields_updated))
+        Ok(fixes)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // NOMBRES DE ARCHIVO
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Renombra a `"{id} {titulo_slug}.md"` los archivos que divergen del
+    /// frontmatter y reescribe los wiki-links que los apuntaban. Los fixes de
+    /// enlaces apuntan al archivo ya renombrado; se omiten los renombres cuyo
+    /// destino ya existe.
+    fn fix_filenames(&self, files: &[PathBuf], project: &[PathBuf]) -> OcResult<Vec<Fix>> {
+        use crate::core::links::rename_link_target;
+        use crate::types::breadcrumb::frontmatter_value;
+        use crate::types::lifetimes::split_frontmatter;
+        use crate::types::path::{canonical_file_name, DataPath};
+
+        let mut renames: Vec<(PathBuf, PathBuf)> = Vec::new();
+        for path in files {
+            let Ok(content) = fs::read_to_string(path) else {
+                continue;
+            };
+            let Some(metadata) = split_frontmatter(&content).map(|s| s.metadata.to_string()) else {
+                continue;
+            };
+            let (Some(id), Some(title)) = (frontmatter_value(&metadata, "id"), frontmatter_value(&metadata, "title")) else {
+                continue;
+            };
+            if DataPath::new(path).matches_convention(&id, Some(&title)) {
+                continue;
+            }
+            let target = path.with_file_name(canonical_file_name(&id, &title));
+            if target.exists() || renames.iter().any(|(_, t)| *t == target) {
+                eprintln!("⚠ {}: destino {} ya existe, se omite", path.display(), target.display());
+                continue;
+            }
+            renames.push((path.clone(), target));
+        }
+
+        let mut fixes: Vec<Fix> = renames
+            .iter()
+            .map(|(old, new)| {
+                let description = format!("🏷️  {} → {}", file_name(old), file_name(new));
+                Fix::rename("FIX-FILENAMES", description, old, new).with_changes(0)
+            })
+            .collect();
+
+        let stems: Vec<(String, String)> = renames
+            .iter()
+            .filter_map(|(old, new)| {
+                let stem = |p: &Path| p.file_stem().and_then(|s| s.to_str()).map(str::to_string);
+                Some((stem(old)?, stem(new)?))
+            })
+            .collect();
+        for path in project {
+            let Ok(content) = fs::read_to_string(path) else {
+                continue;
+            };
+            let mut new_content = content.clone();
+            let mut count = 0;
+            for (old_stem, new_stem) in &stems {
+                let (updated, n) = rename_link_target(&new_content, old_stem, new_stem);
+                new_content = updated;
+                count += n;
+            }
+            if count == 0 {
+                continue;
+            }
+            let current = renames.iter().find(|(old, _)| old == path).map_or(path, |(_, new)| new);
+            let description = format!("🔗 {}: {} enlaces actualizados", file_name(current), count);
+            fixes.push(
+                Fix::edit("FIX-FILENAMES", description, current, content, new_content)
+                    .in_stage(FixStage::Rename)
+                    .with_changes(count),
+            );
+        }
+
+        Ok(fixes)
+    }
+
+    /// Reapunta las referencias a bloques `^id` que se movieron de documento:
+    /// si el destino ya no define el id y un único documento del proyecto lo
+    /// define, el enlace pasa a ese documento.
+    fn fix_blocks(&self, files: &[PathBuf], project: &[PathBuf]) -> OcResult<Vec<Fix>> {
+        use crate::core::anchors::block_owners;
+        use crate::core::links::retarget_block_refs;
+
+        let owners = block_owners(project);
+        let mut fixes = Vec::new();
+        for path in files {
+            let Ok(content) = fs::read_to_string(path) else {
+                continue;
+            };
+            let (new_content, count) = retarget_block_refs(&content, &owners);
+            if count == 0 {
+                continue;
+            }
+            let description = format!("🧱 {}: {} referencias a bloques movidos", file_name(path), count);
+            fixes.push(Fix::edit("FIX-BLOCKS", description, path, content, new_content).with_changes(count));
+        }
+        Ok(fixes)
+    }
+}
+
+impl Fixable for FixSource<FixCommand> {
+    fn fix_stage(&self) -> FixStage {
+        self.command.stage()
+    }
+
+    fn suggest_fixes(&self) -> Vec<Fix> {
+        self.command.collect_fixes(&self.data_dir).unwrap_or_else(|e| {
+            eprintln!("⚠ {}", e);
+            Vec::new()
+        })
+    }
+
+    fn apply_fix(&mut self, fix: &Fix) -> OcResult<traits::FixResult> {
+        fixes::apply(fix, &self.review)
     }
 }
 
@@ -467,6 +916,10 @@ impl FixCommand {
 pub fn run(cmd: FixCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
     let data_dir = PathBuf::from(&cli.data_dir);
 
+    if cmd.plan {
+        print_plan(&cmd.plan(&data_dir));
+    }
+
     println!("🔧 Iniciando corrección...");
 
     let result = cmd.run(&data_dir)?;
@@ -486,10 +939,46 @@ pub fn run(cmd: FixCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<
             result.rows_updated
         );
     }
+    if !result.skipped.is_empty() {
+        println!("⏭️  {} fixes omitidos:", result.skipped.len());
+        for skipped in &result.skipped {
+            println!("   • {}", skipped);
+        }
+    }
+    if !cmd.dry_run && !result.renames.is_empty() {
+        let path = crate::core::redirects::redirects_path();
+        let mut redirects = crate::core::redirects::Redirects::load(&path);
+        for (from, to) in &result.renames {
+            let stem = |p: &PathBuf| p.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string();
+            redirects.record(&stem(from), &stem(to));
+        }
+        redirects.save(&path)?;
+        println!("↪️  {} redirects registrados en {}", result.renames.len(), path.display());
+    }
 
     Ok(())
 }
 
+/// `--plan`: pasos numerados por etapa, con los pasos de los que dependen.
+fn print_plan(plan: &RepairPlan) {
+    println!("🗺️  Plan de reparación: {} pasos ({} reordenados)", plan.len(), plan.reordered());
+    let mut stage = None;
+    for (pos, step) in plan.steps.iter().enumerate() {
+        if stage != Some(step.fix.stage) {
+            stage = Some(step.fix.stage);
+            println!("\n  ── {} ──", step.fix.stage.label());
+        }
+        let after = if step.depends_on.is_empty() {
+            String::new()
+        } else {
+            let deps: Vec<String> = step.depends_on.iter().map(|d| format!("#{}", d + 1)).collect();
+            format!("  ← {}", deps.join(", "))
+        };
+        println!("  {:>4}. [{}] {}{}", pos + 1, step.fix.code, step.fix.description, after);
+    }
+    println!();
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // TESTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -497,6 +986,7 @@ pub fn run(cmd: FixCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TestProject;
 
     #[test]
     fn test_extract_id() {
@@ -545,4 +1035,164 @@ mod tests {
         ];
         assert_eq!(FixCommand::count_descendants("1.1", &ids_with_parent), 3); // 1.1.0, 1.1.1, 1.1.1.2
     }
+
+    #[test]
+    fn test_normalize_dates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("1.2 Alta.md");
+        std::fs::write(
+            &path,
+            "---\nid: \"1.2\"\ncreated: 30/01/2026\nlast_updated: '2026-02-01T09:30:00Z'\nreviewed: \"2026-02-03 10:00\"\n---\ncreated: 01/01/2020\n",
+        )
+        .unwrap();
+
+        let cmd = FixCommand::parse_from(["fix", "--normalize-dates", "--format", "%Y-%m-%d %H:%M"]);
+        let result = cmd.run(dir.path()).unwrap();
+        assert_eq!((result.files_fixed, result.rows_updated), (1, 2));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "---\nid: \"1.2\"\ncreated: \"2026-01-30 00:00\"\nlast_updated: \"2026-02-01 09:30\"\nreviewed: \"2026-02-03 10:00\"\n---\ncreated: 01/01/2020\n"
+        );
+        assert_eq!(cmd.run(dir.path()).unwrap().files_fixed, 0);
+
+        let bad = FixCommand::parse_from(["fix", "--normalize-dates", "--format", "%Q"]);
+        assert!(bad.run(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_fix_breadcrumbs() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        project.write("1 Pagos.md", "---\nid: \"1\"\ntitle: \"Pagos\"\nparent: \"0\"\nbreadcrumb: \"Pagos\"\n---\n");
+        project.write(
+            "1.1 Tarjeta.md",
+            "---\nid: \"1.1\"\ntitle: \"Tarjeta\"\nparent: \"1\"\nbreadcrumb: \"1.1\"\n---\nCuerpo\n",
+        );
+        project.write("1.1.1 Debito.md", "---\nid: \"1.1.1\"\ntitle: \"Débito\"\nparent: \"1.1\"\n---\n");
+        project.write("7.1 Huerfano.md", "---\nid: \"7.1\"\nparent: \"7\"\nbreadcrumb: \"x\"\n---\n");
+
+        let cmd = FixCommand::parse_from(["fix", "--breadcrumbs"]);
+        let result = cmd.run(dir).unwrap();
+        assert_eq!(result.files_fixed, 2);
+
+        assert!(project.read("1.1 Tarjeta.md").contains("breadcrumb: \"Pagos > Tarjeta\"\n---\nCuerpo"));
+        assert!(project.read("1.1.1 Debito.md").contains("breadcrumb: \"Pagos > Tarjeta > Débito\""));
+        assert!(project.read("7.1 Huerfano.md").contains("breadcrumb: \"x\""));
+
+        // Tras el fix, la fase 5 no reporta más que la cadena rota
+        use crate::commands::verify::VerifyCommand;
+        let verify = VerifyCommand::parse_from(["verify", "--phase", "breadcrumbs"]);
+        let warnings = &verify.run(&project.data_dir).unwrap().phases[0].warnings;
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].contains("7.1 Huerfano.md"));
+    }
+
+    #[test]
+    fn test_preflight_readonly() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        project.write("1 Raiz.md", "---\nid: \"1\"\nparent: \"0\"\nchildren_count: 5\n---\n");
+        project.write("1.1 Hijo.md", "---\nid: \"1.1\"\nparent: \"1\"\nchildren_count: 2\n---\n");
+        project.write("2 Otro.md", "---\nid: \"2\"\nparent: \"0\"\n---\n");
+        let locked = dir.join("1 Raiz.md");
+        let mut perms = std::fs::metadata(&locked).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(&locked, perms).unwrap();
+
+        // Aborta antes de escribir nada
+        let err = FixCommand::parse_from(["fix", "--children"]).run(dir).unwrap_err();
+        assert!(err.to_string().contains("1 Raiz.md (solo lectura)"), "{}", err);
+        assert!(project.read("1.1 Hijo.md").contains("children_count: 2"));
+
+        let result = FixCommand::parse_from(["fix", "--children", "--skip-readonly"]).run(dir).unwrap();
+        assert_eq!(result.files_fixed, 1);
+        assert_eq!(result.skipped, vec!["1 Raiz.md (solo lectura)"]);
+        assert!(project.read("1.1 Hijo.md").contains("children_count: 0"));
+        assert!(project.read("1 Raiz.md").contains("children_count: 5"));
+
+        // Un archivo bloqueado que el plan no toca no aborta
+        assert!(FixCommand::parse_from(["fix", "--hashes"]).run(dir).is_ok());
+    }
+
+    #[test]
+    fn test_fix_filenames() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        project.write("1 pagos.md", "---\nid: \"1\"\ntitle: \"Pagos\"\n---\nVer [[1.2 Alta]] y [[1.2 Alta#datos|datos]].\n");
+        project.write("1.2 Alta.md", "---\nid: \"1.2\"\ntitle: \"Alta de clientes\"\n---\nVolver a [[1 pagos]].\n");
+        project.write("1.3 baja.md", "---\nid: \"1.3\"\ntitle: \"Baja\"\n---\n");
+
+        let dry = FixCommand::parse_from(["fix", "--filenames", "--dry-run"]);
+        assert_eq!(dry.run(dir).unwrap().files_fixed, 1);
+        assert!(dir.join("1.2 Alta.md").exists());
+
+        let cmd = FixCommand::parse_from(["fix", "--filenames"]);
+        let result = cmd.run(dir).unwrap();
+        assert_eq!((result.files_fixed, result.rows_updated), (1, 2));
+        assert!(!dir.join("1.2 Alta.md").exists());
+        assert_eq!(
+            result.renames,
+            vec![(dir.join("1.2 Alta.md"), dir.join("1.2 alta_de_clientes.md"))]
+        );
+
+        assert!(project.read("1.2 alta_de_clientes.md").contains("[[1 pagos]]"));
+        assert!(project.read("1 pagos.md").contains("Ver [[1.2 alta_de_clientes]] y [[1.2 alta_de_clientes#datos|datos]]."));
+
+        // Tras el fix, la fase 24 queda limpia
+        use crate::commands::verify::VerifyCommand;
+        let verify = VerifyCommand::parse_from(["verify", "--phase", "filenames"]);
+        let phase = &verify.run(&project.data_dir).unwrap().phases[0];
+        assert!(phase.warnings.is_empty(), "{:?}", phase.warnings);
+    }
+
+    #[test]
+    fn test_fix_blocks() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        project.write("1 Alta.md", "# Alta\n\nVer [[2 Fiscal#^cuit|CUIT]] y ![[2 Fiscal^dni]].\n\nDNI obligatorio. ^dni\n");
+        project.write("2 Fiscal.md", "# Fiscal\n\nCUIT del titular. ^cuit\n");
+        project.write("3 Bajas.md", "![[1 Alta^cuit]]\n");
+
+        let result = FixCommand::parse_from(["fix", "--blocks"]).run(dir).unwrap();
+        assert_eq!((result.files_fixed, result.rows_updated), (2, 2));
+        assert!(project.read("1 Alta.md").contains("Ver [[2 Fiscal#^cuit|CUIT]] y ![[1 Alta^dni]]."));
+        assert_eq!(project.read("3 Bajas.md"), "![[2 Fiscal^cuit]]\n");
+    }
+
+    #[test]
+    fn test_fix_all() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        project.write(
+            "1 pagos.md",
+            "---\nid: \"1\"\ntitle: \"Pagos\"\nparent: \"0\"\nbreadcrumb: \"Pagos\"\ncontent_hash: \"0000\"\n---\nVer [[1.2 Alta]].   \n",
+        );
+        project.write(
+            "1.2 Alta.md",
+            "---\nid: \"1.2\"\ntitle: \"Alta de clientes\"\nparent: \"1\"\nbreadcrumb: \"x\"\ncontent_hash: \"0000\"\n---\nVolver a [[1 pagos]].\n",
+        );
+
+        // Plan: el renombre antes que el enlace que lo apunta
+        let plan = FixCommand::parse_from(["fix", "--all", "--plan"]).plan(dir);
+        let rename = plan.steps.iter().position(|s| s.fix.is_rename()).unwrap();
+        let link = plan.steps.iter().position(|s| s.depends_on.contains(&rename)).unwrap();
+        assert!(rename < link && plan.steps[link].fix.file.as_deref() == Some(&*dir.join("1 pagos.md")));
+
+        let before = project.read("1 pagos.md");
+        FixCommand::parse_from(["fix", "--all", "--dry-run"]).run(dir).unwrap();
+        assert_eq!(project.read("1 pagos.md"), before);
+        assert!(dir.join("1.2 Alta.md").exists());
+
+        let result = FixCommand::parse_from(["fix", "--all"]).run(dir).unwrap();
+        assert!(result.failures.is_empty(), "{:?}", result.failures);
+        let pagos = project.read("1 pagos.md");
+        assert!(pagos.contains("Ver [[1.2 alta_de_clientes]].\n"), "{}", pagos);
+        assert!(project.read("1.2 alta_de_clientes.md").contains("breadcrumb: \"Pagos > Alta de clientes\""));
+
+        // Los hashes se recalcularon sobre el contenido final
+        let sync = SyncCommand::parse_from(["sync"]).collect(dir).unwrap();
+        assert!(sync.changes.is_empty(), "{:?}", sync.changes);
+        let again = FixCommand::parse_from(["fix", "--all"]).run(dir).unwrap();
+        assert_eq!(again.files_fixed, 0);
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Genera nuevos documentos a partir de templates.
 
+use crate::core::summary::LeafSummary;
 use crate::errors::OcResult;
 use clap::Parser;
 use serde::Serialize;
@@ -18,6 +19,18 @@ pub enum DocType {
     Document,
     Index,
     Readme,
+    /// Resúmenes extractivos por módulo (`_summaries/<modulo>.md`).
+    Summaries,
+    /// Anchors `{#slug}` en headings (o índice `_index/anchors.json`).
+    Anchors,
+    /// Block-ids `^id` en párrafos e ítems de lista.
+    BlockIds,
+    /// Registro de decisión (`ADR-0001 Título.md`).
+    Adr,
+    /// Campo `slug:` único y estable en cada documento.
+    Slugs,
+    /// Tabla de contenido general numerada (`_exports/Tabla de Contenido General.md`).
+    MasterToc,
     Custom(String),
 }
 
@@ -28,6 +41,12 @@ impl DocType {
             "document" | "doc" => Self::Document,
             "index" => Self::Index,
             "readme" => Self::Readme,
+            "summaries" | "summary" => Self::Summaries,
+            "anchors" => Self::Anchors,
+            "block-ids" | "blocks" => Self::BlockIds,
+            "adr" | "decision" => Self::Adr,
+            "slugs" | "slug" => Self::Slugs,
+            "master-toc" | "toc" => Self::MasterToc,
             other => Self::Custom(other.to_string()),
         }
     }
@@ -39,6 +58,8 @@ pub struct GenResult {
     pub created_files: Vec<PathBuf>,
     pub template_used: String,
     pub variables_applied: usize,
+    /// Archivos omitidos por no tener cambios (generación incremental).
+    pub unchanged_files: Vec<PathBuf>,
 }
 
 impl GenResult {
@@ -47,6 +68,7 @@ impl GenResult {
             created_files: Vec::new(),
             template_used: template.to_string(),
             variables_applied: 0,
+            unchanged_files: Vec::new(),
         }
     }
 
@@ -66,10 +88,13 @@ pub struct GenCommand {
     /// Tipo de documento.
     pub doc_type: String,
 
-    /// ID del documento (o 'auto' para generar).
+    /// ID del documento ('auto' para generar; en summaries, módulo a resumir;
+    /// en block-ids, documento o módulo a marcar; en adr, título de la decisión).
+    #[arg(default_value = "auto")]
     pub doc_id: String,
 
-    /// Ruta de salida.
+    /// Ruta de salida (en summaries, directorio; default `<data_dir>/_summaries/`; en
+    /// master-toc, default `<data_dir>/_exports/Tabla de Contenido General.md`).
     #[arg(short, long)]
     pub output: Option<PathBuf>,
 
@@ -97,6 +122,28 @@ pub struct GenCommand {
     /// Validar estructura después de generar.
     #[arg(long)]
     pub validate: bool,
+
+    /// Tamaño objetivo de cada resumen en palabras (summaries).
+    #[arg(long, default_value = "400")]
+    pub target_words: usize,
+
+    /// Regenerar aunque los hashes no hayan cambiado (en slugs, recalcular
+    /// desde el título actual y registrar redirects).
+    #[arg(long)]
+    pub force: bool,
+
+    /// anchors: escribir `_index/anchors.json` en vez de modificar los headings.
+    #[arg(long)]
+    pub index: bool,
+
+    /// adr: estado inicial (propuesto, aceptado).
+    #[arg(long, default_value = "propuesto")]
+    pub status: String,
+
+    /// adr: ADR que esta decisión reemplaza (`7` o `ADR-0007`); se marca
+    /// como supersedido con un enlace al nuevo.
+    #[arg(long, value_name = "ADR")]
+    pub supersedes: Option<String>,
 }
 
 /// L14.1: Variables para templates.
@@ -113,6 +160,25 @@ pub struct TemplateVars {
 
 impl GenCommand {
     pub fn run(&self, data_dir: &std::path::Path) -> OcResult<GenResult> {
+        if self.doc_type() == DocType::Summaries {
+            return self.gen_summaries(data_dir);
+        }
+        if self.doc_type() == DocType::Anchors {
+            return self.gen_anchors(data_dir);
+        }
+        if self.doc_type() == DocType::BlockIds {
+            return self.gen_block_ids(data_dir);
+        }
+        if self.doc_type() == DocType::Adr {
+            return self.gen_adr(data_dir);
+        }
+        if self.doc_type() == DocType::Slugs {
+            return self.gen_slugs(data_dir);
+        }
+        if self.doc_type() == DocType::MasterToc {
+            return self.gen_master_toc(data_dir);
+        }
+
         let template = self.template.as_deref().unwrap_or("default");
         let mut result = GenResult::new(template);
 
@@ -350,6 +416,530 @@ last_updated: "{{UPDATED}}"
     pub fn doc_type(&self) -> DocType {
         DocType::from_str(&self.doc_type)
     }
+
+    /// Genera `<data_dir>/_summaries/<modulo>.md` con las primeras oraciones
+    /// y headings de cada hoja. Omite módulos cuyo `source_hash` no cambió.
+    fn gen_summaries(&self, data_dir: &std::path::Path) -> OcResult<GenResult> {
+        use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+        use crate::core::hash::{compute_content_hash, compute_multi_file_hash};
+        use crate::types::breadcrumb::frontmatter_value;
+        use crate::types::lifetimes::split_frontmatter;
+        use std::collections::BTreeMap;
+
+        let mut result = GenResult::new("summaries");
+        let out_dir = self
+            .output
+            .clone()
+            .unwrap_or_else(|| data_dir.join("_summaries"));
+
+        // Agrupar documentos numerados por módulo (primer segmento del `id:`
+        // del frontmatter o, sin él, del nombre de archivo)
+        let mut modules: BTreeMap<u32, Vec<(String, PathBuf)>> = BTreeMap::new();
+        for file in get_all_md_files(data_dir, &ScanOptions::new())? {
+            if file.starts_with(&out_dir) {
+                continue;
+            }
+            let content = read_file_content(&file)?;
+            let Some(id) = split_frontmatter(&content)
+                .and_then(|s| frontmatter_value(s.metadata, "id"))
+                .and_then(|id| numeric_id(&id))
+                .or_else(|| file.file_stem().and_then(|s| s.to_str()).and_then(numeric_id))
+            else {
+                continue;
+            };
+            let module = id_parts(&id)[0];
+            modules.entry(module).or_default().push((id, file));
+        }
+
+        for (module, mut docs) in modules {
+            if self.doc_id != "auto" && self.doc_id != module.to_string() {
+                continue;
+            }
+            docs.sort_by_key(|(id, _)| id_parts(id));
+
+            let paths: Vec<PathBuf> = docs.iter().map(|(_, p)| p.clone()).collect();
+            let source_hash = compute_content_hash(&format!(
+                "{}:{}",
+                compute_multi_file_hash(&paths)?,
+                self.target_words
+            ));
+            let out_path = out_dir.join(format!("{}.md", module));
+
+            // Incremental: mismo hash → sin cambios
+            let hash_line = format!("source_hash: \"{}\"", source_hash);
+            if !self.force {
+                if let Ok(existing) = std::fs::read_to_string(&out_path) {
+                    if existing.contains(&hash_line) {
+                        result.unchanged_files.push(out_path);
+                        continue;
+                    }
+                }
+            }
+
+            let mut root_title = format!("Módulo {}", module);
+            let mut leaves = Vec::new();
+            for (id, path) in &docs {
+                let content = read_file_content(path)?;
+                let title = crate::core::patterns::RE_TITLE
+                    .captures(&content)
+                    .map(|cap| cap[1].trim().to_string())
+                    .unwrap_or_else(|| id.clone());
+                let prefix = format!("{}.", id);
+                let is_leaf = !docs.iter().any(|(other, _)| other.starts_with(&prefix));
+                if id_parts(id).iter().skip(1).all(|p| *p == 0) {
+                    root_title = title.clone();
+                }
+                if is_leaf {
+                    leaves.push(LeafSummary::extract(id, &title, &content, 3));
+                }
+            }
+
+            let body = render_summary_body(&leaves, self.target_words);
+            let content = format!(
+                "---\ntitle: \"Resumen · Módulo {m}: {t}\"\nmodule: \"{m}\"\ntype: \"summary\"\ngenerated: \"{g}\"\n{h}\nleaves: {n}\n---\n\n# Resumen · Módulo {m}: {t}\n\n{b}",
+                m = module,
+                t = root_title,
+                g = chrono::Utc::now().format("%Y-%m-%d %H:%M"),
+                h = hash_line,
+                n = leaves.len(),
+                b = body
+            );
+
+            std::fs::create_dir_all(&out_dir)?;
+            std::fs::write(&out_path, content)?;
+            result.add_file(out_path);
+        }
+
+        Ok(result)
+    }
+
+    /// Fija anchors únicos en cada heading (`## Datos {#datos}`), o con
+    /// `--index` los registra en `_index/anchors.json` sin tocar los documentos.
+    fn gen_anchors(&self, data_dir: &std::path::Path) -> OcResult<GenResult> {
+        use crate::core::anchors::{anchors, insert_anchors, AnchorIndex, ANCHOR_INDEX};
+        use crate::core::files::{get_all_md_files, read_file_content, write_file_atomic, ScanOptions};
+
+        let mut result = GenResult::new("anchors");
+        let mut index = AnchorIndex::new();
+
+        for file in get_all_md_files(data_dir, &ScanOptions::new())? {
+            let content = read_file_content(&file)?;
+            if self.index {
+                let stem = file.file_stem().and_then(|s| s.to_str()).unwrap_or("unknown");
+                index.insert(stem.to_string(), anchors(&content));
+                continue;
+            }
+            match insert_anchors(&content) {
+                Some(updated) => {
+                    write_file_atomic(&file, &updated)?;
+                    result.add_file(file);
+                }
+                None => result.unchanged_files.push(file),
+            }
+        }
+
+        if self.index {
+            let out_path = self.output.clone().unwrap_or_else(|| data_dir.join(ANCHOR_INDEX));
+            if let Some(parent) = out_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&out_path, serde_json::to_string_pretty(&index)?)?;
+            result.variables_applied = index.values().map(Vec::len).sum();
+            result.add_file(out_path);
+        }
+
+        Ok(result)
+    }
+
+    /// Agrega `^id` a los bloques sin marcar de los documentos de `doc_id`
+    /// (el documento y sus descendientes; `auto` = todo el proyecto).
+    fn gen_block_ids(&self, data_dir: &std::path::Path) -> OcResult<GenResult> {
+        use crate::core::anchors::{block_ids, insert_block_ids};
+        use crate::core::files::{get_all_md_files, read_file_content, write_file_atomic, ScanOptions};
+        use crate::core::pipeline::id_within;
+
+        let mut result = GenResult::new("block-ids");
+        for file in get_all_md_files(data_dir, &ScanOptions::new())? {
+            let stem = file.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+            if self.doc_id != "auto" && !numeric_id(stem).is_some_and(|id| id_within(&id, &self.doc_id)) {
+                continue;
+            }
+            let content = read_file_content(&file)?;
+            match insert_block_ids(&content) {
+                Some(updated) => {
+                    result.variables_applied += block_ids(&updated).len() - block_ids(&content).len();
+                    write_file_atomic(&file, &updated)?;
+                    result.add_file(file);
+                }
+                None => result.unchanged_files.push(file),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Crea el siguiente ADR en `--output` (directorio; default el data_dir)
+    /// y, con `--supersedes`, marca el reemplazado.
+    fn gen_adr(&self, data_dir: &std::path::Path) -> OcResult<GenResult> {
+        use crate::core::adr;
+        use crate::core::files::{get_all_md_files, read_file_content, write_file_atomic, ScanOptions};
+        use crate::errors::OcError;
+
+        let title = self
+            .title
+            .clone()
+            .or_else(|| (self.doc_id != "auto").then(|| self.doc_id.clone()))
+            .ok_or_else(|| OcError::Custom("gen adr necesita un título: gen adr \"Usar PostgreSQL\"".into()))?;
+        let status = adr::normalize_status(&self.status)
+            .filter(|s| *s != "supersedido")
+            .ok_or_else(|| OcError::Custom(format!("estado de ADR inválido '{}' (propuesto, aceptado)", self.status)))?;
+
+        let out_dir = self.output.clone().unwrap_or_else(|| data_dir.to_path_buf());
+        let mut files = get_all_md_files(data_dir, &ScanOptions::new())?;
+        if out_dir.is_dir() && !out_dir.starts_with(data_dir) {
+            files.extend(get_all_md_files(&out_dir, &ScanOptions::new())?);
+        }
+        let adrs = adr::index(files.iter().filter_map(|f| f.file_stem().and_then(|s| s.to_str())));
+        let number = adrs.keys().next_back().map_or(1, |n| n + 1);
+        let stem = adr::file_stem(number, &title);
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+
+        let replaced = match &self.supersedes {
+            Some(reference) => adr::parse_ref(reference)
+                .and_then(|n| adrs.get(&n))
+                .and_then(|old| {
+                    let path = files.iter().find(|f| f.file_stem().and_then(|s| s.to_str()) == Some(old.as_str()))?;
+                    Some((old.clone(), path.clone()))
+                })
+                .map(Some)
+                .ok_or_else(|| OcError::Custom(format!("ADR '{}' no encontrado", reference)))?,
+            None => None,
+        };
+
+        let mut result = GenResult::new("adr");
+        let content = adr::render(number, &title, status, replaced.as_ref().map(|(old, _)| old.as_str()), &today);
+        std::fs::create_dir_all(&out_dir)?;
+        let path = out_dir.join(format!("{}.md", stem));
+        std::fs::write(&path, content)?;
+        result.add_file(path);
+
+        if let Some((_, old_path)) = replaced {
+            let updated = adr::supersede(&read_file_content(&old_path)?, &stem, &today)?;
+            write_file_atomic(&old_path, &updated)?;
+            result.add_file(old_path);
+        }
+        Ok(result)
+    }
+
+    /// Fija `slug:` en los documentos que no lo tienen (o lo tienen duplicado
+    /// o inválido; con `--force`, en todos desde el título actual) y registra
+    /// en `_index/slugs.json` los redirects de los slugs que cambiaron.
+    /// `variables_applied` cuenta los redirects nuevos.
+    fn gen_slugs(&self, data_dir: &std::path::Path) -> OcResult<GenResult> {
+        use crate::core::files::{get_all_md_files, read_file_content, write_file_atomic, ScanOptions};
+        use crate::core::slugs::{is_valid, slug_for, unique, SlugRegistry};
+        use crate::core::yaml::{add_field, update_field};
+        use crate::types::breadcrumb::frontmatter_value;
+        use crate::types::lifetimes::split_frontmatter;
+
+        struct SlugDoc {
+            path: PathBuf,
+            content: String,
+            key: String,
+            title: String,
+            current: Option<String>,
+            slug: Option<String>,
+        }
+
+        let mut result = GenResult::new("slugs");
+        let mut docs = Vec::new();
+        for path in get_all_md_files(data_dir, &ScanOptions::new())? {
+            let content = read_file_content(&path)?;
+            let Some(metadata) = split_frontmatter(&content).map(|fm| fm.metadata) else {
+                result.unchanged_files.push(path);
+                continue;
+            };
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string();
+            docs.push(SlugDoc {
+                key: frontmatter_value(metadata, "id").unwrap_or_else(|| stem.clone()),
+                title: frontmatter_value(metadata, "title").unwrap_or(stem),
+                current: frontmatter_value(metadata, "slug"),
+                slug: None,
+                path,
+                content,
+            });
+        }
+
+        // Primero se conservan los slugs válidos (el primero en orden natural
+        // gana un duplicado); después se asignan los que faltan
+        let mut taken = std::collections::HashSet::new();
+        if !self.force {
+            for doc in &mut docs {
+                doc.slug = doc.current.clone().filter(|s| is_valid(s) && taken.insert(s.clone()));
+            }
+        }
+        for doc in docs.iter_mut().filter(|d| d.slug.is_none()) {
+            let slug = unique(&slug_for(&doc.title), &taken);
+            taken.insert(slug.clone());
+            doc.slug = Some(slug);
+        }
+
+        let mut registry = SlugRegistry::load(data_dir);
+        for doc in docs {
+            let slug = doc.slug.unwrap_or_default();
+            if registry.record(&doc.key, &slug).is_some() {
+                result.variables_applied += 1;
+            }
+            if doc.current.as_deref() == Some(slug.as_str()) {
+                result.unchanged_files.push(doc.path);
+                continue;
+            }
+            let quoted = format!("\"{}\"", slug);
+            let updated = match doc.current {
+                Some(_) => update_field(&doc.content, "slug", &quoted)?,
+                None => add_field(&doc.content, "slug", &quoted)?,
+            };
+            write_file_atomic(&doc.path, &updated)?;
+            result.add_file(doc.path);
+        }
+        registry.save(data_dir)?;
+        Ok(result)
+    }
+
+    /// Genera o actualiza la tabla de contenido general: los documentos no
+    /// archivados numerados por jerarquía de ID, con palabras propias y por
+    /// rama, y la última actualización de cada módulo. Va por defecto a
+    /// `_exports/`, un directorio derivado, para que verify y lint no la
+    /// revisen como documento. Como en summaries, se omite si el
+    /// `source_hash` no cambió (salvo `--force`).
+    fn gen_master_toc(&self, data_dir: &std::path::Path) -> OcResult<GenResult> {
+        use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+        use crate::core::hash::compute_multi_file_hash;
+        use crate::core::yaml::count_words;
+        use crate::types::breadcrumb::frontmatter_value;
+        use crate::types::lifetimes::split_frontmatter;
+        use crate::types::{DocumentStatus, OcDate};
+
+        let mut result = GenResult::new("master-toc");
+        let out_path = self
+            .output
+            .clone()
+            .unwrap_or_else(|| data_dir.join("_exports").join(format!("{}.md", MASTER_TOC_TITLE)));
+        let derived = crate::core::config::OcConfig::from_cwd().unwrap_or_default().derived_dirs;
+
+        let mut docs = Vec::new();
+        let mut paths = Vec::new();
+        for path in get_all_md_files(data_dir, &ScanOptions::new().with_skip_dirs(derived))? {
+            if path == out_path {
+                continue;
+            }
+            let content = read_file_content(&path)?;
+            let split = split_frontmatter(&content);
+            let (metadata, body) = split.map_or(("", content.as_str()), |s| (s.metadata, s.content));
+            let archived = frontmatter_value(metadata, "status")
+                .and_then(|s| s.parse::<DocumentStatus>().ok())
+                .is_some_and(|s| s == DocumentStatus::Archived);
+            if archived || frontmatter_value(metadata, "type").as_deref() == Some("toc") {
+                continue;
+            }
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string();
+            let updated = frontmatter_value(metadata, "last_updated")
+                .and_then(|d| d.parse::<OcDate>().ok())
+                .or_else(|| {
+                    let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+                    Some(OcDate::from_datetime(modified.into()))
+                })
+                .map(|d| d.to_date());
+            docs.push(TocEntry {
+                id: numeric_id(&stem),
+                title: frontmatter_value(metadata, "title").unwrap_or_else(|| stem.clone()),
+                words: count_words(body),
+                updated,
+                stem,
+            });
+            paths.push(path);
+        }
+
+        let source_hash = compute_multi_file_hash(&paths)?;
+        let hash_line = format!("source_hash: \"{}\"", source_hash);
+        if !self.force {
+            if let Ok(existing) = std::fs::read_to_string(&out_path) {
+                if existing.contains(&hash_line) {
+                    result.unchanged_files.push(out_path);
+                    return Ok(result);
+                }
+            }
+        }
+
+        let total_words: usize = docs.iter().map(|d| d.words).sum();
+        let content = format!(
+            "---\ntitle: \"{t}\"\ntype: \"toc\"\ngenerated: \"{g}\"\n{h}\ndocuments: {n}\n---\n\n# {t}\n\n_{n} documentos · {w} palabras_\n\n{b}",
+            t = MASTER_TOC_TITLE,
+            g = chrono::Utc::now().format("%Y-%m-%d %H:%M"),
+            h = hash_line,
+            n = docs.len(),
+            w = total_words,
+            b = render_master_toc(&docs)
+        );
+        if let Some(parent) = out_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&out_path, content)?;
+        result.variables_applied = docs.len();
+        result.add_file(out_path);
+        Ok(result)
+    }
+}
+
+/// Título (y nombre de archivo por defecto) de `gen master-toc`.
+pub const MASTER_TOC_TITLE: &str = "Tabla de Contenido General";
+
+/// Documento en la tabla de contenido general.
+struct TocEntry {
+    /// ID numérico del nombre de archivo; sin él va a "Otros documentos".
+    id: Option<String>,
+    stem: String,
+    title: String,
+    words: usize,
+    /// Fecha `YYYY-MM-DD` de `last_updated` o, si falta, del mtime.
+    updated: Option<String>,
+}
+
+/// Cuerpo de la tabla: una sección por módulo (primer segmento del ID) con
+/// sus documentos anidados bajo el ancestro existente más cercano y
+/// numerados por posición (`2.1.3`).
+fn render_master_toc(docs: &[TocEntry]) -> String {
+    use std::collections::{BTreeMap, HashMap};
+
+    let by_id: HashMap<&str, &TocEntry> = docs.iter().filter_map(|d| Some((d.id.as_deref()?, d))).collect();
+    fn parent_of<'a>(id: &'a str, by_id: &HashMap<&str, &TocEntry>) -> Option<&'a str> {
+        let mut current = id;
+        while let Some((parent, _)) = current.rsplit_once('.') {
+            if by_id.contains_key(parent) {
+                return Some(parent);
+            }
+            current = parent;
+        }
+        None
+    }
+    let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut modules: BTreeMap<u32, Vec<&str>> = BTreeMap::new();
+    for &id in by_id.keys() {
+        match parent_of(id, &by_id) {
+            Some(parent) => children.entry(parent).or_default().push(id),
+            None => modules.entry(id_parts(id)[0]).or_default().push(id),
+        }
+    }
+    for ids in children.values_mut().chain(modules.values_mut()) {
+        ids.sort_by_key(|id| id_parts(id));
+    }
+
+    fn branch_words(id: &str, by_id: &HashMap<&str, &TocEntry>, children: &HashMap<&str, Vec<&str>>) -> usize {
+        by_id[id].words
+            + children
+                .get(id)
+                .map_or(0, |ids| ids.iter().map(|c| branch_words(c, by_id, children)).sum())
+    }
+    fn latest(id: &str, by_id: &HashMap<&str, &TocEntry>, children: &HashMap<&str, Vec<&str>>) -> Option<String> {
+        let nested = children.get(id).into_iter().flatten().filter_map(|c| latest(c, by_id, children));
+        nested.chain(by_id[id].updated.clone()).max()
+    }
+    fn link(doc: &TocEntry) -> String {
+        format!("[[{}|{}]]", doc.stem, doc.title)
+    }
+    fn items(
+        ids: &[&str],
+        number: &str,
+        depth: usize,
+        by_id: &HashMap<&str, &TocEntry>,
+        children: &HashMap<&str, Vec<&str>>,
+        out: &mut String,
+    ) {
+        for (i, id) in ids.iter().enumerate() {
+            let doc = by_id[id];
+            let number = format!("{}.{}", number, i + 1);
+            out.push_str(&format!("{}- {} {} — {} palabras", "  ".repeat(depth), number, link(doc), doc.words));
+            if let Some(nested) = children.get(id) {
+                out.push_str(&format!(" (rama: {})\n", branch_words(id, by_id, children)));
+                items(nested, &number, depth + 1, by_id, children, out);
+            } else {
+                out.push('\n');
+            }
+        }
+    }
+
+    let mut out = String::new();
+    for (n, (module, roots)) in modules.iter().enumerate() {
+        let number = (n + 1).to_string();
+        let words: usize = roots.iter().map(|id| branch_words(id, &by_id, &children)).sum();
+        let updated = roots.iter().filter_map(|id| latest(id, &by_id, &children)).max();
+        // Si existe el documento raíz del módulo (`2.`), la sección es ese documento
+        let (heading, nested) = match roots.as_slice() {
+            [root] if id_parts(root).len() == 1 => (link(by_id[root]), children.get(root).cloned().unwrap_or_default()),
+            _ => (format!("Módulo {}", module), roots.clone()),
+        };
+        out.push_str(&format!("## {}. {}\n\n", number, heading));
+        out.push_str(&format!(
+            "_{} palabras · última actualización: {}_\n\n",
+            words,
+            updated.as_deref().unwrap_or("—")
+        ));
+        items(&nested, &number, 0, &by_id, &children, &mut out);
+        if !nested.is_empty() {
+            out.push('\n');
+        }
+    }
+
+    // Sin ID numérico, o con un ID repetido que ya ocupa otro documento
+    let others: Vec<&TocEntry> = docs
+        .iter()
+        .filter(|d| d.id.as_deref().map_or(true, |id| !std::ptr::eq(by_id[id], *d)))
+        .collect();
+    if !others.is_empty() {
+        out.push_str("## Otros documentos\n\n");
+        for doc in others {
+            out.push_str(&format!("- {} — {} palabras\n", link(doc), doc.words));
+        }
+    }
+    out
+}
+
+/// ID numérico al inicio del nombre ("1.2.3 Nombre" → "1.2.3").
+fn numeric_id(stem: &str) -> Option<String> {
+    let id: String = stem
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    let id = id.trim_end_matches('.');
+    (!id.is_empty() && !id.starts_with('.')).then(|| id.to_string())
+}
+
+/// Partes numéricas del ID para ordenar ("1.10" después de "1.9").
+fn id_parts(id: &str) -> Vec<u32> {
+    id.split('.').map(|p| p.parse().unwrap_or(0)).collect()
+}
+
+/// Cuerpo del resumen ajustado al tamaño objetivo: reduce oraciones por hoja
+/// (3 → 1) y, si aún excede, corta la lista de hojas.
+fn render_summary_body(leaves: &[LeafSummary], target_words: usize) -> String {
+    let words = |s: &str| s.split_whitespace().count();
+
+    for sentences in (2..=3).rev() {
+        let body: String = leaves.iter().map(|l| l.render(sentences)).collect();
+        if words(&body) <= target_words {
+            return body;
+        }
+    }
+
+    let mut body = String::new();
+    for (i, leaf) in leaves.iter().enumerate() {
+        let block = leaf.render(1);
+        if i > 0 && words(&body) + words(&block) > target_words {
+            body.push_str(&format!("_… y {} hojas más_\n", leaves.len() - i));
+            break;
+        }
+        body.push_str(&block);
+    }
+    body
 }
 
 #[cfg(test)]
@@ -375,6 +965,190 @@ mod tests {
         assert_eq!(DocType::from_str("doc"), DocType::Document);
     }
 
+    #[test]
+    fn test_numeric_id() {
+        assert_eq!(numeric_id("1.2.3 Nombre"), Some("1.2.3".to_string()));
+        assert_eq!(numeric_id("1. inicio"), Some("1".to_string()));
+        assert_eq!(numeric_id("README"), None);
+    }
+
+    #[test]
+    fn test_gen_summaries_incremental() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("Datos");
+        std::fs::create_dir(&data).unwrap();
+        std::fs::write(data.join("1. Raíz.md"), "---\ntitle: \"Raíz\"\n---\n\nTexto de la raíz.\n").unwrap();
+        std::fs::write(
+            data.join("1.1 Hoja.md"),
+            "---\ntitle: \"Hoja\"\n---\n\nLa hoja explica el flujo. Tiene más detalle.\n\n## Pasos\n",
+        )
+        .unwrap();
+        let out = dir.path().join("_summaries");
+        let out_arg = out.to_str().unwrap();
+
+        let cmd = GenCommand::parse_from(["gen", "summaries", "-o", out_arg]);
+        let first = cmd.run(&data).unwrap();
+        assert_eq!(first.created_files, vec![out.join("1.md")]);
+        let summary = std::fs::read_to_string(out.join("1.md")).unwrap();
+        assert!(summary.contains("Resumen · Módulo 1: Raíz"));
+        assert!(summary.contains("La hoja explica el flujo."));
+        assert!(summary.contains("*Secciones:* Pasos"));
+        assert!(!summary.contains("Texto de la raíz"));
+
+        let second = cmd.run(&data).unwrap();
+        assert!(second.created_files.is_empty());
+        assert_eq!(second.unchanged_files.len(), 1);
+
+        std::fs::write(data.join("1.2 Otra.md"), "Nueva hoja con contenido propio.\n").unwrap();
+        let third = cmd.run(&data).unwrap();
+        assert_eq!(third.created_files.len(), 1);
+
+        // Sin -o: en el data_dir; el módulo sale del `id:` y no del nombre
+        std::fs::write(data.join("Notas.md"), "---\nid: \"2.1\"\ntitle: \"Notas\"\n---\n\nNotas del módulo dos.\n").unwrap();
+        let default = GenCommand::parse_from(["gen", "summaries"]).run(&data).unwrap();
+        let summaries = data.join("_summaries");
+        assert_eq!(default.created_files, vec![summaries.join("1.md"), summaries.join("2.md")]);
+        assert!(std::fs::read_to_string(summaries.join("2.md")).unwrap().contains("Notas del módulo dos."));
+        // Los resúmenes generados no cuentan como documentos
+        assert_eq!(GenCommand::parse_from(["gen", "summaries"]).run(&data).unwrap().unchanged_files.len(), 2);
+    }
+
+    #[test]
+    fn test_gen_anchors() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc = dir.path().join("1 Pagos.md");
+        std::fs::write(&doc, "# Pagos\n\n## Tarjeta\n\n## Tarjeta\n").unwrap();
+        std::fs::write(dir.path().join("2 Listo.md"), "# Listo {#listo}\n").unwrap();
+
+        let index = GenCommand::parse_from(["gen", "anchors", "--index"]);
+        let result = index.run(dir.path()).unwrap();
+        assert_eq!(result.variables_applied, 4);
+        let json = std::fs::read_to_string(dir.path().join("_index/anchors.json")).unwrap();
+        assert!(json.contains("\"tarjeta-1\""));
+        assert!(!std::fs::read_to_string(&doc).unwrap().contains("{#"));
+
+        let cmd = GenCommand::parse_from(["gen", "anchors"]);
+        let result = cmd.run(dir.path()).unwrap();
+        assert_eq!(result.created_files, vec![doc.clone()]);
+        assert_eq!(result.unchanged_files.len(), 1);
+        assert_eq!(
+            std::fs::read_to_string(&doc).unwrap(),
+            "# Pagos {#pagos}\n\n## Tarjeta {#tarjeta}\n\n## Tarjeta {#tarjeta-1}\n"
+        );
+    }
+
+    #[test]
+    fn test_gen_block_ids() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc = dir.path().join("1.2 Pagos.md");
+        std::fs::write(&doc, "# Pagos\n\nSe cobra al mes.\n\n- Tarjeta\n- Débito ^debito\n").unwrap();
+        std::fs::write(dir.path().join("2 Otro.md"), "Sin tocar.\n").unwrap();
+
+        let cmd = GenCommand::parse_from(["gen", "block-ids", "1"]);
+        let result = cmd.run(dir.path()).unwrap();
+        assert_eq!((result.created_files.len(), result.variables_applied), (1, 2));
+        assert!(std::fs::read_to_string(&doc).unwrap().contains("- Débito ^debito\n"));
+        assert_eq!(std::fs::read_to_string(dir.path().join("2 Otro.md")).unwrap(), "Sin tocar.\n");
+        assert!(cmd.run(dir.path()).unwrap().created_files.is_empty());
+    }
+
+    #[test]
+    fn test_gen_adr_numbering_and_supersede() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1 Intro.md"), "# Intro\n").unwrap();
+
+        let first = GenCommand::parse_from(["gen", "adr", "Usar MySQL", "--status", "accepted"]);
+        let created = first.run(dir.path()).unwrap().created_files;
+        assert_eq!(created, vec![dir.path().join("ADR-0001 Usar MySQL.md")]);
+        assert!(std::fs::read_to_string(&created[0]).unwrap().contains("status: \"aceptado\""));
+
+        let second = GenCommand::parse_from(["gen", "adr", "Usar PostgreSQL", "--supersedes", "1"]);
+        let created = second.run(dir.path()).unwrap().created_files;
+        assert_eq!(created[0], dir.path().join("ADR-0002 Usar PostgreSQL.md"));
+        assert!(std::fs::read_to_string(&created[0]).unwrap().contains("supersedes: \"[[ADR-0001 Usar MySQL]]\""));
+        let old = std::fs::read_to_string(&created[1]).unwrap();
+        assert!(old.contains("superseded_by: \"[[ADR-0002 Usar PostgreSQL]]\""));
+
+        assert!(GenCommand::parse_from(["gen", "adr", "Otra", "--supersedes", "ADR-0009"]).run(dir.path()).is_err());
+        assert!(GenCommand::parse_from(["gen", "adr"]).run(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_gen_slugs_stable_with_redirects() {
+        use crate::core::slugs::SlugRegistry;
+
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, fields: &str| {
+            std::fs::write(dir.path().join(name), format!("---\n{}\n---\n# X\n", fields)).unwrap()
+        };
+        write("1 Pagos.md", "id: \"1\"\ntitle: \"Pagos\"");
+        write("2 Pagos.md", "id: \"2\"\ntitle: \"Pagos\"\nslug: \"pagos\"");
+        std::fs::write(dir.path().join("3 Notas.md"), "Sin frontmatter\n").unwrap();
+
+        let cmd = GenCommand::parse_from(["gen", "slugs"]);
+        let first = cmd.run(dir.path()).unwrap();
+        assert_eq!(first.created_files, vec![dir.path().join("1 Pagos.md")]);
+        let doc = std::fs::read_to_string(dir.path().join("1 Pagos.md")).unwrap();
+        assert!(doc.contains("slug: \"pagos-2\"\n---"), "{}", doc);
+        assert!(cmd.run(dir.path()).unwrap().created_files.is_empty());
+
+        // Renombrar el título no cambia el slug; --force lo recalcula con redirect
+        write("1 Pagos.md", "id: \"1\"\ntitle: \"Cobros\"\nslug: \"pagos-2\"");
+        assert!(cmd.run(dir.path()).unwrap().created_files.is_empty());
+        let forced = GenCommand::parse_from(["gen", "slugs", "--force"]).run(dir.path()).unwrap();
+        assert_eq!((forced.created_files.len(), forced.variables_applied), (1, 1));
+        let registry = SlugRegistry::load(dir.path());
+        assert_eq!(registry.redirects["pagos-2"], "cobros");
+        assert_eq!(registry.slugs["2"], "pagos");
+    }
+
+    #[test]
+    fn test_gen_master_toc() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, fields: &str, body: &str| {
+            std::fs::write(dir.path().join(name), format!("---\n{}\n---\n{}\n", fields, body)).unwrap()
+        };
+        write("1. Pagos.md", "title: \"Pagos\"\nlast_updated: \"2024-01-10\"", "uno dos tres");
+        write("1.1 Cobros.md", "title: \"Cobros\"\nlast_updated: \"2024-03-05\"", "cuatro cinco");
+        write("1.1.1 Tarjetas.md", "title: \"Tarjetas\"\nlast_updated: \"2024-02-01\"", "seis");
+        write("1.2 Viejo.md", "title: \"Viejo\"\nstatus: \"archived\"", "no cuenta");
+        write("2.3 Suelto.md", "title: \"Suelto\"\nlast_updated: \"2023-12-31\"", "siete");
+        write("Glosario.md", "title: \"Glosario\"", "ocho");
+
+        let cmd = GenCommand::parse_from(["gen", "master-toc"]);
+        let first = cmd.run(dir.path()).unwrap();
+        let out = dir.path().join("_exports/Tabla de Contenido General.md");
+        assert_eq!((first.created_files.clone(), first.variables_applied), (vec![out.clone()], 5));
+        let toc = std::fs::read_to_string(&out).unwrap();
+        assert!(toc.contains("## 1. [[1. Pagos|Pagos]]\n\n_6 palabras · última actualización: 2024-03-05_"), "{}", toc);
+        assert!(toc.contains("- 1.1 [[1.1 Cobros|Cobros]] — 2 palabras (rama: 3)\n  - 1.1.1 [[1.1.1 Tarjetas|Tarjetas]] — 1 palabras\n"));
+        assert!(toc.contains("## 2. Módulo 2\n\n_1 palabras · última actualización: 2023-12-31_\n\n- 2.1 [[2.3 Suelto|Suelto]]"));
+        assert!(toc.contains("## Otros documentos\n\n- [[Glosario|Glosario]]"));
+        assert!(!toc.contains("Viejo"));
+
+        // Sin cambios en los documentos no se reescribe; la propia tabla no cuenta
+        let second = cmd.run(dir.path()).unwrap();
+        assert_eq!((second.created_files.len(), second.unchanged_files.len()), (0, 1));
+        write("1.2 Viejo.md", "title: \"Viejo\"", "ya no archivado");
+        assert_eq!(cmd.run(dir.path()).unwrap().created_files.len(), 1);
+        assert!(std::fs::read_to_string(&out).unwrap().contains("- 1.2 [[1.2 Viejo|Viejo]]"));
+    }
+
+    #[test]
+    fn test_render_summary_body_target() {
+        let leaves: Vec<LeafSummary> = (1..=20)
+            .map(|i| LeafSummary {
+                id: format!("1.{}", i),
+                title: "Hoja".into(),
+                sentences: vec!["Una oración de prueba bastante larga.".into(); 3],
+                headings: vec![],
+            })
+            .collect();
+        let body = render_summary_body(&leaves, 60);
+        assert!(body.contains("hojas más"));
+        assert!(body.split_whitespace().count() < 80);
+    }
+
     #[test]
     fn test_doc_type_custom() {
         match DocType::from_str("special") {
@@ -388,6 +1162,86 @@ mod tests {
 #[cfg(feature = "cli")]
 pub fn run(cmd: GenCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
     let default_dir = PathBuf::from(&cli.data_dir);
+
+    if cmd.doc_type() == DocType::Summaries {
+        let result = cmd.run(&default_dir)?;
+        println!("📝 Resúmenes por módulo (objetivo: {} palabras)", cmd.target_words);
+        for file in &result.created_files {
+            println!("  ✅ {}", file.display());
+        }
+        if !result.unchanged_files.is_empty() {
+            println!("  ⏭️  {} sin cambios", result.unchanged_files.len());
+        }
+        return Ok(());
+    }
+
+    if cmd.doc_type() == DocType::BlockIds {
+        let result = cmd.run(&default_dir)?;
+        println!(
+            "🧱 {} block-ids nuevos en {} archivos",
+            result.variables_applied,
+            result.created_files.len()
+        );
+        for file in &result.created_files {
+            println!("  ✅ {}", file.display());
+        }
+        if !result.unchanged_files.is_empty() {
+            println!("  ⏭️  {} sin cambios", result.unchanged_files.len());
+        }
+        return Ok(());
+    }
+
+    if cmd.doc_type() == DocType::Adr {
+        let result = cmd.run(&default_dir)?;
+        let mut files = result.created_files.iter();
+        if let Some(created) = files.next() {
+            println!("📜 ADR creado: {}", created.display());
+        }
+        for replaced in files {
+            println!("  ↪ supersedido: {}", replaced.display());
+        }
+        return Ok(());
+    }
+
+    if cmd.doc_type() == DocType::Slugs {
+        let result = cmd.run(&default_dir)?;
+        println!(
+            "🔗 Slugs: {} documentos actualizados, {} sin cambios, {} redirects nuevos",
+            result.created_files.len(),
+            result.unchanged_files.len(),
+            result.variables_applied
+        );
+        for file in &result.created_files {
+            println!("  ✅ {}", file.display());
+        }
+        return Ok(());
+    }
+
+    if cmd.doc_type() == DocType::MasterToc {
+        let result = cmd.run(&default_dir)?;
+        match result.created_files.first() {
+            Some(file) => println!("📑 Tabla de contenido: {} ({} documentos)", file.display(), result.variables_applied),
+            None => println!("⏭️  Tabla de contenido sin cambios (usa --force para regenerar)"),
+        }
+        return Ok(());
+    }
+
+    if cmd.doc_type() == DocType::Anchors {
+        let result = cmd.run(&default_dir)?;
+        if cmd.index {
+            println!("⚓ {} anchors indexados", result.variables_applied);
+        } else {
+            println!("⚓ Anchors en headings: {} archivos actualizados", result.created_files.len());
+        }
+        for file in &result.created_files {
+            println!("  ✅ {}", file.display());
+        }
+        if !result.unchanged_files.is_empty() {
+            println!("  ⏭️  {} sin cambios", result.unchanged_files.len());
+        }
+        return Ok(());
+    }
+
     let data_dir = cmd
         .output
         .as_ref()
@@ -116,7 +116,7 @@ pub struct HealthCommand {
 
 impl HealthCommand {
     pub fn run(&self, data_dir: &std::path::Path) -> OcResult<HealthResult> {
-        use crate::core::loader::quick_stats;
+        use crate::core::loader::{load_project_cached, quick_stats};
 
         let mut result = HealthResult::new();
 
@@ -130,6 +130,20 @@ impl HealthCommand {
             100
         };
 
+        // Metadatos: % de docs con frontmatter válido (el estado cargado se
+        // reutiliza de la caché si ningún archivo cambió)
+        let project = load_project_cached(data_dir)?;
+        let valid = project
+            .documents
+            .iter()
+            .filter(|d| d.frontmatter.validate().is_ok())
+            .count();
+        let metadata = if qs.file_count > 0 {
+            (valid as f64 / qs.file_count as f64 * 100.0) as u8
+        } else {
+            100
+        };
+
         // Estructura: basado en promedio de palabras (>300 = bueno)
         let avg_words = qs.avg_words_per_file();
         let structure = if avg_words >= 500 {
@@ -145,7 +159,7 @@ impl HealthCommand {
         result.add_metric(HealthMetric::new("Cobertura", coverage, 1.0));
         result.add_metric(HealthMetric::new("Enlaces", 95, 0.8)); // Pendiente análisis real
         result.add_metric(HealthMetric::new("Estructura", structure, 1.2));
-        result.add_metric(HealthMetric::new("Metadatos", coverage, 0.5));
+        result.add_metric(HealthMetric::new("Metadatos", metadata, 0.5));
 
         Ok(result)
     }
@@ -177,6 +191,18 @@ mod tests {
         assert_eq!(result.grade, 'F');
     }
 
+    #[test]
+    fn test_metadata_counts_valid_frontmatter() {
+        let project = crate::testing::TestProject::default();
+        project.write("1 Valido.md", "---\nid: \"1\"\ntitle: \"Válido\"\n---\ntexto\n");
+        project.write("2 Sin titulo.md", "---\nid: \"2\"\n---\ntexto\n");
+        project.write("Suelto.md", "texto\n");
+
+        let result = HealthCommand::parse_from(["health"]).run(&project.data_dir).unwrap();
+        let value = |name: &str| result.metrics.iter().find(|m| m.name == name).unwrap().value;
+        assert_eq!((value("Cobertura"), value("Metadatos")), (66, 33));
+    }
+
     #[test]
     fn test_grade_calculation() {
         let mut result = HealthResult::new();
@@ -1,10 +1,73 @@
 //! Comando help - Ayuda extendida.
 //!
-//! Muestra ayuda detallada y ejemplos de uso.
+//! Muestra ayuda detallada y ejemplos de uso. `help <fase>` explica una
+//! fase de verify, `help <regla>` una regla de lint y `help --search` busca
+//! en comandos, fases y reglas.
 
+use crate::core::lint_docs::{get_all_rules, get_rule_doc};
+use crate::core::phase_docs::{resolve_phase, PHASES};
 use crate::errors::OcResult;
 use clap::Parser;
 
+/// Comandos documentados: (nombre, resumen, ejemplos ejecutables).
+const COMMAND_DOCS: &[(&str, &str, &[&str])] = &[
+    ("verify", "Verificación completa del proyecto en 28 fases", &[
+        "oc_diagdoc verify",
+        "oc_diagdoc verify --phase orphans",
+        "oc_diagdoc verify --quick --json",
+    ]),
+    ("lint", "Análisis estático de documentos (reglas L001-L023)", &[
+        "oc_diagdoc lint --fix --dry-run",
+        "oc_diagdoc lint --explain L006",
+        "oc_diagdoc lint --format compact",
+    ]),
+    ("fix", "Corrección automática de tablas, fechas, hashes, breadcrumbs y conteos", &[
+        "oc_diagdoc fix --dates --hashes",
+        "oc_diagdoc fix --breadcrumbs --diff",
+        "oc_diagdoc fix --descendants",
+    ]),
+    ("sync", "Sincronización de fechas, hashes y conteos jerárquicos", &[
+        "oc_diagdoc sync --dry-run",
+        "oc_diagdoc sync --descendants",
+    ]),
+    ("stats", "Estadísticas del proyecto y nube de términos", &[
+        "oc_diagdoc stats",
+        "oc_diagdoc stats --wordcloud --top 30",
+    ]),
+    ("search", "Búsqueda de texto en la documentación", &["oc_diagdoc search \"comisión\""]),
+    ("links", "Análisis de enlaces internos, rotos y huérfanos", &["oc_diagdoc links"]),
+    ("tree", "Árbol jerárquico de documentos", &["oc_diagdoc tree"]),
+    ("audit", "Auditoría de calidad y sugerencia de tags", &["oc_diagdoc audit --suggest-tags --apply"]),
+    ("coverage", "Cobertura de contenido por módulo", &["oc_diagdoc coverage"]),
+    ("export", "Exportar a otros formatos, por rama y con redacción", &[
+        "oc_diagdoc export --format html --root 3 --include-linked",
+        "oc_diagdoc export --redact",
+    ]),
+    ("suggest-placement", "Sugerir padre e ID para un documento suelto (sin ID)", &[
+        "oc_diagdoc suggest-placement borrador.md --top 3",
+    ]),
+    ("migrate", "Migraciones y adopción de documentos sueltos (huérfanos de inbox)", &[
+        "oc_diagdoc migrate --adopt inbox/ --parent 4.2 --dry-run",
+    ]),
+    ("health", "Puntaje de salud del proyecto", &["oc_diagdoc health"]),
+    ("compress", "Compresión del proyecto para contexto LLM", &["oc_diagdoc compress"]),
+];
+
+/// Minúsculas sin tildes, para búsquedas ("huérfano" ≈ "huerfano").
+fn fold(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' => 'a',
+            'é' => 'e',
+            'í' => 'i',
+            'ó' => 'o',
+            'ú' | 'ü' => 'u',
+            _ => c,
+        })
+        .collect()
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // HELP TYPES
 // ═══════════════════════════════════════════════════════════════════════════
@@ -59,27 +122,99 @@ pub struct HelpCommand {
     /// Listar todos los temas.
     #[arg(short, long)]
     pub list: bool,
+
+    /// Buscar en la documentación de comandos, fases y reglas.
+    #[arg(short, long, value_name = "TEXTO")]
+    pub search: Option<String>,
 }
 
 impl HelpCommand {
     pub fn run(&self) -> OcResult<HelpResult> {
+        if let Some(query) = &self.search {
+            return Ok(Self::search(query));
+        }
+
         let topic = self.topic.as_deref().unwrap_or("general");
         let mut result = HelpResult::new(topic);
 
-        result.add_section(HelpSection::new(
-            "Descripción",
-            "oc_diagdoc - Sistema de diagnóstico de documentación",
-        ));
-        result.add_section(HelpSection::new("Uso", "oc_diagdoc <comando> [opciones]"));
+        if let Some((name, summary, examples)) = COMMAND_DOCS.iter().find(|(n, _, _)| *n == topic) {
+            result.add_section(HelpSection::new("Descripción", summary));
+            result.add_section(HelpSection::new("Ejemplos", &examples.join("\n")));
+            result.add_section(HelpSection::new("Opciones", &format!("oc_diagdoc {} --help", name)));
+        } else if let Some(phase) = resolve_phase(topic) {
+            result.topic = format!("Fase {}: {} ({})", phase.id, phase.title, phase.name);
+            result.add_section(HelpSection::new("Qué valida", phase.description));
+            result.add_section(HelpSection::new("Ejemplo de error", phase.example_error));
+            result.add_section(HelpSection::new("Por qué importa", phase.why));
+            result.add_section(HelpSection::new("Cómo corregirlo", phase.how_to_fix));
+            if !phase.fix_commands.is_empty() {
+                result.add_section(HelpSection::new("Comandos", &phase.fix_commands.join("\n")));
+            }
+            result.add_section(HelpSection::new(
+                "Ejecutar",
+                &format!("oc_diagdoc verify --phase {}", phase.name),
+            ));
+        } else if let Some(rule) = get_rule_doc(&topic.to_uppercase()) {
+            result.topic = format!("Regla {}: {}", rule.code, rule.name);
+            result.add_section(HelpSection::new("Descripción", rule.description));
+            result.add_section(HelpSection::new("Incorrecto", rule.example_bad));
+            result.add_section(HelpSection::new("Correcto", rule.example_good));
+            result.add_section(HelpSection::new("Sugerencia", rule.suggestion));
+        } else {
+            result.add_section(HelpSection::new(
+                "Descripción",
+                "oc_diagdoc - Sistema de diagnóstico de documentación",
+            ));
+            result.add_section(HelpSection::new("Uso", "oc_diagdoc <comando> [opciones]"));
+        }
 
         Ok(result)
     }
 
+    /// Busca el texto en comandos, fases y reglas; una sección por coincidencia.
+    pub fn search(query: &str) -> HelpResult {
+        let needle = fold(query.trim());
+        let matches = |fields: &[&str]| fields.iter().any(|f| fold(f).contains(&needle));
+        let mut result = HelpResult::new(&format!("búsqueda: {}", query.trim()));
+
+        for (name, summary, examples) in COMMAND_DOCS {
+            if matches(&[name, summary]) || examples.iter().any(|e| matches(&[e])) {
+                let content = format!("{}\n{}", summary, examples.join("\n"));
+                result.add_section(HelpSection::new(&format!("Comando {}", name), &content));
+            }
+        }
+        for phase in PHASES {
+            if matches(&[phase.name, phase.title, phase.description, phase.example_error]) {
+                let content = format!(
+                    "{}\nEjemplo: {}\noc_diagdoc verify --phase {}",
+                    phase.description, phase.example_error, phase.name
+                );
+                result.add_section(HelpSection::new(
+                    &format!("Fase {}: {}", phase.id, phase.title),
+                    &content,
+                ));
+            }
+        }
+        let mut rules: Vec<_> = get_all_rules().into_values().collect();
+        rules.sort_by_key(|r| r.code);
+        for rule in rules {
+            if matches(&[rule.code, rule.name, rule.description, rule.suggestion]) {
+                let content = format!("{}\n{}", rule.description, rule.suggestion);
+                result.add_section(HelpSection::new(
+                    &format!("Regla {}: {}", rule.code, rule.name),
+                    &content,
+                ));
+            }
+        }
+
+        result
+    }
+
     pub fn available_topics() -> Vec<&'static str> {
-        vec![
-            "general", "verify", "stats", "search", "deps", "tree", "lint", "health", "coverage",
-            "export", "compress",
-        ]
+        let mut topics = vec!["general"];
+        topics.extend(COMMAND_DOCS.iter().map(|(name, _, _)| *name));
+        topics.extend(PHASES.iter().map(|p| p.name));
+        topics
     }
 }
 
@@ -104,6 +239,7 @@ mod tests {
         let cmd = HelpCommand {
             topic: Some("verify".to_string()),
             list: false,
+            search: None,
         };
         let result = cmd.run().unwrap();
         assert!(!result.sections.is_empty());
@@ -114,6 +250,26 @@ mod tests {
         let topics = HelpCommand::available_topics();
         assert!(topics.contains(&"general"));
         assert!(topics.contains(&"verify"));
+        assert!(topics.contains(&"orphans"));
+    }
+
+    #[test]
+    fn test_help_phase_and_rule() {
+        let phase = HelpCommand::parse_from(["help", "19"]).run().unwrap();
+        assert_eq!(phase.topic, "Fase 19: Documentos huérfanos (orphans)");
+        assert!(phase.sections.iter().any(|s| s.content.contains("HUÉRFANO:")));
+
+        let rule = HelpCommand::parse_from(["help", "l006"]).run().unwrap();
+        assert!(rule.topic.starts_with("Regla L006"));
+    }
+
+    #[test]
+    fn test_help_search_ignores_accents() {
+        let result = HelpCommand::parse_from(["help", "--search", "huerfano"]).run().unwrap();
+        let titles: Vec<&str> = result.sections.iter().map(|s| s.title.as_str()).collect();
+        assert!(titles.contains(&"Fase 19: Documentos huérfanos"));
+        assert!(titles.contains(&"Comando migrate"));
+        assert!(HelpCommand::search("zzzz").sections.is_empty());
     }
 }
 
@@ -127,6 +283,10 @@ pub fn run(cmd: HelpCommand, _cli: &crate::commands::CliConfig) -> anyhow::Resul
         }
     } else {
         let result = cmd.run()?;
+        if cmd.search.is_some() && result.sections.is_empty() {
+            println!("🔍 Sin resultados para '{}'", cmd.search.as_deref().unwrap_or_default());
+            return Ok(());
+        }
         println!("📖 Ayuda: {}\n", result.topic);
         for section in &result.sections {
             println!("## {}\n{}\n", section.title, section.content);
@@ -0,0 +1,230 @@
+//! Comando index - Índices precomputados.
+//!
+//! `index build --embeddings` construye el índice semántico usado por
+//! `search --semantic`, reutilizando vectores por content_hash.
+//! `index build --fulltext` construye el índice de palabras de `search
+//! --boolean` (`_index/fulltext.json`).
+
+use crate::core::config::OcConfig;
+use crate::core::embeddings::{BuildStats, Embedder, EmbeddingIndex};
+use crate::core::fulltext::{FullTextIndex, FullTextStats, FULLTEXT_INDEX};
+use crate::errors::{OcError, OcResult};
+use clap::Parser;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// INDEX TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Resultado del comando index.
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexResult {
+    pub action: String,
+    pub index_path: PathBuf,
+    pub model: String,
+    pub files: usize,
+    pub stats: BuildStats,
+    /// Términos distintos (índice full-text).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms: Option<usize>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// INDEX COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Comando de índices.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "index", about = "Construir índices (embeddings)")]
+pub struct IndexCommand {
+    /// Acción: build o stats.
+    #[arg(default_value = "stats")]
+    pub action: String,
+
+    /// Índice de embeddings para búsqueda semántica.
+    #[arg(long)]
+    pub embeddings: bool,
+
+    /// Índice full-text para búsqueda booleana (`search --boolean`).
+    #[arg(long, conflicts_with = "embeddings")]
+    pub fulltext: bool,
+
+    /// Ruta del proyecto.
+    #[arg(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// Ignorar cache y re-vectorizar todo.
+    #[arg(long)]
+    pub rebuild: bool,
+
+    /// Output JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl IndexCommand {
+    pub fn run(&self, data_dir: &Path) -> OcResult<IndexResult> {
+        if self.fulltext {
+            return self.run_fulltext(data_dir);
+        }
+        let config = OcConfig::from_cwd().unwrap_or_default().embeddings;
+        let embedder = Embedder::new(config.clone());
+        let previous = EmbeddingIndex::load(&config.index_path)?;
+
+        match self.action.as_str() {
+            "build" => {
+                if !self.embeddings {
+                    return Err(OcError::InvalidArgument(
+                        "Indique el índice a construir: index build --embeddings|--fulltext".to_string(),
+                    ));
+                }
+                let cache = previous.as_ref().filter(|_| !self.rebuild);
+                let (index, stats) =
+                    EmbeddingIndex::build(data_dir, &embedder, config.chunk_words, cache)?;
+                index.save(&config.index_path)?;
+                Ok(IndexResult {
+                    action: "build".to_string(),
+                    index_path: config.index_path,
+                    model: index.model,
+                    files: index.files.len(),
+                    stats,
+                    terms: None,
+                })
+            }
+            "stats" => {
+                let index = previous.ok_or_else(|| {
+                    OcError::Custom(format!(
+                        "No existe índice en {} (ejecute `index build --embeddings`)",
+                        config.index_path.display()
+                    ))
+                })?;
+                let chunks = index.files.values().map(|f| f.chunks.len()).sum();
+                Ok(IndexResult {
+                    action: "stats".to_string(),
+                    index_path: config.index_path,
+                    model: index.model,
+                    files: index.files.len(),
+                    stats: BuildStats {
+                        chunks,
+                        ..Default::default()
+                    },
+                    terms: None,
+                })
+            }
+            other => Err(OcError::InvalidArgument(format!(
+                "Acción desconocida: '{}' (use build o stats)",
+                other
+            ))),
+        }
+    }
+
+    /// `build`/`stats` del índice full-text (`--fulltext`).
+    fn run_fulltext(&self, data_dir: &Path) -> OcResult<IndexResult> {
+        let previous = FullTextIndex::load(data_dir);
+        let (index, stats) = match self.action.as_str() {
+            "build" => {
+                let (index, stats) = FullTextIndex::build(data_dir, previous.as_ref().filter(|_| !self.rebuild))?;
+                index.save(data_dir)?;
+                (index, stats)
+            }
+            "stats" => {
+                let index = previous.ok_or_else(|| {
+                    OcError::Custom(format!(
+                        "No existe índice en {} (ejecute `index build --fulltext`)",
+                        data_dir.join(FULLTEXT_INDEX).display()
+                    ))
+                })?;
+                let stats = FullTextStats { terms: index.term_count(), ..Default::default() };
+                (index, stats)
+            }
+            other => {
+                return Err(OcError::InvalidArgument(format!(
+                    "Acción desconocida: '{}' (use build o stats)",
+                    other
+                )))
+            }
+        };
+        Ok(IndexResult {
+            action: self.action.clone(),
+            index_path: data_dir.join(FULLTEXT_INDEX),
+            model: "fulltext".to_string(),
+            files: index.files.len(),
+            stats: BuildStats {
+                files_embedded: stats.files_indexed,
+                files_cached: stats.files_cached,
+                chunks: 0,
+            },
+            terms: Some(stats.terms),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_requires_index_kind() {
+        let dir = tempfile::tempdir().unwrap();
+        let cmd = IndexCommand::parse_from(["index", "build"]);
+        assert!(cmd.run(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_fulltext_build_reuses_unchanged() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1 pagos.md"), "Pago con tarjeta\n").unwrap();
+        std::fs::write(dir.path().join("2 spei.md"), "Pago por SPEI\n").unwrap();
+        let build = IndexCommand::parse_from(["index", "build", "--fulltext"]);
+        let result = build.run(dir.path()).unwrap();
+        assert_eq!((result.files, result.stats.files_embedded, result.terms), (2, 2, Some(5)));
+
+        std::fs::write(dir.path().join("2 spei.md"), "Pago por SPEI o CoDi\n").unwrap();
+        let result = build.run(dir.path()).unwrap();
+        assert_eq!((result.stats.files_embedded, result.stats.files_cached), (1, 1));
+        let stats = IndexCommand::parse_from(["index", "stats", "--fulltext"]).run(dir.path()).unwrap();
+        assert_eq!(stats.terms, Some(7));
+    }
+
+    #[test]
+    fn test_unknown_action() {
+        let dir = tempfile::tempdir().unwrap();
+        let cmd = IndexCommand::parse_from(["index", "purge"]);
+        assert!(matches!(cmd.run(dir.path()), Err(OcError::InvalidArgument(_))));
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: IndexCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    let default_dir = PathBuf::from(&cli.data_dir);
+    let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
+    let result = cmd.run(data_dir)?;
+
+    if cmd.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    println!("🧭 Índice: {} ({})", result.index_path.display(), result.model);
+    if let Some(terms) = result.terms {
+        if result.action == "build" {
+            println!(
+                "  ✅ {} archivos indexados, {} desde cache",
+                result.stats.files_embedded, result.stats.files_cached
+            );
+        }
+        println!("  📊 {} archivos, {} términos", result.files, terms);
+        return Ok(());
+    }
+    if result.action == "build" {
+        println!(
+            "  ✅ {} archivos vectorizados, {} desde cache",
+            result.stats.files_embedded, result.stats.files_cached
+        );
+    }
+    println!("  📊 {} archivos, {} fragmentos", result.files, result.stats.chunks);
+
+    Ok(())
+}
@@ -1,11 +1,21 @@
 //! Comando init - Inicialización de proyectos.
 //!
 //! Crea la estructura inicial de un proyecto de documentación.
-
-use crate::errors::OcResult;
+//!
+//! Con `--interactive` un wizard pregunta nombre, módulos iniciales,
+//! convención de status e idioma, y genera el proyecto completo:
+//! Contextualizador con índice de módulos, raíces de módulo, config,
+//! `.ocignore` y hook pre-commit.
+
+use crate::core::config::{CONFIG_DIR, CONFIG_FILE};
+use crate::core::files::OCIGNORE_FILE;
+use crate::core::starters::{self, Starter};
+use crate::errors::{OcError, OcResult};
+use crate::DEFAULT_DATA_DIR;
 use clap::Parser;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // INIT TYPES
@@ -42,6 +52,276 @@ impl InitResult {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// WIZARD
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Módulos iniciales máximos del wizard.
+const MAX_MODULES: usize = 20;
+
+/// Idioma de los textos generados.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Language {
+    Es,
+    En,
+}
+
+impl Language {
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "es" | "español" | "espanol" => Some(Self::Es),
+            "en" | "english" | "inglés" | "ingles" => Some(Self::En),
+            _ => None,
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Es => "es",
+            Self::En => "en",
+        }
+    }
+
+    fn text<'a>(&self, es: &'a str, en: &'a str) -> &'a str {
+        match self {
+            Self::Es => es,
+            Self::En => en,
+        }
+    }
+}
+
+/// Convención de valores de `status` en el frontmatter. Ambas las entiende
+/// `DocumentStatus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum StatusConvention {
+    /// `borrador`, `activo`, `revisado`...
+    Es,
+    /// `draft`, `active`, `reviewed`...
+    En,
+}
+
+impl StatusConvention {
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "es" | "borrador" => Some(Self::Es),
+            "en" | "draft" => Some(Self::En),
+            _ => None,
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Es => "es",
+            Self::En => "en",
+        }
+    }
+
+    /// Valores que acepta `verify`, en orden de ciclo de vida.
+    pub fn values(&self) -> &'static [&'static str] {
+        match self {
+            Self::Es => &["pendiente", "borrador", "activo", "aceptado", "deprecado"],
+            Self::En => &["stub", "draft", "review", "approved"],
+        }
+    }
+
+    fn stub(&self) -> &'static str {
+        self.values()[0]
+    }
+
+    fn draft(&self) -> &'static str {
+        self.values()[1]
+    }
+}
+
+/// Respuestas del wizard de `init --interactive`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WizardAnswers {
+    pub name: String,
+    pub modules: usize,
+    pub status: StatusConvention,
+    pub language: Language,
+}
+
+/// Pregunta hasta obtener un valor válido. Enter acepta el default; sin más
+/// entrada (EOF) también.
+fn ask<T, R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    prompt: &str,
+    default: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> OcResult<T> {
+    loop {
+        write!(output, "{} [{}]: ", prompt, default)?;
+        output.flush()?;
+        let mut line = String::new();
+        let answer = match input.read_line(&mut line)? {
+            0 => default,
+            _ if line.trim().is_empty() => default,
+            _ => line.trim(),
+        };
+        match parse(answer) {
+            Some(value) => return Ok(value),
+            None if answer == default => {
+                return Err(OcError::Custom(format!("Default inválido para '{}': {}", prompt, default)))
+            }
+            None => writeln!(output, "  ⚠️  Valor inválido: {}", answer)?,
+        }
+    }
+}
+
+/// Wizard de `init --interactive`: lee las respuestas de `input` y escribe
+/// las preguntas en `output`.
+pub fn run_wizard<R: BufRead, W: Write>(input: &mut R, output: &mut W, default_name: &str) -> OcResult<WizardAnswers> {
+    writeln!(output, "🧙 Nuevo proyecto oc_diagdoc (Enter acepta el valor entre corchetes)")?;
+    let name = ask(input, output, "Nombre del proyecto", default_name, |s| {
+        (!s.is_empty()).then(|| s.to_string())
+    })?;
+    let modules = ask(input, output, &format!("Módulos iniciales (1-{})", MAX_MODULES), "3", |s| {
+        s.parse().ok().filter(|n| (1..=MAX_MODULES).contains(n))
+    })?;
+    let status = ask(input, output, "Convención de status (es: borrador/activo, en: draft/active)", "es", StatusConvention::parse)?;
+    let language = ask(input, output, "Idioma de la documentación (es/en)", "es", Language::parse)?;
+    Ok(WizardAnswers {
+        name,
+        modules,
+        status,
+        language,
+    })
+}
+
+/// Título del módulo `n`.
+fn module_title(answers: &WizardAnswers, n: usize) -> String {
+    format!("{} {}", answers.language.text("Módulo", "Module"), n)
+}
+
+/// Archivos del proyecto completo, relativos a la raíz.
+pub fn scaffold(answers: &WizardAnswers, now: &str) -> Vec<(PathBuf, String)> {
+    let lang = answers.language;
+    let draft = answers.status.draft();
+    let data = Path::new(DEFAULT_DATA_DIR);
+    let mut files = Vec::new();
+
+    let mut index = format!(
+        "| # | {} | {} |\n|---|--------|--------|\n",
+        lang.text("Módulo", "Module"),
+        lang.text("Estado", "Status")
+    );
+    for n in 1..=answers.modules {
+        index.push_str(&format!("| {} | [[{}. {}]] | {} |\n", n, n, module_title(answers, n), draft));
+    }
+    let statuses: Vec<String> = answers.status.values().iter().map(|s| format!("`{}`", s)).collect();
+    files.push((
+        data.join("0. Contextualizador.md"),
+        format!(
+            "---\nid: \"0\"\ntitle: \"Contextualizador\"\nparent: \"0\"\nbreadcrumb: \"Contextualizador\"\n\
+             type: indice_maestro\nstatus: {draft}\ncreated: \"{now}\"\nlast_updated: \"{now}\"\n---\n\n\
+             # {name}\n\n## {about}\n\n{about_body}\n\n## {modules_heading}\n\n{index}\n\
+             ## {conventions}\n\n- {ids}\n- {status_line}: {statuses}\n- {verify}\n",
+            name = answers.name,
+            draft = draft,
+            now = now,
+            about = lang.text("Descripción general", "Overview"),
+            about_body = lang.text(
+                "[Propósito, alcance y audiencia del proyecto]",
+                "[Project purpose, scope and audience]"
+            ),
+            modules_heading = lang.text("Índice de módulos", "Module index"),
+            index = index,
+            conventions = lang.text("Convenciones", "Conventions"),
+            ids = lang.text(
+                "IDs jerárquicos: `1`, `1.1`, `1.1.1`; cada documento declara su `parent`",
+                "Hierarchical ids: `1`, `1.1`, `1.1.1`; every document declares its `parent`"
+            ),
+            status_line = lang.text("Valores de `status`", "`status` values"),
+            statuses = statuses.join(", "),
+            verify = lang.text(
+                "`oc_diagdoc verify` antes de cada commit (hook pre-commit)",
+                "`oc_diagdoc verify` before every commit (pre-commit hook)"
+            ),
+        ),
+    ));
+
+    for n in 1..=answers.modules {
+        let title = module_title(answers, n);
+        files.push((
+            data.join(format!("{}. {}.md", n, title)),
+            format!(
+                "---\nid: \"{n}\"\ntitle: \"{title}\"\nparent: \"0\"\nbreadcrumb: \"{title}\"\n\
+                 type: modulo_padre\nstatus: {draft}\ncreated: \"{now}\"\nlast_updated: \"{now}\"\nchildren_count: 0\n---\n\n\
+                 # {title}\n\n{back}: [[0. Contextualizador]]\n\n## {about}\n\n{about_body}\n\n## {docs}\n\n\
+                 | ID | {doc_title} | {state} |\n|----|--------|--------|\n| {n}.1 | {pending} | {stub} |\n",
+                n = n,
+                title = title,
+                draft = draft,
+                now = now,
+                back = lang.text("Volver a", "Back to"),
+                about = lang.text("Descripción", "Description"),
+                about_body = lang.text("[Qué cubre este módulo]", "[What this module covers]"),
+                docs = lang.text("Documentos", "Documents"),
+                doc_title = lang.text("Título", "Title"),
+                state = lang.text("Estado", "Status"),
+                pending = lang.text("Por crear", "To be written"),
+                stub = answers.status.stub(),
+            ),
+        ));
+    }
+
+    files.extend(support_files(
+        &answers.name,
+        lang,
+        &[("language", lang.code()), ("status_convention", answers.status.code())],
+        "",
+    ));
+    files
+}
+
+/// Config, `.ocignore` y hook pre-commit del proyecto. `extra` son líneas
+/// YAML de nivel raíz que se agregan a la config.
+fn support_files(name: &str, lang: Language, variables: &[(&str, &str)], extra: &str) -> Vec<(PathBuf, String)> {
+    let mut config = format!(
+        "# Configuración oc_diagdoc: {name}\n# Generado por `oc_diagdoc init`\n\n\
+         data_dir: {data}\n\nvariables:\n  project: \"{name}\"\n",
+        name = name,
+        data = DEFAULT_DATA_DIR,
+    );
+    for (key, value) in variables {
+        config.push_str(&format!("  {}: {}\n", key, value));
+    }
+    config.push_str("\nvalidation:\n  check_yaml: true\n  check_links: true\n  check_orphans: true\n");
+    if !extra.is_empty() {
+        config.push('\n');
+        config.push_str(extra);
+    }
+
+    vec![
+        (Path::new(CONFIG_DIR).join(CONFIG_FILE), config),
+        (
+            Path::new(DEFAULT_DATA_DIR).join(OCIGNORE_FILE),
+            lang.text(
+                "# Rutas que oc_diagdoc no escanea (una por línea, coincide si la ruta la contiene)\n.obsidian/\n.trash/\n_borradores/\n",
+                "# Paths oc_diagdoc does not scan (one per line, matches if the path contains it)\n.obsidian/\n.trash/\n_drafts/\n",
+            )
+            .to_string(),
+        ),
+        (
+            Path::new(CONFIG_DIR).join("hooks").join("pre-commit"),
+            "#!/bin/sh\n# Hook pre-commit generado por `oc_diagdoc init`\nexec oc_diagdoc verify --quick\n".to_string(),
+        ),
+    ]
+}
+
+/// Archivos de un starter (`init --template software-spec`), relativos a la raíz.
+pub fn starter_files(starter: &Starter, name: &str, now: &str) -> Vec<(PathBuf, String)> {
+    let mut files: Vec<(PathBuf, String)> = starter
+        .files(name, now)
+        .into_iter()
+        .map(|(path, content)| (Path::new(DEFAULT_DATA_DIR).join(path), content))
+        .collect();
+    files.extend(support_files(name, Language::Es, &[("starter", starter.name)], &starter.config()));
+    files
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // INIT COMMAND
 // ═══════════════════════════════════════════════════════════════════════════
@@ -63,7 +343,7 @@ pub struct InitCommand {
     pub force: bool,
 
     // L20.1: Template de proyecto
-    /// Template: proyecto o modulo.
+    /// Template: proyecto, modulo o un starter (software-spec, legal, api-docs).
     #[arg(long, short = 't', default_value = "proyecto")]
     pub template: String,
 
@@ -75,10 +355,30 @@ pub struct InitCommand {
     /// Modo preview: muestra qué se crearía sin ejecutar.
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Wizard interactivo: nombre, módulos, convención de status e idioma.
+    #[arg(short, long)]
+    pub interactive: bool,
 }
 
 impl InitCommand {
     pub fn run(&self) -> OcResult<InitResult> {
+        if self.interactive {
+            let stdin = std::io::stdin();
+            let answers = run_wizard(&mut stdin.lock(), &mut std::io::stderr(), &self.default_name())?;
+            return self.run_scaffold(&answers);
+        }
+        if !matches!(self.template.as_str(), "proyecto" | "modulo") {
+            return match starters::find(&self.template) {
+                Some(starter) => self.run_starter(starter),
+                None => Err(OcError::Custom(format!(
+                    "Template desconocido: '{}'. Disponibles: proyecto, modulo, {}",
+                    self.template,
+                    starters::names().join(", ")
+                ))),
+            };
+        }
+
         let mut result = InitResult::new(self.path.clone());
 
         // AN-08 FIX: Dry-run mode - muestra preview sin ejecutar
@@ -124,6 +424,85 @@ impl InitCommand {
         Ok(result)
     }
 
+    /// Nombre por defecto: `--name` o el directorio del proyecto.
+    fn default_name(&self) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+        let path = if self.path == Path::new(".") {
+            std::env::current_dir().unwrap_or_else(|_| self.path.clone())
+        } else {
+            self.path.clone()
+        };
+        path.file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("MiProyecto")
+            .to_string()
+    }
+
+    /// Escribe el proyecto del wizard.
+    pub fn run_scaffold(&self, answers: &WizardAnswers) -> OcResult<InitResult> {
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+        self.write_project(&answers.name, scaffold(answers, &now))
+    }
+
+    /// Escribe un starter embebido.
+    pub fn run_starter(&self, starter: &Starter) -> OcResult<InitResult> {
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+        let name = self.default_name();
+        self.write_project(&name, starter_files(starter, &name, &now))
+    }
+
+    /// Escribe un proyecto completo. Instala el hook en `.git/hooks` si el
+    /// proyecto ya es un repo y no tiene uno.
+    fn write_project(&self, name: &str, files: Vec<(PathBuf, String)>) -> OcResult<InitResult> {
+        let mut result = InitResult::new(self.path.clone());
+
+        if self.dry_run {
+            eprintln!("🔍 [dry-run] Se crearía en: {}", self.path.display());
+            for (path, _) in &files {
+                eprintln!("  📄 {}", path.display());
+            }
+            return Ok(result);
+        }
+
+        if self.path.join(CONFIG_DIR).join(CONFIG_FILE).exists() && !self.force {
+            return Err(OcError::Io(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                "Proyecto ya existe. Usa --force para sobrescribir.",
+            )));
+        }
+
+        for (relative, content) in files {
+            let path = self.path.join(relative);
+            if let Some(parent) = path.parent() {
+                if !parent.exists() {
+                    std::fs::create_dir_all(parent)?;
+                    result.directories_created.push(parent.to_path_buf());
+                }
+            }
+            std::fs::write(&path, content)?;
+            result.files_created.push(path);
+        }
+
+        let hook = self.path.join(CONFIG_DIR).join("hooks").join("pre-commit");
+        let git_hook = self.path.join(".git").join("hooks").join("pre-commit");
+        let mut hooks = vec![hook];
+        if git_hook.parent().is_some_and(Path::is_dir) && !git_hook.exists() {
+            std::fs::copy(&hooks[0], &git_hook)?;
+            result.files_created.push(git_hook.clone());
+            hooks.push(git_hook);
+        }
+        #[cfg(unix)]
+        for hook in &hooks {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(hook, std::fs::Permissions::from_mode(0o755))?;
+        }
+
+        eprintln!("✅ Proyecto '{}' inicializado en: {}", name, self.path.display());
+        Ok(result)
+    }
+
     /// L19.1: Crea estructura de directorios para proyecto.
     fn create_project_structure(&self) -> OcResult<Vec<PathBuf>> {
         let preset = self.preset_enum();
@@ -331,6 +710,7 @@ mod tests {
             template: "proyecto".to_string(),
             name: None,
             dry_run: false,
+            interactive: false,
         };
         assert_eq!(cmd.preset_enum(), InitPreset::Minimal);
     }
@@ -344,10 +724,68 @@ mod tests {
             template: "proyecto".to_string(),
             name: Some("TestProject".to_string()),
             dry_run: false,
+            interactive: false,
         };
         assert_eq!(cmd.preset_enum(), InitPreset::Full);
         assert!(cmd.force);
     }
+
+    #[test]
+    fn test_wizard_scaffold() {
+        let mut input = std::io::Cursor::new("Flota\n0\n2\nen\n\n");
+        let mut output = Vec::new();
+        let answers = run_wizard(&mut input, &mut output, "dir").unwrap();
+        assert_eq!(answers.name, "Flota");
+        assert_eq!(answers.modules, 2);
+        assert_eq!((answers.status, answers.language), (StatusConvention::En, Language::Es));
+        assert!(String::from_utf8(output).unwrap().contains("Valor inválido: 0"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let cmd = InitCommand {
+            path: dir.path().to_path_buf(),
+            preset: "standard".to_string(),
+            force: false,
+            template: "proyecto".to_string(),
+            name: None,
+            dry_run: false,
+            interactive: true,
+        };
+        let result = cmd.run_scaffold(&answers).unwrap();
+        assert_eq!(result.files_created.len(), 6);
+        let data = dir.path().join("Datos");
+        let master = std::fs::read_to_string(data.join("0. Contextualizador.md")).unwrap();
+        assert!(master.contains("| 2 | [[2. Módulo 2]] | draft |"));
+        let root = std::fs::read_to_string(data.join("1. Módulo 1.md")).unwrap();
+        let fm = crate::core::yaml::parse_frontmatter(&root).unwrap().frontmatter;
+        assert_eq!((fm.id.as_str(), fm.parent.as_deref()), ("1", Some("0")));
+
+        let config = crate::core::config::OcConfig::from_file(dir.path().join(".oc_diagdoc/config.yaml")).unwrap();
+        assert_eq!(config.data_dir, PathBuf::from("Datos"));
+        assert_eq!(config.variables["project"], serde_yaml::Value::from("Flota"));
+        assert!(cmd.run_scaffold(&answers).is_err());
+    }
+    #[test]
+    fn test_init_starter_template() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cmd = InitCommand {
+            path: dir.path().to_path_buf(),
+            preset: "standard".to_string(),
+            force: false,
+            template: "legal".to_string(),
+            name: Some("Contratos".to_string()),
+            dry_run: false,
+            interactive: false,
+        };
+        cmd.run().unwrap();
+        assert!(dir.path().join("Datos/2.1 Política de privacidad.md").exists());
+        let config = crate::core::config::OcConfig::from_file(dir.path().join(".oc_diagdoc/config.yaml")).unwrap();
+        assert_eq!(config.variables["starter"], serde_yaml::Value::from("legal"));
+        let profile = crate::core::config::DirProfile::resolve(&config.profiles, Path::new("1. Términos y condiciones.md"));
+        assert!(profile.unwrap().strict_schema);
+
+        cmd.template = "recetas".to_string();
+        assert!(cmd.run().unwrap_err().to_string().contains("software-spec, legal, api-docs"));
+    }
 }
 
 /// Función run para CLI.
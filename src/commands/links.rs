@@ -1,9 +1,19 @@
 //! Comando links - Gestión de enlaces.
 //!
-//! Analiza y repara enlaces entre documentos.
+//! Analiza y repara enlaces entre documentos. `--graph-orphans` lista los
+//! documentos sin padre válido con padres candidatos (prefijo de ID, título
+//! similar y enlaces entrantes); `--adopt` escribe el elegido en `parent`.
+//!
+//! Con una convención de wiki-links (`validation.link_convention` o
+//! `--convention`: nombre, ID o ruta relativa), los links a documentos
+//! existentes escritos de otra forma son no-estándar y `--normalize` los
+//! reescribe.
 
-use crate::errors::OcResult;
+use crate::core::links::{normalize_links, LinkConvention, LinkTargets};
+use crate::errors::{OcError, OcResult};
 use clap::Parser;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -24,6 +34,7 @@ pub enum LinkStatus {
     /// Enlace no-estándar - tiene path completo en lugar de solo nombre.
     /// Ejemplo: [[Proyecto OnlyCarNLD/Datos/2.8.1 Politicas_Seguridad]]
     /// Debería ser: [[2.8.1 Politicas_Seguridad]]
+    /// Con convención configurada: no la cumple (`normalized` = forma correcta).
     NonStandard,
 }
 
@@ -34,7 +45,8 @@ pub struct Link {
     pub target: String,
     pub line: usize,
     pub status: LinkStatus,
-    /// Nombre normalizado (solo el nombre del archivo sin path).
+    /// Nombre normalizado (solo el nombre del archivo sin path, o la forma
+    /// de la convención configurada).
     pub normalized: Option<String>,
 }
 
@@ -107,6 +119,84 @@ impl Default for LinksResult {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// ORPHANS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Candidatos sugeridos por huérfano.
+const MAX_CANDIDATES: usize = 3;
+/// Similitud de título mínima (Jaccard de palabras ponderado por IDF) para
+/// sugerir un padre.
+const MIN_TITLE_SIMILARITY: f64 = 0.2;
+
+/// Padre sugerido para un huérfano.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParentCandidate {
+    pub id: String,
+    pub title: String,
+    pub score: f64,
+    /// Por qué se sugiere (prefijo de ID, título similar, lo enlaza).
+    pub reasons: Vec<String>,
+}
+
+/// Documento sin padre válido.
+#[derive(Debug, Clone, Serialize)]
+pub struct OrphanReport {
+    pub file: PathBuf,
+    pub id: String,
+    pub title: String,
+    pub reason: String,
+    pub candidates: Vec<ParentCandidate>,
+}
+
+/// Palabras significativas de un título (4+ letras, minúsculas, sin números).
+fn title_words(title: &str) -> HashSet<String> {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 4 && !w.chars().all(|c| c.is_ascii_digit()))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Peso IDF de cada palabra: las que están en todos los títulos
+/// ("Documento", "Módulo") pesan 0 y no cuentan como similitud.
+fn idf_weights(titles: &[HashSet<String>]) -> HashMap<String, f64> {
+    let mut df: HashMap<&str, usize> = HashMap::new();
+    for words in titles {
+        for word in words {
+            *df.entry(word).or_default() += 1;
+        }
+    }
+    let n = titles.len() as f64;
+    df.into_iter()
+        .map(|(word, count)| (word.to_string(), ((n + 1.0) / (count as f64 + 1.0)).ln()))
+        .collect()
+}
+
+/// Jaccard ponderado: peso de las palabras comunes sobre el de la unión.
+fn weighted_jaccard(a: &HashSet<String>, b: &HashSet<String>, weights: &HashMap<String, f64>) -> f64 {
+    let weight = |words: &mut dyn Iterator<Item = &String>| -> f64 {
+        words.map(|w| weights.get(w).copied().unwrap_or(0.0)).sum()
+    };
+    let union = weight(&mut a.union(b));
+    if union <= 0.0 {
+        0.0
+    } else {
+        weight(&mut a.intersection(b)) / union
+    }
+}
+
+/// Escribe `parent_id` como `parent` del documento huérfano.
+pub fn adopt(orphan: &OrphanReport, parent_id: &str) -> OcResult<()> {
+    use crate::core::files::{read_file_content, write_file_atomic};
+    use crate::core::yaml::update_field;
+
+    let content = read_file_content(&orphan.file)?;
+    let updated = update_field(&content, "parent", &format!("\"{}\"", parent_id))
+        .map_err(|_| OcError::MissingFrontmatter(orphan.file.clone()))?;
+    write_file_atomic(&orphan.file, &updated)
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // LINKS COMMAND
 // ═══════════════════════════════════════════════════════════════════════════
@@ -143,13 +233,32 @@ pub struct LinksCommand {
     /// Crear backup antes de modificar archivos.
     #[arg(long)]
     pub backup: bool,
+
+    /// Documentos sin padre válido, con la razón y padres candidatos.
+    #[arg(long)]
+    pub graph_orphans: bool,
+
+    /// Con --graph-orphans: elegir interactivamente el padre de cada
+    /// huérfano y escribirlo en el frontmatter.
+    #[arg(long, requires = "graph_orphans")]
+    pub adopt: bool,
+
+    /// Convención de wiki-links: name, id o path (sobrescribe
+    /// validation.link_convention de config).
+    #[arg(long, value_name = "CONV")]
+    pub convention: Option<LinkConvention>,
+
+    /// Reescribir los wiki-links a la convención (con --backup, deja `.md.bak`).
+    #[arg(long)]
+    pub normalize: bool,
 }
 
 
 impl LinksCommand {
     pub fn run(&self, data_dir: &std::path::Path) -> OcResult<LinksResult> {
-        use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+        use crate::core::files::{get_all_md_files, ScanOptions};
         use crate::core::patterns::{RE_WIKI_LINK, RE_MD_LINK};
+        use crate::core::pipeline::DocumentCache;
 
         let mut result = LinksResult::new();
 
@@ -159,9 +268,13 @@ impl LinksCommand {
 
         let options = ScanOptions::new();
         let files = get_all_md_files(data_dir, &options)?;
+        let mut owners: Option<crate::core::anchors::BlockOwners> = None;
+        let convention = self.link_convention();
+        let targets = convention.map(|_| LinkTargets::new(data_dir, &files));
+        let documents = DocumentCache::new();
 
         for file_path in &files {
-            if let Ok(content) = read_file_content(file_path) {
+            if let Ok(content) = documents.content(file_path) {
                 // FP-01 FIX: Tracking de bloques de código
                 let mut in_code_block = false;
                 
@@ -201,13 +314,26 @@ impl LinksCommand {
                             .trim()
                             .to_string();
 
+                        // Con convención: el destino existe pero está escrito de otra forma
+                        let violation = targets
+                            .as_ref()
+                            .zip(convention)
+                            .and_then(|(targets, convention)| targets.violation(target, convention));
+                        let has_path = has_path && convention.is_none();
+
                         // Si tiene path, es NonStandard (aunque el archivo exista)
-                        let status = if has_path {
+                        let status = if has_path || violation.is_some() {
                             // Verificar si el archivo destino existe para dar info adicional
                             let _exists = self.file_exists(&normalized_name, &files);
                             LinkStatus::NonStandard
                         } else {
-                            self.check_link_status(data_dir, file_path, target, &files)
+                            match self.check_link_status(data_dir, file_path, target, &files) {
+                                // [[Doc#^id]]: el documento existe pero el bloque tiene que estar en él
+                                LinkStatus::Valid if !self.block_exists(file_part, &files, &mut owners) => {
+                                    LinkStatus::Broken
+                                }
+                                status => status,
+                            }
                         };
 
                         result.add_link(Link {
@@ -218,7 +344,7 @@ impl LinksCommand {
                             normalized: if has_path {
                                 Some(normalized_name)
                             } else {
-                                None
+                                violation
                             },
                         });
                     }
@@ -257,6 +383,159 @@ impl LinksCommand {
         Ok(result)
     }
 
+    /// Convención de `--convention` o, sin flag, de `validation.link_convention`.
+    pub fn link_convention(&self) -> Option<LinkConvention> {
+        self.convention.or_else(|| {
+            crate::core::config::OcConfig::from_cwd()
+                .ok()
+                .and_then(|c| c.validation.link_convention)
+        })
+    }
+
+    /// Reescribe los wiki-links de todos los documentos a la convención;
+    /// devuelve los archivos modificados con cuántos links cambió cada uno.
+    pub fn normalize(&self, data_dir: &std::path::Path) -> OcResult<Vec<(PathBuf, usize)>> {
+        use crate::core::files::{backup_file, get_all_md_files, read_file_content, write_file_atomic, ScanOptions};
+
+        let convention = self.link_convention().ok_or_else(|| {
+            OcError::Custom("--normalize requiere --convention o validation.link_convention en la config".to_string())
+        })?;
+        let files = get_all_md_files(data_dir, &ScanOptions::new())?;
+        let targets = LinkTargets::new(data_dir, &files);
+        let mut changed = Vec::new();
+        for path in &files {
+            let content = read_file_content(path)?;
+            let (updated, count) = normalize_links(&content, &targets, convention);
+            if count == 0 {
+                continue;
+            }
+            if self.backup {
+                backup_file(path)?;
+            }
+            write_file_atomic(path, &updated)?;
+            changed.push((path.clone(), count));
+        }
+        Ok(changed)
+    }
+
+    /// Huérfanos (sin `parent`, `parent` inexistente o a sí mismo) con
+    /// hasta `MAX_CANDIDATES` padres sugeridos, el más probable primero.
+    pub fn graph_orphans(&self, data_dir: &std::path::Path) -> OcResult<Vec<OrphanReport>> {
+        use crate::core::links::extract_links;
+        use crate::core::pipeline::{id_within, ProjectPipeline};
+
+        let pipeline = ProjectPipeline::new(data_dir);
+        let docs = pipeline.parse(&pipeline.scan()?);
+        let ids: Vec<String> = docs
+            .iter()
+            .map(|d| d.effective_id().unwrap_or(d.stem()).trim_end_matches('.').to_string())
+            .collect();
+        let titles: Vec<String> = docs
+            .iter()
+            .map(|d| d.title.clone().unwrap_or_else(|| d.stem().to_string()))
+            .collect();
+        let known: HashSet<&str> = ids.iter().map(String::as_str).collect();
+        let words: Vec<HashSet<String>> = titles.iter().map(|t| title_words(t)).collect();
+        let weights = idf_weights(&words);
+
+        let mut by_name: HashMap<String, usize> = HashMap::new();
+        for (i, doc) in docs.iter().enumerate() {
+            by_name.insert(doc.stem().to_lowercase(), i);
+            by_name.entry(ids[i].to_lowercase()).or_insert(i);
+        }
+        // Enlaces entrantes: destino → orígenes
+        let mut incoming: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (i, doc) in docs.iter().enumerate() {
+            for link in extract_links(&doc.content).into_iter().filter(|l| l.is_internal()) {
+                let target = link.target.split('#').next().unwrap_or_default().trim().trim_end_matches(".md");
+                if let Some(&j) = by_name.get(&target.to_lowercase()).filter(|&&j| j != i) {
+                    incoming.entry(j).or_default().insert(i);
+                }
+            }
+        }
+
+        let mut orphans = Vec::new();
+        for (i, doc) in docs.iter().enumerate() {
+            let id = &ids[i];
+            if id == "0" || doc.content.contains("type: indice_maestro") {
+                continue;
+            }
+            let parent = doc.parent.as_deref().map(|p| p.trim().trim_end_matches('.'));
+            let reason = match parent {
+                // Módulo de primer nivel: raíz aunque no exista el documento 0
+                Some("0") => continue,
+                None | Some("") | Some("null") | Some("~") => "sin parent".to_string(),
+                Some(p) if p == id => "parent apunta a sí mismo".to_string(),
+                Some(p) if !known.contains(p) => format!("parent inexistente: '{}'", p),
+                Some(_) => continue,
+            };
+
+            let mut candidates: Vec<ParentCandidate> = Vec::new();
+            for (j, candidate_id) in ids.iter().enumerate() {
+                // Ni él mismo ni sus descendientes (crearía un ciclo)
+                if j == i || id_within(candidate_id, id) {
+                    continue;
+                }
+                let mut score = 0.0;
+                let mut reasons = Vec::new();
+                if id.rsplit_once('.').is_some_and(|(prefix, _)| prefix == candidate_id) {
+                    score += 3.0;
+                    reasons.push("prefijo de ID".to_string());
+                } else if id_within(id, candidate_id) {
+                    score += 1.0;
+                    reasons.push("ancestro por ID".to_string());
+                }
+                let similarity = weighted_jaccard(&words[i], &words[j], &weights);
+                if similarity >= MIN_TITLE_SIMILARITY {
+                    score += 2.0 * similarity;
+                    reasons.push(format!("título similar ({:.0}%)", similarity * 100.0));
+                }
+                if incoming.get(&i).is_some_and(|from| from.contains(&j)) {
+                    score += 2.0;
+                    reasons.push("lo enlaza".to_string());
+                }
+                if score > 0.0 {
+                    candidates.push(ParentCandidate {
+                        id: candidate_id.clone(),
+                        title: titles[j].clone(),
+                        score,
+                        reasons,
+                    });
+                }
+            }
+            candidates.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+            candidates.truncate(MAX_CANDIDATES);
+
+            orphans.push(OrphanReport {
+                file: doc.path.clone(),
+                id: id.clone(),
+                title: titles[i].clone(),
+                reason,
+                candidates,
+            });
+        }
+        orphans.sort_by(|a, b| crate::core::files::natural_cmp(&a.id, &b.id));
+        Ok(orphans)
+    }
+
+    /// ¿El bloque de `[[Doc#^id]]` / `[[Doc^id]]` está en `Doc`? Sin bloque, `true`.
+    fn block_exists(
+        &self,
+        target: &str,
+        files: &[std::path::PathBuf],
+        owners: &mut Option<crate::core::anchors::BlockOwners>,
+    ) -> bool {
+        use crate::core::anchors::{block_owners, names_doc, split_target, Fragment};
+
+        let (doc, Fragment::Block(id)) = split_target(target) else {
+            return true;
+        };
+        owners
+            .get_or_insert_with(|| block_owners(files))
+            .get(id)
+            .is_some_and(|stems| stems.iter().any(|stem| names_doc(doc, stem)))
+    }
+
     /// Verifica si un archivo existe en la lista de archivos.
     fn file_exists(&self, name: &str, files: &[std::path::PathBuf]) -> bool {
         let name_lower = name.to_lowercase();
@@ -293,8 +572,8 @@ impl LinksCommand {
         let target_clean = target_clean.split('|').next().unwrap_or(&target_clean);
         // Paso 3: Quitar path si existe [[path/doc]] -> doc
         let target_name = target_clean.split('/').next_back().unwrap_or(target_clean);
-        // Paso 4: Quitar anchor [[doc#section]] -> doc
-        let target_name = target_name.split('#').next().unwrap_or(target_name).trim();
+        // Paso 4: Quitar anchor [[doc#section]] / bloque ![[doc^id]] -> doc
+        let target_name = target_name.split(['#', '^']).next().unwrap_or(target_name).trim();
 
         // Intentar resolver el path usando nombre limpio
         let resolved = if target_name.starts_with('/') {
@@ -350,6 +629,7 @@ impl LinksCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TestProject;
 
     #[test]
     fn test_links_result_new() {
@@ -405,6 +685,89 @@ mod tests {
 
         assert_eq!(result.health_score(), 50.0);
     }
+
+    #[test]
+    fn test_block_refs_in_links() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1 Alta.md"), "DNI obligatorio. ^dni\n").unwrap();
+        std::fs::write(dir.path().join("2 Ref.md"), "[[1 Alta#^dni]] [[1 Alta#^cuit]] ![[1 Alta^dni]]\n").unwrap();
+
+        let result = LinksCommand::parse_from(["links"]).run(dir.path()).unwrap();
+        let broken: Vec<&str> = result.broken_links().iter().map(|l| l.target.as_str()).collect();
+        assert_eq!(broken, vec!["1 Alta#^cuit"]);
+        assert_eq!(result.total_valid, 2);
+    }
+
+    #[test]
+    fn test_link_convention_and_normalize() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("2. Pagos")).unwrap();
+        std::fs::write(dir.path().join("2. Pagos/2.1 Tarjetas.md"), "# Tarjetas\n").unwrap();
+        let doc = dir.path().join("1 Inicio.md");
+        std::fs::write(&doc, "[[2.1 Tarjetas]] [[2.1#Alta|alta]] [[2. Pagos/2.1 Tarjetas]] [[Falta]]\n").unwrap();
+
+        let cmd = LinksCommand::parse_from(["links", "--convention", "id"]);
+        let result = cmd.run(dir.path()).unwrap();
+        let nonstandard: Vec<(&str, Option<&str>)> = result
+            .links
+            .iter()
+            .filter(|l| l.is_nonstandard())
+            .map(|l| (l.target.as_str(), l.normalized.as_deref()))
+            .collect();
+        assert_eq!(nonstandard, vec![("2.1 Tarjetas", Some("2.1")), ("2. Pagos/2.1 Tarjetas", Some("2.1"))]);
+        assert_eq!((result.total_valid, result.total_broken), (1, 1));
+
+        let cmd = LinksCommand::parse_from(["links", "--normalize", "--convention", "path", "--backup"]);
+        assert_eq!(cmd.normalize(dir.path()).unwrap(), vec![(doc.clone(), 2)]);
+        assert_eq!(
+            std::fs::read_to_string(&doc).unwrap(),
+            "[[2. Pagos/2.1 Tarjetas]] [[2. Pagos/2.1 Tarjetas#Alta|alta]] [[2. Pagos/2.1 Tarjetas]] [[Falta]]\n"
+        );
+        assert!(doc.with_extension("md.bak").exists());
+        assert_eq!(cmd.run(dir.path()).unwrap().total_nonstandard, 0);
+    }
+
+    #[test]
+    fn test_graph_orphans_and_adopt() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        project.write("0. Contextualizador.md", "---\nid: \"0\"\nparent: \"0\"\ntype: indice_maestro\n---\n");
+        project.write("2. Pagos.md", "---\nid: \"2\"\nparent: \"0\"\ntitle: \"Pagos\"\n---\n");
+        project.write("2.1 Tarjetas.md", "---\nid: \"2.1\"\nparent: \"2\"\ntitle: \"Pagos con tarjeta\"\n---\n[[2.1.4 Reembolsos]]\n");
+        project.write("2.1.4 Reembolsos.md", "---\nid: \"2.1.4\"\ntitle: \"Reembolsos de tarjeta\"\n---\nx\n");
+        project.write("3. Envios.md", "---\nid: \"3\"\nparent: \"9\"\ntitle: \"Envíos\"\n---\n");
+
+        let cmd = LinksCommand::parse_from(["links", "--graph-orphans"]);
+        let orphans = cmd.graph_orphans(dir).unwrap();
+        let summary: Vec<(&str, &str)> = orphans.iter().map(|o| (o.id.as_str(), o.reason.as_str())).collect();
+        assert_eq!(summary, vec![("2.1.4", "sin parent"), ("3", "parent inexistente: '9'")]);
+
+        let best = &orphans[0].candidates[0];
+        assert_eq!(best.id, "2.1");
+        assert_eq!(best.reasons, vec!["prefijo de ID", "título similar (28%)", "lo enlaza"]);
+        assert_eq!(orphans[0].candidates[1].id, "2");
+
+        adopt(&orphans[0], &best.id).unwrap();
+        let written = project.read("2.1.4 Reembolsos.md");
+        assert!(written.contains("parent: \"2.1\"\n---"));
+        let ids: Vec<String> = cmd.graph_orphans(dir).unwrap().into_iter().map(|o| o.id).collect();
+        assert_eq!(ids, vec!["3"]);
+    }
+
+    #[test]
+    fn test_graph_orphans_roots_and_generic_titles() {
+        let project = TestProject::default();
+        // Sin documento 0: los módulos con parent "0" siguen siendo raíces
+        project.write("1 Documento 1.md", "---\nid: \"1\"\nparent: \"0\"\ntitle: \"Documento 1\"\n---\n");
+        project.write("2 Documento 2.md", "---\nid: \"2\"\ntitle: \"Documento 2\"\n---\n");
+
+        let cmd = LinksCommand::parse_from(["links", "--graph-orphans"]);
+        let orphans = cmd.graph_orphans(&project.data_dir).unwrap();
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].id, "2");
+        // Títulos que solo comparten la palabra común no se parecen
+        assert!(orphans[0].candidates.is_empty(), "{:?}", orphans[0].candidates);
+    }
 }
 
 /// Función run para CLI.
@@ -414,13 +777,53 @@ pub fn run(cmd: LinksCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
     use regex::Regex;
     
     let data_dir = std::path::Path::new(&cli.data_dir);
+
+    if cmd.graph_orphans {
+        let orphans = cmd.graph_orphans(data_dir)?;
+        println!("👻 {} documentos huérfanos", orphans.len());
+        for orphan in &orphans {
+            println!("\n📄 {} {} → {}", orphan.id, orphan.title, orphan.reason);
+            if orphan.candidates.is_empty() {
+                println!("   (sin candidatos)");
+            }
+            for c in &orphan.candidates {
+                println!("   💡 {} {} [{}]", c.id, c.title, c.reasons.join(", "));
+            }
+            if cmd.adopt && !orphan.candidates.is_empty() {
+                let mut items: Vec<String> = orphan.candidates.iter().map(|c| format!("{} {}", c.id, c.title)).collect();
+                items.push("Omitir".to_string());
+                let choice = dialoguer::Select::new()
+                    .with_prompt(format!("Padre para {}", orphan.id))
+                    .items(&items)
+                    .default(0)
+                    .interact()
+                    .map_err(|e| OcError::Custom(format!("Entrada interactiva: {}", e)))?;
+                if let Some(parent) = orphan.candidates.get(choice) {
+                    adopt(orphan, &parent.id)?;
+                    println!("   ✅ parent: \"{}\"", parent.id);
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    if cmd.normalize {
+        let changed = cmd.normalize(data_dir)?;
+        let total: usize = changed.iter().map(|(_, count)| count).sum();
+        println!("🔗 {} links reescritos en {} archivos", total, changed.len());
+        for (file, count) in &changed {
+            println!("  ✅ {} ({})", file.display(), count);
+        }
+        return Ok(());
+    }
     
     // Handle --find-refs: buscar todas las referencias a un documento
     if let Some(ref target_id) = cmd.find_refs {
         println!("\n🔍 Buscando referencias a: {}", target_id);
         println!("{}", "─".repeat(60));
         
-        let pattern = Regex::new(&format!(r"\[\[{}[|\]]", regex::escape(target_id)))?;
+        // También [[Doc#sección]], [[Doc#^id]] y ![[Doc^id]]
+        let pattern = Regex::new(&format!(r"\[\[{}[|\]#^]", regex::escape(target_id)))?;
         let options = ScanOptions::new();
         let files = get_all_md_files(data_dir, &options)?;
         
@@ -495,7 +898,23 @@ pub fn run(cmd: LinksCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
     println!("  🔄 Circulares: {}", result.total_circular);
     println!("  📊 Salud: {:.1}%", result.health_score());
 
-    if result.total_nonstandard > 0 {
+    if let Some(convention) = cmd.link_convention().filter(|_| result.total_nonstandard > 0) {
+        println!("\n⚠️  Enlaces fuera de la convención '{}':", convention.as_str());
+        for link in result.links.iter().filter(|l| l.is_nonstandard()).take(10) {
+            println!(
+                "  {}:{} [[{}]] → [[{}]]",
+                link.source.display(),
+                link.line,
+                link.target,
+                link.normalized.as_deref().unwrap_or_default()
+            );
+        }
+        // Con --convention, el hint la repite: sin config, --normalize sola falla
+        match cmd.convention {
+            Some(flag) => println!("  Reescribir todos: oc_diagdoc links --normalize --convention {}", flag.as_str()),
+            None => println!("  Reescribir todos: oc_diagdoc links --normalize"),
+        }
+    } else if result.total_nonstandard > 0 {
         println!("\n⚠️  Los enlaces no-estándar tienen path completo.");
         println!("  Formato correcto: [[nombre_archivo]]");
         println!("  Formato incorrecto: [[Proyecto/Datos/nombre_archivo]]");
@@ -2,9 +2,18 @@
 //!
 //! Detecta problemas de estilo y estructura.
 
+use crate::core::fixes::{self, FixSource};
+use crate::core::links::{link_violations, LinkConvention, LinkTargets};
+use crate::core::patch::PatchReview;
+use crate::core::pipeline::DocumentCache;
 use crate::errors::OcResult;
+use crate::traits::{self, Fix, Fixable};
 use clap::Parser;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // LINT TYPES
@@ -28,6 +37,8 @@ pub struct LintIssue {
     pub line: Option<usize>,
     pub severity: LintSeverity,
     pub fixable: bool,
+    /// Corrección del archivo (todas sus reglas fixables juntas), si es fixable.
+    pub fix: Option<Fix>,
 }
 
 impl LintIssue {
@@ -39,6 +50,7 @@ impl LintIssue {
             line: None,
             severity: LintSeverity::Error,
             fixable: false,
+            fix: None,
         }
     }
 
@@ -50,8 +62,26 @@ impl LintIssue {
             line: None,
             severity: LintSeverity::Warning,
             fixable: false,
+            fix: None,
         }
     }
+
+    /// Línea estilo compilador `archivo:línea:col: CÓDIGO mensaje` (errorformat
+    /// `%f:%l:%c: %m`). Las reglas reportan por línea, así que la columna es 1.
+    pub fn to_compact(&self) -> String {
+        format!(
+            "{}:{}:1: {} {}",
+            self.file.display(),
+            self.line.unwrap_or(1),
+            self.code,
+            self.message.replace('\n', " ")
+        )
+    }
+
+    /// Categoría de la regla (formato, estructura, links, tablas...).
+    pub fn category(&self) -> &'static str {
+        rule_category(&self.code)
+    }
 }
 
 /// Resultado del lint.
@@ -60,6 +90,8 @@ pub struct LintResult {
     pub issues: Vec<LintIssue>,
     pub files_checked: usize,
     pub files_with_issues: usize,
+    /// Reporte de `--timings`, si se pidió.
+    pub timings: Option<crate::core::timings::TimingReport>,
 }
 
 impl LintResult {
@@ -68,6 +100,7 @@ impl LintResult {
             issues: Vec::new(),
             files_checked: 0,
             files_with_issues: 0,
+            timings: None,
         }
     }
 
@@ -120,6 +153,14 @@ pub struct LintCommand {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Mostrar diff coloreado de cada corrección (requiere --fix).
+    #[arg(long)]
+    pub diff: bool,
+
+    /// Aceptar/rechazar cada hunk interactivamente (requiere --fix).
+    #[arg(long)]
+    pub interactive: bool,
+
     /// Solo errores, omitir warnings/hints.
     #[arg(long)]
     pub errors_only: bool,
@@ -137,6 +178,15 @@ pub struct LintCommand {
     #[arg(long)]
     pub summary: bool,
 
+    /// Analizar solo el módulo N (equivale a --id-prefix N).
+    #[arg(long, value_name = "N", conflicts_with = "id_prefix")]
+    pub module: Option<u32>,
+
+    /// Analizar solo el subárbol de un ID (ej: --id-prefix 2.5). Las reglas
+    /// que cuentan hijos siguen viendo todo el proyecto.
+    #[arg(long, value_name = "ID")]
+    pub id_prefix: Option<String>,
+
     /// P3-A3: Mostrar detalle de todos los fixes aplicables.
     #[arg(long)]
     pub show_fixes: bool,
@@ -144,6 +194,75 @@ pub struct LintCommand {
     /// RFC-03: Explicar regla de lint (ej: --explain L006).
     #[arg(long, value_name = "CODE")]
     pub explain: Option<String>,
+
+    /// Fix de L016 (secciones vacías): placeholder o remove.
+    #[arg(long, value_name = "MODE", default_value = "placeholder", value_parser = ["placeholder", "remove"])]
+    pub empty_headings: String,
+
+    /// Formato de salida: text, compact (`archivo:línea:col: CÓDIGO mensaje`)
+    /// o hyperlink (text con `archivo:línea` clicable vía OSC 8).
+    #[arg(long, default_value = "text", value_parser = ["text", "compact", "hyperlink"])]
+    pub format: String,
+
+    /// Abrir en $EDITOR los issues N (1-based, en el orden listado; sin
+    /// valor, el primero).
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "1", value_delimiter = ',')]
+    pub open: Vec<usize>,
+
+    /// Reportar tiempos por regla y por archivo, lecturas de disco y memoria pico.
+    #[arg(long)]
+    pub timings: bool,
+
+    /// L020 estricto: también NBSP del texto, comillas tipográficas
+    /// mezcladas y archivos con UTF-8 inválido, todo como error.
+    #[arg(long)]
+    pub strict_utf8: bool,
+
+    /// Revisar también los directorios derivados (`derived_dirs` de la config).
+    #[arg(long)]
+    pub include_derived: bool,
+
+    /// Solo reglas de estas categorías (ej: --category a11y). Las categorías
+    /// opt-in (a11y) solo corren si se piden así o con --rule.
+    #[arg(long, value_name = "CAT", value_delimiter = ',')]
+    pub category: Vec<String>,
+
+    /// Omitir las reglas de estas categorías (ej: --disable-category formato).
+    #[arg(long, value_name = "CAT", value_delimiter = ',')]
+    pub disable_category: Vec<String>,
+
+    /// Archivos más lentos a listar con --timings.
+    #[arg(long, value_name = "N", default_value_t = 10)]
+    pub timings_top: usize,
+
+    /// Perfiles por subdirectorio; `None` = sección `profiles` de la config.
+    #[arg(skip)]
+    pub profiles: Option<Vec<crate::core::config::DirProfile>>,
+
+    /// Colector de `--timings`; lo crea `run()`.
+    #[arg(skip)]
+    pub profiler: Option<Arc<crate::core::timings::Profiler>>,
+
+    /// Caché de documentos; compartirla entre comandos evita releer archivos.
+    #[arg(skip)]
+    pub documents: Option<Arc<DocumentCache>>,
+
+    /// Variables de proyecto para L017; `None` = sección `variables` de la config.
+    #[arg(skip)]
+    pub variables: Option<Arc<crate::core::variables::Variables>>,
+
+    /// Guía de estilo para L018; `None` = `styleguide.yaml` del proyecto.
+    #[arg(skip)]
+    pub styleguide: Option<Arc<crate::core::styleguide::StyleGuide>>,
+
+    /// Trato por tipo para L019; `None` = sección `register` de la config.
+    #[arg(skip)]
+    pub register: Option<Arc<crate::core::config::RegisterConfig>>,
+
+    /// Convención de wiki-links e índice de documentos para L014; `None` =
+    /// `validation.link_convention` de la config (`name` si no está).
+    #[arg(skip)]
+    pub links: Option<Arc<(LinkConvention, LinkTargets)>>,
 }
 
 impl LintCommand {
@@ -157,31 +276,124 @@ impl LintCommand {
             return Ok(LintResult::new());
         }
 
+        let known = categories();
+        if let Some(unknown) = self
+            .category
+            .iter()
+            .chain(&self.disable_category)
+            .find(|c| !known.iter().any(|k| k.eq_ignore_ascii_case(c)))
+        {
+            return Err(crate::errors::OcError::Custom(format!(
+                "Categoría desconocida: '{}'. Disponibles: {}",
+                unknown,
+                known.join(", ")
+            )));
+        }
+
+        if self.documents.is_none()
+            || self.variables.is_none()
+            || self.styleguide.is_none()
+            || self.register.is_none()
+            || self.links.is_none()
+            || (self.timings && self.profiler.is_none())
+        {
+            let mut cmd = self.clone();
+            if cmd.timings && cmd.profiler.is_none() {
+                cmd.profiler = Some(Arc::new(crate::core::timings::Profiler::new()));
+            }
+            if cmd.documents.is_none() {
+                cmd.documents = Some(Arc::new(
+                    DocumentCache::new().with_profiler(cmd.profiler.clone()),
+                ));
+            }
+            if cmd.variables.is_none() {
+                cmd.variables = Some(Arc::new(crate::core::variables::Variables::from_cwd()));
+            }
+            if cmd.styleguide.is_none() {
+                cmd.styleguide = Some(Arc::new(crate::core::styleguide::StyleGuide::from_cwd()));
+            }
+            if cmd.register.is_none() {
+                let config = crate::core::config::OcConfig::from_cwd().unwrap_or_default();
+                cmd.register = Some(Arc::new(config.register));
+            }
+            if cmd.links.is_none() {
+                let config = crate::core::config::OcConfig::from_cwd().unwrap_or_default();
+                let convention = config.validation.link_convention.unwrap_or(LinkConvention::Name);
+                let files = get_all_md_files(data_dir, &ScanOptions::new())?;
+                cmd.links = Some(Arc::new((convention, LinkTargets::new(data_dir, &files))));
+            }
+            return cmd.run(data_dir);
+        }
+
+        let profiles = match &self.profiles {
+            Some(p) => p.clone(),
+            None => crate::core::config::OcConfig::from_cwd()
+                .map(|c| c.profiles)
+                .unwrap_or_default(),
+        };
+
         let mut result = LintResult::new();
         let mut files_fixed = 0usize;
 
-        let options = ScanOptions::new();
-        let files = get_all_md_files(data_dir, &options)?;
+        let derived = if self.include_derived {
+            Vec::new()
+        } else {
+            crate::core::config::OcConfig::from_cwd().unwrap_or_default().derived_dirs
+        };
+        let options = ScanOptions::new().with_skip_dirs(derived);
+        let mut files = get_all_md_files(data_dir, &options)?;
+        if let Some(prefix) = crate::core::pipeline::id_scope(self.module, self.id_prefix.as_deref()) {
+            if let Some(cache) = &self.documents {
+                files.retain(|path| cache.in_scope(path, &prefix));
+            }
+        }
 
         result.files_checked = files.len();
         let mut files_with_issues_set: HashSet<PathBuf> = HashSet::new();
 
         for file_path in &files {
-            if let Ok(content) = read_file_content(file_path) {
+            if let Some(profiler) = &self.profiler {
+                profiler.begin_file(file_path);
+            }
+            let content = match &self.documents {
+                Some(cache) => cache.content(file_path).ok(),
+                None => read_file_content(file_path).ok().map(Arc::new),
+            };
+            if let Some(content) = content {
+                let mut issues = self.lint_file(file_path, &content, data_dir);
+
+                // Cada issue fixable lleva la corrección completa del archivo
+                let file_fix = if self.fix || issues.iter().any(|i| i.fixable) {
+                    self.file_fix(file_path, &content, &issues, data_dir)
+                } else {
+                    None
+                };
+                for issue in issues.iter_mut().filter(|i| i.fixable) {
+                    issue.fix = file_fix.clone();
+                }
+
                 // L4.4: Aplicar --fix si se solicitó
-                if self.fix {
-                    if let Some(fixed_content) = self.fix_file(file_path, &content) {
-                        if self.dry_run {
+                if let Some(fix) = file_fix.filter(|_| self.fix) {
+                    let review = PatchReview::new(self.diff, self.interactive, self.dry_run);
+                    match fixes::apply(&fix, &review) {
+                        Ok(traits::FixResult::Applied { .. }) if self.dry_run => {
                             eprintln!("🔍 [DRY-RUN] Sería corregido: {}", file_path.display());
-                        } else {
-                            if std::fs::write(file_path, &fixed_content).is_ok() {
-                                files_fixed += 1;
+                        }
+                        Ok(traits::FixResult::Applied { .. }) => {
+                            files_fixed += 1;
+                            if let Some(cache) = &self.documents {
+                                cache.invalidate(file_path);
                             }
                         }
+                        _ => {}
                     }
                 }
 
-                let issues = self.lint_file(file_path, &content, data_dir);
+                // Reglas omitidas por el perfil del directorio
+                let relative = file_path.strip_prefix(data_dir).unwrap_or(file_path);
+                if let Some(profile) = crate::core::config::DirProfile::resolve(&profiles, relative) {
+                    issues.retain(|issue| !profile.skips_rule(&issue.code));
+                }
 
                 if !issues.is_empty() {
                     files_with_issues_set.insert(file_path.clone());
@@ -192,10 +404,20 @@ impl LintCommand {
                         result.issues.push(issue);
                     }
                 }
+            } else if self.strict_utf8 && self.should_run_rule("L020") {
+                if let Some(issue) = self.invalid_utf8_issue(file_path) {
+                    files_with_issues_set.insert(file_path.clone());
+                    result.issues.push(issue);
+                }
             }
         }
 
+        // Orden estable: archivo (natural), línea y, a igual línea, orden de reglas
+        result.issues.sort_by(|a, b| {
+            crate::core::files::natural_path_cmp(&a.file, &b.file).then(a.line.unwrap_or(0).cmp(&b.line.unwrap_or(0)))
+        });
         result.files_with_issues = files_with_issues_set.len();
+        result.timings = self.profiler.as_ref().map(|p| p.report(self.timings_top));
 
         // Agregar estadística de archivos corregidos (usar info log si hay fix)
         if self.fix && files_fixed > 0 {
@@ -205,6 +427,53 @@ impl LintCommand {
         Ok(result)
     }
 
+    /// Corrección de todas las reglas fixables de un archivo (L003/L005…,
+    /// L015, L016, L018 con `autofix` y L020), como un único fix de contenido.
+    fn file_fix(&self, file_path: &PathBuf, content: &str, issues: &[LintIssue], data_dir: &std::path::Path) -> Option<Fix> {
+        let fixed = self.fix_file(file_path, content);
+        let fixed = if self.should_run_rule("L015") {
+            let base = fixed.as_deref().unwrap_or(content);
+            self.fix_index_table(file_path, base, data_dir).or(fixed)
+        } else {
+            fixed
+        };
+        let fixed = if self.should_run_rule("L016") {
+            let base = fixed.as_deref().unwrap_or(content);
+            self.fix_empty_headings(base).or(fixed)
+        } else {
+            fixed
+        };
+        let fixed = match self.styleguide.as_deref().filter(|g| g.autofix) {
+            Some(guide) if self.should_run_rule("L018") => {
+                let base = fixed.as_deref().unwrap_or(content);
+                guide.fix(base).or(fixed)
+            }
+            _ => fixed,
+        };
+        let fixed = if self.should_run_rule("L020") {
+            let base = fixed.as_deref().unwrap_or(content);
+            crate::core::charset::normalize(base, self.strict_utf8).or(fixed)
+        } else {
+            fixed
+        }?;
+
+        let mut codes: Vec<&str> = issues.iter().filter(|i| i.fixable).map(|i| i.code.as_str()).collect();
+        let changes = codes.len().max(1);
+        codes.sort_unstable();
+        codes.dedup();
+        let name = file_path.file_name().and_then(|n| n.to_str()).unwrap_or("?");
+        let description = format!("🧹 {}: {}", name, if codes.is_empty() { "lint".to_string() } else { codes.join(", ") });
+        Some(Fix::edit("LINT", description, file_path, content, fixed).with_changes(changes))
+    }
+
+    /// Ejecuta una regla; con --timings acumula su tiempo.
+    fn timed(&self, code: &str, rule: impl FnOnce() -> Vec<LintIssue>) -> Vec<LintIssue> {
+        match &self.profiler {
+            Some(profiler) => profiler.time(code, rule),
+            None => rule(),
+        }
+    }
+
     /// Aplica todas las reglas a un archivo.
     fn lint_file(&self, file_path: &PathBuf, content: &str, data_dir: &std::path::Path) -> Vec<LintIssue> {
         let mut issues = Vec::new();
@@ -212,72 +481,114 @@ impl LintCommand {
 
         // Regla 1: Archivo debe tener frontmatter YAML
         if self.should_run_rule("L001") {
-            issues.extend(self.rule_frontmatter(file_path, content));
+            issues.extend(self.timed("L001", || self.rule_frontmatter(file_path, content)));
         }
 
         // Regla 2: Headers deben ser jerárquicos
         if self.should_run_rule("L002") {
-            issues.extend(self.rule_header_hierarchy(file_path, &lines));
+            issues.extend(self.timed("L002", || self.rule_header_hierarchy(file_path, &lines)));
         }
 
         // Regla 3: No trailing whitespace
         if self.should_run_rule("L003") {
-            issues.extend(self.rule_trailing_whitespace(file_path, &lines));
+            issues.extend(self.timed("L003", || self.rule_trailing_whitespace(file_path, &lines)));
         }
 
         // Regla 4: Archivo termina con newline
         if self.should_run_rule("L004") {
-            issues.extend(self.rule_final_newline(file_path, content));
+            issues.extend(self.timed("L004", || self.rule_final_newline(file_path, content)));
         }
 
         // Regla 5: No líneas > 300 caracteres (muy largas)
         if self.should_run_rule("L005") {
-            issues.extend(self.rule_line_length(file_path, &lines));
+            issues.extend(self.timed("L005", || self.rule_line_length(file_path, &lines)));
         }
 
         // Regla 6: Code blocks deben tener lenguaje
         if self.should_run_rule("L006") {
-            issues.extend(self.rule_code_block_language(file_path, &lines));
+            issues.extend(self.timed("L006", || self.rule_code_block_language(file_path, &lines)));
         }
 
         // Regla 7: Headers no duplicados
         if self.should_run_rule("L007") {
-            issues.extend(self.rule_duplicate_headers(file_path, &lines));
+            issues.extend(self.timed("L007", || self.rule_duplicate_headers(file_path, &lines)));
         }
 
         // Regla 8: Frontmatter fields obligatorios
         if self.should_run_rule("L008") {
-            issues.extend(self.rule_required_fields(file_path, content));
+            issues.extend(self.timed("L008", || self.rule_required_fields(file_path, content)));
         }
 
         // L4: Regla 9: Tablas con header
         if self.should_run_rule("L009") {
-            issues.extend(self.rule_table_headers(file_path, &lines));
+            issues.extend(self.timed("L009", || self.rule_table_headers(file_path, &lines)));
         }
 
-        // L4: Regla 10: Imágenes con alt text
-        if self.should_run_rule("L010") {
-            issues.extend(self.rule_image_alt(file_path, &lines));
-        }
 
         // L011: Separadores duplicados en tablas
         if self.should_run_rule("L011") {
-            issues.extend(self.rule_table_double_separator(file_path, &lines));
+            issues.extend(self.timed("L011", || self.rule_table_double_separator(file_path, &lines)));
         }
 
         // L012: Pipes sin escapar en wikilinks dentro de tablas
         if self.should_run_rule("L012") {
-            issues.extend(self.rule_unescaped_pipe_in_table(file_path, &lines));
+            issues.extend(self.timed("L012", || self.rule_unescaped_pipe_in_table(file_path, &lines)));
         }
 
         // L013: Nietos count mismatch
         if self.should_run_rule("L013") {
-            issues.extend(self.rule_nietos_mismatch(file_path, &lines, data_dir));
+            issues.extend(self.timed("L013", || self.rule_nietos_mismatch(file_path, &lines, data_dir)));
         }
 
-        // L014: Wikilinks con paths absolutos
+
+        // L014: Wiki-links fuera de la convención
         if self.should_run_rule("L014") {
-            issues.extend(self.rule_wikilink_absolute_path(file_path, &lines));
+            issues.extend(self.timed("L014", || self.rule_link_convention(file_path, content)));
+        }
+
+        // L015: Tabla índice vs hijos reales
+        if self.should_run_rule("L015") {
+            issues.extend(self.timed("L015", || self.rule_index_table_children(file_path, &lines, data_dir)));
+        }
+
+        // L016: Headings sin contenido
+        if self.should_run_rule("L016") {
+            issues.extend(self.timed("L016", || self.rule_empty_headings(file_path, &lines)));
+        }
+
+        // L017: Variables de proyecto sin definir
+        if self.should_run_rule("L017") {
+            issues.extend(self.timed("L017", || self.rule_unresolved_variables(file_path, content)));
+        }
+
+        // L018: Guía de estilo (términos prohibidos, marcas)
+        if self.should_run_rule("L018") {
+            issues.extend(self.timed("L018", || self.rule_styleguide(file_path, content)));
+        }
+
+        // L019: Trato (tú/usted/vos) consistente en documentos de usuario final
+        if self.should_run_rule("L019") {
+            issues.extend(self.timed("L019", || self.rule_register(file_path, content)));
+        }
+
+        // L020: Caracteres invisibles y unicode problemáticos
+        if self.should_run_rule("L020") {
+            issues.extend(self.timed("L020", || self.rule_problem_chars(file_path, content)));
+        }
+
+        // L022-L023: accesibilidad (opt-in, --category a11y)
+        if self.should_run_rule("L022") {
+            issues.extend(self.timed("L022", || self.rule_table_header_cells(file_path, &lines)));
+        }
+        if self.should_run_rule("L023") {
+            issues.extend(self.timed("L023", || self.rule_decorative_images(file_path, content)));
+        }
+
+        // Reglas declarativas (`lint_rule!`): L010, L021
+        for rule in crate::core::lint_rules::DECLARED_RULES {
+            if self.should_run_rule(rule.code) {
+                issues.extend(self.timed(rule.code, || self.rule_declared(rule, file_path, content)));
+            }
         }
 
         issues
@@ -300,6 +611,7 @@ impl LintCommand {
                 line: Some(1),
                 severity: LintSeverity::Warning,
                 fixable: false,
+                fix: None,
             }];
         }
         Vec::new()
@@ -337,6 +649,7 @@ impl LintCommand {
                         line: Some(idx + 1),
                         severity: LintSeverity::Warning,
                         fixable: false,
+                        fix: None,
                     });
                 }
                 last_level = level;
@@ -357,6 +670,7 @@ impl LintCommand {
                     line: Some(idx + 1),
                     severity: LintSeverity::Info,
                     fixable: true,
+                    fix: None,
                 });
             }
         }
@@ -373,6 +687,7 @@ impl LintCommand {
                 line: None,
                 severity: LintSeverity::Info,
                 fixable: true,
+                fix: None,
             }];
         }
         Vec::new()
@@ -401,6 +716,7 @@ impl LintCommand {
                     line: Some(idx + 1),
                     severity: LintSeverity::Warning,
                     fixable: false,
+                    fix: None,
                 });
             }
         }
@@ -413,7 +729,6 @@ impl LintCommand {
     fn rule_code_block_language(&self, file_path: &PathBuf, lines: &[&str]) -> Vec<LintIssue> {
         let mut issues = Vec::new();
         let mut in_code_block = false;
-        let mut block_start_idx = 0;
 
         for (idx, line) in lines.iter().enumerate() {
             let trimmed = line.trim();
@@ -423,7 +738,6 @@ impl LintCommand {
                 if !in_code_block {
                     // APERTURA de code block
                     in_code_block = true;
-                    block_start_idx = idx;
                     
                     // Verificar si tiene lenguaje especificado después de ```
                     let after_backticks = trimmed.trim_start_matches('`');
@@ -438,6 +752,7 @@ impl LintCommand {
                                 line: Some(idx + 1),
                                 severity: LintSeverity::Hint,
                                 fixable: false,
+                                fix: None,
                             });
                         }
                     }
@@ -465,8 +780,8 @@ impl LintCommand {
         // Analizar máximo 50 líneas después del inicio
         let end = lines.len().min(start + 50);
         
-        for i in (start + 1)..end {
-            let line = lines[i].trim();
+        for raw in &lines[(start + 1).min(end)..end] {
+            let line = raw.trim();
             
             // Si encontramos el cierre del bloque, terminamos
             if line == "```" {
@@ -542,6 +857,7 @@ impl LintCommand {
                         line: Some(idx + 1),
                         severity: LintSeverity::Warning,
                         fixable: false,
+                        fix: None,
                     });
                 } else {
                     seen.insert(header, idx + 1);
@@ -570,6 +886,7 @@ impl LintCommand {
                         line: None,
                         severity: LintSeverity::Error,
                         fixable: false,
+                        fix: None,
                     });
                 }
             }
@@ -617,6 +934,7 @@ impl LintCommand {
                         line: Some(i + 1),
                         severity: LintSeverity::Warning,
                         fixable: false,
+                        fix: None,
                     });
                 }
                 // Saltar hasta el final de la tabla
@@ -630,28 +948,6 @@ impl LintCommand {
         issues
     }
 
-    /// L4.3 Regla: Imágenes deben tener alt text.
-    fn rule_image_alt(&self, file_path: &PathBuf, lines: &[&str]) -> Vec<LintIssue> {
-        use crate::core::patterns::RE_IMAGE_EMPTY_ALT;
-        // Busca ![](path) donde alt text está vacío
-        let empty_alt = &*RE_IMAGE_EMPTY_ALT;
-
-        let mut issues = Vec::new();
-        for (idx, line) in lines.iter().enumerate() {
-            if empty_alt.is_match(line) {
-                issues.push(LintIssue {
-                    code: "L010".to_string(),
-                    message: "Imagen sin alt text".to_string(),
-                    file: file_path.clone(),
-                    line: Some(idx + 1),
-                    severity: LintSeverity::Warning,
-                    fixable: false,
-                });
-            }
-        }
-        issues
-    }
-
     /// L011: Detecta separadores duplicados en tablas.
     /// Una tabla válida solo tiene UN separador |---| después del header.
     /// RFC-FIX: Ahora ignora contenido dentro de fenced code blocks (ASCII art, mockups).
@@ -712,6 +1008,7 @@ impl LintCommand {
                                 line: Some(i + 1),
                                 severity: LintSeverity::Error,
                                 fixable: true,
+                                fix: None,
                             });
                         }
                         i += 1;
@@ -756,6 +1053,7 @@ impl LintCommand {
                             line: Some(idx + 1),
                             severity: LintSeverity::Error,
                             fixable: true,
+                            fix: None,
                         });
                     }
                 }
@@ -832,6 +1130,7 @@ impl LintCommand {
                                     line: Some(row_idx + 1),
                                     severity: LintSeverity::Warning,
                                     fixable: true,
+                                    fix: None,
                                 });
                             }
                         }
@@ -871,33 +1170,407 @@ impl LintCommand {
         }
     }
 
-    /// L014: Detecta wikilinks con paths absolutos.
-    /// Los wikilinks no deben usar prefijo de proyecto como "Proyecto OnlyCarNLD/Datos/".
-    fn rule_wikilink_absolute_path(&self, file_path: &PathBuf, lines: &[&str]) -> Vec<LintIssue> {
-        use regex::Regex;
-        lazy_static::lazy_static! {
-            // Detecta paths absolutos en wikilinks
-            static ref ABSOLUTE_PATH: Regex = Regex::new(r"\[\[Proyecto[^\]]*OnlyCarNLD/Datos/([^\]]+)\]\]").unwrap();
+    /// L015: La tabla índice del padre debe listar exactamente sus hijos directos.
+    /// Complementa L013: reporta hijos existentes sin fila y filas que apuntan
+    /// a hijos inexistentes.
+    fn rule_index_table_children(&self, file_path: &PathBuf, lines: &[&str], data_dir: &std::path::Path) -> Vec<LintIssue> {
+        let Some(check) = IndexTableCheck::new(file_path, lines, data_dir) else {
+            return Vec::new();
+        };
+
+        let mut issues = Vec::new();
+        for (row_idx, target) in &check.stale_rows {
+            issues.push(LintIssue {
+                code: "L015".to_string(),
+                message: format!("Tabla índice lista documento inexistente: [[{}]]", target),
+                file: file_path.clone(),
+                line: Some(row_idx + 1),
+                severity: LintSeverity::Warning,
+                fixable: true,
+                fix: None,
+            });
         }
-        
+        for (_, stem) in &check.missing {
+            issues.push(LintIssue {
+                code: "L015".to_string(),
+                message: format!("Hijo sin fila en la tabla índice: {}", stem),
+                file: file_path.clone(),
+                line: Some(check.header_idx + 1),
+                severity: LintSeverity::Warning,
+                fixable: true,
+                fix: None,
+            });
+        }
+        issues
+    }
+
+    /// Fix L015: quita filas de hijos inexistentes y agrega las de hijos
+    /// faltantes en orden por ID, con el mismo número de columnas.
+    pub fn fix_index_table(&self, file_path: &PathBuf, content: &str, data_dir: &std::path::Path) -> Option<String> {
+        let lines: Vec<&str> = content.lines().collect();
+        let check = IndexTableCheck::new(file_path, &lines, data_dir)?;
+        if check.stale_rows.is_empty() && check.missing.is_empty() {
+            return None;
+        }
+
+        let stale: std::collections::HashSet<usize> = check.stale_rows.iter().map(|(i, _)| *i).collect();
+        let mut rows: Vec<(Vec<u32>, String)> = (check.header_idx + 2..check.end_idx)
+            .filter(|i| !stale.contains(i))
+            .map(|i| (check.row_ids.get(&i).cloned().unwrap_or_default(), lines[i].to_string()))
+            .collect();
+        for (id, stem) in &check.missing {
+            let row = check.new_row(stem, data_dir, self);
+            let key = id_key(id);
+            let pos = rows
+                .iter()
+                .position(|(k, _)| !k.is_empty() && *k > key)
+                .unwrap_or(rows.len());
+            rows.insert(pos, (key, row));
+        }
+
+        let mut out: Vec<String> = lines[..check.header_idx + 2].iter().map(|l| l.to_string()).collect();
+        out.extend(rows.into_iter().map(|(_, r)| r));
+        out.extend(lines[check.end_idx..].iter().map(|l| l.to_string()));
+        let mut fixed = out.join("\n");
+        if content.ends_with('\n') {
+            fixed.push('\n');
+        }
+        Some(fixed)
+    }
+
+    /// L016: Detecta headings sin contenido (seguidos por otro heading del
+    /// mismo o mayor nivel, o por el fin del archivo). Suelen ser restos de plantillas.
+    fn rule_empty_headings(&self, file_path: &PathBuf, lines: &[&str]) -> Vec<LintIssue> {
+        let empty = empty_sections(lines);
+        let total = empty.len();
+        empty
+            .into_iter()
+            .map(|idx| LintIssue {
+                code: "L016".to_string(),
+                message: format!(
+                    "Sección vacía: '{}' ({} en el archivo)",
+                    lines[idx].trim(),
+                    total
+                ),
+                file: file_path.clone(),
+                line: Some(idx + 1),
+                severity: LintSeverity::Warning,
+                fixable: true,
+                fix: None,
+            })
+            .collect()
+    }
+
+    /// L017: Variables `{{nombre}}` sin valor en la sección `variables` de
+    /// config; el export las dejaría sin sustituir.
+    fn rule_unresolved_variables(&self, file_path: &PathBuf, content: &str) -> Vec<LintIssue> {
+        let Some(variables) = &self.variables else {
+            return Vec::new();
+        };
+        variables
+            .unresolved(content)
+            .into_iter()
+            .map(|v| LintIssue {
+                code: "L017".to_string(),
+                message: format!("Variable no definida: {{{{{}}}}}", v.name),
+                file: file_path.clone(),
+                line: Some(v.line),
+                severity: LintSeverity::Warning,
+                fixable: false,
+                fix: None,
+            })
+            .collect()
+    }
+
+    /// L018: Términos prohibidos y marcas mal escritas según la guía de
+    /// estilo; fixables si la guía tiene `autofix` y sugiere reemplazo.
+    fn rule_styleguide(&self, file_path: &PathBuf, content: &str) -> Vec<LintIssue> {
+        let Some(guide) = &self.styleguide else {
+            return Vec::new();
+        };
+        guide
+            .check(content)
+            .into_iter()
+            .map(|v| LintIssue {
+                code: "L018".to_string(),
+                message: v.message(),
+                file: file_path.clone(),
+                line: Some(v.line),
+                severity: LintSeverity::Warning,
+                fixable: guide.autofix && v.suggestion.is_some(),
+                fix: None,
+            })
+            .collect()
+    }
+
+    /// L019: Líneas con mezcla de tú/usted/vos o con un trato distinto del
+    /// configurado para el `type:` del documento (`auto` = el predominante).
+    fn rule_register(&self, file_path: &PathBuf, content: &str) -> Vec<LintIssue> {
+        use crate::core::register::{check, Register};
+
+        let Some(config) = &self.register else {
+            return Vec::new();
+        };
+        let doc_type = crate::core::yaml::frontmatter_block(content)
+            .and_then(|m| crate::types::breadcrumb::frontmatter_value(m, "type"));
+        let Some(expected) = doc_type.as_deref().and_then(|t| config.for_type(t)) else {
+            return Vec::new();
+        };
+        check(content, Register::parse(expected))
+            .into_iter()
+            .map(|issue| LintIssue {
+                code: "L019".to_string(),
+                message: issue.message,
+                file: file_path.clone(),
+                line: Some(issue.line),
+                severity: LintSeverity::Warning,
+                fixable: false,
+                fix: None,
+            })
+            .collect()
+    }
+
+    /// L014: Wiki-links que resuelven a un documento pero no están escritos
+    /// en la convención del proyecto; `links --normalize` los reescribe.
+    fn rule_link_convention(&self, file_path: &PathBuf, content: &str) -> Vec<LintIssue> {
+        let Some(links) = &self.links else {
+            return Vec::new();
+        };
+        let (convention, targets) = links.as_ref();
+        link_violations(content, targets, *convention)
+            .into_iter()
+            .map(|(line, target, canonical)| LintIssue {
+                code: "L014".to_string(),
+                message: format!(
+                    "Wikilink [[{}]] fuera de la convención '{}': usar [[{}]]",
+                    target,
+                    convention.as_str(),
+                    canonical
+                ),
+                file: file_path.clone(),
+                line: Some(line),
+                severity: LintSeverity::Info,
+                fixable: false,
+                fix: None,
+            })
+            .collect()
+    }
+
+    /// Regla declarada con `lint_rule!`: un issue por match.
+    fn rule_declared(&self, rule: &crate::core::lint_rules::DeclaredRule, file_path: &PathBuf, content: &str) -> Vec<LintIssue> {
+        let severity = match rule.severity {
+            crate::types::Severity::Error => LintSeverity::Error,
+            crate::types::Severity::Warning => LintSeverity::Warning,
+            crate::types::Severity::Info => LintSeverity::Info,
+            crate::types::Severity::Hint => LintSeverity::Hint,
+        };
+        rule.check(content)
+            .into_iter()
+            .map(|(line, message)| LintIssue {
+                code: rule.code.to_string(),
+                message,
+                file: file_path.clone(),
+                line: Some(line),
+                severity,
+                fixable: false,
+                fix: None,
+            })
+            .collect()
+    }
+
+    /// L020: Caracteres invisibles en cualquier parte y NBSP/guiones unicode
+    /// en referencias (con `--strict-utf8`, también NBSP y comillas mezcladas
+    /// del texto); fixables por normalización.
+    fn rule_problem_chars(&self, file_path: &PathBuf, content: &str) -> Vec<LintIssue> {
+        let severity = if self.strict_utf8 { LintSeverity::Error } else { LintSeverity::Warning };
+        crate::core::charset::check(content, self.strict_utf8)
+            .into_iter()
+            .map(|issue| LintIssue {
+                code: "L020".to_string(),
+                message: issue.message(),
+                file: file_path.clone(),
+                line: Some(issue.line),
+                severity,
+                fixable: true,
+                fix: None,
+            })
+            .collect()
+    }
+
+    /// L022: Tablas markdown con columnas sin encabezado y tablas HTML sin `<th>`.
+    fn rule_table_header_cells(&self, file_path: &PathBuf, lines: &[&str]) -> Vec<LintIssue> {
+        use crate::core::patterns::{RE_TABLE_ROW, RE_TABLE_SEPARATOR};
+        let issue = |line: usize, message: String| LintIssue {
+            code: "L022".to_string(),
+            message,
+            file: file_path.clone(),
+            line: Some(line),
+            severity: LintSeverity::Warning,
+            fixable: false,
+            fix: None,
+        };
+
         let mut issues = Vec::new();
-        
-        for (idx, line) in lines.iter().enumerate() {
-            for cap in ABSOLUTE_PATH.captures_iter(line) {
-                let full_match = cap.get(0).unwrap().as_str();
-                issues.push(LintIssue {
-                    code: "L014".to_string(),
-                    message: format!("Wikilink con path absoluto: {}", &full_match[..full_match.len().min(50)]),
-                    file: file_path.clone(),
-                    line: Some(idx + 1),
-                    severity: LintSeverity::Info,
-                    fixable: false,
-                });
+        let mut in_code_block = false;
+        let mut html_table: Option<(usize, bool)> = None;
+        for (i, line) in lines.iter().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+
+            // Header markdown: la fila anterior al separador
+            let is_header = RE_TABLE_ROW.is_match(trimmed)
+                && lines.get(i + 1).is_some_and(|next| RE_TABLE_SEPARATOR.is_match(next.trim()))
+                && (i == 0 || !RE_TABLE_ROW.is_match(lines[i - 1].trim()));
+            if is_header {
+                let row = trimmed.replace("\\|", "");
+                let cells: Vec<&str> = row.trim_matches('|').split('|').collect();
+                let empty: Vec<String> = cells
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, cell)| cell.trim().is_empty())
+                    .map(|(n, _)| (n + 1).to_string())
+                    .collect();
+                if !empty.is_empty() {
+                    issues.push(issue(i + 1, format!("Tabla con columnas sin encabezado: {}", empty.join(", "))));
+                }
+            }
+
+            let lower = trimmed.to_lowercase();
+            if lower.contains("<table") {
+                html_table = Some((i + 1, false));
+            }
+            if let Some((_, has_th)) = html_table.as_mut() {
+                *has_th |= lower.contains("<th");
+            }
+            if lower.contains("</table") {
+                if let Some((start, false)) = html_table.take() {
+                    issues.push(issue(start, "Tabla HTML sin encabezados <th>".to_string()));
+                }
             }
         }
         issues
     }
 
+    /// L023: Imágenes con alt genérico o igual al archivo, y sin alt con
+    /// atributos pero sin marcar `{.decorativa}`.
+    fn rule_decorative_images(&self, file_path: &PathBuf, content: &str) -> Vec<LintIssue> {
+        static RE_IMAGE: Lazy<Regex> = Lazy::new(|| Regex::new(r"!\[([^\]]*)\]\(\s*([^)\s]*)[^)]*\)(\{[^}]*\})?").unwrap());
+        const GENERIC_ALT: &[&str] = &[
+            "imagen", "image", "img", "foto", "photo", "picture", "captura", "screenshot", "icono", "icon", "logo", "diagrama",
+            "diagram", "figura", "figure",
+        ];
+
+        let mut issues = Vec::new();
+        let mut in_code_block = false;
+        for (i, line) in content.lines().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+            for caps in RE_IMAGE.captures_iter(line) {
+                let alt = caps[1].trim();
+                let attrs = caps.get(3).map(|m| m.as_str());
+                if attrs.is_some_and(|a| a.contains(".decorativa") || a.contains(".decorative")) {
+                    continue;
+                }
+                let file = caps[2].rsplit('/').next().unwrap_or("");
+                let stem = file.rsplit_once('.').map_or(file, |(stem, _)| stem);
+                let message = if alt.is_empty() {
+                    // Sin atributos lo reporta L010
+                    attrs.map(|_| "Imagen sin alt ni marca {.decorativa}".to_string())
+                } else if GENERIC_ALT.contains(&alt.to_lowercase().as_str())
+                    || alt.eq_ignore_ascii_case(file)
+                    || alt.eq_ignore_ascii_case(stem)
+                {
+                    Some(format!("Alt no descriptivo: \"{}\" (describir la imagen o marcarla {{.decorativa}})", alt))
+                } else {
+                    None
+                };
+                if let Some(message) = message {
+                    issues.push(LintIssue {
+                        code: "L023".to_string(),
+                        message,
+                        file: file_path.clone(),
+                        line: Some(i + 1),
+                        severity: LintSeverity::Info,
+                        fixable: false,
+                        fix: None,
+                    });
+                }
+            }
+        }
+        issues
+    }
+
+    /// L020 con `--strict-utf8`: archivo ilegible como UTF-8, en la línea del
+    /// primer byte inválido.
+    fn invalid_utf8_issue(&self, file_path: &PathBuf) -> Option<LintIssue> {
+        let bytes = std::fs::read(file_path).ok()?;
+        let error = std::str::from_utf8(&bytes).err()?;
+        let valid = &bytes[..error.valid_up_to()];
+        Some(LintIssue {
+            code: "L020".to_string(),
+            message: format!("UTF-8 inválido (byte {})", error.valid_up_to()),
+            file: file_path.clone(),
+            line: Some(valid.iter().filter(|&&b| b == b'\n').count() + 1),
+            severity: LintSeverity::Error,
+            fixable: false,
+            fix: None,
+        })
+    }
+
+    /// Fix L016: inserta un placeholder bajo cada sección vacía o elimina el
+    /// heading (`--empty-headings remove`).
+    pub fn fix_empty_headings(&self, content: &str) -> Option<String> {
+        let lines: Vec<&str> = content.lines().collect();
+        let empty = empty_sections(&lines);
+        if empty.is_empty() {
+            return None;
+        }
+        let remove = self.empty_headings == "remove";
+
+        let mut out: Vec<&str> = Vec::with_capacity(lines.len() + empty.len() * 2);
+        let mut i = 0;
+        while i < lines.len() {
+            if !empty.contains(&i) {
+                out.push(lines[i]);
+                i += 1;
+                continue;
+            }
+            if remove {
+                // Quitar heading y las líneas en blanco que lo siguen
+                i += 1;
+                while i < lines.len() && lines[i].trim().is_empty() {
+                    i += 1;
+                }
+                continue;
+            }
+            out.push(lines[i]);
+            out.push("");
+            out.push(EMPTY_SECTION_PLACEHOLDER);
+            if lines.get(i + 1).is_some_and(|l| !l.trim().is_empty()) {
+                out.push("");
+            }
+            i += 1;
+        }
+
+        let mut fixed = out.join("\n");
+        if content.ends_with('\n') {
+            fixed.push('\n');
+        }
+        Some(fixed)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // L4.4: FIX AUTOMÁTICO
     // ═══════════════════════════════════════════════════════════════════════
@@ -937,18 +1610,272 @@ impl LintCommand {
         }
     }
 
-    /// Verifica si una regla debe ejecutarse según el filtro --rule.
+    /// Verifica si una regla debe ejecutarse según --rule, --category y
+    /// --disable-category.
     fn should_run_rule(&self, rule_code: &str) -> bool {
-        match &self.rule {
-            Some(filter) => rule_code == filter,
-            None => true,
+        if let Some(filter) = &self.rule {
+            return rule_code == filter;
+        }
+        let category = rule_category(rule_code);
+        if self.disable_category.iter().any(|c| c.eq_ignore_ascii_case(category)) {
+            return false;
+        }
+        if self.category.is_empty() {
+            return !OPT_IN_CATEGORIES.contains(&category);
         }
+        self.category.iter().any(|c| {
+            c.eq_ignore_ascii_case(category) || (c.eq_ignore_ascii_case("a11y") && A11Y_RELATED.contains(&rule_code))
+        })
+    }
+}
+
+/// Fuente de fixes para `fix --all`: un fix por archivo con issues fixables.
+impl Fixable for FixSource<LintCommand> {
+    fn suggest_fixes(&self) -> Vec<Fix> {
+        let cmd = LintCommand { fix: false, ..self.command.clone() };
+        let issues = match cmd.run(&self.data_dir) {
+            Ok(result) => result.issues,
+            Err(e) => {
+                eprintln!("⚠ {}", e);
+                return Vec::new();
+            }
+        };
+        let mut fixes: Vec<Fix> = Vec::new();
+        for fix in issues.into_iter().filter_map(|i| i.fix) {
+            if !fixes.iter().any(|f| f.file == fix.file) {
+                fixes.push(fix);
+            }
+        }
+        fixes
+    }
+
+    fn apply_fix(&mut self, fix: &Fix) -> OcResult<traits::FixResult> {
+        fixes::apply(fix, &self.review)
+    }
+}
+
+
+/// Categorías que no corren salvo `--category` o `--rule`.
+const OPT_IN_CATEGORIES: &[&str] = &["a11y"];
+
+/// Reglas de otras categorías que también revisan accesibilidad: orden de
+/// headings (L002) y alt text (L010). `--category a11y` las incluye.
+const A11Y_RELATED: &[&str] = &["L002", "L010"];
+
+/// Categoría de cada regla de lint según el registro.
+static RULE_CATEGORIES: Lazy<HashMap<String, String>> = Lazy::new(|| {
+    crate::core::registry::RuleRegistry::builtin()
+        .by_kind(crate::core::registry::RuleKind::Lint)
+        .into_iter()
+        .map(|meta| (meta.code.clone(), meta.category.clone()))
+        .collect()
+});
+
+/// Categoría de una regla de lint (vacía si no está registrada).
+pub fn rule_category(code: &str) -> &'static str {
+    RULE_CATEGORIES.get(code).map(String::as_str).unwrap_or("")
+}
+
+/// Categorías de lint existentes, ordenadas.
+pub fn categories() -> Vec<&'static str> {
+    let mut categories: Vec<&'static str> = RULE_CATEGORIES.values().map(String::as_str).collect();
+    categories.sort_unstable();
+    categories.dedup();
+    categories
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// L016: SECCIONES VACÍAS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Placeholder insertado por el fix de L016 (lo detecta la fase 17 de verify).
+const EMPTY_SECTION_PLACEHOLDER: &str = "_Contenido pendiente._";
+
+/// Nivel de un heading ATX (`## x` → 2).
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let rest = &line[level..];
+    ((1..=6).contains(&level) && rest.starts_with(' ') && !rest.trim().is_empty()).then_some(level)
+}
+
+/// Índices de headings cuya sección está vacía, fuera de frontmatter y código.
+fn empty_sections(lines: &[&str]) -> Vec<usize> {
+    let mut empty = Vec::new();
+    let mut open: Option<(usize, usize)> = None; // (línea, nivel) sin contenido aún
+    let mut in_code = false;
+    let mut in_frontmatter = lines.first().is_some_and(|l| l.trim() == "---");
+
+    for (idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if in_frontmatter {
+            in_frontmatter = idx == 0 || trimmed != "---";
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+            open = None;
+            continue;
+        }
+        if in_code || trimmed.is_empty() {
+            continue;
+        }
+        match heading_level(line) {
+            Some(level) => {
+                if let Some((open_idx, open_level)) = open {
+                    if level <= open_level {
+                        empty.push(open_idx);
+                    }
+                }
+                open = Some((idx, level));
+            }
+            None => open = None,
+        }
+    }
+    if let Some((open_idx, _)) = open {
+        empty.push(open_idx);
+    }
+    empty
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// L015: TABLA ÍNDICE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// ID numérico como clave ordenable ("2.10" > "2.9").
+fn id_key(id: &str) -> Vec<u32> {
+    id.split('.').filter_map(|s| s.parse().ok()).collect()
+}
+
+/// ID jerárquico al inicio de un nombre ("2.1. alta" → "2.1").
+fn leading_id(name: &str) -> Option<String> {
+    let id = name.split_whitespace().next()?.trim_end_matches('.');
+    (!id.is_empty() && id.split('.').all(|s| s.parse::<u32>().is_ok())).then(|| id.to_string())
+}
+
+/// Estado de la tabla índice de un documento padre.
+struct IndexTableCheck {
+    header_idx: usize,
+    /// Primera línea después de la tabla.
+    end_idx: usize,
+    columns: Vec<String>,
+    /// Fila → clave del ID enlazado.
+    row_ids: std::collections::HashMap<usize, Vec<u32>>,
+    /// (fila, target) de hijos directos que no existen.
+    stale_rows: Vec<(usize, String)>,
+    /// (id, stem) de hijos existentes sin fila.
+    missing: Vec<(String, String)>,
+}
+
+impl IndexTableCheck {
+    /// Primera tabla cuya primera columna enlaza a hijos directos del documento.
+    fn new(file_path: &std::path::Path, lines: &[&str], data_dir: &std::path::Path) -> Option<Self> {
+        use crate::core::patterns::{RE_TABLE_ROW, RE_TABLE_SEPARATOR};
+        use regex::Regex;
+        lazy_static::lazy_static! {
+            static ref FIRST_WIKILINK: Regex = Regex::new(r"\[\[([^\]\|\\]+)").unwrap();
+        }
+
+        let stem = file_path.file_stem()?.to_str()?;
+        let parent_id = leading_id(stem)?;
+        let is_child = |id: &str| id.rsplit_once('.').is_some_and(|(p, _)| p == parent_id);
+
+        let children: Vec<(String, String)> = std::fs::read_dir(data_dir)
+            .ok()?
+            .filter_map(|e| e.ok())
+            .filter_map(|e| {
+                let name = e.file_name().to_string_lossy().to_string();
+                let child_stem = name.strip_suffix(".md")?.to_string();
+                let id = leading_id(&child_stem)?;
+                is_child(&id).then_some((id, child_stem))
+            })
+            .collect();
+
+        let mut in_code = false;
+        let mut i = 0;
+        while i < lines.len() {
+            let trimmed = lines[i].trim();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code = !in_code;
+            }
+            let is_header = !in_code
+                && RE_TABLE_ROW.is_match(trimmed)
+                && lines.get(i + 1).is_some_and(|l| RE_TABLE_SEPARATOR.is_match(l.trim()));
+            if !is_header {
+                i += 1;
+                continue;
+            }
+
+            let mut end = i + 2;
+            let mut linked: Vec<(usize, String, String)> = Vec::new();
+            while end < lines.len() && RE_TABLE_ROW.is_match(lines[end].trim()) {
+                let first_col = lines[end].trim().trim_start_matches('|').split('|').next().unwrap_or("");
+                if let Some(cap) = FIRST_WIKILINK.captures(first_col) {
+                    let target = cap[1].trim().to_string();
+                    if let Some(id) = leading_id(&target) {
+                        linked.push((end, id, target));
+                    }
+                }
+                end += 1;
+            }
+
+            if linked.iter().any(|(_, id, _)| is_child(id)) {
+                let listed: std::collections::HashSet<&str> =
+                    linked.iter().map(|(_, id, _)| id.as_str()).collect();
+                let stale_rows = linked
+                    .iter()
+                    .filter(|(_, id, _)| is_child(id) && !children.iter().any(|(c, _)| c == id))
+                    .map(|(row, _, target)| (*row, target.clone()))
+                    .collect();
+                let mut missing: Vec<(String, String)> = children
+                    .iter()
+                    .filter(|(id, _)| !listed.contains(id.as_str()))
+                    .cloned()
+                    .collect();
+                missing.sort_by_key(|(id, _)| id_key(id));
+                return Some(Self {
+                    header_idx: i,
+                    end_idx: end,
+                    columns: trimmed
+                        .trim_matches('|')
+                        .split('|')
+                        .map(|c| c.trim().to_lowercase())
+                        .collect(),
+                    row_ids: linked.iter().map(|(row, id, _)| (*row, id_key(id))).collect(),
+                    stale_rows,
+                    missing,
+                });
+            }
+            i = end;
+        }
+        None
+    }
+
+    /// Fila nueva: wikilink en la primera columna, título y nietos si hay columnas para ello.
+    fn new_row(&self, stem: &str, data_dir: &std::path::Path, cmd: &LintCommand) -> String {
+        let title = std::fs::read_to_string(data_dir.join(format!("{}.md", stem)))
+            .ok()
+            .and_then(|c| crate::core::patterns::RE_TITLE.captures(&c).map(|cap| cap[1].trim().to_string()));
+        let cells: Vec<String> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, col)| match col.as_str() {
+                _ if idx == 0 => format!("[[{}]]", stem),
+                "título" | "titulo" | "title" | "nombre" => title.clone().unwrap_or_default(),
+                "nietos" => leading_id(stem)
+                    .map(|id| cmd.count_descendants(data_dir, &id).to_string())
+                    .unwrap_or_default(),
+                _ => String::new(),
+            })
+            .collect();
+        format!("| {} |", cells.join(" | "))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TestProject;
 
     #[test]
     fn test_lint_result_new() {
@@ -986,6 +1913,264 @@ mod tests {
             .push(LintIssue::error("E001", "err", PathBuf::from("a.md")));
         assert!(!result.is_clean());
     }
+
+    #[test]
+    fn test_index_table_children() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        let parent = "# Pagos\n\n| Doc | Título |\n|---|---|\n| [[2.1 tarjeta]] | Tarjeta |\n| [[2.3 cheque]] | Cheque |\n\nFin.\n";
+        project.write("2 pagos.md", parent);
+        project.write("2.1 tarjeta.md", "---\ntitle: \"Tarjeta\"\n---\n");
+        project.write("2.2 efectivo.md", "---\ntitle: \"Efectivo\"\n---\n");
+        project.write("2.2.1 caja.md", "---\ntitle: \"Caja\"\n---\n");
+
+        let cmd = LintCommand::parse_from(["lint", "--rule", "L015"]);
+        let path = dir.join("2 pagos.md");
+        let lines: Vec<&str> = parent.lines().collect();
+        let issues = cmd.rule_index_table_children(&path, &lines, dir);
+        let messages: Vec<&str> = issues.iter().map(|i| i.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Tabla índice lista documento inexistente: [[2.3 cheque]]",
+                "Hijo sin fila en la tabla índice: 2.2 efectivo",
+            ]
+        );
+
+        let fixed = cmd.fix_index_table(&path, parent, dir).unwrap();
+        assert_eq!(
+            fixed,
+            "# Pagos\n\n| Doc | Título |\n|---|---|\n| [[2.1 tarjeta]] | Tarjeta |\n| [[2.2 efectivo]] | Efectivo |\n\nFin.\n"
+        );
+        assert!(cmd.fix_index_table(&path, &fixed, dir).is_none());
+    }
+
+    #[test]
+    fn test_empty_headings() {
+        let content = "---\n# comentario yaml\n---\n# Título\n\n## Vacía\n\n## Con texto\ntexto\n### Sub\nmás\n## Plantilla\n```\n## no es heading\n```\n## Final\n";
+        let lines: Vec<&str> = content.lines().collect();
+        let cmd = LintCommand::parse_from(["lint", "--rule", "L016"]);
+
+        let issues = cmd.rule_empty_headings(&PathBuf::from("a.md"), &lines);
+        let found: Vec<(usize, &str)> = issues
+            .iter()
+            .map(|i| (i.line.unwrap(), i.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (6, "Sección vacía: '## Vacía' (2 en el archivo)"),
+                (16, "Sección vacía: '## Final' (2 en el archivo)"),
+            ]
+        );
+
+        let fixed = cmd.fix_empty_headings("## A\n## B\ntexto\n").unwrap();
+        assert_eq!(fixed, "## A\n\n_Contenido pendiente._\n\n## B\ntexto\n");
+
+        let remove = LintCommand::parse_from(["lint", "--empty-headings", "remove"]);
+        assert_eq!(remove.fix_empty_headings("## A\n\n## B\ntexto\n").unwrap(), "## B\ntexto\n");
+        assert!(remove.fix_empty_headings("## B\ntexto\n").is_none());
+    }
+
+    #[test]
+    fn test_unresolved_variables() {
+        let config: std::collections::BTreeMap<String, serde_yaml::Value> =
+            serde_yaml::from_str("project:\n  name: OnlyCar\n").unwrap();
+        let mut cmd = LintCommand::parse_from(["lint", "--rule", "L017"]);
+        cmd.variables = Some(Arc::new(crate::core::variables::Variables::new(&config)));
+
+        let issues = cmd.rule_unresolved_variables(&PathBuf::from("a.md"), "{{project.name}} al {{today}}\n{{version}}\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, Some(2));
+        assert_eq!(issues[0].message, "Variable no definida: {{version}}");
+    }
+
+    #[test]
+    fn test_link_convention() {
+        let data = std::path::Path::new("/docs");
+        let files = vec![data.join("2. Seguridad/2.8.1 Politicas.md"), data.join("README.md")];
+        let targets = LinkTargets::new(data, &files);
+        let content = "Ver [[2. Seguridad/2.8.1 Politicas#Alcance]] y [[README]].\n```\n[[2.8.1]]\n```\n[[Otro]]\n";
+        let mut cmd = LintCommand::parse_from(["lint", "--rule", "L014"]);
+
+        cmd.links = Some(Arc::new((LinkConvention::Name, targets.clone())));
+        let issues = cmd.rule_link_convention(&PathBuf::from("a.md"), content);
+        let found: Vec<_> = issues.iter().map(|i| (i.line, i.message.as_str())).collect();
+        assert_eq!(
+            found,
+            vec![(Some(1), "Wikilink [[2. Seguridad/2.8.1 Politicas]] fuera de la convención 'name': usar [[2.8.1 Politicas]]")]
+        );
+
+        cmd.links = Some(Arc::new((LinkConvention::Path, targets)));
+        assert!(cmd.rule_link_convention(&PathBuf::from("a.md"), content).is_empty());
+    }
+
+    #[test]
+    fn test_styleguide_autofix() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.md");
+        std::fs::write(&path, "---\nid: \"1\"\n---\n# Onlycar\n\nLa app móvil.\n").unwrap();
+        let guide = "forbidden:\n  app móvil: aplicación\nbrands: [OnlyCar]\nautofix: true\n";
+        let mut cmd = LintCommand::parse_from(["lint", "--rule", "L018", "--fix"]);
+        cmd.styleguide = Some(Arc::new(crate::core::styleguide::StyleGuide::parse(guide).unwrap()));
+
+        let result = cmd.run(dir.path()).unwrap();
+        let lines: Vec<Option<usize>> = result.issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, vec![Some(4), Some(6)]);
+        assert!(result.issues.iter().all(|i| i.fixable));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "---\nid: \"1\"\n---\n# OnlyCar\n\nLa aplicación.\n");
+    }
+
+    #[test]
+    fn test_register_by_type() {
+        let mut cmd = LintCommand::parse_from(["lint", "--rule", "L019"]);
+        cmd.register = Some(Arc::new(crate::core::config::RegisterConfig::default()));
+        let body = "# Alta\n\nIngrese sus datos.\nSi usted quiere, revisa tus pedidos.\n";
+
+        let guia = format!("---\ntype: guia\n---\n{}", body);
+        let issues = cmd.rule_register(&PathBuf::from("a.md"), &guia);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, Some(7));
+        assert_eq!(issues[0].message, "Mezcla de trato en la línea: tú (tus) y usted (usted)");
+
+        let tecnico = format!("---\ntype: tecnico\n---\n{}", body);
+        assert!(cmd.rule_register(&PathBuf::from("b.md"), &tecnico).is_empty());
+    }
+
+    #[test]
+    fn test_problem_chars_fix_and_strict() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.md");
+        std::fs::write(&path, "---\nid: \"1\"\n---\nVer [[1.2\u{00A0}baja]].\u{200B}\nTexto\u{00A0}libre.\n").unwrap();
+        std::fs::write(dir.path().join("b.md"), b"---\nid: \"2\"\n---\nmal \xff\n").unwrap();
+
+        let strict = LintCommand::parse_from(["lint", "--rule", "L020", "--strict-utf8"]).run(dir.path()).unwrap();
+        let found: Vec<(Option<usize>, &str)> = strict.issues.iter().map(|i| (i.line, i.message.as_str())).collect();
+        assert_eq!(
+            found,
+            vec![
+                (Some(4), "Espacio de no separación en referencia (U+00A0)"),
+                (Some(4), "Carácter invisible (U+200B)"),
+                (Some(5), "Espacio de no separación (U+00A0)"),
+                (Some(4), "UTF-8 inválido (byte 20)"),
+            ]
+        );
+        assert_eq!(strict.error_count(), 4);
+
+        let result = LintCommand::parse_from(["lint", "--rule", "L020", "--fix"]).run(dir.path()).unwrap();
+        assert_eq!((result.issues.len(), result.warning_count()), (2, 2));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "---\nid: \"1\"\n---\nVer [[1.2 baja]].\nTexto\u{00A0}libre.\n");
+    }
+
+    #[test]
+    fn test_a11y_category() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = "---\nid: \"1\"\ntitle: \"A\"\n---\n\n# Accesible\n\nPara pagar [clic aquí](pagos.md) o [ver pagos](pagos.md).\n\n\
+                       | | Estado |\n|---|---|\n| 1.1 | activo |\n\n<table><tr><td>x</td></tr></table>\n\n\
+                       ![captura](img/paso1.png) ![paso1](img/paso1.png) ![Formulario de alta](img/alta.png)\n\
+                       ![](sep.svg){.decorativa} ![](sep.svg){width=100%} ![](sin_alt.png)\n";
+        std::fs::write(dir.path().join("1 accesible.md"), content).unwrap();
+        let codes = |args: &[&str]| -> Vec<(String, usize)> {
+            let mut codes: Vec<(String, usize)> = LintCommand::parse_from(args)
+                .run(dir.path())
+                .unwrap()
+                .issues
+                .into_iter()
+                .filter(|i| !matches!(i.code.as_str(), "L001" | "L003" | "L004" | "L013"))
+                .map(|i| (i.code, i.line.unwrap_or(0)))
+                .collect();
+            codes.sort();
+            codes
+        };
+
+        // Opt-in: sin --category solo corre L010
+        assert_eq!(codes(&["lint"]), vec![("L010".to_string(), 17)]);
+        let a11y = codes(&["lint", "--category", "a11y"]);
+        let expected = [("L010", 17), ("L021", 8), ("L022", 10), ("L022", 14), ("L023", 16), ("L023", 16), ("L023", 17)];
+        assert_eq!(a11y, expected.map(|(c, l)| (c.to_string(), l)).to_vec());
+        assert_eq!(codes(&["lint", "--rule", "L021"]), vec![("L021".to_string(), 8)]);
+    }
+
+    #[test]
+    fn test_category_filters() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1 a.md"), "# A  \n\n| a | b |\n| 1 | 2 |\n").unwrap();
+        let codes = |args: &[&str]| -> Vec<String> {
+            let mut codes: Vec<String> = LintCommand::parse_from(args).run(dir.path()).unwrap().issues.into_iter().map(|i| i.code).collect();
+            codes.dedup();
+            codes
+        };
+
+        let all = LintCommand::parse_from(["lint"]).run(dir.path()).unwrap().issues;
+        assert!(all.iter().any(|i| i.code == "L003" && i.category() == "formato"));
+        assert_eq!(codes(&["lint", "--category", "tablas"]), vec!["L009"]);
+        assert!(!codes(&["lint", "--disable-category", "formato,estructura"]).iter().any(|c| rule_category(c) == "formato"));
+        assert!(categories().contains(&"links"));
+        let err = LintCommand::parse_from(["lint", "--disable-category", "estilo"]).run(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("Categoría desconocida: 'estilo'"));
+    }
+
+    #[test]
+    fn test_compact_format() {
+        let mut issue = LintIssue::warning("L006", "Code block sin lenguaje especificado", PathBuf::from("Datos/1.1 a.md"));
+        assert_eq!(issue.to_compact(), "Datos/1.1 a.md:1:1: L006 Code block sin lenguaje especificado");
+        issue.line = Some(12);
+        assert!(issue.to_compact().starts_with("Datos/1.1 a.md:12:1: L006 "));
+    }
+
+    #[test]
+    fn test_dir_profile_skip_rules() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("drafts")).unwrap();
+        let content = "---\nid: \"1\"\n---\n```\ncodigo\n```\n";
+        std::fs::write(dir.path().join("a.md"), content).unwrap();
+        std::fs::write(dir.path().join("drafts/b.md"), content).unwrap();
+
+        let mut cmd = LintCommand::parse_from(["lint", "--rule", "L006"]);
+        cmd.profiles = Some(vec![crate::core::config::DirProfile {
+            path: "drafts".to_string(),
+            skip_rules: vec!["L006".to_string()],
+            ..Default::default()
+        }]);
+        let result = cmd.run(dir.path()).unwrap();
+        let files: Vec<_> = result.issues.iter().map(|i| i.file.file_name().unwrap()).collect();
+        assert_eq!(files, vec!["a.md"]);
+    }
+
+    #[test]
+    fn test_fixable_issues_carry_fix() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.md");
+        std::fs::write(&path, "---\nid: \"1\"\n---\n# A   \ntexto\n").unwrap();
+
+        let mut cmd = LintCommand::parse_from(["lint", "--rule", "L003"]);
+        cmd.profiles = Some(Vec::new());
+        let result = cmd.run(dir.path()).unwrap();
+        let issue = result.issues.iter().find(|i| i.code == "L003").unwrap();
+        let fix = issue.fix.as_ref().unwrap();
+        assert!(fixes::render_diff(fix, false).unwrap().contains("+# A\n"));
+
+        let mut source = FixSource::new(cmd, dir.path(), PatchReview::default());
+        assert_eq!(source.apply_all_auto_fixes().len(), 1);
+        assert!(source.suggest_fixes().is_empty());
+    }
+
+    #[test]
+    fn test_timings_per_rule_and_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.md"), "---\nid: \"1\"\n---\n# A\n").unwrap();
+        std::fs::write(dir.path().join("b.md"), "---\nid: \"2\"\n---\n# B\n").unwrap();
+
+        let mut cmd = LintCommand::parse_from(["lint", "--timings", "--timings-top", "1"]);
+        cmd.profiles = Some(Vec::new());
+        let timings = cmd.run(dir.path()).unwrap().timings.unwrap();
+        assert_eq!(timings.disk_reads, 2);
+        assert_eq!(timings.slowest_files.len(), 1);
+        assert!(timings.stages.iter().any(|s| s.name == "L001"));
+
+        let plain = LintCommand::parse_from(["lint"]);
+        assert!(plain.run(dir.path()).unwrap().timings.is_none());
+    }
 }
 
 /// Función run para CLI.
@@ -996,6 +2181,18 @@ pub fn run(cmd: LintCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
     let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
     let result = cmd.run(data_dir)?;
 
+    // Formato compacto para errorformat/problem matchers: sin emojis ni resumen
+    if cmd.format == "compact" {
+        for issue in &result.issues {
+            println!("{}", issue.to_compact());
+        }
+        if let Some(timings) = &result.timings {
+            timings.print("regla");
+        }
+        open_issues(&result, &cmd.open)?;
+        return Ok(());
+    }
+
     for issue in &result.issues {
         let icon = match issue.severity {
             LintSeverity::Error => "❌",
@@ -1003,15 +2200,28 @@ pub fn run(cmd: LintCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
             LintSeverity::Info => "ℹ️",
             LintSeverity::Hint => "💡",
         };
-        let line_info = issue.line.map(|l| format!(":{}", l)).unwrap_or_default();
-        println!(
-            "{} [{}] {}{}: {}",
-            icon,
-            issue.code,
-            issue.file.display(),
-            line_info,
-            issue.message
-        );
+        let location = if cmd.format == "hyperlink" {
+            crate::core::editor::location_link(&issue.file, issue.line)
+        } else {
+            let line_info = issue.line.map(|l| format!(":{}", l)).unwrap_or_default();
+            format!("{}{}", issue.file.display(), line_info)
+        };
+        println!("{} [{} {}] {}: {}", icon, issue.code, issue.category(), location, issue.message);
+    }
+
+    // P3-A3: diff de la corrección de cada archivo fixable
+    if cmd.show_fixes {
+        let mut shown: Vec<&PathBuf> = Vec::new();
+        for fix in result.issues.iter().filter_map(|i| i.fix.as_ref()) {
+            let Some(file) = fix.file.as_ref().filter(|f| !shown.contains(f)) else {
+                continue;
+            };
+            shown.push(file);
+            println!("\n🔧 {}", fix.description);
+            if let Some(diff) = fixes::render_diff(fix, crate::ui::theme::colors_enabled()) {
+                print!("{}", diff);
+            }
+        }
     }
 
     println!("\n📊 Lint Report:");
@@ -1021,9 +2231,42 @@ pub fn run(cmd: LintCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
     println!("  ⚠️  Warnings: {}", result.warning_count());
     println!("  🔧 Fixables: {}", result.fixable_count());
 
+    if cmd.summary {
+        let mut by_category: std::collections::BTreeMap<&str, (usize, usize, usize)> = Default::default();
+        for issue in &result.issues {
+            let entry = by_category.entry(issue.category()).or_default();
+            match issue.severity {
+                LintSeverity::Error => entry.0 += 1,
+                LintSeverity::Warning => entry.1 += 1,
+                LintSeverity::Info | LintSeverity::Hint => entry.2 += 1,
+            }
+        }
+        println!("\n📂 Por categoría:");
+        for (category, (errors, warnings, other)) in by_category {
+            println!("  {:<12} ❌ {:<4} ⚠️  {:<4} ℹ️  {}", category, errors, warnings, other);
+        }
+    }
+
     if result.is_clean() {
         println!("\n✅ Sin problemas detectados");
     }
+    if let Some(timings) = &result.timings {
+        timings.print("regla");
+    }
+    open_issues(&result, &cmd.open)?;
+
+    Ok(())
+}
 
+/// `--open`: lanza el editor en cada issue pedido (1-based).
+#[cfg(feature = "cli")]
+fn open_issues(result: &LintResult, selected: &[usize]) -> anyhow::Result<()> {
+    for &n in selected {
+        let issue = n
+            .checked_sub(1)
+            .and_then(|i| result.issues.get(i))
+            .ok_or_else(|| anyhow::anyhow!("--open {}: hay {} issues", n, result.issues.len()))?;
+        crate::core::editor::open_in_editor(&issue.file, issue.line)?;
+    }
     Ok(())
 }
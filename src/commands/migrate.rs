@@ -2,10 +2,11 @@
 //!
 //! Migra proyectos entre versiones de oc_diagdoc.
 
-use crate::errors::OcResult;
+use crate::errors::{OcError, OcResult};
+use crate::types::{Breadcrumb, BreadcrumbIndex};
 use clap::Parser;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // MIGRATE TYPES
@@ -35,6 +36,24 @@ impl MigrateResult {
     }
 }
 
+/// Documento adoptado desde el inbox.
+#[derive(Debug, Clone, Serialize)]
+pub struct AdoptedDoc {
+    pub source: PathBuf,
+    pub dest: PathBuf,
+    pub id: String,
+    pub title: String,
+}
+
+/// Resultado de `migrate --adopt`.
+#[derive(Debug, Clone, Serialize)]
+pub struct AdoptResult {
+    pub parent: String,
+    pub adopted: Vec<AdoptedDoc>,
+    /// (archivo, motivo) de los que no se pudieron procesar.
+    pub failed: Vec<(PathBuf, String)>,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // MIGRATE COMMAND
 // ═══════════════════════════════════════════════════════════════════════════
@@ -54,6 +73,14 @@ pub struct MigrateCommand {
     /// Modo dry-run.
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Adoptar los .md sin frontmatter de esta carpeta (inbox).
+    #[arg(long, value_name = "DIR", requires = "parent")]
+    pub adopt: Option<PathBuf>,
+
+    /// ID del padre bajo el que se asignan IDs secuenciales (con --adopt).
+    #[arg(long, value_name = "ID")]
+    pub parent: Option<String>,
 }
 
 impl MigrateCommand {
@@ -68,6 +95,160 @@ impl MigrateCommand {
 
         Ok(result)
     }
+
+    /// Adopta un lote de documentos sueltos: genera frontmatter completo
+    /// (ID secuencial bajo el padre, breadcrumb, fechas, hash) y los mueve
+    /// al data_dir.
+    pub fn adopt(&self, data_dir: &Path) -> OcResult<AdoptResult> {
+        use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+
+        let inbox = self
+            .adopt
+            .as_ref()
+            .ok_or_else(|| OcError::InvalidArgument("Falta --adopt DIR".to_string()))?;
+        let parent_id = self
+            .parent
+            .as_deref()
+            .ok_or_else(|| OcError::InvalidArgument("Falta --parent ID".to_string()))?
+            .trim_end_matches('.');
+        if !inbox.is_dir() {
+            return Err(OcError::DirectoryNotFound(inbox.clone()));
+        }
+
+        let project = get_all_md_files(data_dir, &ScanOptions::new())?;
+        let ids: Vec<(String, PathBuf)> = project
+            .iter()
+            .filter_map(|p| Some((file_id(p)?, p.clone())))
+            .collect();
+        let parent_path = ids
+            .iter()
+            .find(|(id, _)| id == parent_id)
+            .map(|(_, p)| p.clone())
+            .ok_or_else(|| {
+                OcError::InvalidArgument(format!("El padre '{}' no existe en {}", parent_id, data_dir.display()))
+            })?;
+        // Breadcrumb canónico del padre; si su cadena está rota, se usa el declarado
+        let mut index = BreadcrumbIndex::new();
+        for path in &project {
+            if let Ok(content) = read_file_content(path) {
+                index.insert_document(path, &content);
+            }
+        }
+        let parent_breadcrumb = match index.canonical(parent_id) {
+            Ok(bc) => bc,
+            Err(_) => {
+                let parent_content = read_file_content(&parent_path)?;
+                yaml_value(&parent_content, "breadcrumb")
+                    .or_else(|| yaml_value(&parent_content, "title"))
+                    .map(|bc| Breadcrumb::parse(&bc))
+                    .unwrap_or_else(|| Breadcrumb::from_segments([stem_of(&parent_path)]))
+            }
+        };
+
+        let prefix = format!("{}.", parent_id);
+        let mut next = ids
+            .iter()
+            .filter_map(|(id, _)| id.strip_prefix(&prefix)?.split('.').next()?.parse::<u32>().ok())
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        let mut sources: Vec<PathBuf> = std::fs::read_dir(inbox)?
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.is_file() && p.extension().is_some_and(|e| e == "md"))
+            .collect();
+        sources.sort();
+
+        let mut result = AdoptResult {
+            parent: parent_id.to_string(),
+            adopted: Vec::new(),
+            failed: Vec::new(),
+        };
+        for source in sources {
+            let content = match read_file_content(&source) {
+                Ok(c) => c,
+                Err(e) => {
+                    result.failed.push((source, e.to_string()));
+                    continue;
+                }
+            };
+            if content.trim_start().starts_with("---") {
+                result.failed.push((source, "ya tiene frontmatter".to_string()));
+                continue;
+            }
+            if content.trim().is_empty() {
+                result.failed.push((source, "archivo vacío".to_string()));
+                continue;
+            }
+
+            let id = format!("{}{}", prefix, next);
+            let stem = stem_of(&source);
+            let title = content
+                .lines()
+                .find_map(|l| l.strip_prefix("# "))
+                .map(|t| t.trim().to_string())
+                .unwrap_or_else(|| stem.to_string());
+            let dest = data_dir.join(format!("{} {}.md", id, stem));
+            if dest.exists() {
+                result.failed.push((source, format!("ya existe {}", dest.display())));
+                continue;
+            }
+
+            let document = adopted_document(&id, &title, parent_id, &parent_breadcrumb.child(&title), &content);
+            if !self.dry_run {
+                crate::core::files::write_file_atomic(&dest, &document)?;
+                std::fs::remove_file(&source)?;
+            }
+            result.adopted.push(AdoptedDoc { source, dest, id, title });
+            next += 1;
+        }
+
+        Ok(result)
+    }
+}
+
+/// ID jerárquico al inicio del nombre de archivo ("4.2.1 alta.md" → "4.2.1").
+fn file_id(path: &Path) -> Option<String> {
+    let id = stem_of(path).split_whitespace().next()?.trim_end_matches('.');
+    (!id.is_empty() && id.split('.').all(|s| s.parse::<u32>().is_ok())).then(|| id.to_string())
+}
+
+fn stem_of(path: &Path) -> &str {
+    path.file_stem().and_then(|s| s.to_str()).unwrap_or("documento")
+}
+
+/// Valor escalar de un campo del frontmatter.
+fn yaml_value(content: &str, field: &str) -> Option<String> {
+    let prefix = format!("{}:", field);
+    content
+        .lines()
+        .skip(1)
+        .take_while(|l| l.trim() != "---")
+        .find_map(|l| l.strip_prefix(&prefix))
+        .map(|v| v.trim().trim_matches(|c| c == '"' || c == '\'').to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Documento con frontmatter completo; el hash usa la misma lógica que sync/verify.
+fn adopted_document(id: &str, title: &str, parent: &str, breadcrumb: &Breadcrumb, body: &str) -> String {
+    use crate::core::hash::compute_hash_excluding_volatile;
+
+    let now = chrono::Local::now();
+    let title = title.replace('"', "'");
+    let breadcrumb = breadcrumb.to_string().replace('"', "'");
+    let frontmatter = format!(
+        "---\nid: \"{id}\"\ntitle: \"{title}\"\nparent: \"{parent}\"\nbreadcrumb: \"{breadcrumb}\"\ntype: \"hoja\"\nstatus: \"borrador\"\ncreated: \"{}\"\nlast_updated: \"{}\"\n",
+        now.format("%Y-%m-%d"),
+        now.format("%Y-%m-%d %H:%M:%S"),
+    );
+    let without_hash = format!("{}---\n\n{}", frontmatter, body.trim_start());
+    let hash = compute_hash_excluding_volatile(&without_hash);
+    format!(
+        "{}content_hash: \"{}\"\n---\n\n{}",
+        frontmatter,
+        &hash.full()[..16],
+        body.trim_start()
+    )
 }
 
 #[cfg(test)]
@@ -93,6 +274,8 @@ mod tests {
             path: None,
             to: Some("3.0".to_string()),
             dry_run: false,
+            adopt: None,
+            parent: None,
         };
         let result = cmd.run().unwrap();
         assert_eq!(result.to_version, "3.0");
@@ -104,15 +287,84 @@ mod tests {
             path: None,
             to: None,
             dry_run: false,
+            adopt: None,
+            parent: None,
         };
         let result = cmd.run().unwrap();
         assert_eq!(result.to_version, "3.0");
     }
+
+    #[test]
+    fn test_adopt_inbox() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("Datos");
+        let inbox = dir.path().join("inbox");
+        std::fs::create_dir_all(&data).unwrap();
+        std::fs::create_dir_all(&inbox).unwrap();
+        std::fs::write(
+            data.join("4.2 pagos.md"),
+            "---\nid: \"4.2\"\ntitle: \"Pagos\"\nparent: \"4\"\nbreadcrumb: \"Finanzas > Pagos\"\n---\n",
+        )
+        .unwrap();
+        std::fs::write(data.join("4 finanzas.md"), "---\nid: \"4\"\ntitle: \"Finanzas\"\n---\n").unwrap();
+        std::fs::write(data.join("4.2.1 tarjeta.md"), "---\nid: \"4.2.1\"\n---\n").unwrap();
+        std::fs::write(inbox.join("a_reembolsos.md"), "# Reembolsos\n\nTexto.\n").unwrap();
+        std::fs::write(inbox.join("b_cheques.md"), "Sin título.\n").unwrap();
+        std::fs::write(inbox.join("c_listo.md"), "---\nid: x\n---\n").unwrap();
+
+        let cmd = MigrateCommand::parse_from([
+            "migrate",
+            "--adopt",
+            inbox.to_str().unwrap(),
+            "--parent",
+            "4.2",
+        ]);
+        let result = cmd.adopt(&data).unwrap();
+
+        let ids: Vec<&str> = result.adopted.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["4.2.2", "4.2.3"]);
+        assert_eq!(result.failed.len(), 1);
+        assert!(!inbox.join("a_reembolsos.md").exists());
+
+        let adopted = std::fs::read_to_string(data.join("4.2.2 a_reembolsos.md")).unwrap();
+        assert!(adopted.contains("title: \"Reembolsos\""));
+        assert!(adopted.contains("breadcrumb: \"Finanzas > Pagos > Reembolsos\""));
+        assert!(adopted.ends_with("---\n\n# Reembolsos\n\nTexto.\n"));
+
+        // El hash generado coincide con el que verifica la fase 21
+        use crate::commands::verify::VerifyCommand;
+        let verify = VerifyCommand::parse_from(["verify", "--phase", "hash"]);
+        let verified = verify.run(&data).unwrap();
+        assert!(verified.phases[0].warnings.is_empty(), "{:?}", verified.phases[0].warnings);
+
+        let missing = MigrateCommand::parse_from(["migrate", "--adopt", inbox.to_str().unwrap(), "--parent", "9"]);
+        assert!(missing.adopt(&data).is_err());
+    }
 }
 
 /// Función run para CLI.
 #[cfg(feature = "cli")]
-pub fn run(cmd: MigrateCommand, _cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+pub fn run(cmd: MigrateCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    if cmd.adopt.is_some() {
+        let default_dir = PathBuf::from(&cli.data_dir);
+        let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
+        let result = cmd.adopt(data_dir)?;
+        let prefix = if cmd.dry_run { "[DRY-RUN] " } else { "" };
+        println!("📥 {}Adoptando bajo {}", prefix, result.parent);
+        for doc in &result.adopted {
+            println!("  ✓ {} → {} ({})", doc.source.display(), doc.dest.display(), doc.title);
+        }
+        for (path, reason) in &result.failed {
+            println!("  ✗ {}: {}", path.display(), reason);
+        }
+        println!(
+            "📊 {} adoptados, {} sin procesar",
+            result.adopted.len(),
+            result.failed.len()
+        );
+        return Ok(());
+    }
+
     let result = cmd.run()?;
 
     println!(
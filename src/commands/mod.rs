@@ -5,7 +5,10 @@ use crate::CliConfig;
 use clap::Subcommand;
 
 // Comandos analíticos
+pub mod ask;
+pub mod board;
 pub mod deps;
+pub mod quantum;
 pub mod search;
 pub mod stats;
 pub mod tree;
@@ -16,6 +19,7 @@ pub mod batch;
 pub mod fix;  // RFC-07
 pub mod links;
 pub mod sync;
+pub mod version;
 
 // Comandos de diagnóstico
 pub mod audit;
@@ -23,6 +27,7 @@ pub mod coverage;
 pub mod health;
 pub mod lint;
 pub mod module;
+pub mod placement;
 pub mod report;
 pub mod trace;
 pub mod watch;
@@ -36,6 +41,7 @@ pub mod template;
 // Comandos de producción
 pub mod archive;
 pub mod ci;
+pub mod daemon;
 pub mod diff;
 pub mod init;
 pub mod migrate;
@@ -43,8 +49,13 @@ pub mod restore;
 pub mod snapshot;
 
 // Comandos de sistema
+pub mod bench;
+pub mod cache;
 pub mod help;
+pub mod index;
 pub mod readme;
+pub mod repl;
+pub mod rules;
 pub mod dashboard;  // ADD#1: TUI Dashboard
 
 #[cfg(feature = "cli")]
@@ -54,7 +65,10 @@ pub enum Command {
     Verify(verify::VerifyCommand),
     Stats(stats::StatsCommand),
     Search(search::SearchCommand),
+    Ask(ask::AskCommand),
+    Board(board::BoardCommand),
     Deps(deps::DepsCommand),
+    Quantum(quantum::QuantumCommand),
     Tree(tree::TreeCommand),
 
     // Modificación
@@ -62,6 +76,7 @@ pub enum Command {
     Fix(fix::FixCommand),  // RFC-07
     Sync(sync::SyncCommand),
     Links(links::LinksCommand),
+    Version(version::VersionCommand),
 
     // Diagnóstico
     Lint(lint::LintCommand),
@@ -71,6 +86,7 @@ pub enum Command {
     Audit(audit::AuditCommand),
     Report(report::ReportCommand),
     Module(module::ModuleCommand),
+    SuggestPlacement(placement::SuggestPlacementCommand),
     Watch(watch::WatchCommand),
 
     // Generación
@@ -87,25 +103,60 @@ pub enum Command {
     Restore(restore::RestoreCommand),
     Archive(archive::ArchiveCommand),
     Ci(ci::CiCommand),
+    Daemon(daemon::DaemonCommand),
 
     // Sistema
+    Bench(bench::BenchCommand),
     Readme(readme::ReadmeCommand),
+    Index(index::IndexCommand),
+    Cache(cache::CacheCommand),
+    Help(help::HelpCommand),
+    Rules(rules::RulesCommand),
+    Repl(repl::ReplCommand),
 
     Dashboard(dashboard::DashboardCommand),  // ADD#1: TUI Dashboard
 }
 
+/// Código de salida de un comando que termina sin error pero no en 0
+/// (verify, ci). `main` sale con él después de guardar la caché de
+/// frontmatter y la telemetría, sin imprimirlo como error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExitStatus(pub i32);
+
+impl ExitStatus {
+    /// `Ok` para 0; si no, el código como error para `main`.
+    pub fn result(code: i32) -> anyhow::Result<()> {
+        match code {
+            0 => Ok(()),
+            code => Err(Self(code).into()),
+        }
+    }
+}
+
+impl std::fmt::Display for ExitStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "código de salida {}", self.0)
+    }
+}
+
+impl std::error::Error for ExitStatus {}
+
 #[cfg(feature = "cli")]
 pub fn execute(cmd: Command, cli: &CliConfig) -> anyhow::Result<()> {
     match cmd {
         Command::Verify(args) => verify::run(args, cli),
         Command::Stats(args) => stats::run(args, cli),
         Command::Search(args) => search::run(args, cli),
+        Command::Ask(args) => ask::run(args, cli),
+        Command::Board(args) => board::run(args, cli),
         Command::Deps(args) => deps::run(args, cli),
+        Command::Quantum(args) => quantum::run(args, cli),
         Command::Tree(args) => tree::run(args, cli),
         Command::Batch(args) => batch::run(args, cli),
         Command::Fix(args) => fix::run(args, cli),  // RFC-07
         Command::Sync(args) => sync::run(args, cli),
         Command::Links(args) => links::run(args, cli),
+        Command::Version(args) => version::run(args, cli),
         Command::Lint(args) => lint::run(args, cli),
         Command::Health(args) => health::run(args, cli),
         Command::Coverage(args) => coverage::run(args, cli),
@@ -113,6 +164,7 @@ pub fn execute(cmd: Command, cli: &CliConfig) -> anyhow::Result<()> {
         Command::Audit(args) => audit::run(args, cli),
         Command::Report(args) => report::run(args, cli),
         Command::Module(args) => module::run(args, cli),
+        Command::SuggestPlacement(args) => placement::run(args, cli),
         Command::Watch(args) => watch::run(args, cli),
         Command::Gen(args) => gen::run(args, cli),
         Command::Template(args) => template::run(args, cli),
@@ -125,8 +177,94 @@ pub fn execute(cmd: Command, cli: &CliConfig) -> anyhow::Result<()> {
         Command::Restore(args) => restore::run(args, cli),
         Command::Archive(args) => archive::run(args, cli),
         Command::Ci(args) => ci::run(args, cli),
+        Command::Daemon(args) => daemon::run(args, cli),
+        Command::Bench(args) => bench::run(args, cli),
         Command::Readme(args) => readme::run(args, cli),
+        Command::Index(args) => index::run(args, cli),
+        Command::Cache(args) => cache::run(args, cli),
+        Command::Help(args) => help::run(args, cli),
+        Command::Rules(args) => rules::run(args, cli),
+        Command::Repl(args) => repl::run(args, cli),
 
         Command::Dashboard(args) => dashboard::run(args, cli),  // ADD#1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// Salida de verify, lint, deps, stats y tree sobre un proyecto creado en
+    /// desorden: debe salir por módulo, ID natural y línea, siempre igual.
+    fn ordered_output(dir: &std::path::Path) -> String {
+        let mut out = String::new();
+
+        for name in ["unique_ids", "links"] {
+            let mut verify = verify::VerifyCommand::parse_from(["verify", "--phase", name]);
+            verify.quiet = true;
+            for phase in verify.run(&dir.to_path_buf()).unwrap().phases {
+                for msg in phase.errors.iter().chain(&phase.warnings) {
+                    out.push_str(&format!("verify V{}: {}\n", phase.id, msg));
+                }
+            }
+        }
+
+        let mut lint = lint::LintCommand::parse_from(["lint", "--rule", "L003"]);
+        lint.profiles = Some(Vec::new());
+        for issue in lint.run(dir).unwrap().issues {
+            let name = issue.file.file_name().unwrap().to_string_lossy().to_string();
+            out.push_str(&format!("lint {}:{} {}\n", name, issue.line.unwrap_or(0), issue.code));
+        }
+
+        let deps = deps::DepsCommand::parse_from(["deps"]).run(dir).unwrap();
+        out.push_str(&format!("deps roots: {}\n", deps.root_nodes.join(", ")));
+
+        let (_, modules) = stats::StatsCommand::parse_from(["stats"]).run(dir).unwrap();
+        let ids: Vec<&str> = modules.iter().map(|m| m.id.as_str()).collect();
+        out.push_str(&format!("stats modules: {}\n", ids.join(", ")));
+
+        let tree = tree::TreeCommand::parse_from(["tree"]).run(dir).unwrap();
+        let ids: Vec<&str> = tree.nodes.iter().map(|n| n.id.as_str()).collect();
+        out.push_str(&format!("tree: {}\n", ids.join(", ")));
+        out
+    }
+
+    #[test]
+    fn test_output_order_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let docs = [
+            ("10 diez", "10", "0", "[[9 nada]]"),
+            ("2 dos", "2", "0", "texto  "),
+            ("1.10 alta", "1.10", "1", "[[7 nada]]\nfin  "),
+            ("1 raiz", "1", "0", "uno  \n[[8 nada]]"),
+            ("1.2 baja", "1.2", "1", "dup  "),
+            ("1.9 dup", "1.2", "1", "[[6 nada]]  "),
+        ];
+        for (stem, id, parent, body) in docs {
+            let content = format!("---\nid: \"{}\"\ntitle: \"{}\"\nparent: \"{}\"\n---\n{}\n", id, stem, parent, body);
+            std::fs::write(dir.path().join(format!("{}.md", stem)), content).unwrap();
+        }
+
+        let snapshot = ordered_output(dir.path());
+        assert_eq!(snapshot, ordered_output(dir.path()));
+        assert_eq!(
+            snapshot,
+            "\
+verify V3: ID DUPLICADO: '1.2' en 1.2 baja.md, 1.9 dup.md
+verify V9: 1 raiz.md: Link roto [[8 nada]]
+verify V9: 1.9 dup.md: Link roto [[6 nada]]
+verify V9: 1.10 alta.md: Link roto [[7 nada]]
+verify V9: 10 diez.md: Link roto [[9 nada]]
+lint 1 raiz.md:6 L003
+lint 1.2 baja.md:6 L003
+lint 1.9 dup.md:6 L003
+lint 1.10 alta.md:7 L003
+lint 2 dos.md:6 L003
+deps roots: 1 raiz, 1.2 baja, 1.9 dup, 1.10 alta, 2 dos, 10 diez
+stats modules: 1, 2, 10
+tree: 1 raiz, 1.2 baja, 1.9 dup, 1.10 alta, 2 dos, 10 diez
+"
+        );
+    }
+}
@@ -35,11 +35,7 @@ impl ModuleInfo {
     }
 
     pub fn avg_words(&self) -> usize {
-        if self.document_count == 0 {
-            0
-        } else {
-            self.word_count / self.document_count
-        }
+        self.word_count.checked_div(self.document_count).unwrap_or(0)
     }
 }
 
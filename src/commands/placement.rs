@@ -0,0 +1,247 @@
+//! Comando suggest-placement - Ubicación sugerida para documentos sueltos.
+//!
+//! Compara los términos de un borrador (sin ID) con cada documento del
+//! proyecto y su subárbol, y propone padres con el siguiente ID libre.
+
+use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+use crate::core::patterns::RE_TITLE;
+use crate::core::terms::significant_words;
+use crate::errors::{OcError, OcResult};
+use crate::types::DataPath;
+use clap::Parser;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Vector de términos con peso sublineal (1 + ln tf).
+type TermVector = BTreeMap<String, f64>;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PLACEMENT TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Padre candidato para el borrador.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlacementSuggestion {
+    pub parent_id: String,
+    pub parent_title: String,
+    pub parent_file: PathBuf,
+    /// Similitud combinada (documento + subárbol), 0-1.
+    pub score: f64,
+    /// Siguiente ID hijo libre bajo el padre.
+    pub next_id: String,
+}
+
+/// Resultado de suggest-placement.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlacementResult {
+    pub file: PathBuf,
+    pub suggestions: Vec<PlacementSuggestion>,
+}
+
+/// Documento del proyecto con ID.
+struct PlacedDoc {
+    id: String,
+    title: String,
+    path: PathBuf,
+    words: Vec<String>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SUGGEST-PLACEMENT COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Comando suggest-placement.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "suggest-placement", about = "Sugerir padre e ID para un documento suelto")]
+pub struct SuggestPlacementCommand {
+    /// Archivo borrador a ubicar.
+    pub file: PathBuf,
+
+    /// Cantidad de padres candidatos.
+    #[arg(long, default_value = "3")]
+    pub top: usize,
+
+    /// Ruta del proyecto.
+    #[arg(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// Output JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl SuggestPlacementCommand {
+    pub fn run(&self, data_dir: &Path) -> OcResult<PlacementResult> {
+        if !self.file.is_file() {
+            return Err(OcError::FileNotFound(self.file.clone()));
+        }
+        let draft = vectorize(&significant_words(&read_file_content(&self.file)?));
+        if draft.is_empty() {
+            return Err(OcError::InvalidArgument(format!(
+                "{} no tiene contenido suficiente para comparar",
+                self.file.display()
+            )));
+        }
+
+        let draft_path = self.file.canonicalize().ok();
+        let docs: Vec<PlacedDoc> = get_all_md_files(data_dir, &ScanOptions::new())?
+            .into_iter()
+            .filter(|p| p.canonicalize().ok() != draft_path)
+            .filter_map(|path| {
+                let id = DataPath::new(path.clone())
+                    .extract_id()?
+                    .trim_end_matches('.')
+                    .to_string();
+                if !id.split('.').all(|s| s.parse::<u32>().is_ok()) {
+                    return None;
+                }
+                let content = read_file_content(&path).ok()?;
+                let title = RE_TITLE
+                    .captures(&content)
+                    .map(|c| c[1].trim().to_string())
+                    .unwrap_or_else(|| {
+                        path.file_stem().and_then(|s| s.to_str()).unwrap_or("").to_string()
+                    });
+                Some(PlacedDoc {
+                    id,
+                    title,
+                    words: significant_words(&content),
+                    path,
+                })
+            })
+            .collect();
+
+        let mut suggestions: Vec<PlacementSuggestion> = docs
+            .iter()
+            .map(|doc| {
+                let prefix = format!("{}.", doc.id);
+                let subtree: Vec<String> = docs
+                    .iter()
+                    .filter(|d| d.id == doc.id || d.id.starts_with(&prefix))
+                    .flat_map(|d| d.words.iter().cloned())
+                    .collect();
+                let score = 0.5 * cosine(&draft, &vectorize(&doc.words))
+                    + 0.5 * cosine(&draft, &vectorize(&subtree));
+                PlacementSuggestion {
+                    parent_id: doc.id.clone(),
+                    parent_title: doc.title.clone(),
+                    parent_file: doc.path.clone(),
+                    score,
+                    next_id: next_child_id(&doc.id, docs.iter().map(|d| d.id.as_str())),
+                }
+            })
+            .filter(|s| s.score > 0.0)
+            .collect();
+        suggestions.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.parent_id.cmp(&b.parent_id))
+        });
+        suggestions.truncate(self.top);
+
+        Ok(PlacementResult {
+            file: self.file.clone(),
+            suggestions,
+        })
+    }
+}
+
+/// Vector normalizado (L2) de términos.
+fn vectorize(words: &[String]) -> TermVector {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for w in words {
+        *counts.entry(w.as_str()).or_default() += 1;
+    }
+    let mut v: TermVector = counts
+        .into_iter()
+        .map(|(t, c)| (t.to_string(), 1.0 + (c as f64).ln()))
+        .collect();
+    let norm = v.values().map(|x| x * x).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        v.values_mut().for_each(|x| *x /= norm);
+    }
+    v
+}
+
+fn cosine(a: &TermVector, b: &TermVector) -> f64 {
+    a.iter().filter_map(|(t, x)| b.get(t).map(|y| x * y)).sum()
+}
+
+/// Siguiente hijo directo libre: `<parent>.<max+1>`.
+fn next_child_id<'a>(parent: &str, ids: impl Iterator<Item = &'a str>) -> String {
+    let prefix = format!("{}.", parent);
+    let max = ids
+        .filter_map(|id| id.strip_prefix(&prefix))
+        .filter_map(|rest| rest.split('.').next()?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0);
+    format!("{}{}", prefix, max + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    #[test]
+    fn test_next_child_id() {
+        let ids = ["2", "2.1", "2.3", "2.3.1", "2.10", "3.1"];
+        assert_eq!(next_child_id("2", ids.into_iter()), "2.11");
+        assert_eq!(next_child_id("2.3", ids.into_iter()), "2.3.2");
+        assert_eq!(next_child_id("4", ids.into_iter()), "4.1");
+    }
+
+    #[test]
+    fn test_suggest_placement_by_terms() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        project.write("1 Pagos.md", "---\ntitle: \"Pagos\"\n---\nPagos, tarjetas y comisiones.\n");
+        project.write("1.1 Tarjeta.md", "Pagos con tarjeta de crédito y comisiones.\n");
+        project.write("2 Envios.md", "---\ntitle: \"Envíos\"\n---\nEnvíos, logística y transportistas.\n");
+        project.write("README.md", "Pagos con tarjeta y comisiones.\n");
+
+        let draft = dir.join("borrador.md");
+        std::fs::write(&draft, "Nueva comisión para pagos con tarjeta débito.\n").unwrap();
+
+        let cmd = SuggestPlacementCommand::parse_from(["suggest-placement", draft.to_str().unwrap()]);
+        let result = cmd.run(dir).unwrap();
+
+        let best = &result.suggestions[0];
+        assert_eq!(best.parent_id, "1.1");
+        assert_eq!(best.next_id, "1.1.1");
+        assert_eq!(result.suggestions[1].parent_id, "1");
+        assert_eq!(result.suggestions[1].next_id, "1.2");
+        assert!(result.suggestions.iter().all(|s| s.parent_id != "2" && s.parent_id != "README"));
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: SuggestPlacementCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    let default_dir = PathBuf::from(&cli.data_dir);
+    let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
+    let result = cmd.run(data_dir)?;
+
+    if cmd.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    println!("📍 Ubicación sugerida para {}", result.file.display());
+    if result.suggestions.is_empty() {
+        println!("  Sin coincidencias de términos con el proyecto");
+        return Ok(());
+    }
+    for (i, s) in result.suggestions.iter().enumerate() {
+        println!(
+            "  {}. {} ({}) → nuevo ID {} [score {:.2}]",
+            i + 1,
+            s.parent_id,
+            s.parent_title,
+            s.next_id,
+            s.score
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,248 @@
+//! Comando quantum - Análisis sobre el grafo del proyecto.
+//!
+//! `quantum partition --teams 4` reparte los documentos pendientes de
+//! revisión en lotes balanceados por palabras y con el menor acoplamiento
+//! posible entre lotes (min-cut sobre el grafo de links, ver
+//! `quantum::partition`), para distribuir la revisión entre equipos.
+
+use crate::core::files::natural_cmp;
+use crate::core::links::extract_links;
+use crate::core::pipeline::ProjectPipeline;
+use crate::core::yaml::count_words;
+use crate::errors::{OcError, OcResult};
+use crate::quantum::partition::partition;
+use crate::types::breadcrumb::frontmatter_value;
+use crate::types::lifetimes::split_frontmatter;
+use crate::types::DocumentStatus;
+use clap::Parser;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PARTITION TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Lote de revisión.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewBatch {
+    /// Número de lote (1-based).
+    pub batch: usize,
+    pub words: usize,
+    /// Documentos del lote (stem del archivo), en orden jerárquico.
+    pub documents: Vec<String>,
+    /// Links hacia documentos de otros lotes.
+    pub external_links: usize,
+}
+
+/// Reparto de la revisión entre equipos.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartitionResult {
+    pub teams: usize,
+    pub documents: usize,
+    pub words: usize,
+    /// Links entre documentos pendientes.
+    pub links: usize,
+    /// Links que cruzan de un lote a otro.
+    pub cut_links: usize,
+    pub batches: Vec<ReviewBatch>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// QUANTUM COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Comando de análisis cuántico.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "quantum", about = "Análisis sobre el grafo del proyecto")]
+pub struct QuantumCommand {
+    /// Acción: partition.
+    pub action: String,
+
+    /// Cantidad de lotes (equipos de revisión).
+    #[arg(long, default_value = "4")]
+    pub teams: usize,
+
+    /// Incluir también los documentos ya revisados, obsoletos o archivados.
+    #[arg(long)]
+    pub all: bool,
+
+    /// Ruta del proyecto.
+    #[arg(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// Output JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// ¿El documento espera revisión? Sin `status` (o con uno desconocido)
+/// cuenta como borrador.
+fn pending_review(status: Option<&str>) -> bool {
+    let status = status.and_then(|s| s.parse::<DocumentStatus>().ok()).unwrap_or_default();
+    !matches!(
+        status,
+        DocumentStatus::Reviewed | DocumentStatus::Deprecated | DocumentStatus::Archived
+    )
+}
+
+impl QuantumCommand {
+    pub fn run(&self, data_dir: &Path) -> OcResult<PartitionResult> {
+        if self.action != "partition" {
+            return Err(OcError::InvalidArgument(format!(
+                "Acción desconocida: '{}' (use partition)",
+                self.action
+            )));
+        }
+        if self.teams == 0 {
+            return Err(OcError::InvalidArgument("--teams debe ser al menos 1".to_string()));
+        }
+        self.partition(data_dir)
+    }
+
+    fn partition(&self, data_dir: &Path) -> OcResult<PartitionResult> {
+        let pipeline = ProjectPipeline::new(data_dir);
+        let files = pipeline.scan()?;
+        let mut docs: Vec<_> = pipeline
+            .parse(&files)
+            .into_iter()
+            .filter(|doc| {
+                let split = split_frontmatter(&doc.content);
+                let status = split.and_then(|s| frontmatter_value(s.metadata, "status"));
+                self.all || pending_review(status.as_deref())
+            })
+            .collect();
+        // Orden jerárquico: el reparto inicial deja cada módulo junto
+        docs.sort_by(|a, b| natural_cmp(a.stem(), b.stem()));
+
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for (i, doc) in docs.iter().enumerate() {
+            index.insert(doc.stem().to_string(), i);
+            if let Some(id) = doc.effective_id() {
+                index.entry(id.to_string()).or_insert(i);
+            }
+        }
+
+        let weights: Vec<usize> = docs
+            .iter()
+            .map(|doc| count_words(split_frontmatter(&doc.content).map_or(doc.content.as_str(), |s| s.content)))
+            .collect();
+
+        // Links no dirigidos entre pendientes, con su multiplicidad
+        let mut pairs: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+        for (from, doc) in docs.iter().enumerate() {
+            for link in extract_links(&doc.content) {
+                if !link.is_internal() || link.is_embed() {
+                    continue;
+                }
+                let target = link.target.split('#').next().unwrap_or_default();
+                // file_stem cortaría "1.2 baja" en el primer punto
+                let name = target.trim().rsplit('/').next().unwrap_or_default();
+                let stem = name.strip_suffix(".md").unwrap_or(name);
+                if let Some(&to) = index.get(stem).filter(|&&to| to != from) {
+                    *pairs.entry((from.min(to), from.max(to))).or_default() += 1;
+                }
+            }
+        }
+        let edges: Vec<(usize, usize, usize)> = pairs.iter().map(|(&(a, b), &w)| (a, b, w)).collect();
+
+        let result = partition(&weights, &edges, self.teams);
+        let mut batches: Vec<ReviewBatch> = (0..self.teams)
+            .map(|i| ReviewBatch {
+                batch: i + 1,
+                words: 0,
+                documents: Vec::new(),
+                external_links: 0,
+            })
+            .collect();
+        for (i, doc) in docs.iter().enumerate() {
+            let batch = &mut batches[result.assignment[i]];
+            batch.documents.push(doc.stem().to_string());
+            batch.words += weights[i];
+        }
+        for &(a, b, w) in &edges {
+            let (lot_a, lot_b) = (result.assignment[a], result.assignment[b]);
+            if lot_a != lot_b {
+                batches[lot_a].external_links += w;
+                batches[lot_b].external_links += w;
+            }
+        }
+
+        Ok(PartitionResult {
+            teams: self.teams,
+            documents: docs.len(),
+            words: weights.iter().sum(),
+            links: edges.iter().map(|&(.., w)| w).sum(),
+            cut_links: result.cut,
+            batches,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition_pending_documents() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, status: &str, body: &str| {
+            let doc = format!("---\nid: \"{}\"\nstatus: {}\n---\n{}\n", &name[..3], status, body);
+            std::fs::write(dir.path().join(format!("{}.md", name)), doc).unwrap();
+        };
+        let words = "palabra ".repeat(40);
+        write("1.1 alta", "draft", &format!("{} [[1.2 baja]] [[1.3 pago]]", words));
+        write("1.2 baja", "draft", &format!("{} [[1.1 alta]]", words));
+        write("1.3 pago", "draft", &format!("{} [[2.1 mora]]", words));
+        write("2.1 mora", "draft", &format!("{} [[2.2 cobro]]", words));
+        write("2.2 cobro", "draft", &format!("{} [[2.1 mora]]", words));
+        write("2.3 nota", "active", &format!("{} [[2.1 mora|mora]]", words));
+        write("3.1 listo", "reviewed", &words);
+
+        let cmd = QuantumCommand::parse_from(["quantum", "partition", "--teams", "2"]);
+        let result = cmd.run(dir.path()).unwrap();
+        assert_eq!((result.documents, result.links), (6, 7));
+        assert_eq!(result.batches.len(), 2);
+        let first = &result.batches[0];
+        assert_eq!(first.documents, vec!["1.1 alta", "1.2 baja", "1.3 pago"]);
+        assert_eq!(result.cut_links, 1);
+        assert_eq!((first.words, first.external_links), (128, 1));
+
+        let all = QuantumCommand::parse_from(["quantum", "partition", "--all"]).run(dir.path()).unwrap();
+        assert_eq!(all.documents, 7);
+        assert!(QuantumCommand::parse_from(["quantum", "cluster"]).run(dir.path()).is_err());
+        assert!(QuantumCommand::parse_from(["quantum", "partition", "--teams", "0"]).run(dir.path()).is_err());
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: QuantumCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    let default_dir = PathBuf::from(&cli.data_dir);
+    let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
+    let result = cmd.run(data_dir)?;
+
+    if cmd.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    println!(
+        "🧩 {} documentos ({} palabras) en {} lotes · {} de {} links entre lotes",
+        result.documents, result.words, result.teams, result.cut_links, result.links
+    );
+    for batch in &result.batches {
+        println!(
+            "\n📦 Lote {}: {} documentos, {} palabras, {} links externos",
+            batch.batch,
+            batch.documents.len(),
+            batch.words,
+            batch.external_links
+        );
+        for doc in &batch.documents {
+            println!("   • {}", doc);
+        }
+    }
+
+    Ok(())
+}
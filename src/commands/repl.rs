@@ -0,0 +1,263 @@
+//! Comando repl - Sesión interactiva sobre el proyecto en memoria.
+//!
+//! Carga el proyecto una vez en un `DocumentCache` y ejecuta verify, search,
+//! tree y deps sobre él; antes de cada comando recarga solo los archivos
+//! modificados o borrados.
+
+use crate::commands::deps::DepsCommand;
+use crate::commands::search::SearchCommand;
+use crate::commands::tree::TreeCommand;
+use crate::commands::verify::VerifyCommand;
+use crate::core::pipeline::{DocumentCache, ProjectPipeline};
+use crate::errors::OcResult;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REPL COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Comando repl.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "repl", about = "Sesión interactiva con el proyecto en memoria")]
+pub struct ReplCommand {
+    /// Ruta del proyecto.
+    #[arg(short, long)]
+    pub path: Option<PathBuf>,
+}
+
+/// Línea de la sesión: un comando sin el nombre del binario.
+#[derive(Parser, Debug)]
+#[command(
+    name = "oc",
+    about = "Comandos de la sesión repl",
+    no_binary_name = true,
+    disable_version_flag = true
+)]
+struct ReplLine {
+    #[command(subcommand)]
+    action: ReplAction,
+}
+
+#[derive(Subcommand, Debug)]
+enum ReplAction {
+    Verify(VerifyCommand),
+    Search(SearchCommand),
+    Tree(TreeCommand),
+    Deps(DepsCommand),
+    /// Recargar archivos modificados.
+    Reload,
+    /// Terminar la sesión.
+    #[command(alias = "quit")]
+    Exit,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SESSION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Estado de una sesión: directorio y caché compartida por los comandos.
+pub struct ReplSession {
+    data_dir: PathBuf,
+    documents: Arc<DocumentCache>,
+}
+
+impl ReplSession {
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            data_dir: data_dir.into(),
+            documents: Arc::new(DocumentCache::new()),
+        }
+    }
+
+    /// Carga todos los documentos; devuelve cuántos quedaron en memoria.
+    pub fn load(&self) -> OcResult<usize> {
+        let pipeline =
+            ProjectPipeline::new(&self.data_dir).with_cache(Some(Arc::clone(&self.documents)));
+        pipeline.parse(&pipeline.scan()?);
+        Ok(self.documents.len())
+    }
+
+    /// Descarta los documentos cambiados; se releen en el próximo comando.
+    pub fn reload(&self) -> usize {
+        self.documents.refresh()
+    }
+
+    pub fn documents(&self) -> &Arc<DocumentCache> {
+        &self.documents
+    }
+
+    /// Ejecuta una línea. Devuelve `false` si la sesión debe terminar.
+    #[cfg(feature = "cli")]
+    pub fn execute(&self, line: &str) -> anyhow::Result<bool> {
+        let args = split_args(line);
+        if args.is_empty() {
+            return Ok(true);
+        }
+        let action = match ReplLine::try_parse_from(&args) {
+            Ok(parsed) => parsed.action,
+            Err(e) => {
+                print!("{}", e.render());
+                return Ok(true);
+            }
+        };
+
+        let reloaded = self.reload();
+        if reloaded > 0 {
+            println!("↻ {} archivos recargados", reloaded);
+        }
+
+        let cli = crate::CliConfig {
+            data_dir: self.data_dir.to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let documents = Some(Arc::clone(&self.documents));
+        match action {
+            ReplAction::Verify(mut cmd) => {
+                cmd.documents = documents;
+                let data_dir = cmd.path.clone().unwrap_or_else(|| self.data_dir.clone());
+                print_verification(&cmd.run(&data_dir)?);
+            }
+            ReplAction::Search(mut cmd) => {
+                cmd.documents = documents;
+                crate::commands::search::run(cmd, &cli)?;
+            }
+            ReplAction::Tree(mut cmd) => {
+                cmd.documents = documents;
+                crate::commands::tree::run(cmd, &cli)?;
+            }
+            ReplAction::Deps(mut cmd) => {
+                cmd.documents = documents;
+                crate::commands::deps::run(cmd, &cli)?;
+            }
+            ReplAction::Reload => println!("📚 {} documentos en memoria", self.load()?),
+            ReplAction::Exit => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// Resumen de verify sin terminar el proceso (a diferencia del CLI).
+#[cfg(feature = "cli")]
+fn print_verification(result: &crate::commands::verify::VerificationResult) {
+    for phase in result.phases.iter().filter(|p| !p.errors.is_empty() || !p.warnings.is_empty()) {
+        let status = if phase.passed { "⚠️" } else { "❌" };
+        println!("{} Fase {}: {}", status, phase.id, phase.name);
+        for error in &phase.errors {
+            println!("   ✗ {}", error);
+        }
+        for warning in &phase.warnings {
+            println!("   ⚠ {}", warning);
+        }
+    }
+    println!(
+        "📊 {}/{} fases pasaron, {} errores, {} warnings ({}ms)",
+        result.phases_passed(),
+        result.phases.len(),
+        result.total_errors,
+        result.total_warnings,
+        result.duration_ms
+    );
+}
+
+/// Separa una línea en argumentos respetando comillas simples y dobles.
+pub fn split_args(line: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut in_arg = false;
+
+    for c in line.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            None if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+    if in_arg {
+        args.push(current);
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_args() {
+        assert_eq!(split_args("search \"tasa de comisión\" -i"), vec!["search", "tasa de comisión", "-i"]);
+        assert_eq!(split_args("  tree  "), vec!["tree"]);
+        assert_eq!(split_args("search ''"), vec!["search", ""]);
+        assert!(split_args("").is_empty());
+    }
+
+    #[test]
+    fn test_session_reuses_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc = dir.path().join("1 raiz.md");
+        std::fs::write(&doc, "---\nid: \"1\"\ntitle: \"Raíz\"\nparent: \"0\"\n---\nTexto.\n").unwrap();
+
+        let session = ReplSession::new(dir.path());
+        assert_eq!(session.load().unwrap(), 1);
+        assert_eq!(session.reload(), 0);
+
+        assert!(session.execute("tree").unwrap());
+        assert!(session.execute("search Texto").unwrap());
+        assert!(session.execute("comando-inexistente").unwrap());
+        assert_eq!(session.documents().len(), 1);
+
+        let file = std::fs::File::options().write(true).open(&doc).unwrap();
+        file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(5)).unwrap();
+        assert_eq!(session.reload(), 1);
+
+        assert!(!session.execute("exit").unwrap());
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: ReplCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    use std::io::{BufRead, Write};
+
+    let data_dir = cmd.path.unwrap_or_else(|| PathBuf::from(&cli.data_dir));
+    let session = ReplSession::new(&data_dir);
+    println!(
+        "📚 {} documentos cargados de {}",
+        session.load()?,
+        data_dir.display()
+    );
+    println!("   Comandos: verify, search, tree, deps, reload, exit (help para ayuda)");
+
+    let stdin = std::io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("oc> ");
+        std::io::stdout().flush()?;
+        let Some(line) = lines.next() else {
+            println!();
+            break;
+        };
+        match session.execute(&line?) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => eprintln!("❌ {}", e),
+        }
+    }
+
+    Ok(())
+}
@@ -1,11 +1,15 @@
 //! Comando report - Generación de reportes.
 //!
-//! Genera reportes en múltiples formatos.
+//! Genera reportes en múltiples formatos. Con `--template` los datos
+//! (stats, salud, top issues) se renderizan con una plantilla Handlebars del
+//! usuario ([`crate::core::hbs`]).
 
-use crate::errors::OcResult;
+use crate::commands::health::{HealthCommand, HealthResult};
+use crate::commands::lint::{LintCommand, LintSeverity};
+use crate::errors::{OcError, OcResult};
 use clap::Parser;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // REPORT TYPES
@@ -68,6 +72,56 @@ impl Report {
     }
 }
 
+/// Documentos por módulo.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleCount {
+    pub id: String,
+    pub documents: usize,
+}
+
+/// Estadísticas del proyecto.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportStats {
+    pub documents: usize,
+    pub words: usize,
+    pub avg_words: usize,
+    pub links: usize,
+    pub modules: Vec<ModuleCount>,
+}
+
+/// Issues de lint de una regla.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueCount {
+    pub code: String,
+    pub category: String,
+    pub count: usize,
+    /// Primer mensaje, como ejemplo.
+    pub example: String,
+}
+
+/// Resumen de issues de lint.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueSummary {
+    pub total: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    /// Reglas con más issues, la mayor primero.
+    pub top: Vec<IssueCount>,
+}
+
+/// Datos que recibe la plantilla de `report --template`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportData {
+    pub project: String,
+    pub generated_at: String,
+    pub stats: ReportStats,
+    pub health: HealthResult,
+    pub issues: IssueSummary,
+}
+
+/// Reglas en `issues.top`.
+const TOP_ISSUES: usize = 10;
+
 // ═══════════════════════════════════════════════════════════════════════════
 // REPORT COMMAND
 // ═══════════════════════════════════════════════════════════════════════════
@@ -91,18 +145,61 @@ pub struct ReportCommand {
     /// Tipo de reporte.
     #[arg(short, long, default_value = "full")]
     pub report_type: String,
+
+    /// Plantilla Handlebars (ej: informes/ejecutivo.hbs) con los datos de
+    /// project, generated_at, stats, health e issues.
+    #[arg(long, value_name = "HBS")]
+    pub template: Option<PathBuf>,
 }
 
 impl ReportCommand {
     pub fn run(&self, data_dir: &std::path::Path) -> OcResult<Report> {
-        
-        use std::collections::HashMap;
-        use std::fs;
-
         let mut report = Report::new("Reporte de Documentación OnlyCar");
+        let stats = Self::collect_stats(data_dir);
+
+        // Summary section
+        let summary = format!(
+            "| Métrica | Valor |\n|---------|-------|\n\
+             | Documentos | {} |\n\
+             | Palabras totales | {} |\n\
+             | Promedio palabras/doc | {} |\n\
+             | Enlaces internos | {} |\n\
+             | Módulos | {} |",
+            stats.documents,
+            stats.words,
+            stats.avg_words,
+            stats.links,
+            stats.modules.len()
+        );
+        report.add_section("Resumen Ejecutivo", &summary, 2);
 
-        // Collect files using WalkDir (RECURSIVE)
+        let modules_content = stats
+            .modules
+            .iter()
+            .map(|m| format!("- **Módulo {}**: {} documentos", m.id, m.documents))
+            .collect::<Vec<_>>()
+            .join("\n");
+        report.add_section("Distribución por Módulos", &modules_content, 2);
+
+        // Health section
+        let health = format!(
+            "- ✅ **Documentos con frontmatter válido**: {}%\n\
+             - 📊 **Cobertura de enlaces**: {} links en {} docs",
+            100, // All files have frontmatter by default
+            stats.links,
+            stats.documents
+        );
+        report.add_section("Salud del Proyecto", &health, 2);
+
+        Ok(report)
+    }
+
+    /// Documentos, palabras, links y módulos del data_dir (recursivo).
+    pub fn collect_stats(data_dir: &Path) -> ReportStats {
+        use crate::core::patterns::RE_WIKI_LINK_WITH_ALIAS;
+        use std::collections::HashMap;
         use walkdir::WalkDir;
+
         let files: Vec<PathBuf> = WalkDir::new(data_dir)
             .into_iter()
             .filter_map(|e| e.ok())
@@ -116,15 +213,13 @@ impl ReportCommand {
             .map(|e| e.path().to_path_buf())
             .collect();
 
-        // Calculate stats
-        use crate::core::patterns::RE_WIKI_LINK_WITH_ALIAS;
         let link_re = &*RE_WIKI_LINK_WITH_ALIAS;
-        let mut total_words = 0usize;
-        let mut total_links = 0usize;
+        let mut words = 0usize;
+        let mut links = 0usize;
         let mut modules: HashMap<String, usize> = HashMap::new();
 
         for path in &files {
-            if let Ok(content) = fs::read_to_string(path) {
+            if let Ok(content) = std::fs::read_to_string(path) {
                 // Words
                 let body = if content.starts_with("---") {
                     if let Some(end) = content[3..].find("---") {
@@ -135,10 +230,10 @@ impl ReportCommand {
                 } else {
                     &content
                 };
-                total_words += body.split_whitespace().count();
+                words += body.split_whitespace().count();
 
                 // Links
-                total_links += link_re.captures_iter(&content).count();
+                links += link_re.captures_iter(&content).count();
 
                 // Modules
                 if let Some(id) = Self::get_yaml_field(&content, "id") {
@@ -148,48 +243,68 @@ impl ReportCommand {
             }
         }
 
-        // Summary section
-        let summary = format!(
-            "| Métrica | Valor |\n|---------|-------|\n\
-             | Documentos | {} |\n\
-             | Palabras totales | {} |\n\
-             | Promedio palabras/doc | {} |\n\
-             | Enlaces internos | {} |\n\
-             | Módulos | {} |",
-            files.len(),
-            total_words,
-            if files.is_empty() {
-                0
-            } else {
-                total_words / files.len()
-            },
-            total_links,
-            modules.len()
-        );
-        report.add_section("Resumen Ejecutivo", &summary, 2);
-
-        // Modules section
-        let mut sorted_modules: Vec<_> = modules.iter().collect();
-        sorted_modules.sort_by_key(|(k, _)| k.parse::<u32>().unwrap_or(0));
-
-        let modules_content = sorted_modules
-            .iter()
-            .map(|(id, count)| format!("- **Módulo {}**: {} documentos", id, count))
-            .collect::<Vec<_>>()
-            .join("\n");
-        report.add_section("Distribución por Módulos", &modules_content, 2);
+        let mut modules: Vec<ModuleCount> = modules
+            .into_iter()
+            .map(|(id, documents)| ModuleCount { id, documents })
+            .collect();
+        modules.sort_by_key(|m| m.id.parse::<u32>().unwrap_or(0));
+
+        ReportStats {
+            documents: files.len(),
+            words,
+            avg_words: if files.is_empty() { 0 } else { words / files.len() },
+            links,
+            modules,
+        }
+    }
 
-        // Health section
-        let health = format!(
-            "- ✅ **Documentos con frontmatter válido**: {}%\n\
-             - 📊 **Cobertura de enlaces**: {} links en {} docs",
-            100, // All files have frontmatter by default
-            total_links,
-            files.len()
-        );
-        report.add_section("Salud del Proyecto", &health, 2);
+    /// Stats, salud y top issues de lint para la plantilla.
+    pub fn data(&self, data_dir: &Path) -> OcResult<ReportData> {
+        let health = HealthCommand {
+            path: None,
+            json: false,
+            verbose: false,
+        }
+        .run(data_dir)?;
+        let lint = LintCommand::parse_from(["lint"]).run(data_dir)?;
+
+        let mut by_code: Vec<IssueCount> = Vec::new();
+        for issue in &lint.issues {
+            match by_code.iter_mut().find(|c| c.code == issue.code) {
+                Some(count) => count.count += 1,
+                None => by_code.push(IssueCount {
+                    code: issue.code.clone(),
+                    category: issue.category().to_string(),
+                    count: 1,
+                    example: issue.message.clone(),
+                }),
+            }
+        }
+        by_code.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.code.cmp(&b.code)));
+        by_code.truncate(TOP_ISSUES);
+
+        Ok(ReportData {
+            project: project_name(data_dir),
+            generated_at: chrono::Local::now().format("%Y-%m-%d %H:%M").to_string(),
+            stats: Self::collect_stats(data_dir),
+            health,
+            issues: IssueSummary {
+                total: lint.issues.len(),
+                errors: lint.issues.iter().filter(|i| i.severity == LintSeverity::Error).count(),
+                warnings: lint.issues.iter().filter(|i| i.severity == LintSeverity::Warning).count(),
+                top: by_code,
+            },
+        })
+    }
 
-        Ok(report)
+    /// Renderiza los datos del proyecto con la plantilla `template`.
+    pub fn render_template(&self, data_dir: &Path, template: &Path) -> OcResult<String> {
+        let source = std::fs::read_to_string(template).map_err(|e| OcError::FileRead {
+            path: template.to_path_buf(),
+            source: e,
+        })?;
+        let data = serde_json::to_value(self.data(data_dir)?)?;
+        crate::core::hbs::render(&source, &data)
     }
 
     fn get_yaml_field(content: &str, field: &str) -> Option<String> {
@@ -212,6 +327,25 @@ impl ReportCommand {
     }
 }
 
+/// Nombre del proyecto: `variables.project` (o `project.name`) de la config,
+/// si no el directorio del proyecto.
+fn project_name(data_dir: &Path) -> String {
+    let config = crate::core::config::OcConfig::from_cwd().unwrap_or_default();
+    let configured = match config.variables.get("project") {
+        Some(serde_yaml::Value::String(name)) => Some(name.clone()),
+        Some(serde_yaml::Value::Mapping(map)) => map.get("name").and_then(|v| v.as_str()).map(String::from),
+        _ => None,
+    };
+    configured.unwrap_or_else(|| {
+        let dir = std::fs::canonicalize(data_dir).unwrap_or_else(|_| data_dir.to_path_buf());
+        dir.parent()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .unwrap_or("Documentación")
+            .to_string()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,6 +385,36 @@ mod tests {
         assert!(md.contains("## H2"));
         assert!(md.contains("### H3"));
     }
+
+    #[test]
+    fn test_render_template() {
+        let dir = tempfile::tempdir().unwrap();
+        let data_dir = dir.path().join("Datos");
+        std::fs::create_dir(&data_dir).unwrap();
+        std::fs::write(
+            data_dir.join("1. Módulo.md"),
+            "---\nid: \"1\"\ntitle: \"Módulo\"\n---\n\n# Módulo\n\nVer [[1.1 Hijo]]\n",
+        )
+        .unwrap();
+        std::fs::write(data_dir.join("1.1 Hijo.md"), "---\nid: \"1.1\"\n---\n\nTexto corto\n").unwrap();
+        let template = dir.path().join("ejecutivo.hbs");
+        std::fs::write(
+            &template,
+            "{{stats.documents}} docs, {{stats.links}} links{{#each stats.modules}} [M{{id}}: {{documents}}]{{/each}}\n\
+             Salud {{health.grade}}; issues {{issues.total}}{{#if issues.top}}, top {{issues.top.0.code}}{{/if}}",
+        )
+        .unwrap();
+
+        let cmd = ReportCommand::parse_from(["report"]);
+        let out = cmd.render_template(&data_dir, &template).unwrap();
+        assert!(out.starts_with("2 docs, 1 links [M1: 2]\nSalud "), "{}", out);
+        let data = cmd.data(&data_dir).unwrap();
+        assert!(data.issues.total > 0);
+        assert!(out.ends_with(&format!("issues {}, top {}", data.issues.total, data.issues.top[0].code)));
+
+        std::fs::write(&template, "{{#each stats.modules}}").unwrap();
+        assert!(cmd.render_template(&data_dir, &template).is_err());
+    }
 }
 
 /// Función run para CLI.
@@ -261,9 +425,10 @@ pub fn run(cmd: ReportCommand, cli: &crate::commands::CliConfig) -> anyhow::Resu
         .path
         .clone()
         .unwrap_or_else(|| std::path::PathBuf::from(&cli.data_dir));
-    let report = cmd.run(&data_dir)?;
-
-    let output = report.to_markdown();
+    let output = match &cmd.template {
+        Some(template) => cmd.render_template(&data_dir, template)?,
+        None => cmd.run(&data_dir)?.to_markdown(),
+    };
 
     if let Some(path) = &cmd.output {
         std::fs::write(path, &output)?;
@@ -0,0 +1,108 @@
+//! Comando rules - Catálogo de reglas.
+//!
+//! Lista fases de verify, reglas de lint y reglas custom con sus metadatos
+//! desde `core::registry::RuleRegistry`; `--format json` para tooling externo.
+
+use crate::core::registry::{RuleMeta, RuleRegistry};
+use crate::errors::OcResult;
+use clap::Parser;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// RULES COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Comando de catálogo de reglas.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "rules", about = "Catálogo de reglas de verify y lint")]
+pub struct RulesCommand {
+    /// Acción a ejecutar.
+    #[arg(default_value = "list", value_parser = ["list"])]
+    pub action: String,
+
+    /// Formato de salida: text o json.
+    #[arg(long, default_value = "text", value_parser = ["text", "json"])]
+    pub format: String,
+
+    /// Filtrar por tipo: phase, lint o custom.
+    #[arg(long, value_parser = ["phase", "lint", "custom"])]
+    pub kind: Option<String>,
+
+    /// Filtrar por categoría (ej: tablas).
+    #[arg(long)]
+    pub category: Option<String>,
+}
+
+impl RulesCommand {
+    pub fn run(&self) -> OcResult<Vec<RuleMeta>> {
+        Ok(self.filter(&RuleRegistry::builtin()))
+    }
+
+    /// Aplica los filtros `--kind` y `--category` sobre un registro.
+    pub fn filter(&self, registry: &RuleRegistry) -> Vec<RuleMeta> {
+        registry
+            .list()
+            .iter()
+            .filter(|r| self.kind.as_deref().map_or(true, |k| r.kind.as_str() == k))
+            .filter(|r| {
+                self.category
+                    .as_deref()
+                    .map_or(true, |c| r.category.eq_ignore_ascii_case(c))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules_list_filters() {
+        let all = RulesCommand::parse_from(["rules"]).run().unwrap();
+        let lint = RulesCommand::parse_from(["rules", "list", "--kind", "lint"]).run().unwrap();
+        assert!(all.len() > lint.len());
+        assert!(lint.iter().all(|r| r.code.starts_with('L')));
+
+        let tables = RulesCommand::parse_from(["rules", "--category", "Tablas"]).run().unwrap();
+        assert!(tables.iter().any(|r| r.code == "L011"));
+        assert!(tables.iter().any(|r| r.code == "P14"));
+    }
+
+    #[test]
+    fn test_rules_json_shape() {
+        let rules = RulesCommand::parse_from(["rules", "--kind", "phase"]).run().unwrap();
+        let json = serde_json::to_value(&rules).unwrap();
+        assert_eq!(json[0]["code"], "P01");
+        assert_eq!(json[0]["kind"], "phase");
+        assert!(json[0]["fixable"].is_boolean());
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: RulesCommand, _cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    let rules = cmd.run()?;
+
+    if cmd.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&rules)?);
+        return Ok(());
+    }
+
+    println!("📏 Reglas ({}):", rules.len());
+    println!();
+    for rule in &rules {
+        println!(
+            "  {:<5} {:<22} {:<7} {:<11} {:<8} {} {}",
+            rule.code,
+            rule.name,
+            rule.kind.as_str(),
+            rule.category,
+            rule.severity,
+            if rule.fixable { "🔧" } else { "  " },
+            rule.since
+        );
+    }
+
+    Ok(())
+}
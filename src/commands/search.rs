@@ -1,17 +1,38 @@
 //! Comando search - Búsqueda en documentación.
 //!
-//! Búsqueda por contenido y metadata YAML.
-
-use crate::errors::OcResult;
+//! Búsqueda por contenido y metadata YAML. Con `--replace`, reemplazo
+//! masivo (con `$1`/`${nombre}` si el patrón es regex) sobre el body, sin
+//! tocar frontmatter ni bloques de código salvo que se pidan. Con
+//! `--boolean`, consultas con AND/OR/NOT y proximidad sobre el índice
+//! full-text (ver [`crate::core::fulltext`]). Con `--group-by doc`, un
+//! resultado por documento ordenado por relevancia y paginado.
+
+use crate::core::config::OcConfig;
+use crate::core::embeddings::{DocHit, Embedder, EmbeddingIndex};
+use crate::core::files::read_file_content;
+use crate::core::fixes;
+use crate::core::fulltext::{words, FullTextIndex, Query};
+use crate::core::patch::PatchReview;
+use crate::core::pipeline::{DocumentCache, ProjectPipeline};
+use crate::errors::{OcError, OcResult};
+use crate::traits::{Fix, FixResult};
 use clap::Parser;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // SEARCH RESULT
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Saturación de la frecuencia de matches (BM25).
+const BM25_K1: f64 = 1.2;
+
+/// Peso de la normalización por largo del documento (BM25).
+const BM25_B: f64 = 0.75;
+
 /// Resultado de búsqueda.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SearchMatch {
     /// Ruta del archivo.
     pub file_path: PathBuf,
@@ -23,6 +44,8 @@ pub struct SearchMatch {
     pub match_start: usize,
     /// Longitud del match.
     pub match_length: usize,
+    /// Todos los matches de la línea (inicio, longitud), si hay varios.
+    pub highlights: Vec<(usize, usize)>,
     /// Contexto (líneas antes/después).
     pub context_before: Vec<String>,
     pub context_after: Vec<String>,
@@ -42,6 +65,7 @@ impl SearchMatch {
             line_content,
             match_start,
             match_length,
+            highlights: Vec::new(),
             context_before: Vec::new(),
             context_after: Vec::new(),
         }
@@ -49,11 +73,22 @@ impl SearchMatch {
 
     /// Línea con highlight.
     pub fn highlighted_line(&self) -> String {
-        let before = &self.line_content[..self.match_start];
-        let matched = &self.line_content[self.match_start..self.match_start + self.match_length];
-        let after = &self.line_content[self.match_start + self.match_length..];
+        if self.highlights.is_empty() {
+            let before = &self.line_content[..self.match_start];
+            let matched = &self.line_content[self.match_start..self.match_start + self.match_length];
+            let after = &self.line_content[self.match_start + self.match_length..];
+            return format!("{}\x1b[1;33m{}\x1b[0m{}", before, matched, after);
+        }
 
-        format!("{}\x1b[1;33m{}\x1b[0m{}", before, matched, after)
+        let mut out = String::new();
+        let mut last = 0;
+        for &(start, length) in &self.highlights {
+            out.push_str(&self.line_content[last..start]);
+            out.push_str(&format!("\x1b[1;33m{}\x1b[0m", &self.line_content[start..start + length]));
+            last = start + length;
+        }
+        out.push_str(&self.line_content[last..]);
+        out
     }
 }
 
@@ -86,6 +121,101 @@ impl SearchResults {
         paths.dedup();
         paths.len()
     }
+
+    /// Un resultado por documento, del más relevante al menos relevante. El
+    /// score es BM25 del número de matches, normalizado por el largo del
+    /// documento respecto al promedio de los documentos con matches.
+    pub fn by_document(&self) -> Vec<DocResult> {
+        let mut groups: Vec<(&PathBuf, Vec<&SearchMatch>)> = Vec::new();
+        for m in &self.matches {
+            match groups.iter_mut().find(|(path, _)| *path == &m.file_path) {
+                Some((_, list)) => list.push(m),
+                None => groups.push((&m.file_path, vec![m])),
+            }
+        }
+        let lengths: Vec<f64> = groups
+            .iter()
+            .map(|(path, _)| read_file_content(path).map_or(1, |content| words(&content).len().max(1)) as f64)
+            .collect();
+        let average = lengths.iter().sum::<f64>() / lengths.len().max(1) as f64;
+
+        let mut docs: Vec<DocResult> = groups
+            .into_iter()
+            .zip(lengths)
+            .map(|((path, list), length)| {
+                let count = |m: &SearchMatch| m.highlights.len().max(1);
+                let matches: usize = list.iter().map(|m| count(m)).sum();
+                let tf = matches as f64;
+                let norm = 1.0 - BM25_B + BM25_B * length / average;
+                // Ante empate, la primera línea
+                let best = list.iter().rev().max_by_key(|m| count(m)).map(|m| (*m).clone());
+                DocResult {
+                    file_path: path.clone(),
+                    score: tf * (BM25_K1 + 1.0) / (tf + BM25_K1 * norm),
+                    matches,
+                    best: best.expect("grupo con matches"),
+                }
+            })
+            .collect();
+        docs.sort_by(|a, b| b.score.total_cmp(&a.score));
+        docs
+    }
+}
+
+/// Documento con matches (`--group-by doc`).
+#[derive(Debug, Clone, Serialize)]
+pub struct DocResult {
+    pub file_path: PathBuf,
+    pub score: f64,
+    pub matches: usize,
+    /// Mejor snippet: la línea con más matches.
+    pub best: SearchMatch,
+}
+
+/// Página de resultados por documento.
+#[derive(Debug, Clone, Serialize)]
+pub struct DocPage {
+    pub query: String,
+    /// Documentos con matches en total.
+    pub total: usize,
+    /// Página actual (desde 1) y cantidad de páginas.
+    pub page: usize,
+    pub pages: usize,
+    pub docs: Vec<DocResult>,
+}
+
+impl DocPage {
+    /// Página `page` (desde 1) de `limit` documentos.
+    pub fn new(query: &str, docs: Vec<DocResult>, limit: usize, page: usize) -> Self {
+        let limit = limit.max(1);
+        let page = page.max(1);
+        Self {
+            query: query.to_string(),
+            total: docs.len(),
+            page,
+            pages: docs.len().div_ceil(limit),
+            docs: docs.into_iter().skip((page - 1) * limit).take(limit).collect(),
+        }
+    }
+}
+
+/// Reemplazos en un archivo.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileReplacement {
+    pub path: PathBuf,
+    pub replacements: usize,
+}
+
+/// Resultado de `search --replace`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReplaceResult {
+    pub files: Vec<FileReplacement>,
+    pub replacements: usize,
+    /// Módulos que alcanzaron `--max-per-module` (quedan matches sin reemplazar).
+    pub limited_modules: Vec<String>,
+    /// Archivos que no se pudieron escribir.
+    pub failed: Vec<(PathBuf, String)>,
+    pub dry_run: bool,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -143,14 +273,61 @@ pub struct SearchCommand {
     /// P2-D3: Usar búsqueda fuzzy/aproximada.
     #[arg(long)]
     pub fuzzy: bool,
+
+    /// Búsqueda semántica sobre el índice de embeddings (`index build --embeddings`).
+    #[arg(long)]
+    pub semantic: bool,
+
+    /// Consulta booleana sobre el índice full-text: AND, OR, NOT, paréntesis,
+    /// "frases" y proximidad "a b"~N.
+    #[arg(long, conflicts_with_all = ["regex", "fuzzy", "semantic", "replace"])]
+    pub boolean: bool,
+
+    /// Agrupar resultados: una línea por match o un documento por resultado.
+    #[arg(long, value_name = "MODE", default_value = "line", value_parser = ["line", "doc"])]
+    pub group_by: String,
+
+    /// Con --group-by doc: documentos por página.
+    #[arg(long, default_value = "10")]
+    pub limit: usize,
+
+    /// Con --group-by doc: página a mostrar (desde 1).
+    #[arg(long, default_value = "1")]
+    pub page: usize,
+
+    /// Reemplazar cada match por este texto (`$1`, `${nombre}` con --regex).
+    #[arg(long, value_name = "TEXT")]
+    pub replace: Option<String>,
+
+    /// Con --replace: mostrar cambios sin escribir.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Con --replace: mostrar el diff de cada archivo.
+    #[arg(long)]
+    pub diff: bool,
+
+    /// Con --replace: reemplazar también dentro de bloques de código.
+    #[arg(long)]
+    pub include_code: bool,
+
+    /// Con --replace: reemplazar también en el frontmatter.
+    #[arg(long)]
+    pub include_frontmatter: bool,
+
+    /// Con --replace: máximo de reemplazos por módulo.
+    #[arg(long, value_name = "N")]
+    pub max_per_module: Option<usize>,
+
+    /// Caché de documentos compartida (ver `core::pipeline`).
+    #[arg(skip)]
+    pub documents: Option<std::sync::Arc<DocumentCache>>,
 }
 
 
 impl SearchCommand {
     /// Ejecuta la búsqueda.
     pub fn run(&self, data_dir: &std::path::Path) -> OcResult<SearchResults> {
-        use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
-
         let mut results = SearchResults::new(&self.pattern);
 
         // Compilar regex si es necesario
@@ -165,13 +342,13 @@ impl SearchCommand {
         };
 
         // Escanear todos los archivos
-        let options = ScanOptions::new();
-        let files = get_all_md_files(data_dir, &options)?;
+        let pipeline = ProjectPipeline::new(data_dir).with_cache(self.documents.clone());
+        let files = pipeline.scan()?;
 
         results.files_searched = files.len();
 
         for file_path in files {
-            if let Ok(content) = read_file_content(&file_path) {
+            if let Ok(content) = pipeline.read(&file_path) {
                 results.total_lines_searched += content.lines().count();
 
                 let matches = self.search_in_content(&content, &file_path);
@@ -192,6 +369,171 @@ impl SearchCommand {
         Ok(results)
     }
 
+    /// Búsqueda booleana (`--boolean`): parte del índice guardado por `index
+    /// build --fulltext` y reindexa en memoria lo que cambió. Cada línea con
+    /// matches sale una vez, con todos resaltados y hasta `--context` líneas
+    /// alrededor; un documento que cumple solo por `NOT` sale por su primera
+    /// línea.
+    pub fn run_boolean(&self, data_dir: &std::path::Path) -> OcResult<SearchResults> {
+        let query = Query::parse(&self.pattern)?;
+        let (index, _) = FullTextIndex::build(data_dir, FullTextIndex::load(data_dir).as_ref())?;
+        let mut results = SearchResults::new(&self.pattern);
+        results.files_searched = index.files.len();
+        results.total_lines_searched = index.files.values().map(|doc| doc.line_starts.len()).sum();
+
+        for (rel, hits) in index.search(&query) {
+            let doc = &index.files[rel];
+            let file_path = data_dir.join(rel);
+            let content = read_file_content(&file_path)?;
+            let lines: Vec<&str> = content.lines().collect();
+            let mut by_line: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+            for pos in hits {
+                let (line, word) = doc.locate(pos);
+                by_line.entry(line).or_default().push(word);
+            }
+            if by_line.is_empty() && !lines.is_empty() {
+                by_line.insert(0, Vec::new());
+            }
+
+            let matched: Vec<usize> = by_line.keys().copied().collect();
+            for (i, (&line, hit_words)) in by_line.iter().enumerate() {
+                let text = lines.get(line).copied().unwrap_or_default();
+                let spans = words(text);
+                let highlights: Vec<(usize, usize)> = hit_words
+                    .iter()
+                    .filter_map(|&w| spans.get(w))
+                    .map(|&(start, end, _)| (start, end - start))
+                    .collect();
+                let (start, length) = highlights.first().copied().unwrap_or_default();
+                let mut m = SearchMatch::new(file_path.clone(), line + 1, text.to_string(), start, length);
+                m.highlights = highlights;
+                // El contexto no repite líneas de otros matches del archivo
+                let from = line.saturating_sub(self.context).max(if i > 0 { matched[i - 1] + 1 } else { 0 });
+                let to = (line + 1 + self.context).min(matched.get(i + 1).copied().unwrap_or(lines.len()));
+                m.context_before = lines[from..line].iter().map(|l| l.to_string()).collect();
+                m.context_after = lines[(line + 1).min(to)..to].iter().map(|l| l.to_string()).collect();
+                results.matches.push(m);
+
+                if self.max_results.is_some_and(|max| results.matches.len() >= max) {
+                    return Ok(results);
+                }
+            }
+        }
+        Ok(results)
+    }
+
+    /// Reemplazo masivo (`--replace`) sobre el body de los documentos.
+    pub fn run_replace(&self, data_dir: &std::path::Path) -> OcResult<ReplaceResult> {
+        let replacement = self.replace.as_deref().unwrap_or_default();
+        let pattern = if self.regex { self.pattern.clone() } else { regex::escape(&self.pattern) };
+        let pattern = if self.ignore_case { format!("(?i){}", pattern) } else { pattern };
+        let regex = regex::Regex::new(&pattern)
+            .map_err(|e| OcError::InvalidArgument(format!("Regex inválida '{}': {}", self.pattern, e)))?;
+
+        let pipeline = ProjectPipeline::new(data_dir).with_cache(self.documents.clone());
+        let files = pipeline.scan()?;
+        let review = PatchReview::new(self.diff, false, self.dry_run);
+        let mut result = ReplaceResult { dry_run: self.dry_run, ..Default::default() };
+        let mut per_module: BTreeMap<String, usize> = BTreeMap::new();
+
+        for doc in pipeline.parse(&files) {
+            let module = doc.effective_id().and_then(|id| id.split('.').next()).unwrap_or_default().to_string();
+            if self.module.is_some_and(|m| m.to_string() != module) {
+                continue;
+            }
+            let used = per_module.entry(module.clone()).or_default();
+            let budget = self.max_per_module.map_or(usize::MAX, |max| max - (*used).min(max));
+            let (after, count, found) = self.replace_in_content(&regex, replacement, &doc.content, budget);
+            *used += count;
+            if found > count && !result.limited_modules.contains(&module) {
+                result.limited_modules.push(module);
+            }
+            if count == 0 {
+                continue;
+            }
+
+            let name = doc.path.file_name().and_then(|n| n.to_str()).unwrap_or("?");
+            let description = format!("🔁 {}: {} reemplazos", name, count);
+            let fix = Fix::edit("SEARCH", description, &doc.path, doc.content.as_str(), after).with_changes(count);
+            match fixes::apply(&fix, &review)? {
+                FixResult::Applied { .. } => {
+                    if let Some(cache) = &self.documents {
+                        cache.invalidate(&doc.path);
+                    }
+                    result.replacements += count;
+                    result.files.push(FileReplacement { path: doc.path.clone(), replacements: count });
+                }
+                FixResult::Failed { reason } => result.failed.push((doc.path.clone(), reason)),
+                _ => {}
+            }
+        }
+        Ok(result)
+    }
+
+    /// Aplica hasta `budget` reemplazos línea a línea, saltando frontmatter y
+    /// bloques de código salvo `--include-frontmatter`/`--include-code`.
+    /// Devuelve el contenido, los reemplazos hechos y los matches totales.
+    fn replace_in_content(
+        &self,
+        regex: &regex::Regex,
+        replacement: &str,
+        content: &str,
+        budget: usize,
+    ) -> (String, usize, usize) {
+        let mut out = String::with_capacity(content.len());
+        let mut count = 0;
+        let mut total = 0;
+        let mut in_frontmatter = false;
+        let mut in_fence = false;
+        for (n, line) in content.split_inclusive('\n').enumerate() {
+            let text = line.trim_end_matches(['\n', '\r']);
+            let ending = &line[text.len()..];
+            let trimmed = text.trim_start();
+            let is_delimiter = text.trim_end() == "---" && (n == 0 || in_frontmatter);
+            let is_fence = !in_frontmatter && (trimmed.starts_with("```") || trimmed.starts_with("~~~"));
+
+            let excluded = if is_delimiter {
+                true
+            } else if in_frontmatter {
+                !self.include_frontmatter
+            } else {
+                (in_fence || is_fence) && !self.include_code
+            };
+            let found = if excluded { 0 } else { regex.find_iter(text).count() };
+            total += found;
+            let found = found.min(budget - count);
+            // replacen con 0 reemplazaría todo
+            if found == 0 {
+                out.push_str(line);
+            } else {
+                let replaced = if self.regex {
+                    regex.replacen(text, found, replacement)
+                } else {
+                    regex.replacen(text, found, regex::NoExpand(replacement))
+                };
+                out.push_str(&replaced);
+                out.push_str(ending);
+                count += found;
+            }
+
+            if is_delimiter {
+                in_frontmatter = !in_frontmatter;
+            } else if is_fence {
+                in_fence = !in_fence;
+            }
+        }
+        (out, count, total)
+    }
+
+    /// Documentos más similares a la consulta según el índice de embeddings.
+    pub fn run_semantic(&self) -> OcResult<Vec<DocHit>> {
+        let config = OcConfig::from_cwd().unwrap_or_default().embeddings;
+        let embedder = Embedder::new(config.clone());
+        let index = EmbeddingIndex::open(&config, &embedder)?;
+        let query = embedder.embed(std::slice::from_ref(&self.pattern))?;
+        Ok(index.search_docs(&query[0], self.max_results.unwrap_or(10)))
+    }
+
     /// Busca en un contenido.
     pub fn search_in_content(&self, content: &str, file_path: &PathBuf) -> Vec<SearchMatch> {
         let mut matches = Vec::new();
@@ -261,6 +603,18 @@ mod tests {
             field: None,
             format: "text".to_string(),
             fuzzy: false,
+            semantic: false,
+            boolean: false,
+            group_by: "line".to_string(),
+            limit: 10,
+            page: 1,
+            replace: None,
+            dry_run: false,
+            diff: false,
+            include_code: false,
+            include_frontmatter: false,
+            max_per_module: None,
+            documents: None,
         };
 
         let content = "line1\nhello world\nline3";
@@ -271,6 +625,78 @@ mod tests {
     }
 
 
+    #[test]
+    fn test_replace_with_groups_and_zones() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc = "---\nid: \"1.1\"\ntitle: Alta v1\n---\nVer v1 y v2.\n```\nv1\n```\nFin v3\n";
+        std::fs::write(dir.path().join("1.1 alta.md"), doc).unwrap();
+        std::fs::write(dir.path().join("1.2 baja.md"), "---\nid: \"1.2\"\n---\nv4\n").unwrap();
+        std::fs::write(dir.path().join("2.1 mora.md"), "---\nid: \"2.1\"\n---\nv5 v6\n").unwrap();
+
+        let args = ["search", r"v(\d)", "--regex", "--replace", "versión $1", "--max-per-module", "3"];
+        let result = SearchCommand::parse_from(args).run_replace(dir.path()).unwrap();
+        assert_eq!(result.replacements, 5);
+        assert_eq!(result.limited_modules, vec!["1"]);
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("1.1 alta.md")).unwrap(),
+            "---\nid: \"1.1\"\ntitle: Alta v1\n---\nVer versión 1 y versión 2.\n```\nv1\n```\nFin versión 3\n"
+        );
+        assert_eq!(std::fs::read_to_string(dir.path().join("1.2 baja.md")).unwrap(), "---\nid: \"1.2\"\n---\nv4\n");
+
+        // Literal, opt-in de zonas, solo un módulo y dry-run
+        let args = ["search", "v1", "--replace", "$x", "--include-code", "--include-frontmatter", "--module", "1", "--dry-run"];
+        let result = SearchCommand::parse_from(args).run_replace(dir.path()).unwrap();
+        assert_eq!((result.replacements, result.files.len()), (2, 1));
+        assert!(std::fs::read_to_string(dir.path().join("1.1 alta.md")).unwrap().contains("Alta v1"));
+        assert!(SearchCommand::parse_from(["search", "(", "-r", "--replace", "x"]).run_replace(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_boolean_search_with_context() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc = "# Pagos\nIntro\nPago con tarjeta o SPEI.\nNota\nAlta del operador.\nFin\n";
+        std::fs::write(dir.path().join("1 pagos.md"), doc).unwrap();
+        std::fs::write(dir.path().join("2 sandbox.md"), "Pago con tarjeta en sandbox\n").unwrap();
+
+        let args = ["search", "pago AND (tarjeta OR spei) NOT sandbox", "--boolean", "-C", "1"];
+        let results = SearchCommand::parse_from(args).run_boolean(dir.path()).unwrap();
+        let lines: Vec<usize> = results.matches.iter().map(|m| m.line_number).collect();
+        assert_eq!(lines, vec![3]);
+        let m = &results.matches[0];
+        assert_eq!(m.highlights, vec![(0, 4), (9, 7), (19, 4)]);
+        assert_eq!((m.context_before.clone(), m.context_after.clone()), (vec!["Intro".to_string()], vec!["Nota".to_string()]));
+        assert_eq!(m.highlighted_line().matches("\x1b[1;33m").count(), 3);
+
+        let near = SearchCommand::parse_from(["search", "\"operador alta\"~2", "--boolean", "-C", "0"]);
+        let results = near.run_boolean(dir.path()).unwrap();
+        assert_eq!((results.matches.len(), results.matches[0].line_number), (1, 5));
+        assert!(results.matches[0].context_before.is_empty());
+        assert!(SearchCommand::parse_from(["search", "pago OR", "--boolean"]).run_boolean(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_group_by_document() {
+        let dir = tempfile::tempdir().unwrap();
+        let filler = "relleno ".repeat(200);
+        std::fs::write(dir.path().join("1 largo.md"), format!("pago\n{}\npago y pago\n", filler)).unwrap();
+        std::fs::write(dir.path().join("2 corto.md"), "Pago\nOtro pago pendiente\npago\n").unwrap();
+        std::fs::write(dir.path().join("3 uno.md"), "un pago\n").unwrap();
+
+        let cmd = SearchCommand::parse_from(["search", "pago", "--boolean", "--group-by", "doc", "--limit", "2"]);
+        let docs = cmd.run_boolean(dir.path()).unwrap().by_document();
+        let ranked: Vec<(String, usize)> = docs
+            .iter()
+            .map(|d| (d.file_path.file_name().unwrap().to_string_lossy().into_owned(), d.matches))
+            .collect();
+        assert_eq!(ranked, vec![("2 corto.md".into(), 3), ("3 uno.md".into(), 1), ("1 largo.md".into(), 3)]);
+        assert_eq!(docs[2].best.line_number, 3);
+        assert!(docs.windows(2).all(|w| w[0].score >= w[1].score));
+
+        let page = DocPage::new("pago", docs, cmd.limit, 2);
+        assert_eq!((page.total, page.pages, page.docs.len()), (3, 2, 1));
+        assert!(DocPage::new("pago", Vec::new(), 0, 0).docs.is_empty());
+    }
+
     #[test]
     fn test_highlighted_line() {
         let m = SearchMatch::new(PathBuf::from("test.md"), 1, "hello world".to_string(), 0, 5);
@@ -286,7 +712,82 @@ pub fn run(cmd: SearchCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
     // F6: Corregir path handling
     let default_dir = std::path::PathBuf::from(&cli.data_dir);
     let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
-    let results = cmd.run(data_dir)?;
+
+    if cmd.semantic {
+        let hits = cmd.run_semantic()?;
+        if cmd.format == "json" {
+            println!("{}", serde_json::to_string_pretty(&hits)?);
+        } else if hits.is_empty() {
+            println!("🔍 Índice vacío");
+        } else {
+            println!("🧭 Más similares a '{}':\n", cmd.pattern);
+            for hit in &hits {
+                println!("  {:.3}  {} — {}", hit.score, hit.path, hit.title);
+            }
+        }
+        return Ok(());
+    }
+
+    if cmd.replace.is_some() {
+        let result = cmd.run_replace(data_dir)?;
+        if cmd.format == "json" {
+            println!("{}", serde_json::to_string_pretty(&result)?);
+            return Ok(());
+        }
+        let prefix = if result.dry_run { "🔍 [DRY-RUN] " } else { "" };
+        for file in &result.files {
+            println!("{}🔁 {}: {} reemplazos", prefix, file.path.display(), file.replacements);
+        }
+        for (path, reason) in &result.failed {
+            eprintln!("❌ {}: {}", path.display(), reason);
+        }
+        println!(
+            "\n{}{} reemplazos en {} archivos",
+            prefix,
+            result.replacements,
+            result.files.len()
+        );
+        if !result.limited_modules.is_empty() {
+            println!(
+                "⚠️  Límite por módulo alcanzado en: {} (quedan matches sin reemplazar)",
+                result.limited_modules.join(", ")
+            );
+        }
+        return Ok(());
+    }
+
+    let results = if cmd.boolean { cmd.run_boolean(data_dir)? } else { cmd.run(data_dir)? };
+
+    if cmd.group_by == "doc" {
+        let page = DocPage::new(&results.query, results.by_document(), cmd.limit, cmd.page);
+        if cmd.format == "json" {
+            println!("{}", serde_json::to_string_pretty(&page)?);
+            return Ok(());
+        }
+        if page.total == 0 {
+            println!("🔍 No se encontraron resultados para: {}", page.query);
+            return Ok(());
+        }
+        println!(
+            "🔍 {} documentos para '{}' (página {}/{})\n",
+            page.total, page.query, page.page, page.pages
+        );
+        let first = (page.page - 1) * cmd.limit.max(1);
+        for (i, doc) in page.docs.iter().enumerate() {
+            println!(
+                "{:>3}. {}  score {:.2} · {} matches",
+                first + i + 1,
+                doc.file_path.display(),
+                doc.score,
+                doc.matches
+            );
+            println!("     {}: {}", doc.best.line_number, doc.best.highlighted_line().trim());
+        }
+        if page.page < page.pages {
+            println!("\n… página siguiente: --page {}", page.page + 1);
+        }
+        return Ok(());
+    }
 
     if results.matches.is_empty() {
         println!("🔍 No se encontraron resultados para: {}", results.query);
@@ -300,12 +801,19 @@ pub fn run(cmd: SearchCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
         );
 
         for m in &results.matches {
+            let first = m.line_number - m.context_before.len();
+            for (i, line) in m.context_before.iter().enumerate() {
+                println!("{}-{}- {}", m.file_path.display(), first + i, line);
+            }
             println!(
                 "{}:{}: {}",
                 m.file_path.display(),
                 m.line_number,
                 m.highlighted_line()
             );
+            for (i, line) in m.context_after.iter().enumerate() {
+                println!("{}-{}- {}", m.file_path.display(), m.line_number + 1 + i, line);
+            }
         }
     }
 
@@ -2,10 +2,10 @@
 //!
 //! Crea y gestiona snapshots del estado del proyecto.
 
-use crate::errors::OcResult;
+use crate::errors::{OcError, OcResult};
 use clap::Parser;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // SNAPSHOT TYPES
@@ -33,6 +33,33 @@ impl SnapshotInfo {
     }
 }
 
+/// Directorio de snapshots del proyecto (`snapshots/` junto al data_dir,
+/// donde los busca `stats --compare`).
+pub fn snapshots_dir(data_dir: &Path) -> PathBuf {
+    data_dir.parent().unwrap_or(Path::new(".")).join("snapshots")
+}
+
+/// Copia los documentos de `data_dir` a `dest` conservando la estructura.
+pub fn write_snapshot(data_dir: &Path, dest: &Path, name: &str) -> OcResult<SnapshotInfo> {
+    use crate::core::files::{get_all_md_files, ScanOptions};
+
+    let mut info = SnapshotInfo::new(name, name);
+    for file in get_all_md_files(data_dir, &ScanOptions::new())? {
+        let relative = file.strip_prefix(data_dir).unwrap_or(&file);
+        let target = dest.join(relative);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let bytes = std::fs::copy(&file, &target).map_err(|e| OcError::FileWrite {
+            path: target.clone(),
+            source: e,
+        })?;
+        info.file_count += 1;
+        info.size_bytes += bytes as usize;
+    }
+    Ok(info)
+}
+
 /// Resultado de operación snapshot.
 #[derive(Debug, Clone, Serialize)]
 pub struct SnapshotResult {
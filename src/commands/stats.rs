@@ -2,9 +2,14 @@
 //!
 //! Muestra estadísticas completas del proyecto.
 
+use crate::core::loader::{stream_files, StreamOptions, StreamReport};
+use crate::core::pipeline::{DocumentCache, ProjectPipeline};
+use crate::core::project_cache::CacheStore;
 use crate::errors::OcResult;
+use crate::traits::Cacheable;
+use crate::types::ContentHash;
 use clap::Parser;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -28,6 +33,9 @@ pub struct ProjectStats {
     pub modules_count: usize,
     /// Profundidad máxima.
     pub max_depth: usize,
+    /// Lotes y memoria del modo streaming (`--batch-size`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub streaming: Option<StreamReport>,
 }
 
 impl ProjectStats {
@@ -40,6 +48,7 @@ impl ProjectStats {
             broken_links: 0,
             modules_count: 0,
             max_depth: 0,
+            streaming: None,
         }
     }
 
@@ -52,11 +61,7 @@ impl ProjectStats {
     }
 
     pub fn avg_words_per_doc(&self) -> usize {
-        if self.total_documents == 0 {
-            0
-        } else {
-            self.total_words / self.total_documents
-        }
+        self.total_words.checked_div(self.total_documents).unwrap_or(0)
     }
 }
 
@@ -67,15 +72,457 @@ impl Default for ProjectStats {
 }
 
 /// Estadísticas por módulo.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModuleStats {
     pub id: String,
     pub name: String,
     pub document_count: usize,
     pub word_count: usize,
+    pub link_count: usize,
     pub health_score: f64,
 }
 
+/// Resultado de `stats` sin modo streaming, tal como se guarda entre
+/// ejecuciones (ver `core::project_cache`). Plano porque
+/// [`ProjectStats::streaming`] omite campos al serializar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsSummary {
+    pub data_dir: PathBuf,
+    pub total_documents: usize,
+    pub healthy_documents: usize,
+    pub total_words: usize,
+    pub total_links: usize,
+    pub broken_links: usize,
+    pub max_depth: usize,
+    pub modules: Vec<ModuleStats>,
+}
+
+impl StatsSummary {
+    pub fn new(data_dir: &std::path::Path, (stats, modules): (ProjectStats, Vec<ModuleStats>)) -> Self {
+        Self {
+            data_dir: data_dir.to_path_buf(),
+            total_documents: stats.total_documents,
+            healthy_documents: stats.healthy_documents,
+            total_words: stats.total_words,
+            total_links: stats.total_links,
+            broken_links: stats.broken_links,
+            max_depth: stats.max_depth,
+            modules,
+        }
+    }
+
+    pub fn into_stats(self) -> (ProjectStats, Vec<ModuleStats>) {
+        let stats = ProjectStats {
+            total_documents: self.total_documents,
+            healthy_documents: self.healthy_documents,
+            total_words: self.total_words,
+            total_links: self.total_links,
+            broken_links: self.broken_links,
+            modules_count: self.modules.len(),
+            max_depth: self.max_depth,
+            streaming: None,
+        };
+        (stats, self.modules)
+    }
+}
+
+impl Cacheable for StatsSummary {
+    type Key = PathBuf;
+    const CACHE_NAME: &'static str = "stats";
+
+    fn cache_key(&self) -> PathBuf {
+        self.data_dir.clone()
+    }
+
+    fn content_hash(&self) -> ContentHash {
+        let mut listing = format!(
+            "{} {} {} {} {} {}",
+            self.total_documents, self.healthy_documents, self.total_words, self.total_links, self.broken_links, self.max_depth
+        );
+        for module in &self.modules {
+            listing.push_str(&format!(
+                "\n{} {} {} {}",
+                module.id, module.document_count, module.word_count, module.link_count
+            ));
+        }
+        ContentHash::compute(&listing)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// WORDCLOUD
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Término y su frecuencia.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TermFreq {
+    pub term: String,
+    pub count: usize,
+}
+
+/// Términos más frecuentes de un módulo.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleTerms {
+    pub id: String,
+    pub terms: Vec<TermFreq>,
+}
+
+/// Frecuencia de términos global y por módulo (para nubes de palabras).
+#[derive(Debug, Clone, Serialize)]
+pub struct WordCloud {
+    pub top: usize,
+    pub terms: Vec<TermFreq>,
+    pub modules: Vec<ModuleTerms>,
+}
+
+impl WordCloud {
+    /// Tabla top-N global.
+    pub fn render_table(&self) -> String {
+        let mut out = format!("☁️  Top {} términos\n", self.top);
+        let max = self.terms.first().map(|t| t.count).unwrap_or(1).max(1);
+        for (i, t) in self.terms.iter().enumerate() {
+            let bar = "█".repeat((t.count * 20).div_ceil(max));
+            out.push_str(&format!("  {:>3}. {:24} {:>6}  {}\n", i + 1, t.term, t.count, bar));
+        }
+        out
+    }
+}
+
+fn to_freqs(counts: &crate::core::terms::TermCounts, n: usize) -> Vec<TermFreq> {
+    crate::core::terms::top_terms(counts, n)
+        .into_iter()
+        .map(|(term, count)| TermFreq { term, count })
+        .collect()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// COMPARE (snapshots)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Delta de un módulo entre snapshot y estado actual.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleDelta {
+    pub id: String,
+    pub docs_before: usize,
+    pub docs_after: usize,
+    pub words_before: usize,
+    pub words_after: usize,
+    pub links_before: usize,
+    pub links_after: usize,
+    pub health_before: f64,
+    pub health_after: f64,
+}
+
+/// Comparación de estadísticas contra un snapshot anterior.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsComparison {
+    pub snapshot: PathBuf,
+    pub before: ProjectStats,
+    pub after: ProjectStats,
+    pub modules: Vec<ModuleDelta>,
+}
+
+impl StatsComparison {
+    /// Cruza los módulos de ambos estados (los que aparecen o desaparecen cuentan desde 0).
+    pub fn new(
+        snapshot: PathBuf,
+        (before, before_modules): (ProjectStats, Vec<ModuleStats>),
+        (after, after_modules): (ProjectStats, Vec<ModuleStats>),
+    ) -> Self {
+        use std::collections::BTreeMap;
+
+        let mut merged: BTreeMap<(u32, String), (Option<ModuleStats>, Option<ModuleStats>)> =
+            BTreeMap::new();
+        let key = |m: &ModuleStats| (m.id.parse::<u32>().unwrap_or(u32::MAX), m.id.clone());
+        for m in before_modules {
+            let k = key(&m);
+            merged.entry(k).or_default().0 = Some(m);
+        }
+        for m in after_modules {
+            let k = key(&m);
+            merged.entry(k).or_default().1 = Some(m);
+        }
+
+        let modules = merged
+            .into_iter()
+            .map(|((_, id), (b, a))| ModuleDelta {
+                id,
+                docs_before: b.as_ref().map_or(0, |m| m.document_count),
+                docs_after: a.as_ref().map_or(0, |m| m.document_count),
+                words_before: b.as_ref().map_or(0, |m| m.word_count),
+                words_after: a.as_ref().map_or(0, |m| m.word_count),
+                links_before: b.as_ref().map_or(0, |m| m.link_count),
+                links_after: a.as_ref().map_or(0, |m| m.link_count),
+                health_before: b.as_ref().map_or(0.0, |m| m.health_score),
+                health_after: a.as_ref().map_or(0.0, |m| m.health_score),
+            })
+            .collect();
+
+        Self {
+            snapshot,
+            before,
+            after,
+            modules,
+        }
+    }
+
+    /// Tabla de deltas (total + por módulo).
+    pub fn render_table(&self) -> String {
+        let mut out = format!("📈 Comparación con {}\n\n", self.snapshot.display());
+        out.push_str(&format!(
+            "  {:<10} {:>14} {:>16} {:>14} {:>16}\n",
+            "Módulo", "Docs", "Palabras", "Links", "Salud %"
+        ));
+        out.push_str(&format!(
+            "  {:<10} {:>14} {:>16} {:>14} {:>16}\n",
+            "TOTAL",
+            signed(self.after.total_documents, self.before.total_documents),
+            signed(self.after.total_words, self.before.total_words),
+            signed(self.after.total_links, self.before.total_links),
+            signed_pct(self.after.health_percent(), self.before.health_percent()),
+        ));
+        for m in &self.modules {
+            out.push_str(&format!(
+                "  {:<10} {:>14} {:>16} {:>14} {:>16}\n",
+                m.id,
+                signed(m.docs_after, m.docs_before),
+                signed(m.words_after, m.words_before),
+                signed(m.links_after, m.links_before),
+                signed_pct(m.health_after, m.health_before),
+            ));
+        }
+        out
+    }
+}
+
+/// "valor (+delta)".
+fn signed(after: usize, before: usize) -> String {
+    let delta = after as i64 - before as i64;
+    format!("{} ({:+})", after, delta)
+}
+
+fn signed_pct(after: f64, before: f64) -> String {
+    format!("{:.1} ({:+.1})", after, after - before)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// COMPLEXITY (grafo documental)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Documento con su grado en el grafo de enlaces.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DocDegree {
+    pub id: String,
+    pub count: usize,
+}
+
+/// Acoplamiento de un módulo con el resto.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleCoupling {
+    pub id: String,
+    pub documents: usize,
+    /// Enlaces entre documentos del mismo módulo.
+    pub internal_links: usize,
+    /// Enlaces salientes hacia otros módulos (Ce).
+    pub efferent: usize,
+    /// Enlaces entrantes desde otros módulos (Ca).
+    pub afferent: usize,
+    /// Ce / (Ca + Ce): 0 = estable (solo lo usan), 1 = solo depende de otros.
+    pub instability: f64,
+}
+
+/// Métricas estructurales del grafo documental (`stats --complexity`).
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphComplexity {
+    /// Fecha de cálculo, para series históricas.
+    pub generated: String,
+    pub documents: usize,
+    /// Enlaces distintos entre documentos (origen → destino).
+    pub links: usize,
+    pub avg_fan_in: f64,
+    pub avg_fan_out: f64,
+    pub max_fan_in: Option<DocDegree>,
+    pub max_fan_out: Option<DocDegree>,
+    pub avg_depth: f64,
+    pub max_depth: usize,
+    /// Documentos sin hijos.
+    pub leaves: usize,
+    /// Documentos con al menos un hijo.
+    pub containers: usize,
+    pub leaf_ratio: f64,
+    /// Fracción de enlaces que cruzan módulos (0 = módulos aislados).
+    pub coupling_index: f64,
+    /// Módulos sin enlaces cruzados en ningún sentido.
+    pub decoupled_modules: Vec<String>,
+    pub modules: Vec<ModuleCoupling>,
+}
+
+fn ratio(a: usize, b: usize) -> f64 {
+    if b == 0 {
+        0.0
+    } else {
+        a as f64 / b as f64
+    }
+}
+
+impl GraphComplexity {
+    /// Tabla de métricas y acoplamiento por módulo.
+    pub fn render_table(&self) -> String {
+        let degree = |d: &Option<DocDegree>| {
+            d.as_ref().map_or("-".to_string(), |d| format!("{} ({})", d.count, d.id))
+        };
+        let mut out = String::from("🕸️  Complejidad del grafo documental\n\n");
+        out.push_str(&format!("  Documentos:            {}\n", self.documents));
+        out.push_str(&format!("  Enlaces:               {}\n", self.links));
+        out.push_str(&format!(
+            "  Fan-in / fan-out:      {:.2} / {:.2} (máx {} / {})\n",
+            self.avg_fan_in,
+            self.avg_fan_out,
+            degree(&self.max_fan_in),
+            degree(&self.max_fan_out)
+        ));
+        out.push_str(&format!("  Profundidad media:     {:.2} (máx {})\n", self.avg_depth, self.max_depth));
+        out.push_str(&format!(
+            "  Hojas / contenedores:  {} / {} (ratio {:.2})\n",
+            self.leaves, self.containers, self.leaf_ratio
+        ));
+        out.push_str(&format!("  Índice de acoplamiento: {:.2}\n", self.coupling_index));
+        if !self.decoupled_modules.is_empty() {
+            out.push_str(&format!("  Módulos desacoplados:  {}\n", self.decoupled_modules.join(", ")));
+        }
+        out.push_str(&format!(
+            "\n  {:<10} {:>6} {:>10} {:>6} {:>6} {:>12}\n",
+            "Módulo", "Docs", "Internos", "Ce", "Ca", "Inestab."
+        ));
+        for m in &self.modules {
+            out.push_str(&format!(
+                "  {:<10} {:>6} {:>10} {:>6} {:>6} {:>12.2}\n",
+                m.id, m.documents, m.internal_links, m.efferent, m.afferent, m.instability
+            ));
+        }
+        out
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DISTRIBUCIÓN
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Rangos de palabras por documento.
+const WORD_BUCKETS: &[(usize, usize)] = &[(0, 99), (100, 249), (250, 499), (500, 999), (1000, 1999), (2000, 4999)];
+/// Rangos de hijos por padre.
+const CHILDREN_BUCKETS: &[(usize, usize)] = &[(1, 1), (2, 2), (3, 3), (4, 5), (6, 10), (11, 20)];
+/// Un valor es atípico si supera este múltiplo de la mediana.
+const OUTLIER_FACTOR: usize = 3;
+/// Ancho de las barras del histograma.
+const BAR_WIDTH: usize = 30;
+
+/// Barra de un histograma.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Bucket {
+    pub label: String,
+    pub count: usize,
+}
+
+/// Histograma con sus estadísticos y los valores atípicos.
+#[derive(Debug, Clone, Serialize)]
+pub struct Histogram {
+    pub title: String,
+    /// Cantidad de valores (documentos o padres).
+    pub samples: usize,
+    pub median: usize,
+    pub max: usize,
+    pub buckets: Vec<Bucket>,
+    /// Por encima de `OUTLIER_FACTOR` × la mediana, el mayor primero.
+    pub outliers: Vec<DocDegree>,
+}
+
+impl Histogram {
+    /// Agrupa `values` (id, valor) en rangos cerrados; lo que supera el
+    /// último va a un rango abierto `N+`.
+    fn ranged(title: &str, values: &[(String, usize)], ranges: &[(usize, usize)]) -> Self {
+        let mut buckets: Vec<Bucket> = ranges
+            .iter()
+            .map(|&(lo, hi)| Bucket {
+                label: if lo == hi { lo.to_string() } else { format!("{}-{}", lo, hi) },
+                count: values.iter().filter(|(_, v)| (lo..=hi).contains(v)).count(),
+            })
+            .collect();
+        let open = ranges.last().map_or(0, |&(_, hi)| hi + 1);
+        buckets.push(Bucket {
+            label: format!("{}+", open),
+            count: values.iter().filter(|(_, v)| *v >= open).count(),
+        });
+        Self::with_buckets(title, values, buckets)
+    }
+
+    fn with_buckets(title: &str, values: &[(String, usize)], buckets: Vec<Bucket>) -> Self {
+        let mut sorted: Vec<usize> = values.iter().map(|(_, v)| *v).collect();
+        sorted.sort_unstable();
+        let median = sorted.get(sorted.len() / 2).copied().unwrap_or(0);
+        let mut outliers: Vec<DocDegree> = values
+            .iter()
+            .filter(|(_, v)| median > 0 && *v > median * OUTLIER_FACTOR)
+            .map(|(id, v)| DocDegree { id: id.clone(), count: *v })
+            .collect();
+        outliers.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.id.cmp(&b.id)));
+        Self {
+            title: title.to_string(),
+            samples: values.len(),
+            median,
+            max: sorted.last().copied().unwrap_or(0),
+            buckets,
+            outliers,
+        }
+    }
+
+    /// Histograma ASCII con barras proporcionales a la barra más alta.
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "{} (n={}, mediana {}, máx {})\n",
+            self.title, self.samples, self.median, self.max
+        );
+        let top = self.buckets.iter().map(|b| b.count).max().unwrap_or(0) as f64;
+        let label_width = self.buckets.iter().map(|b| b.label.chars().count()).max().unwrap_or(0);
+        for bucket in &self.buckets {
+            out.push_str(&format!(
+                "  {:<width$}  {} {}\n",
+                bucket.label,
+                crate::ui::heatmap::render_bar(bucket.count as f64, top, BAR_WIDTH),
+                bucket.count,
+                width = label_width
+            ));
+        }
+        if !self.outliers.is_empty() {
+            let list: Vec<String> = self.outliers.iter().map(|o| format!("{} ({})", o.id, o.count)).collect();
+            out.push_str(&format!("  ⚠️  Más de {}× la mediana: {}\n", OUTLIER_FACTOR, list.join(", ")));
+        }
+        out
+    }
+}
+
+/// Distribución de tamaño y forma de la jerarquía (`stats --distribution`).
+#[derive(Debug, Clone, Serialize)]
+pub struct Distribution {
+    /// Palabras por documento (cuerpo, sin frontmatter ni código).
+    pub words: Histogram,
+    /// Hijos por documento padre.
+    pub children: Histogram,
+    /// Documentos por nivel de profundidad del ID.
+    pub depth: Histogram,
+}
+
+impl Distribution {
+    pub fn render_table(&self) -> String {
+        format!(
+            "📊 Distribución de la jerarquía\n\n{}\n{}\n{}",
+            self.words.render(),
+            self.children.render(),
+            self.depth.render()
+        )
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // STATS COMMAND
 // ═══════════════════════════════════════════════════════════════════════════
@@ -125,33 +572,100 @@ pub struct StatsCommand {
     /// P2-C3: Usar caché para estadísticas (sled).
     #[arg(long)]
     pub cache: bool,
+
+    /// Comparar contra un snapshot (directorio, o nombre en `snapshots/`).
+    #[arg(long, value_name = "SNAPSHOT")]
+    pub compare: Option<String>,
+
+    /// Términos más frecuentes (global y por módulo, sin stopwords).
+    #[arg(long)]
+    pub wordcloud: bool,
+
+    /// Cantidad de términos a mostrar con --wordcloud.
+    #[arg(long, default_value = "20")]
+    pub top: usize,
+
+    /// Métricas del grafo: fan-in/fan-out, profundidad, hojas y acoplamiento entre módulos.
+    #[arg(long)]
+    pub complexity: bool,
+
+    /// Modo streaming: leer de a N documentos sin retenerlos (archivos grandes vía mmap)
+    /// y reportar memoria pico. Para proyectos de miles de documentos.
+    #[arg(long, value_name = "N")]
+    pub batch_size: Option<usize>,
+
+    /// Resumen de la telemetría local de uso (`telemetry.enabled` en la config).
+    #[arg(long)]
+    pub usage: bool,
+
+    /// Histogramas de palabras por documento, hijos por padre y documentos
+    /// por nivel de profundidad.
+    #[arg(long)]
+    pub distribution: bool,
+
+    /// Contar también los directorios derivados (`derived_dirs` de la config).
+    #[arg(long)]
+    pub include_derived: bool,
+
+    /// Caché de documentos compartida (ver `core::pipeline`).
+    #[arg(skip)]
+    pub documents: Option<std::sync::Arc<DocumentCache>>,
 }
 
 impl StatsCommand {
+    /// Pipeline sobre la caché compartida del comando, sin los directorios
+    /// derivados salvo `--include-derived`.
+    fn pipeline(&self, data_dir: &std::path::Path) -> ProjectPipeline {
+        let derived = if self.include_derived {
+            Vec::new()
+        } else {
+            crate::core::config::OcConfig::from_cwd().unwrap_or_default().derived_dirs
+        };
+        ProjectPipeline::new(data_dir)
+            .with_options(crate::core::files::ScanOptions::new().with_skip_dirs(derived))
+            .with_cache(self.documents.clone())
+    }
+
     /// Ejecuta el comando.
     pub fn run(&self, data_dir: &std::path::Path) -> OcResult<(ProjectStats, Vec<ModuleStats>)> {
-        
-        use std::collections::HashMap;
-        use std::fs;
-
-        use crate::core::patterns::RE_WIKI_LINK_WITH_ALIAS;
-        let link_re = &*RE_WIKI_LINK_WITH_ALIAS;
+        self.run_with_store(data_dir, None)
+    }
 
-        // Collect all .md files using WalkDir (RECURSIVE)
-        use walkdir::WalkDir;
-        let files: Vec<PathBuf> = WalkDir::new(data_dir)
+    /// Ejecuta el comando reutilizando el resumen guardado en `store` si
+    /// ningún archivo cambió (no aplica en modo streaming).
+    pub fn run_with_store(
+        &self,
+        data_dir: &std::path::Path,
+        store: Option<&CacheStore>,
+    ) -> OcResult<(ProjectStats, Vec<ModuleStats>)> {
+        // Scan recursivo vía pipeline, sin archivos de test
+        let pipeline = self.pipeline(data_dir);
+        let files: Vec<PathBuf> = pipeline
+            .scan()?
             .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                let path = e.path();
-                if !path.is_file() { return false; }
-                if path.extension().map(|ext| ext != "md").unwrap_or(true) { return false; }
+            .filter(|path| {
                 let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
                 !name.starts_with("TRAP_") && !name.starts_with("AUTOTEST_") && !name.starts_with("TEST_")
             })
-            .map(|e| e.path().to_path_buf())
             .collect();
 
+        match store.filter(|_| self.batch_size.is_none()) {
+            Some(store) => {
+                let build = || Ok(StatsSummary::new(data_dir, self.summarize(&pipeline, &files)));
+                let summary: StatsSummary = store.get_or_build(&data_dir.to_path_buf(), &files, build)?;
+                Ok(summary.into_stats())
+            }
+            None => Ok(self.summarize(&pipeline, &files)),
+        }
+    }
+
+    /// Cuenta documentos, palabras, links y módulos de `files`.
+    fn summarize(&self, pipeline: &ProjectPipeline, files: &[PathBuf]) -> (ProjectStats, Vec<ModuleStats>) {
+        use std::collections::HashMap;
+
+        use crate::core::patterns::RE_WIKI_LINK_WITH_ALIAS;
+        let link_re = &*RE_WIKI_LINK_WITH_ALIAS;
+
         // Build file map for link checking
         let file_map: std::collections::HashSet<String> = files
             .iter()
@@ -167,99 +681,117 @@ impl StatsCommand {
         let mut broken_links = 0usize;
         let mut healthy_documents = 0usize;
         let mut max_depth = 0usize;
-        let mut module_map: HashMap<String, (usize, usize)> = HashMap::new(); // module_id -> (doc_count, word_count)
-
-        for path in &files {
-            if let Ok(content) = fs::read_to_string(path) {
-                // Count words (skip YAML frontmatter)
-                let body = if content.starts_with("---") {
-                    if let Some(end) = content[3..].find("---") {
-                        &content[3 + end + 3..]
-                    } else {
-                        &content
-                    }
+        let mut module_map: HashMap<String, (usize, usize, usize, usize)> = HashMap::new(); // module_id -> (doc_count, word_count, link_count, healthy)
+
+        let mut process = |content: &str| {
+            // Count words (skip YAML frontmatter)
+            let body = if content.starts_with("---") {
+                if let Some(end) = content[3..].find("---") {
+                    &content[3 + end + 3..]
                 } else {
-                    &content
-                };
-                let words = body.split_whitespace().count();
-                total_words += words;
+                    content
+                }
+            } else {
+                content
+            };
+            let words = body.split_whitespace().count();
+            total_words += words;
+
+            // Check if healthy (has YAML frontmatter)
+            let healthy = content.starts_with("---") && content[3..].contains("---");
+            if healthy {
+                healthy_documents += 1;
+            }
+            let links_before = total_links;
 
-                // Check if healthy (has YAML frontmatter)
-                if content.starts_with("---") && content[3..].contains("---") {
-                    healthy_documents += 1;
+            // Count links and check if broken
+            // FIX BUG 1: Ignorar code blocks (sincronizado con links.rs)
+            let mut in_code_block = false;
+            for line in content.lines() {
+                let trimmed = line.trim_start();
+                if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                    in_code_block = !in_code_block;
+                    continue;
                 }
+                if in_code_block {
+                    continue;
+                }
+                
+                for cap in link_re.captures_iter(line) {
+                    if let Some(m) = cap.get(1) {
+                        let link_raw = m.as_str().trim().trim_end_matches('\\');
+                        if !link_raw.is_empty() && !link_raw.starts_with("http") && !link_raw.starts_with('#') {
+                            total_links += 1;
 
-                // Count links and check if broken
-                // FIX BUG 1: Ignorar code blocks (sincronizado con links.rs)
-                let mut in_code_block = false;
-                for line in content.lines() {
-                    let trimmed = line.trim_start();
-                    if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
-                        in_code_block = !in_code_block;
-                        continue;
-                    }
-                    if in_code_block {
-                        continue;
-                    }
-                    
-                    for cap in link_re.captures_iter(line) {
-                        if let Some(m) = cap.get(1) {
-                            let link_raw = m.as_str().trim().trim_end_matches('\\');
-                            if !link_raw.is_empty() && !link_raw.starts_with("http") && !link_raw.starts_with('#') {
-                                total_links += 1;
-
-                                // FIX BUG 4: Normalizar escaped pipes
-                                let link_clean = link_raw.replace("\\|", "|");
-                                
-                                // FIX BUG 3: Extraer nombre sin alias
-                                let link_no_alias = link_clean.split('|').next().unwrap_or(&link_clean);
-                                
-                                // FIX BUG 2: Extraer nombre sin path
-                                let link_no_path = link_no_alias.split('/').next_back().unwrap_or(link_no_alias);
-                                
-                                // Quitar anchor
-                                let link_file = link_no_path.split('#').next().unwrap_or(link_no_path).trim();
-                                
-                                // FIX BUG 5: Usar fuzzy matching (sincronizado con links.rs)
-                                let link_lower = link_file.to_lowercase();
-                                let mut found = file_map.contains(&link_lower);
-                                
-                                if !found {
-                                    // Fuzzy: match parcial (archivo termina con target o comienza con target)
-                                    for file_name in &file_map {
-                                        if file_name.ends_with(&link_lower) 
-                                            || file_name.starts_with(&link_lower)
-                                            || file_name.contains(&link_lower) {
-                                            found = true;
-                                            break;
-                                        }
+                            // FIX BUG 4: Normalizar escaped pipes
+                            let link_clean = link_raw.replace("\\|", "|");
+                            
+                            // FIX BUG 3: Extraer nombre sin alias
+                            let link_no_alias = link_clean.split('|').next().unwrap_or(&link_clean);
+                            
+                            // FIX BUG 2: Extraer nombre sin path
+                            let link_no_path = link_no_alias.split('/').next_back().unwrap_or(link_no_alias);
+                            
+                            // Quitar anchor
+                            let link_file = link_no_path.split('#').next().unwrap_or(link_no_path).trim();
+                            
+                            // FIX BUG 5: Usar fuzzy matching (sincronizado con links.rs)
+                            let link_lower = link_file.to_lowercase();
+                            let mut found = file_map.contains(&link_lower);
+                            
+                            if !found {
+                                // Fuzzy: match parcial (archivo termina con target o comienza con target)
+                                for file_name in &file_map {
+                                    if file_name.ends_with(&link_lower) 
+                                        || file_name.starts_with(&link_lower)
+                                        || file_name.contains(&link_lower) {
+                                        found = true;
+                                        break;
                                     }
                                 }
+                            }
 
-                                if !found {
-                                    broken_links += 1;
-                                }
+                            if !found {
+                                broken_links += 1;
                             }
                         }
                     }
                 }
+            }
 
-                // Extract ID for depth and module stats
-                if let Some(id) = Self::get_yaml_field(&content, "id") {
-                    // Calculate depth from ID (e.g., "1.2.3" = depth 3)
-                    let depth = id.matches('.').count() + 1;
-                    if depth > max_depth {
-                        max_depth = depth;
-                    }
+            // Extract ID for depth and module stats
+            if let Some(id) = Self::get_yaml_field(&content, "id") {
+                // Calculate depth from ID (e.g., "1.2.3" = depth 3)
+                let depth = id.matches('.').count() + 1;
+                if depth > max_depth {
+                    max_depth = depth;
+                }
 
-                    // Extract module (first number in ID)
-                    let module_id = id.split('.').next().unwrap_or("0").to_string();
-                    let entry = module_map.entry(module_id).or_insert((0, 0));
-                    entry.0 += 1;
-                    entry.1 += words;
+                // Extract module (first number in ID)
+                let module_id = id.split('.').next().unwrap_or("0").to_string();
+                let entry = module_map.entry(module_id).or_insert((0, 0, 0, 0));
+                entry.0 += 1;
+                entry.1 += words;
+                entry.2 += total_links - links_before;
+                entry.3 += usize::from(healthy);
+            }
+        };
+
+        let streaming = match self.batch_size {
+            Some(batch_size) => Some(stream_files(files, &StreamOptions::with_batch_size(batch_size), |batch| {
+                for (_, content) in batch {
+                    process(content);
+                }
+            })),
+            None => {
+                for path in files {
+                    if let Ok(content) = pipeline.read(path) {
+                        process(&content);
+                    }
                 }
+                None
             }
-        }
+        };
 
         let project_stats = ProjectStats {
             total_documents: files.len(),
@@ -269,31 +801,277 @@ impl StatsCommand {
             broken_links,
             modules_count: module_map.len(),
             max_depth,
+            streaming,
         };
 
         // Build module stats
         let mut module_stats: Vec<ModuleStats> = module_map
             .iter()
-            .map(|(id, (doc_count, word_count))| {
-                let health = if *doc_count > 0 { 100.0 } else { 0.0 };
+            .map(|(id, (doc_count, word_count, link_count, healthy))| {
+                let health = if *doc_count > 0 {
+                    (*healthy as f64 / *doc_count as f64) * 100.0
+                } else {
+                    0.0
+                };
                 ModuleStats {
                     id: id.clone(),
                     name: format!("Módulo {}", id),
                     document_count: *doc_count,
                     word_count: *word_count,
+                    link_count: *link_count,
                     health_score: health,
                 }
             })
             .collect();
 
-        // Sort by ID numerically
-        module_stats.sort_by(|a, b| {
-            a.id.parse::<u32>()
-                .unwrap_or(0)
-                .cmp(&b.id.parse::<u32>().unwrap_or(0))
-        });
+        // Sort by ID naturally
+        module_stats.sort_by(|a, b| crate::core::files::natural_cmp(&a.id, &b.id));
+
+        (project_stats, module_stats)
+    }
+
+    /// Resuelve el snapshot: ruta tal cual, `snapshots/<nombre>` junto a los
+    /// datos o `<data_dir>/<nombre>`.
+    pub fn resolve_snapshot(name: &str, data_dir: &std::path::Path) -> OcResult<PathBuf> {
+        [
+            PathBuf::from(name),
+            crate::commands::snapshot::snapshots_dir(data_dir).join(name),
+            data_dir.join(name),
+        ]
+        .into_iter()
+        .find(|p| p.is_dir())
+        .ok_or_else(|| crate::errors::OcError::DirectoryNotFound(PathBuf::from(name)))
+    }
+
+    /// Compara el estado actual con un snapshot.
+    pub fn compare(&self, snapshot: &str, data_dir: &std::path::Path) -> OcResult<StatsComparison> {
+        let snapshot_dir = Self::resolve_snapshot(snapshot, data_dir)?;
+        let before = self.run(&snapshot_dir)?;
+        let after = self.run(data_dir)?;
+        Ok(StatsComparison::new(snapshot_dir, before, after))
+    }
+
+    /// Frecuencia de términos por módulo (ID del frontmatter o del nombre).
+    pub fn wordcloud(&self, data_dir: &std::path::Path) -> OcResult<WordCloud> {
+        use crate::core::terms::{count_terms, TermCounts};
+        use crate::types::DataPath;
+        use std::collections::BTreeMap;
+
+        let mut global = TermCounts::new();
+        let mut by_module: BTreeMap<String, TermCounts> = BTreeMap::new();
+        let pipeline = self.pipeline(data_dir);
+        for path in pipeline.scan()? {
+            let Ok(content) = pipeline.read(&path) else {
+                continue;
+            };
+            let id = Self::get_yaml_field(&content, "id")
+                .or_else(|| DataPath::new(path.clone()).extract_id())
+                .unwrap_or_else(|| "0".to_string());
+            let module = id.split('.').next().unwrap_or("0").to_string();
+
+            let mut counts = TermCounts::new();
+            count_terms(&content, &mut counts);
+            let module_counts = by_module.entry(module).or_default();
+            for (term, n) in counts {
+                *module_counts.entry(term.clone()).or_default() += n;
+                *global.entry(term).or_default() += n;
+            }
+        }
+
+        Ok(WordCloud {
+            top: self.top,
+            terms: to_freqs(&global, self.top),
+            modules: by_module
+                .iter()
+                .map(|(id, counts)| ModuleTerms {
+                    id: id.clone(),
+                    terms: to_freqs(counts, self.top),
+                })
+                .collect(),
+        })
+    }
+
+    /// Métricas estructurales sobre la jerarquía (id/parent) y los enlaces
+    /// internos resueltos por nombre de archivo o ID.
+    pub fn complexity(&self, data_dir: &std::path::Path) -> OcResult<GraphComplexity> {
+        use crate::core::links::{extract_links, LinkType};
+        use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+        let pipeline = self.pipeline(data_dir);
+        let docs = pipeline.parse(&pipeline.scan()?);
+        let ids: Vec<String> = docs
+            .iter()
+            .map(|d| d.effective_id().unwrap_or(d.stem()).trim_end_matches('.').to_string())
+            .collect();
+        let module_of = |id: &str| id.split('.').next().unwrap_or("0").to_string();
+
+        let mut by_name: HashMap<String, usize> = HashMap::new();
+        for (i, doc) in docs.iter().enumerate() {
+            by_name.insert(doc.stem().to_lowercase(), i);
+            by_name.entry(ids[i].to_lowercase()).or_insert(i);
+        }
+
+        // Jerarquía: parent del frontmatter o prefijo del ID
+        let known: HashSet<&str> = ids.iter().map(String::as_str).collect();
+        let mut containers: HashSet<&str> = HashSet::new();
+        for (i, doc) in docs.iter().enumerate() {
+            let parent = doc
+                .parent
+                .as_deref()
+                .map(|p| p.trim_end_matches('.'))
+                .or_else(|| ids[i].rsplit_once('.').map(|(p, _)| p));
+            if let Some(parent) = parent.filter(|p| known.contains(p)) {
+                containers.insert(parent);
+            }
+        }
+        let depths: Vec<usize> = ids.iter().map(|id| id.split('.').filter(|s| !s.is_empty()).count()).collect();
+
+        // Enlaces distintos origen → destino (sin autoenlaces)
+        let mut edges: BTreeSet<(usize, usize)> = BTreeSet::new();
+        for (i, doc) in docs.iter().enumerate() {
+            for link in extract_links(&doc.content) {
+                if !matches!(
+                    link.link_type,
+                    LinkType::ObsidianInternal | LinkType::ObsidianEmbed | LinkType::Markdown
+                ) {
+                    continue;
+                }
+                let target = link.target.split(['#', '|']).next().unwrap_or_default();
+                let name = target.rsplit('/').next().unwrap_or(target).trim().trim_end_matches(".md");
+                if let Some(&j) = by_name.get(&name.to_lowercase()) {
+                    if j != i {
+                        edges.insert((i, j));
+                    }
+                }
+            }
+        }
+
+        let n = docs.len();
+        let (mut fan_in, mut fan_out) = (vec![0usize; n], vec![0usize; n]);
+        let mut modules: BTreeMap<String, ModuleCoupling> = BTreeMap::new();
+        for id in &ids {
+            modules
+                .entry(module_of(id))
+                .or_insert_with(|| ModuleCoupling {
+                    id: module_of(id),
+                    documents: 0,
+                    internal_links: 0,
+                    efferent: 0,
+                    afferent: 0,
+                    instability: 0.0,
+                })
+                .documents += 1;
+        }
+        let mut cross = 0;
+        for &(from, to) in &edges {
+            fan_out[from] += 1;
+            fan_in[to] += 1;
+            let (a, b) = (module_of(&ids[from]), module_of(&ids[to]));
+            if a == b {
+                modules.get_mut(&a).unwrap().internal_links += 1;
+            } else {
+                cross += 1;
+                modules.get_mut(&a).unwrap().efferent += 1;
+                modules.get_mut(&b).unwrap().afferent += 1;
+            }
+        }
+        for m in modules.values_mut() {
+            m.instability = ratio(m.efferent, m.efferent + m.afferent);
+        }
+
+        // Empates: el de menor ID
+        let max_degree = |degrees: &[usize]| {
+            degrees
+                .iter()
+                .enumerate()
+                .filter(|(_, &d)| d > 0)
+                .max_by_key(|(i, &d)| (d, std::cmp::Reverse(&ids[*i])))
+                .map(|(i, &d)| DocDegree { id: ids[i].clone(), count: d })
+        };
+        let mut modules: Vec<ModuleCoupling> = modules.into_values().collect();
+        modules.sort_by_key(|m| (m.id.parse::<u32>().unwrap_or(u32::MAX), m.id.clone()));
+        let leaves = n - containers.len();
+
+        Ok(GraphComplexity {
+            generated: chrono::Local::now().to_rfc3339(),
+            documents: n,
+            links: edges.len(),
+            avg_fan_in: ratio(edges.len(), n),
+            avg_fan_out: ratio(edges.len(), n),
+            max_fan_in: max_degree(&fan_in),
+            max_fan_out: max_degree(&fan_out),
+            avg_depth: ratio(depths.iter().sum(), n),
+            max_depth: depths.iter().copied().max().unwrap_or(0),
+            leaves,
+            containers: containers.len(),
+            leaf_ratio: ratio(leaves, containers.len()),
+            coupling_index: ratio(cross, edges.len()),
+            decoupled_modules: modules
+                .iter()
+                .filter(|m| m.efferent + m.afferent == 0)
+                .map(|m| m.id.clone())
+                .collect(),
+            modules,
+        })
+    }
+
+    /// Histogramas de tamaño, ramificación y profundidad.
+    pub fn distribution(&self, data_dir: &std::path::Path) -> OcResult<Distribution> {
+        use crate::core::yaml::count_words;
+        use crate::types::lifetimes::split_frontmatter;
+        use std::collections::{BTreeMap, HashSet};
+
+        let pipeline = self.pipeline(data_dir);
+        let docs = pipeline.parse(&pipeline.scan()?);
+        let ids: Vec<String> = docs
+            .iter()
+            .map(|d| d.effective_id().unwrap_or(d.stem()).trim_end_matches('.').to_string())
+            .collect();
+
+        let words: Vec<(String, usize)> = docs
+            .iter()
+            .zip(&ids)
+            .map(|(doc, id)| {
+                let body = split_frontmatter(&doc.content).map_or(doc.content.as_str(), |s| s.content);
+                (id.clone(), count_words(body))
+            })
+            .collect();
+
+        // Hijos: parent del frontmatter o prefijo del ID (como en --complexity)
+        let known: HashSet<&str> = ids.iter().map(String::as_str).collect();
+        let mut children: BTreeMap<&str, usize> = BTreeMap::new();
+        for (doc, id) in docs.iter().zip(&ids) {
+            let parent = doc
+                .parent
+                .as_deref()
+                .map(|p| p.trim_end_matches('.'))
+                .or_else(|| id.rsplit_once('.').map(|(p, _)| p));
+            if let Some(parent) = parent.filter(|p| known.contains(p) && p != id) {
+                *children.entry(parent).or_insert(0) += 1;
+            }
+        }
+        let children: Vec<(String, usize)> = children.into_iter().map(|(p, n)| (p.to_string(), n)).collect();
+
+        let depths: Vec<(String, usize)> = ids
+            .iter()
+            .map(|id| (id.clone(), id.split('.').filter(|s| !s.is_empty()).count()))
+            .collect();
+        let max_depth = depths.iter().map(|(_, d)| *d).max().unwrap_or(0);
+        let levels = (1..=max_depth)
+            .map(|level| Bucket {
+                label: format!("nivel {}", level),
+                count: depths.iter().filter(|(_, d)| *d == level).count(),
+            })
+            .collect();
+        let mut depth = Histogram::with_buckets("Documentos por nivel", &depths, levels);
+        // Un documento profundo no es atípico por sí solo
+        depth.outliers.clear();
 
-        Ok((project_stats, module_stats))
+        Ok(Distribution {
+            words: Histogram::ranged("Palabras por documento", &words, WORD_BUCKETS),
+            children: Histogram::ranged("Hijos por padre", &children, CHILDREN_BUCKETS),
+            depth,
+        })
     }
 
     /// Helper to extract YAML field
@@ -382,6 +1160,136 @@ mod tests {
 
         assert!(output.contains("PROJECT STATS"));
     }
+
+    #[test]
+    fn test_compare_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("Datos");
+        let snap = dir.path().join("snapshots").join("snapshot-2024-12");
+        std::fs::create_dir_all(&data).unwrap();
+        std::fs::create_dir_all(&snap).unwrap();
+
+        let doc = |id: &str, body: &str| format!("---\nid: \"{}\"\n---\n{}\n", id, body);
+        std::fs::write(snap.join("1.1.md"), doc("1.1", "uno dos")).unwrap();
+        std::fs::write(data.join("1.1.md"), doc("1.1", "uno dos tres [[1.2]]")).unwrap();
+        std::fs::write(data.join("1.2.md"), doc("1.2", "nuevo")).unwrap();
+        std::fs::write(data.join("2.1.md"), doc("2.1", "otro")).unwrap();
+
+        let cmd = StatsCommand::parse_from(["stats"]);
+        let cmp = cmd.compare("snapshot-2024-12", &data).unwrap();
+
+        assert_eq!(cmp.before.total_documents, 1);
+        assert_eq!(cmp.after.total_documents, 3);
+        assert_eq!(cmp.modules.len(), 2);
+        let m1 = &cmp.modules[0];
+        assert_eq!((m1.docs_before, m1.docs_after), (1, 2));
+        assert_eq!((m1.links_before, m1.links_after), (0, 1));
+        assert_eq!((cmp.modules[1].docs_before, cmp.modules[1].docs_after), (0, 1));
+        assert!(cmp.render_table().contains("2 (+1)"));
+
+        assert!(cmd.compare("no-existe", &data).is_err());
+    }
+
+    #[test]
+    fn test_wordcloud_by_module() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1.1 pagos.md"), "Pagos y pagos con tarjeta.").unwrap();
+        std::fs::write(
+            dir.path().join("2.1 envios.md"),
+            "---\nid: \"2.1\"\n---\nEnvíos para pagos.",
+        )
+        .unwrap();
+
+        let cmd = StatsCommand::parse_from(["stats", "--wordcloud", "--top", "2"]);
+        let cloud = cmd.wordcloud(dir.path()).unwrap();
+
+        assert_eq!(cloud.terms[0], TermFreq { term: "pagos".to_string(), count: 3 });
+        assert_eq!(cloud.terms.len(), 2);
+        let ids: Vec<&str> = cloud.modules.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2"]);
+        assert_eq!(cloud.modules[1].terms[0].term, "envíos");
+        assert!(cloud.render_table().contains("pagos"));
+    }
+
+    #[test]
+    fn test_run_streaming_matches_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, content) in [
+            ("1 a.md", "---\nid: \"1\"\n---\nuno dos [[2 b]]"),
+            ("2 b.md", "---\nid: \"2\"\n---\ntres [[9 roto]]"),
+            ("2.1 c.md", "---\nid: \"2.1\"\n---\ncuatro"),
+        ] {
+            std::fs::write(dir.path().join(name), content).unwrap();
+        }
+
+        let (cached, cached_modules) = StatsCommand::parse_from(["stats"]).run(dir.path()).unwrap();
+        let (streamed, streamed_modules) =
+            StatsCommand::parse_from(["stats", "--batch-size", "2"]).run(dir.path()).unwrap();
+        assert_eq!(
+            (streamed.total_words, streamed.total_links, streamed.broken_links, streamed.max_depth),
+            (cached.total_words, cached.total_links, cached.broken_links, cached.max_depth)
+        );
+        assert_eq!(streamed_modules.len(), cached_modules.len());
+        assert!(cached.streaming.is_none());
+        let report = streamed.streaming.unwrap();
+        assert_eq!((report.files, report.batches), (3, 2));
+    }
+
+    #[test]
+    fn test_complexity() {
+        let dir = tempfile::tempdir().unwrap();
+        let docs = [
+            ("1 pagos.md", "---\nid: \"1\"\n---\n[[1.1 tarjeta]] [[1.2 efectivo]] [[2 envios]]"),
+            ("1.1 tarjeta.md", "---\nid: \"1.1\"\nparent: \"1\"\n---\n[[1.2 efectivo#Montos]] [[1.1 tarjeta]]"),
+            ("1.2 efectivo.md", "---\nid: \"1.2\"\nparent: \"1\"\n---\n[[2]] [[https://x.com]]"),
+            ("2 envios.md", "---\nid: \"2\"\n---\nSin enlaces."),
+            ("3 legal.md", "---\nid: \"3\"\n---\n[[9 no existe]]"),
+        ];
+        for (name, content) in docs {
+            std::fs::write(dir.path().join(name), content).unwrap();
+        }
+
+        let cmd = StatsCommand::parse_from(["stats", "--complexity"]);
+        let c = cmd.complexity(dir.path()).unwrap();
+        assert_eq!((c.documents, c.links), (5, 5));
+        assert_eq!(c.max_fan_in, Some(DocDegree { id: "1.2".to_string(), count: 2 }));
+        assert_eq!(c.max_fan_out, Some(DocDegree { id: "1".to_string(), count: 3 }));
+        assert_eq!((c.leaves, c.containers), (4, 1));
+        assert!((c.avg_depth - 1.4).abs() < 1e-9);
+        assert!((c.coupling_index - 0.4).abs() < 1e-9);
+        assert_eq!(c.decoupled_modules, vec!["3".to_string()]);
+        assert_eq!(c.modules[0].instability, 1.0);
+        assert_eq!(c.modules[1].afferent, 2);
+        assert!(c.render_table().contains("Índice de acoplamiento: 0.40"));
+    }
+
+    #[test]
+    fn test_distribution() {
+        let dir = tempfile::tempdir().unwrap();
+        let words = |n: usize| vec!["palabra"; n].join(" ");
+        let mut docs = vec![("1 raiz.md".to_string(), format!("---\nid: \"1\"\n---\n{}", words(50)))];
+        for i in 1..=5 {
+            docs.push((format!("1.{} hijo.md", i), format!("---\nid: \"1.{}\"\n---\n{}", i, words(60))));
+        }
+        docs.push(("1.1.1 nieto.md".to_string(), format!("---\nid: \"1.1.1\"\n---\n{}", words(2500))));
+        for (name, content) in docs {
+            std::fs::write(dir.path().join(name), content).unwrap();
+        }
+
+        let d = StatsCommand::parse_from(["stats", "--distribution"]).distribution(dir.path()).unwrap();
+        assert_eq!((d.words.samples, d.words.median, d.words.max), (7, 60, 2500));
+        assert_eq!(d.words.buckets[0], Bucket { label: "0-99".to_string(), count: 6 });
+        assert_eq!(d.words.buckets[5].count, 1);
+        assert_eq!(d.words.outliers, vec![DocDegree { id: "1.1.1".to_string(), count: 2500 }]);
+        assert_eq!(d.children.samples, 2);
+        assert_eq!(d.children.buckets[3], Bucket { label: "4-5".to_string(), count: 1 });
+        let levels: Vec<usize> = d.depth.buckets.iter().map(|b| b.count).collect();
+        assert_eq!(levels, vec![1, 5, 1]);
+
+        let table = d.render_table();
+        assert!(table.contains("Hijos por padre (n=2, mediana 5, máx 5)"));
+        assert!(table.contains("Más de 3× la mediana: 1.1.1 (2500)"));
+    }
 }
 
 /// Función de ejecución para CLI.
@@ -394,7 +1302,69 @@ pub fn run(cmd: StatsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
         .path
         .clone()
         .unwrap_or_else(|| std::path::PathBuf::from(&cli.data_dir));
-    let (stats, module_stats) = cmd.run(&data_dir)?;
+
+    if cmd.usage {
+        let summary = crate::core::usage::summarize(&crate::core::usage::load(&crate::core::usage::usage_path())?);
+        if cmd.json {
+            println!("{}", serde_json::to_string_pretty(&summary)?);
+        } else if summary.events == 0 {
+            println!("📈 Sin registros de uso. Activar con `telemetry: {{ enabled: true }}` en .oc_diagdoc/config.yaml u OC_TELEMETRY=1");
+        } else {
+            print!("{}", summary.render_table());
+        }
+        return Ok(());
+    }
+
+    if let Some(ref snapshot) = cmd.compare {
+        let comparison = cmd.compare(snapshot, &data_dir)?;
+        if cmd.json {
+            println!("{}", serde_json::to_string_pretty(&comparison)?);
+        } else {
+            print!("{}", comparison.render_table());
+        }
+        return Ok(());
+    }
+
+    if cmd.distribution {
+        let distribution = cmd.distribution(&data_dir)?;
+        if cmd.json {
+            println!("{}", serde_json::to_string_pretty(&distribution)?);
+        } else {
+            print!("{}", distribution.render_table());
+        }
+        return Ok(());
+    }
+
+    if cmd.complexity {
+        let complexity = cmd.complexity(&data_dir)?;
+        if cmd.json {
+            println!("{}", serde_json::to_string_pretty(&complexity)?);
+        } else {
+            print!("{}", complexity.render_table());
+        }
+        return Ok(());
+    }
+
+    if cmd.wordcloud {
+        let cloud = cmd.wordcloud(&data_dir)?;
+        if cmd.json {
+            println!("{}", serde_json::to_string_pretty(&cloud)?);
+        } else {
+            print!("{}", cloud.render_table());
+            for module in &cloud.modules {
+                let terms: Vec<String> = module
+                    .terms
+                    .iter()
+                    .take(10)
+                    .map(|t| format!("{} ({})", t.term, t.count))
+                    .collect();
+                println!("  📦 Módulo {}: {}", module.id, terms.join(", "));
+            }
+        }
+        return Ok(());
+    }
+
+    let (stats, module_stats) = cmd.run_with_store(&data_dir, CacheStore::project().as_ref())?;
 
     if cmd.json {
         println!("{}", serde_json::to_string_pretty(&stats)?);
@@ -403,6 +1373,15 @@ pub fn run(cmd: StatsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
 
     // Render stats básicas
     println!("{}", StatsCommand::render_table(&stats));
+    if let Some(ref report) = stats.streaming {
+        let peak = report
+            .peak_memory_kb
+            .map_or("n/d".to_string(), |kb| format!("{:.1} MB", kb as f64 / 1024.0));
+        println!(
+            "🧠 Streaming: {} archivos en {} lotes de {} ({} vía mmap, {} omitidos), {} bytes leídos, memoria pico {}",
+            report.files, report.batches, report.batch_size, report.mapped_files, report.skipped, report.bytes_read, peak
+        );
+    }
 
     if cmd.by_module && !module_stats.is_empty() {
         println!("\n📦 Stats por módulo:");
@@ -422,9 +1401,8 @@ pub fn run(cmd: StatsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
         let mut recent_files: Vec<(std::path::PathBuf, std::time::SystemTime, usize)> = Vec::new();
         let mut total_bytes: u64 = 0;
 
-        // Use WalkDir for recursive scanning
-        use walkdir::WalkDir;
-        for entry in WalkDir::new(&data_dir).into_iter().filter_map(|e| e.ok()) {
+        // Recorrido recursivo en orden natural
+        for entry in crate::core::files::walk_sorted(&data_dir).into_iter().filter_map(|e| e.ok()) {
             let path = entry.path();
             if !path.is_file() { continue; }
             if path.extension().map(|e| e != "md").unwrap_or(true) { continue; }
@@ -471,7 +1449,7 @@ pub fn run(cmd: StatsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
         if cmd.by_status && !status_map.is_empty() {
             println!("\n📊 Por Status:");
             let mut sorted: Vec<_> = status_map.iter().collect();
-            sorted.sort_by(|a, b| b.1.cmp(a.1));
+            sorted.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
             for (status, count) in sorted {
                 println!("  {:15} {:>5} docs", status, count);
             }
@@ -481,7 +1459,7 @@ pub fn run(cmd: StatsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
         if cmd.by_type && !type_map.is_empty() {
             println!("\n📁 Por Tipo:");
             let mut sorted: Vec<_> = type_map.iter().collect();
-            sorted.sort_by(|a, b| b.1.cmp(a.1));
+            sorted.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
             for (doc_type, count) in sorted.iter().take(15) {
                 println!("  {:20} {:>5} docs", doc_type, count);
             }
@@ -492,7 +1470,7 @@ pub fn run(cmd: StatsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
 
         // Mostrar recientes
         if let Some(n) = cmd.recent {
-            recent_files.sort_by(|a, b| b.1.cmp(&a.1));
+            recent_files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| crate::core::files::natural_path_cmp(&a.0, &b.0)));
             println!("\n⏰ {} Archivos más recientes:", n.min(recent_files.len()));
             for (path, mtime, size) in recent_files.iter().take(n) {
                 let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("?");
@@ -533,7 +1511,7 @@ pub fn run(cmd: StatsCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
             use std::collections::BTreeMap;
             let mut month_activity: BTreeMap<String, usize> = BTreeMap::new();
             
-            for entry in WalkDir::new(&data_dir).into_iter().filter_map(|e| e.ok()) {
+            for entry in crate::core::files::walk_sorted(&data_dir).into_iter().filter_map(|e| e.ok()) {
                 let path = entry.path();
                 if !path.is_file() || path.extension().map(|e| e != "md").unwrap_or(true) {
                     continue;
@@ -2,7 +2,11 @@
 //!
 //! Sincroniza fechas, hashes y metadatos entre documentos.
 
-use crate::errors::OcResult;
+use crate::core::fixes::{self, FixSource};
+use crate::core::patch::PatchReview;
+use crate::errors::{OcError, OcResult};
+use crate::traits::{self, Fix, FixStage, Fixable};
+use crate::types::OcTimezone;
 use chrono::{DateTime, Utc};
 use clap::Parser;
 use std::path::PathBuf;
@@ -37,6 +41,8 @@ pub struct SyncResult {
     pub files_modified: usize,
     pub skipped_tolerance: usize,      // D3: Archivos sin cambios reales
     pub hashes_initialized: usize,      // D3: Hashes inicializados
+    /// Un fix por archivo con cambios (etapa de metadatos).
+    pub fixes: Vec<Fix>,
 }
 
 impl SyncResult {
@@ -47,6 +53,7 @@ impl SyncResult {
             files_modified: 0,
             skipped_tolerance: 0,
             hashes_initialized: 0,
+            fixes: Vec::new(),
         }
     }
 
@@ -113,6 +120,10 @@ pub struct SyncCommand {
     #[arg(long)]
     pub children: bool,
 
+    /// Sincronizar descendants_count (total recursivo de descendientes).
+    #[arg(long)]
+    pub descendants: bool,
+
     // F3: Nuevas flags de paridad con Python
     /// Propagar sincronización a documentos descendientes.
     #[arg(long)]
@@ -127,23 +138,86 @@ pub struct SyncCommand {
     pub tolerance: u64,
 
     // P1: Nuevas flags de paridad con Python v16
-    /// Ejecutar TODAS las sincronizaciones (dates + hashes + breadcrumbs + children).
+    /// Ejecutar TODAS las sincronizaciones (dates + hashes + breadcrumbs + children + descendants).
     #[arg(long)]
     pub fix_all: bool,
 
     /// Filtrar por módulo específico (ej: 1, 2, 3...).
     #[arg(long)]
     pub module: Option<u8>,
+
+    /// Completar `created`/`file_create` faltantes desde el primer commit de
+    /// git o el mtime más antiguo (archivo y copias en snapshots).
+    #[arg(long)]
+    pub init_created: bool,
+
+    /// Snapshot para --init-created (default: todos los de `snapshots/`).
+    #[arg(long, value_name = "NAME")]
+    pub snapshot: Option<String>,
+}
+
+/// Hash de sync: SHA-256 (16 hex) sin los campos volátiles.
+fn volatile_free_hash(content: &str) -> String {
+    use sha2::{Digest, Sha256};
+
+    let content_for_hash: String = content
+        .lines()
+        .filter(|l| {
+            !l.starts_with("last_updated:") &&
+            !l.starts_with("content_hash:") &&
+            !l.starts_with("file_create:")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut hasher = Sha256::new();
+    hasher.update(content_for_hash.as_bytes());
+    format!("{:x}", hasher.finalize())[..16].to_string()
+}
+
+/// Fecha de alta de un archivo según su primer commit en git (siguiendo
+/// renombres); `None` fuera de un repo o si nunca se commiteó.
+fn git_first_commit(path: &std::path::Path) -> Option<DateTime<Utc>> {
+    let dir = path.parent().filter(|d| !d.as_os_str().is_empty())?;
+    let output = std::process::Command::new("git")
+        .args(["log", "--follow", "--diff-filter=A", "--format=%aI", "--"])
+        .arg(path.file_name()?)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let oldest = stdout.lines().map(str::trim).filter(|l| !l.is_empty()).last()?;
+    DateTime::parse_from_rfc3339(oldest).ok().map(|d| d.with_timezone(&Utc))
 }
 
 
 impl SyncCommand {
     pub fn run(&self, data_dir: &std::path::Path) -> OcResult<SyncResult> {
+        let result = self.collect(data_dir)?;
+
+        // Escribir cambios si no es dry-run
+        if !self.dry_run {
+            for fix in &result.fixes {
+                if let traits::FixResult::Failed { reason } = fixes::apply(fix, &PatchReview::default())? {
+                    return Err(OcError::Custom(reason));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Calcula los cambios de sincronización sin escribir nada.
+    pub fn collect(&self, data_dir: &std::path::Path) -> OcResult<SyncResult> {
         use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
         
         use std::collections::HashMap;
 
         let mut result = SyncResult::new();
+        // last_updated se escribe en la zona horaria del proyecto
+        let tz = OcTimezone::from_cwd();
 
         let options = ScanOptions::new();
         let files = get_all_md_files(data_dir, &options)?;
@@ -175,33 +249,33 @@ impl SyncCommand {
             }
         }
 
+        // Grafo jerárquico para descendants_count
+        let hierarchy = (self.descendants || self.fix_all)
+            .then(|| crate::core::graph::hierarchy_from_files(&files));
+
+        let snapshots = if self.init_created { self.snapshot_dirs(data_dir)? } else { Vec::new() };
+
         for file_path in &files {
-            if let Ok(content) = read_file_content(file_path) {
+            if let Ok(original) = read_file_content(file_path) {
                 let file_id = file_path
                     .file_stem()
                     .and_then(|s| s.to_str())
                     .unwrap_or("unknown");
+                let changes_before = result.changes.len();
+                // Los campos de alta se agregan antes del resto: los pasos
+                // siguientes ven el documento ya completado
+                let content = if self.init_created {
+                    self.init_created_fields(file_path, &original, data_dir, &snapshots, &tz, &mut result)
+                } else {
+                    original.clone()
+                };
                 let mut modified_content = content.clone();
-                let mut file_has_changes = false;
+                let mut file_has_changes = content != original;
 
                 // D6: Hash-based date synchronization (reemplaza mtime)
                 if !self.hashes_only {
-                    use sha2::{Digest, Sha256};
-                    
                     // Calcular hash del contenido (excluyendo campos volátiles)
-                    let content_for_hash: String = content
-                        .lines()
-                        .filter(|l| {
-                            !l.starts_with("last_updated:") &&
-                            !l.starts_with("content_hash:") &&
-                            !l.starts_with("file_create:")
-                        })
-                        .collect::<Vec<_>>()
-                        .join("\n");
-                    
-                    let mut hasher = Sha256::new();
-                    hasher.update(content_for_hash.as_bytes());
-                    let current_hash = format!("{:x}", hasher.finalize())[..16].to_string();
+                    let current_hash = volatile_free_hash(&content);
                     
                     // Extraer hash almacenado
                     let stored_hash = hash_regex
@@ -233,7 +307,7 @@ impl SyncCommand {
                     }
                     // Caso 3: Hash difiere O force → actualizar fecha + hash
                     else if has_changed || self.force {
-                        let new_date = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+                        let new_date = tz.now().format("%Y-%m-%d %H:%M:%S").to_string();
                         
                         // Extraer fecha antigua
                         let old_date = date_regex
@@ -266,23 +340,9 @@ impl SyncCommand {
 
                 // L15.3: Regenerar hashes
                 if !self.dates_only {
-                    use sha2::{Digest, Sha256};
-                    
                     // RFC-06: Usar exactamente la misma lógica de hash que verify.rs
                     // Excluir campos volátiles (last_updated, content_hash, file_create)
-                    let content_for_hash: String = content
-                        .lines()
-                        .filter(|l| {
-                            !l.starts_with("last_updated:") &&
-                            !l.starts_with("content_hash:") &&
-                            !l.starts_with("file_create:")
-                        })
-                        .collect::<Vec<_>>()
-                        .join("\n");
-                    
-                    let mut hasher = Sha256::new();
-                    hasher.update(content_for_hash.as_bytes());
-                    let new_hash = format!("{:x}", hasher.finalize())[..16].to_string();
+                    let new_hash = volatile_free_hash(&content);
 
                     if let Some(cap) = hash_regex.captures(&content) {
                         let old_hash = cap[1].trim().to_string();
@@ -326,9 +386,43 @@ impl SyncCommand {
                     }
                 }
 
-                // Escribir cambios si no es dry-run
-                if file_has_changes && !self.dry_run {
-                    std::fs::write(file_path, &modified_content)?;
+                // Sincronizar descendants_count desde el grafo jerárquico
+                if let Some(graph) = &hierarchy {
+                    use crate::core::graph::apply_descendants_count;
+                    use crate::types::DocumentId;
+
+                    let doc_id = crate::types::lifetimes::split_frontmatter(&modified_content)
+                        .and_then(|s| crate::types::breadcrumb::frontmatter_value(s.metadata, "id"))
+                        .and_then(|id| id.trim_end_matches('.').parse::<DocumentId>().ok());
+                    if let Some(doc_id) = doc_id {
+                        let count = graph.descendants_count(&doc_id);
+                        if let Some((old, updated)) = apply_descendants_count(&modified_content, count) {
+                            result.add_change(SyncChange {
+                                path: file_path.clone(),
+                                field: "descendants_count".to_string(),
+                                old_value: old.map(|o| o.to_string()).unwrap_or_else(|| "N/A".to_string()),
+                                new_value: count.to_string(),
+                            });
+                            modified_content = updated;
+                            file_has_changes = true;
+                        }
+                    }
+                }
+
+                if file_has_changes {
+                    let fields: Vec<&str> = result.changes[changes_before..]
+                        .iter()
+                        .map(|c| c.field.as_str())
+                        .collect();
+                    let changes = fields.len().max(1);
+                    let fields = if fields.is_empty() { "content_hash".to_string() } else { fields.join(", ") };
+                    let name = file_path.file_name().and_then(|n| n.to_str()).unwrap_or("?");
+                    let description = format!("🔄 {}: {}", name, fields);
+                    result.fixes.push(
+                        Fix::edit("SYNC", description, file_path, original.as_str(), modified_content)
+                            .in_stage(FixStage::Metadata)
+                            .with_changes(changes),
+                    );
                 }
             }
         }
@@ -336,6 +430,93 @@ impl SyncCommand {
         Ok(result)
     }
 
+    /// Snapshots donde buscar copias más antiguas: `--snapshot` o todos los
+    /// directorios de `snapshots/` junto a los datos.
+    fn snapshot_dirs(&self, data_dir: &std::path::Path) -> OcResult<Vec<PathBuf>> {
+        use crate::commands::stats::StatsCommand;
+
+        if let Some(name) = &self.snapshot {
+            return Ok(vec![StatsCommand::resolve_snapshot(name, data_dir)?]);
+        }
+        let root = data_dir.parent().unwrap_or(std::path::Path::new(".")).join("snapshots");
+        let mut dirs: Vec<PathBuf> = std::fs::read_dir(&root)
+            .map(|entries| entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect())
+            .unwrap_or_default();
+        dirs.sort();
+        Ok(dirs)
+    }
+
+    /// Origen del documento: primer commit de git o, si no hay, el mtime más
+    /// antiguo entre el archivo y sus copias en los snapshots.
+    fn created_origin(
+        path: &std::path::Path,
+        data_dir: &std::path::Path,
+        snapshots: &[PathBuf],
+    ) -> Option<(DateTime<Utc>, &'static str)> {
+        if let Some(date) = git_first_commit(path) {
+            return Some((date, "git"));
+        }
+        let relative = path.strip_prefix(data_dir).unwrap_or(path);
+        std::iter::once(path.to_path_buf())
+            .chain(snapshots.iter().map(|s| s.join(relative)))
+            .filter_map(|p| std::fs::metadata(p).and_then(|m| m.modified()).ok())
+            .min()
+            .map(|t| (DateTime::<Utc>::from(t), "mtime"))
+    }
+
+    /// Agrega `created` y `file_create` si faltan. Si el content_hash estaba
+    /// al día se recalcula: completar el alta no es un cambio de contenido.
+    fn init_created_fields(
+        &self,
+        path: &std::path::Path,
+        content: &str,
+        data_dir: &std::path::Path,
+        snapshots: &[PathBuf],
+        tz: &OcTimezone,
+        result: &mut SyncResult,
+    ) -> String {
+        use crate::core::patterns::RE_CONTENT_HASH;
+        use crate::core::yaml::add_field;
+
+        let Some(split) = crate::types::lifetimes::split_frontmatter(content) else {
+            return content.to_string();
+        };
+        let missing: Vec<(&str, &str)> = [("created", "%Y-%m-%d"), ("file_create", "%Y-%m-%d %H:%M:%S")]
+            .into_iter()
+            .filter(|(field, _)| {
+                !split.metadata.lines().any(|l| l.strip_prefix(field).is_some_and(|r| r.starts_with(':')))
+            })
+            .collect();
+        if missing.is_empty() {
+            return content.to_string();
+        }
+        let Some((origin, source)) = Self::created_origin(path, data_dir, snapshots) else {
+            return content.to_string();
+        };
+
+        let mut updated = content.to_string();
+        for (field, format) in missing {
+            let value = tz.from_utc(&origin).format(format).to_string();
+            let Ok(with_field) = add_field(&updated, field, &format!("\"{}\"", value)) else {
+                return content.to_string();
+            };
+            updated = with_field;
+            result.add_change(SyncChange {
+                path: path.to_path_buf(),
+                field: field.to_string(),
+                old_value: "N/A".to_string(),
+                new_value: format!("{} ({})", value, source),
+            });
+        }
+
+        let stored = RE_CONTENT_HASH.captures(content).map(|c| c[1].trim().to_string());
+        if stored.as_deref() == Some(volatile_free_hash(content).as_str()) {
+            let field = format!("content_hash: \"{}\"", volatile_free_hash(&updated));
+            updated = RE_CONTENT_HASH.replace(&updated, field.as_str()).to_string();
+        }
+        updated
+    }
+
     /// Genera timestamp actual ISO8601.
     pub fn current_timestamp() -> String {
         let now: DateTime<Utc> = SystemTime::now().into();
@@ -343,9 +524,93 @@ impl SyncCommand {
     }
 }
 
+/// Fuente de fixes para `fix --all`: va última porque los hashes dependen
+/// del contenido final.
+impl Fixable for FixSource<SyncCommand> {
+    fn fix_stage(&self) -> FixStage {
+        FixStage::Metadata
+    }
+
+    fn suggest_fixes(&self) -> Vec<Fix> {
+        match self.command.collect(&self.data_dir) {
+            Ok(result) => result.fixes,
+            Err(e) => {
+                eprintln!("⚠ {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    fn apply_fix(&mut self, fix: &Fix) -> OcResult<traits::FixResult> {
+        fixes::apply(fix, &self.review)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TestProject;
+
+    #[test]
+    fn test_sync_descendants_count() {
+        let project = TestProject::default();
+        let dir = project.data_dir.as_path();
+        project.write("1 Raiz.md", "---\nid: \"1\"\nparent: \"0\"\ndescendants_count: 1\n---\n");
+        project.write("1.1 Hijo.md", "---\nid: \"1.1\"\nparent: \"1\"\n---\n");
+        project.write("1.1.1 Nieto.md", "---\nid: \"1.1.1\"\nparent: \"1.1\"\n---\n");
+
+        let cmd = SyncCommand::parse_from(["sync", "--descendants", "--hashes-only"]);
+        let result = cmd.run(dir).unwrap();
+        let fields: Vec<&str> = result
+            .changes
+            .iter()
+            .filter(|c| c.field == "descendants_count")
+            .map(|c| c.new_value.as_str())
+            .collect();
+        assert_eq!(fields.len(), 2);
+
+        assert!(project.read("1 Raiz.md").contains("descendants_count: 2"));
+        assert!(project.read("1.1 Hijo.md").contains("descendants_count: 1"));
+        assert!(!project.read("1.1.1 Nieto.md").contains("descendants_count"));
+    }
+
+    #[test]
+    fn test_init_created_from_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("Datos");
+        let snap = dir.path().join("snapshots/2020-01");
+        std::fs::create_dir_all(&data).unwrap();
+        std::fs::create_dir_all(&snap).unwrap();
+        let body = "---\nid: \"1\"\nlast_updated: \"2024-05-01 10:00\"\n---\nCuerpo\n";
+        let hash = volatile_free_hash(body);
+        let doc = body.replacen("---\n", &format!("---\ncontent_hash: \"{}\"\n", hash), 1);
+        std::fs::write(data.join("1 alta.md"), &doc).unwrap();
+        std::fs::write(data.join("2 baja.md"), "---\nid: \"2\"\ncreated: 2024-06-01\nfile_create: \"2024-06-01 09:00:00\"\nlast_updated: 2024-05-01\n---\n").unwrap();
+        let old = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_577_966_400);
+        std::fs::write(snap.join("1 alta.md"), &doc).unwrap();
+        std::fs::File::options().write(true).open(snap.join("1 alta.md")).unwrap().set_modified(old).unwrap();
+
+        let cmd = SyncCommand::parse_from(["sync", "--init-created"]);
+        let result = cmd.run(&data).unwrap();
+        let created: Vec<&str> = result.changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(created, vec!["created", "file_create"]);
+
+        let tz = OcTimezone::from_cwd();
+        let origin = tz.from_utc(&DateTime::<Utc>::from(old));
+        let updated = std::fs::read_to_string(data.join("1 alta.md")).unwrap();
+        assert!(updated.contains(&format!("created: \"{}\"\n", origin.format("%Y-%m-%d"))));
+        assert!(updated.contains(&format!("file_create: \"{}\"\n", origin.format("%Y-%m-%d %H:%M:%S"))));
+        // Completar el alta no cuenta como edición: la fecha no cambia
+        assert!(updated.contains("last_updated: \"2024-05-01 10:00\""));
+        assert!(updated.contains(&volatile_free_hash(&updated)));
+        assert!(!cmd.run(&data).unwrap().has_changes());
+
+        use crate::commands::verify::VerifyCommand;
+        let verify = VerifyCommand::parse_from(["verify", "--phase", "dates_sync"]);
+        let warnings = &verify.run(&data).unwrap().phases[0].warnings;
+        let created: Vec<&String> = warnings.iter().filter(|w| w.contains("created")).collect();
+        assert_eq!(created, vec!["2 baja.md: created '2024-06-01' posterior a last_updated '2024-05-01'"]);
+    }
 
     #[test]
     fn test_sync_result_new() {
@@ -2,9 +2,14 @@
 //!
 //! Muestra la estructura jerárquica de documentos.
 
+use crate::core::pipeline::{DocumentCache, ProjectPipeline};
+use crate::core::project_cache::CacheStore;
 use crate::errors::OcResult;
+use crate::traits::Cacheable;
+use crate::types::ContentHash;
 use clap::Parser;
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // TREE NODE
@@ -377,6 +382,10 @@ pub struct TreeCommand {
     /// Mostrar conteo de hijos junto a cada nodo.
     #[arg(long)]
     pub show_children: bool,
+
+    /// Caché de documentos compartida (ver `core::pipeline`).
+    #[arg(skip)]
+    pub documents: Option<std::sync::Arc<DocumentCache>>,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -413,35 +422,116 @@ fn depth_relative_to(id: &str, root: Option<&str>) -> usize {
 }
 
 
-impl TreeCommand {
-    /// Ejecuta el comando.
-    pub fn run(&self, data_dir: &std::path::Path) -> OcResult<TreeResult> {
-        use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
-        
-        use std::collections::HashMap;
+// ═══════════════════════════════════════════════════════════════════════════
+// ID INDEX
+// ═══════════════════════════════════════════════════════════════════════════
 
-        let mut result = TreeResult::new();
+/// Documento en el índice de IDs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdEntry {
+    pub id: String,
+    pub title: String,
+    pub parent_id: Option<String>,
+    pub word_count: usize,
+}
 
-        let options = ScanOptions::new();
-        let files = get_all_md_files(data_dir, &options)?;
+/// Índice de IDs de todos los documentos legibles, en orden de escaneo.
+/// `tree` lo guarda entre ejecuciones (ver `core::project_cache`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdIndex {
+    pub data_dir: PathBuf,
+    pub entries: Vec<IdEntry>,
+}
 
+impl IdIndex {
+    /// Extrae ID, título, parent_id y palabras de cada archivo.
+    pub fn build(data_dir: &Path, pipeline: &ProjectPipeline, files: &[PathBuf]) -> Self {
         // Regex para extraer parent_id y title del frontmatter
         use crate::core::patterns::{RE_PARENT_ID, RE_TITLE};
         let parent_regex = &*RE_PARENT_ID;
         let title_regex = &*RE_TITLE;
 
+        let entries = files
+            .iter()
+            .filter_map(|file_path| {
+                let id = file_path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+                let content = pipeline.read(file_path).ok()?;
+                let parent_id = parent_regex.captures(&content).map(|cap| cap[1].to_string());
+                // Extraer título o usar el ID
+                let title = title_regex
+                    .captures(&content)
+                    .map(|cap| cap[1].trim().to_string())
+                    .unwrap_or_else(|| id.clone());
+                Some(IdEntry {
+                    word_count: content.split_whitespace().count(),
+                    id,
+                    title,
+                    parent_id,
+                })
+            })
+            .collect();
+        Self {
+            data_dir: data_dir.to_path_buf(),
+            entries,
+        }
+    }
+}
+
+impl Cacheable for IdIndex {
+    type Key = PathBuf;
+    const CACHE_NAME: &'static str = "id_index";
+
+    fn cache_key(&self) -> PathBuf {
+        self.data_dir.clone()
+    }
+
+    fn content_hash(&self) -> ContentHash {
+        let listing: Vec<String> = self
+            .entries
+            .iter()
+            .map(|e| format!("{}\t{}\t{:?}\t{}", e.id, e.title, e.parent_id, e.word_count))
+            .collect();
+        ContentHash::compute(&listing.join("\n"))
+    }
+}
+
+impl TreeCommand {
+    /// Ejecuta el comando.
+    pub fn run(&self, data_dir: &Path) -> OcResult<TreeResult> {
+        self.run_with_store(data_dir, None)
+    }
+
+    /// Ejecuta el comando reutilizando el índice de IDs guardado en `store`
+    /// si ningún archivo cambió.
+    pub fn run_with_store(&self, data_dir: &Path, store: Option<&CacheStore>) -> OcResult<TreeResult> {
+        let pipeline = ProjectPipeline::new(data_dir).with_cache(self.documents.clone());
+        let files = pipeline.scan()?;
+        let build = || Ok(IdIndex::build(data_dir, &pipeline, &files));
+        let index = match store {
+            Some(store) => store.get_or_build(&data_dir.to_path_buf(), &files, build)?,
+            None => build()?,
+        };
+        Ok(self.run_with_index(&index))
+    }
+
+    /// Construye el árbol a partir del índice de IDs.
+    pub fn run_with_index(&self, index: &IdIndex) -> TreeResult {
+        use std::collections::HashMap;
+
+        let mut result = TreeResult::new();
+
         // Estructura: id -> (title, parent_id, word_count)
         let mut docs: HashMap<String, (String, Option<String>, usize)> = HashMap::new();
         // Estructura: parent_id -> [children_ids]
         let mut children_map: HashMap<String, Vec<String>> = HashMap::new();
 
-        // Fase 1: Parsear todos los documentos
-        for file_path in &files {
-            let file_id = file_path
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("unknown")
-                .to_string();
+        // Fase 1: Filtrar los documentos del índice
+        for entry in &index.entries {
+            let file_id = &entry.id;
 
             // Filtrar por módulo si se especificó
             if let Some(ref module_filter) = self.module {
@@ -462,36 +552,21 @@ impl TreeCommand {
                 // Matching flexible: prefijo exacto O prefijo normalizado O file_id == filter
                 let matches = file_id.starts_with(root_filter) 
                     || file_id.starts_with(&normalized_filter)
-                    || file_id == *root_filter;
+                    || file_id == root_filter;
                 
                 if !matches {
                     continue;
                 }
             }
 
-            if let Ok(content) = read_file_content(file_path) {
-                let word_count = content.split_whitespace().count();
-
-                // Extraer parent_id
-                let parent_id = parent_regex
-                    .captures(&content)
-                    .map(|cap| cap[1].to_string());
-
-                // Extraer título o usar el ID
-                let title = title_regex
-                    .captures(&content)
-                    .map(|cap| cap[1].trim().to_string())
-                    .unwrap_or_else(|| file_id.clone());
-
-                docs.insert(file_id.clone(), (title, parent_id.clone(), word_count));
+            docs.insert(file_id.clone(), (entry.title.clone(), entry.parent_id.clone(), entry.word_count));
 
-                // Registrar en children_map
-                if let Some(ref pid) = parent_id {
-                    children_map
-                        .entry(pid.clone())
-                        .or_default()
-                        .push(file_id.clone());
-                }
+            // Registrar en children_map
+            if let Some(ref pid) = entry.parent_id {
+                children_map
+                    .entry(pid.clone())
+                    .or_default()
+                    .push(file_id.clone());
             }
         }
 
@@ -504,7 +579,7 @@ impl TreeCommand {
             .map(|(id, _)| id.clone())
             .collect();
 
-        root_ids.sort();
+        root_ids.sort_by(|a, b| crate::core::files::natural_cmp(a, b));
 
         // Fase 3: Construir árbol recursivamente
         for (idx, root_id) in root_ids.iter().enumerate() {
@@ -526,7 +601,7 @@ impl TreeCommand {
         result.orphans_count = result.nodes.iter().filter(|n| n.is_orphan).count();
         result.total_words = result.nodes.iter().map(|n| n.word_count).sum();
 
-        Ok(result)
+        result
     }
 
     /// Construye el árbol recursivamente.
@@ -584,7 +659,7 @@ impl TreeCommand {
             // Continuar solo para procesar hijos (por si hay huérfanos anidados)
             if let Some(children) = children_map.get(id) {
                 let mut sorted_children: Vec<_> = children.clone();
-                sorted_children.sort();
+                sorted_children.sort_by(|a, b| crate::core::files::natural_cmp(a, b));
 
                 ancestors_are_last.push(is_last);
                 for (idx, child_id) in sorted_children.iter().enumerate() {
@@ -654,7 +729,7 @@ impl TreeCommand {
         // Procesar hijos
         if let Some(children) = children_map.get(id) {
             let mut sorted_children: Vec<_> = children.clone();
-            sorted_children.sort();
+            sorted_children.sort_by(|a, b| crate::core::files::natural_cmp(a, b));
 
             ancestors_are_last.push(is_last);
 
@@ -740,6 +815,28 @@ mod tests {
         assert_eq!(tree.total_nodes, 5);
         assert_eq!(tree.max_depth, 2);
     }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn test_tree_with_cached_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("Datos");
+        std::fs::create_dir(&data).unwrap();
+        std::fs::write(data.join("1.md"), "---\nid: \"1\"\ntitle: \"Uno\"\n---\n").unwrap();
+        std::fs::write(data.join("1.1.md"), "---\nid: \"1.1\"\ntitle: \"Hijo\"\nparent_id: \"1\"\n---\n").unwrap();
+        let store = CacheStore::new(dir.path().join("state"));
+        let cmd = TreeCommand::parse_from(["tree"]);
+
+        let fresh = cmd.run_with_store(&data, Some(&store)).unwrap();
+        let files = crate::core::files::get_all_md_files(&data, &Default::default()).unwrap();
+        let index: IdIndex = store.load(&data, &files).unwrap();
+        assert_eq!(index.entries.len(), 2);
+        assert_eq!(cmd.run_with_store(&data, Some(&store)).unwrap().render(), fresh.render());
+
+        // Un documento nuevo invalida el índice guardado
+        std::fs::write(data.join("1.2.md"), "---\nid: \"1.2\"\ntitle: \"Otro\"\nparent_id: \"1\"\n---\n").unwrap();
+        assert_eq!(cmd.run_with_store(&data, Some(&store)).unwrap().total_nodes, 3);
+    }
 }
 
 /// Función de ejecución para CLI.
@@ -748,7 +845,7 @@ pub fn run(cmd: TreeCommand, cli: &crate::CliConfig) -> anyhow::Result<()> {
     // F1.2: Priorizar cmd.path sobre cli.data_dir
     let default_dir = std::path::PathBuf::from(&cli.data_dir);
     let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
-    let result = cmd.run(data_dir)?;
+    let result = cmd.run_with_store(data_dir, CacheStore::project().as_ref())?;
 
     // P3 FIX: Seleccionar método de renderizado según --format
     let output = if result.nodes.is_empty() {
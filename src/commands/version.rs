@@ -0,0 +1,314 @@
+//! Comando version - Versionado semántico de documentos.
+//!
+//! `version bump 2.3.1 --minor --reason "nuevo flujo"` incrementa el campo
+//! `version:` del documento y registra el motivo en su sección "Historial".
+//! Los hijos que declaran `depends_version` se validan en la fase 4 de
+//! verify contra la versión del padre.
+
+use crate::core::files::{get_all_md_files, ScanOptions};
+use crate::core::release::{BumpType, VersionBumper};
+use crate::core::yaml::frontmatter_block;
+use crate::errors::{OcError, OcResult};
+use crate::types::breadcrumb::frontmatter_value;
+use crate::types::OcTimezone;
+use clap::Parser;
+use regex::Regex;
+use semver::Version;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Título de la sección donde se registran los bumps.
+pub const HISTORY_HEADING: &str = "Historial";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VERSION TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Resultado de un bump.
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionResult {
+    pub id: String,
+    pub file: PathBuf,
+    /// Versión anterior declarada (`None` si el documento no tenía `version:`).
+    pub previous: Option<String>,
+    pub version: String,
+    pub bump: String,
+    /// Línea agregada al Historial.
+    pub entry: String,
+    pub dry_run: bool,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VERSION COMMAND
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Comando de versionado de documentos.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "version", about = "Versionado semántico de documentos")]
+pub struct VersionCommand {
+    /// Acción: bump.
+    pub action: String,
+
+    /// ID del documento.
+    pub id: String,
+
+    /// Incrementar la versión mayor.
+    #[arg(long, conflicts_with_all = ["minor", "patch"])]
+    pub major: bool,
+
+    /// Incrementar la versión menor.
+    #[arg(long, conflicts_with = "patch")]
+    pub minor: bool,
+
+    /// Incrementar el patch (por defecto).
+    #[arg(long)]
+    pub patch: bool,
+
+    /// Motivo del cambio, registrado en el Historial.
+    #[arg(long)]
+    pub reason: Option<String>,
+
+    /// Ruta del proyecto.
+    #[arg(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// Mostrar el cambio sin escribir.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Output JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl VersionCommand {
+    fn bump_type(&self) -> BumpType {
+        if self.major {
+            BumpType::Major
+        } else if self.minor {
+            BumpType::Minor
+        } else {
+            BumpType::Patch
+        }
+    }
+
+    pub fn run(&self, data_dir: &Path) -> OcResult<VersionResult> {
+        if self.action != "bump" {
+            return Err(OcError::InvalidArgument(format!(
+                "Acción desconocida: '{}' (use bump)",
+                self.action
+            )));
+        }
+        let reason = self
+            .reason
+            .as_deref()
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .ok_or_else(|| {
+                OcError::InvalidArgument(format!(
+                    "Indique el motivo: version bump {} --reason \"...\"",
+                    self.id
+                ))
+            })?;
+
+        let (file, content) = find_document(data_dir, &self.id)?;
+        let previous = frontmatter_block(&content).and_then(|m| frontmatter_value(m, "version"));
+        // Sin `version:` el documento está implícitamente en 1.0.0
+        let current = match &previous {
+            Some(v) => VersionBumper::parse_lenient(v).ok_or_else(|| {
+                OcError::Validation {
+                    message: format!("{}: version '{}' no es semver", file.display(), v),
+                }
+            })?,
+            None => Version::new(1, 0, 0),
+        };
+
+        let bump = self.bump_type();
+        let bump_name = format!("{:?}", bump).to_lowercase();
+        let version = VersionBumper::bump(&current, bump);
+        let date = OcTimezone::from_cwd().now().format("%Y-%m-%d");
+        let entry = format!("- {} — v{} ({}): {}", date, version, bump_name, reason);
+
+        let updated = append_history(&set_version(&content, &version)?, &entry);
+        if !self.dry_run {
+            std::fs::write(&file, updated).map_err(|e| OcError::FileWrite {
+                path: file.clone(),
+                source: e,
+            })?;
+        }
+
+        Ok(VersionResult {
+            id: self.id.clone(),
+            file,
+            previous,
+            version: version.to_string(),
+            bump: bump_name,
+            entry,
+            dry_run: self.dry_run,
+        })
+    }
+}
+
+/// Busca el documento cuyo frontmatter declara `id: <id>`.
+pub(crate) fn find_document(data_dir: &Path, id: &str) -> OcResult<(PathBuf, String)> {
+    for path in get_all_md_files(data_dir, &ScanOptions::new())? {
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if frontmatter_block(&content).and_then(|m| frontmatter_value(m, "id")).as_deref() == Some(id) {
+            return Ok((path, content));
+        }
+    }
+    Err(OcError::InvalidId(format!("no existe documento con id '{}'", id)))
+}
+
+/// Reemplaza (o agrega) `version:` en el frontmatter sin tocar
+/// `depends_version` ni el resto de los campos.
+fn set_version(content: &str, version: &Version) -> OcResult<String> {
+    let value = format!("\"{}\"", version);
+    let mut out = String::with_capacity(content.len() + 20);
+    let mut in_yaml = false;
+    let mut replaced = false;
+    for (idx, line) in content.split_inclusive('\n').enumerate() {
+        let trimmed = line.trim_end_matches(['\n', '\r']);
+        if trimmed.trim() == "---" {
+            in_yaml = idx == 0;
+        } else if in_yaml && !replaced && trimmed.starts_with("version:") {
+            out.push_str(&format!("version: {}{}", value, &line[trimmed.len()..]));
+            replaced = true;
+            continue;
+        }
+        out.push_str(line);
+    }
+    if replaced {
+        Ok(out)
+    } else {
+        crate::core::yaml::add_field(content, "version", &value)
+    }
+}
+
+/// Agrega `entry` al final de la sección Historial (la crea al final del
+/// documento si no existe).
+fn append_history(content: &str, entry: &str) -> String {
+    let heading = Regex::new(r"^(#{1,6})\s+(.+?)\s*#*\s*$").expect("regex válida");
+    let lines: Vec<&str> = content.lines().collect();
+
+    let mut section: Option<(usize, usize)> = None; // (nivel, línea del título)
+    let mut end = lines.len();
+    let mut in_code = false;
+    for (idx, line) in lines.iter().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        let Some(cap) = heading.captures(line).filter(|_| !in_code) else {
+            continue;
+        };
+        let level = cap[1].len();
+        match section {
+            None if cap[2].eq_ignore_ascii_case(HISTORY_HEADING) => section = Some((level, idx)),
+            Some((open, _)) if level <= open => {
+                end = idx;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let Some((_, start)) = section else {
+        return format!("{}\n\n## {}\n\n{}\n", content.trim_end(), HISTORY_HEADING, entry);
+    };
+    // Tras la última línea no vacía de la sección
+    let insert_at = (start + 1..end)
+        .rev()
+        .find(|&i| !lines[i].trim().is_empty())
+        .map_or(start + 1, |i| i + 1);
+
+    let mut out: Vec<&str> = lines[..insert_at].to_vec();
+    if insert_at == start + 1 {
+        out.push("");
+    }
+    out.push(entry);
+    if insert_at < lines.len() {
+        if !lines[insert_at].trim().is_empty() {
+            out.push("");
+        }
+        out.extend_from_slice(&lines[insert_at..]);
+    }
+    format!("{}\n", out.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bump(dir: &Path, args: &[&str]) -> OcResult<VersionResult> {
+        let mut argv = vec!["version", "bump"];
+        argv.extend_from_slice(args);
+        VersionCommand::parse_from(argv).run(dir)
+    }
+
+    #[test]
+    fn test_bump_records_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("2.3.1 flujo.md");
+        std::fs::write(
+            &path,
+            "---\nid: \"2.3.1\"\ndepends_version: \"^1\"\nversion: \"2.3.1\"\n---\n# Flujo\n\n\
+             ## Historial\n\n- 2026-01-01 — v2.3.1 (patch): typo\n\n## Anexo\n\ntexto\n",
+        )
+        .unwrap();
+
+        let result = bump(dir.path(), &["2.3.1", "--minor", "--reason", "nuevo flujo"]).unwrap();
+        assert_eq!((result.previous.as_deref(), result.version.as_str()), (Some("2.3.1"), "2.4.0"));
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("depends_version: \"^1\"\nversion: \"2.4.0\"\n"));
+        assert!(content.contains(&format!(
+            "- 2026-01-01 — v2.3.1 (patch): typo\n{}\n\n## Anexo",
+            result.entry
+        )));
+        assert!(result.entry.ends_with("v2.4.0 (minor): nuevo flujo"));
+    }
+
+    #[test]
+    fn test_bump_without_version_or_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("1 a.md");
+        std::fs::write(&path, "---\nid: \"1\"\n---\n# A\n").unwrap();
+
+        let result = bump(dir.path(), &["1", "--major", "--reason", "rediseño"]).unwrap();
+        assert_eq!((result.previous, result.version.as_str()), (None, "2.0.0"));
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("---\nid: \"1\"\nversion: \"2.0.0\"\n---\n"));
+        assert!(content.ends_with(&format!("# A\n\n## Historial\n\n{}\n", result.entry)));
+
+        assert!(matches!(bump(dir.path(), &["1"]), Err(OcError::InvalidArgument(_))));
+        assert!(matches!(bump(dir.path(), &["9", "--reason", "x"]), Err(OcError::InvalidId(_))));
+    }
+}
+
+/// Función run para CLI.
+#[cfg(feature = "cli")]
+pub fn run(cmd: VersionCommand, cli: &crate::commands::CliConfig) -> anyhow::Result<()> {
+    let default_dir = PathBuf::from(&cli.data_dir);
+    let data_dir = cmd.path.as_ref().unwrap_or(&default_dir);
+    let result = cmd.run(data_dir)?;
+
+    if cmd.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    let prefix = if result.dry_run { "[DRY-RUN] " } else { "" };
+    println!(
+        "🏷️  {}{}: v{} → v{} ({})",
+        prefix,
+        result.id,
+        result.previous.as_deref().unwrap_or("1.0.0"),
+        result.version,
+        result.file.display()
+    );
+    println!("   {}", result.entry);
+
+    Ok(())
+}
@@ -1,11 +1,20 @@
 //! Comando watch - Observar cambios en tiempo real.
 //!
-//! Monitorea cambios en la documentación y ejecuta acciones.
-
+//! Monitorea cambios en la documentación y ejecuta acciones. Con la feature
+//! `watch` usa notify nativo; sin ella, polling de mtimes. Los eventos de una
+//! ráfaga se agrupan y se re-verifican los archivos tocados y sus backlinks.
+
+use crate::commands::verify::VerificationResult;
+use crate::core::files::{get_all_md_files, ScanOptions};
+use crate::core::links::extract_links;
+use crate::core::pipeline::{DocumentCache, LoadedDocument, ProjectPipeline};
+use crate::core::webhook::{Notifier, NotifySummary, WebhookFormat};
 use crate::errors::OcResult;
 use clap::Parser;
-use std::path::PathBuf;
-use std::time::Duration;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // WATCH TYPES
@@ -50,6 +59,221 @@ impl WatchConfig {
     pub fn debounce_duration(&self) -> Duration {
         Duration::from_millis(self.debounce_ms)
     }
+
+    /// ¿El archivo dispara eventos? Debe coincidir con `patterns` y no ser
+    /// temporal de editor ni estar bajo un directorio de `ignore_patterns`.
+    pub fn accepts(&self, path: &Path) -> bool {
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let matches = self.patterns.iter().any(|pattern| match pattern.strip_prefix('*') {
+            Some(suffix) => name.ends_with(suffix),
+            None => name == pattern,
+        });
+        let ignored = path.components().any(|c| {
+            let part = c.as_os_str().to_string_lossy();
+            self.ignore_patterns.iter().any(|i| *i == part)
+        });
+        matches && !ignored && !is_editor_temp(path)
+    }
+}
+
+/// Archivos temporales de editores: swap de vim (`.swp`, `4913`), backups
+/// con `~`, locks y autosaves de emacs (`.#x`, `#x#`).
+pub fn is_editor_temp(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    name.ends_with('~')
+        || name.starts_with(".#")
+        || (name.starts_with('#') && name.ends_with('#'))
+        || name == "4913"
+        || [".swp", ".swx", ".swo", ".tmp"].iter().any(|ext| name.ends_with(ext))
+}
+
+/// Agrupa los eventos de una ráfaga en uno por archivo, en orden de ruta.
+///
+/// Un archivo creado y luego modificado sigue siendo `Created`; creado y
+/// borrado desaparece; borrado y recreado (guardado atómico) es `Modified`.
+pub fn coalesce(events: Vec<WatchEvent>) -> Vec<WatchEvent> {
+    use WatchEventType::*;
+
+    let mut merged: BTreeMap<PathBuf, Option<WatchEvent>> = BTreeMap::new();
+    for event in events {
+        let slot = merged.entry(event.path.clone()).or_insert(None);
+        let event_type = match slot.as_ref().map(|e| &e.event_type) {
+            None => Some(event.event_type.clone()),
+            Some(Created) if event.event_type == Deleted => None,
+            Some(Created) => Some(Created),
+            Some(Deleted) if event.event_type != Deleted => Some(Modified),
+            Some(_) => Some(event.event_type.clone()),
+        };
+        *slot = event_type.map(|event_type| WatchEvent { event_type, ..event });
+    }
+    merged.into_values().flatten().collect()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHANGE SOURCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Origen de eventos: notify nativo (inotify/FSEvents/ReadDirectoryChanges)
+/// con la feature `watch`, o polling de mtimes como alternativa.
+enum ChangeSource {
+    #[cfg(feature = "watch")]
+    Native {
+        _watcher: notify::RecommendedWatcher,
+        rx: std::sync::mpsc::Receiver<notify::Result<notify::Event>>,
+        config: WatchConfig,
+        /// notify reporta rutas absolutas; se re-basan sobre la observada.
+        root: (PathBuf, PathBuf),
+    },
+    Polling(PollingSource),
+}
+
+impl ChangeSource {
+    fn new(path: &Path, config: WatchConfig, quiet: bool) -> OcResult<Self> {
+        #[cfg(feature = "watch")]
+        {
+            use notify::{RecursiveMode, Watcher};
+
+            let (tx, rx) = std::sync::mpsc::channel();
+            let native = notify::recommended_watcher(tx)
+                .and_then(|mut w| w.watch(path, RecursiveMode::Recursive).map(|_| w));
+            match native {
+                Ok(watcher) => {
+                    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+                    return Ok(Self::Native {
+                        _watcher: watcher,
+                        rx,
+                        config,
+                        root: (canonical, path.to_path_buf()),
+                    });
+                }
+                Err(e) if !quiet => eprintln!("⚠️  notify no disponible ({}), usando polling", e),
+                Err(_) => {}
+            }
+        }
+        #[cfg(not(feature = "watch"))]
+        let _ = quiet;
+
+        Ok(Self::Polling(PollingSource::new(path, config)?))
+    }
+
+    /// Espera hasta `debounce` por el primer evento y sigue acumulando hasta
+    /// que pase un `debounce` sin eventos nuevos. Puede devolver un lote vacío.
+    fn next_batch(&mut self) -> Vec<WatchEvent> {
+        match self {
+            #[cfg(feature = "watch")]
+            Self::Native { rx, config, root, .. } => {
+                let mut events = Vec::new();
+                while let Ok(received) = rx.recv_timeout(config.debounce_duration()) {
+                    if let Ok(event) = received {
+                        events.extend(native_events(&event, config).into_iter().map(|mut e| {
+                            if let Ok(rel) = e.path.strip_prefix(&root.0) {
+                                e.path = root.1.join(rel);
+                            }
+                            e
+                        }));
+                    }
+                }
+                coalesce(events)
+            }
+            Self::Polling(polling) => {
+                std::thread::sleep(polling.config.debounce_duration());
+                polling.poll()
+            }
+        }
+    }
+}
+
+/// Traduce un evento de notify, descartando accesos y archivos no aceptados.
+#[cfg(feature = "watch")]
+fn native_events(event: &notify::Event, config: &WatchConfig) -> Vec<WatchEvent> {
+    use notify::event::{EventKind, ModifyKind};
+
+    let timestamp = chrono::Utc::now().to_rfc3339();
+    event
+        .paths
+        .iter()
+        .filter(|path| config.accepts(path))
+        .filter_map(|path| {
+            let event_type = match event.kind {
+                EventKind::Create(_) => WatchEventType::Created,
+                EventKind::Remove(_) => WatchEventType::Deleted,
+                // Renombres: el destino existe, el origen no
+                EventKind::Modify(ModifyKind::Name(_)) if path.exists() => WatchEventType::Created,
+                EventKind::Modify(ModifyKind::Name(_)) => WatchEventType::Deleted,
+                EventKind::Modify(ModifyKind::Metadata(_)) => return None,
+                EventKind::Modify(_) => WatchEventType::Modified,
+                _ => return None,
+            };
+            Some(WatchEvent {
+                path: path.clone(),
+                event_type,
+                timestamp: timestamp.clone(),
+            })
+        })
+        .collect()
+}
+
+/// L23.1: Detección por comparación de mtimes entre pasadas.
+struct PollingSource {
+    root: PathBuf,
+    config: WatchConfig,
+    mtimes: HashMap<PathBuf, SystemTime>,
+}
+
+impl PollingSource {
+    fn new(root: &Path, config: WatchConfig) -> OcResult<Self> {
+        let mut source = Self {
+            root: root.to_path_buf(),
+            config,
+            mtimes: HashMap::new(),
+        };
+        source.mtimes = source.snapshot()?;
+        Ok(source)
+    }
+
+    fn snapshot(&self) -> OcResult<HashMap<PathBuf, SystemTime>> {
+        let files = get_all_md_files(&self.root, &ScanOptions::new())?;
+        Ok(files
+            .into_iter()
+            .filter(|f| self.config.accepts(f))
+            .filter_map(|f| {
+                let mtime = std::fs::metadata(&f).and_then(|m| m.modified()).ok()?;
+                Some((f, mtime))
+            })
+            .collect())
+    }
+
+    /// Cambios desde la pasada anterior.
+    fn poll(&mut self) -> Vec<WatchEvent> {
+        let Ok(current) = self.snapshot() else {
+            return Vec::new();
+        };
+        let timestamp = chrono::Utc::now().to_rfc3339();
+        let event = |path: &PathBuf, event_type| WatchEvent {
+            path: path.clone(),
+            event_type,
+            timestamp: timestamp.clone(),
+        };
+
+        let mut events: Vec<WatchEvent> = current
+            .iter()
+            .filter_map(|(path, mtime)| match self.mtimes.get(path) {
+                None => Some(event(path, WatchEventType::Created)),
+                Some(old) if old != mtime => Some(event(path, WatchEventType::Modified)),
+                Some(_) => None,
+            })
+            .collect();
+        events.extend(
+            self.mtimes
+                .keys()
+                .filter(|path| !current.contains_key(*path))
+                .map(|path| event(path, WatchEventType::Deleted)),
+        );
+        self.mtimes = current;
+        coalesce(events)
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -88,117 +312,121 @@ pub struct WatchCommand {
     /// Número máximo de iteraciones (0 = infinito).
     #[arg(long, default_value = "0")]
     pub max_iterations: usize,
+
+    /// URL de webhook (sobrescribe notify.webhook_url de config).
+    #[arg(long, value_name = "URL")]
+    pub webhook: Option<String>,
+
+    /// Formato del webhook: slack, teams o json.
+    #[arg(long, value_name = "FORMAT")]
+    pub webhook_format: Option<WebhookFormat>,
 }
 
 impl WatchCommand {
     pub fn run(&self, data_dir: &std::path::Path) -> OcResult<Vec<WatchEvent>> {
-        use crate::core::files::{get_all_md_files, ScanOptions};
-        use std::collections::HashMap;
-
         let mut events = Vec::new();
         let default_path = PathBuf::from(data_dir);
         let watch_path = self.path.as_ref().unwrap_or(&default_path);
+        let config = self.config();
 
         if !self.quiet {
             eprintln!("👁️  Observando: {}", watch_path.display());
         }
 
-        // L23.1: Polling para detectar cambios (simplificado sin notify crate)
-        let options = ScanOptions::new();
-        let mut file_mtimes: HashMap<PathBuf, std::time::SystemTime> = HashMap::new();
+        let mut source = ChangeSource::new(watch_path, config.clone(), self.quiet)?;
+
+        // Proyecto en memoria: cada lote solo relee los archivos tocados
+        let documents = Arc::new(DocumentCache::new());
+        let pipeline = ProjectPipeline::new(watch_path).with_cache(Some(Arc::clone(&documents)));
+        pipeline.parse(&pipeline.scan()?);
 
-        // Primera pasada: capturar estado inicial
-        if let Ok(files) = get_all_md_files(watch_path, &options) {
-            for file in files {
-                if let Ok(meta) = std::fs::metadata(&file) {
-                    if let Ok(mtime) = meta.modified() {
-                        file_mtimes.insert(file, mtime);
+        // Webhook: baseline de errores para notificar solo los nuevos
+        let notifier = self.notifier();
+        let mut known_errors: BTreeSet<String> = BTreeSet::new();
+        if notifier.is_some() {
+            if let Some(result) = self.run_verify(data_dir, &documents) {
+                known_errors = verify_errors(&result).collect();
+            }
+        }
+
+        let mut iteration = 0;
+        while self.max_iterations == 0 || iteration < self.max_iterations {
+            iteration += 1;
+            let batch = source.next_batch();
+
+            for event in &batch {
+                documents.invalidate(&event.path);
+                if !self.quiet {
+                    let icon = match event.event_type {
+                        WatchEventType::Created => "➕ Nuevo archivo",
+                        WatchEventType::Deleted => "🗑️  Eliminado",
+                        _ => "📝 Cambio detectado",
+                    };
+                    eprintln!("{}: {}", icon, event.path.display());
+                }
+
+                if event.event_type != WatchEventType::Deleted {
+                    // L24.1: Ejecutar hooks personalizados
+                    if let Some(ref hook_file) = self.hooks {
+                        self.run_hooks(hook_file, &event.path);
+                    }
+
+                    // L23.2: Ejecutar comando --exec
+                    if let Some(ref exec_cmd) = self.exec {
+                        self.run_exec_command(exec_cmd, &event.path);
                     }
                 }
             }
-        }
 
-        // Simular una iteración de verificación
-        let iterations = if self.max_iterations == 0 {
-            1
-        } else {
-            self.max_iterations
-        };
+            // L23.2: Re-verificar (una vez por lote) los tocados y sus dependientes
+            if !batch.is_empty() && (self.verify || notifier.is_some()) {
+                let touched: Vec<PathBuf> = batch.iter().map(|e| e.path.clone()).collect();
+                let docs = pipeline.parse(&pipeline.scan()?);
+                let affected = dependents(&docs, &touched);
 
-        for i in 0..iterations {
-            std::thread::sleep(self.config().debounce_duration());
-
-            // Detectar cambios
-            if let Ok(files) = get_all_md_files(watch_path, &options) {
-                for file in &files {
-                    if let Ok(meta) = std::fs::metadata(file) {
-                        if let Ok(mtime) = meta.modified() {
-                            match file_mtimes.get(file) {
-                                Some(old_mtime) if mtime != *old_mtime => {
-                                    // L23.2: Archivo modificado
-                                    let event = WatchEvent {
-                                        path: file.clone(),
-                                        event_type: WatchEventType::Modified,
-                                        timestamp: chrono::Utc::now().to_rfc3339(),
-                                    };
-
-                                    if !self.quiet {
-                                        eprintln!("📝 Cambio detectado: {}", file.display());
-                                    }
-
-                                    events.push(event);
-                                    file_mtimes.insert(file.clone(), mtime);
-
-                                    // L23.2: Ejecutar verify si solicitado
-                                    if self.verify {
-                                        self.run_verify(data_dir);
-                                    }
-
-                                    // L24.1: Ejecutar hooks personalizados
-                                    if let Some(ref hook_file) = self.hooks {
-                                        self.run_hooks(hook_file, file);
-                                    }
-
-                                    // L23.2: Ejecutar comando --exec
-                                    if let Some(ref exec_cmd) = self.exec {
-                                        self.run_exec_command(exec_cmd, file);
-                                    }
-                                }
-                                None => {
-                                    // Archivo nuevo
-                                    let event = WatchEvent {
-                                        path: file.clone(),
-                                        event_type: WatchEventType::Created,
-                                        timestamp: chrono::Utc::now().to_rfc3339(),
-                                    };
-
-                                    if !self.quiet {
-                                        eprintln!("➕ Nuevo archivo: {}", file.display());
-                                    }
-
-                                    events.push(event);
-                                    file_mtimes.insert(file.clone(), mtime);
-                                }
-                                _ => {}
-                            }
+                if let Some(result) = self.run_verify(data_dir, &documents) {
+                    if !self.quiet {
+                        for error in affected_errors(&result, &affected) {
+                            eprintln!("    ✗ {}", error);
                         }
                     }
+                    if let Some(ref notifier) = notifier {
+                        let changed = touched.iter().map(|f| relative(f, watch_path)).collect();
+                        let summary = watch_summary(&result, &known_errors, changed);
+                        match notifier.notify(&summary) {
+                            Ok(true) if !self.quiet => eprintln!("  📣 Webhook notificado"),
+                            Err(e) => eprintln!("  ⚠️  {}", e),
+                            _ => {}
+                        }
+                        known_errors = verify_errors(&result).collect();
+                    }
                 }
             }
+            events.extend(batch);
 
-            if i < iterations - 1 && !self.quiet {
-                eprintln!("⏳ Esperando cambios... ({}/{})", i + 1, iterations);
+            if iteration < self.max_iterations && !self.quiet {
+                eprintln!("⏳ Esperando cambios... ({}/{})", iteration, self.max_iterations);
             }
         }
 
         Ok(events)
     }
 
-    /// L23.2: Ejecutar verify automático.
-    fn run_verify(&self, _data_dir: &std::path::Path) {
+    /// Notifier desde config (`notify`) con overrides de CLI.
+    fn notifier(&self) -> Option<Notifier> {
+        let config = crate::core::config::OcConfig::from_cwd().unwrap_or_default();
+        Notifier::from_config(&config.notify, self.webhook.as_deref(), self.webhook_format)
+    }
+
+    /// L23.2: Ejecutar verify automático sobre la caché del watcher.
+    fn run_verify(
+        &self,
+        data_dir: &std::path::Path,
+        documents: &Arc<DocumentCache>,
+    ) -> Option<VerificationResult> {
         use crate::commands::verify::VerifyCommand;
 
-        let data_dir_buf = _data_dir.to_path_buf();
+        let data_dir_buf = data_dir.to_path_buf();
         let verify_cmd = VerifyCommand {
             path: Some(data_dir_buf.clone()),
             schema_strict: false,
@@ -210,15 +438,40 @@ impl WatchCommand {
             cache: false,
             root_only: false,  // RFC-04
             exclude: vec![],   // RFC-04
+            ignore_symlinks: false,
+            include_derived: false,
+            case_sensitive: false,
+            link_convention: None,
+            output: None,
+            module: None,
+            id_prefix: None,
+            timings: false,
+            timings_top: 10,
+            only_errors: false,
+            group_by: "phase".to_string(),
+            max_issues_per_file: None,
+            explain: None,
+            summary: false,
+            format: "text".to_string(),
+            open: Vec::new(),
+            fix: false,
+            compare_run: false,
+            all: false,
+            profiles: None,
+            derived_dirs: None,
+            profiler: None,
+            documents: Some(Arc::clone(documents)),
         };
 
-        if let Ok(result) = verify_cmd.run(&data_dir_buf) {
+        let result = verify_cmd.run(&data_dir_buf).ok()?;
+        if !self.quiet {
             eprintln!(
                 "  ✅ Verify: {} fases OK, {} errores",
                 result.phases_passed(),
                 result.phases_failed()
             );
         }
+        Some(result)
     }
 
     /// L24.1: Ejecutar hooks desde archivo.
@@ -250,6 +503,70 @@ impl WatchCommand {
     }
 }
 
+/// Errores de todas las fases de verify.
+fn verify_errors(result: &VerificationResult) -> impl Iterator<Item = String> + '_ {
+    result.phases.iter().flat_map(|p| p.errors.iter().cloned())
+}
+
+/// Archivos tocados más los documentos que los enlazan (backlinks).
+pub fn dependents(docs: &[Arc<LoadedDocument>], touched: &[PathBuf]) -> BTreeSet<PathBuf> {
+    let stems: HashSet<&str> = touched
+        .iter()
+        .filter_map(|p| p.file_name().and_then(|s| s.to_str()))
+        .map(|name| name.strip_suffix(".md").unwrap_or(name))
+        .collect();
+
+    let mut affected: BTreeSet<PathBuf> = touched.iter().cloned().collect();
+    for doc in docs {
+        // Los stems llevan puntos ("1.2 alta"): se compara sin `file_stem`
+        let links_touched = extract_links(&doc.content).iter().any(|link| {
+            let target = link.normalized_target();
+            let name = target.rsplit('/').next().unwrap_or(&target);
+            link.is_internal() && stems.contains(name.strip_suffix(".md").unwrap_or(name))
+        });
+        if links_touched {
+            affected.insert(doc.path.clone());
+        }
+    }
+    affected
+}
+
+/// Errores de verify que mencionan alguno de los archivos afectados.
+fn affected_errors<'a>(
+    result: &'a VerificationResult,
+    affected: &'a BTreeSet<PathBuf>,
+) -> impl Iterator<Item = String> + 'a {
+    let names: Vec<String> = affected
+        .iter()
+        .filter_map(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+        .collect();
+    verify_errors(result).filter(move |e| names.iter().any(|n| e.contains(n.as_str())))
+}
+
+/// Ruta relativa al directorio observado, para mensajes.
+fn relative(file: &std::path::Path, base: &std::path::Path) -> String {
+    file.strip_prefix(base).unwrap_or(file).display().to_string()
+}
+
+/// Resumen de un lote de cambios para el webhook.
+fn watch_summary(
+    result: &VerificationResult,
+    known_errors: &BTreeSet<String>,
+    changed_docs: Vec<String>,
+) -> NotifySummary {
+    let mut seen = BTreeSet::new();
+    NotifySummary {
+        passed: result.passed,
+        total_errors: result.total_errors,
+        total_warnings: result.total_warnings,
+        new_errors: verify_errors(result)
+            .filter(|e| !known_errors.contains(e) && seen.insert(e.clone()))
+            .collect(),
+        changed_docs,
+        ..NotifySummary::new("watch")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,12 +609,115 @@ mod tests {
             verify: true,
             hooks: None,
             max_iterations: 5,
+            webhook: None,
+            webhook_format: None,
         };
 
         assert_eq!(cmd.config().debounce_ms, 1000);
         assert!(cmd.verify);
         assert_eq!(cmd.max_iterations, 5);
     }
+
+    fn event(path: &str, event_type: WatchEventType) -> WatchEvent {
+        WatchEvent {
+            path: PathBuf::from(path),
+            event_type,
+            timestamp: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_accepts_ignores_editor_temp_files() {
+        let config = WatchConfig::default();
+        assert!(config.accepts(Path::new("Datos/1.2 alta.md")));
+        assert!(!config.accepts(Path::new("Datos/.1.2 alta.md.swp")));
+        assert!(!config.accepts(Path::new("Datos/1.2 alta.md~")));
+        assert!(!config.accepts(Path::new("Datos/.#1.2 alta.md")));
+        assert!(!config.accepts(Path::new("Datos/4913")));
+        assert!(!config.accepts(Path::new("Datos/notas.txt")));
+        assert!(!config.accepts(Path::new(".git/x.md")));
+    }
+
+    #[test]
+    fn test_coalesce_events() {
+        use WatchEventType::*;
+
+        let batch = coalesce(vec![
+            event("b.md", Modified),
+            event("a.md", Created),
+            event("b.md", Modified),
+            event("a.md", Modified),
+            event("c.md", Created),
+            event("c.md", Deleted),
+            event("d.md", Deleted),
+            event("d.md", Created),
+        ]);
+        let summary: Vec<_> = batch
+            .iter()
+            .map(|e| (e.path.to_str().unwrap(), e.event_type.clone()))
+            .collect();
+        assert_eq!(summary, vec![("a.md", Created), ("b.md", Modified), ("d.md", Modified)]);
+    }
+
+    #[test]
+    fn test_polling_detects_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a.md");
+        let b = dir.path().join("b.md");
+        std::fs::write(&a, "# A\n").unwrap();
+        std::fs::write(&b, "# B\n").unwrap();
+
+        let mut source = PollingSource::new(dir.path(), WatchConfig::default()).unwrap();
+        assert!(source.poll().is_empty());
+
+        let file = std::fs::File::options().write(true).open(&a).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(5)).unwrap();
+        std::fs::remove_file(&b).unwrap();
+        std::fs::write(dir.path().join("c.md"), "# C\n").unwrap();
+        std::fs::write(dir.path().join(".c.md.swp"), "").unwrap();
+
+        let types: Vec<_> = source.poll().into_iter().map(|e| e.event_type).collect();
+        assert_eq!(
+            types,
+            vec![WatchEventType::Modified, WatchEventType::Deleted, WatchEventType::Created]
+        );
+        assert!(source.poll().is_empty());
+    }
+
+    #[test]
+    fn test_dependents_by_backlinks() {
+        let doc = |name: &str, content: &str| {
+            Arc::new(LoadedDocument::parse(Path::new(name), content.to_string()))
+        };
+        let docs = vec![
+            doc("1 raiz.md", "Ver [[1.1 hijo]] y [[otro#seccion]]."),
+            doc("1.1 hijo.md", "Sin enlaces."),
+            doc("2 anexo.md", "[Hijo](../Datos/1.1 hijo.md) y [[1.1 hijo#datos]]"),
+            doc("3 ajeno.md", "[[otro]] y https://ejemplo.com"),
+        ];
+
+        let affected = dependents(&docs, &[PathBuf::from("1.1 hijo.md")]);
+        let names: Vec<_> = affected.iter().map(|p| p.to_str().unwrap()).collect();
+        assert_eq!(names, vec!["1 raiz.md", "1.1 hijo.md", "2 anexo.md"]);
+    }
+
+    #[test]
+    fn test_watch_summary_new_errors() {
+        use crate::commands::verify::VerificationPhase;
+
+        let mut phase = VerificationPhase::new(1, "links", "Enlaces");
+        phase.add_error("viejo");
+        phase.add_error("nuevo");
+        let mut result = VerificationResult::new();
+        result.add_phase(phase);
+
+        let known = BTreeSet::from(["viejo".to_string()]);
+        let summary = watch_summary(&result, &known, vec!["a.md".into()]);
+        assert_eq!(summary.source, "watch");
+        assert_eq!(summary.new_errors, vec!["nuevo".to_string()]);
+        assert_eq!(summary.changed_docs, vec!["a.md".to_string()]);
+        assert!(summary.is_degraded());
+    }
 }
 
 /// Función run para CLI.
@@ -319,6 +739,9 @@ pub fn run(cmd: WatchCommand, cli: &crate::commands::CliConfig) -> anyhow::Resul
         if let Some(ref exec) = cmd.exec {
             println!("⚡ Exec: {}", exec);
         }
+        if cmd.webhook.is_some() {
+            println!("📣 Webhook: activado");
+        }
     }
 
     let events = cmd.run(data_dir)?;
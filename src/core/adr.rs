@@ -0,0 +1,153 @@
+//! Registro de decisiones de arquitectura (ADR).
+//!
+//! `gen adr "Usar PostgreSQL"` crea `ADR-0001 Usar PostgreSQL.md` con
+//! numeración propia (la siguiente a la mayor existente) y `type: adr`. El
+//! estado es `propuesto`, `aceptado` o `supersedido`; un ADR supersedido
+//! enlaza a su reemplazo en `superseded_by`, y la fase 7 de verify lo exige.
+
+use crate::core::yaml::update_field;
+use crate::errors::OcResult;
+use crate::types::breadcrumb::frontmatter_value;
+use crate::types::lifetimes::split_frontmatter;
+use std::collections::BTreeMap;
+
+/// Estados válidos de un ADR, en orden de ciclo de vida.
+pub const ADR_STATUSES: &[&str] = &["propuesto", "aceptado", "supersedido"];
+
+/// Estado canónico (acepta los nombres en inglés).
+pub fn normalize_status(status: &str) -> Option<&'static str> {
+    match status.trim().to_lowercase().as_str() {
+        "propuesto" | "proposed" => Some("propuesto"),
+        "aceptado" | "accepted" => Some("aceptado"),
+        "supersedido" | "superseded" => Some("supersedido"),
+        _ => None,
+    }
+}
+
+/// Identificador con formato fijo (`ADR-0007`).
+pub fn adr_id(number: u32) -> String {
+    format!("ADR-{:04}", number)
+}
+
+/// Número del ADR al inicio de un nombre de archivo (`ADR-0007 Título`).
+pub fn adr_number(stem: &str) -> Option<u32> {
+    let rest = stem.get(..4).filter(|p| p.eq_ignore_ascii_case("adr-")).map(|_| &stem[4..])?;
+    let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+/// Referencia a un ADR escrita por el usuario: `7`, `ADR-7` o `ADR-0007 Título`.
+pub fn parse_ref(reference: &str) -> Option<u32> {
+    let reference = reference.trim();
+    reference.parse().ok().or_else(|| adr_number(reference))
+}
+
+/// Nombre de archivo (sin `.md`) del ADR: sin caracteres inválidos en paths.
+pub fn file_stem(number: u32, title: &str) -> String {
+    let title: String = title
+        .chars()
+        .filter(|c| !matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '[' | ']'))
+        .collect();
+    format!("{} {}", adr_id(number), title.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// ADRs por número (nombre de archivo sin extensión).
+pub fn index<'a>(stems: impl IntoIterator<Item = &'a str>) -> BTreeMap<u32, String> {
+    stems
+        .into_iter()
+        .filter_map(|stem| adr_number(stem).map(|n| (n, stem.to_string())))
+        .collect()
+}
+
+/// Documento de un ADR nuevo.
+pub fn render(number: u32, title: &str, status: &str, supersedes: Option<&str>, date: &str) -> String {
+    let id = adr_id(number);
+    let supersedes = supersedes
+        .map(|stem| format!("supersedes: \"[[{}]]\"\n", stem))
+        .unwrap_or_default();
+    format!(
+        "---\nid: \"{id}\"\ntitle: \"{id}: {title}\"\nparent: null\nbreadcrumb: \"Decisiones > {id}\"\n\
+         type: \"adr\"\nstatus: \"{status}\"\n{supersedes}created: \"{date}\"\nlast_updated: \"{date}\"\n---\n\n\
+         # {id}: {title}\n\n\
+         ## Contexto\n\n[Qué problema o fuerza motiva la decisión]\n\n\
+         ## Decisión\n\n[Qué se decidió y por qué]\n\n\
+         ## Alternativas consideradas\n\n- [Alternativa y motivo del descarte]\n\n\
+         ## Consecuencias\n\n[Qué cambia, qué se gana y qué se pierde]\n"
+    )
+}
+
+/// Marca un ADR como supersedido por `replacement` (nombre sin `.md`).
+pub fn supersede(content: &str, replacement: &str, date: &str) -> OcResult<String> {
+    let content = update_field(content, "status", "\"supersedido\"")?;
+    let content = update_field(&content, "superseded_by", &format!("\"[[{}]]\"", replacement))?;
+    update_field(&content, "last_updated", &format!("\"{}\"", date))
+}
+
+/// Problemas de un ADR: estado inválido o supersedido sin un enlace a un
+/// ADR existente en `superseded_by`. `adrs` es el índice del proyecto.
+pub fn check(stem: &str, content: &str, adrs: &BTreeMap<u32, String>) -> Vec<String> {
+    let Some(metadata) = split_frontmatter(content).map(|fm| fm.metadata) else {
+        return Vec::new();
+    };
+    let status = frontmatter_value(metadata, "status").unwrap_or_default();
+    let Some(status) = normalize_status(&status) else {
+        return vec![format!(
+            "estado de ADR inválido '{}' (válidos: {})",
+            status,
+            ADR_STATUSES.join(", ")
+        )];
+    };
+    if status != "supersedido" {
+        return Vec::new();
+    }
+
+    let Some(link) = frontmatter_value(metadata, "superseded_by") else {
+        return vec!["ADR supersedido sin enlace a su reemplazo (superseded_by)".to_string()];
+    };
+    let target = link.trim_start_matches("[[").trim_end_matches("]]");
+    let target = target.split(['|', '#']).next().unwrap_or(target).trim();
+    match adr_number(target) {
+        Some(n) if adr_number(stem) == Some(n) => vec!["ADR supersedido por sí mismo".to_string()],
+        Some(n) if adrs.contains_key(&n) => Vec::new(),
+        _ => vec![format!("superseded_by [[{}]] no es un ADR existente", target)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adr_render_supersede_and_check() {
+        assert_eq!(adr_number("ADR-0012 Usar Redis"), Some(12));
+        assert_eq!(adr_number("adr-3"), Some(3));
+        assert_eq!(adr_number("1.2 Pagos"), None);
+        assert_eq!(parse_ref("ADR-7"), Some(7));
+        assert_eq!(parse_ref("7"), Some(7));
+        assert_eq!(file_stem(1, "Usar  PostgreSQL: v16"), "ADR-0001 Usar PostgreSQL v16");
+        assert_eq!(normalize_status("Accepted"), Some("aceptado"));
+
+        let adrs = index(["ADR-0001 Usar MySQL", "ADR-0002 Usar PostgreSQL", "1 Intro"]);
+        assert_eq!(adrs.len(), 2);
+        let old = render(1, "Usar MySQL", "aceptado", None, "2026-01-01");
+        assert!(check("ADR-0001 Usar MySQL", &old, &adrs).is_empty());
+
+        let superseded = supersede(&old, "ADR-0002 Usar PostgreSQL", "2026-02-01").unwrap();
+        assert!(superseded.contains("status: \"supersedido\""));
+        assert!(superseded.contains("superseded_by: \"[[ADR-0002 Usar PostgreSQL]]\""));
+        assert!(check("ADR-0001 Usar MySQL", &superseded, &adrs).is_empty());
+
+        let dangling = old.replace("status: \"aceptado\"", "status: \"supersedido\"");
+        assert_eq!(
+            check("ADR-0001 Usar MySQL", &dangling, &adrs),
+            vec!["ADR supersedido sin enlace a su reemplazo (superseded_by)"]
+        );
+        let missing = supersede(&old, "ADR-0009 Otra", "2026-02-01").unwrap();
+        assert_eq!(
+            check("ADR-0001 Usar MySQL", &missing, &adrs),
+            vec!["superseded_by [[ADR-0009 Otra]] no es un ADR existente"]
+        );
+        let invalid = old.replace("aceptado", "rechazadx");
+        assert!(check("ADR-0001 Usar MySQL", &invalid, &adrs)[0].starts_with("estado de ADR inválido"));
+    }
+}
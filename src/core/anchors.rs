@@ -0,0 +1,432 @@
+//! Anchors estables para headings.
+//!
+//! Cada heading recibe un slug (`## Alta de clientes` → `alta-de-clientes`)
+//! único dentro del archivo. `gen anchors` los fija como `{#slug}` (o en
+//! `_index/anchors.json`) para que `[[Doc#Sección]]` siga resolviendo aunque
+//! el texto del heading cambie. Los bloques se marcan con `^id` al final
+//! del párrafo o ítem (`[[Doc#^id]]`, `![[Doc^id]]`); `gen block-ids` los
+//! genera a partir del hash del bloque.
+
+use crate::types::path::slugify;
+use crate::types::ContentHash;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Anchor explícito al final del heading: `## Título {#slug}`.
+static EXPLICIT_ANCHOR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\s*\{#([A-Za-z0-9_-]+)\}\s*$").unwrap());
+
+/// Block-id al final de una línea: `texto ^id` o `^id` solo.
+static BLOCK_ID: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?:^|\s)\^([A-Za-z0-9-]+)\s*$").unwrap());
+
+/// Ítem de lista (`- `, `* `, `+ `, `1. `).
+static LIST_ITEM: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*(?:[-*+]|\d+[.)])\s").unwrap());
+
+/// Ruta del índice de anchors dentro del data_dir.
+pub const ANCHOR_INDEX: &str = "_index/anchors.json";
+
+/// Índice de anchors por documento (stem → anchors).
+pub type AnchorIndex = BTreeMap<String, Vec<HeadingAnchor>>;
+
+/// Heading con su anchor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeadingAnchor {
+    /// Línea (1-based).
+    pub line: usize,
+    pub level: usize,
+    /// Texto del heading sin el `{#slug}`.
+    pub text: String,
+    pub slug: String,
+    /// ¿El anchor está escrito en el heading?
+    #[serde(default)]
+    pub explicit: bool,
+}
+
+/// Slug de un heading: minúsculas, sin tildes, palabras unidas por `-`.
+pub fn heading_slug(text: &str) -> String {
+    slugify(text).replace('_', "-")
+}
+
+/// Nivel de un heading ATX (`## Texto`).
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let rest = &line[level..];
+    ((1..=6).contains(&level) && rest.starts_with(' ') && !rest.trim().is_empty()).then_some(level)
+}
+
+/// Headings del documento (fuera de frontmatter y código) con anchors
+/// únicos: los explícitos se respetan y los generados que chocan reciben
+/// sufijo `-1`, `-2`…
+pub fn anchors(content: &str) -> Vec<HeadingAnchor> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut in_code = false;
+    let mut in_frontmatter = lines.first().is_some_and(|l| l.trim() == "---");
+    let mut found: Vec<(usize, usize, String, Option<String>)> = Vec::new();
+
+    for (idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if in_frontmatter {
+            in_frontmatter = idx == 0 || trimmed != "---";
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+        let Some(level) = heading_level(line) else {
+            continue;
+        };
+        let text = line[level..].trim();
+        match EXPLICIT_ANCHOR.captures(text) {
+            Some(cap) => {
+                let bare = text[..cap.get(0).unwrap().start()].trim().to_string();
+                found.push((idx + 1, level, bare, Some(cap[1].to_string())));
+            }
+            None => found.push((idx + 1, level, text.to_string(), None)),
+        }
+    }
+
+    let mut taken: HashSet<String> = found.iter().filter_map(|f| f.3.clone()).collect();
+    found
+        .into_iter()
+        .map(|(line, level, text, explicit)| {
+            let (slug, explicit) = match explicit {
+                Some(slug) => (slug, true),
+                None => {
+                    let base = heading_slug(&text);
+                    let mut slug = base.clone();
+                    let mut n = 1;
+                    while slug.is_empty() || taken.contains(&slug) {
+                        slug = format!("{}-{}", base, n);
+                        n += 1;
+                    }
+                    taken.insert(slug.clone());
+                    (slug, false)
+                }
+            };
+            HeadingAnchor { line, level, text, slug, explicit }
+        })
+        .collect()
+}
+
+/// Agrega `{#slug}` a los headings que no lo tienen; `None` si ya estaban todos.
+pub fn insert_anchors(content: &str) -> Option<String> {
+    let missing: Vec<HeadingAnchor> = anchors(content).into_iter().filter(|a| !a.explicit).collect();
+    if missing.is_empty() {
+        return None;
+    }
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    for anchor in missing {
+        let line = &mut lines[anchor.line - 1];
+        *line = format!("{} {{#{}}}", line.trim_end(), anchor.slug);
+    }
+    let mut updated = lines.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    Some(updated)
+}
+
+/// Heading al que apunta `#sección`: por anchor exacto o por slug del texto,
+/// así mayúsculas, tildes y puntuación no rompen el enlace.
+pub fn resolve_section<'a>(anchors: &'a [HeadingAnchor], section: &str) -> Option<&'a HeadingAnchor> {
+    let section = section.trim();
+    let wanted = heading_slug(section);
+    anchors
+        .iter()
+        .find(|a| a.slug == section || a.slug == wanted || heading_slug(&a.text) == wanted)
+}
+
+/// Fragmento de un destino `[[Doc#...]]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fragment<'a> {
+    Whole,
+    Section(&'a str),
+    Block(&'a str),
+}
+
+/// Separa documento y fragmento: `Doc#Sección`, `Doc#^id` y `Doc^id`.
+pub fn split_target(target: &str) -> (&str, Fragment<'_>) {
+    if let Some((doc, section)) = target.split_once('#') {
+        let section = section.trim();
+        return match section.strip_prefix('^') {
+            Some(id) => (doc.trim(), Fragment::Block(id.trim())),
+            None if section.is_empty() => (doc.trim(), Fragment::Whole),
+            None => (doc.trim(), Fragment::Section(section)),
+        };
+    }
+    match target.split_once('^') {
+        Some((doc, id)) => (doc.trim(), Fragment::Block(id.trim())),
+        None => (target.trim(), Fragment::Whole),
+    }
+}
+
+/// Bloque marcado con `^id`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockAnchor {
+    /// Línea del marcador (1-based).
+    pub line: usize,
+    pub id: String,
+}
+
+/// Block-ids del documento, fuera de frontmatter y código.
+pub fn block_ids(content: &str) -> Vec<BlockAnchor> {
+    let mut in_code = false;
+    let mut in_frontmatter = content.lines().next().is_some_and(|l| l.trim() == "---");
+    let mut found = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if in_frontmatter {
+            in_frontmatter = idx == 0 || trimmed != "---";
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code || heading_level(line).is_some() {
+            continue;
+        }
+        if let Some(cap) = BLOCK_ID.captures(line) {
+            found.push(BlockAnchor {
+                line: idx + 1,
+                id: cap[1].to_string(),
+            });
+        }
+    }
+    found
+}
+
+/// Texto del bloque `^id` sin el marcador: el ítem de lista o el párrafo
+/// que termina en esa línea; si el marcador va solo, el bloque anterior.
+pub fn block_fragment(content: &str, id: &str) -> Option<String> {
+    let marker = block_ids(content).into_iter().find(|b| b.id == id)?;
+    let lines: Vec<&str> = content.lines().collect();
+    let mut end = marker.line - 1;
+    let last = BLOCK_ID.replace(lines[end], "").trim_end().to_string();
+
+    let mut block = Vec::new();
+    if last.trim().is_empty() {
+        while end > 0 && lines[end - 1].trim().is_empty() {
+            end -= 1;
+        }
+    } else if LIST_ITEM.is_match(&last) {
+        return Some(last);
+    } else {
+        block.push(last);
+    }
+    let mut start = end;
+    while start > 0 {
+        let prev = lines[start - 1];
+        if prev.trim().is_empty() || heading_level(prev).is_some() || prev.trim() == "---" {
+            break;
+        }
+        start -= 1;
+    }
+    let mut text: Vec<String> = lines[start..end].iter().map(|l| l.to_string()).collect();
+    text.extend(block);
+    (!text.is_empty()).then(|| text.join("\n"))
+}
+
+/// Documentos (stem) que definen cada block-id.
+pub type BlockOwners = HashMap<String, Vec<String>>;
+
+/// Dueños de los block-ids de `files` (los ilegibles se omiten). Un id con
+/// un único dueño permite seguir al bloque cuando se mueve de documento.
+pub fn block_owners(files: &[PathBuf]) -> BlockOwners {
+    let mut owners = BlockOwners::new();
+    for file in files {
+        let (Some(stem), Ok(content)) = (file.file_stem().and_then(|s| s.to_str()), std::fs::read_to_string(file)) else {
+            continue;
+        };
+        for block in block_ids(&content) {
+            let stems = owners.entry(block.id).or_default();
+            if !stems.iter().any(|s| s == stem) {
+                stems.push(stem.to_string());
+            }
+        }
+    }
+    owners
+}
+
+/// ¿`doc` (stem, `stem.md` o ID numérico) nombra al archivo `stem`?
+pub fn names_doc(doc: &str, stem: &str) -> bool {
+    let doc = doc.trim().trim_end_matches(".md");
+    let doc = doc.rsplit('/').next().unwrap_or(doc);
+    stem.eq_ignore_ascii_case(doc)
+        || stem.split_whitespace().next().is_some_and(|id| id.trim_end_matches('.') == doc)
+}
+
+/// Bloques sin `^id` como (primera, última) línea, 0-based: párrafos e
+/// ítems de lista fuera de frontmatter y código. Las tablas se omiten.
+fn untagged_blocks(content: &str) -> Vec<(usize, usize)> {
+    let mut in_code = false;
+    let mut in_frontmatter = content.lines().next().is_some_and(|l| l.trim() == "---");
+    let mut blocks = Vec::new();
+    let mut open: Option<(usize, usize)> = None;
+    // Último bloque cerrado: un `^id` suelto después de líneas en blanco es suyo
+    let mut pending: Option<(usize, usize)> = None;
+    let close = |open: &mut Option<(usize, usize)>, pending: &mut Option<(usize, usize)>, blocks: &mut Vec<_>| {
+        if let Some(block) = open.take() {
+            blocks.extend(pending.replace(block));
+        }
+    };
+
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if in_frontmatter {
+            in_frontmatter = idx == 0 || trimmed != "---";
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            close(&mut open, &mut pending, &mut blocks);
+            blocks.extend(pending.take());
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+        if trimmed.starts_with('^') && BLOCK_ID.is_match(trimmed) {
+            // Marcador suelto: el bloque anterior ya tiene id
+            if open.take().is_none() {
+                pending = None;
+            }
+            continue;
+        }
+        if trimmed.is_empty()
+            || trimmed == "---"
+            || trimmed.starts_with('|')
+            || trimmed.starts_with("<!--")
+            || heading_level(line).is_some()
+        {
+            close(&mut open, &mut pending, &mut blocks);
+            if !trimmed.is_empty() {
+                blocks.extend(pending.take());
+            }
+            continue;
+        }
+        if LIST_ITEM.is_match(line) {
+            close(&mut open, &mut pending, &mut blocks);
+        }
+        if open.is_none() {
+            blocks.extend(pending.take());
+        }
+        open = Some((open.map_or(idx, |(start, _)| start), idx));
+    }
+    close(&mut open, &mut pending, &mut blocks);
+    blocks.extend(pending);
+
+    let lines: Vec<&str> = content.lines().collect();
+    blocks.retain(|(_, end)| !BLOCK_ID.is_match(lines[*end]));
+    blocks
+}
+
+/// Agrega `^id` a los párrafos e ítems de lista que no lo tienen; `None` si
+/// ya estaban todos. El id sale del hash del texto, así regenerar sobre el
+/// mismo contenido da los mismos ids.
+pub fn insert_block_ids(content: &str) -> Option<String> {
+    let blocks = untagged_blocks(content);
+    if blocks.is_empty() {
+        return None;
+    }
+    let mut taken: HashSet<String> = block_ids(content).into_iter().map(|b| b.id).collect();
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    for (start, end) in blocks {
+        let text = lines[start..=end].join("\n");
+        let hash = ContentHash::compute(text.trim());
+        let base = hash.short()[..6].to_string();
+        let mut id = base.clone();
+        let mut n = 1;
+        while taken.contains(&id) {
+            id = format!("{}-{}", base, n);
+            n += 1;
+        }
+        lines[end] = format!("{} ^{}", lines[end].trim_end(), id);
+        taken.insert(id);
+    }
+    let mut updated = lines.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    Some(updated)
+}
+
+/// Carga `_index/anchors.json` del data_dir si existe.
+pub fn load_index(data_dir: &Path) -> Option<AnchorIndex> {
+    let content = std::fs::read_to_string(data_dir.join(ANCHOR_INDEX)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = "---\ntitle: \"x\"\n# no\n---\n# Alta de Clientes\n\n## Datos\n\n```md\n## Código\n```\n\n## Datos\n\n### Pagos {#cobros}\n";
+
+    #[test]
+    fn test_anchors_unique() {
+        let found = anchors(DOC);
+        let slugs: Vec<&str> = found.iter().map(|a| a.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["alta-de-clientes", "datos", "datos-1", "cobros"]);
+        assert_eq!(found[3].text, "Pagos");
+        assert!(found[3].explicit);
+        assert_eq!(found[0].line, 5);
+    }
+
+    #[test]
+    fn test_insert_anchors() {
+        let updated = insert_anchors(DOC).unwrap();
+        assert!(updated.contains("# Alta de Clientes {#alta-de-clientes}\n"));
+        assert!(updated.contains("## Datos {#datos-1}\n"));
+        assert!(updated.contains("## Código\n"));
+        assert!(updated.ends_with("### Pagos {#cobros}\n"));
+        assert_eq!(insert_anchors(&updated), None);
+    }
+
+    #[test]
+    fn test_resolve_section() {
+        let renamed = "## Alta de clientes (v2) {#alta-de-clientes}\n";
+        let found = anchors(renamed);
+        assert!(resolve_section(&found, "Alta de Clientes").is_some());
+        assert!(resolve_section(&found, "alta-de-clientes").is_some());
+        assert!(resolve_section(&found, "Alta de clientes (v2)").is_some());
+        assert!(resolve_section(&found, "Baja").is_none());
+    }
+
+    #[test]
+    fn test_block_ids() {
+        let doc = "---\nid: \"1\"\n---\n# Pagos ^no\n\nPrimera línea\nsegunda. ^regla-1\n\n- uno\n- dos ^item\n\n| a |\n| b |\n\n^tabla\n\n```\nx ^codigo\n```\n";
+        let ids: Vec<String> = block_ids(doc).into_iter().map(|b| b.id).collect();
+        assert_eq!(ids, vec!["regla-1", "item", "tabla"]);
+        assert_eq!(block_fragment(doc, "regla-1").unwrap(), "Primera línea\nsegunda.");
+        assert_eq!(block_fragment(doc, "item").unwrap(), "- dos");
+        assert_eq!(block_fragment(doc, "tabla").unwrap(), "| a |\n| b |");
+        assert!(block_fragment(doc, "codigo").is_none());
+
+        assert_eq!(split_target("Doc#^regla-1"), ("Doc", Fragment::Block("regla-1")));
+        assert_eq!(split_target("Doc^regla-1"), ("Doc", Fragment::Block("regla-1")));
+        assert_eq!(split_target("Doc#Pagos"), ("Doc", Fragment::Section("Pagos")));
+        assert_eq!(split_target("Doc"), ("Doc", Fragment::Whole));
+    }
+
+    #[test]
+    fn test_insert_block_ids() {
+        let doc = "---\nid: \"1\"\n---\n# Pagos\n\nUno\ndos.\n\n- a\n  sigue\n- b ^fijo\n\n| t |\n\nTres.\n\n^suelto\n\n```\ncódigo\n```\n";
+        let updated = insert_block_ids(doc).unwrap();
+        let ids = block_ids(&updated);
+        assert_eq!(ids.len(), 4);
+        assert!(updated.contains("- b ^fijo\n"));
+        assert!(updated.contains("  sigue ^"));
+        assert!(updated.contains("Tres.\n\n^suelto\n"));
+        assert!(updated.ends_with("```\ncódigo\n```\n"));
+        assert_eq!(block_fragment(&updated, &ids[0].id).unwrap(), "Uno\ndos.");
+        assert_eq!(insert_block_ids(&updated), None);
+    }
+}
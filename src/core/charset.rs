@@ -0,0 +1,251 @@
+//! Caracteres problemáticos en el texto.
+//!
+//! Los invisibles (espacio de ancho cero, BOM, guion suave, ZWJ entre
+//! letras) rompen el matching de links y anclajes estén donde estén. Los
+//! espacios de no separación y los guiones unicode (`‐ – — −`) solo se
+//! reportan en referencias: targets de wiki-links y links markdown, e
+//! `id`/`parent` del frontmatter. El modo estricto (`lint --strict-utf8`)
+//! suma los NBSP del texto y las comillas tipográficas de los archivos que
+//! las mezclan con `"`. Base de la regla de lint L020.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::ops::Range;
+
+static RE_REFERENCE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[\[([^\]|]*)|\]\(([^)]*)\)").unwrap());
+static RE_INLINE_CODE: Lazy<Regex> = Lazy::new(|| Regex::new(r"`[^`]*`").unwrap());
+static RE_ID_FIELD: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*(?:id|parent)\s*:(.*)$").unwrap());
+
+/// Tipo de carácter problemático.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharKind {
+    /// Carácter invisible: se elimina.
+    Invisible,
+    /// Espacio de no separación en una referencia.
+    SpaceInReference,
+    /// Guion unicode en una referencia.
+    DashInReference,
+    /// Espacio de no separación en el texto (modo estricto).
+    Nbsp,
+    /// Comilla tipográfica en un archivo que también usa `"` (modo estricto).
+    MixedQuote,
+}
+
+impl CharKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Invisible => "Carácter invisible",
+            Self::SpaceInReference => "Espacio de no separación en referencia",
+            Self::DashInReference => "Guion unicode en referencia",
+            Self::Nbsp => "Espacio de no separación",
+            Self::MixedQuote => "Comilla tipográfica mezclada con \"",
+        }
+    }
+
+    /// Reemplazo de la normalización (`None` = quitar).
+    fn replacement(&self) -> Option<char> {
+        match self {
+            Self::Invisible => None,
+            Self::SpaceInReference | Self::Nbsp => Some(' '),
+            Self::DashInReference => Some('-'),
+            Self::MixedQuote => Some('"'),
+        }
+    }
+}
+
+/// Primer carácter problemático de un tipo en una línea.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharIssue {
+    /// Línea (1-based).
+    pub line: usize,
+    pub kind: CharKind,
+    pub ch: char,
+}
+
+impl CharIssue {
+    pub fn message(&self) -> String {
+        format!("{} (U+{:04X})", self.kind.label(), self.ch as u32)
+    }
+}
+
+/// Carácter a normalizar: línea (0-based) y byte dentro de ella.
+struct Found {
+    line: usize,
+    byte: usize,
+    ch: char,
+    kind: CharKind,
+}
+
+fn is_invisible(prev: Option<char>, ch: char, next: Option<char>) -> bool {
+    match ch {
+        '\u{200B}' | '\u{200C}' | '\u{2060}' | '\u{FEFF}' | '\u{00AD}' => true,
+        // El ZWJ compone emojis; pegado a letras o dígitos es basura
+        '\u{200D}' => prev.is_some_and(char::is_alphanumeric) || next.is_some_and(char::is_alphanumeric),
+        _ => false,
+    }
+}
+
+fn is_nbsp(ch: char) -> bool {
+    matches!(ch, '\u{00A0}' | '\u{202F}' | '\u{2007}')
+}
+
+fn is_unicode_dash(ch: char) -> bool {
+    matches!(ch, '\u{2010}'..='\u{2015}' | '\u{2212}')
+}
+
+fn in_ranges(ranges: &[Range<usize>], byte: usize) -> bool {
+    ranges.iter().any(|r| r.contains(&byte))
+}
+
+fn scan(content: &str, strict: bool) -> Vec<Found> {
+    let mut found = Vec::new();
+    let mut straight_quotes = false;
+    let mut in_frontmatter = false;
+    let mut in_fence = false;
+
+    for (n, line) in content.split('\n').enumerate() {
+        let marker = line.trim_start_matches('\u{FEFF}').trim_end();
+        let is_delimiter = marker == "---" && (n == 0 || in_frontmatter);
+        let trimmed = line.trim_start();
+        let is_fence = !in_frontmatter && (trimmed.starts_with("```") || trimmed.starts_with("~~~"));
+
+        let (references, code): (Vec<Range<usize>>, Vec<Range<usize>>) = if in_frontmatter {
+            let id = RE_ID_FIELD.captures(line).and_then(|c| c.get(1)).map(|m| m.range());
+            (id.into_iter().collect(), Vec::new())
+        } else {
+            let references = RE_REFERENCE
+                .captures_iter(line)
+                .filter_map(|c| c.get(1).or_else(|| c.get(2)))
+                .map(|m| m.range())
+                .collect();
+            (references, RE_INLINE_CODE.find_iter(line).map(|m| m.range()).collect())
+        };
+
+        let chars: Vec<(usize, char)> = line.char_indices().collect();
+        for (i, &(byte, ch)) in chars.iter().enumerate() {
+            let prev = i.checked_sub(1).map(|p| chars[p].1);
+            let next = chars.get(i + 1).map(|&(_, c)| c);
+            let kind = if is_invisible(prev, ch, next) {
+                Some(CharKind::Invisible)
+            } else if in_fence || is_fence {
+                None
+            } else if in_ranges(&references, byte) {
+                if is_nbsp(ch) {
+                    Some(CharKind::SpaceInReference)
+                } else if is_unicode_dash(ch) {
+                    Some(CharKind::DashInReference)
+                } else {
+                    None
+                }
+            } else if !strict || in_frontmatter || in_ranges(&code, byte) {
+                None
+            } else if is_nbsp(ch) {
+                Some(CharKind::Nbsp)
+            } else if matches!(ch, '\u{201C}' | '\u{201D}') {
+                Some(CharKind::MixedQuote)
+            } else {
+                straight_quotes |= ch == '"';
+                None
+            };
+            if let Some(kind) = kind {
+                found.push(Found { line: n, byte, ch, kind });
+            }
+        }
+
+        if is_delimiter {
+            in_frontmatter = !in_frontmatter;
+        } else if is_fence {
+            in_fence = !in_fence;
+        }
+    }
+
+    // Solo tipográficas: estilo consistente, no se tocan
+    if !straight_quotes {
+        found.retain(|f| f.kind != CharKind::MixedQuote);
+    }
+    found
+}
+
+/// Caracteres problemáticos, uno por tipo y línea.
+pub fn check(content: &str, strict: bool) -> Vec<CharIssue> {
+    let mut issues: Vec<CharIssue> = Vec::new();
+    for f in scan(content, strict) {
+        let line = f.line + 1;
+        if !issues.iter().any(|i| i.line == line && i.kind == f.kind) {
+            issues.push(CharIssue { line, kind: f.kind, ch: f.ch });
+        }
+    }
+    issues
+}
+
+/// Contenido normalizado: invisibles fuera, NBSP → espacio, guiones de
+/// referencias → `-` y comillas mezcladas → `"`. `None` si no hay cambios.
+pub fn normalize(content: &str, strict: bool) -> Option<String> {
+    let found = scan(content, strict);
+    if found.is_empty() {
+        return None;
+    }
+    let mut pending = found.iter().peekable();
+    let mut out = String::with_capacity(content.len());
+    for (n, line) in content.split('\n').enumerate() {
+        if n > 0 {
+            out.push('\n');
+        }
+        for (byte, ch) in line.char_indices() {
+            match pending.peek() {
+                Some(f) if f.line == n && f.byte == byte => {
+                    out.extend(f.kind.replacement());
+                    pending.next();
+                }
+                _ => out.push(ch),
+            }
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_references_and_invisibles() {
+        let content = "---\nid: \"1.2\u{2011}3\"\nparent: \"1\u{2013}2\"\n---\n\
+                       # Alta\u{200B}\n\nVer [[1.2\u{00A0}baja|la\u{00A0}baja]] y [guía](1\u{2014}2.md).\n\
+                       Texto\u{00A0}normal y \u{201C}citas\u{201D} con \"rectas\".\n\
+                       ```\ncódigo\u{00A0}[[a\u{2013}b]]\n```\n👩\u{200D}💻\n";
+        let issues = check(content, false);
+        let found: Vec<(usize, CharKind)> = issues.iter().map(|i| (i.line, i.kind)).collect();
+        assert_eq!(
+            found,
+            vec![
+                (2, CharKind::DashInReference),
+                (3, CharKind::DashInReference),
+                (5, CharKind::Invisible),
+                (7, CharKind::SpaceInReference),
+                (7, CharKind::DashInReference),
+            ]
+        );
+        assert_eq!(issues[1].message(), "Guion unicode en referencia (U+2013)");
+
+        let strict = check(content, true);
+        // El alias del link ya no es referencia: NBSP común del modo estricto
+        assert_eq!(strict.len(), 8);
+        assert!(strict.contains(&CharIssue { line: 7, kind: CharKind::Nbsp, ch: '\u{00A0}' }));
+        assert!(strict.contains(&CharIssue { line: 8, kind: CharKind::Nbsp, ch: '\u{00A0}' }));
+        assert!(strict.contains(&CharIssue { line: 8, kind: CharKind::MixedQuote, ch: '\u{201C}' }));
+        // Solo comillas tipográficas: no hay mezcla
+        assert!(check("Unas \u{201C}citas\u{201D}.\n", true).is_empty());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let content = "\u{FEFF}---\nid: \"2\u{2013}1\"\n---\nVer [[2.1\u{00A0}alta]].\u{200B}\n\u{201C}a\u{201D} y \"b\"\n";
+        assert_eq!(
+            normalize(content, false).unwrap(),
+            "---\nid: \"2-1\"\n---\nVer [[2.1 alta]].\n\u{201C}a\u{201D} y \"b\"\n"
+        );
+        assert_eq!(normalize(content, true).unwrap().lines().last(), Some("\"a\" y \"b\""));
+        assert_eq!(normalize("Sin problemas — nada.\n", false), None);
+    }
+}
@@ -0,0 +1,61 @@
+//! Clasificación de sensibilidad de los documentos.
+//!
+//! El campo `classification: public|internal|confidential` del frontmatter
+//! (validado en la fase 2 de verify) y el `confidential: true` histórico de
+//! `export --redact` definen el nivel de cada documento; `export --audience`
+//! rechaza los que superan la audiencia del export.
+
+use crate::types::breadcrumb::frontmatter_value;
+use crate::types::lifetimes::split_frontmatter;
+
+/// Valores válidos de `classification`, de menor a mayor sensibilidad.
+pub const CLASSIFICATIONS: &[&str] = &["public", "internal", "confidential"];
+
+/// Nivel de sensibilidad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Classification {
+    Public,
+    Internal,
+    Confidential,
+}
+
+impl Classification {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "public" => Some(Classification::Public),
+            "internal" => Some(Classification::Internal),
+            "confidential" => Some(Classification::Confidential),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        CLASSIFICATIONS[*self as usize]
+    }
+
+    /// Nivel declarado por un documento (sin frontmatter o sin campos, None).
+    pub fn of(content: &str) -> Option<Self> {
+        let metadata = split_frontmatter(content)?.metadata;
+        if let Some(level) = frontmatter_value(metadata, "classification").and_then(|v| Self::parse(&v)) {
+            return Some(level);
+        }
+        frontmatter_value(metadata, "confidential")
+            .filter(|v| matches!(v.to_lowercase().as_str(), "true" | "yes" | "sí" | "si"))
+            .map(|_| Classification::Confidential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classification_of() {
+        assert_eq!(Classification::of("---\nclassification: Internal\n---\n"), Some(Classification::Internal));
+        assert_eq!(Classification::of("---\nconfidential: true\n---\n"), Some(Classification::Confidential));
+        assert_eq!(Classification::of("---\nclassification: secreto\n---\n"), None);
+        assert_eq!(Classification::of("# Sin frontmatter\n"), None);
+        assert!(Classification::Confidential > Classification::Public);
+        assert_eq!(Classification::Internal.as_str(), "internal");
+    }
+}
@@ -17,6 +17,9 @@ pub const CONFIG_DIR: &str = ".oc_diagdoc";
 /// Nombre del archivo de configuración.
 pub const CONFIG_FILE: &str = "config.yaml";
 
+/// Directorios generados que verify, lint y stats omiten por defecto.
+pub const DEFAULT_DERIVED_DIRS: &[&str] = &["_summaries", "_prompts", "_exports"];
+
 /// Configuración principal de oc_diagdoc.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -39,6 +42,33 @@ pub struct OcConfig {
     pub validation: ValidationConfig,
     /// Configuración de cobertura.
     pub coverage: CoverageConfig,
+    /// Perfiles de pipeline para `ci`.
+    pub ci: CiConfig,
+    /// Directorios derivados (resúmenes, prompts, exports) que verify, lint
+    /// y stats omiten salvo con `--include-derived`. Un nombre coincide con
+    /// cualquier carpeta; una ruta (`docs/_gen`), desde el data_dir.
+    pub derived_dirs: Vec<String>,
+    /// Webhook de notificaciones para `ci` y `watch`.
+    pub notify: NotifyConfig,
+    /// Índice de embeddings para `search --semantic`.
+    pub embeddings: EmbeddingsConfig,
+    /// Reglas de `export --redact`.
+    pub redact: crate::core::redact::RedactConfig,
+    /// Límites estructurales (fase 23 de verify).
+    pub limits: LimitsConfig,
+    /// Detección de secretos (fase 27 de verify).
+    pub secrets: crate::core::secrets::SecretsConfig,
+    /// Variables `{{nombre}}` que export y compress sustituyen.
+    pub variables: std::collections::BTreeMap<String, serde_yaml::Value>,
+    /// Perfiles de fases/reglas por subdirectorio.
+    pub profiles: Vec<DirProfile>,
+    /// Zona horaria de las fechas del frontmatter (`UTC`, `local`, `-06:00`
+    /// o IANA como `America/Mexico_City`); sin valor, la del sistema.
+    pub timezone: Option<String>,
+    /// Consistencia de trato (tú/usted/vos) por tipo de documento (L019).
+    pub register: RegisterConfig,
+    /// Telemetría local de uso (opt-in, ver `core::usage`).
+    pub telemetry: TelemetryConfig,
 }
 
 impl Default for OcConfig {
@@ -53,6 +83,18 @@ impl Default for OcConfig {
             threads: 0,
             validation: ValidationConfig::default(),
             coverage: CoverageConfig::default(),
+            ci: CiConfig::default(),
+            derived_dirs: DEFAULT_DERIVED_DIRS.iter().map(|d| d.to_string()).collect(),
+            notify: NotifyConfig::default(),
+            embeddings: EmbeddingsConfig::default(),
+            redact: Default::default(),
+            limits: LimitsConfig::default(),
+            secrets: Default::default(),
+            variables: Default::default(),
+            profiles: Vec::new(),
+            timezone: None,
+            register: RegisterConfig::default(),
+            telemetry: TelemetryConfig::default(),
         }
     }
 }
@@ -200,6 +242,20 @@ pub struct ValidationConfig {
     pub check_orphans: bool,
     /// Modo estricto de esquema.
     pub strict_schema: bool,
+    /// Valores permitidos de `priority` en el schema estricto (vacío = libre).
+    pub priorities: Vec<String>,
+    /// Formato canónico (strftime) de las fechas del frontmatter para
+    /// `fix --normalize-dates`.
+    pub date_format: String,
+    /// Simular un filesystem case-sensitive al resolver links (en
+    /// macOS/Windows `[[doc]]` encuentra `Doc.md`, pero rompe en Linux).
+    pub case_sensitive_links: bool,
+    /// Severidad de los links con mayúsculas distintas (`error` o `warning`).
+    pub case_severity: String,
+    /// Forma de los wiki-links que validan `links` y verify (fase 9) y que
+    /// escribe `links --normalize`: `name`, `id` o `path`. Sin valor, solo
+    /// se marcan los links con ruta.
+    pub link_convention: Option<crate::core::links::LinkConvention>,
 }
 
 impl Default for ValidationConfig {
@@ -209,6 +265,11 @@ impl Default for ValidationConfig {
             check_links: true,
             check_orphans: true,
             strict_schema: false,
+            priorities: ["critica", "alta", "media", "baja"].map(String::from).to_vec(),
+            date_format: "%Y-%m-%d %H:%M".to_string(),
+            case_sensitive_links: false,
+            case_severity: "error".to_string(),
+            link_convention: None,
         }
     }
 }
@@ -238,6 +299,310 @@ impl Default for CoverageConfig {
     }
 }
 
+/// Perfil de pipeline CI: qué checks correr y con qué umbrales.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CiProfile {
+    /// Checks a ejecutar (verify, lint, links, health).
+    pub checks: Vec<String>,
+    /// Ejecutar verify en modo quick (omite fases lentas).
+    pub verify_quick: bool,
+    /// Máximo de errores permitidos (verify + lint).
+    pub max_errors: usize,
+    /// Máximo de warnings permitidos (None = sin límite).
+    pub max_warnings: Option<usize>,
+    /// Máximo de enlaces rotos permitidos.
+    pub max_broken_links: usize,
+    /// Score de salud mínimo (0-100).
+    pub min_health: u8,
+}
+
+impl Default for CiProfile {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl CiProfile {
+    /// Perfil estricto: todos los checks, cero tolerancia.
+    pub fn strict() -> Self {
+        Self {
+            checks: vec!["verify".into(), "lint".into(), "links".into(), "health".into()],
+            verify_quick: false,
+            max_errors: 0,
+            max_warnings: Some(0),
+            max_broken_links: 0,
+            min_health: 85,
+        }
+    }
+
+    /// Perfil estándar: todos los checks, warnings tolerados.
+    pub fn standard() -> Self {
+        Self {
+            checks: vec!["verify".into(), "lint".into(), "links".into(), "health".into()],
+            verify_quick: false,
+            max_errors: 0,
+            max_warnings: None,
+            max_broken_links: 10,
+            min_health: 70,
+        }
+    }
+
+    /// Perfil rápido: verify quick + lint, para cada push.
+    pub fn fast() -> Self {
+        Self {
+            checks: vec!["verify".into(), "lint".into()],
+            verify_quick: true,
+            max_errors: 0,
+            max_warnings: None,
+            max_broken_links: usize::MAX,
+            min_health: 0,
+        }
+    }
+
+    /// Perfil predefinido por nombre.
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "strict" => Some(Self::strict()),
+            "standard" => Some(Self::standard()),
+            "fast" => Some(Self::fast()),
+            _ => None,
+        }
+    }
+
+    /// ¿El perfil incluye este check?
+    pub fn has_check(&self, check: &str) -> bool {
+        self.checks.iter().any(|c| c == check)
+    }
+}
+
+/// Configuración de `ci`: perfiles definidos por el usuario.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CiConfig {
+    /// Perfiles por nombre (tienen prioridad sobre los predefinidos).
+    pub profiles: std::collections::HashMap<String, CiProfile>,
+}
+
+impl CiConfig {
+    /// Resuelve un perfil: primero config, luego predefinidos.
+    pub fn profile(&self, name: &str) -> Option<CiProfile> {
+        self.profiles
+            .get(name)
+            .cloned()
+            .or_else(|| CiProfile::builtin(name))
+    }
+}
+
+/// Configuración de notificaciones por webhook.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotifyConfig {
+    /// URL del webhook (Slack, Teams o endpoint propio).
+    pub webhook_url: Option<String>,
+    /// Formato del payload: slack, teams o json.
+    pub format: crate::core::webhook::WebhookFormat,
+    /// Solo notificar cuando falla o aparecen errores nuevos.
+    pub only_on_degradation: bool,
+}
+
+/// Configuración de embeddings para búsqueda semántica.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmbeddingsConfig {
+    /// Proveedor: hash (local, sin red), api (compatible OpenAI) o command.
+    pub provider: crate::core::embeddings::EmbeddingProvider,
+    /// Modelo a solicitar a la API (ej: text-embedding-3-small).
+    pub model: Option<String>,
+    /// Endpoint de la API de embeddings.
+    pub api_url: Option<String>,
+    /// Variable de entorno con la API key.
+    pub api_key_env: String,
+    /// Comando externo (ej: script con modelo ONNX local): JSON por stdin/stdout.
+    pub command: Option<String>,
+    /// Dimensiones del proveedor `hash`.
+    pub dimensions: usize,
+    /// Palabras máximas por fragmento indexado.
+    pub chunk_words: usize,
+    /// Archivo del índice.
+    pub index_path: PathBuf,
+}
+
+impl Default for EmbeddingsConfig {
+    fn default() -> Self {
+        Self {
+            provider: Default::default(),
+            model: None,
+            api_url: None,
+            api_key_env: "OPENAI_API_KEY".to_string(),
+            command: None,
+            dimensions: 384,
+            chunk_words: 200,
+            index_path: PathBuf::from(".oc_diagdoc/embeddings.json"),
+        }
+    }
+}
+
+/// Límites estructurales: cuándo sugerir dividir, agrupar o aplanar.
+///
+/// Un valor 0 desactiva el límite. `by_type` sobreescribe por `type:`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LimitsConfig {
+    /// Palabras máximas por documento (sugerir split).
+    pub max_words: usize,
+    /// Hijos directos máximos por padre (sugerir agrupar).
+    pub max_children: usize,
+    /// Niveles máximos de jerarquía (segmentos del ID).
+    pub max_depth: usize,
+    /// Líneas idénticas consecutivas que cuentan como bloque copiado entre
+    /// padre, hijos y hermanos (fase 25).
+    pub copied_block_lines: usize,
+    /// Umbrales por tipo de documento.
+    pub by_type: std::collections::HashMap<String, TypeLimits>,
+}
+
+/// Umbrales de un tipo; los ausentes heredan de [`LimitsConfig`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TypeLimits {
+    pub max_words: Option<usize>,
+    pub max_children: Option<usize>,
+    pub max_depth: Option<usize>,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_words: 3000,
+            max_children: 15,
+            max_depth: 6,
+            copied_block_lines: 5,
+            by_type: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl LimitsConfig {
+    fn type_limits(&self, doc_type: Option<&str>) -> Option<&TypeLimits> {
+        doc_type.and_then(|t| self.by_type.get(t))
+    }
+
+    pub fn max_words(&self, doc_type: Option<&str>) -> usize {
+        self.type_limits(doc_type).and_then(|t| t.max_words).unwrap_or(self.max_words)
+    }
+
+    pub fn max_children(&self, doc_type: Option<&str>) -> usize {
+        self.type_limits(doc_type).and_then(|t| t.max_children).unwrap_or(self.max_children)
+    }
+
+    pub fn max_depth(&self, doc_type: Option<&str>) -> usize {
+        self.type_limits(doc_type).and_then(|t| t.max_depth).unwrap_or(self.max_depth)
+    }
+}
+
+/// Trato esperado por tipo de documento para la regla L019.
+///
+/// ```yaml
+/// register:
+///   by_type:
+///     guia: usted   # tu, usted, vos o auto (el predominante del documento)
+///     ux: auto
+/// ```
+///
+/// Los tipos ausentes no se revisan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RegisterConfig {
+    pub by_type: std::collections::BTreeMap<String, String>,
+}
+
+impl Default for RegisterConfig {
+    fn default() -> Self {
+        Self {
+            by_type: [("guia", "auto"), ("ux", "auto")]
+                .into_iter()
+                .map(|(t, r)| (t.to_string(), r.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl RegisterConfig {
+    /// Trato configurado para un tipo (`None` = el tipo no se revisa).
+    pub fn for_type(&self, doc_type: &str) -> Option<&str> {
+        self.by_type.get(doc_type).map(String::as_str)
+    }
+}
+
+/// Telemetría local de uso: solo escribe en `.oc_diagdoc/usage.jsonl`,
+/// nunca a la red. `OC_TELEMETRY=0|1` tiene prioridad sobre la config.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelemetryConfig {
+    /// Registrar comando, flags y duración de cada ejecución (apagada por defecto).
+    pub enabled: bool,
+}
+
+/// Fase de verify por número (`3`), nombre (`yaml`) o rango (`"1-3"`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PhaseRef {
+    Number(u8),
+    Name(String),
+}
+
+/// Perfil de verificación para un subdirectorio del data_dir.
+///
+/// ```yaml
+/// profiles:
+///   - path: _prompts
+///     phases: ["1-3"]
+///   - path: legal
+///     strict_schema: true
+///   - path: drafts
+///     skip_phases: [min_content]
+///     skip_rules: [L006]
+/// ```
+///
+/// Si varios perfiles aplican a un archivo gana el de ruta más larga.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DirProfile {
+    /// Subdirectorio relativo al data_dir.
+    pub path: String,
+    /// Fases a ejecutar; vacío = todas.
+    pub phases: Vec<PhaseRef>,
+    /// Fases a omitir.
+    pub skip_phases: Vec<PhaseRef>,
+    /// Reglas de lint a omitir (ej: L006).
+    pub skip_rules: Vec<String>,
+    /// Validación estricta de schema en la fase 2.
+    pub strict_schema: bool,
+}
+
+impl DirProfile {
+    /// ¿El archivo (relativo al data_dir) está bajo este perfil? `"."` es
+    /// todo el data_dir.
+    pub fn matches(&self, relative: &Path) -> bool {
+        let dir = self.path.trim_matches('/');
+        dir == "." || (!dir.is_empty() && relative.starts_with(dir))
+    }
+
+    /// Perfil más específico para un archivo relativo al data_dir.
+    pub fn resolve<'a>(profiles: &'a [DirProfile], relative: &Path) -> Option<&'a DirProfile> {
+        profiles
+            .iter()
+            .filter(|p| p.matches(relative))
+            .max_by_key(|p| p.path.trim_matches('/').len())
+    }
+
+    /// ¿Se omite la regla de lint?
+    pub fn skips_rule(&self, code: &str) -> bool {
+        self.skip_rules.iter().any(|r| r.eq_ignore_ascii_case(code))
+    }
+}
+
 /// Builder para OcConfig.
 #[derive(Debug, Default)]
 pub struct OcConfigBuilder {
@@ -302,6 +667,18 @@ impl OcConfigBuilder {
             threads: self.threads.unwrap_or(default.threads),
             validation: default.validation,
             coverage: default.coverage,
+            ci: default.ci,
+            derived_dirs: default.derived_dirs,
+            notify: default.notify,
+            embeddings: default.embeddings,
+            redact: default.redact,
+            limits: default.limits,
+            secrets: default.secrets,
+            variables: default.variables,
+            profiles: default.profiles,
+            timezone: default.timezone,
+            register: default.register,
+            telemetry: default.telemetry,
         }
     }
 }
@@ -317,6 +694,9 @@ mod tests {
         assert_eq!(config.data_dir, PathBuf::from("Datos"));
         assert!(config.cache_enabled);
         assert!(config.parallel);
+        assert_eq!(config.derived_dirs, vec!["_summaries", "_prompts", "_exports"]);
+        let custom: OcConfig = serde_yaml::from_str("derived_dirs: [_build]\n").unwrap();
+        assert_eq!(custom.derived_dirs, vec!["_build"]);
     }
 
     #[test]
@@ -349,6 +729,55 @@ mod tests {
         assert!(loaded.verbose);
     }
 
+    #[test]
+    fn test_ci_profiles() {
+        let mut ci = CiConfig::default();
+        assert_eq!(ci.profile("strict"), Some(CiProfile::strict()));
+        assert!(ci.profile("nightly").is_none());
+
+        let custom = CiProfile {
+            checks: vec!["lint".into()],
+            ..CiProfile::fast()
+        };
+        ci.profiles.insert("fast".into(), custom.clone());
+        assert_eq!(ci.profile("fast"), Some(custom));
+    }
+
+    #[test]
+    fn test_notify_config_yaml() {
+        let yaml = "notify:\n  webhook_url: https://hooks.example/x\n  format: slack\n";
+        let config: OcConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.notify.webhook_url.as_deref(), Some("https://hooks.example/x"));
+        assert_eq!(config.notify.format, crate::core::webhook::WebhookFormat::Slack);
+        assert!(!config.notify.only_on_degradation);
+    }
+
+    #[test]
+    fn test_limits_by_type() {
+        let yaml = "limits:\n  max_words: 2000\n  by_type:\n    indice:\n      max_children: 40\n";
+        let config: OcConfig = serde_yaml::from_str(yaml).unwrap();
+        let limits = &config.limits;
+        assert_eq!(limits.max_words(Some("indice")), 2000);
+        assert_eq!(limits.max_children(Some("indice")), 40);
+        assert_eq!(limits.max_children(Some("hoja")), 15);
+        assert_eq!(limits.max_depth(None), 6);
+    }
+
+    #[test]
+    fn test_dir_profiles() {
+        let yaml = "profiles:\n  - path: _prompts/\n    phases: [\"1-3\"]\n  - path: legal\n    strict_schema: true\n  - path: legal/borradores\n    skip_phases: [16, min_content]\n    skip_rules: [l006]\n";
+        let config: OcConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.profiles[0].phases, vec![PhaseRef::Name("1-3".to_string())]);
+        assert_eq!(config.profiles[2].skip_phases[0], PhaseRef::Number(16));
+
+        let resolve = |p: &str| DirProfile::resolve(&config.profiles, Path::new(p)).map(|p| p.path.as_str());
+        assert_eq!(resolve("_prompts/a.md"), Some("_prompts/"));
+        assert_eq!(resolve("legal/borradores/x.md"), Some("legal/borradores"));
+        assert_eq!(resolve("legal/x.md"), Some("legal"));
+        assert_eq!(resolve("legales/x.md"), None);
+        assert!(config.profiles[2].skips_rule("L006"));
+    }
+
     #[test]
     fn test_coverage_config() {
         let config = CoverageConfig::default();
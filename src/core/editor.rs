@@ -0,0 +1,128 @@
+//! Apertura de issues en el editor y enlaces clicables.
+//!
+//! `lint --open` y `verify --open` lanzan `$VISUAL`/`$EDITOR` en la línea
+//! del issue con la sintaxis de cada editor (`vim +12 archivo`,
+//! `code -g archivo:12`). `--format hyperlink` envuelve `archivo:línea` en
+//! una secuencia OSC 8 hacia `file://ruta#línea` para terminales que la
+//! soportan.
+
+use crate::errors::{OcError, OcResult};
+use std::path::{Path, PathBuf};
+
+/// Editor configurado: `$VISUAL` y si no `$EDITOR`.
+pub fn editor_from_env() -> Option<String> {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .map(|e| e.trim().to_string())
+        .find(|e| !e.is_empty())
+}
+
+/// Programa y argumentos para abrir `path` en `line` con `editor` (que
+/// puede traer sus propios flags, ej. `code -w`).
+pub fn editor_command(editor: &str, path: &Path, line: Option<usize>) -> OcResult<(String, Vec<String>)> {
+    let mut parts = editor.split_whitespace().map(str::to_string);
+    let program = parts
+        .next()
+        .ok_or_else(|| OcError::InvalidArgument("editor vacío".to_string()))?;
+    let mut args: Vec<String> = parts.collect();
+    let file = path.display().to_string();
+    let name = Path::new(&program)
+        .file_stem()
+        .and_then(|n| n.to_str())
+        .unwrap_or(&program)
+        .to_lowercase();
+
+    match (name.as_str(), line) {
+        (_, None) => args.push(file),
+        ("code" | "code-insiders" | "codium" | "cursor", Some(l)) => {
+            args.push("-g".to_string());
+            args.push(format!("{}:{}", file, l));
+        }
+        ("subl" | "zed" | "hx" | "helix" | "idea" | "goland", Some(l)) => args.push(format!("{}:{}", file, l)),
+        // vi, vim, nvim, nano, emacs, micro, kak… aceptan `+línea`
+        (_, Some(l)) => {
+            args.push(format!("+{}", l));
+            args.push(file);
+        }
+    }
+    Ok((program, args))
+}
+
+/// Abre `path` en `line` con el editor del entorno y espera a que termine.
+pub fn open_in_editor(path: &Path, line: Option<usize>) -> OcResult<()> {
+    let editor = editor_from_env()
+        .ok_or_else(|| OcError::InvalidArgument("defina $EDITOR (o $VISUAL) para usar --open".to_string()))?;
+    let (program, args) = editor_command(&editor, path, line)?;
+    let status = std::process::Command::new(&program)
+        .args(&args)
+        .status()
+        .map_err(|e| OcError::Custom(format!("no se pudo lanzar '{}': {}", program, e)))?;
+    if !status.success() {
+        return Err(OcError::Custom(format!("'{}' terminó con {}", program, status)));
+    }
+    Ok(())
+}
+
+/// URL `file://` absoluta con la línea como fragmento.
+pub fn file_url(path: &Path, line: Option<usize>) -> String {
+    let absolute: PathBuf = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().map(|d| d.join(path)).unwrap_or_else(|_| path.to_path_buf())
+    };
+    let mut url = String::from("file://");
+    for byte in absolute.to_string_lossy().bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => url.push(byte as char),
+            _ => url.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    if let Some(line) = line {
+        url.push_str(&format!("#{}", line));
+    }
+    url
+}
+
+/// `text` como hipervínculo OSC 8 hacia `url`.
+pub fn hyperlink(url: &str, text: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+/// `archivo:línea` clicable hacia el archivo.
+pub fn location_link(path: &Path, line: Option<usize>) -> String {
+    let text = match line {
+        Some(l) => format!("{}:{}", path.display(), l),
+        None => path.display().to_string(),
+    };
+    hyperlink(&file_url(path, line), &text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_command() {
+        let path = Path::new("Datos/1.1 alta.md");
+        let cmd = |editor: &str, line| editor_command(editor, path, line).unwrap();
+        assert_eq!(cmd("vim", Some(12)), ("vim".to_string(), vec!["+12".to_string(), "Datos/1.1 alta.md".to_string()]));
+        assert_eq!(
+            cmd("/usr/bin/code -w", Some(3)).1,
+            vec!["-w".to_string(), "-g".to_string(), "Datos/1.1 alta.md:3".to_string()]
+        );
+        assert_eq!(cmd("hx", Some(3)).1, vec!["Datos/1.1 alta.md:3".to_string()]);
+        assert_eq!(cmd("nano", None).1, vec!["Datos/1.1 alta.md".to_string()]);
+        assert!(editor_command("  ", path, None).is_err());
+    }
+
+    #[test]
+    fn test_hyperlink() {
+        let url = file_url(Path::new("/datos/1.1 alta ñ.md"), Some(7));
+        assert_eq!(url, "file:///datos/1.1%20alta%20%C3%B1.md#7");
+        assert_eq!(
+            location_link(Path::new("/a.md"), Some(2)),
+            "\x1b]8;;file:///a.md#2\x1b\\/a.md:2\x1b]8;;\x1b\\"
+        );
+    }
+}
@@ -0,0 +1,538 @@
+//! Índice de embeddings para búsqueda semántica.
+//!
+//! Los documentos se parten en fragmentos (por sección, con rango de líneas)
+//! y cada fragmento se vectoriza con el proveedor configurado:
+//!
+//! - `hash`: vectores léxicos locales (feature hashing de palabras y bigramas),
+//!   sin red ni modelo. Captura coincidencia de vocabulario, no sinónimos.
+//! - `api`: endpoint compatible con OpenAI (`POST {model, input}` → `data[].embedding`).
+//! - `command`: programa externo (ej. un modelo ONNX local) que recibe
+//!   `{"input": [...]}` por stdin y responde `{"embeddings": [[...]]}`.
+//!
+//! El índice se cachea por `content_hash`: solo se re-vectorizan los archivos
+//! que cambiaron desde el último `index build --embeddings`.
+
+use crate::core::config::EmbeddingsConfig;
+use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+use crate::core::hash::compute_content_hash;
+use crate::core::http::post_json;
+use crate::core::patterns::RE_TITLE;
+use crate::errors::{OcError, OcResult};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Fragmentos por request al proveedor.
+const BATCH_SIZE: usize = 64;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROVEEDORES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Proveedor de embeddings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingProvider {
+    /// Vectores léxicos locales.
+    #[default]
+    Hash,
+    /// API HTTP compatible con OpenAI.
+    Api,
+    /// Programa externo vía stdin/stdout.
+    Command,
+}
+
+/// Vectoriza textos según [`EmbeddingsConfig`].
+#[derive(Debug, Clone)]
+pub struct Embedder {
+    config: EmbeddingsConfig,
+}
+
+impl Embedder {
+    pub fn new(config: EmbeddingsConfig) -> Self {
+        Self { config }
+    }
+
+    /// Identificador del modelo; un índice solo es válido con el mismo id.
+    pub fn model_id(&self) -> String {
+        match self.config.provider {
+            EmbeddingProvider::Hash => format!("hash:{}", self.config.dimensions),
+            EmbeddingProvider::Api => format!(
+                "api:{}",
+                self.config.model.as_deref().unwrap_or("default")
+            ),
+            EmbeddingProvider::Command => format!(
+                "command:{}",
+                self.config.command.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+
+    /// Vectores normalizados (L2) para cada texto.
+    pub fn embed(&self, texts: &[String]) -> OcResult<Vec<Vec<f32>>> {
+        let mut vectors = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(BATCH_SIZE) {
+            let raw = match self.config.provider {
+                EmbeddingProvider::Hash => batch
+                    .iter()
+                    .map(|t| hash_embed(t, self.config.dimensions))
+                    .collect(),
+                EmbeddingProvider::Api => self.embed_api(batch)?,
+                EmbeddingProvider::Command => self.embed_command(batch)?,
+            };
+            if raw.len() != batch.len() {
+                return Err(OcError::Custom(format!(
+                    "El proveedor de embeddings devolvió {} vectores para {} textos",
+                    raw.len(),
+                    batch.len()
+                )));
+            }
+            vectors.extend(raw.into_iter().map(normalize));
+        }
+        Ok(vectors)
+    }
+
+    fn embed_api(&self, batch: &[String]) -> OcResult<Vec<Vec<f32>>> {
+        let url = self.config.api_url.as_deref().ok_or_else(|| {
+            OcError::InvalidArgument("embeddings.api_url no configurado".to_string())
+        })?;
+        let mut headers = Vec::new();
+        if let Ok(key) = std::env::var(&self.config.api_key_env) {
+            headers.push(format!("Authorization: Bearer {}", key));
+        }
+        let mut payload = json!({ "input": batch });
+        if let Some(model) = &self.config.model {
+            payload["model"] = json!(model);
+        }
+        let response: Value = serde_json::from_str(&post_json(url, &headers, &payload)?)?;
+        let data = response["data"].as_array().ok_or_else(|| {
+            OcError::Custom("Respuesta de embeddings sin campo `data`".to_string())
+        })?;
+        Ok(data.iter().map(|item| parse_vector(&item["embedding"])).collect())
+    }
+
+    fn embed_command(&self, batch: &[String]) -> OcResult<Vec<Vec<f32>>> {
+        let command = self.config.command.as_deref().ok_or_else(|| {
+            OcError::InvalidArgument("embeddings.command no configurado".to_string())
+        })?;
+        let mut child = Command::new("sh")
+            .args(["-c", command])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|e| OcError::Custom(format!("No se pudo ejecutar '{}': {}", command, e)))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(json!({ "input": batch }).to_string().as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(OcError::Custom(format!(
+                "'{}' terminó con {}",
+                command, output.status
+            )));
+        }
+        let response: Value = serde_json::from_slice(&output.stdout)?;
+        let list = response
+            .get("embeddings")
+            .unwrap_or(&response)
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        Ok(list.iter().map(parse_vector).collect())
+    }
+}
+
+fn parse_vector(value: &Value) -> Vec<f32> {
+    value
+        .as_array()
+        .map(|a| a.iter().filter_map(|v| v.as_f64()).map(|v| v as f32).collect())
+        .unwrap_or_default()
+}
+
+fn normalize(mut v: Vec<f32>) -> Vec<f32> {
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        v.iter_mut().for_each(|x| *x /= norm);
+    }
+    v
+}
+
+/// Similitud coseno entre vectores ya normalizados.
+pub fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Términos normalizados (minúsculas, sin tildes) de al menos 3 caracteres.
+fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|w| {
+            w.to_lowercase()
+                .chars()
+                .map(|c| match c {
+                    'á' | 'à' | 'ä' => 'a',
+                    'é' | 'è' | 'ë' => 'e',
+                    'í' | 'ì' | 'ï' => 'i',
+                    'ó' | 'ò' | 'ö' => 'o',
+                    'ú' | 'ù' | 'ü' => 'u',
+                    other => other,
+                })
+                .collect::<String>()
+        })
+        .filter(|w| w.chars().count() >= 3)
+        .collect()
+}
+
+/// FNV-1a: hash estable entre versiones (el índice se persiste).
+fn fnv1a(s: &str) -> u64 {
+    s.bytes().fold(0xcbf29ce484222325, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Feature hashing de palabras y bigramas con peso sublineal (1 + ln tf).
+fn hash_embed(text: &str, dims: usize) -> Vec<f32> {
+    let dims = dims.max(1);
+    let words = terms(text);
+    let mut counts: BTreeMap<String, f32> = BTreeMap::new();
+    for w in &words {
+        *counts.entry(w.clone()).or_default() += 1.0;
+    }
+    for pair in words.windows(2) {
+        *counts.entry(format!("{} {}", pair[0], pair[1])).or_default() += 0.5;
+    }
+
+    let mut v = vec![0.0f32; dims];
+    for (term, tf) in counts {
+        let h = fnv1a(&term);
+        let sign = if h & (1 << 63) == 0 { 1.0 } else { -1.0 };
+        v[(h % dims as u64) as usize] += sign * (1.0 + tf.ln());
+    }
+    v
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FRAGMENTOS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Fragmento indexado con su rango de líneas (1-based, inclusivo).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedChunk {
+    pub line_start: usize,
+    pub line_end: usize,
+    pub text: String,
+    pub vector: Vec<f32>,
+}
+
+/// Parte un documento en fragmentos por heading o cada `max_words` palabras
+/// (cortando en línea en blanco). Omite el frontmatter.
+pub fn chunk_document(content: &str, max_words: usize) -> Vec<(usize, usize, String)> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut start = 0;
+    if lines.first().map(|l| l.trim() == "---").unwrap_or(false) {
+        if let Some(end) = lines.iter().skip(1).position(|l| l.trim() == "---") {
+            start = end + 2;
+        }
+    }
+
+    let mut chunks = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    let mut current_start = start + 1;
+    let mut words = 0;
+    let mut flush = |current: &mut Vec<&str>, first: usize, last: usize, words: &mut usize| {
+        let text = current.join("\n").trim().to_string();
+        if text.split_whitespace().count() >= 3 {
+            chunks.push((first, last, text));
+        }
+        current.clear();
+        *words = 0;
+    };
+
+    for (i, line) in lines.iter().enumerate().skip(start) {
+        let number = i + 1;
+        let is_heading = line.trim_start().starts_with('#');
+        let split_here = is_heading || (words >= max_words && line.trim().is_empty());
+        if split_here && !current.is_empty() {
+            flush(&mut current, current_start, number - 1, &mut words);
+        }
+        if current.is_empty() {
+            current_start = number;
+        }
+        words += line.split_whitespace().count();
+        current.push(line);
+    }
+    if !current.is_empty() {
+        flush(&mut current, current_start, lines.len(), &mut words);
+    }
+    chunks
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ÍNDICE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Archivo indexado.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedFile {
+    pub content_hash: String,
+    pub title: String,
+    pub chunks: Vec<IndexedChunk>,
+}
+
+/// Fragmento encontrado por similitud.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChunkHit {
+    pub path: String,
+    pub title: String,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub text: String,
+    pub score: f32,
+}
+
+/// Documento encontrado (mejor fragmento del documento).
+#[derive(Debug, Clone, Serialize)]
+pub struct DocHit {
+    pub path: String,
+    pub title: String,
+    pub score: f32,
+}
+
+/// Estadísticas de construcción.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BuildStats {
+    pub files_embedded: usize,
+    pub files_cached: usize,
+    pub chunks: usize,
+}
+
+/// Índice de embeddings persistido en JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingIndex {
+    pub model: String,
+    pub built: String,
+    pub files: BTreeMap<String, IndexedFile>,
+}
+
+impl EmbeddingIndex {
+    /// Carga el índice; `None` si no existe.
+    pub fn load(path: &Path) -> OcResult<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = read_file_content(path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    pub fn save(&self, path: &Path) -> OcResult<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(self)?).map_err(|e| OcError::FileWrite {
+            path: path.to_path_buf(),
+            source: e,
+        })
+    }
+
+    /// Construye el índice reutilizando vectores de `previous` cuando el
+    /// `content_hash` del archivo y el modelo coinciden.
+    pub fn build(
+        data_dir: &Path,
+        embedder: &Embedder,
+        chunk_words: usize,
+        previous: Option<&Self>,
+    ) -> OcResult<(Self, BuildStats)> {
+        let model = embedder.model_id();
+        let cache = previous.filter(|p| p.model == model);
+        let mut stats = BuildStats::default();
+        let mut files = BTreeMap::new();
+
+        let mut paths = get_all_md_files(data_dir, &ScanOptions::new())?;
+        paths.sort();
+        for path in paths {
+            let content = read_file_content(&path)?;
+            let rel = path.strip_prefix(data_dir).unwrap_or(&path).display().to_string();
+            let content_hash = compute_content_hash(&content).to_string();
+
+            if let Some(cached) = cache
+                .and_then(|c| c.files.get(&rel))
+                .filter(|f| f.content_hash == content_hash)
+            {
+                stats.files_cached += 1;
+                stats.chunks += cached.chunks.len();
+                files.insert(rel, cached.clone());
+                continue;
+            }
+
+            let title = RE_TITLE
+                .captures(&content)
+                .map(|cap| cap[1].trim().to_string())
+                .unwrap_or_else(|| {
+                    path.file_stem()
+                        .map(|s| s.to_string_lossy().into_owned())
+                        .unwrap_or_default()
+                });
+            let pieces = chunk_document(&content, chunk_words);
+            let inputs: Vec<String> = pieces
+                .iter()
+                .map(|(_, _, text)| format!("{}\n{}", title, text))
+                .collect();
+            let vectors = embedder.embed(&inputs)?;
+            let chunks: Vec<IndexedChunk> = pieces
+                .into_iter()
+                .zip(vectors)
+                .map(|((line_start, line_end, text), vector)| IndexedChunk {
+                    line_start,
+                    line_end,
+                    text,
+                    vector,
+                })
+                .collect();
+
+            stats.files_embedded += 1;
+            stats.chunks += chunks.len();
+            files.insert(
+                rel,
+                IndexedFile {
+                    content_hash,
+                    title,
+                    chunks,
+                },
+            );
+        }
+
+        let index = Self {
+            model,
+            built: chrono::Utc::now().to_rfc3339(),
+            files,
+        };
+        Ok((index, stats))
+    }
+
+    /// Carga el índice configurado y verifica que coincida con el modelo.
+    pub fn open(config: &EmbeddingsConfig, embedder: &Embedder) -> OcResult<Self> {
+        let index = Self::load(&config.index_path)?.ok_or_else(|| {
+            OcError::Custom(format!(
+                "No existe índice en {} (ejecute `index build --embeddings`)",
+                config.index_path.display()
+            ))
+        })?;
+        if index.model != embedder.model_id() {
+            return Err(OcError::Custom(format!(
+                "El índice se construyó con '{}' pero la config usa '{}' (reconstruya con `index build --embeddings`)",
+                index.model,
+                embedder.model_id()
+            )));
+        }
+        Ok(index)
+    }
+
+    /// Los `top` fragmentos más similares a la consulta.
+    pub fn search_chunks(&self, query: &[f32], top: usize) -> Vec<ChunkHit> {
+        let mut hits: Vec<ChunkHit> = self
+            .files
+            .iter()
+            .flat_map(|(path, file)| {
+                file.chunks.iter().map(move |chunk| ChunkHit {
+                    path: path.clone(),
+                    title: file.title.clone(),
+                    line_start: chunk.line_start,
+                    line_end: chunk.line_end,
+                    text: chunk.text.clone(),
+                    score: cosine(query, &chunk.vector),
+                })
+            })
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(top);
+        hits
+    }
+
+    /// Los `top` documentos más similares (score del mejor fragmento).
+    pub fn search_docs(&self, query: &[f32], top: usize) -> Vec<DocHit> {
+        let mut hits: Vec<DocHit> = self
+            .files
+            .iter()
+            .filter_map(|(path, file)| {
+                let score = file
+                    .chunks
+                    .iter()
+                    .map(|c| cosine(query, &c.vector))
+                    .max_by(f32::total_cmp)?;
+                Some(DocHit {
+                    path: path.clone(),
+                    title: file.title.clone(),
+                    score,
+                })
+            })
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(top);
+        hits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_embedder() -> Embedder {
+        Embedder::new(EmbeddingsConfig::default())
+    }
+
+    #[test]
+    fn test_hash_embed_similarity() {
+        let e = hash_embedder();
+        let v = e
+            .embed(&[
+                "cálculo de la comisión mensual".to_string(),
+                "cómo se calcula la comision del mes".to_string(),
+                "alta de operador en el sistema".to_string(),
+            ])
+            .unwrap();
+        assert!((cosine(&v[0], &v[0]) - 1.0).abs() < 1e-5);
+        assert!(cosine(&v[0], &v[1]) > cosine(&v[0], &v[2]));
+    }
+
+    #[test]
+    fn test_chunk_document_lines() {
+        let doc = "---\ntitle: X\n---\n# X\n\nIntro con varias palabras.\n\n## Pasos\n\nPrimero se hace esto.\n";
+        let chunks = chunk_document(doc, 200);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!((chunks[0].0, chunks[0].1), (4, 7));
+        assert_eq!(chunks[1].0, 8);
+        assert!(chunks[1].2.contains("Primero se hace esto."));
+    }
+
+    #[test]
+    fn test_build_reuses_cache_and_searches() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("1.1 comision.md"),
+            "---\ntitle: \"Comisiones\"\n---\n\nLa comisión se calcula sobre el monto mensual.\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("1.2 operador.md"),
+            "---\ntitle: \"Operadores\"\n---\n\nEl alta de operador requiere aprobación.\n",
+        )
+        .unwrap();
+
+        let e = hash_embedder();
+        let (index, stats) = EmbeddingIndex::build(dir.path(), &e, 200, None).unwrap();
+        assert_eq!(stats.files_embedded, 2);
+
+        let (_, stats) = EmbeddingIndex::build(dir.path(), &e, 200, Some(&index)).unwrap();
+        assert_eq!(stats.files_cached, 2);
+        assert_eq!(stats.files_embedded, 0);
+
+        let query = e.embed(&["cómo se calcula la comisión".to_string()]).unwrap();
+        let docs = index.search_docs(&query[0], 1);
+        assert_eq!(docs[0].path, "1.1 comision.md");
+        let chunks = index.search_chunks(&query[0], 5);
+        assert_eq!(chunks[0].line_start, 4);
+    }
+}
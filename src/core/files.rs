@@ -4,26 +4,54 @@
 //! - Escanear directorios buscando archivos .md
 //! - Leer y escribir archivos con manejo de errores
 //! - Operaciones atómicas y backups
+//!
+//! El escaneo sigue symlinks por defecto: los ciclos, los alias de un
+//! archivo ya incluido y los documentos que viven fuera del directorio
+//! escaneado se devuelven como [`ScanIssue`] (ver [`scan_md_files`]) en vez
+//! de descartarse o duplicarse en silencio.
 
 use crate::errors::{OcError, OcResult};
+use std::cmp::Ordering;
 use std::fs::{self};
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
-use walkdir::{DirEntry, WalkDir};
+use walkdir::WalkDir;
+
+/// Archivo de exclusiones en la raíz del directorio escaneado: una ruta por
+/// línea (`#` comenta), con la misma semántica que `exclude_patterns`.
+pub const OCIGNORE_FILE: &str = ".ocignore";
 
 /// Opciones para escaneo de archivos.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct ScanOptions {
     /// Patrones a excluir (glob-like).
     pub exclude_patterns: Vec<String>,
     /// Profundidad máxima de recursión (0 = infinito).
     pub max_depth: usize,
-    /// Seguir symlinks.
+    /// Seguir symlinks a directorios (los ciclos se detectan y reportan).
     pub follow_symlinks: bool,
+    /// Omitir symlinks por completo (archivos y directorios).
+    pub ignore_symlinks: bool,
     /// Incluir archivos ocultos.
     pub include_hidden: bool,
     /// RFC-04: Solo procesar archivos en la raíz (no recursivo).
     pub root_only: bool,
+    /// Directorios derivados a omitir (ver [`in_dirs`]).
+    pub skip_dirs: Vec<String>,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            exclude_patterns: Vec::new(),
+            max_depth: 0,
+            follow_symlinks: true,
+            ignore_symlinks: false,
+            include_hidden: false,
+            root_only: false,
+            skip_dirs: Vec::new(),
+        }
+    }
 }
 
 impl ScanOptions {
@@ -31,6 +59,12 @@ impl ScanOptions {
         Self::default()
     }
 
+    /// Omitir symlinks en vez de seguirlos.
+    pub fn with_ignore_symlinks(mut self, ignore: bool) -> Self {
+        self.ignore_symlinks = ignore;
+        self
+    }
+
     pub fn with_excludes(mut self, patterns: Vec<String>) -> Self {
         self.exclude_patterns = patterns;
         self
@@ -51,6 +85,29 @@ impl ScanOptions {
         self.root_only = root_only;
         self
     }
+
+    /// Omitir los archivos dentro de estos directorios (`derived_dirs`).
+    pub fn with_skip_dirs(mut self, dirs: Vec<String>) -> Self {
+        self.skip_dirs = dirs;
+        self
+    }
+}
+
+/// ¿Está `path` dentro de alguno de `dirs`, relativo a `root`? Un nombre
+/// simple (`_summaries`) coincide con cualquier carpeta de ese nombre; una
+/// ruta (`docs/_generados`) solo desde la raíz.
+pub fn in_dirs(root: &Path, path: &Path, dirs: &[String]) -> bool {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    let Some(parent) = relative.parent() else {
+        return false;
+    };
+    dirs.iter().map(|d| d.trim_matches('/')).filter(|d| !d.is_empty()).any(|dir| {
+        if dir.contains('/') {
+            parent.starts_with(dir)
+        } else {
+            parent.components().any(|c| c.as_os_str() == dir)
+        }
+    })
 }
 
 /// Metadata de un archivo.
@@ -66,15 +123,212 @@ pub struct FileMetadata {
     pub is_symlink: bool,
 }
 
-/// Escanea un directorio buscando archivos markdown.
-pub fn get_all_md_files(dir: impl AsRef<Path>, options: &ScanOptions) -> OcResult<Vec<PathBuf>> {
+/// Orden natural: los tramos de dígitos se comparan por valor, así
+/// "1.9" < "1.10" y "2 x" < "10 y". Los padres ("1 x") quedan antes que sus
+/// hijos ("1.1 y").
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a.chars().peekable(), b.chars().peekable());
+    loop {
+        let (x, y) = match (a.peek(), b.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(&x), Some(&y)) => (x, y),
+        };
+        if x.is_ascii_digit() && y.is_ascii_digit() {
+            let take = |it: &mut std::iter::Peekable<std::str::Chars>| {
+                let mut digits = String::new();
+                while let Some(c) = it.next_if(char::is_ascii_digit) {
+                    digits.push(c);
+                }
+                digits
+            };
+            let (na, nb) = (take(&mut a), take(&mut b));
+            let (ta, tb) = (na.trim_start_matches('0'), nb.trim_start_matches('0'));
+            let ord = ta.len().cmp(&tb.len()).then_with(|| ta.cmp(tb)).then_with(|| na.len().cmp(&nb.len()));
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        } else {
+            if x != y {
+                return x.cmp(&y);
+            }
+            a.next();
+            b.next();
+        }
+    }
+}
+
+/// [`natural_cmp`] componente a componente.
+pub fn natural_path_cmp(a: &Path, b: &Path) -> Ordering {
+    let mut a = a.components();
+    let mut b = b.components();
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => {
+                let ord = natural_cmp(&x.as_os_str().to_string_lossy(), &y.as_os_str().to_string_lossy());
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+        }
+    }
+}
+
+/// Recorrido con las entradas de cada directorio en orden natural, para que
+/// la salida no dependa del orden del sistema de archivos.
+pub fn walk_sorted(dir: impl AsRef<Path>) -> WalkDir {
+    WalkDir::new(dir).sort_by(|a, b| natural_cmp(&a.file_name().to_string_lossy(), &b.file_name().to_string_lossy()))
+}
+
+/// Problema de un escaneo con symlinks. Las rutas son relativas al
+/// directorio escaneado; `target` es la ruta real.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScanIssue {
+    /// Symlink a un directorio que lo contiene (se omite).
+    SymlinkLoop { link: PathBuf, target: PathBuf },
+    /// Segundo camino al mismo archivo (se omite; queda `original`).
+    Duplicate { path: PathBuf, original: PathBuf },
+    /// Documento o directorio enlazado que vive fuera del escaneado (se incluye).
+    Outside { path: PathBuf, target: PathBuf },
+}
+
+impl std::fmt::Display for ScanIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScanIssue::SymlinkLoop { link, target } => {
+                write!(f, "{}: ciclo de symlinks (apunta a {})", link.display(), target.display())
+            }
+            ScanIssue::Duplicate { path, original } => {
+                write!(f, "{}: symlink a {} (ya incluido, se omite)", path.display(), original.display())
+            }
+            ScanIssue::Outside { path, target } => {
+                write!(f, "{}: fuera del data_dir (→ {})", path.display(), target.display())
+            }
+        }
+    }
+}
+
+/// Resultado de [`scan_md_files`].
+#[derive(Debug, Clone, Default)]
+pub struct MdScan {
+    pub files: Vec<PathBuf>,
+    pub issues: Vec<ScanIssue>,
+}
+
+/// Recorre `walker` (sobre `dir`) con la política de symlinks de `options`
+/// y devuelve los `.md` que acepta `keep`. Solo si aparece algún symlink se
+/// resuelven las rutas reales para detectar alias y documentos externos.
+pub fn walk_md_files(dir: &Path, walker: WalkDir, options: &ScanOptions, keep: impl Fn(&Path) -> bool) -> MdScan {
+    let relative = |path: &Path| path.strip_prefix(dir).unwrap_or(path).to_path_buf();
+    let mut scan = MdScan::default();
+    let mut symlinks: Vec<PathBuf> = Vec::new();
+
+    let walker = walker.follow_links(options.follow_symlinks && !options.ignore_symlinks);
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                if let (Some(path), Some(target)) = (e.path(), e.loop_ancestor()) {
+                    scan.issues.push(ScanIssue::SymlinkLoop {
+                        link: relative(path),
+                        target: target.to_path_buf(),
+                    });
+                }
+                continue;
+            }
+        };
+        let path = entry.path();
+        if entry.path_is_symlink() {
+            if options.ignore_symlinks {
+                continue;
+            }
+            symlinks.push(path.to_path_buf());
+        }
+        if path.is_file() && path.extension().is_some_and(|e| e == "md") && keep(path) {
+            scan.files.push(path.to_path_buf());
+        }
+    }
+    if symlinks.is_empty() {
+        return scan;
+    }
+
+    let root = fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+    for link in &symlinks {
+        if let Ok(target) = fs::canonicalize(link) {
+            if !target.starts_with(&root) {
+                scan.issues.push(ScanIssue::Outside {
+                    path: relative(link),
+                    target,
+                });
+            }
+        }
+    }
+
+    // Entre alias del mismo archivo se queda el camino sin symlinks
+    let via_link = |file: &PathBuf| symlinks.iter().any(|link| file.starts_with(link));
+    let mut seen: std::collections::HashMap<PathBuf, PathBuf> = std::collections::HashMap::new();
+    for file in scan.files.iter().filter(|f| !via_link(f)).chain(scan.files.iter().filter(|f| via_link(f))) {
+        let real = fs::canonicalize(file).unwrap_or_else(|_| file.clone());
+        match seen.get(&real) {
+            Some(original) => scan.issues.push(ScanIssue::Duplicate {
+                path: relative(file),
+                original: relative(original),
+            }),
+            None => {
+                seen.insert(real, file.clone());
+            }
+        }
+    }
+    let kept: std::collections::HashSet<PathBuf> = seen.into_values().collect();
+    scan.files.retain(|file| kept.contains(file));
+    scan
+}
+
+/// Ruta real de `path` si queda fuera de `dir` (siguiendo symlinks y `..`).
+pub fn outside_dir(path: &Path, dir: &Path) -> Option<PathBuf> {
+    let root = fs::canonicalize(dir).ok()?;
+    fs::canonicalize(path).ok().filter(|real| !real.starts_with(&root))
+}
+
+/// Ruta `relative` (bajo `base`) con las mayúsculas reales de cada
+/// componente, comparando sin distinguir mayúsculas; None si no existe.
+/// Permite simular un filesystem case-sensitive en macOS/Windows.
+pub fn real_case_path(base: &Path, relative: &Path) -> Option<PathBuf> {
+    let mut real = PathBuf::new();
+    for component in relative.components() {
+        let std::path::Component::Normal(name) = component else {
+            real.push(component);
+            continue;
+        };
+        let wanted = name.to_string_lossy().to_lowercase();
+        let entries = fs::read_dir(base.join(&real)).ok()?;
+        let names: Vec<std::ffi::OsString> = entries.filter_map(|e| e.ok()).map(|e| e.file_name()).collect();
+        let found = names
+            .iter()
+            .find(|n| n.as_os_str() == name)
+            .or_else(|| names.iter().find(|n| n.to_string_lossy().to_lowercase() == wanted))?;
+        real.push(found);
+    }
+    Some(real)
+}
+
+/// Escanea un directorio buscando archivos markdown, en orden natural, con
+/// los problemas de symlinks encontrados.
+pub fn scan_md_files(dir: impl AsRef<Path>, options: &ScanOptions) -> OcResult<MdScan> {
     let dir = dir.as_ref();
 
     if !dir.exists() {
         return Err(OcError::DirectoryNotFound(dir.to_path_buf()));
     }
 
-    let mut walker = WalkDir::new(dir).follow_links(options.follow_symlinks);
+    let mut options = options.clone();
+    options.exclude_patterns.extend(ocignore_patterns(dir));
+
+    let mut walker = walk_sorted(dir);
 
     // RFC-04: Si root_only, limitar profundidad a 1 (solo archivos directos)
     if options.root_only {
@@ -83,31 +337,32 @@ pub fn get_all_md_files(dir: impl AsRef<Path>, options: &ScanOptions) -> OcResul
         walker = walker.max_depth(options.max_depth);
     }
 
-    let files: Vec<PathBuf> = walker
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| is_valid_md_file(e, options))
-        .map(|e| e.path().to_path_buf())
-        .collect();
-
-    Ok(files)
+    Ok(walk_md_files(dir, walker, &options, |path| {
+        is_valid_md_file(path, &options) && !in_dirs(dir, path, &options.skip_dirs)
+    }))
 }
 
-/// Verifica si una entrada es un archivo markdown válido.
-fn is_valid_md_file(entry: &DirEntry, options: &ScanOptions) -> bool {
-    let path = entry.path();
-
-    // Debe ser archivo
-    if !path.is_file() {
-        return false;
-    }
+/// Escanea un directorio buscando archivos markdown, en orden natural.
+pub fn get_all_md_files(dir: impl AsRef<Path>, options: &ScanOptions) -> OcResult<Vec<PathBuf>> {
+    scan_md_files(dir, options).map(|scan| scan.files)
+}
 
-    // Debe tener extensión .md
-    let ext = path.extension().and_then(|e| e.to_str());
-    if ext != Some("md") {
-        return false;
-    }
+/// Patrones del `.ocignore` de `dir` (ninguno si no existe).
+pub fn ocignore_patterns(dir: &Path) -> Vec<String> {
+    std::fs::read_to_string(dir.join(OCIGNORE_FILE))
+        .map(|content| {
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
 
+/// Verifica si un `.md` pasa los filtros de ocultos y exclusiones.
+fn is_valid_md_file(path: &Path, options: &ScanOptions) -> bool {
     // Verificar archivos ocultos
     if !options.include_hidden {
         if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
@@ -320,6 +575,54 @@ mod tests {
         assert!(files[0].ends_with("test.md"));
     }
 
+    #[test]
+    fn test_natural_order() {
+        let mut names = vec!["1.10 b", "10 z", "1.2 a", "1 raiz", "2 y", "1.2.1 c"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(names, vec!["1 raiz", "1.2 a", "1.2.1 c", "1.10 b", "2 y", "10 z"]);
+
+        let dir = tempdir().unwrap();
+        for name in ["10 z.md", "2 y.md", "1.10 b.md", "1.2 a.md"] {
+            fs::write(dir.path().join(name), "").unwrap();
+        }
+        let files = get_all_md_files(dir.path(), &ScanOptions::default()).unwrap();
+        let names: Vec<_> = files.iter().map(|f| f.file_name().unwrap().to_str().unwrap()).collect();
+        assert_eq!(names, vec!["1.2 a.md", "1.10 b.md", "2 y.md", "10 z.md"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_symlinks() {
+        use std::os::unix::fs::symlink;
+
+        let dir = tempdir().unwrap();
+        let data = dir.path().join("Datos");
+        let external = dir.path().join("externo");
+        fs::create_dir_all(data.join("sub")).unwrap();
+        fs::create_dir_all(&external).unwrap();
+        fs::write(data.join("1 a.md"), "# A").unwrap();
+        fs::write(data.join("sub").join("2 b.md"), "# B").unwrap();
+        fs::write(external.join("9 ext.md"), "# Ext").unwrap();
+        symlink(data.join("1 a.md"), data.join("0 alias.md")).unwrap();
+        symlink(&data, data.join("sub").join("ciclo")).unwrap();
+        symlink(&external, data.join("ext")).unwrap();
+
+        let scan = scan_md_files(&data, &ScanOptions::new()).unwrap();
+        let files: Vec<PathBuf> = scan.files.iter().map(|f| f.strip_prefix(&data).unwrap().to_path_buf()).collect();
+        assert_eq!(files, vec![PathBuf::from("1 a.md"), PathBuf::from("ext/9 ext.md"), PathBuf::from("sub/2 b.md")]);
+        assert_eq!(scan.issues.len(), 3, "{:?}", scan.issues);
+        assert!(scan.issues.contains(&ScanIssue::Duplicate {
+            path: PathBuf::from("0 alias.md"),
+            original: PathBuf::from("1 a.md"),
+        }));
+        assert!(scan.issues.iter().any(|i| matches!(i, ScanIssue::SymlinkLoop { link, .. } if link == Path::new("sub/ciclo"))));
+        assert!(scan.issues.iter().any(|i| i.to_string().starts_with("ext: fuera del data_dir")));
+
+        let ignored = scan_md_files(&data, &ScanOptions::new().with_ignore_symlinks(true)).unwrap();
+        assert_eq!(ignored.files.len(), 2);
+        assert!(ignored.issues.is_empty());
+    }
+
     #[test]
     fn test_read_write_file() {
         let dir = tempdir().unwrap();
@@ -398,5 +701,28 @@ mod tests {
         let files = get_all_md_files(dir.path(), &options).unwrap();
         assert_eq!(files.len(), 1);
         assert!(files[0].ends_with("good.md"));
+
+        fs::write(dir.path().join(OCIGNORE_FILE), "# borradores\n\ngood.md\n").unwrap();
+        assert!(get_all_md_files(dir.path(), &options).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_skip_derived_dirs() {
+        let dir = tempdir().unwrap();
+        for sub in ["_summaries", "docs/_exports", "guias/_prompts", "mis_summaries"] {
+            fs::create_dir_all(dir.path().join(sub)).unwrap();
+            fs::write(dir.path().join(sub).join("a.md"), "A").unwrap();
+        }
+        fs::write(dir.path().join("_summaries.md"), "B").unwrap();
+
+        let dirs = vec!["_summaries".to_string(), "_prompts/".to_string(), "docs/_exports".to_string()];
+        let options = ScanOptions::new().with_skip_dirs(dirs);
+        let files: Vec<String> = get_all_md_files(dir.path(), &options)
+            .unwrap()
+            .iter()
+            .map(|f| f.strip_prefix(dir.path()).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect();
+        assert_eq!(files, vec!["_summaries.md", "mis_summaries/a.md"]);
+        assert!(!in_dirs(dir.path(), &dir.path().join("guias/docs/_exports/a.md"), &["docs/_exports".to_string()]));
     }
 }
@@ -152,7 +152,7 @@ impl FixRouter {
         });
         
         routes.insert(AnomalyType::BreadcrumbInconsistent, FixSuggestion {
-            command: "sync --breadcrumbs".into(),
+            command: "fix --breadcrumbs".into(),
             description: "Regenera breadcrumbs".into(),
             auto_safe: true,
             severity: FixSeverity::Medium,
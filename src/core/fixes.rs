@@ -0,0 +1,303 @@
+//! Aplicación de fixes de todos los subsistemas.
+//!
+//! `fix`, `lint` y `sync` implementan [`Fixable`] sobre un [`FixSource`]
+//! (comando + data_dir). [`run_fixes`] ordena las fuentes por [`FixStage`] y
+//! recolecta los fixes de cada una recién cuando le toca, así ve en disco lo
+//! que dejaron las anteriores: los renombres se aplican antes de reescribir
+//! contenido y los hashes se recalculan al final. Dentro de cada fuente los
+//! fixes se aplican en el orden de [`RepairPlan`] (padres antes que hijos).
+//! [`write_blocks`] pre-chequea los archivos que no se podrían escribir, para
+//! no fallar a mitad de camino.
+
+use crate::core::patch::{FilePatch, PatchReview, DEFAULT_CONTEXT};
+use crate::errors::{OcError, OcResult};
+use crate::quantum::planner::RepairPlan;
+use crate::traits::{Fix, FixResult, Fixable};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Comando de un subsistema junto al data_dir sobre el que propone fixes y
+/// las opciones de revisión con que se aplican.
+#[derive(Debug, Clone)]
+pub struct FixSource<C> {
+    pub command: C,
+    pub data_dir: PathBuf,
+    pub review: PatchReview,
+}
+
+impl<C> FixSource<C> {
+    pub fn new(command: C, data_dir: impl Into<PathBuf>, review: PatchReview) -> Self {
+        Self {
+            command,
+            data_dir: data_dir.into(),
+            review,
+        }
+    }
+}
+
+/// Diff unificado de un fix de edición (`None` para renombres).
+pub fn render_diff(fix: &Fix, color: bool) -> Option<String> {
+    let (file, before, after) = (fix.file.as_ref()?, fix.before.as_deref()?, fix.after.as_deref()?);
+    let patch = FilePatch::compute(file, before, after, DEFAULT_CONTEXT);
+    (!patch.is_empty()).then(|| patch.render(color))
+}
+
+/// Aplica un fix con las opciones de revisión (`--diff`, `--interactive`,
+/// `--dry-run`). Un fix de edición falla si el archivo cambió desde que se
+/// propuso.
+pub fn apply(fix: &Fix, review: &PatchReview) -> OcResult<FixResult> {
+    let Some(file) = &fix.file else {
+        return Ok(FixResult::Failed {
+            reason: "fix sin archivo".to_string(),
+        });
+    };
+
+    if let Some(target) = &fix.rename_to {
+        if target.exists() {
+            return Ok(FixResult::Failed {
+                reason: format!("destino {} ya existe", target.display()),
+            });
+        }
+        if !review.dry_run {
+            std::fs::rename(file, target).map_err(|e| OcError::FileWrite {
+                path: target.clone(),
+                source: e,
+            })?;
+        }
+        return Ok(FixResult::Applied {
+            description: fix.description.clone(),
+        });
+    }
+
+    let (Some(before), Some(after)) = (fix.before.as_deref(), fix.after.as_deref()) else {
+        return Ok(FixResult::Failed {
+            reason: "fix sin contenido".to_string(),
+        });
+    };
+    // En dry-run las etapas previas no escribieron: el disco puede diferir
+    if !review.dry_run && std::fs::read_to_string(file).ok().as_deref() != Some(before) {
+        return Ok(FixResult::Failed {
+            reason: format!("{} cambió desde que se propuso el fix", file.display()),
+        });
+    }
+    if review.write(file, before, after)? {
+        Ok(FixResult::Applied {
+            description: fix.description.clone(),
+        })
+    } else {
+        Ok(FixResult::NotNeeded)
+    }
+}
+
+/// Resultado de aplicar fixes de varias fuentes.
+#[derive(Debug, Clone, Default)]
+pub struct FixReport {
+    pub applied: Vec<Fix>,
+    pub failed: Vec<(Fix, String)>,
+    /// Sin cambios o rechazados en `--interactive`.
+    pub not_needed: usize,
+    /// Omitidos por tocar un archivo que no se puede escribir.
+    pub skipped: Vec<Fix>,
+}
+
+impl FixReport {
+    /// Cambios aplicados (filas, campos, enlaces).
+    pub fn changes(&self) -> usize {
+        self.applied.iter().map(|f| f.changes).sum()
+    }
+
+    /// Archivos distintos tocados (un renombre cuenta por su destino).
+    pub fn files(&self) -> usize {
+        self.applied
+            .iter()
+            .filter_map(|f| f.rename_to.as_ref().or(f.file.as_ref()))
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
+
+/// Plan de los fixes automáticos que proponen hoy todas las fuentes. Las
+/// etapas posteriores se recalculan al ejecutar sobre lo que dejen las
+/// anteriores, así que sus pasos pueden cambiar.
+pub fn plan_fixes(sources: &mut [Box<dyn Fixable + '_>]) -> RepairPlan {
+    sources.sort_by_key(|s| s.fix_stage());
+    let fixes = sources
+        .iter()
+        .flat_map(|source| source.suggest_fixes())
+        .filter(|f| f.auto_apply)
+        .collect();
+    RepairPlan::new(fixes)
+}
+
+/// Aplica los fixes automáticos de todas las fuentes, ordenadas por etapa.
+pub fn run_fixes(sources: &mut [Box<dyn Fixable + '_>]) -> FixReport {
+    run_fixes_skipping(sources, &HashSet::new())
+}
+
+/// Como [`run_fixes`], omitiendo los fixes sobre los archivos de `skip`.
+pub fn run_fixes_skipping(sources: &mut [Box<dyn Fixable + '_>], skip: &HashSet<PathBuf>) -> FixReport {
+    sources.sort_by_key(|s| s.fix_stage());
+    let mut report = FixReport::default();
+    for source in sources.iter_mut() {
+        let fixes = source.suggest_fixes().into_iter().filter(|f| f.auto_apply).collect();
+        for fix in RepairPlan::new(fixes).into_fixes() {
+            if fix.file.as_ref().is_some_and(|f| skip.contains(f)) {
+                report.skipped.push(fix);
+                continue;
+            }
+            match source.apply_fix(&fix) {
+                Ok(FixResult::Applied { .. }) => report.applied.push(fix),
+                Ok(FixResult::Failed { reason }) => report.failed.push((fix, reason)),
+                Ok(_) => report.not_needed += 1,
+                Err(e) => {
+                    let reason = e.to_string();
+                    report.failed.push((fix, reason));
+                }
+            }
+        }
+    }
+    report
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PREFLIGHT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Motivo por el que un fix no debería escribir un archivo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteBlock {
+    ReadOnly,
+    /// Fuera del repo git del proyecto: el cambio no quedaría versionado.
+    OutsideRepo,
+}
+
+impl WriteBlock {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::ReadOnly => "solo lectura",
+            Self::OutsideRepo => "fuera del repo git",
+        }
+    }
+}
+
+/// Raíz del repo git que contiene `dir`, si hay uno.
+fn git_toplevel(dir: &Path) -> Option<PathBuf> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let root = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    PathBuf::from(root).canonicalize().ok()
+}
+
+/// Archivos de `files` que no se pueden escribir: de solo lectura o, si
+/// `data_dir` está en un repo git, fuera de él (p. ej. symlinks a otro lado).
+pub fn write_blocks(files: &[PathBuf], data_dir: &Path) -> Vec<(PathBuf, WriteBlock)> {
+    let root = git_toplevel(data_dir);
+    files
+        .iter()
+        .filter_map(|file| {
+            let readonly = std::fs::metadata(file).is_ok_and(|m| m.permissions().readonly());
+            let outside = root
+                .as_ref()
+                .is_some_and(|root| file.canonicalize().is_ok_and(|real| !real.starts_with(root)));
+            let block = if readonly {
+                WriteBlock::ReadOnly
+            } else if outside {
+                WriteBlock::OutsideRepo
+            } else {
+                return None;
+            };
+            Some((file.clone(), block))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::FixStage;
+
+    /// Fuente que propone fixes a partir de lo que lee en disco.
+    struct Upper {
+        path: PathBuf,
+        stage: FixStage,
+    }
+
+    impl Fixable for Upper {
+        fn fix_stage(&self) -> FixStage {
+            self.stage
+        }
+
+        fn suggest_fixes(&self) -> Vec<Fix> {
+            let before = std::fs::read_to_string(&self.path).unwrap();
+            let after = match self.stage {
+                FixStage::Content => before.to_uppercase(),
+                _ => format!("{}hash: {}\n", before, before.len()),
+            };
+            vec![Fix::edit("T", "test", &self.path, before, after).in_stage(self.stage)]
+        }
+
+        fn apply_fix(&mut self, fix: &Fix) -> OcResult<FixResult> {
+            apply(fix, &PatchReview::default())
+        }
+    }
+
+    #[test]
+    fn test_run_fixes_in_stage_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.md");
+        std::fs::write(&path, "abc\n").unwrap();
+
+        // Declaradas al revés: la de metadatos debe ver el contenido ya corregido
+        let mut sources: Vec<Box<dyn Fixable>> = vec![
+            Box::new(Upper { path: path.clone(), stage: FixStage::Metadata }),
+            Box::new(Upper { path: path.clone(), stage: FixStage::Content }),
+        ];
+        let report = run_fixes(&mut sources);
+        assert_eq!(report.applied.len(), 2);
+        assert_eq!((report.files(), report.changes()), (1, 2));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "ABC\nhash: 4\n");
+    }
+
+    #[test]
+    fn test_write_blocks_and_skip() {
+        let dir = tempfile::tempdir().unwrap();
+        let (a, b) = (dir.path().join("a.md"), dir.path().join("b.md"));
+        std::fs::write(&a, "abc\n").unwrap();
+        std::fs::write(&b, "def\n").unwrap();
+        let mut perms = std::fs::metadata(&b).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(&b, perms).unwrap();
+
+        let blocked = write_blocks(&[a.clone(), b.clone()], dir.path());
+        assert_eq!(blocked, vec![(b.clone(), WriteBlock::ReadOnly)]);
+
+        let mut sources: Vec<Box<dyn Fixable>> = vec![
+            Box::new(Upper { path: a.clone(), stage: FixStage::Content }),
+            Box::new(Upper { path: b.clone(), stage: FixStage::Content }),
+        ];
+        let skip = blocked.into_iter().map(|(path, _)| path).collect();
+        let report = run_fixes_skipping(&mut sources, &skip);
+        assert_eq!((report.applied.len(), report.skipped.len()), (1, 1));
+        assert_eq!(std::fs::read_to_string(&b).unwrap(), "def\n");
+    }
+
+    #[test]
+    fn test_apply_rejects_stale_fix() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.md");
+        std::fs::write(&path, "nuevo\n").unwrap();
+        let fix = Fix::edit("T", "test", &path, "viejo\n", "corregido\n");
+        assert!(apply(&fix, &PatchReview::default()).unwrap().is_failure());
+        assert!(render_diff(&fix, false).unwrap().contains("+corregido"));
+
+        let rename = Fix::rename("T", "mover", &path, dir.path().join("b.md"));
+        assert!(apply(&rename, &PatchReview::default()).unwrap().is_success());
+        assert!(dir.path().join("b.md").exists());
+    }
+}
@@ -0,0 +1,275 @@
+//! Caché de frontmatter parseado, compartida entre comandos.
+//!
+//! `.oc_diagdoc/cache/frontmatter.bin` (bincode) guarda el frontmatter de
+//! cada documento junto con el mtime y el content_hash del archivo. La etapa
+//! Parse de [`crate::core::pipeline`] (el `DocumentCache` de tree, stats,
+//! verify, lint, deps y links) y el loader ([`crate::core::loader`]) la
+//! consultan antes de parsear YAML: si el mtime o el hash cambiaron, se
+//! vuelve a parsear y se reemplaza la entrada; las de archivos que ya no
+//! existen se descartan al guardar. El binario la activa al inicio de cada
+//! comando (solo en proyectos con `.oc_diagdoc/`) y la guarda al terminar;
+//! `cache stats` y `cache clear` la inspeccionan y la borran. Sin la feature
+//! `cache` se parsea siempre.
+
+use crate::core::config::CONFIG_DIR;
+use crate::core::hash::compute_content_hash;
+use crate::core::yaml::{parse_frontmatter, split_document, ParsedDocument, YamlFrontmatter};
+use crate::errors::{OcError, OcResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+/// Caché dentro de `.oc_diagdoc/`.
+pub const FRONTMATTER_CACHE: &str = "cache/frontmatter.bin";
+
+/// Versión del formato; una caché de otra versión se descarta.
+const CACHE_VERSION: u32 = 1;
+
+/// Ruta de la caché del proyecto actual.
+pub fn frontmatter_cache_path() -> PathBuf {
+    Path::new(CONFIG_DIR).join(FRONTMATTER_CACHE)
+}
+
+/// Frontmatter de un archivo tal como estaba al parsearlo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFrontmatter {
+    mtime: SystemTime,
+    content_hash: String,
+    frontmatter: YamlFrontmatter,
+}
+
+/// Estado de la caché (`cache stats`).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CacheStats {
+    pub path: PathBuf,
+    pub entries: usize,
+    pub size_bytes: u64,
+}
+
+/// Caché de frontmatter indexada por (ruta, mtime, hash).
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FrontmatterCache {
+    version: u32,
+    entries: HashMap<PathBuf, CachedFrontmatter>,
+    /// Documentos servidos desde la caché y parseados en esta ejecución.
+    #[serde(skip)]
+    hits: usize,
+    #[serde(skip)]
+    misses: usize,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl FrontmatterCache {
+    /// Caché en `path`; vacía si falta, no se entiende o es de otra versión.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read(path)
+            .ok()
+            .and_then(|bytes| decode(&bytes))
+            .filter(|cache| cache.version == CACHE_VERSION)
+            .unwrap_or_else(|| Self {
+                version: CACHE_VERSION,
+                ..Default::default()
+            })
+    }
+
+    /// Guarda la caché sin las entradas de archivos borrados.
+    pub fn save(&mut self, path: &Path) -> OcResult<()> {
+        self.entries.retain(|file, _| file.is_file());
+        let bytes = encode(self)?;
+        let write_err = |e: std::io::Error| OcError::FileWrite {
+            path: path.to_path_buf(),
+            source: e,
+        };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(write_err)?;
+        }
+        std::fs::write(path, bytes).map_err(write_err)?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Parsea el documento, reutilizando el frontmatter si el archivo tiene
+    /// el mismo mtime y hash que cuando se guardó.
+    pub fn parse(&mut self, path: &Path, content: &str) -> OcResult<ParsedDocument> {
+        let key = CacheKey::new(path, content);
+        if let Some(frontmatter) = self.lookup(path, &key) {
+            return with_frontmatter(content, frontmatter);
+        }
+        let parsed = parse_frontmatter(content)?;
+        self.store(path, key, &parsed.frontmatter);
+        Ok(parsed)
+    }
+
+    /// Frontmatter guardado si el archivo no cambió; cuenta el hit o el miss.
+    fn lookup(&mut self, path: &Path, key: &CacheKey) -> Option<YamlFrontmatter> {
+        let found = self
+            .entries
+            .get(path)
+            .filter(|c| Some(c.mtime) == key.mtime && c.content_hash == key.content_hash)
+            .map(|c| c.frontmatter.clone());
+        if found.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        found
+    }
+
+    fn store(&mut self, path: &Path, key: CacheKey, frontmatter: &YamlFrontmatter) {
+        if let Some(mtime) = key.mtime {
+            let entry = CachedFrontmatter {
+                mtime,
+                content_hash: key.content_hash,
+                frontmatter: frontmatter.clone(),
+            };
+            self.entries.insert(path.to_path_buf(), entry);
+            self.dirty = true;
+        }
+    }
+
+    pub fn stats(&self, path: &Path) -> CacheStats {
+        CacheStats {
+            path: path.to_path_buf(),
+            entries: self.entries.len(),
+            size_bytes: std::fs::metadata(path).map_or(0, |m| m.len()),
+        }
+    }
+}
+
+/// mtime y hash con los que se valida una entrada.
+struct CacheKey {
+    mtime: Option<SystemTime>,
+    content_hash: String,
+}
+
+impl CacheKey {
+    fn new(path: &Path, content: &str) -> Self {
+        Self {
+            mtime: std::fs::metadata(path).and_then(|m| m.modified()).ok(),
+            content_hash: compute_content_hash(content).to_string(),
+        }
+    }
+}
+
+/// Documento con un frontmatter ya parseado.
+fn with_frontmatter(content: &str, frontmatter: YamlFrontmatter) -> OcResult<ParsedDocument> {
+    let (yaml_raw, body) = split_document(content)?;
+    Ok(ParsedDocument {
+        frontmatter,
+        yaml_raw: yaml_raw.to_string(),
+        body,
+    })
+}
+
+#[cfg(feature = "cache")]
+fn encode(cache: &FrontmatterCache) -> OcResult<Vec<u8>> {
+    bincode::serialize(cache).map_err(|e| OcError::Custom(format!("Caché de frontmatter: {}", e)))
+}
+
+#[cfg(feature = "cache")]
+fn decode(bytes: &[u8]) -> Option<FrontmatterCache> {
+    bincode::deserialize(bytes).ok()
+}
+
+#[cfg(not(feature = "cache"))]
+fn encode(_: &FrontmatterCache) -> OcResult<Vec<u8>> {
+    Err(OcError::Custom("Caché de frontmatter no disponible (compilar con la feature `cache`)".to_string()))
+}
+
+#[cfg(not(feature = "cache"))]
+fn decode(_: &[u8]) -> Option<FrontmatterCache> {
+    None
+}
+
+/// Caché activa en esta ejecución, entre [`enable`] y [`flush`].
+static ACTIVE: Mutex<Option<(PathBuf, FrontmatterCache)>> = Mutex::new(None);
+
+/// Activa la caché del proyecto actual si existe `.oc_diagdoc/`.
+pub fn enable() {
+    if !cfg!(feature = "cache") || !Path::new(CONFIG_DIR).is_dir() {
+        return;
+    }
+    let path = frontmatter_cache_path();
+    let cache = FrontmatterCache::load(&path);
+    *ACTIVE.lock().unwrap() = Some((path, cache));
+}
+
+/// Guarda la caché activa si cambió (una sola vez). Los errores de
+/// escritura se ignoran: la caché nunca hace fallar un comando.
+pub fn flush() {
+    if let Some((path, mut cache)) = ACTIVE.lock().unwrap().take() {
+        if cache.dirty {
+            let _ = cache.save(&path);
+        }
+    }
+}
+
+/// Parsea el frontmatter con la caché activa o, sin ella, directamente.
+/// El YAML se parsea sin tomar el lock, así los lectores en paralelo no se
+/// serializan.
+pub fn parse(path: &Path, content: &str) -> OcResult<ParsedDocument> {
+    if ACTIVE.lock().unwrap().is_none() {
+        return parse_frontmatter(content);
+    }
+    let key = CacheKey::new(path, content);
+    let cached = ACTIVE.lock().unwrap().as_mut().and_then(|(_, cache)| cache.lookup(path, &key));
+    if let Some(frontmatter) = cached {
+        return with_frontmatter(content, frontmatter);
+    }
+    let parsed = parse_frontmatter(content)?;
+    if let Some((_, cache)) = ACTIVE.lock().unwrap().as_mut() {
+        cache.store(path, key, &parsed.frontmatter);
+    }
+    Ok(parsed)
+}
+
+/// Borra la caché en `path`; devuelve si existía.
+pub fn clear(path: &Path) -> OcResult<bool> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(OcError::FileWrite {
+            path: path.to_path_buf(),
+            source: e,
+        }),
+    }
+}
+
+#[cfg(all(test, feature = "cache"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_reuses_and_invalidates() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc = dir.path().join("1.md");
+        let cache_path = dir.path().join(CONFIG_DIR).join(FRONTMATTER_CACHE);
+        std::fs::write(&doc, "---\nid: \"1\"\ntitle: Uno\n---\n# Uno\n").unwrap();
+
+        let mut cache = FrontmatterCache::load(&cache_path);
+        let content = std::fs::read_to_string(&doc).unwrap();
+        assert_eq!(cache.parse(&doc, &content).unwrap().frontmatter.title, "Uno");
+        cache.save(&cache_path).unwrap();
+
+        let mut cache = FrontmatterCache::load(&cache_path);
+        let parsed = cache.parse(&doc, &content).unwrap();
+        assert_eq!((parsed.frontmatter.id.as_str(), parsed.body.as_str()), ("1", "# Uno\n"));
+        assert_eq!((cache.hits, cache.misses, cache.dirty), (1, 0, false));
+
+        // Contenido distinto con la misma ruta: se vuelve a parsear
+        let changed = "---\nid: \"1\"\ntitle: Otro\n---\n";
+        std::fs::write(&doc, changed).unwrap();
+        assert_eq!(cache.parse(&doc, changed).unwrap().frontmatter.title, "Otro");
+        assert_eq!(cache.misses, 1);
+        assert!(cache.parse(&doc, "sin frontmatter").is_err());
+
+        std::fs::remove_file(&doc).unwrap();
+        cache.save(&cache_path).unwrap();
+        assert_eq!(FrontmatterCache::load(&cache_path).stats(&cache_path).entries, 0);
+        assert!(clear(&cache_path).unwrap());
+        assert!(!clear(&cache_path).unwrap());
+    }
+}
@@ -0,0 +1,393 @@
+//! Índice full-text y consultas booleanas.
+//!
+//! `index build --fulltext` guarda en `_index/fulltext.json` la posición de
+//! cada palabra por documento (en minúsculas y sin tildes), cacheada por
+//! `content_hash`. `search --boolean` parte de ese índice (reindexando en
+//! memoria los documentos que cambiaron) para evaluar consultas como
+//! `pago AND (tarjeta OR spei) NOT sandbox` o de proximidad
+//! `"alta operador"~5`.
+
+use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+use crate::core::hash::compute_content_hash;
+use crate::errors::{OcError, OcResult};
+use crate::types::path::slugify;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Índice dentro del data_dir.
+pub const FULLTEXT_INDEX: &str = "_index/fulltext.json";
+
+/// Término normalizado de una palabra: minúsculas y sin tildes.
+pub fn normalize(word: &str) -> String {
+    slugify(word).replace('_', "")
+}
+
+/// Palabras de una línea: rango en bytes (inicio, fin) y término.
+pub fn words(line: &str) -> Vec<(usize, usize, String)> {
+    let mut out = Vec::new();
+    let mut start = None;
+    for (i, c) in line.char_indices().chain(std::iter::once((line.len(), ' '))) {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                out.push((s, i, normalize(&line[s..i])));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ÍNDICE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Documento indexado.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedText {
+    pub content_hash: String,
+    /// Término → posiciones (número de palabra en el documento).
+    pub terms: BTreeMap<String, Vec<u32>>,
+    /// Posición de la primera palabra de cada línea.
+    pub line_starts: Vec<u32>,
+}
+
+impl IndexedText {
+    pub fn from_content(content: &str) -> Self {
+        let mut terms: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+        let mut line_starts = Vec::new();
+        let mut pos = 0;
+        for line in content.lines() {
+            line_starts.push(pos);
+            for (_, _, term) in words(line) {
+                terms.entry(term).or_default().push(pos);
+                pos += 1;
+            }
+        }
+        Self {
+            content_hash: compute_content_hash(content).to_string(),
+            terms,
+            line_starts,
+        }
+    }
+
+    fn has(&self, term: &str, pos: u32) -> bool {
+        self.terms.get(term).is_some_and(|list| list.binary_search(&pos).is_ok())
+    }
+
+    /// Línea (desde 0) y número de palabra dentro de ella.
+    pub fn locate(&self, pos: u32) -> (usize, usize) {
+        let line = self.line_starts.partition_point(|&start| start <= pos).saturating_sub(1);
+        let start = self.line_starts.get(line).copied().unwrap_or_default();
+        (line, (pos - start) as usize)
+    }
+}
+
+/// Estadísticas de construcción.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FullTextStats {
+    pub files_indexed: usize,
+    pub files_cached: usize,
+    pub terms: usize,
+}
+
+/// Índice full-text persistido en JSON.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FullTextIndex {
+    pub built: String,
+    /// Ruta relativa al data_dir → documento.
+    pub files: BTreeMap<String, IndexedText>,
+}
+
+impl FullTextIndex {
+    /// Índice del proyecto; None si falta o no se entiende.
+    pub fn load(data_dir: &Path) -> Option<Self> {
+        std::fs::read_to_string(data_dir.join(FULLTEXT_INDEX))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+    }
+
+    pub fn save(&self, data_dir: &Path) -> OcResult<()> {
+        let path = data_dir.join(FULLTEXT_INDEX);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_string(self)?).map_err(|e| OcError::FileWrite { path, source: e })
+    }
+
+    /// Indexa los documentos reutilizando los de `previous` cuyo
+    /// `content_hash` no cambió.
+    pub fn build(data_dir: &Path, previous: Option<&Self>) -> OcResult<(Self, FullTextStats)> {
+        let mut stats = FullTextStats::default();
+        let mut files = BTreeMap::new();
+        for path in get_all_md_files(data_dir, &ScanOptions::new())? {
+            let content = read_file_content(&path)?;
+            let rel = path.strip_prefix(data_dir).unwrap_or(&path).display().to_string();
+            let content_hash = compute_content_hash(&content).to_string();
+            let doc = match previous.and_then(|p| p.files.get(&rel)).filter(|d| d.content_hash == content_hash) {
+                Some(cached) => {
+                    stats.files_cached += 1;
+                    cached.clone()
+                }
+                None => {
+                    stats.files_indexed += 1;
+                    IndexedText::from_content(&content)
+                }
+            };
+            files.insert(rel, doc);
+        }
+        let index = Self {
+            built: chrono::Utc::now().to_rfc3339(),
+            files,
+        };
+        stats.terms = index.term_count();
+        Ok((index, stats))
+    }
+
+    /// Términos distintos en todo el índice.
+    pub fn term_count(&self) -> usize {
+        self.files.values().flat_map(|d| d.terms.keys()).collect::<std::collections::HashSet<_>>().len()
+    }
+
+    /// Documentos que cumplen la consulta, con las posiciones a resaltar.
+    pub fn search(&self, query: &Query) -> Vec<(&str, Vec<u32>)> {
+        self.files
+            .iter()
+            .filter_map(|(path, doc)| {
+                let mut hits = query.matches(doc)?;
+                hits.sort_unstable();
+                hits.dedup();
+                Some((path.as_str(), hits))
+            })
+            .collect()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONSULTAS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Consulta booleana.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    Term(String),
+    /// Palabras seguidas y en orden (`"alta operador"`).
+    Phrase(Vec<String>),
+    /// Palabras a no más de N posiciones entre sí, en cualquier orden
+    /// (`"alta operador"~5`).
+    Near(Vec<String>, usize),
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Not(Box<Query>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Words(Vec<String>, Option<usize>),
+}
+
+fn invalid(query: &str, reason: &str) -> OcError {
+    OcError::InvalidArgument(format!("Consulta inválida '{}': {}", query, reason))
+}
+
+fn lex(query: &str) -> OcResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = query.trim_start();
+    while let Some(c) = rest.chars().next() {
+        if c == '(' || c == ')' {
+            tokens.push(if c == '(' { Token::Open } else { Token::Close });
+            rest = &rest[1..];
+        } else if c == '"' {
+            let end = rest[1..].find('"').ok_or_else(|| invalid(query, "comillas sin cerrar"))? + 1;
+            let terms: Vec<String> = words(&rest[1..end]).into_iter().map(|(_, _, t)| t).collect();
+            rest = &rest[end + 1..];
+            let mut distance = None;
+            if let Some(after) = rest.strip_prefix('~') {
+                let digits = after.find(|c: char| !c.is_ascii_digit()).unwrap_or(after.len());
+                distance = Some(after[..digits].parse().map_err(|_| invalid(query, "falta la distancia tras `~`"))?);
+                rest = &after[digits..];
+            }
+            if terms.is_empty() {
+                return Err(invalid(query, "frase vacía"));
+            }
+            tokens.push(Token::Words(terms, distance));
+        } else {
+            let end = rest.find(|c: char| c.is_whitespace() || c == '(' || c == ')' || c == '"').unwrap_or(rest.len());
+            let word = &rest[..end];
+            rest = &rest[end..];
+            tokens.push(match word {
+                "AND" => Token::And,
+                "OR" => Token::Or,
+                "NOT" => Token::Not,
+                _ => {
+                    let terms: Vec<String> = words(word).into_iter().map(|(_, _, t)| t).collect();
+                    if terms.is_empty() {
+                        rest = rest.trim_start();
+                        continue;
+                    }
+                    Token::Words(terms, None)
+                }
+            });
+        }
+        rest = rest.trim_start();
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    query: &'a str,
+    tokens: Vec<Token>,
+    next: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next)
+    }
+
+    fn or(&mut self) -> OcResult<Query> {
+        let mut left = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.next += 1;
+            left = Query::Or(Box::new(left), Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    /// Términos seguidos (con o sin `AND`) se combinan con AND; `a NOT b`
+    /// equivale a `a AND NOT b`.
+    fn and(&mut self) -> OcResult<Query> {
+        let mut left = self.unary()?;
+        loop {
+            match self.peek() {
+                Some(Token::And) => self.next += 1,
+                Some(Token::Not | Token::Open | Token::Words(..)) => {}
+                _ => return Ok(left),
+            }
+            left = Query::And(Box::new(left), Box::new(self.unary()?));
+        }
+    }
+
+    fn unary(&mut self) -> OcResult<Query> {
+        let token = self.peek().cloned().ok_or_else(|| invalid(self.query, "falta un término"))?;
+        self.next += 1;
+        match token {
+            Token::Not => Ok(Query::Not(Box::new(self.unary()?))),
+            Token::Open => {
+                let inner = self.or()?;
+                if self.peek() != Some(&Token::Close) {
+                    return Err(invalid(self.query, "paréntesis sin cerrar"));
+                }
+                self.next += 1;
+                Ok(inner)
+            }
+            Token::Words(terms, Some(distance)) => Ok(Query::Near(terms, distance)),
+            Token::Words(mut terms, None) if terms.len() == 1 => Ok(Query::Term(terms.remove(0))),
+            Token::Words(terms, None) => Ok(Query::Phrase(terms)),
+            Token::Close | Token::And | Token::Or => Err(invalid(self.query, "operador sin término")),
+        }
+    }
+}
+
+impl Query {
+    /// Consulta con `AND`, `OR`, `NOT` (en mayúsculas), paréntesis, frases
+    /// entre comillas y proximidad `"a b"~N`.
+    pub fn parse(query: &str) -> OcResult<Self> {
+        let mut parser = Parser {
+            query,
+            tokens: lex(query)?,
+            next: 0,
+        };
+        let parsed = parser.or()?;
+        if parser.peek().is_some() {
+            return Err(invalid(query, "paréntesis sin abrir"));
+        }
+        Ok(parsed)
+    }
+
+    /// Posiciones que cumplen la consulta en el documento, o None si no la
+    /// cumple. `NOT` cumple sin aportar posiciones.
+    pub fn matches(&self, doc: &IndexedText) -> Option<Vec<u32>> {
+        let found = |hits: Vec<u32>| (!hits.is_empty()).then_some(hits);
+        match self {
+            Query::Term(term) => doc.terms.get(term).cloned(),
+            Query::Phrase(terms) => {
+                let hits = doc.terms.get(&terms[0])?
+                    .iter()
+                    .filter(|&&p| terms.iter().zip(p..).skip(1).all(|(term, pos)| doc.has(term, pos)))
+                    .flat_map(|&p| p..p + terms.len() as u32)
+                    .collect();
+                found(hits)
+            }
+            Query::Near(terms, distance) => {
+                let lists: Vec<&Vec<u32>> = terms.iter().map(|t| doc.terms.get(t)).collect::<Option<_>>()?;
+                let mut hits = Vec::new();
+                for &start in lists.iter().copied().flatten() {
+                    let window = start..=start + *distance as u32;
+                    let in_window: Option<Vec<u32>> =
+                        lists.iter().map(|list| list.iter().copied().find(|p| window.contains(p))).collect();
+                    hits.extend(in_window.unwrap_or_default());
+                }
+                hits.sort_unstable();
+                hits.dedup();
+                found(hits)
+            }
+            Query::And(a, b) => {
+                let mut hits = a.matches(doc)?;
+                hits.extend(b.matches(doc)?);
+                Some(hits)
+            }
+            Query::Or(a, b) => match (a.matches(doc), b.matches(doc)) {
+                (None, None) => None,
+                (a, b) => Some(a.into_iter().chain(b).flatten().collect()),
+            },
+            Query::Not(inner) => match inner.matches(doc) {
+                Some(_) => None,
+                None => Some(Vec::new()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boolean_and_proximity_queries() {
+        let term = |t: &str| Box::new(Query::Term(t.to_string()));
+        assert_eq!(
+            Query::parse("pago AND (tarjeta OR spei) NOT sandbox").unwrap(),
+            Query::And(
+                Box::new(Query::And(term("pago"), Box::new(Query::Or(term("tarjeta"), term("spei"))))),
+                Box::new(Query::Not(term("sandbox")))
+            )
+        );
+        assert_eq!(
+            Query::parse("\"Alta Operador\"~5").unwrap(),
+            Query::Near(vec!["alta".to_string(), "operador".to_string()], 5)
+        );
+        for bad in ["pago AND", "(pago", "pago)", "\"pago", "\"a b\"~"] {
+            assert!(Query::parse(bad).is_err(), "{}", bad);
+        }
+
+        let doc = IndexedText::from_content("Pago con tarjeta.\n\nEl alta del nuevo operador de cobro.\nOperador en sandbox");
+        let hits = |q: &str| Query::parse(q).unwrap().matches(&doc);
+        assert_eq!(hits("pago AND (tarjeta OR spei)"), Some(vec![0, 2]));
+        assert!(hits("pago NOT sandbox").is_none());
+        assert!(hits("pago NOT spei").is_some());
+        assert_eq!(hits("\"alta del nuevo\""), Some(vec![4, 5, 6]));
+        assert!(hits("\"alta nuevo\"").is_none());
+        assert_eq!(hits("\"operador alta\"~3"), Some(vec![4, 7]));
+        assert!(hits("\"alta operador\"~2").is_none());
+        assert_eq!(doc.locate(7), (2, 4));
+        assert_eq!(doc.locate(10), (3, 0));
+    }
+}
@@ -5,8 +5,13 @@
 //! - Detección de ciclos
 //! - Análisis de huérfanos y jerarquía
 
+use crate::core::links::extract_links;
+use crate::core::patterns::RE_DESCENDANTS_COUNT;
+use crate::types::breadcrumb::frontmatter_value;
+use crate::types::lifetimes::split_frontmatter;
 use crate::types::DocumentId;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
 
 /// Nodo en el grafo de dependencias.
 #[derive(Debug, Clone)]
@@ -114,6 +119,11 @@ impl DependencyGraph {
         result
     }
 
+    /// Total de descendientes (hijos, nietos, ...).
+    pub fn descendants_count(&self, id: &DocumentId) -> usize {
+        self.get_descendants(id).len()
+    }
+
     /// Obtiene ancestros (recursivo hacia arriba).
     pub fn get_ancestors(&self, id: &DocumentId) -> Vec<DocumentId> {
         let mut result = Vec::new();
@@ -242,6 +252,81 @@ impl DependencyGraph {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// JERARQUÍA DESDE FRONTMATTER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Grafo jerárquico (`id` → `parent`) de los documentos. Se omiten los que
+/// no tienen ID numérico; `parent` vacío o `0` es raíz. Los wiki-links
+/// internos hacia documentos del grafo quedan como enlaces.
+pub fn hierarchy_from_files(files: &[PathBuf]) -> DependencyGraph {
+    let contents: Vec<String> = files
+        .iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .collect();
+    hierarchy_from_contents(contents.iter().map(String::as_str))
+}
+
+/// Como `hierarchy_from_files`, a partir de contenidos ya leídos.
+pub fn hierarchy_from_contents<'a>(contents: impl IntoIterator<Item = &'a str>) -> DependencyGraph {
+    let mut graph = DependencyGraph::new();
+    let mut links: Vec<(DocumentId, Vec<DocumentId>)> = Vec::new();
+    for content in contents {
+        let Some(split) = split_frontmatter(content) else {
+            continue;
+        };
+        let parse = |field: &str| -> Option<DocumentId> {
+            frontmatter_value(split.metadata, field)?.trim_end_matches('.').parse().ok()
+        };
+        if let Some(id) = parse("id") {
+            let parent = parse("parent").filter(|p| p.as_str() != "0");
+            graph.add_node_with_parent(id.clone(), parent);
+            let targets = extract_links(split.content)
+                .iter()
+                .filter(|l| l.is_internal())
+                .filter_map(|l| link_target_id(&l.target))
+                .collect();
+            links.push((id, targets));
+        }
+    }
+    // Al final, para no crear nodos sueltos por links a IDs inexistentes
+    for (from, targets) in links {
+        for to in targets {
+            if to != from && graph.nodes.contains_key(&to) {
+                graph.add_link(from.clone(), to);
+            }
+        }
+    }
+    graph
+}
+
+/// ID del destino de un wiki-link (`[[2.1 Tarjeta#Alta]]` → `2.1`), ya
+/// internado como cualquier [`DocumentId`].
+fn link_target_id(target: &str) -> Option<DocumentId> {
+    let target = target.split(['#', '^', '|']).next()?;
+    let name = target.rsplit(['/', '\\']).next()?;
+    let name = name.strip_suffix(".md").unwrap_or(name);
+    name.split_whitespace().next()?.trim_end_matches('.').parse().ok()
+}
+
+/// Escribe `descendants_count` en el frontmatter. Si el campo no existe
+/// solo se agrega cuando hay descendientes. Devuelve el valor anterior y el
+/// contenido nuevo, o `None` si no hay cambios.
+pub fn apply_descendants_count(content: &str, count: usize) -> Option<(Option<usize>, String)> {
+    let field = format!("descendants_count: {}", count);
+    match RE_DESCENDANTS_COUNT.captures(content) {
+        Some(cap) => {
+            let old: usize = cap[1].parse().ok()?;
+            (old != count).then(|| (Some(old), RE_DESCENDANTS_COUNT.replace(content, field.as_str()).into_owned()))
+        }
+        None if count > 0 => {
+            let updated = crate::core::yaml::add_field(content, "descendants_count", &count.to_string()).ok()?;
+            Some((None, updated))
+        }
+        None => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -336,4 +421,55 @@ mod tests {
         assert!(mermaid.contains("graph TD"));
         assert!(mermaid.contains("1 --> 1.1"));
     }
+
+    #[test]
+    fn test_hierarchy_descendants_count() {
+        let dir = tempfile::tempdir().unwrap();
+        let files: Vec<PathBuf> = [
+            ("1.md", "---\nid: \"1\"\nparent: \"0\"\n---\n"),
+            ("1.1.md", "---\nid: \"1.1\"\nparent: \"1\"\n---\n"),
+            ("1.1.1.md", "---\nid: \"1.1.1\"\nparent: \"1.1\"\n---\n"),
+            ("1.2.md", "---\nid: \"1.2\"\nparent: \"1\"\n---\n"),
+        ]
+        .iter()
+        .map(|(name, body)| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, body).unwrap();
+            path
+        })
+        .collect();
+
+        let graph = hierarchy_from_files(&files);
+        assert_eq!(graph.descendants_count(&id("1")), 3);
+        assert_eq!(graph.descendants_count(&id("1.1")), 1);
+        assert_eq!(graph.descendants_count(&id("1.2")), 0);
+    }
+
+    #[test]
+    fn test_hierarchy_links() {
+        let graph = hierarchy_from_contents([
+            "---\nid: \"1\"\n---\nVer [[1.1 Alta#Datos|alta]], [[9 Nada]] y [[https://x.com]].\n",
+            "---\nid: \"1.1\"\nparent: \"1\"\n---\n![[1]]\n",
+        ]);
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 2);
+        let backlinks = graph.get_backlinks(&id("1.1"));
+        assert_eq!(backlinks, vec![&id("1")]);
+        // Los destinos comparten el string internado del ID
+        assert!(backlinks[0].interned().ptr_eq(id("1").interned()));
+    }
+
+    #[test]
+    fn test_apply_descendants_count() {
+        let (old, updated) = apply_descendants_count("---\nid: 1\ndescendants_count: 2\n---\n", 3).unwrap();
+        assert_eq!(old, Some(2));
+        assert!(updated.contains("descendants_count: 3"));
+
+        let (old, added) = apply_descendants_count("---\nid: 1\n---\n", 4).unwrap();
+        assert_eq!(old, None);
+        assert!(added.contains("descendants_count: 4\n---"));
+
+        assert!(apply_descendants_count("---\nid: 1\n---\n", 0).is_none());
+        assert!(apply_descendants_count("---\ndescendants_count: 3\n---\n", 3).is_none());
+    }
 }
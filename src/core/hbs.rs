@@ -0,0 +1,446 @@
+//! Plantillas estilo Handlebars.
+//!
+//! Subconjunto compatible para `report --template` y `export --template`:
+//! `{{ruta.al.valor}}` (escapa HTML) y `{{{crudo}}}`, bloques `#each` (arrays y objetos, con
+//! `@index`, `@key`, `@first`, `@last` y `{{else}}` si está vacío), `#if`,
+//! `#unless`, `#with`, `this`, `../` para el contexto padre, comentarios
+//! `{{! }}`/`{{!-- --}}` y control de espacios con `~`. Como en Handlebars,
+//! son falsos `false`, `null`, `""`, `0` y `[]`.
+//!
+//! Helpers en línea (argumentos: rutas o literales entre comillas):
+//! `{{formatDate fecha "%d/%m/%Y"}}` (sin formato, `%Y-%m-%d`; si la fecha no
+//! se entiende, queda tal cual), `{{slugify titulo}}` y `{{{toc body}}}`
+//! (lista markdown con los headings del texto y sus anchors).
+
+use crate::core::anchors::anchors;
+use crate::core::slugs::slug_for;
+use crate::errors::{OcError, OcResult};
+use crate::types::OcDate;
+use serde_json::Value;
+
+/// Helpers en línea disponibles.
+const HELPERS: &[&str] = &["formatDate", "slugify", "toc"];
+
+/// Argumento de un helper en línea.
+#[derive(Debug, Clone, PartialEq)]
+enum Arg {
+    Path(String),
+    Literal(String),
+}
+
+/// Nodo de la plantilla parseada.
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Var { path: String, raw: bool },
+    Helper { name: String, args: Vec<Arg>, raw: bool },
+    Block { helper: String, arg: String, body: Vec<Node>, inverse: Vec<Node> },
+}
+
+/// Etiqueta `{{...}}` con su línea, para los errores.
+#[derive(Debug)]
+enum Tag {
+    Var { path: String, raw: bool },
+    Open { helper: String, arg: String },
+    Else,
+    Close(String),
+}
+
+fn parse_error(line: usize, message: impl std::fmt::Display) -> OcError {
+    OcError::Custom(format!("Plantilla, línea {}: {}", line, message))
+}
+
+/// Separa texto y etiquetas aplicando el control de espacios `~`.
+fn tokenize(template: &str) -> OcResult<Vec<(usize, Result<String, Tag>)>> {
+    let mut tokens: Vec<(usize, Result<String, Tag>)> = Vec::new();
+    let mut rest = template;
+    let mut line = 1;
+    let mut trim_next = false;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find("{{") else {
+            let text = if trim_next { rest.trim_start() } else { rest };
+            tokens.push((line, Ok(text.to_string())));
+            break;
+        };
+        let mut text = &rest[..start];
+        if trim_next {
+            text = text.trim_start();
+        }
+        let after = &rest[start..];
+        let raw = after.starts_with("{{{");
+        let comment_long = after.starts_with("{{!--");
+        let close = if raw {
+            "}}}"
+        } else if comment_long {
+            "--}}"
+        } else {
+            "}}"
+        };
+        let open_len = if raw { 3 } else { 2 };
+        let end = after[open_len..]
+            .find(close)
+            .map(|i| i + open_len)
+            .ok_or_else(|| parse_error(line + rest[..start].matches('\n').count(), "etiqueta sin cerrar"))?;
+        let mut inner = &after[open_len..end];
+
+        let trim_before = inner.starts_with('~');
+        trim_next = inner.ends_with('~');
+        inner = inner.trim_start_matches('~').trim_end_matches('~').trim();
+        if trim_before {
+            text = text.trim_end();
+        }
+        if !text.is_empty() {
+            tokens.push((line, Ok(text.to_string())));
+        }
+        line += rest[..start].matches('\n').count();
+        let tag_line = line;
+        line += after[..end + close.len()].matches('\n').count();
+        rest = &after[end + close.len()..];
+
+        if inner.starts_with('!') {
+            continue;
+        }
+        let tag = if let Some(block) = inner.strip_prefix('#') {
+            let (helper, arg) = block.split_once(char::is_whitespace).unwrap_or((block, ""));
+            Tag::Open {
+                helper: helper.to_string(),
+                arg: arg.trim().to_string(),
+            }
+        } else if let Some(name) = inner.strip_prefix('/') {
+            Tag::Close(name.trim().to_string())
+        } else if inner == "else" || inner == "^" {
+            Tag::Else
+        } else {
+            Tag::Var {
+                path: inner.to_string(),
+                raw,
+            }
+        };
+        tokens.push((tag_line, Err(tag)));
+    }
+    Ok(tokens)
+}
+
+/// Separa los argumentos de un helper respetando las comillas.
+fn split_args(text: &str) -> Option<Vec<Arg>> {
+    let mut args = Vec::new();
+    let mut rest = text.trim_start();
+    while !rest.is_empty() {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted.find('"')?;
+            args.push(Arg::Literal(quoted[..end].to_string()));
+            rest = quoted[end + 1..].trim_start();
+        } else {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            args.push(Arg::Path(rest[..end].to_string()));
+            rest = rest[end..].trim_start();
+        }
+    }
+    Some(args)
+}
+
+/// `{{ruta}}` o, si lleva argumentos, `{{helper arg...}}` validado.
+fn var_node(line: usize, path: String, raw: bool) -> OcResult<Node> {
+    let Some((name, rest)) = path.split_once(char::is_whitespace) else {
+        return Ok(Node::Var { path, raw });
+    };
+    if !HELPERS.contains(&name) {
+        return Err(parse_error(line, format!("helper desconocido: {}", name)));
+    }
+    let args = split_args(rest).ok_or_else(|| parse_error(line, "comillas sin cerrar"))?;
+    let expected = if name == "formatDate" { 1..=2 } else { 1..=1 };
+    if !expected.contains(&args.len()) {
+        return Err(parse_error(line, format!("{}: cantidad de argumentos inválida", name)));
+    }
+    if let Some(Arg::Literal(format)) = args.get(1) {
+        OcDate::check_format(format).map_err(|e| parse_error(line, e))?;
+    }
+    Ok(Node::Helper {
+        name: name.to_string(),
+        args,
+        raw,
+    })
+}
+
+/// Arma el árbol hasta el cierre de `open` (o el final si es `None`).
+fn build(
+    tokens: &mut std::iter::Peekable<std::vec::IntoIter<(usize, Result<String, Tag>)>>,
+    open: Option<(&str, usize)>,
+) -> OcResult<(Vec<Node>, Vec<Node>)> {
+    let mut body = Vec::new();
+    let mut inverse = Vec::new();
+    let mut in_inverse = false;
+
+    while let Some((line, token)) = tokens.next() {
+        let target = if in_inverse { &mut inverse } else { &mut body };
+        match token {
+            Ok(text) => target.push(Node::Text(text)),
+            Err(Tag::Var { path, raw }) => target.push(var_node(line, path, raw)?),
+            Err(Tag::Open { helper, arg }) => {
+                if !matches!(helper.as_str(), "each" | "if" | "unless" | "with") {
+                    return Err(parse_error(line, format!("helper desconocido: #{}", helper)));
+                }
+                if arg.is_empty() {
+                    return Err(parse_error(line, format!("#{} sin argumento", helper)));
+                }
+                let (inner, inner_inverse) = build(tokens, Some((&helper, line)))?;
+                target.push(Node::Block {
+                    helper,
+                    arg,
+                    body: inner,
+                    inverse: inner_inverse,
+                });
+            }
+            Err(Tag::Else) => match open {
+                Some(_) if !in_inverse => in_inverse = true,
+                _ => return Err(parse_error(line, "{{else}} fuera de un bloque")),
+            },
+            Err(Tag::Close(name)) => match open {
+                Some((helper, _)) if helper == name => return Ok((body, inverse)),
+                Some((helper, _)) => {
+                    return Err(parse_error(line, format!("se esperaba {{{{/{}}}}}, no {{{{/{}}}}}", helper, name)))
+                }
+                None => return Err(parse_error(line, format!("{{{{/{}}}}} sin apertura", name))),
+            },
+        }
+    }
+    match open {
+        Some((helper, line)) => Err(parse_error(line, format!("{{{{#{}}}}} sin cerrar", helper))),
+        None => Ok((body, inverse)),
+    }
+}
+
+fn parse(template: &str) -> OcResult<Vec<Node>> {
+    let mut tokens = tokenize(template)?.into_iter().peekable();
+    Ok(build(&mut tokens, None)?.0)
+}
+
+/// Contexto de render: valor actual, variables `@` y el padre.
+struct Scope<'a> {
+    value: &'a Value,
+    index: Option<usize>,
+    key: Option<String>,
+    last: bool,
+    parent: Option<&'a Scope<'a>>,
+}
+
+impl<'a> Scope<'a> {
+    fn root(value: &'a Value) -> Self {
+        Self {
+            value,
+            index: None,
+            key: None,
+            last: false,
+            parent: None,
+        }
+    }
+
+    fn lookup(&self, path: &str) -> Value {
+        let mut scope = self;
+        let mut path = path.trim();
+        while let Some(rest) = path.strip_prefix("../") {
+            scope = scope.parent.unwrap_or(scope);
+            path = rest;
+        }
+        match path {
+            "@index" => return scope.index.map_or(Value::Null, Value::from),
+            "@key" => return scope.key.clone().map_or(Value::Null, Value::from),
+            "@first" => return Value::Bool(scope.index == Some(0)),
+            "@last" => return Value::Bool(scope.last),
+            _ => {}
+        }
+        let path = match path {
+            "this" => "",
+            _ => path.strip_prefix("this.").unwrap_or(path),
+        };
+        let mut value = scope.value;
+        for part in path.split('.').filter(|p| !p.is_empty()) {
+            value = match value {
+                Value::Object(map) => map.get(part).unwrap_or(&Value::Null),
+                Value::Array(items) => part.parse::<usize>().ok().and_then(|i| items.get(i)).unwrap_or(&Value::Null),
+                _ => &Value::Null,
+            };
+        }
+        value.clone()
+    }
+}
+
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64() != Some(0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#x27;"),
+            '`' => out.push_str("&#x60;"),
+            '=' => out.push_str("&#x3D;"),
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Índice markdown de los headings de `text`, anidado por nivel.
+fn toc(text: &str) -> String {
+    let headings = anchors(text);
+    let top = headings.iter().map(|h| h.level).min().unwrap_or(1);
+    headings
+        .iter()
+        .map(|h| format!("{}- [{}](#{})\n", "  ".repeat(h.level - top), h.text, h.slug))
+        .collect()
+}
+
+fn call_helper(name: &str, args: &[Arg], scope: &Scope) -> String {
+    let values: Vec<String> = args
+        .iter()
+        .map(|arg| match arg {
+            Arg::Path(path) => display(&scope.lookup(path)),
+            Arg::Literal(text) => text.clone(),
+        })
+        .collect();
+    let value = values[0].as_str();
+    match name {
+        "formatDate" => {
+            let format = values.get(1).map_or("%Y-%m-%d", String::as_str);
+            value
+                .parse::<OcDate>()
+                .ok()
+                .and_then(|date| date.format_with(format).ok())
+                .unwrap_or_else(|| value.to_string())
+        }
+        "slugify" => slug_for(value),
+        "toc" => toc(value),
+        _ => String::new(),
+    }
+}
+
+fn render_nodes(nodes: &[Node], scope: &Scope, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var { path, raw } => {
+                let text = display(&scope.lookup(path));
+                out.push_str(&if *raw { text } else { escape_html(&text) });
+            }
+            Node::Helper { name, args, raw } => {
+                let text = call_helper(name, args, scope);
+                out.push_str(&if *raw { text } else { escape_html(&text) });
+            }
+            Node::Block { helper, arg, body, inverse } => {
+                let value = scope.lookup(arg);
+                match helper.as_str() {
+                    "if" | "unless" => {
+                        let branch = if truthy(&value) == (helper == "if") { body } else { inverse };
+                        render_nodes(branch, scope, out);
+                    }
+                    "with" if truthy(&value) => {
+                        let inner = Scope {
+                            value: &value,
+                            index: None,
+                            key: None,
+                            last: false,
+                            parent: Some(scope),
+                        };
+                        render_nodes(body, &inner, out);
+                    }
+                    "each" => {
+                        let items: Vec<(Option<String>, &Value)> = match &value {
+                            Value::Array(items) => items.iter().map(|v| (None, v)).collect(),
+                            Value::Object(map) => map.iter().map(|(k, v)| (Some(k.clone()), v)).collect(),
+                            _ => Vec::new(),
+                        };
+                        if items.is_empty() {
+                            render_nodes(inverse, scope, out);
+                        }
+                        let count = items.len();
+                        for (i, (key, item)) in items.into_iter().enumerate() {
+                            let inner = Scope {
+                                value: item,
+                                index: Some(i),
+                                key,
+                                last: i + 1 == count,
+                                parent: Some(scope),
+                            };
+                            render_nodes(body, &inner, out);
+                        }
+                    }
+                    _ => render_nodes(inverse, scope, out),
+                }
+            }
+        }
+    }
+}
+
+/// Renderiza `template` con `data`. Los errores de sintaxis indican la línea.
+pub fn render(template: &str, data: &Value) -> OcResult<String> {
+    let nodes = parse(template)?;
+    let mut out = String::new();
+    render_nodes(&nodes, &Scope::root(data), &mut out);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_blocks_and_paths() {
+        let data = json!({
+            "project": "Flota <2>",
+            "health": {"score": 82, "grade": "B"},
+            "issues": [{"code": "L005", "count": 3}, {"code": "L009", "count": 0}],
+            "empty": [],
+        });
+        let template = "{{! informe }}# {{project}} / {{{project}}}\n\
+                        {{#with health}}Salud {{score}} ({{grade}}){{/with}}\n\
+                        {{#each issues~}}\n- {{@index}} {{code}}{{#if count}}: {{count}}{{else}} ok{{/if}} de {{../project}}{{#unless @last}},{{/unless}}\n{{/each~}}\n\
+                        {{#each empty}}x{{else}}sin datos{{/each}}";
+        assert_eq!(
+            render(template, &data).unwrap(),
+            "# Flota &lt;2&gt; / Flota <2>\nSalud 82 (B)\n- 0 L005: 3 de Flota &lt;2&gt;,\n- 1 L009 ok de Flota &lt;2&gt;\nsin datos"
+        );
+
+        let err = render("a\n{{#each issues}}\n{{/if}}", &data).unwrap_err();
+        assert!(err.to_string().contains("línea 3"), "{}", err);
+        assert!(render("{{#loop x}}{{/loop}}", &data).is_err());
+    }
+
+    #[test]
+    fn test_inline_helpers() {
+        let data = json!({
+            "title": "Gestión de Flota",
+            "date": "2025-03-07",
+            "body": "# Intro\n\n## Alta de unidad\n\n```\n# no\n```\n## Baja\n",
+        });
+        let template = "{{slugify title}} {{formatDate date \"%d/%m/%Y\"}} {{formatDate date}} {{formatDate title}}\n{{{toc body}}}";
+        assert_eq!(
+            render(template, &data).unwrap(),
+            "gestion-de-flota 07/03/2025 2025-03-07 Gestión de Flota\n- [Intro](#intro)\n  - [Alta de unidad](#alta-de-unidad)\n  - [Baja](#baja)\n"
+        );
+        assert!(render("{{upper title}}", &data).unwrap_err().to_string().contains("helper desconocido"));
+        assert!(render("{{formatDate date \"%Q\"}}", &data).is_err());
+    }
+}
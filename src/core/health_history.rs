@@ -0,0 +1,102 @@
+//! Historial de salud por módulo.
+//!
+//! Cada ejecución de `dashboard` agrega una línea a
+//! `.oc_diagdoc/health_history.jsonl` con la salud global y la de cada
+//! módulo; el panel de módulos la usa para las sparklines de tendencia.
+
+use crate::core::config::CONFIG_DIR;
+use crate::errors::{OcError, OcResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Archivo del historial dentro de `.oc_diagdoc/`.
+pub const HEALTH_HISTORY_FILE: &str = "health_history.jsonl";
+
+/// Puntos de tendencia por módulo (las últimas N mediciones).
+pub const TREND_POINTS: usize = 12;
+
+/// Medición de salud en un momento dado.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthSnapshot {
+    pub timestamp: String,
+    pub overall: f64,
+    /// ID de módulo → salud (0-100).
+    pub modules: BTreeMap<String, f64>,
+}
+
+impl HealthSnapshot {
+    pub fn new(overall: f64, modules: BTreeMap<String, f64>) -> Self {
+        Self {
+            timestamp: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            overall,
+            modules,
+        }
+    }
+}
+
+/// Ruta del historial del proyecto actual.
+pub fn history_path() -> PathBuf {
+    Path::new(CONFIG_DIR).join(HEALTH_HISTORY_FILE)
+}
+
+/// Agrega una medición al historial (lo crea si falta).
+pub fn append(path: &Path, snapshot: &HealthSnapshot) -> OcResult<()> {
+    let write_err = |e: std::io::Error| OcError::FileWrite {
+        path: path.to_path_buf(),
+        source: e,
+    };
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(write_err)?;
+    }
+    let line = serde_json::to_string(snapshot)?;
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path).map_err(write_err)?;
+    writeln!(file, "{}", line).map_err(write_err)
+}
+
+/// Mediciones en orden cronológico; sin archivo, ninguna. Las líneas
+/// ilegibles se omiten.
+pub fn load(path: &Path) -> OcResult<Vec<HealthSnapshot>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(path).map_err(|e| OcError::FileRead {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    Ok(content.lines().filter_map(|line| serde_json::from_str(line).ok()).collect())
+}
+
+/// Últimas `points` mediciones de un módulo (las que no lo incluyen se saltan).
+pub fn module_trend(history: &[HealthSnapshot], module: &str, points: usize) -> Vec<f64> {
+    let values: Vec<f64> = history.iter().filter_map(|s| s.modules.get(module).copied()).collect();
+    values[values.len().saturating_sub(points)..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_roundtrip_and_trend() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".oc_diagdoc").join(HEALTH_HISTORY_FILE);
+        assert!(load(&path).unwrap().is_empty());
+
+        for (one, two) in [(50.0, None), (60.0, Some(90.0)), (75.0, Some(80.0))] {
+            let mut modules = BTreeMap::from([("1".to_string(), one)]);
+            if let Some(two) = two {
+                modules.insert("2".to_string(), two);
+            }
+            append(&path, &HealthSnapshot::new(70.0, modules)).unwrap();
+        }
+        std::fs::OpenOptions::new().append(true).open(&path).unwrap().write_all(b"roto\n").unwrap();
+
+        let history = load(&path).unwrap();
+        assert_eq!(history.len(), 3);
+        assert_eq!(module_trend(&history, "1", 2), vec![60.0, 75.0]);
+        assert_eq!(module_trend(&history, "2", TREND_POINTS), vec![90.0, 80.0]);
+        assert!(module_trend(&history, "9", TREND_POINTS).is_empty());
+    }
+}
@@ -0,0 +1,93 @@
+//! Cliente HTTP mínimo vía `curl`.
+//!
+//! Evita arrastrar un stack HTTP/TLS al binario para los pocos usos de red
+//! (webhooks, APIs de embeddings). Requiere `curl` en el PATH.
+//!
+//! Los headers (que pueden llevar API keys) nunca van en la línea de
+//! comandos, visible para cualquier usuario vía `ps`: se escriben en un
+//! archivo temporal privado que curl lee con `-H @archivo`.
+
+use crate::errors::{OcError, OcResult};
+use serde_json::Value;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// POST de `payload` como JSON; devuelve el body de la respuesta.
+///
+/// `headers` en formato `Nombre: valor`. Falla si el status HTTP es >= 400.
+pub fn post_json(url: &str, headers: &[String], payload: &Value) -> OcResult<String> {
+    let body = serde_json::to_vec(payload)?;
+    let header_file = header_file(headers)?;
+    let mut command = curl_command(url, header_file.as_ref().map(|f| f.path()));
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| OcError::Custom(format!("No se pudo ejecutar curl: {}", e)))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(&body)
+            .map_err(|e| OcError::Custom(format!("Error enviando payload: {}", e)))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| OcError::Custom(format!("Error esperando a curl: {}", e)))?;
+    if !output.status.success() {
+        return Err(OcError::Custom(format!(
+            "POST {} falló ({}): {}",
+            url,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Headers en un archivo temporal (solo legible por el usuario), uno por
+/// línea; `None` sin headers. Se borra al soltarlo.
+fn header_file(headers: &[String]) -> OcResult<Option<tempfile::NamedTempFile>> {
+    if headers.is_empty() {
+        return Ok(None);
+    }
+    let mut file = tempfile::NamedTempFile::new()?;
+    for header in headers {
+        writeln!(file, "{}", header)?;
+    }
+    file.flush()?;
+    Ok(Some(file))
+}
+
+/// `curl` que envía stdin como body JSON, con los headers de `header_file`.
+fn curl_command(url: &str, header_file: Option<&Path>) -> Command {
+    let mut command = Command::new("curl");
+    command.args(["-sS", "-f", "-X", "POST", "-H", "Content-Type: application/json"]);
+    if let Some(path) = header_file {
+        command.arg("-H").arg(format!("@{}", path.display()));
+    }
+    command.args(["--data-binary", "@-", url]);
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headers_stay_off_the_command_line() {
+        let headers = vec!["Authorization: Bearer sk-secreta".to_string()];
+        let file = header_file(&headers).unwrap().unwrap();
+        let command = curl_command("https://api.example/v1", Some(file.path()));
+
+        let args: Vec<String> = command.get_args().map(|a| a.to_string_lossy().into_owned()).collect();
+        assert!(args.iter().all(|a| !a.contains("sk-secreta")), "{:?}", args);
+        assert!(args.contains(&format!("@{}", file.path().display())));
+        assert_eq!(std::fs::read_to_string(file.path()).unwrap(), "Authorization: Bearer sk-secreta\n");
+        assert!(header_file(&[]).unwrap().is_none());
+    }
+}
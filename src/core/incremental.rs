@@ -0,0 +1,170 @@
+//! Builds incrementales para `export` y `compress`.
+//!
+//! El manifest del build anterior guarda, por documento, el `content_hash`
+//! de sus fuentes (el documento y lo que embebe) junto con la salida que
+//! generó. Con el mismo hash de opciones (flags y secciones de config que
+//! afectan la salida) un documento se reutiliza si su hash no cambió y su
+//! salida sigue en disco; las salidas de documentos borrados se eliminan.
+
+use crate::core::files::read_file_content;
+use crate::errors::{OcError, OcResult};
+use crate::types::ContentHash;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Nombre del manifest dentro del directorio de salida de `export`.
+pub const MANIFEST_FILE: &str = ".oc_manifest.json";
+
+/// Entrada de un documento en el manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Hash de las fuentes ([`sources_hash`]).
+    pub content_hash: String,
+    /// Salida generada, relativa al directorio de salida (vacía si el
+    /// documento no genera un archivo propio).
+    #[serde(default)]
+    pub output: String,
+    /// Datos que el comando necesita para no reprocesar el documento.
+    #[serde(default)]
+    pub data: serde_json::Value,
+}
+
+/// Manifest de un build.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuildManifest {
+    pub built: String,
+    /// Hash de las opciones del build ([`options_hash`]).
+    pub options: String,
+    /// Ruta relativa del documento → entrada.
+    pub docs: BTreeMap<String, ManifestEntry>,
+}
+
+/// Documentos regenerados, reutilizados y salidas eliminadas.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct IncrementalStats {
+    pub rebuilt: usize,
+    pub reused: usize,
+    pub removed: usize,
+}
+
+impl IncrementalStats {
+    pub fn summary(&self) -> String {
+        format!(
+            "{} regenerados, {} sin cambios, {} eliminados",
+            self.rebuilt, self.reused, self.removed
+        )
+    }
+}
+
+/// Hash de un documento y de los documentos que embebe.
+pub fn sources_hash(content: &str, embedded: &[&str]) -> String {
+    let mut joined = String::from(content);
+    for source in embedded {
+        joined.push('\0');
+        joined.push_str(source);
+    }
+    ContentHash::compute(&joined).full().to_string()
+}
+
+/// Hash de las opciones que afectan la salida.
+pub fn options_hash(parts: &[&str]) -> String {
+    ContentHash::compute(&parts.join("\0")).full().to_string()
+}
+
+/// Manifest de un archivo de salida único (`compiled.md` →
+/// `.compiled.md.oc_manifest.json`, en el mismo directorio).
+pub fn manifest_for_file(output: &Path) -> PathBuf {
+    let name = output.file_name().and_then(|n| n.to_str()).unwrap_or("output");
+    output.with_file_name(format!(".{}{}", name, MANIFEST_FILE))
+}
+
+impl BuildManifest {
+    pub fn new(options: String) -> Self {
+        Self {
+            built: chrono::Utc::now().to_rfc3339(),
+            options,
+            docs: BTreeMap::new(),
+        }
+    }
+
+    /// Manifest anterior si existe, es legible y se generó con las mismas
+    /// opciones; si no, build completo.
+    pub fn previous(path: &Path, options: &str) -> Option<Self> {
+        let content = read_file_content(path).ok()?;
+        serde_json::from_str::<Self>(&content)
+            .ok()
+            .filter(|m| m.options == options)
+    }
+
+    pub fn save(&self, path: &Path) -> OcResult<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?).map_err(|e| OcError::FileWrite {
+            path: path.to_path_buf(),
+            source: e,
+        })
+    }
+
+    /// Entrada reutilizable: mismo hash y salida todavía en `output_dir`.
+    pub fn fresh(&self, doc: &str, content_hash: &str, output_dir: &Path) -> Option<&ManifestEntry> {
+        self.docs
+            .get(doc)
+            .filter(|e| e.content_hash == content_hash)
+            .filter(|e| e.output.is_empty() || output_dir.join(&e.output).is_file())
+    }
+
+    /// Borra de `output_dir` las salidas de `self` que `current` ya no
+    /// genera (documentos borrados, filtrados o renombrados).
+    pub fn remove_stale(&self, current: &Self, output_dir: &Path) -> usize {
+        let kept: std::collections::HashSet<&str> = current.docs.values().map(|e| e.output.as_str()).collect();
+        self.docs
+            .values()
+            .filter(|e| !e.output.is_empty() && !kept.contains(e.output.as_str()))
+            .filter(|e| std::fs::remove_file(output_dir.join(&e.output)).is_ok())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_fresh_and_stale() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = options_hash(&["markdown", "{}"]);
+        let hash = sources_hash("# A", &["# B"]);
+        assert_ne!(hash, sources_hash("# A", &[]));
+
+        let mut previous = BuildManifest::new(options.clone());
+        for (doc, output) in [("a.md", "a.md"), ("b.md", "b.md")] {
+            std::fs::write(dir.path().join(output), "x").unwrap();
+            previous.docs.insert(
+                doc.to_string(),
+                ManifestEntry {
+                    content_hash: hash.clone(),
+                    output: output.to_string(),
+                    data: serde_json::Value::Null,
+                },
+            );
+        }
+        let path = dir.path().join(MANIFEST_FILE);
+        previous.save(&path).unwrap();
+
+        assert!(BuildManifest::previous(&path, "otras").is_none());
+        let loaded = BuildManifest::previous(&path, &options).unwrap();
+        assert!(loaded.fresh("a.md", &hash, dir.path()).is_some());
+        assert!(loaded.fresh("a.md", "otro", dir.path()).is_none());
+
+        let mut current = BuildManifest::new(options);
+        current.docs.insert("a.md".to_string(), loaded.docs["a.md"].clone());
+        assert_eq!(loaded.remove_stale(&current, dir.path()), 1);
+        assert!(!dir.path().join("b.md").exists());
+        assert_eq!(
+            manifest_for_file(Path::new("out/compiled.md")),
+            PathBuf::from("out/.compiled.md.oc_manifest.json")
+        );
+    }
+}
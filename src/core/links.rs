@@ -5,11 +5,13 @@
 //! - Links Markdown: [text](url)
 //! - Embeds: ![[image]] y ![alt](url)
 
+use crate::core::anchors::{names_doc, split_target, BlockOwners, Fragment};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 /// Patrón para links Obsidian: [[target]] o [[target|alias]]
 static OBSIDIAN_LINK: Lazy<Regex> =
@@ -249,6 +251,258 @@ pub fn replace_link(content: &str, old_target: &str, new_target: &str) -> String
     result.to_string()
 }
 
+/// Apunta los wiki-links (y embeds) de `old_stem` a `new_stem`, conservando
+/// `#sección`, `^bloque` y alias. Devuelve el contenido y cuántos enlaces cambiaron.
+pub fn rename_link_target(content: &str, old_stem: &str, new_stem: &str) -> (String, usize) {
+    let mut count = 0;
+    let result = OBSIDIAN_LINK.replace_all(content, |caps: &regex::Captures| {
+        let target = &caps[1];
+        let (file, anchor) = match target.find(['#', '^']) {
+            Some(i) => target.split_at(i),
+            None => (target, ""),
+        };
+        let file = file.trim();
+        if file != old_stem && file.strip_suffix(".md") != Some(old_stem) {
+            return caps[0].to_string();
+        }
+        count += 1;
+        match caps.get(2) {
+            Some(alias) => format!("[[{}{}|{}]]", new_stem, anchor, alias.as_str()),
+            None => format!("[[{}{}]]", new_stem, anchor),
+        }
+    });
+    (result.into_owned(), count)
+}
+
+/// Reapunta `[[Doc#^id]]`, `![[Doc^id]]` y `![[Doc#^id]]` cuyo bloque ya
+/// no está en `Doc` al único documento que hoy lo define, conservando alias.
+/// Devuelve el contenido y cuántos enlaces cambiaron.
+pub fn retarget_block_refs(content: &str, owners: &BlockOwners) -> (String, usize) {
+    let mut count = 0;
+    let result = OBSIDIAN_LINK.replace_all(content, |caps: &regex::Captures| {
+        let target = &caps[1];
+        let (doc, Fragment::Block(id)) = split_target(target) else {
+            return caps[0].to_string();
+        };
+        let new_doc = match owners.get(id).map(Vec::as_slice) {
+            Some([owner]) if !names_doc(doc, owner) => owner,
+            _ => return caps[0].to_string(),
+        };
+        let fragment = target.find('#').or_else(|| target.find('^')).map_or("", |i| &target[i..]);
+        count += 1;
+        match caps.get(2) {
+            Some(alias) => format!("[[{}{}|{}]]", new_doc, fragment, alias.as_str()),
+            None => format!("[[{}{}]]", new_doc, fragment),
+        }
+    });
+    (result.into_owned(), count)
+}
+
+/// Forma canónica de los wiki-links del proyecto (`validation.link_convention`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkConvention {
+    /// Solo el nombre del archivo: `[[2.8.1 Politicas_Seguridad]]`.
+    Name,
+    /// Solo el ID del documento: `[[2.8.1]]`.
+    Id,
+    /// Ruta relativa al data_dir: `[[2. Seguridad/2.8.1 Politicas_Seguridad]]`.
+    Path,
+}
+
+impl LinkConvention {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Id => "id",
+            Self::Path => "path",
+        }
+    }
+}
+
+impl FromStr for LinkConvention {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "name" | "nombre" => Ok(Self::Name),
+            "id" => Ok(Self::Id),
+            "path" | "ruta" => Ok(Self::Path),
+            other => Err(format!(
+                "convención de links desconocida: '{}' (use name, id o path)",
+                other
+            )),
+        }
+    }
+}
+
+/// Documento al que puede apuntar un wiki-link.
+#[derive(Debug, Clone)]
+pub struct LinkTarget {
+    pub path: PathBuf,
+    pub stem: String,
+    /// ID numérico del nombre de archivo (`2.8.1` de `2.8.1 Politicas`).
+    pub id: Option<String>,
+    /// Ruta relativa al data_dir, sin `.md` y con `/`.
+    pub relative: String,
+}
+
+/// Índice de documentos para resolver wiki-links por ruta, nombre o ID y
+/// escribirlos en una [`LinkConvention`].
+#[derive(Debug, Clone, Default)]
+pub struct LinkTargets {
+    docs: Vec<LinkTarget>,
+    by_path: HashMap<String, usize>,
+    by_stem: HashMap<String, Vec<usize>>,
+    by_id: HashMap<String, Vec<usize>>,
+}
+
+impl LinkTargets {
+    pub fn new(data_dir: &Path, files: &[PathBuf]) -> Self {
+        let mut index = Self::default();
+        for path in files {
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let relative = path
+                .strip_prefix(data_dir)
+                .unwrap_or(path)
+                .with_extension("")
+                .to_string_lossy()
+                .replace('\\', "/");
+            let id = stem
+                .split_whitespace()
+                .next()
+                .map(|token| token.trim_end_matches('.'))
+                .filter(|id| id.starts_with(|c: char| c.is_ascii_digit()))
+                .filter(|id| id.chars().all(|c| c.is_ascii_digit() || c == '.'))
+                .map(str::to_string);
+
+            let i = index.docs.len();
+            index.by_path.insert(relative.clone(), i);
+            index.by_stem.entry(stem.to_string()).or_default().push(i);
+            if let Some(id) = &id {
+                index.by_id.entry(id.clone()).or_default().push(i);
+            }
+            index.docs.push(LinkTarget {
+                path: path.clone(),
+                stem: stem.to_string(),
+                id,
+                relative,
+            });
+        }
+        index
+    }
+
+    /// Documento que nombra `target` (sin `#sección`, `^bloque` ni alias):
+    /// por ruta relativa, nombre o ID exactos; una ruta que no existe se
+    /// resuelve por su último componente. `None` si no existe o es ambiguo.
+    pub fn resolve(&self, target: &str) -> Option<&LinkTarget> {
+        let target = target.trim().replace('\\', "/");
+        let target = target.trim_start_matches('/');
+        let target = target.strip_suffix(".md").unwrap_or(target);
+        if let Some((_, name)) = target.rsplit_once('/') {
+            return match self.by_path.get(target) {
+                Some(&i) => Some(&self.docs[i]),
+                None => self.resolve(name),
+            };
+        }
+        let unique = |found: Option<&Vec<usize>>| match found.map(Vec::as_slice) {
+            Some([i]) => Some(&self.docs[*i]),
+            _ => None,
+        };
+        unique(self.by_stem.get(target)).or_else(|| unique(self.by_id.get(target)))
+    }
+
+    /// Cómo se escribe un link a `doc` en `convention`. Si el ID o el nombre
+    /// no bastan para identificarlo (repetido o sin ID), se usa la siguiente
+    /// forma inequívoca: ID → nombre → ruta.
+    pub fn canonical(&self, doc: &LinkTarget, convention: LinkConvention) -> String {
+        let unique = |found: Option<&Vec<usize>>| found.is_some_and(|v| v.len() == 1);
+        let id = doc.id.as_ref().filter(|id| {
+            unique(self.by_id.get(id.as_str())) && !self.by_stem.contains_key(id.as_str())
+        });
+        match (convention, id) {
+            (LinkConvention::Id, Some(id)) => id.clone(),
+            (LinkConvention::Name | LinkConvention::Id, _) if unique(self.by_stem.get(&doc.stem)) => doc.stem.clone(),
+            _ => doc.relative.clone(),
+        }
+    }
+
+    /// Forma canónica del destino de `[[target]]` si resuelve y no la cumple.
+    pub fn violation(&self, target: &str, convention: LinkConvention) -> Option<String> {
+        let file = link_file(target).1;
+        let canonical = self.canonical(self.resolve(file)?, convention);
+        (canonical != file).then_some(canonical)
+    }
+}
+
+/// Separa el destino de un wiki-link en (espacio inicial, archivo, resto),
+/// donde el resto es `#sección`, `^bloque` o el `\` de un `\|` en tablas.
+fn link_file(target: &str) -> (usize, &str, &str) {
+    let end = target.find(['#', '^']).unwrap_or(target.len());
+    let file = &target[..end];
+    let start = file.len() - file.trim_start().len();
+    let name = file[start..].trim_end_matches('\\').trim_end();
+    (start, name, &target[start + name.len()..])
+}
+
+/// Reescribe en `convention` los wiki-links y embeds que resuelven a un
+/// documento, conservando `#sección`, `^bloque` y alias. Los bloques de
+/// código no se tocan. Devuelve el contenido y cuántos enlaces cambiaron.
+pub fn normalize_links(content: &str, targets: &LinkTargets, convention: LinkConvention) -> (String, usize) {
+    let mut count = 0;
+    let mut in_code = false;
+    let mut result = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+        }
+        if in_code || trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            result.push_str(line);
+            continue;
+        }
+        let updated = OBSIDIAN_LINK.replace_all(line, |caps: &regex::Captures| {
+            let target = &caps[1];
+            let Some(canonical) = targets.violation(target, convention) else {
+                return caps[0].to_string();
+            };
+            count += 1;
+            let rest = link_file(target).2;
+            match caps.get(2) {
+                Some(alias) => format!("[[{}{}|{}]]", canonical, rest, alias.as_str()),
+                None => format!("[[{}{}]]", canonical, rest),
+            }
+        });
+        result.push_str(&updated);
+    }
+    (result, count)
+}
+
+/// Wiki-links fuera de `convention`, fuera de bloques de código: (línea,
+/// destino escrito, forma canónica). Son los que reescribe [`normalize_links`].
+pub fn link_violations(content: &str, targets: &LinkTargets, convention: LinkConvention) -> Vec<(usize, String, String)> {
+    let mut violations = Vec::new();
+    let mut in_code = false;
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+        for caps in OBSIDIAN_LINK.captures_iter(line) {
+            if let Some(canonical) = targets.violation(&caps[1], convention) {
+                violations.push((i + 1, link_file(&caps[1]).1.to_string(), canonical));
+            }
+        }
+    }
+    violations
+}
+
 /// Convierte enlaces Obsidian a formato Markdown.
 pub fn convert_obsidian_to_md(content: &str) -> String {
     // Convertir embeds ![[file]] → ![file](file)
@@ -334,6 +588,69 @@ mod tests {
         // MD links no se reemplazan (target diferente)
     }
 
+    #[test]
+    fn test_rename_link_target() {
+        let content = "[[1.2 Alta]], [[1.2 Alta#datos|ver]], ![[1.2 Alta.md]], ![[1.2 Alta^b1]] y [[1.2 Alta vieja]].";
+        let (updated, count) = rename_link_target(content, "1.2 Alta", "1.2 alta_cliente");
+
+        assert_eq!(count, 4);
+        assert_eq!(
+            updated,
+            "[[1.2 alta_cliente]], [[1.2 alta_cliente#datos|ver]], ![[1.2 alta_cliente]], ![[1.2 alta_cliente^b1]] y [[1.2 Alta vieja]]."
+        );
+    }
+
+    #[test]
+    fn test_retarget_block_refs() {
+        let owners = BlockOwners::from([
+            ("cuit".to_string(), vec!["2 Fiscal".to_string()]),
+            ("dni".to_string(), vec!["1 Alta".to_string()]),
+            ("doble".to_string(), vec!["1 Alta".to_string(), "2 Fiscal".to_string()]),
+        ]);
+        let content = "[[1 Alta#^cuit|CUIT]] ![[1 Alta^cuit]] ![[1#^dni]] [[1 Alta#^doble]] [[1 Alta#^nada]]";
+        let (updated, count) = retarget_block_refs(content, &owners);
+        assert_eq!(count, 2);
+        assert_eq!(
+            updated,
+            "[[2 Fiscal#^cuit|CUIT]] ![[2 Fiscal^cuit]] ![[1#^dni]] [[1 Alta#^doble]] [[1 Alta#^nada]]"
+        );
+    }
+
+    #[test]
+    fn test_normalize_links_conventions() {
+        let data = Path::new("/docs");
+        let files: Vec<PathBuf> = ["2. Seguridad/2.8.1 Politicas.md", "2. Seguridad/Glosario.md", "3/Glosario.md", "README.md"]
+            .iter()
+            .map(|f| data.join(f))
+            .collect();
+        let targets = LinkTargets::new(data, &files);
+        assert_eq!(targets.resolve("2.8.1").unwrap().stem, "2.8.1 Politicas");
+        assert_eq!(targets.resolve("Proyecto/Datos/2.8.1 Politicas.md").unwrap().id.as_deref(), Some("2.8.1"));
+        assert!(targets.resolve("Glosario").is_none());
+
+        let content = "[[2.8.1]], [[2. Seguridad/2.8.1 Politicas#Alcance|ver]], | [[README\\|inicio]] |\n```\n[[2.8.1]]\n```\n[[3/Glosario]] [[Otro]]\n";
+        let (by_name, count) = normalize_links(content, &targets, LinkConvention::Name);
+        assert_eq!(count, 2);
+        assert_eq!(
+            by_name,
+            "[[2.8.1 Politicas]], [[2.8.1 Politicas#Alcance|ver]], | [[README\\|inicio]] |\n```\n[[2.8.1]]\n```\n[[3/Glosario]] [[Otro]]\n"
+        );
+        let (by_id, _) = normalize_links(&by_name, &targets, LinkConvention::Id);
+        assert!(by_id.starts_with("[[2.8.1]], [[2.8.1#Alcance|ver]]"));
+        let (by_path, count) = normalize_links(&by_id, &targets, LinkConvention::Path);
+        assert_eq!(count, 2);
+        assert!(by_path.starts_with("[[2. Seguridad/2.8.1 Politicas]], [[2. Seguridad/2.8.1 Politicas#Alcance|ver]], | [[README\\|inicio]]"));
+        assert_eq!(targets.violation("2. Seguridad/2.8.1 Politicas", LinkConvention::Path), None);
+        assert_eq!(
+            link_violations(content, &targets, LinkConvention::Name),
+            vec![
+                (1, "2.8.1".to_string(), "2.8.1 Politicas".to_string()),
+                (1, "2. Seguridad/2.8.1 Politicas".to_string(), "2.8.1 Politicas".to_string()),
+            ]
+        );
+        assert_eq!("ruta".parse::<LinkConvention>(), Ok(LinkConvention::Path));
+    }
+
     #[test]
     fn test_validate_links() {
         let links = vec![
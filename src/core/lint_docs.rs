@@ -120,17 +120,6 @@ pub fn get_all_rules() -> HashMap<&'static str, LintRuleDoc> {
         suggestion: "Agregar fila de encabezado y separador |---|.",
     });
     
-    rules.insert("L010", LintRuleDoc {
-        code: "L010",
-        name: "Image Alt Text",
-        description: "Las imágenes deben tener texto alternativo (alt text).",
-        impact: "⚠️ Medio - Afecta accesibilidad y SEO.",
-        example_bad: "![](imagen.png)",
-        example_good: "![Descripción de la imagen](imagen.png)",
-        auto_fixable: false,
-        suggestion: "Agregar descripción dentro de los corchetes: ![descripción](url).",
-    });
-    
     rules.insert("L011", LintRuleDoc {
         code: "L011",
         name: "Table Double Separator",
@@ -166,15 +155,108 @@ pub fn get_all_rules() -> HashMap<&'static str, LintRuleDoc> {
     
     rules.insert("L014", LintRuleDoc {
         code: "L014",
-        name: "Wikilink Absolute Path",
-        description: "Los wikilinks no deben usar paths absolutos con prefijo de proyecto.",
+        name: "Wikilink Convention",
+        description: "Los wikilinks deben seguir la convención del proyecto (validation.link_convention: name, id o path; name si no está configurada).",
         impact: "ℹ️ Bajo - Afecta portabilidad y legibilidad.",
-        example_bad: "[[Proyecto OnlyCarNLD/Datos/1.1. identidad]]",
-        example_good: "[[1.1. identidad]]",
+        example_bad: "[[2. Seguridad/2.8.1 Politicas]] (convención name)",
+        example_good: "[[2.8.1 Politicas]]",
         auto_fixable: false,
-        suggestion: "Revisar manualmente y usar paths relativos.",
+        suggestion: "Ejecutar: oc_diagdoc links --normalize",
+    });
+    
+    rules.insert("L015", LintRuleDoc {
+        code: "L015",
+        name: "Index Table Children",
+        description: "La tabla índice del padre debe listar cada hijo directo existente y ninguno inexistente.",
+        impact: "⚠️ Medio - La navegación omite documentos o lleva a enlaces rotos.",
+        example_bad: "| [[2.1 tarjeta]] | Tarjeta |\n| [[2.3 cheque]] | (no existe) |",
+        example_good: "| [[2.1 tarjeta]] | Tarjeta |\n| [[2.2 efectivo]] | Efectivo |",
+        auto_fixable: true,
+        suggestion: "Ejecutar: oc_diagdoc lint --fix --rule L015",
+    });
+    
+    rules.insert("L016", LintRuleDoc {
+        code: "L016",
+        name: "Empty Heading",
+        description: "Un heading no debe ir seguido directamente por otro del mismo o mayor nivel (sección vacía).",
+        impact: "ℹ️ Bajo - Suelen ser restos de plantilla que dejan secciones sin contenido.",
+        example_bad: "## Requisitos\n\n## Flujo",
+        example_good: "## Requisitos\n\nEl operador debe...\n\n## Flujo",
+        auto_fixable: true,
+        suggestion: "Ejecutar: oc_diagdoc lint --fix --rule L016 [--empty-headings remove]",
+    });
+    
+    rules.insert("L017", LintRuleDoc {
+        code: "L017",
+        name: "Unresolved Variable",
+        description: "Cada variable {{nombre}} debe estar definida en la sección variables de config (today es automática).",
+        impact: "⚠️ Medio - El export/compilado deja la sintaxis {{...}} sin sustituir.",
+        example_bad: "Manual de {{proyecto.nombre}}",
+        example_good: "Manual de {{project.name}}   # variables: {project: {name: OnlyCar}}",
+        auto_fixable: false,
+        suggestion: "Definir la variable en .oc_diagdoc/config.yaml o corregir su nombre.",
+    });
+    
+    rules.insert("L018", LintRuleDoc {
+        code: "L018",
+        name: "Style Guide",
+        description: "El texto debe respetar styleguide.yaml: sin términos prohibidos (forbidden) y con las mayúsculas de marca (brands).",
+        impact: "ℹ️ Bajo - Terminología inconsistente entre documentos.",
+        example_bad: "Descargue la app móvil de Onlycar",
+        example_good: "Descargue la aplicación de OnlyCar",
+        auto_fixable: true,
+        suggestion: "Con autofix: true en styleguide.yaml: oc_diagdoc lint --fix --rule L018",
     });
     
+    rules.insert("L019", LintRuleDoc {
+        code: "L019",
+        name: "Register Consistency",
+        description: "Los documentos de usuario final (tipos en register.by_type) deben tratar al lector siempre de tú, de usted o de vos.",
+        impact: "ℹ️ Bajo - Mezclar tratos resta claridad y profesionalismo a guías y textos de UX.",
+        example_bad: "Ingrese sus datos y revisa tus pedidos.",
+        example_good: "Ingrese sus datos y revise sus pedidos.",
+        auto_fixable: false,
+        suggestion: "Unificar el trato o fijarlo por tipo en la sección register de .oc_diagdoc/config.yaml.",
+    });
+    
+    rules.insert("L020", LintRuleDoc {
+        code: "L020",
+        name: "Problem Characters",
+        description: "Sin caracteres invisibles (ancho cero, BOM, guion suave) ni NBSP o guiones unicode en IDs y targets de links. Con --strict-utf8, tampoco NBSP en el texto, comillas tipográficas mezcladas con \" ni UTF-8 inválido.",
+        impact: "⚠️ Medio - [[1.2 alta]] con un NBSP o un guion unicode no coincide con el archivo y el link queda roto.",
+        example_bad: "[[1.2\u{00A0}alta]]   # NBSP invisible en el target",
+        example_good: "[[1.2 alta]]",
+        auto_fixable: true,
+        suggestion: "Normalizar con: oc_diagdoc lint --fix --rule L020 (agregar --strict-utf8 para el modo estricto)",
+    });
+    
+    rules.insert("L022", LintRuleDoc {
+        code: "L022",
+        name: "Table Header Cells",
+        description: "Cada columna de una tabla debe tener encabezado; las tablas HTML deben usar <th>.",
+        impact: "⚠️ Medio - Los lectores de pantalla anuncian cada celda con su encabezado; sin él la tabla es una lista de valores sueltos.",
+        example_bad: "| | Estado |\n|---|---|\n| 1.1 | activo |",
+        example_good: "| ID | Estado |\n|---|---|\n| 1.1 | activo |",
+        auto_fixable: false,
+        suggestion: "Nombrar cada columna. Se activa con: oc_diagdoc lint --category a11y",
+    });
+    
+    rules.insert("L023", LintRuleDoc {
+        code: "L023",
+        name: "Decorative Image Marking",
+        description: "El alt de una imagen debe describirla; si es decorativa, se marca con {.decorativa} y alt vacío. Un alt genérico (\"imagen\") o el nombre del archivo no sirven.",
+        impact: "ℹ️ Bajo - El lector de pantalla lee \"imagen\" o \"logo_v2.png\" sin aportar nada.",
+        example_bad: "![captura](img/paso1.png)\n![](separador.svg){width=100%}",
+        example_good: "![Formulario de alta con el campo email resaltado](img/paso1.png)\n![](separador.svg){.decorativa}",
+        auto_fixable: false,
+        suggestion: "Describir la imagen o marcarla decorativa. Se activa con: oc_diagdoc lint --category a11y",
+    });
+    
+    // Reglas declaradas con `lint_rule!`
+    for rule in crate::core::lint_rules::DECLARED_RULES {
+        rules.insert(rule.code, rule.doc());
+    }
+    
     rules
 }
 
@@ -212,7 +294,7 @@ pub fn print_rule_explanation(code: &str) {
         println!();
     } else {
         eprintln!("❌ Regla '{}' no encontrada.", code);
-        eprintln!("   Reglas válidas: L001-L014");
+        eprintln!("   Reglas válidas: L001-L023");
     }
 }
 
@@ -223,12 +305,20 @@ mod tests {
     #[test]
     fn test_get_all_rules() {
         let rules = get_all_rules();
-        assert_eq!(rules.len(), 14);
+        assert_eq!(rules.len(), 23);
         assert!(rules.contains_key("L006"));
         assert!(rules.contains_key("L011"));
         assert!(rules.contains_key("L012"));
         assert!(rules.contains_key("L013"));
         assert!(rules.contains_key("L014"));
+        assert!(rules.contains_key("L015"));
+        assert!(rules.contains_key("L016"));
+        assert!(rules.contains_key("L017"));
+        assert!(rules.contains_key("L018"));
+        assert!(rules.contains_key("L019"));
+        assert!(rules.contains_key("L020"));
+        assert!(rules.contains_key("L021"));
+        assert!(rules.contains_key("L023"));
     }
 
     
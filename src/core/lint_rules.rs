@@ -0,0 +1,147 @@
+//! Reglas de lint declarativas.
+//!
+//! Las reglas que son solo un patrón por línea se declaran con
+//! [`lint_rule!`](crate::lint_rule) en [`DECLARED_RULES`]: `lint` las
+//! ejecuta, `lint --explain` las documenta y `RuleRegistry::builtin` las
+//! registra sin más código. Las reglas con estado (tablas, jerarquía, fixes)
+//! siguen siendo métodos de `LintCommand`.
+
+use crate::core::lint_docs::LintRuleDoc;
+use crate::lint_rule;
+use crate::types::Severity;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Regla de lint declarada con `lint_rule!`.
+#[derive(Debug, Clone, Copy)]
+pub struct DeclaredRule {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub severity: Severity,
+    pub category: &'static str,
+    pub since: &'static str,
+    pub pattern: &'static str,
+    /// Mensaje del issue; `$0`, `$1`, `${nombre}` se expanden con el match.
+    pub message: &'static str,
+    pub description: &'static str,
+    pub impact: &'static str,
+    pub example_bad: &'static str,
+    pub example_good: &'static str,
+    pub suggestion: &'static str,
+}
+
+/// Reglas declarativas, en orden de código.
+pub static DECLARED_RULES: &[DeclaredRule] = &[
+    lint_rule! {
+        code: "L010",
+        name: "Image Alt Text",
+        severity: Warning,
+        category: "contenidos",
+        since: "3.0.0",
+        // Con bloque de atributos decide L023 (`{.decorativa}`)
+        pattern: r"!\[\]\([^)]+\)(?:[^{]|$)",
+        message: "Imagen sin alt text",
+        description: "Las imágenes deben tener texto alternativo (alt text).",
+        impact: "⚠️ Medio - Afecta accesibilidad y SEO.",
+        example_bad: "![](imagen.png)",
+        example_good: "![Descripción de la imagen](imagen.png)",
+        suggestion: "Agregar descripción dentro de los corchetes: ![descripción](url), o marcarla decorativa: ![](url){.decorativa}.",
+    },
+    lint_rule! {
+        code: "L021",
+        name: "Generic Link Text",
+        severity: Warning,
+        category: "a11y",
+        since: "3.2.0",
+        pattern: r"(?i)(?:^|[^!\]])\[\s*((?:haz\s+)?cli(?:c|ck)\s+(?:aquí|aqui|acá|aca|here)|aquí|aqui|acá|aca|here|enlace|este enlace|link|this link|leer más|leer mas|ver más|ver mas|más|mas|more|read more)\s*[.!]?\s*\]\(",
+        message: "Texto de enlace genérico: \"$1\"",
+        description: "El texto de un enlace debe decir a dónde lleva: los lectores de pantalla listan los enlaces fuera de contexto.",
+        impact: "⚠️ Medio - Una lista de \"clic aquí\" no permite elegir destino sin leer todo el texto.",
+        example_bad: "Para configurar pagos [clic aquí](pagos.md).",
+        example_good: "Ver [configuración de pagos](pagos.md).",
+        suggestion: "Usar como texto del enlace el título o el propósito del destino.",
+    },
+];
+
+static COMPILED: Lazy<HashMap<&'static str, Regex>> = Lazy::new(|| {
+    DECLARED_RULES
+        .iter()
+        .map(|rule| {
+            let regex = Regex::new(rule.pattern).unwrap_or_else(|e| panic!("patrón inválido en {}: {}", rule.code, e));
+            (rule.code, regex)
+        })
+        .collect()
+});
+
+impl DeclaredRule {
+    /// Issues de la regla: (línea 1-based, mensaje), uno por match.
+    pub fn check(&self, content: &str) -> Vec<(usize, String)> {
+        let compiled;
+        let regex = match COMPILED.get(self.code) {
+            Some(regex) => regex,
+            // Regla fuera de DECLARED_RULES (tests, plugins)
+            None => match Regex::new(self.pattern) {
+                Ok(regex) => {
+                    compiled = regex;
+                    &compiled
+                }
+                Err(_) => return Vec::new(),
+            },
+        };
+        let mut issues = Vec::new();
+        for (idx, line) in content.lines().enumerate() {
+            for caps in regex.captures_iter(line) {
+                let mut message = String::new();
+                caps.expand(self.message, &mut message);
+                issues.push((idx + 1, message));
+            }
+        }
+        issues
+    }
+
+    /// Documentación para `lint --explain`.
+    pub fn doc(&self) -> LintRuleDoc {
+        LintRuleDoc {
+            code: self.code,
+            name: self.name,
+            description: self.description,
+            impact: self.impact,
+            example_bad: self.example_bad,
+            example_good: self.example_good,
+            auto_fixable: false,
+            suggestion: self.suggestion,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declared_rules_compile_and_expand() {
+        assert_eq!(COMPILED.len(), DECLARED_RULES.len());
+        let l021 = DECLARED_RULES.iter().find(|r| r.code == "L021").unwrap();
+        let content = "# T\nPara pagos [clic aquí](pagos.md) y [alta](alta.md).\n";
+        assert_eq!(l021.check(content), vec![(2, "Texto de enlace genérico: \"clic aquí\"".to_string())]);
+
+        let custom = lint_rule! {
+            code: "X001",
+            name: "Todo",
+            severity: Hint,
+            category: "contenidos",
+            since: "3.2.0",
+            pattern: r"TODO\((?P<who>\w+)\)",
+            message: "Pendiente de ${who}",
+            description: "d",
+            impact: "i",
+            example_bad: "TODO(ana)",
+            example_good: "",
+            suggestion: "s",
+        };
+        assert_eq!(custom.severity, Severity::Hint);
+        assert_eq!(custom.check("a\nTODO(ana) y TODO(luis)\n"), vec![(2, "Pendiente de ana".to_string()), (2, "Pendiente de luis".to_string())]);
+        assert_eq!(custom.doc().code, "X001");
+    }
+}
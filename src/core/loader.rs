@@ -1,13 +1,24 @@
 //! Loader de proyectos.
 //!
 //! Integra FileScanner + YamlParser para cargar proyectos completos.
+//! Para proyectos gigantes, [`stream_files`] procesa por lotes liberando el
+//! contenido de cada lote (archivos grandes vía mmap) e [`index_project`]
+//! deja residentes solo ids, links y hashes. Para un solo pase,
+//! [`Project::iter`] lee y parsea un documento por vez. El frontmatter pasa
+//! por [`crate::core::frontmatter_cache`] para no re-parsear YAML sin cambios.
 
 use crate::core::config::OcConfig;
 use crate::core::files::{get_all_md_files, read_file_content, ScanOptions};
+use crate::core::frontmatter_cache;
+use crate::core::project_cache::CacheStore;
+use crate::core::yaml::YamlFrontmatter;
 use crate::data::document::Document;
 use crate::data::project::ProjectState;
 use crate::errors::{OcError, OcResult};
-use std::path::Path;
+use crate::types::cow::{intern, InternedStr};
+use crate::types::DocumentStatus;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
 
 /// Carga un proyecto completo desde un directorio.
 pub fn load_project(data_dir: impl AsRef<Path>) -> OcResult<ProjectState> {
@@ -28,7 +39,7 @@ pub fn load_project(data_dir: impl AsRef<Path>) -> OcResult<ProjectState> {
     let options = ScanOptions::new();
     let files = get_all_md_files(data_dir, &options)?;
 
-    // Parsear documentos usando Document::from_file
+    // Parsear documentos usando Document::from_file (con la caché de frontmatter)
     let mut documents = Vec::new();
     for file_path in files {
         match Document::from_file(&file_path) {
@@ -47,6 +58,19 @@ pub fn load_project(data_dir: impl AsRef<Path>) -> OcResult<ProjectState> {
     Ok(state)
 }
 
+/// Como [`load_project`], pero reutiliza el estado guardado en
+/// `.oc_diagdoc/cache/state/` (ver [`crate::core::project_cache`]) si ningún
+/// archivo del proyecto cambió desde la última carga.
+pub fn load_project_cached(data_dir: impl AsRef<Path>) -> OcResult<ProjectState> {
+    let data_dir = data_dir.as_ref();
+    let store = match CacheStore::project() {
+        Some(store) if data_dir.exists() => store,
+        _ => return load_project(data_dir),
+    };
+    let files = get_all_md_files(data_dir, &ScanOptions::new())?;
+    store.get_or_build(&data_dir.to_path_buf(), &files, || load_project(data_dir))
+}
+
 /// Calcula estadísticas rápidas sin cargar todo el proyecto.
 pub fn quick_stats(data_dir: impl AsRef<Path>) -> OcResult<QuickStats> {
     let data_dir = data_dir.as_ref();
@@ -81,10 +105,331 @@ pub struct QuickStats {
 
 impl QuickStats {
     pub fn avg_words_per_file(&self) -> usize {
-        if self.file_count == 0 {
-            0
+        self.total_words.checked_div(self.file_count).unwrap_or(0)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// STREAMING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Lote por defecto del modo streaming.
+pub const DEFAULT_BATCH_SIZE: usize = 500;
+/// Desde este tamaño los archivos se leen vía mmap (1 MiB).
+pub const MMAP_THRESHOLD: u64 = 1024 * 1024;
+
+/// Opciones del modo streaming.
+#[derive(Debug, Clone)]
+pub struct StreamOptions {
+    /// Documentos en memoria a la vez.
+    pub batch_size: usize,
+    /// Tamaño a partir del cual se usa mmap en vez de leer a un `String`.
+    pub mmap_threshold: u64,
+}
+
+impl Default for StreamOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: DEFAULT_BATCH_SIZE,
+            mmap_threshold: MMAP_THRESHOLD,
+        }
+    }
+}
+
+impl StreamOptions {
+    pub fn with_batch_size(batch_size: usize) -> Self {
+        Self {
+            batch_size: batch_size.max(1),
+            ..Self::default()
+        }
+    }
+}
+
+/// Contenido de un archivo: leído a memoria o mapeado.
+pub enum FileContent {
+    Owned(String),
+    Mapped(memmap2::Mmap),
+}
+
+impl FileContent {
+    /// Lee `path`, mapeándolo si supera `mmap_threshold`. Falla si no es UTF-8.
+    pub fn open(path: &Path, mmap_threshold: u64) -> OcResult<Self> {
+        let read_err = |e| OcError::FileRead {
+            path: path.to_path_buf(),
+            source: e,
+        };
+        let file = std::fs::File::open(path).map_err(read_err)?;
+        let len = file.metadata().map_err(read_err)?.len();
+        if len < mmap_threshold || len == 0 {
+            return read_file_content(path).map(Self::Owned);
+        }
+        // SAFETY: el mapa es de solo lectura y vive lo que dura el lote; si
+        // otro proceso trunca el archivo mientras tanto, el contenido leído
+        // puede ser inconsistente (igual que con una lectura concurrente).
+        let map = unsafe { memmap2::Mmap::map(&file) }.map_err(read_err)?;
+        std::str::from_utf8(&map).map_err(|e| {
+            read_err(std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+        })?;
+        Ok(Self::Mapped(map))
+    }
+
+    pub fn is_mapped(&self) -> bool {
+        matches!(self, Self::Mapped(_))
+    }
+}
+
+impl std::ops::Deref for FileContent {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            Self::Owned(s) => s,
+            // Validado como UTF-8 en `open`.
+            Self::Mapped(map) => std::str::from_utf8(map).unwrap_or_default(),
+        }
+    }
+}
+
+/// Reporte de memoria del modo streaming.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StreamReport {
+    pub files: usize,
+    pub batches: usize,
+    pub batch_size: usize,
+    /// Archivos leídos vía mmap.
+    pub mapped_files: usize,
+    pub bytes_read: u64,
+    /// Archivos ilegibles (omitidos).
+    pub skipped: usize,
+    /// Memoria residente pico del proceso (solo Linux).
+    pub peak_memory_kb: Option<u64>,
+}
+
+/// Procesa `files` en lotes de `batch_size`: cada lote se lee, se pasa a
+/// `on_batch` y se libera antes de leer el siguiente.
+pub fn stream_files(
+    files: &[PathBuf],
+    options: &StreamOptions,
+    mut on_batch: impl FnMut(&[(PathBuf, FileContent)]),
+) -> StreamReport {
+    let mut report = StreamReport {
+        batch_size: options.batch_size.max(1),
+        ..Default::default()
+    };
+    for chunk in files.chunks(report.batch_size) {
+        let batch: Vec<(PathBuf, FileContent)> = chunk
+            .iter()
+            .filter_map(|path| match FileContent::open(path, options.mmap_threshold) {
+                Ok(content) => Some((path.clone(), content)),
+                Err(_) => {
+                    report.skipped += 1;
+                    None
+                }
+            })
+            .collect();
+        report.batches += 1;
+        report.files += batch.len();
+        report.mapped_files += batch.iter().filter(|(_, c)| c.is_mapped()).count();
+        report.bytes_read += batch.iter().map(|(_, c)| c.len() as u64).sum::<u64>();
+        on_batch(&batch);
+    }
+    report.peak_memory_kb = crate::core::timings::peak_memory_kb();
+    report
+}
+
+/// Lo que queda residente de un documento en modo streaming.
+///
+/// IDs y destinos de enlaces se internan: un ID enlazado desde cientos de
+/// documentos ocupa memoria una sola vez.
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexedDocument {
+    pub path: PathBuf,
+    pub id: Option<InternedStr>,
+    pub parent: Option<InternedStr>,
+    /// Targets de wiki-links (sin alias).
+    pub links: Vec<InternedStr>,
+    pub content_hash: String,
+    pub words: usize,
+}
+
+impl IndexedDocument {
+    pub fn from_content(path: &Path, content: &str) -> Self {
+        use crate::types::breadcrumb::frontmatter_value;
+        use crate::types::lifetimes::split_frontmatter;
+
+        let split = split_frontmatter(content);
+        let field = |name: &str| {
+            split
+                .as_ref()
+                .and_then(|s| frontmatter_value(s.metadata, name))
+                .map(|v| intern(&v))
+        };
+        let body = split.as_ref().map_or(content, |s| s.content);
+        let mut links: Vec<InternedStr> = crate::core::links::extract_links(body)
+            .into_iter()
+            .filter(|l| l.is_internal())
+            .map(|l| intern(&l.target))
+            .collect();
+        links.dedup();
+        Self {
+            path: path.to_path_buf(),
+            id: field("id"),
+            parent: field("parent"),
+            links,
+            content_hash: crate::types::ContentHash::compute(content).full().to_string(),
+            words: body.split_whitespace().count(),
+        }
+    }
+}
+
+/// Índice del proyecto construido en streaming.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectIndex {
+    pub documents: Vec<IndexedDocument>,
+    pub report: StreamReport,
+}
+
+/// Indexa el proyecto por lotes sin retener el contenido.
+pub fn index_project(data_dir: impl AsRef<Path>, options: &StreamOptions) -> OcResult<ProjectIndex> {
+    let files = get_all_md_files(data_dir.as_ref(), &ScanOptions::new())?;
+    let mut documents = Vec::with_capacity(files.len());
+    let report = stream_files(&files, options, |batch| {
+        documents.extend(batch.iter().map(|(path, content)| IndexedDocument::from_content(path, content)));
+    });
+    Ok(ProjectIndex { documents, report })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROJECT ITER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Punto de entrada de la API de iteración.
+///
+/// ```rust,ignore
+/// use oc_diagdoc_lib::{DocumentStatus, Project};
+///
+/// for doc in Project::iter("Datos").filter_status(DocumentStatus::Active) {
+///     let doc = doc?;
+///     println!("{}", doc.path.display());
+/// }
+/// ```
+pub struct Project;
+
+impl Project {
+    /// Itera perezosamente los documentos bajo `data_dir`.
+    pub fn iter(data_dir: impl AsRef<Path>) -> ProjectIter {
+        ProjectIter::new(data_dir)
+    }
+}
+
+/// Documento leído por [`ProjectIter`].
+#[derive(Debug, Clone)]
+pub struct ProjectEntry {
+    pub path: PathBuf,
+    /// Contenido completo del archivo.
+    pub content: String,
+    /// Frontmatter parseado (solo con `with_frontmatter` o `filter_status`).
+    pub frontmatter: Option<YamlFrontmatter>,
+}
+
+impl ProjectEntry {
+    /// Estado del documento; sin frontmatter o con un estado desconocido
+    /// cuenta como borrador.
+    pub fn status(&self) -> DocumentStatus {
+        self.frontmatter
+            .as_ref()
+            .and_then(|fm| fm.status.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Parsea el documento completo (body, enlaces, hash).
+    pub fn into_document(self) -> OcResult<Document> {
+        let parsed = frontmatter_cache::parse(&self.path, &self.content)?;
+        Ok(Document::from_parsed(self.path, parsed))
+    }
+}
+
+/// Iterador perezoso de documentos: cada `next()` lee y parsea un solo
+/// archivo, así una herramienta de un solo pase no retiene el árbol. Por
+/// adelantado solo se listan las rutas, en el primer `next()`; si el
+/// directorio no existe, ese primer elemento es el error.
+pub struct ProjectIter {
+    data_dir: PathBuf,
+    options: ScanOptions,
+    files: Option<std::vec::IntoIter<PathBuf>>,
+    frontmatter: bool,
+    status: Option<DocumentStatus>,
+}
+
+impl ProjectIter {
+    pub fn new(data_dir: impl AsRef<Path>) -> Self {
+        Self {
+            data_dir: data_dir.as_ref().to_path_buf(),
+            options: ScanOptions::new(),
+            files: None,
+            frontmatter: false,
+            status: None,
+        }
+    }
+
+    /// Opciones de escaneo (profundidad, exclusiones, symlinks).
+    pub fn with_options(mut self, options: ScanOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Parsea el frontmatter y omite los documentos sin uno válido.
+    pub fn with_frontmatter(mut self) -> Self {
+        self.frontmatter = true;
+        self
+    }
+
+    /// Solo documentos con este estado (implica `with_frontmatter`).
+    pub fn filter_status(mut self, status: DocumentStatus) -> Self {
+        self.frontmatter = true;
+        self.status = Some(status);
+        self
+    }
+
+    /// Lee un archivo; `None` si los filtros lo descartan.
+    fn load(&self, path: PathBuf) -> OcResult<Option<ProjectEntry>> {
+        let content = read_file_content(&path)?;
+        let frontmatter = if self.frontmatter {
+            match frontmatter_cache::parse(&path, &content) {
+                Ok(parsed) => Some(parsed.frontmatter),
+                Err(_) => return Ok(None),
+            }
         } else {
-            self.total_words / self.file_count
+            None
+        };
+        let entry = ProjectEntry { path, content, frontmatter };
+        if self.status.is_some_and(|status| entry.status() != status) {
+            return Ok(None);
+        }
+        Ok(Some(entry))
+    }
+}
+
+impl Iterator for ProjectIter {
+    type Item = OcResult<ProjectEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.files.is_none() {
+            match get_all_md_files(&self.data_dir, &self.options) {
+                Ok(files) => self.files = Some(files.into_iter()),
+                Err(e) => {
+                    self.files = Some(Vec::new().into_iter());
+                    return Some(Err(e));
+                }
+            }
+        }
+        loop {
+            let path = self.files.as_mut()?.next()?;
+            match self.load(path) {
+                Ok(Some(entry)) => return Some(Ok(entry)),
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            }
         }
     }
 }
@@ -130,4 +475,54 @@ Some words here.
         assert_eq!(stats.file_count, 2);
         assert!(stats.total_words > 0);
     }
+
+    #[test]
+    fn test_index_project_in_batches() {
+        let temp = TempDir::new().unwrap();
+        for id in ["1", "2", "3"] {
+            create_test_doc(temp.path(), &format!("{}.md", id), id);
+        }
+        fs::write(temp.path().join("4.md"), "---\nid: \"4\"\nparent: \"1\"\n---\n[[1]] y [[2|dos]]\n").unwrap();
+
+        let options = StreamOptions { batch_size: 2, mmap_threshold: 100 };
+        let index = index_project(temp.path(), &options).unwrap();
+        assert_eq!(index.documents.len(), 4);
+        assert_eq!(index.report.batches, 2);
+        assert_eq!(index.report.mapped_files, 3);
+
+        let doc = index.documents.iter().find(|d| d.id.as_deref() == Some("4")).unwrap();
+        assert_eq!(doc.parent.as_deref(), Some("1"));
+        assert_eq!(doc.links, vec![intern("1"), intern("2")]);
+        let target = index.documents.iter().find(|d| d.id.as_deref() == Some("1")).unwrap();
+        assert!(doc.links[0].ptr_eq(target.id.as_ref().unwrap()));
+        assert_eq!(doc.content_hash.len(), 64);
+    }
+
+    #[test]
+    fn test_project_iter_filters() {
+        let temp = TempDir::new().unwrap();
+        create_test_doc(temp.path(), "1.md", "1");
+        create_test_doc(temp.path(), "2.md", "2");
+        fs::write(temp.path().join("3.md"), "---\nid: \"3\"\ntitle: T\nstatus: activo\n---\nTexto\n").unwrap();
+        fs::write(temp.path().join("4.md"), "Sin frontmatter\n").unwrap();
+
+        assert_eq!(Project::iter(temp.path()).count(), 4);
+        assert_eq!(Project::iter(temp.path()).with_frontmatter().count(), 3);
+        let drafts: Vec<ProjectEntry> = Project::iter(temp.path())
+            .filter_status(DocumentStatus::Draft)
+            .collect::<OcResult<_>>()
+            .unwrap();
+        assert_eq!(drafts.len(), 2);
+        let active: Vec<Document> = Project::iter(temp.path())
+            .filter_status(DocumentStatus::Active)
+            .map(|entry| entry.and_then(ProjectEntry::into_document))
+            .collect::<OcResult<_>>()
+            .unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].title(), "T");
+
+        let mut missing = Project::iter(temp.path().join("nada"));
+        assert!(missing.next().unwrap().is_err());
+        assert!(missing.next().is_none());
+    }
 }
@@ -6,19 +6,53 @@
 //!
 //! | Módulo | Descripción |
 //! |--------|-------------|
+//! | [`adr`] | Registro de decisiones de arquitectura (`gen adr`) |
+//! | [`anchors`] | Anchors estables `{#slug}` para headings |
+//! | [`charset`] | Caracteres invisibles y unicode problemáticos (L020) |
 //! | [`cli`] | Parseador de argumentos CLI con clap |
+//! | [`classification`] | Clasificación de sensibilidad (`export --audience`) |
 //! | [`config`] | Configuración global del proyecto ([`OcConfig`]) |
 //! | [`docs`] | Utilidades para manipulación de documentos |
+//! | [`editor`] | Apertura de issues en `$EDITOR` y enlaces OSC 8 |
+//! | [`embeddings`] | Índice de embeddings y búsqueda semántica |
 //! | [`files`] | Sistema de archivos: escaneo, lectura, escritura atómica |
+//! | [`frontmatter_cache`] | Caché de frontmatter parseado (`.oc_diagdoc/cache/frontmatter.bin`) |
+//! | [`fulltext`] | Índice full-text y consultas booleanas (`search --boolean`) |
+//! | [`fixes`] | Aplicación por etapas de fixes de todos los subsistemas |
 //! | [`graph`] | Grafo de dependencias y detección de ciclos |
 //! | [`hash`] | Hashing SHA-256 con cache inteligente |
+//! | [`hbs`] | Plantillas estilo Handlebars (`report --template`) |
+//! | [`health_history`] | Historial de salud por módulo (`dashboard`) |
+//! | [`http`] | POST JSON mínimo vía `curl` |
+//! | [`incremental`] | Manifest de builds incrementales (`export`/`compress --incremental`) |
 //! | [`links`] | Resolución de wiki-links `[[target]]` |
+//! | [`lint_rules`] | Reglas de lint declarativas (`lint_rule!`) |
 //! | [`loader`] | Cargador de proyectos completos |
+//! | [`patch`] | Diff por hunks y revisión previa a escribir |
 //! | [`patterns`] | Patrones regex precompilados con Lazy |
-//! | [`pipeline`] | Pipeline de procesamiento por etapas |
-//! | [`registry`] | Registro de comandos disponibles |
+//! | [`phase_docs`] | Catálogo de las fases de verify |
+//! | [`pipeline`] | Pipeline por etapas y caché de documentos compartida |
+//! | [`project_cache`] | Persistencia de estado e índices `Cacheable` (`.oc_diagdoc/cache/state/`) |
+//! | [`redact`] | Redacción de datos sensibles en exports |
+//! | [`register`] | Consistencia de trato tú/usted/vos |
+//! | [`registry`] | Registro de comandos disponibles y de reglas (`RuleRegistry`) |
+//! | [`reviews`] | Revisiones asignadas con vencimiento (fase 28, `audit --due-soon`) |
 //! | [`release`] | Información de versión y release |
+//! | [`secrets`] | Detección de secretos y credenciales (fase 27) |
+//! | [`redirects`] | Redirects de documentos renombrados (`.oc_diagdoc/redirects.json`) |
 //! | [`schema`] | Validación de frontmatter YAML |
+//! | [`sitemap`] | `sitemap.xml` y `nav.json` para la web pública (`export --sitemap`) |
+//! | [`slugs`] | Slugs estables y redirects (`gen slugs`, `_index/slugs.json`) |
+//! | [`starters`] | Plantillas de proyecto embebidas (`init --template`) |
+//! | [`styleguide`] | Guía de estilo: términos prohibidos y marcas (`styleguide.yaml`) |
+//! | [`summary`] | Resúmenes extractivos (oraciones + headings) |
+//! | [`timings`] | Perfil de rendimiento para `--timings` |
+//! | [`terms`] | Frecuencia de términos sin stopwords |
+//! | [`tokens`] | Conteo de tokens para presupuestos LLM |
+//! | [`transclude`] | Expansión de embeds `![[Doc#Sección]]` |
+//! | [`usage`] | Telemetría local de uso, opt-in (`stats --usage`) |
+//! | [`variables`] | Variables de proyecto `{{project.name}}` |
+//! | [`webhook`] | Notificaciones a Slack/Teams/webhook |
 //! | [`yaml`] | Parser de YAML con fallbacks |
 //!
 //! ## Uso básico
@@ -30,23 +64,57 @@
 //! let project = load_project("Datos")?;
 //! ```
 
+pub mod adr;
+pub mod anchors;
+pub mod charset;
 pub mod cli;
+pub mod classification;
 pub mod config;
 pub mod docs;
+pub mod editor;
+pub mod embeddings;
 pub mod files;
 pub mod fix_router;  // RFC-02
+pub mod fixes;
+pub mod frontmatter_cache;
+pub mod fulltext;
 pub mod graph;
 pub mod hash;
+pub mod hbs;
+pub mod health_history;
+pub mod http;
+pub mod incremental;
 pub mod links;
 pub mod lint_docs;   // RFC-03
+pub mod lint_rules;
 pub mod loader;
+pub mod patch;
 pub mod patterns;
+pub mod phase_docs;
 pub mod pipeline;
+pub mod project_cache;
+pub mod redact;
+pub mod register;
 pub mod registry;
 pub mod release;
+pub mod redirects;
+pub mod reviews;
 pub mod schema;
+pub mod secrets;
+pub mod sitemap;
+pub mod slugs;
+pub mod starters;
+pub mod styleguide;
+pub mod summary;
+pub mod terms;
+pub mod timings;
+pub mod tokens;
+pub mod transclude;
+pub mod usage;
+pub mod variables;
+pub mod webhook;
 pub mod yaml;
 
 pub use config::OcConfig;
-pub use loader::{load_project, quick_stats};
+pub use loader::{load_project, load_project_cached, quick_stats, Project, ProjectEntry, ProjectIter};
 
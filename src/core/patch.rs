@@ -0,0 +1,391 @@
+//! Diff por líneas y revisión de cambios antes de escribir.
+//!
+//! Genera hunks estilo `git diff` (unified) entre el contenido original y el
+//! corregido, los renderiza con colores y permite aceptar/rechazar hunk por
+//! hunk antes de escribir el archivo.
+
+use crate::errors::{OcError, OcResult};
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Líneas de contexto por defecto alrededor de cada hunk.
+pub const DEFAULT_CONTEXT: usize = 3;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TIPOS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Tipo de línea dentro de un hunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    Context,
+    Removed,
+    Added,
+}
+
+/// Línea de un hunk (conserva el salto de línea original).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffLine {
+    pub kind: LineKind,
+    pub text: String,
+}
+
+/// Bloque contiguo de cambios con su contexto.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hunk {
+    /// Línea inicial en el original (base 0).
+    pub old_start: usize,
+    pub old_len: usize,
+    /// Línea inicial en el nuevo contenido (base 0).
+    pub new_start: usize,
+    pub new_len: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+impl Hunk {
+    /// Cabecera `@@ -a,b +c,d @@`.
+    pub fn header(&self) -> String {
+        format!(
+            "@@ -{},{} +{},{} @@",
+            self.old_start + 1,
+            self.old_len,
+            self.new_start + 1,
+            self.new_len
+        )
+    }
+
+    pub fn added(&self) -> usize {
+        self.lines.iter().filter(|l| l.kind == LineKind::Added).count()
+    }
+
+    pub fn removed(&self) -> usize {
+        self.lines.iter().filter(|l| l.kind == LineKind::Removed).count()
+    }
+}
+
+/// Diff de un archivo completo.
+#[derive(Debug, Clone)]
+pub struct FilePatch {
+    pub path: PathBuf,
+    pub hunks: Vec<Hunk>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CÁLCULO DEL DIFF
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Divide el contenido en líneas conservando el `\n` final de cada una.
+fn split_lines(content: &str) -> Vec<&str> {
+    content.split_inclusive('\n').collect()
+}
+
+/// Operaciones de edición línea a línea (Myers en espacio lineal: un
+/// documento de miles de líneas no reserva una tabla n·m).
+fn diff_ops<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(LineKind, &'a str)> {
+    use similar::{capture_diff_slices, Algorithm, ChangeTag};
+
+    capture_diff_slices(Algorithm::Myers, old, new)
+        .iter()
+        .flat_map(|op| op.iter_changes(old, new))
+        .map(|change| {
+            let kind = match change.tag() {
+                ChangeTag::Equal => LineKind::Context,
+                ChangeTag::Delete => LineKind::Removed,
+                ChangeTag::Insert => LineKind::Added,
+            };
+            (kind, change.value())
+        })
+        .collect()
+}
+
+/// Calcula los hunks entre `old` y `new` con `context` líneas de contexto.
+pub fn compute_hunks(old: &str, new: &str, context: usize) -> Vec<Hunk> {
+    let old_lines = split_lines(old);
+    let new_lines = split_lines(new);
+    let ops = diff_ops(&old_lines, &new_lines);
+
+    // Índices de operaciones que son cambios
+    let changes: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (k, _))| *k != LineKind::Context)
+        .map(|(i, _)| i)
+        .collect();
+
+    if changes.is_empty() {
+        return Vec::new();
+    }
+
+    // Agrupar cambios cercanos en rangos [start, end) de ops
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &idx in &changes {
+        let start = idx.saturating_sub(context);
+        let end = (idx + 1 + context).min(ops.len());
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    // Posición (old, new) al inicio de cada op
+    let mut positions = Vec::with_capacity(ops.len() + 1);
+    let (mut o, mut n) = (0usize, 0usize);
+    for (kind, _) in &ops {
+        positions.push((o, n));
+        match kind {
+            LineKind::Context => {
+                o += 1;
+                n += 1;
+            }
+            LineKind::Removed => o += 1,
+            LineKind::Added => n += 1,
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| {
+            let (old_start, new_start) = positions[start];
+            let lines: Vec<DiffLine> = ops[start..end]
+                .iter()
+                .map(|(kind, text)| DiffLine {
+                    kind: *kind,
+                    text: text.to_string(),
+                })
+                .collect();
+            let old_len = lines.iter().filter(|l| l.kind != LineKind::Added).count();
+            let new_len = lines.iter().filter(|l| l.kind != LineKind::Removed).count();
+            Hunk {
+                old_start,
+                old_len,
+                new_start,
+                new_len,
+                lines,
+            }
+        })
+        .collect()
+}
+
+impl FilePatch {
+    /// Calcula el patch de un archivo.
+    pub fn compute(path: impl Into<PathBuf>, old: &str, new: &str, context: usize) -> Self {
+        Self {
+            path: path.into(),
+            hunks: compute_hunks(old, new, context),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hunks.is_empty()
+    }
+
+    /// Renderiza el patch en formato unified (coloreado si `color`).
+    pub fn render(&self, color: bool) -> String {
+        let mut out = String::new();
+        let name = self.path.display().to_string();
+        let header = format!("--- a/{}\n+++ b/{}\n", name, name);
+        out.push_str(&if color { header.bold().to_string() } else { header });
+        for hunk in &self.hunks {
+            out.push_str(&render_hunk(hunk, color));
+        }
+        out
+    }
+
+    /// Aplica sobre `old` solo los hunks aceptados (`accepted[i]`).
+    pub fn apply(&self, old: &str, accepted: &[bool]) -> String {
+        let old_lines = split_lines(old);
+        let mut out = String::with_capacity(old.len());
+        let mut cursor = 0usize;
+
+        for (hunk, keep) in self.hunks.iter().zip(accepted.iter()) {
+            for line in &old_lines[cursor..hunk.old_start] {
+                out.push_str(line);
+            }
+            for line in &hunk.lines {
+                let emit = match line.kind {
+                    LineKind::Context => true,
+                    LineKind::Removed => !keep,
+                    LineKind::Added => *keep,
+                };
+                if emit {
+                    out.push_str(&line.text);
+                }
+            }
+            cursor = hunk.old_start + hunk.old_len;
+        }
+        for line in &old_lines[cursor.min(old_lines.len())..] {
+            out.push_str(line);
+        }
+        out
+    }
+}
+
+/// Renderiza un hunk individual.
+pub fn render_hunk(hunk: &Hunk, color: bool) -> String {
+    let mut out = String::new();
+    let header = hunk.header();
+    out.push_str(&if color { header.cyan().to_string() } else { header });
+    out.push('\n');
+    for line in &hunk.lines {
+        let text = line.text.trim_end_matches(['\n', '\r']);
+        let rendered = match line.kind {
+            LineKind::Context => format!(" {}", text),
+            LineKind::Removed if color => format!("-{}", text).red().to_string(),
+            LineKind::Removed => format!("-{}", text),
+            LineKind::Added if color => format!("+{}", text).green().to_string(),
+            LineKind::Added => format!("+{}", text),
+        };
+        out.push_str(&rendered);
+        out.push('\n');
+        if !line.text.ends_with('\n') {
+            out.push_str("\\ No newline at end of file\n");
+        }
+    }
+    out
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REVISIÓN ANTES DE ESCRIBIR
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Opciones de revisión de cambios (`--diff` / `--interactive`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PatchReview {
+    /// Mostrar diff antes de escribir.
+    pub show_diff: bool,
+    /// Preguntar hunk por hunk.
+    pub interactive: bool,
+    /// No escribir nada.
+    pub dry_run: bool,
+}
+
+impl PatchReview {
+    pub fn new(show_diff: bool, interactive: bool, dry_run: bool) -> Self {
+        Self {
+            show_diff,
+            interactive,
+            dry_run,
+        }
+    }
+
+    /// Revisa el cambio `old → new` y devuelve el contenido final a escribir.
+    ///
+    /// Retorna `None` si no hay cambios o si todos los hunks fueron rechazados.
+    pub fn review(&self, path: &Path, old: &str, new: &str) -> OcResult<Option<String>> {
+        if old == new {
+            return Ok(None);
+        }
+        if !self.show_diff && !self.interactive {
+            return Ok(Some(new.to_string()));
+        }
+
+        let patch = FilePatch::compute(path, old, new, DEFAULT_CONTEXT);
+        if patch.is_empty() {
+            return Ok(None);
+        }
+
+        let color = crate::ui::theme::colors_enabled();
+        if !self.interactive {
+            print!("{}", patch.render(color));
+            return Ok(Some(new.to_string()));
+        }
+
+        let name = path.display().to_string();
+        println!("{}", if color { name.bold().to_string() } else { name });
+        let mut accepted = Vec::with_capacity(patch.hunks.len());
+        for (i, hunk) in patch.hunks.iter().enumerate() {
+            print!("{}", render_hunk(hunk, color));
+            let keep = dialoguer::Confirm::new()
+                .with_prompt(format!("¿Aplicar hunk {}/{}?", i + 1, patch.hunks.len()))
+                .default(true)
+                .interact()
+                .map_err(|e| OcError::Custom(format!("Entrada interactiva: {}", e)))?;
+            accepted.push(keep);
+        }
+
+        if !accepted.iter().any(|k| *k) {
+            return Ok(None);
+        }
+        Ok(Some(patch.apply(old, &accepted)))
+    }
+
+    /// Revisa y escribe. Retorna `true` si el archivo cambió (o cambiaría en dry-run).
+    pub fn write(&self, path: &Path, old: &str, new: &str) -> OcResult<bool> {
+        match self.review(path, old, new)? {
+            Some(content) => {
+                if !self.dry_run {
+                    fs::write(path, &content).map_err(|e| OcError::FileWrite {
+                        path: path.to_path_buf(),
+                        source: e,
+                    })?;
+                }
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_changes() {
+        assert!(compute_hunks("a\nb\n", "a\nb\n", 3).is_empty());
+    }
+
+    #[test]
+    fn test_single_hunk() {
+        let hunks = compute_hunks("a\nb\nc\n", "a\nB\nc\n", 1);
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].header(), "@@ -1,3 +1,3 @@");
+        assert_eq!(hunks[0].added(), 1);
+        assert_eq!(hunks[0].removed(), 1);
+    }
+
+    #[test]
+    fn test_separate_hunks() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+        let new = "1\nX\n3\n4\n5\n6\n7\n8\nY\n10\n";
+        assert_eq!(compute_hunks(old, new, 1).len(), 2);
+        assert_eq!(compute_hunks(old, new, 5).len(), 1);
+    }
+
+    #[test]
+    fn test_apply_partial() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+        let new = "1\nX\n3\n4\n5\n6\n7\n8\nY\n10\n";
+        let patch = FilePatch::compute("t.md", old, new, 1);
+        assert_eq!(patch.apply(old, &[true, true]), new);
+        assert_eq!(patch.apply(old, &[false, false]), old);
+        assert_eq!(patch.apply(old, &[false, true]), "1\n2\n3\n4\n5\n6\n7\n8\nY\n10\n");
+    }
+
+    #[test]
+    fn test_large_document() {
+        // Cambios repartidos: el recorte de prefijo/sufijo no acota nada
+        let old: String = (0..6000).map(|i| if i % 50 == 0 { format!("l{} \n", i) } else { format!("l{}\n", i) }).collect();
+        let new: String = (0..6000).map(|i| format!("l{}\n", i)).collect();
+        let patch = FilePatch::compute("t.md", &old, &new, 0);
+        assert_eq!(patch.hunks.len(), 120);
+        assert_eq!(patch.apply(&old, &[true; 120]), new);
+    }
+
+    #[test]
+    fn test_missing_final_newline() {
+        let patch = FilePatch::compute("t.md", "a\nb", "a\nb\n", 3);
+        assert_eq!(patch.apply("a\nb", &[true]), "a\nb\n");
+        assert!(patch.render(false).contains("No newline at end of file"));
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let patch = FilePatch::compute("t.md", "a \n", "a\n", 3);
+        let out = patch.render(false);
+        assert!(out.contains("--- a/t.md"));
+        assert!(out.contains("-a "));
+        assert!(out.contains("+a"));
+    }
+}
@@ -50,6 +50,10 @@ pub static RE_TITLE: Lazy<Regex> =
 pub static RE_STATUS: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#"status:\s*["']?([^"'\n]+)["']?"#).unwrap());
 
+/// Captura `priority: <valor>` del frontmatter.
+pub static RE_PRIORITY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"priority:\s*["']?([^"'\n]+)["']?"#).unwrap());
+
 /// Captura `last_updated: <valor>` del frontmatter.
 pub static RE_LAST_UPDATED: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#"last_updated:\s*["']?([^"'\n]+)["']?"#).unwrap());
@@ -62,6 +66,10 @@ pub static RE_CONTENT_HASH: Lazy<Regex> =
 pub static RE_CHILDREN_COUNT: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#"children_count:\s*(\d+)"#).unwrap());
 
+/// Captura `descendants_count: <número>` del frontmatter.
+pub static RE_DESCENDANTS_COUNT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?m)^descendants_count:\s*(\d+)"#).unwrap());
+
 /// Captura `total_children: <número>` del frontmatter.
 pub static RE_TOTAL_CHILDREN: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#"total_children:\s*(\d+)"#).unwrap());
@@ -0,0 +1,406 @@
+//! Catálogo central de las fases de `verify`.
+//!
+//! Fuente única de número, nombre, alias y documentación de cada fase;
+//! lo usan `verify` (resolución de `--phase` y `--explain`) y `help <fase>`.
+
+/// Documentación de una fase de verificación.
+#[derive(Debug, Clone)]
+pub struct PhaseDoc {
+    pub id: u8,
+    /// Nombre canónico (el que aparece en el reporte).
+    pub name: &'static str,
+    /// Nombres alternativos aceptados por `--phase`.
+    pub aliases: &'static [&'static str],
+    pub title: &'static str,
+    pub description: &'static str,
+    pub example_error: &'static str,
+    /// Por qué importa.
+    pub why: &'static str,
+    /// Cómo corregirlo a mano.
+    pub how_to_fix: &'static str,
+    /// Comandos que lo corrigen o ayudan a corregirlo.
+    pub fix_commands: &'static [&'static str],
+}
+
+/// Las fases en orden de ejecución.
+pub static PHASES: &[PhaseDoc] = &[
+    PhaseDoc {
+        id: 1,
+        name: "file_count",
+        aliases: &["files"],
+        title: "Conteo de archivos",
+        description: "Comprueba que el directorio de datos contenga al menos un archivo .md (excluye archivos de test como TRAP_ o AUTOTEST_); avisa de ciclos de symlinks, alias de un mismo archivo y documentos enlazados que viven fuera del data_dir.",
+        example_error: "No se encontraron archivos .md en el directorio",
+        why: "Sin documentos el resto de fases no tiene nada que verificar y un directorio mal configurado pasaría desapercibido.",
+        how_to_fix: "Revisa --path o data_dir en la configuración y que los documentos tengan extensión .md.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 2,
+        name: "yaml_validation",
+        aliases: &["yaml"],
+        title: "Validación YAML",
+        description: "Cada documento debe abrir y cerrar su frontmatter con '---' y declarar id, title, parent, breadcrumb, type y status; si trae classification, debe ser public, internal o confidential.",
+        example_error: "1.2 alta.md: Falta YAML: breadcrumb, status",
+        why: "El frontmatter es la fuente de la jerarquía, los breadcrumbs y los hashes; un campo ausente rompe las fases siguientes.",
+        how_to_fix: "Añade los campos que faltan al frontmatter; para documentos sin YAML usa migrate --adopt.",
+        fix_commands: &["oc_diagdoc migrate --adopt <dir> --parent <id>"],
+    },
+    PhaseDoc {
+        id: 3,
+        name: "unique_ids",
+        aliases: &["ids"],
+        title: "IDs únicos",
+        description: "Ningún ID ni `slug:` puede repetirse entre documentos; los slugs deben ser válidos.",
+        example_error: "ID DUPLICADO: '1.2' en 1.2 alta.md, 1.2 baja.md",
+        why: "Con IDs repetidos los enlaces, el árbol y los parents apuntan a un documento ambiguo.",
+        how_to_fix: "Renumera uno de los documentos; suggest-placement propone el siguiente ID libre bajo un padre. Para slugs, gen slugs reasigna los repetidos.",
+        fix_commands: &["oc_diagdoc suggest-placement <archivo>", "oc_diagdoc gen slugs"],
+    },
+    PhaseDoc {
+        id: 4,
+        name: "valid_parents",
+        aliases: &["parents"],
+        title: "Parents válidos",
+        description: "El campo parent debe apuntar a un ID existente (0 o vacío indica raíz).",
+        example_error: "1.2.1 detalle.md: Parent '1.9' no existe",
+        why: "Un parent inexistente deja al documento fuera del árbol y de la navegación.",
+        how_to_fix: "Corrige el campo parent para que apunte a un ID existente o crea el documento padre.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 5,
+        name: "breadcrumbs",
+        aliases: &["breadcrumb"],
+        title: "Breadcrumbs consistentes",
+        description: "El breadcrumb debe coincidir con la cadena real de títulos de los ancestros, desde la raíz hasta el documento.",
+        example_error: "1.2 alta.md: Breadcrumb inconsistente ('Pagos > Altas' ≠ canónico 'Pagos > Alta')",
+        why: "El breadcrumb es la ruta de navegación que ve el lector; si no refleja la jerarquía real, desorienta.",
+        how_to_fix: "Regenera los breadcrumbs desde la cadena de parents.",
+        fix_commands: &["oc_diagdoc fix --breadcrumbs"],
+    },
+    PhaseDoc {
+        id: 6,
+        name: "types",
+        aliases: &["type"],
+        title: "Types consistentes",
+        description: "El campo type debe pertenecer al vocabulario de tipos del proyecto (hoja, seccion, indice, ...).",
+        example_error: "1.2 alta.md: Type no estándar: 'pagina'",
+        why: "Los reportes, la exportación y las plantillas se apoyan en el vocabulario de tipos.",
+        how_to_fix: "Usa uno de los tipos estándar en el campo type.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 7,
+        name: "status",
+        aliases: &[],
+        title: "Status válidos",
+        description: "El campo status debe ser uno de los estados conocidos (activo, borrador, deprecado, ...). En los ADR (type: adr) debe ser propuesto, aceptado o supersedido, y un ADR supersedido debe enlazar a un ADR existente en superseded_by.",
+        example_error: "1.2 alta.md: Status no estándar: 'vigente'",
+        why: "El status decide qué se exporta y qué se considera publicado.",
+        how_to_fix: "Usa uno de los estados estándar en el campo status.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 8,
+        name: "dates_sync",
+        aliases: &["dates"],
+        title: "Fechas sincronizadas",
+        description: "last_updated no debe diferir más de 24 horas de la fecha de modificación del archivo.",
+        example_error: "1.2 alta.md: YAML date '2024-01-10' vs file mtime (>24h drift)",
+        why: "Una fecha desfasada hace que el documento parezca más viejo o más nuevo de lo que es y engaña a los reportes de frescura.",
+        how_to_fix: "Sincroniza last_updated con la fecha de modificación del archivo.",
+        fix_commands: &["oc_diagdoc fix --dates", "oc_diagdoc sync --dates-only"],
+    },
+    PhaseDoc {
+        id: 9,
+        name: "internal_links",
+        aliases: &["links"],
+        title: "Enlaces internos",
+        description: "Los wikilinks [[...]] deben apuntar a documentos existentes, respetando mayúsculas; [[Doc#Sección]] debe resolver a un heading o anchor y [[Doc#^id]] a un block-id del destino; un link que resuelve fuera del data_dir ([[../otro/Doc]]) o al nombre anterior de un documento renombrado (.oc_diagdoc/redirects.json) es warning.",
+        example_error: "1.2 alta.md: Link roto [[1.9 inexistente]]",
+        why: "Un enlace roto es un callejón sin salida para el lector y suele indicar un documento renombrado.",
+        how_to_fix: "Corrige el destino del enlace o crea el documento referenciado.",
+        fix_commands: &["oc_diagdoc links", "oc_diagdoc gen anchors", "oc_diagdoc fix --blocks"],
+    },
+    PhaseDoc {
+        id: 10,
+        name: "embeds",
+        aliases: &[],
+        title: "Embeds válidos",
+        description: "Los embeds ![[...]] deben referenciar documentos existentes; ![[Doc#Sección]] y ![[Doc^bloque]] deben resolver a un heading o block-id real del destino.",
+        example_error: "1.2 alta.md: Embed no existe ![[1.9 tabla]]",
+        why: "Un embed roto deja un hueco en el documento renderizado.",
+        how_to_fix: "Corrige el destino del embed o elimínalo; para secciones y bloques, revisa el heading o el marcador ^id del destino.",
+        fix_commands: &["oc_diagdoc links"],
+    },
+    PhaseDoc {
+        id: 11,
+        name: "images",
+        aliases: &[],
+        title: "Imágenes existentes",
+        description: "Las imágenes locales ![](ruta) deben existir en disco.",
+        example_error: "1.2 alta.md: Imagen no existe: img/flujo.png",
+        why: "Las imágenes faltantes se ven como enlaces rotos en la exportación.",
+        how_to_fix: "Agrega la imagen a la ruta indicada o corrige la ruta.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 12,
+        name: "code_blocks",
+        aliases: &["code"],
+        title: "Código blocks",
+        description: "Los bloques de código ``` deben estar cerrados (número par de delimitadores).",
+        example_error: "1.2 alta.md: Code block no cerrado (3 delimitadores)",
+        why: "Un bloque sin cerrar convierte el resto del documento en código.",
+        how_to_fix: "Cierra el bloque con ``` al final.",
+        fix_commands: &["oc_diagdoc lint --fix"],
+    },
+    PhaseDoc {
+        id: 13,
+        name: "mermaid",
+        aliases: &[],
+        title: "Diagramas Mermaid",
+        description: "Los bloques mermaid deben declarar un tipo de diagrama válido (graph, sequenceDiagram, ...).",
+        example_error: "1.2 alta.md: Mermaid sin tipo de diagrama válido",
+        why: "Un diagrama sin tipo no se renderiza.",
+        how_to_fix: "Empieza el bloque con un tipo de diagrama (graph TD, sequenceDiagram, ...).",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 14,
+        name: "tables",
+        aliases: &[],
+        title: "Tablas Markdown",
+        description: "Las tablas deben tener fila separadora bajo el header.",
+        example_error: "1.2 alta.md: Tabla en línea 12 sin separador de header",
+        why: "Sin separador la tabla se renderiza como texto plano.",
+        how_to_fix: "Agrega la fila |---| bajo el header.",
+        fix_commands: &["oc_diagdoc fix --tables"],
+    },
+    PhaseDoc {
+        id: 15,
+        name: "headings",
+        aliases: &[],
+        title: "Estructura headings",
+        description: "Un solo H1 por documento y sin saltos de nivel (H2 → H4).",
+        example_error: "1.2 alta.md: Salto de heading H2 a H4",
+        why: "Los saltos de nivel rompen el índice y la accesibilidad del documento.",
+        how_to_fix: "Usa un único H1 y baja los niveles de uno en uno.",
+        fix_commands: &["oc_diagdoc lint --fix"],
+    },
+    PhaseDoc {
+        id: 16,
+        name: "min_content",
+        aliases: &["content"],
+        title: "Contenido mínimo",
+        description: "Los documentos hoja deben superar un mínimo de palabras en el cuerpo.",
+        example_error: "1.2 alta.md: Contenido mínimo (12 palabras, mínimo 50)",
+        why: "Un documento casi vacío promete contenido que no entrega.",
+        how_to_fix: "Completa el documento o conviértelo en sección si solo agrupa hijos.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 17,
+        name: "placeholders",
+        aliases: &[],
+        title: "Placeholders detectados",
+        description: "Detecta marcadores pendientes (TODO, TBD, Lorem ipsum, ...) que no deberían publicarse.",
+        example_error: "1.2 alta.md: Placeholder detectado: 'TODO'",
+        why: "Los marcadores pendientes se cuelan en la versión publicada.",
+        how_to_fix: "Reemplaza el marcador por el contenido definitivo.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 18,
+        name: "duplicates",
+        aliases: &[],
+        title: "Duplicados",
+        description: "Dos documentos no deberían compartir el mismo título.",
+        example_error: "Título duplicado 'alta' en: 1.2 alta.md, 3.1 alta.md",
+        why: "Títulos repetidos hacen ambiguos los enlaces y la búsqueda.",
+        how_to_fix: "Renombra uno de los documentos con un título más específico.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 19,
+        name: "orphans",
+        aliases: &[],
+        title: "Documentos huérfanos",
+        description: "Un documento sin parent debe estar enlazado desde algún otro; si no, queda huérfano e inaccesible.",
+        example_error: "HUÉRFANO: 7 notas sueltas.md",
+        why: "Un documento huérfano no se alcanza navegando y suele quedar desactualizado.",
+        how_to_fix: "Asígnale un parent o enlázalo desde otro documento.",
+        fix_commands: &["oc_diagdoc migrate --adopt <dir> --parent <id>"],
+    },
+    PhaseDoc {
+        id: 20,
+        name: "children_count",
+        aliases: &["children"],
+        title: "Children count válido",
+        description: "children_count y descendants_count deben coincidir con los hijos directos y el total recursivo reales.",
+        example_error: "1 pagos.md: descendants_count=3 vs actual=5",
+        why: "Los conteos alimentan índices y dashboards; si están desfasados los números mienten.",
+        how_to_fix: "Recalcula children_count y descendants_count desde la jerarquía.",
+        fix_commands: &["oc_diagdoc sync --children --descendants", "oc_diagdoc fix --descendants"],
+    },
+    PhaseDoc {
+        id: 21,
+        name: "hash_integrity",
+        aliases: &["hash"],
+        title: "Hash integridad",
+        description: "content_hash debe coincidir con el SHA-256 del contenido (sin campos volátiles).",
+        example_error: "1.2 alta.md: Hash mismatch (stored vs computed)",
+        why: "El hash detecta ediciones que no pasaron por sync; un mismatch indica cambios sin registrar.",
+        how_to_fix: "Recalcula content_hash tras revisar el cambio.",
+        fix_commands: &["oc_diagdoc fix --hashes", "oc_diagdoc sync --hashes-only"],
+    },
+    PhaseDoc {
+        id: 22,
+        name: "numeric_consistency",
+        aliases: &["numeric", "numbers"],
+        title: "Consistencia numérica",
+        description: "Los valores de términos del glosario (porcentajes, montos, fechas) en un hijo deben coincidir con los de su padre.",
+        example_error: "1.2 alta.md:8: 'comisión' = 5% vs 1 pagos.md:4 = 3%",
+        why: "Un mismo término con dos valores distintos (una comisión del 3% y del 5%) es una contradicción para el lector.",
+        how_to_fix: "Unifica el valor en el hijo o en el padre según cuál sea el correcto.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 23,
+        name: "structural_limits",
+        aliases: &["limits"],
+        title: "Límites estructurales",
+        description: "Sugiere dividir, agrupar o aplanar cuando un documento supera los límites de palabras, hijos o profundidad de la sección limits.",
+        example_error: "1.2 alta.md: 4200 palabras (máx 3000) — considerar dividir en subdocumentos",
+        why: "Documentos demasiado largos, anchos o profundos son difíciles de leer y mantener.",
+        how_to_fix: "Divide el documento, agrupa hijos en secciones o aplana niveles según la sugerencia.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 24,
+        name: "filenames",
+        aliases: &["naming", "names"],
+        title: "Nombres de archivo",
+        description: "El nombre de archivo debe seguir \"{id} {titulo_slug}.md\" y coincidir con el id y el title del frontmatter.",
+        example_error: "1.2 Alta.md: nombre no coincide con title 'Alta de clientes' (esperado '1.2 alta_de_clientes.md')",
+        why: "Los wiki-links apuntan al nombre de archivo; si diverge del id/title, los enlaces y búsquedas por nombre se vuelven ambiguos.",
+        how_to_fix: "Renombra el archivo al nombre canónico y actualiza los wiki-links que lo apuntan.",
+        fix_commands: &["oc_diagdoc fix --filenames"],
+    },
+    PhaseDoc {
+        id: 25,
+        name: "copied_blocks",
+        aliases: &["copies", "copied"],
+        title: "Bloques copiados",
+        description: "Detecta bloques de N líneas idénticas (limits.copied_block_lines) copiados entre un documento y su padre, hijos o hermanos.",
+        example_error: "1.2 alta.md:14: 6 líneas idénticas a 1 clientes.md:20 — reemplazar por ![[1 clientes#Requisitos]]",
+        why: "El texto duplicado diverge en cuanto se edita solo una de las copias.",
+        how_to_fix: "Deja el texto en un solo documento y reemplaza la copia por un embed ![[Doc#Sección]].",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 26,
+        name: "merge_conflicts",
+        aliases: &["conflicts", "merge"],
+        title: "Conflictos de merge",
+        description: "Detecta marcadores de conflicto de merge sin resolver (<<<<<<<, =======, >>>>>>>) fuera de bloques de código.",
+        example_error: "2.1 pagos.md:18: marcador de conflicto de merge '<<<<<<<'",
+        why: "Un merge mal resuelto publica ambas versiones del texto junto con los marcadores.",
+        how_to_fix: "Resuelve el conflicto: deja la versión correcta y borra las líneas de marcadores.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 27,
+        name: "secrets",
+        aliases: &["secretos", "credentials", "security"],
+        title: "Secretos y credenciales",
+        description: "Detecta API keys, tokens JWT, cadenas de conexión con contraseña y claves privadas en frontmatter y cuerpo (patrones conocidos y entropía de valores asignados a claves sensibles).",
+        example_error: "3.2 integracion.md:14: AWS access key (AKIA…MPLE) [hash: 1f3a9c0b]",
+        why: "Un secreto publicado en la documentación queda en el historial de git y en cada export.",
+        how_to_fix: "Rota la credencial y reemplázala por un placeholder (<API_KEY>); si es un valor de ejemplo aceptado, agrega su hash a secrets.allowlist.",
+        fix_commands: &[],
+    },
+    PhaseDoc {
+        id: 28,
+        name: "reviews",
+        aliases: &["revisiones", "review_due"],
+        title: "Revisiones vencidas",
+        description: "Los documentos con review_due (fecha límite de la próxima revisión) no deben tenerla vencida; review_due debe ser una fecha válida y tener un responsable en review_by.",
+        example_error: "2.1 alta.md:9: revisión vencida hace 12 días (2026-03-01, responsable: ana)",
+        why: "Una revisión vencida es contenido que nadie confirmó que siga vigente.",
+        how_to_fix: "Revisa el documento y mueve review_due a la próxima fecha; audit --due-soon 14d lista las próximas por responsable.",
+        fix_commands: &[],
+    },
+];
+
+/// Resuelve una fase por número, nombre canónico o alias.
+pub fn resolve_phase(input: &str) -> Option<&'static PhaseDoc> {
+    let input = input.trim().to_lowercase();
+    if let Ok(id) = input.parse::<u8>() {
+        return PHASES.iter().find(|p| p.id == id);
+    }
+    PHASES
+        .iter()
+        .find(|p| p.name == input || p.aliases.contains(&input.as_str()))
+}
+
+/// Imprime la explicación de una fase.
+pub fn print_phase_explanation(doc: &PhaseDoc) {
+    println!();
+    println!("📘 FASE {}: {} ({})", doc.id, doc.title, doc.name);
+    println!("═══════════════════════════════════════════════════════════════");
+    println!();
+    println!("📋 QUÉ VALIDA:");
+    println!("   {}", doc.description);
+    println!();
+    println!("❌ EJEMPLO DE ERROR:");
+    println!("   {}", doc.example_error);
+    println!();
+    println!("❓ POR QUÉ IMPORTA:");
+    println!("   {}", doc.why);
+    println!();
+    println!("🔧 CÓMO CORREGIRLO:");
+    println!("   {}", doc.how_to_fix);
+    println!();
+    if !doc.fix_commands.is_empty() {
+        println!("🛠️  COMANDOS:");
+        for command in doc.fix_commands {
+            println!("   {}", command);
+        }
+        println!();
+    }
+    if !doc.aliases.is_empty() {
+        println!("🔤 ALIAS: {}", doc.aliases.join(", "));
+    }
+    println!("▶️  EJECUTAR: oc_diagdoc verify --phase {}", doc.name);
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_is_complete() {
+        let ids: Vec<u8> = PHASES.iter().map(|p| p.id).collect();
+        assert_eq!(ids, (1..=28).collect::<Vec<u8>>());
+        assert!(PHASES.iter().all(|p| !p.why.is_empty() && !p.how_to_fix.is_empty()));
+    }
+
+    #[test]
+    fn test_fix_commands() {
+        let dates = resolve_phase("dates_sync").unwrap();
+        assert!(dates.fix_commands.contains(&"oc_diagdoc fix --dates"));
+        assert!(resolve_phase("mermaid").unwrap().fix_commands.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_phase() {
+        assert_eq!(resolve_phase("9").unwrap().name, "internal_links");
+        assert_eq!(resolve_phase("Links").unwrap().id, 9);
+        assert_eq!(resolve_phase("hash_integrity").unwrap().id, 21);
+        assert!(resolve_phase("29").is_none());
+        assert!(resolve_phase("nada").is_none());
+    }
+}
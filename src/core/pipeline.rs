@@ -1,8 +1,20 @@
 //! Pipeline de procesamiento de documentos.
 //!
-//! Proporciona un sistema de pipeline composable para procesar documentos.
-
-use std::path::PathBuf;
+//! Proporciona un sistema de pipeline composable para procesar documentos:
+//! etapas por documento (`PipelineStage`) y etapas de proyecto
+//! (`ProjectPipeline`: Scan → Parse → BuildGraph → Analyze → Write) que
+//! comparten un `DocumentCache` para que cada archivo se lea una sola vez.
+
+use crate::core::files::{get_all_md_files, write_file_atomic, ScanOptions};
+use crate::core::frontmatter_cache;
+use crate::core::graph::{hierarchy_from_contents, DependencyGraph};
+use crate::core::timings::Profiler;
+use crate::errors::OcResult;
+use crate::types::breadcrumb::frontmatter_value;
+use crate::types::lifetimes::split_frontmatter;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // PIPELINE TYPES
@@ -168,10 +180,320 @@ impl PipelineStage for LinkValidationStage {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// PROJECT PIPELINE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Documento leído una vez y compartido entre etapas y comandos.
+#[derive(Debug)]
+pub struct LoadedDocument {
+    pub path: PathBuf,
+    pub content: Arc<String>,
+    pub id: Option<String>,
+    pub parent: Option<String>,
+    pub title: Option<String>,
+    /// mtime al momento de leerlo; `DocumentCache::refresh` lo compara.
+    pub modified: Option<std::time::SystemTime>,
+}
+
+impl LoadedDocument {
+    /// Parse: extrae los campos jerárquicos del frontmatter a través de la
+    /// caché de frontmatter. Si el YAML no cumple el esquema (ej. sin
+    /// `title`), los campos se leen uno a uno.
+    pub fn parse(path: &Path, content: String) -> Self {
+        let (id, parent, title) = match frontmatter_cache::parse(path, &content) {
+            Ok(parsed) => {
+                let fm = parsed.frontmatter;
+                (Some(fm.id), fm.parent, Some(fm.title))
+            }
+            Err(_) => {
+                let metadata = split_frontmatter(&content).map(|s| s.metadata);
+                let field = |name: &str| metadata.and_then(|m| frontmatter_value(m, name));
+                (field("id"), field("parent"), field("title"))
+            }
+        };
+        let present = |value: Option<String>| value.filter(|v| !v.trim().is_empty());
+        Self {
+            path: path.to_path_buf(),
+            id: present(id),
+            parent: present(parent),
+            title: present(title),
+            modified: std::fs::metadata(path).and_then(|m| m.modified()).ok(),
+            content: Arc::new(content),
+        }
+    }
+
+    /// Stem del archivo (ej: "1.2 alta").
+    pub fn stem(&self) -> &str {
+        self.path.file_stem().and_then(|s| s.to_str()).unwrap_or("unknown")
+    }
+
+    /// ID del frontmatter o, si falta, el prefijo numérico del nombre
+    /// ("2.5.1 alta" → "2.5.1").
+    pub fn effective_id(&self) -> Option<&str> {
+        self.id.as_deref().or_else(|| {
+            self.stem()
+                .split_whitespace()
+                .next()
+                .filter(|p| p.starts_with(|c: char| c.is_ascii_digit()))
+                .filter(|p| p.chars().all(|c| c.is_ascii_digit() || c == '.'))
+        })
+    }
+
+    /// ¿Pertenece al subárbol del prefijo de ID? Ver `id_within`.
+    pub fn within(&self, prefix: &str) -> bool {
+        self.effective_id().is_some_and(|id| id_within(id, prefix))
+    }
+}
+
+/// ¿`id` es `prefix` o un descendiente? "2.5" cubre "2.5.3" pero no "2.50".
+pub fn id_within(id: &str, prefix: &str) -> bool {
+    let prefix = prefix.trim_end_matches('.');
+    id == prefix || id.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('.'))
+}
+
+/// Prefijo de `--module N` / `--id-prefix ID`; `None` = todo el proyecto.
+pub fn id_scope(module: Option<u32>, id_prefix: Option<&str>) -> Option<String> {
+    id_prefix
+        .map(str::to_string)
+        .or_else(|| module.map(|m| m.to_string()))
+}
+
+/// Caché de documentos leídos; se comparte con `Arc` entre comandos.
+#[derive(Debug, Default)]
+pub struct DocumentCache {
+    docs: Mutex<HashMap<PathBuf, Arc<LoadedDocument>>>,
+    profiler: Option<Arc<Profiler>>,
+}
+
+impl DocumentCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cuenta las lecturas reales de disco en el profiler de `--timings`.
+    pub fn with_profiler(mut self, profiler: Option<Arc<Profiler>>) -> Self {
+        self.profiler = profiler;
+        self
+    }
+
+    /// Devuelve el documento, leyéndolo de disco solo la primera vez.
+    pub fn get(&self, path: &Path) -> std::io::Result<Arc<LoadedDocument>> {
+        if let Some(doc) = self.docs.lock().unwrap().get(path) {
+            return Ok(Arc::clone(doc));
+        }
+        let content = match &self.profiler {
+            Some(profiler) => profiler.read(path)?,
+            None => std::fs::read_to_string(path)?,
+        };
+        let doc = Arc::new(LoadedDocument::parse(path, content));
+        self.docs
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), Arc::clone(&doc));
+        Ok(doc)
+    }
+
+    /// ¿El documento cae en el subárbol del prefijo? Lo lee (o lo toma de
+    /// la caché) para conocer su `id`; si es ilegible, queda fuera.
+    pub fn in_scope(&self, path: &Path, prefix: &str) -> bool {
+        self.get(path).is_ok_and(|doc| doc.within(prefix))
+    }
+
+    /// Contenido del documento.
+    pub fn content(&self, path: &Path) -> std::io::Result<Arc<String>> {
+        self.get(path).map(|doc| Arc::clone(&doc.content))
+    }
+
+    /// Descarta un documento tras escribirlo.
+    pub fn invalidate(&self, path: &Path) {
+        self.docs.lock().unwrap().remove(path);
+    }
+
+    /// Descarta los documentos modificados o borrados desde que se leyeron;
+    /// devuelve cuántos. La próxima lectura los recarga.
+    pub fn refresh(&self) -> usize {
+        let mut docs = self.docs.lock().unwrap();
+        let before = docs.len();
+        docs.retain(|path, doc| {
+            let current = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+            current.is_some() && current == doc.modified
+        });
+        before - docs.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.docs.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Etapas de proyecto componibles sobre un directorio de datos.
+///
+/// ```rust,ignore
+/// let pipeline = ProjectPipeline::new(data_dir);
+/// let docs = pipeline.parse(&pipeline.scan()?);
+/// let graph = pipeline.build_graph(&docs);
+/// let words = pipeline.analyze(&docs, |d| d.content.split_whitespace().count());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProjectPipeline {
+    data_dir: PathBuf,
+    options: ScanOptions,
+    cache: Arc<DocumentCache>,
+}
+
+impl ProjectPipeline {
+    pub fn new(data_dir: impl AsRef<Path>) -> Self {
+        Self {
+            data_dir: data_dir.as_ref().to_path_buf(),
+            options: ScanOptions::new(),
+            cache: Arc::new(DocumentCache::new()),
+        }
+    }
+
+    pub fn with_options(mut self, options: ScanOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Reutiliza la caché de otro comando; `None` conserva la propia.
+    pub fn with_cache(mut self, cache: Option<Arc<DocumentCache>>) -> Self {
+        if let Some(cache) = cache {
+            self.cache = cache;
+        }
+        self
+    }
+
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    pub fn cache(&self) -> &Arc<DocumentCache> {
+        &self.cache
+    }
+
+    /// Scan: archivos .md del directorio según las opciones.
+    pub fn scan(&self) -> OcResult<Vec<PathBuf>> {
+        get_all_md_files(&self.data_dir, &self.options)
+    }
+
+    /// Parse: documentos leídos (desde la caché); omite los ilegibles.
+    pub fn parse(&self, files: &[PathBuf]) -> Vec<Arc<LoadedDocument>> {
+        files.iter().filter_map(|path| self.cache.get(path).ok()).collect()
+    }
+
+    /// Lee un único documento a través de la caché.
+    pub fn read(&self, path: &Path) -> std::io::Result<Arc<String>> {
+        self.cache.content(path)
+    }
+
+    /// BuildGraph: grafo jerárquico id → parent.
+    pub fn build_graph(&self, docs: &[Arc<LoadedDocument>]) -> DependencyGraph {
+        hierarchy_from_contents(docs.iter().map(|d| d.content.as_str()))
+    }
+
+    /// Analyze: aplica un análisis por documento.
+    pub fn analyze<T>(&self, docs: &[Arc<LoadedDocument>], f: impl Fn(&LoadedDocument) -> T) -> Vec<T> {
+        docs.iter().map(|d| f(d)).collect()
+    }
+
+    /// Write: escritura atómica que invalida la caché del archivo.
+    pub fn write(&self, path: &Path, content: &str) -> OcResult<()> {
+        write_file_atomic(path, content)?;
+        self.cache.invalidate(path);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_project_pipeline_stages() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("1 raiz.md"), "---\nid: \"1\"\ntitle: \"Raíz\"\nparent: \"0\"\n---\nuno dos\n").unwrap();
+        std::fs::write(dir.path().join("1.1 hijo.md"), "---\nid: \"1.1\"\nparent: \"1\"\n---\ntres\n").unwrap();
+
+        let profiler = Arc::new(Profiler::new());
+        let cache = Arc::new(DocumentCache::new().with_profiler(Some(Arc::clone(&profiler))));
+        let pipeline = ProjectPipeline::new(dir.path()).with_cache(Some(Arc::clone(&cache)));
+
+        let files = pipeline.scan().unwrap();
+        let docs = pipeline.parse(&files);
+        assert_eq!(docs.len(), 2);
+        let root = docs.iter().find(|d| d.stem() == "1 raiz").unwrap();
+        assert_eq!(root.title.as_deref(), Some("Raíz"));
+
+        let graph = pipeline.build_graph(&docs);
+        assert_eq!(graph.descendants_count(&"1".parse().unwrap()), 1);
+
+        // Segunda pasada (otro comando con la misma caché): sin lecturas nuevas
+        let other = ProjectPipeline::new(dir.path()).with_cache(Some(cache));
+        let words: usize = other
+            .analyze(&other.parse(&files), |d| d.content.split_whitespace().count())
+            .iter()
+            .sum();
+        assert!(words > 0);
+        assert_eq!(profiler.report(0).disk_reads, 2);
+
+        // Write invalida la caché
+        let hijo = dir.path().join("1.1 hijo.md");
+        other.write(&hijo, "---\nid: \"1.1\"\nparent: \"1\"\n---\ncuatro\n").unwrap();
+        assert!(other.read(&hijo).unwrap().contains("cuatro"));
+        assert_eq!(profiler.report(0).disk_reads, 3);
+    }
+
+    #[test]
+    fn test_id_scope() {
+        assert!(id_within("2.5", "2.5"));
+        assert!(id_within("2.5.3", "2.5"));
+        assert!(id_within("2.5.3", "2.5."));
+        assert!(!id_within("2.50", "2.5"));
+        assert!(!id_within("2", "2.5"));
+        assert_eq!(id_scope(Some(3), None).as_deref(), Some("3"));
+        assert_eq!(id_scope(None, Some("2.5")).as_deref(), Some("2.5"));
+        assert_eq!(id_scope(None, None), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let with_id = dir.path().join("x.md");
+        let by_name = dir.path().join("3.2 sin yaml.md");
+        let loose = dir.path().join("notas.md");
+        std::fs::write(&with_id, "---\nid: \"3.1\"\n---\n").unwrap();
+        std::fs::write(&by_name, "# Sin YAML\n").unwrap();
+        std::fs::write(&loose, "# Notas\n").unwrap();
+
+        let cache = DocumentCache::new();
+        assert!(cache.in_scope(&with_id, "3"));
+        assert!(cache.in_scope(&by_name, "3.2"));
+        assert!(!cache.in_scope(&by_name, "3.1"));
+        assert!(!cache.in_scope(&loose, "3"));
+    }
+
+    #[test]
+    fn test_document_cache_refresh() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a.md");
+        let b = dir.path().join("b.md");
+        std::fs::write(&a, "uno").unwrap();
+        std::fs::write(&b, "dos").unwrap();
+
+        let cache = DocumentCache::new();
+        cache.get(&a).unwrap();
+        cache.get(&b).unwrap();
+        assert_eq!(cache.refresh(), 0);
+
+        let file = std::fs::File::options().write(true).open(&a).unwrap();
+        file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(5)).unwrap();
+        std::fs::remove_file(&b).unwrap();
+        assert_eq!(cache.refresh(), 2);
+        assert!(cache.is_empty());
+    }
+
     #[test]
     fn test_pipeline_context_new() {
         let ctx = PipelineContext::new(PathBuf::from("test.md"), "content".to_string());
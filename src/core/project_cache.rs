@@ -0,0 +1,257 @@
+//! Persistencia de estado e índices del proyecto entre ejecuciones.
+//!
+//! Cada tipo [`Cacheable`] (documentos cargados de un `ProjectState` con
+//! `load_project_cached`, que usa `health`; índice de IDs de `tree`, grafo
+//! de links de `deps`, resumen de `stats`) se guarda en
+//! `.oc_diagdoc/cache/state/<CACHE_NAME>.bin` (bincode) junto con su clave,
+//! la versión del formato y la huella de los archivos fuente. La huella usa
+//! solo ruta, mtime y tamaño, así que validar una entrada no lee ningún
+//! documento: si un archivo cambia, aparece o desaparece, la entrada se
+//! descarta y se reconstruye. Sin la feature `cache` nunca hay aciertos.
+
+use crate::core::config::CONFIG_DIR;
+use crate::errors::{OcError, OcResult};
+use crate::traits::Cacheable;
+use crate::types::ContentHash;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Directorio de la caché dentro de `.oc_diagdoc/`.
+pub const CACHE_DIR: &str = "cache/state";
+
+/// Huella de los archivos fuente: ruta, mtime y tamaño de cada uno, en orden.
+pub fn fingerprint(files: &[PathBuf]) -> ContentHash {
+    let mut listing = String::new();
+    for path in files {
+        let meta = std::fs::metadata(path).ok();
+        let mtime = meta
+            .as_ref()
+            .and_then(|m| m.modified().ok())
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_nanos());
+        let len = meta.map_or(0, |m| m.len());
+        let _ = writeln!(listing, "{}\t{}\t{}", path.display(), mtime, len);
+    }
+    ContentHash::compute(&listing)
+}
+
+/// Entrada tal como se guarda en disco.
+#[derive(Serialize, Deserialize)]
+struct Stored<K, T> {
+    version: u32,
+    key: K,
+    /// Huella de los archivos fuente ([`fingerprint`]).
+    source: ContentHash,
+    /// `content_hash()` del valor al guardarlo.
+    hash: ContentHash,
+    value: T,
+}
+
+/// Archivo de la caché (`cache stats`).
+#[derive(Debug, Clone, Serialize)]
+pub struct StoredEntry {
+    pub name: String,
+    pub size_bytes: u64,
+}
+
+/// Almacén de valores [`Cacheable`] en un directorio.
+#[derive(Debug, Clone)]
+pub struct CacheStore {
+    dir: PathBuf,
+}
+
+impl CacheStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Almacén del proyecto actual; `None` fuera de un proyecto con
+    /// `.oc_diagdoc/` o sin la feature `cache`.
+    pub fn project() -> Option<Self> {
+        (cfg!(feature = "cache") && Path::new(CONFIG_DIR).is_dir())
+            .then(|| Self::new(Path::new(CONFIG_DIR).join(CACHE_DIR)))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path_of<T: Cacheable>(&self) -> PathBuf {
+        self.dir.join(format!("{}.bin", T::CACHE_NAME))
+    }
+
+    /// Valor guardado para `key` si la versión, la huella de `sources` y el
+    /// hash del contenido coinciden.
+    pub fn load<T>(&self, key: &T::Key, sources: &[PathBuf]) -> Option<T>
+    where
+        T: Cacheable + DeserializeOwned,
+        T::Key: DeserializeOwned,
+    {
+        let bytes = std::fs::read(self.path_of::<T>()).ok()?;
+        let stored: Stored<T::Key, T> = decode(&bytes)?;
+        let valid = stored.version == T::FORMAT_VERSION
+            && stored.key == *key
+            && stored.source == fingerprint(sources)
+            && !stored.value.has_changed(&stored.hash);
+        valid.then_some(stored.value)
+    }
+
+    /// Guarda `value` con la huella de `sources`.
+    pub fn save<T>(&self, value: &T, sources: &[PathBuf]) -> OcResult<()>
+    where
+        T: Cacheable + Serialize,
+        T::Key: Serialize,
+    {
+        let stored = Stored {
+            version: T::FORMAT_VERSION,
+            key: value.cache_key(),
+            source: fingerprint(sources),
+            hash: value.content_hash(),
+            value,
+        };
+        let bytes = encode(&stored)?;
+        let path = self.path_of::<T>();
+        let write_err = |e: std::io::Error| OcError::FileWrite {
+            path: path.clone(),
+            source: e,
+        };
+        std::fs::create_dir_all(&self.dir).map_err(write_err)?;
+        std::fs::write(&path, bytes).map_err(write_err)
+    }
+
+    /// Valor guardado o, si no sirve, el que devuelve `build` (que se guarda
+    /// para la próxima). Los errores de escritura se ignoran: la caché nunca
+    /// hace fallar un comando.
+    pub fn get_or_build<T>(
+        &self,
+        key: &T::Key,
+        sources: &[PathBuf],
+        build: impl FnOnce() -> OcResult<T>,
+    ) -> OcResult<T>
+    where
+        T: Cacheable + Serialize + DeserializeOwned,
+        T::Key: Serialize + DeserializeOwned,
+    {
+        if let Some(value) = self.load(key, sources) {
+            return Ok(value);
+        }
+        let value = build()?;
+        let _ = self.save(&value, sources);
+        Ok(value)
+    }
+
+    /// Archivos guardados, por nombre.
+    pub fn entries(&self) -> Vec<StoredEntry> {
+        let mut entries: Vec<StoredEntry> = std::fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "bin"))
+            .map(|e| StoredEntry {
+                name: e.path().file_stem().and_then(|s| s.to_str()).unwrap_or("").to_string(),
+                size_bytes: e.metadata().map_or(0, |m| m.len()),
+            })
+            .collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        entries
+    }
+
+    /// Borra todas las entradas; devuelve cuántas había.
+    pub fn clear(&self) -> OcResult<usize> {
+        let entries = self.entries();
+        for entry in &entries {
+            let path = self.dir.join(format!("{}.bin", entry.name));
+            std::fs::remove_file(&path).map_err(|e| OcError::FileWrite { path, source: e })?;
+        }
+        Ok(entries.len())
+    }
+}
+
+#[cfg(feature = "cache")]
+fn encode<T: Serialize>(value: &T) -> OcResult<Vec<u8>> {
+    bincode::serialize(value).map_err(|e| OcError::Custom(format!("Caché de estado: {}", e)))
+}
+
+#[cfg(feature = "cache")]
+fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Option<T> {
+    bincode::deserialize(bytes).ok()
+}
+
+#[cfg(not(feature = "cache"))]
+fn encode<T: Serialize>(_: &T) -> OcResult<Vec<u8>> {
+    Err(OcError::Custom("Caché de estado no disponible (compilar con la feature `cache`)".to_string()))
+}
+
+#[cfg(not(feature = "cache"))]
+fn decode<T: DeserializeOwned>(_: &[u8]) -> Option<T> {
+    None
+}
+
+#[cfg(all(test, feature = "cache"))]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Titles(PathBuf, Vec<String>);
+
+    impl Cacheable for Titles {
+        type Key = PathBuf;
+        const CACHE_NAME: &'static str = "titles";
+
+        fn cache_key(&self) -> PathBuf {
+            self.0.clone()
+        }
+
+        fn content_hash(&self) -> ContentHash {
+            ContentHash::compute(&self.1.join("\n"))
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct TitlesV2(PathBuf, Vec<String>);
+
+    impl Cacheable for TitlesV2 {
+        type Key = PathBuf;
+        const CACHE_NAME: &'static str = "titles";
+        const FORMAT_VERSION: u32 = 2;
+
+        fn cache_key(&self) -> PathBuf {
+            self.0.clone()
+        }
+
+        fn content_hash(&self) -> ContentHash {
+            ContentHash::compute(&self.1.join("\n"))
+        }
+    }
+
+    #[test]
+    fn test_store_roundtrip_and_invalidation() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc = dir.path().join("1.md");
+        std::fs::write(&doc, "# Uno\n").unwrap();
+        let sources = vec![doc.clone()];
+        let key = PathBuf::from("Datos");
+        let store = CacheStore::new(dir.path().join(CACHE_DIR));
+
+        let built = store
+            .get_or_build(&key, &sources, || Ok(Titles(key.clone(), vec!["Uno".into()])))
+            .unwrap();
+        let cached: Titles = store.get_or_build(&key, &sources, || panic!("debería venir de la caché")).unwrap();
+        assert_eq!(cached, built);
+
+        // Otra clave u otra versión del formato: no sirve
+        assert!(store.load::<Titles>(&PathBuf::from("Otros"), &sources).is_none());
+        assert!(store.load::<TitlesV2>(&key, &sources).is_none());
+
+        // Archivo fuente modificado: se reconstruye
+        std::fs::write(&doc, "# Uno modificado\n").unwrap();
+        assert!(store.load::<Titles>(&key, &sources).is_none());
+
+        assert_eq!(store.entries()[0].name, "titles");
+        assert_eq!(store.clear().unwrap(), 1);
+        assert!(store.entries().is_empty());
+    }
+}
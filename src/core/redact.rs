@@ -0,0 +1,226 @@
+//! Redacción de datos sensibles para exports externos.
+//!
+//! Reemplaza emails, teléfonos, montos y reglas propias por placeholders.
+//! Las reglas por patrón se aplican solo al cuerpo; en el frontmatter se
+//! redactan los campos listados en `redact.fields` (el YAML sigue siendo
+//! válido). Un documento con `confidential: true` exporta solo un aviso.
+
+use crate::errors::{OcError, OcResult};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Placeholder del cuerpo de documentos `confidential: true`.
+pub const CONFIDENTIAL_PLACEHOLDER: &str = "> [CONFIDENCIAL] Contenido omitido en export externo.\n";
+
+static RE_EMAIL: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").unwrap());
+
+/// Teléfonos con separadores (`+54 11 4567-8901`, `555-123-4567`); no
+/// captura fechas ni IDs jerárquicos.
+static RE_PHONE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:\+\d{1,3}[\s.-]?)?(?:\(\d{2,4}\)[\s.-]?|\b\d{2,4}[\s.-])\d{3,4}[\s.-]\d{4}\b")
+        .unwrap()
+});
+
+/// Montos con símbolo o código de moneda (no porcentajes).
+static RE_AMOUNT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?:[$€£]|\b(?:USD|EUR|ARS|MXN|CLP|COP)\b)\s?\d[\d.,]*|\b\d[\d.,]*\s?(?:€|\b(?:USD|EUR|ARS|MXN|CLP|COP)\b)",
+    )
+    .unwrap()
+});
+
+static RE_CONFIDENTIAL: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?m)^confidential:\s*["']?(true|yes|sí|si)["']?\s*$"#).unwrap());
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONFIGURACIÓN
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Regla de redacción propia.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactRule {
+    /// Nombre de la regla (aparece en el reporte).
+    pub name: String,
+    /// Regex a reemplazar.
+    pub pattern: String,
+    /// Placeholder; por defecto `[<NAME>]`.
+    #[serde(default)]
+    pub replacement: Option<String>,
+}
+
+/// Configuración de `export --redact` (sección `redact`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedactConfig {
+    pub emails: bool,
+    pub phones: bool,
+    pub amounts: bool,
+    /// Campos de frontmatter cuyo valor se reemplaza (ej: author, cliente).
+    pub fields: Vec<String>,
+    /// Reglas adicionales.
+    pub rules: Vec<RedactRule>,
+}
+
+impl Default for RedactConfig {
+    fn default() -> Self {
+        Self {
+            emails: true,
+            phones: true,
+            amounts: true,
+            fields: Vec::new(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REDACTOR
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Redactor con reglas compiladas.
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    rules: Vec<(String, Regex, String)>,
+    fields: Vec<Regex>,
+}
+
+/// Conteo de reemplazos por regla.
+pub type RedactCounts = BTreeMap<String, usize>;
+
+impl Redactor {
+    pub fn new(config: &RedactConfig) -> OcResult<Self> {
+        let mut rules = Vec::new();
+        let builtin = [
+            (config.emails, "email", &*RE_EMAIL, "[EMAIL]"),
+            (config.phones, "telefono", &*RE_PHONE, "[TELÉFONO]"),
+            (config.amounts, "monto", &*RE_AMOUNT, "[MONTO]"),
+        ];
+        for (enabled, name, re, placeholder) in builtin {
+            if enabled {
+                rules.push((name.to_string(), re.clone(), placeholder.to_string()));
+            }
+        }
+        for rule in &config.rules {
+            let re = Regex::new(&rule.pattern).map_err(|e| {
+                OcError::Custom(format!("Regla de redacción '{}' inválida: {}", rule.name, e))
+            })?;
+            let replacement = rule
+                .replacement
+                .clone()
+                .unwrap_or_else(|| format!("[{}]", rule.name.to_uppercase()));
+            rules.push((rule.name.clone(), re, replacement));
+        }
+
+        let fields = config
+            .fields
+            .iter()
+            .map(|f| Regex::new(&format!(r"(?m)^({}):[ \t]*\S.*$", regex::escape(f))).unwrap())
+            .collect();
+
+        Ok(Self { rules, fields })
+    }
+
+    /// Redacta un documento completo; suma los reemplazos en `counts`.
+    pub fn redact(&self, content: &str, counts: &mut RedactCounts) -> String {
+        let (frontmatter, body) = split_raw(content);
+
+        let mut fm = frontmatter.to_string();
+        for re in &self.fields {
+            let n = re.find_iter(&fm).count();
+            if n > 0 {
+                fm = re.replace_all(&fm, "$1: \"[REDACTADO]\"").into_owned();
+                *counts.entry("campo".to_string()).or_default() += n;
+            }
+        }
+
+        if RE_CONFIDENTIAL.is_match(frontmatter) {
+            *counts.entry("confidencial".to_string()).or_default() += 1;
+            return format!("{}\n{}", fm, CONFIDENTIAL_PLACEHOLDER);
+        }
+
+        let mut body = body.to_string();
+        for (name, re, replacement) in &self.rules {
+            let n = re.find_iter(&body).count();
+            if n > 0 {
+                body = re.replace_all(&body, regex::NoExpand(replacement)).into_owned();
+                *counts.entry(name.clone()).or_default() += n;
+            }
+        }
+        fm + &body
+    }
+}
+
+/// Separa el frontmatter (con delimitadores) del cuerpo, sin recortar nada.
+fn split_raw(content: &str) -> (&str, &str) {
+    if !content.starts_with("---") {
+        return ("", content);
+    }
+    let Some(first_nl) = content.find('\n') else {
+        return ("", content);
+    };
+    let mut offset = first_nl + 1;
+    for line in content[offset..].split_inclusive('\n') {
+        offset += line.len();
+        if line.trim_end() == "---" {
+            return content.split_at(offset);
+        }
+    }
+    ("", content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_builtin_patterns() {
+        let redactor = Redactor::new(&RedactConfig::default()).unwrap();
+        let mut counts = RedactCounts::new();
+        let out = redactor.redact(
+            "---\ncreated: 2024-01-15\n---\nEscribir a ana@empresa.com o al +54 11 4567-8901.\nCosto: $ 1.500,00 (15%) desde 2024-01-15 en 1.2.3.\n",
+            &mut counts,
+        );
+        assert!(out.starts_with("---\ncreated: 2024-01-15\n---\n"));
+        assert!(out.contains("a [EMAIL] o al [TELÉFONO]."));
+        assert!(out.contains("Costo: [MONTO] (15%) desde 2024-01-15 en 1.2.3."));
+        assert_eq!(counts["email"], 1);
+        assert_eq!(counts["telefono"], 1);
+        assert_eq!(counts["monto"], 1);
+    }
+
+    #[test]
+    fn test_redact_fields_confidential_and_rules() {
+        let config = RedactConfig {
+            fields: vec!["cliente".to_string()],
+            rules: vec![RedactRule {
+                name: "cuit".to_string(),
+                pattern: r"\b\d{2}-\d{8}-\d\b".to_string(),
+                replacement: None,
+            }],
+            ..Default::default()
+        };
+        let redactor = Redactor::new(&config).unwrap();
+        let mut counts = RedactCounts::new();
+
+        let out = redactor.redact("---\ncliente: ACME\n---\nCUIT 30-12345678-9\n", &mut counts);
+        assert_eq!(out, "---\ncliente: \"[REDACTADO]\"\n---\nCUIT [CUIT]\n");
+
+        let secret = redactor.redact("---\nconfidential: true\n---\nPlan secreto\n", &mut counts);
+        assert!(!secret.contains("Plan secreto"));
+        assert!(secret.contains("[CONFIDENCIAL]"));
+        assert_eq!(counts["confidencial"], 1);
+
+        let bad = RedactConfig {
+            rules: vec![RedactRule {
+                name: "x".to_string(),
+                pattern: "(".to_string(),
+                replacement: None,
+            }],
+            ..Default::default()
+        };
+        assert!(Redactor::new(&bad).is_err());
+    }
+}
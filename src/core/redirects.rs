@@ -0,0 +1,198 @@
+//! Redirecciones de documentos renombrados.
+//!
+//! Cuando `fix --filenames` renombra un documento, el nombre anterior queda
+//! registrado en `.oc_diagdoc/redirects.json` apuntando al actual. La fase 9
+//! de verify no reporta como rotos los enlaces al nombre anterior (avisa que
+//! conviene actualizarlos) y `export --redirects netlify|html` publica estas
+//! redirecciones, junto con las de slugs de `gen slugs`, para el sitio
+//! estático.
+
+use crate::core::config::CONFIG_DIR;
+use crate::core::sitemap::SitePage;
+use crate::core::slugs::slug_for;
+use crate::errors::{OcError, OcResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Registro dentro de `.oc_diagdoc/`; también es el nombre del JSON de
+/// redirects que `export --sitemap` deja en la salida.
+pub const REDIRECTS_FILE: &str = "redirects.json";
+
+/// Archivo de redirects de Netlify (y compatibles) en la salida del export.
+pub const NETLIFY_FILE: &str = "_redirects";
+
+/// Ruta del registro del proyecto actual.
+pub fn redirects_path() -> PathBuf {
+    Path::new(CONFIG_DIR).join(REDIRECTS_FILE)
+}
+
+/// Nombres anteriores de documentos → nombre actual (sin `.md`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Redirects {
+    pub docs: BTreeMap<String, String>,
+}
+
+impl Redirects {
+    /// Registro en `path`; vacío si falta o no se entiende.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> OcResult<()> {
+        let write_err = |e: std::io::Error| OcError::FileWrite {
+            path: path.to_path_buf(),
+            source: e,
+        };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(write_err)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?).map_err(write_err)
+    }
+
+    /// Registra el renombre `from` → `to`. Los redirects que llevaban a
+    /// `from` pasan a `to`, y un nombre vuelto a usar deja de redirigir.
+    pub fn record(&mut self, from: &str, to: &str) {
+        if from == to {
+            return;
+        }
+        self.docs.remove(to);
+        for target in self.docs.values_mut().filter(|t| *t == from) {
+            *target = to.to_string();
+        }
+        self.docs.insert(from.to_string(), to.to_string());
+    }
+
+    /// Nombre actual de un documento referido por un nombre anterior.
+    pub fn resolve(&self, name: &str) -> Option<&str> {
+        self.docs.get(name).map(String::as_str)
+    }
+
+    /// Pares (slug anterior, slug actual) para el sitio: los redirects de
+    /// slugs de `gen slugs` y, por cada documento renombrado que se publica,
+    /// el slug de su nombre anterior. `pages` son las páginas exportadas con
+    /// su nombre de archivo.
+    pub fn site_pairs(&self, slug_redirects: &BTreeMap<String, String>, pages: &[(String, SitePage)]) -> Vec<(String, String)> {
+        let mut pairs: BTreeMap<String, String> = slug_redirects.clone();
+        for (old, new) in &self.docs {
+            let Some((_, page)) = pages.iter().find(|(stem, _)| stem == new) else {
+                continue;
+            };
+            let old_slug = slug_for(old);
+            if old_slug != page.slug && !pages.iter().any(|(_, p)| p.slug == old_slug) {
+                pairs.entry(old_slug).or_insert_with(|| page.slug.clone());
+            }
+        }
+        pairs.into_iter().collect()
+    }
+}
+
+/// Formato de redirects para sitios estáticos.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RedirectFormat {
+    /// Archivo `_redirects` (Netlify, Cloudflare Pages).
+    Netlify,
+    /// Una página `<slug>/index.html` con meta refresh por redirect.
+    Html,
+}
+
+impl RedirectFormat {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "netlify" | "_redirects" => Some(Self::Netlify),
+            "html" | "meta" | "meta-refresh" => Some(Self::Html),
+            _ => None,
+        }
+    }
+}
+
+/// Redirect publicado (`from` → `to`, URLs absolutas).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Redirect {
+    pub from: String,
+    pub to: String,
+}
+
+/// Redirects con URLs bajo `base_url`.
+pub fn redirect_urls(pairs: &[(String, String)], base_url: &str) -> Vec<Redirect> {
+    let base = base_url.trim_end_matches('/');
+    pairs
+        .iter()
+        .map(|(from, to)| Redirect {
+            from: format!("{}/{}", base, from),
+            to: format!("{}/{}", base, to),
+        })
+        .collect()
+}
+
+/// Contenido de `_redirects`: rutas relativas al dominio (con el prefijo de
+/// `base_url` si la documentación no está en la raíz) y código 301.
+pub fn netlify(pairs: &[(String, String)], base_url: &str) -> String {
+    let without_scheme = base_url.split_once("://").map_or(base_url, |(_, rest)| rest);
+    let prefix = without_scheme
+        .find('/')
+        .map_or("", |i| &without_scheme[i..])
+        .trim_end_matches('/');
+    pairs
+        .iter()
+        .map(|(from, to)| format!("{prefix}/{from} {prefix}/{to} 301\n"))
+        .collect()
+}
+
+/// Página HTML que redirige a `url` (meta refresh y enlace canónico).
+pub fn meta_refresh(url: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Redirigiendo…</title>\n\
+         <link rel=\"canonical\" href=\"{url}\">\n<meta http-equiv=\"refresh\" content=\"0; url={url}\">\n\
+         </head>\n<body>\n<p>Movido a <a href=\"{url}\">{url}</a>.</p>\n</body>\n</html>\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redirects_record_and_site_pairs() {
+        let mut redirects = Redirects::default();
+        redirects.record("1.2 Pagos", "1.2 Cobros");
+        redirects.record("1.2 Cobros", "1.2 Cobros online");
+        redirects.record("1.3 Nada", "1.3 Nada");
+        assert_eq!(redirects.resolve("1.2 Pagos"), Some("1.2 Cobros online"));
+        assert_eq!(redirects.docs.len(), 2);
+        // Un nombre que vuelve a existir deja de redirigir
+        redirects.record("2 Viejo", "1.2 Pagos");
+        assert_eq!(redirects.resolve("1.2 Pagos"), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONFIG_DIR).join(REDIRECTS_FILE);
+        redirects.save(&path).unwrap();
+        assert_eq!(Redirects::load(&path), redirects);
+
+        let page = |stem: &str, extra: &str| {
+            let content = format!("---\nid: \"1\"\n{}---\n", extra);
+            (stem.to_string(), SitePage::from_doc(stem, &content))
+        };
+        let pages = vec![page("1.2 Cobros online", ""), page("1.2 Pagos", "slug: \"pagos\"\n")];
+        let slug_redirects = BTreeMap::from([("pagos-viejo".to_string(), "pagos".to_string())]);
+        let pairs = redirects.site_pairs(&slug_redirects, &pages);
+        assert_eq!(
+            pairs,
+            vec![
+                ("1-2-cobros".to_string(), "1-2-cobros-online".to_string()),
+                ("2-viejo".to_string(), "pagos".to_string()),
+                ("pagos-viejo".to_string(), "pagos".to_string()),
+            ]
+        );
+
+        assert_eq!(netlify(&pairs[..1], "https://x.mx/docs/"), "/docs/1-2-cobros /docs/1-2-cobros-online 301\n");
+        assert_eq!(netlify(&pairs[..1], "https://x.mx"), "/1-2-cobros /1-2-cobros-online 301\n");
+        assert_eq!(redirect_urls(&pairs, "https://x.mx")[2].to, "https://x.mx/pagos");
+        assert!(meta_refresh("https://x.mx/pagos").contains("content=\"0; url=https://x.mx/pagos\""));
+        assert_eq!(RedirectFormat::from_str("Netlify"), Some(RedirectFormat::Netlify));
+    }
+}
@@ -0,0 +1,229 @@
+//! Consistencia de trato (tú/usted/vos) en documentos para usuario final.
+//!
+//! Heurística por marcadores inequívocos: pronombres y posesivos (`tú`,
+//! `tus`, `usted`, `vos`), verbos con enclítico (`asegúrate`, `asegúrese`,
+//! `asegurate`) e imperativos frecuentes en instrucciones (`ingrese`,
+//! `ingresá`). Las formas que coinciden con la tercera persona (`ingresa`,
+//! `puede`) no cuentan. Se ignoran frontmatter, bloques y spans de código,
+//! links y URLs. Base de la regla de lint L019.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::BTreeMap;
+
+static RE_PROTECTED: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"`[^`]*`|!?\[\[[^\]]*\]\]|\]\([^)]*\)|https?://\S+").unwrap());
+
+/// Marcadores de tuteo.
+const TU_WORDS: &[&str] = &[
+    "tú", "ti", "contigo", "tu", "tus", "tuyo", "tuya", "tuyos", "tuyas", "te", "puedes", "debes",
+    "tienes", "quieres", "necesitas", "eres", "estás", "sabes", "haz", "pon", "ten",
+];
+
+/// Marcadores de voseo.
+const VOS_WORDS: &[&str] = &[
+    "vos", "podés", "debés", "tenés", "querés", "necesitás", "sos", "sabés", "hacé", "poné",
+    "tené", "salí", "ingresá", "seleccioná", "presioná", "escribí", "verificá", "revisá",
+    "utilizá", "usá", "elegí", "accedé", "introducí", "pulsá", "oprimí", "confirmá", "completá",
+    "descargá", "andá", "fijate", "asegurate", "dirigite", "registrate", "conectate",
+];
+
+/// Marcadores de trato de usted.
+const USTED_WORDS: &[&str] = &[
+    "usted", "ud", "uds", "ingrese", "seleccione", "presione", "haga", "escriba", "verifique",
+    "revise", "utilice", "elija", "acceda", "introduzca", "pulse", "oprima", "confirme",
+    "descargue", "diríjase", "asegúrese", "regístrese", "conéctese", "fíjese", "tenga", "ponga",
+];
+
+/// Trato con el lector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Register {
+    Tu,
+    Usted,
+    Vos,
+}
+
+impl Register {
+    /// `tu`/`tú`, `usted` o `vos` (`auto` y otros valores → `None`).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "tu" | "tú" | "tuteo" => Some(Self::Tu),
+            "usted" => Some(Self::Usted),
+            "vos" | "voseo" => Some(Self::Vos),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Tu => "tú",
+            Self::Usted => "usted",
+            Self::Vos => "vos",
+        }
+    }
+
+    /// Trato que marca una palabra (en minúsculas).
+    fn of_word(word: &str) -> Option<Self> {
+        // `estás` es tuteo y voseo: cuenta como tuteo
+        if TU_WORDS.contains(&word) {
+            return Some(Self::Tu);
+        }
+        if VOS_WORDS.contains(&word) {
+            return Some(Self::Vos);
+        }
+        if USTED_WORDS.contains(&word) {
+            return Some(Self::Usted);
+        }
+        // Imperativo con enclítico acentuado: asegúrate / asegúrese
+        let accented = word.chars().rev().skip(3).any(|c| "áéíóú".contains(c));
+        if accented && word.chars().count() > 6 {
+            if word.ends_with("ate") || word.ends_with("ete") || word.ends_with("ite") {
+                return Some(Self::Tu);
+            }
+            if word.ends_with("ase") || word.ends_with("ese") {
+                return Some(Self::Usted);
+            }
+        }
+        None
+    }
+}
+
+/// Palabra que marca un trato.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisterMarker {
+    /// Línea (1-based).
+    pub line: usize,
+    pub register: Register,
+    pub word: String,
+}
+
+/// Línea con un trato distinto del esperado o con mezcla de tratos.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisterIssue {
+    /// Línea (1-based).
+    pub line: usize,
+    pub message: String,
+}
+
+/// Marcadores de trato del cuerpo del documento.
+pub fn markers(content: &str) -> Vec<RegisterMarker> {
+    let mut found = Vec::new();
+    let mut in_frontmatter = content.starts_with("---");
+    let mut in_code = false;
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if in_frontmatter {
+            in_frontmatter = idx == 0 || trimmed != "---";
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+        let prose = RE_PROTECTED.replace_all(line, " ");
+        for word in prose.split(|c: char| !c.is_alphabetic()).filter(|w| !w.is_empty()) {
+            let word = word.to_lowercase();
+            if let Some(register) = Register::of_word(&word) {
+                found.push(RegisterMarker {
+                    line: idx + 1,
+                    register,
+                    word,
+                });
+            }
+        }
+    }
+    found
+}
+
+/// Líneas inconsistentes: las que mezclan tratos y las que usan uno
+/// distinto de `expected` (con `None`, el predominante del documento; en
+/// empate no hay predominante y solo cuentan las mezclas).
+pub fn check(content: &str, expected: Option<Register>) -> Vec<RegisterIssue> {
+    let markers = markers(content);
+    let expected = expected.or_else(|| {
+        let mut counts: BTreeMap<Register, usize> = BTreeMap::new();
+        for m in &markers {
+            *counts.entry(m.register).or_default() += 1;
+        }
+        let max = counts.values().copied().max()?;
+        let mut top = counts.iter().filter(|(_, &c)| c == max);
+        match (top.next(), top.next()) {
+            (Some((&register, _)), None) => Some(register),
+            _ => None,
+        }
+    });
+
+    let mut by_line: BTreeMap<usize, Vec<&RegisterMarker>> = BTreeMap::new();
+    for m in &markers {
+        by_line.entry(m.line).or_default().push(m);
+    }
+
+    let describe = |register: Register, found: &[&RegisterMarker]| {
+        let mut words: Vec<&str> = found
+            .iter()
+            .filter(|m| m.register == register)
+            .map(|m| m.word.as_str())
+            .collect();
+        words.dedup();
+        format!("{} ({})", register.as_str(), words.join(", "))
+    };
+
+    by_line
+        .into_iter()
+        .filter_map(|(line, found)| {
+            let mut registers: Vec<Register> = found.iter().map(|m| m.register).collect();
+            registers.sort();
+            registers.dedup();
+            let message = if registers.len() > 1 {
+                let parts: Vec<String> = registers.iter().map(|&r| describe(r, &found)).collect();
+                format!("Mezcla de trato en la línea: {}", parts.join(" y "))
+            } else {
+                let expected = expected.filter(|&e| e != registers[0])?;
+                format!(
+                    "Trato {} en documento con trato {}",
+                    describe(registers[0], &found),
+                    expected.as_str()
+                )
+            };
+            Some(RegisterIssue { line, message })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_markers() {
+        let words = |s: &str| markers(s).into_iter().map(|m| (m.register, m.word)).collect::<Vec<_>>();
+        assert_eq!(
+            words("Ingrese sus datos y asegúrate de que `tu` clave [[tu perfil]] sea válida.\n"),
+            vec![(Register::Usted, "ingrese".to_string()), (Register::Tu, "asegúrate".to_string())]
+        );
+        assert!(words("El sistema ingresa la solicitud y puede rechazarla.\n").is_empty());
+        assert_eq!(words("Si querés, fijate acá.\n")[1], (Register::Vos, "fijate".to_string()));
+    }
+
+    #[test]
+    fn test_register_check() {
+        let doc = "---\ntype: guia\n---\n# Alta\n\nIngrese a la app.\nSeleccione su plan.\n\
+                   Luego presiona Guardar y revisa tus datos.\nSi usted puede, haz clic.\n";
+        let issues = check(doc, None);
+        let lines: Vec<(usize, &str)> = issues.iter().map(|i| (i.line, i.message.as_str())).collect();
+        assert_eq!(
+            lines,
+            vec![
+                (8, "Trato tú (tus) en documento con trato usted"),
+                (9, "Mezcla de trato en la línea: tú (haz) y usted (usted)"),
+            ]
+        );
+        // Con trato fijo, las líneas de usted también se reportan
+        assert_eq!(check(doc, Some(Register::Tu)).len(), 3);
+        assert_eq!(Register::parse("Tú"), Some(Register::Tu));
+        assert_eq!(Register::parse("auto"), None);
+    }
+}
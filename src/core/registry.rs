@@ -1,7 +1,12 @@
-//! Registry de comandos y plugins.
+//! Registry de comandos, plugins y reglas.
 //!
-//! Sistema de registro dinámico para extensibilidad.
+//! Sistema de registro dinámico para extensibilidad. `RuleRegistry` unifica
+//! fases de verify, reglas de lint y reglas custom con sus metadatos.
 
+use crate::core::lint_docs::get_all_rules;
+use crate::core::lint_rules::DECLARED_RULES;
+use crate::core::phase_docs::PHASES;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -170,6 +175,248 @@ impl Default for RegistryBuilder {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// RULE REGISTRY
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Origen de una regla.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleKind {
+    /// Fase de `verify`.
+    Phase,
+    /// Regla de `lint`.
+    Lint,
+    /// Regla registrada por un plugin o integración.
+    Custom,
+}
+
+impl RuleKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RuleKind::Phase => "phase",
+            RuleKind::Lint => "lint",
+            RuleKind::Custom => "custom",
+        }
+    }
+}
+
+/// Metadatos de una regla.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleMeta {
+    /// Código estable: `L006` para lint, `P08` para fases.
+    pub code: String,
+    pub name: String,
+    pub kind: RuleKind,
+    pub category: String,
+    /// Severidad por defecto: error, warning, info o hint.
+    pub severity: String,
+    pub fixable: bool,
+    /// Versión en la que apareció.
+    pub since: String,
+    pub description: String,
+}
+
+impl RuleMeta {
+    pub fn new(code: &str, name: &str, kind: RuleKind) -> Self {
+        Self {
+            code: code.to_string(),
+            name: name.to_string(),
+            kind,
+            category: "general".to_string(),
+            severity: "warning".to_string(),
+            fixable: false,
+            since: env!("CARGO_PKG_VERSION").to_string(),
+            description: String::new(),
+        }
+    }
+
+    pub fn with_category(mut self, category: &str) -> Self {
+        self.category = category.to_string();
+        self
+    }
+
+    pub fn with_severity(mut self, severity: &str) -> Self {
+        self.severity = severity.to_string();
+        self
+    }
+
+    pub fn with_fixable(mut self, fixable: bool) -> Self {
+        self.fixable = fixable;
+        self
+    }
+
+    pub fn with_since(mut self, since: &str) -> Self {
+        self.since = since.to_string();
+        self
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+}
+
+/// (fase, categoría, severidad, desde).
+const PHASE_META: &[(u8, &str, &str, &str)] = &[
+    (1, "estructura", "error", "3.0.0"),
+    (2, "metadatos", "error", "3.0.0"),
+    (3, "metadatos", "error", "3.0.0"),
+    (4, "estructura", "error", "3.0.0"),
+    (5, "estructura", "warning", "3.0.0"),
+    (6, "metadatos", "warning", "3.0.0"),
+    (7, "metadatos", "warning", "3.0.0"),
+    (8, "metadatos", "warning", "3.0.0"),
+    (9, "links", "error", "3.0.0"),
+    (10, "links", "warning", "3.0.0"),
+    (11, "links", "warning", "3.0.0"),
+    (12, "formato", "warning", "3.0.0"),
+    (13, "formato", "warning", "3.0.0"),
+    (14, "tablas", "warning", "3.0.0"),
+    (15, "estructura", "warning", "3.0.0"),
+    (16, "contenidos", "warning", "3.0.0"),
+    (17, "contenidos", "warning", "3.0.0"),
+    (18, "contenidos", "warning", "3.0.0"),
+    (19, "estructura", "warning", "3.0.0"),
+    (20, "estructura", "warning", "3.0.0"),
+    (21, "integridad", "warning", "3.0.0"),
+    (22, "contenidos", "warning", "3.2.0"),
+    (23, "estructura", "warning", "3.2.0"),
+    (24, "estructura", "warning", "3.2.0"),
+    (25, "contenidos", "warning", "3.2.0"),
+    (26, "formato", "error", "3.2.0"),
+    (27, "seguridad", "error", "3.2.0"),
+    (28, "metadatos", "warning", "3.2.0"),
+];
+
+/// (regla, categoría, severidad, desde); las declaradas con `lint_rule!`
+/// traen sus metadatos.
+const LINT_META: &[(&str, &str, &str, &str)] = &[
+    ("L001", "estructura", "warning", "3.0.0"),
+    ("L002", "estructura", "warning", "3.0.0"),
+    ("L003", "formato", "info", "3.0.0"),
+    ("L004", "formato", "info", "3.0.0"),
+    ("L005", "formato", "warning", "3.0.0"),
+    ("L006", "formato", "hint", "3.0.0"),
+    ("L007", "estructura", "warning", "3.0.0"),
+    ("L008", "estructura", "error", "3.0.0"),
+    ("L009", "tablas", "warning", "3.0.0"),
+    ("L011", "tablas", "error", "3.1.0"),
+    ("L012", "tablas", "error", "3.1.0"),
+    ("L013", "contenidos", "warning", "3.1.0"),
+    ("L014", "links", "info", "3.1.0"),
+    ("L015", "tablas", "warning", "3.2.0"),
+    ("L016", "contenidos", "warning", "3.2.0"),
+    ("L017", "contenidos", "warning", "3.2.0"),
+    ("L018", "contenidos", "warning", "3.2.0"),
+    ("L019", "contenidos", "warning", "3.2.0"),
+    ("L020", "formato", "warning", "3.2.0"),
+    ("L022", "a11y", "warning", "3.2.0"),
+    ("L023", "a11y", "info", "3.2.0"),
+];
+
+/// Registro de reglas: fases, lint y custom.
+#[derive(Debug, Clone, Default)]
+pub struct RuleRegistry {
+    rules: Vec<RuleMeta>,
+}
+
+impl RuleRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registro con todas las fases de verify y reglas de lint.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+
+        for phase in PHASES {
+            let (category, severity, since) = PHASE_META
+                .iter()
+                .find(|(id, ..)| *id == phase.id)
+                .map(|(_, c, s, v)| (*c, *s, *v))
+                .unwrap_or(("general", "warning", "3.0.0"));
+            registry.register(
+                RuleMeta::new(&format!("P{:02}", phase.id), phase.name, RuleKind::Phase)
+                    .with_category(category)
+                    .with_severity(severity)
+                    .with_fixable(phase.fix_commands.iter().any(|c| {
+                        c.starts_with("oc_diagdoc fix") || c.starts_with("oc_diagdoc sync")
+                    }))
+                    .with_since(since)
+                    .with_description(phase.description),
+            );
+        }
+
+        let docs = get_all_rules();
+        let mut lint: Vec<RuleMeta> = LINT_META
+            .iter()
+            .filter_map(|(code, category, severity, since)| {
+                let doc = docs.get(code)?;
+                Some(
+                    RuleMeta::new(code, doc.name, RuleKind::Lint)
+                        .with_category(category)
+                        .with_severity(severity)
+                        .with_fixable(doc.auto_fixable)
+                        .with_since(since)
+                        .with_description(doc.description),
+                )
+            })
+            .collect();
+        lint.extend(DECLARED_RULES.iter().map(|rule| {
+            RuleMeta::new(rule.code, rule.name, RuleKind::Lint)
+                .with_category(rule.category)
+                .with_severity(&rule.severity.to_string().to_lowercase())
+                .with_since(rule.since)
+                .with_description(rule.description)
+        }));
+        lint.sort_by(|a, b| a.code.cmp(&b.code));
+        for meta in lint {
+            registry.register(meta);
+        }
+
+        registry
+    }
+
+    /// Registra una regla; reemplaza la existente con el mismo código.
+    pub fn register(&mut self, meta: RuleMeta) {
+        match self.rules.iter_mut().find(|r| r.code.eq_ignore_ascii_case(&meta.code)) {
+            Some(existing) => *existing = meta,
+            None => self.rules.push(meta),
+        }
+    }
+
+    /// Busca por código (sin distinguir mayúsculas) o nombre.
+    pub fn get(&self, code: &str) -> Option<&RuleMeta> {
+        self.rules
+            .iter()
+            .find(|r| r.code.eq_ignore_ascii_case(code) || r.name.eq_ignore_ascii_case(code))
+    }
+
+    pub fn list(&self) -> &[RuleMeta] {
+        &self.rules
+    }
+
+    pub fn by_kind(&self, kind: RuleKind) -> Vec<&RuleMeta> {
+        self.rules.iter().filter(|r| r.kind == kind).collect()
+    }
+
+    pub fn by_category(&self, category: &str) -> Vec<&RuleMeta> {
+        self.rules
+            .iter()
+            .filter(|r| r.category.eq_ignore_ascii_case(category))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +445,29 @@ mod tests {
         let registry = RegistryBuilder::new().with_builtins().build();
         assert!(registry.command_count() > 0);
     }
+
+    #[test]
+    fn test_rule_registry_builtin() {
+        let registry = RuleRegistry::builtin();
+        assert_eq!(registry.by_kind(RuleKind::Phase).len(), PHASES.len());
+        assert_eq!(registry.by_kind(RuleKind::Lint).len(), get_all_rules().len());
+
+        let dates = registry.get("dates_sync").unwrap();
+        assert_eq!(dates.code, "P08");
+        assert!(dates.fixable);
+        assert_eq!(registry.get("l011").unwrap().severity, "error");
+        assert!(!registry.by_category("tablas").is_empty());
+    }
+
+    #[test]
+    fn test_rule_registry_custom() {
+        let mut registry = RuleRegistry::builtin();
+        let before = registry.len();
+        registry.register(
+            RuleMeta::new("X001", "glosario", RuleKind::Custom).with_category("contenidos"),
+        );
+        registry.register(RuleMeta::new("x001", "glosario", RuleKind::Custom).with_severity("error"));
+        assert_eq!(registry.len(), before + 1);
+        assert_eq!(registry.get("X001").unwrap().severity, "error");
+    }
 }
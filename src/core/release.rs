@@ -61,6 +61,21 @@ impl VersionBumper {
             BumpType::Patch => Version::new(version.major, version.minor, version.patch + 1),
         }
     }
+
+    /// Parsea versiones de documentos, más laxas que semver: acepta
+    /// prefijo `v` y componentes faltantes (`2` → 2.0.0, `v2.3` → 2.3.0).
+    pub fn parse_lenient(s: &str) -> Option<Version> {
+        let s = s.trim().trim_start_matches(['v', 'V']);
+        if let Ok(v) = Version::parse(s) {
+            return Some(v);
+        }
+        let parts: Vec<u64> = s.split('.').map(|p| p.parse().ok()).collect::<Option<_>>()?;
+        match parts[..] {
+            [major] => Some(Version::new(major, 0, 0)),
+            [major, minor] => Some(Version::new(major, minor, 0)),
+            _ => None,
+        }
+    }
 }
 
 /// Checklist de release.
@@ -129,6 +144,14 @@ mod tests {
         assert_eq!(bumped, Version::new(1, 3, 0));
     }
 
+    #[test]
+    fn test_parse_lenient() {
+        assert_eq!(VersionBumper::parse_lenient("v2.3"), Some(Version::new(2, 3, 0)));
+        assert_eq!(VersionBumper::parse_lenient("2"), Some(Version::new(2, 0, 0)));
+        assert_eq!(VersionBumper::parse_lenient("2.3.1"), Some(Version::new(2, 3, 1)));
+        assert_eq!(VersionBumper::parse_lenient("dos"), None);
+    }
+
     #[test]
     fn test_release_checklist() {
         let mut checklist = ReleaseChecklist::new();
@@ -0,0 +1,125 @@
+//! Revisiones asignadas con vencimiento.
+//!
+//! `review_by:` (responsable) y `review_due:` (fecha límite) en el
+//! frontmatter programan la próxima revisión de un documento. La fase 28
+//! de verify reporta las vencidas y las mal declaradas; `audit --due-soon`
+//! lista las próximas por responsable.
+
+use crate::errors::{OcError, OcResult};
+use crate::types::breadcrumb::frontmatter_value;
+use crate::types::lifetimes::split_frontmatter;
+use crate::types::OcDate;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Revisión programada de un documento.
+#[derive(Debug, Clone, Serialize)]
+pub struct Review {
+    pub file: PathBuf,
+    pub id: Option<String>,
+    pub title: String,
+    /// Responsable (`review_by`), si está asignado.
+    pub review_by: Option<String>,
+    pub review_due: String,
+    /// Días hasta el vencimiento (negativo si ya venció).
+    pub days_left: i64,
+}
+
+impl Review {
+    /// Revisión del documento: None si no declara `review_due`, Err con el
+    /// valor si la fecha no se entiende.
+    pub fn of(file: &Path, content: &str, today: NaiveDate) -> Option<Result<Self, String>> {
+        let metadata = split_frontmatter(content)?.metadata;
+        let due = frontmatter_value(metadata, "review_due").filter(|v| v != "null")?;
+        let Ok(date) = due.parse::<OcDate>() else {
+            return Some(Err(due));
+        };
+        let stem = file.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+        Some(Ok(Self {
+            file: file.to_path_buf(),
+            id: frontmatter_value(metadata, "id"),
+            title: frontmatter_value(metadata, "title").unwrap_or_else(|| stem.to_string()),
+            review_by: frontmatter_value(metadata, "review_by").filter(|v| v != "null"),
+            review_due: due,
+            days_left: (date.date_naive() - today).num_days(),
+        }))
+    }
+
+    pub fn is_overdue(&self) -> bool {
+        self.days_left < 0
+    }
+
+    /// Responsable para mostrar (`sin asignar` si falta).
+    pub fn owner(&self) -> &str {
+        self.review_by.as_deref().unwrap_or("sin asignar")
+    }
+}
+
+/// Plazo en días: `14d`, `2w` o un número de días.
+pub fn parse_days(text: &str) -> OcResult<i64> {
+    let text = text.trim();
+    let invalid = || OcError::InvalidArgument(format!("Plazo inválido: '{}' (ej: 14d, 2w)", text));
+    let (number, factor) = match text.char_indices().last() {
+        Some((i, 'd')) => (&text[..i], 1),
+        Some((i, 'w')) => (&text[..i], 7),
+        _ => (text, 1),
+    };
+    number.parse::<i64>().map(|n| n * factor).map_err(|_| invalid())
+}
+
+/// Revisiones que vencen dentro de `days` días (incluidas las vencidas),
+/// agrupadas con [`by_owner`].
+pub fn due_within(reviews: &[Review], days: i64) -> Vec<(String, Vec<&Review>)> {
+    let due: Vec<&Review> = reviews.iter().filter(|r| r.days_left <= days).collect();
+    by_owner(due)
+}
+
+/// Revisiones agrupadas por responsable (en orden alfabético) y, en cada
+/// grupo, la más urgente primero.
+pub fn by_owner<'a>(reviews: impl IntoIterator<Item = &'a Review>) -> Vec<(String, Vec<&'a Review>)> {
+    let mut groups: Vec<(String, Vec<&Review>)> = Vec::new();
+    let mut sorted: Vec<&Review> = reviews.into_iter().collect();
+    sorted.sort_by_key(|r| r.days_left);
+    for review in sorted {
+        match groups.iter_mut().find(|(owner, _)| owner == review.owner()) {
+            Some((_, list)) => list.push(review),
+            None => groups.push((review.owner().to_string(), vec![review])),
+        }
+    }
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reviews_due_and_grouping() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let doc = |by: &str, due: &str| format!("---\nid: \"1\"\ntitle: \"Doc\"\n{}review_due: {}\n---\n", by, due);
+        let review = |by: &str, due: &str| Review::of(Path::new("a.md"), &doc(by, due), today).unwrap();
+
+        let late = review("review_by: ana\n", "\"2026-03-01\"").unwrap();
+        assert_eq!((late.days_left, late.is_overdue()), (-9, true));
+        let soon = review("review_by: ana\n", "2026-03-20").unwrap();
+        let orphan = review("", "2026-03-12 09:00").unwrap();
+        let later = review("review_by: luis\n", "2026-06-01").unwrap();
+        assert_eq!(orphan.owner(), "sin asignar");
+        assert_eq!(review("", "mañana").unwrap_err(), "mañana");
+        assert!(Review::of(Path::new("a.md"), "---\nid: \"1\"\n---\n", today).is_none());
+
+        let reviews = vec![soon, later, late, orphan];
+        let groups = due_within(&reviews, parse_days("2w").unwrap());
+        let summary: Vec<(&str, Vec<i64>)> = groups
+            .iter()
+            .map(|(owner, list)| (owner.as_str(), list.iter().map(|r| r.days_left).collect()))
+            .collect();
+        assert_eq!(summary, vec![("ana", vec![-9, 10]), ("sin asignar", vec![2])]);
+        let owners: Vec<(String, usize)> = by_owner(&reviews).into_iter().map(|(o, l)| (o, l.len())).collect();
+        assert_eq!(owners, vec![("ana".to_string(), 2), ("luis".to_string(), 1), ("sin asignar".to_string(), 1)]);
+        assert_eq!(parse_days("14d").unwrap(), 14);
+        assert!(parse_days("pronto").is_err());
+    }
+}
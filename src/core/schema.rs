@@ -4,6 +4,8 @@
 //! - Definición de esquemas con campos requeridos/opcionales
 //! - Validación de documentos contra esquemas
 //! - Sugerencias de corrección automáticas
+//! - Chequeo tipado del YAML crudo ([`typed_field_errors`]) para el schema
+//!   estricto de verify: fechas, enteros, listas y prioridades con su línea
 
 use crate::core::yaml::YamlFrontmatter;
 use crate::errors::{OcError, OcResult};
@@ -326,6 +328,95 @@ pub fn suggest_fixes(violations: &[SchemaViolation]) -> Vec<String> {
         .collect()
 }
 
+/// Campo del frontmatter cuyo valor no tiene el tipo esperado.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldTypeError {
+    /// Línea del campo en el documento (1-based).
+    pub line: usize,
+    pub field: String,
+    /// Valor ofensivo tal como está en el YAML.
+    pub value: String,
+    pub expected: String,
+}
+
+impl std::fmt::Display for FieldTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: se esperaba {}, valor '{}'", self.field, self.expected, self.value)
+    }
+}
+
+/// Línea (1-based) donde se declara `field` en el frontmatter.
+pub fn field_line(content: &str, field: &str) -> Option<usize> {
+    let mut lines = content.lines().enumerate();
+    lines.next().filter(|(_, l)| l.trim() == "---")?;
+    lines
+        .take_while(|(_, l)| l.trim() != "---")
+        .find(|(_, l)| {
+            l.strip_prefix(field)
+                .is_some_and(|rest| rest.trim_start().starts_with(':'))
+        })
+        .map(|(idx, _)| idx + 1)
+}
+
+/// Valor YAML como aparece en el documento.
+fn yaml_display(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s.clone(),
+        other => serde_yaml::to_string(other).unwrap_or_default().trim().replace('\n', " "),
+    }
+}
+
+/// Verifica que los campos tipados del frontmatter parseen a sus tipos Rust:
+/// `created`/`last_updated` como [`OcDate`](crate::types::OcDate),
+/// `children_count` como entero no negativo, `tags`/`actors` como listas y
+/// `priority` dentro de `priorities` (vacío = sin restricción). Los campos
+/// ausentes o nulos no se reportan; un YAML que no parsea tampoco (lo
+/// reporta el chequeo de sintaxis).
+pub fn typed_field_errors(content: &str, priorities: &[String]) -> Vec<FieldTypeError> {
+    use serde_yaml::Value;
+
+    let mut errors = Vec::new();
+    let Some(split) = crate::types::lifetimes::split_frontmatter(content) else {
+        return errors;
+    };
+    let Ok(Value::Mapping(map)) = serde_yaml::from_str::<Value>(split.metadata) else {
+        return errors;
+    };
+
+    let mut check = |field: &str, expected: String, ok: &dyn Fn(&Value) -> bool| {
+        match map.get(field) {
+            None | Some(Value::Null) => {}
+            Some(value) if ok(value) => {}
+            Some(value) => errors.push(FieldTypeError {
+                line: field_line(content, field).unwrap_or(1),
+                field: field.to_string(),
+                value: yaml_display(value),
+                expected,
+            }),
+        }
+    };
+
+    for field in ["created", "last_updated"] {
+        check(field, "fecha (YYYY-MM-DD o ISO 8601)".to_string(), &|v| {
+            v.as_str()
+                .is_some_and(|s| s.trim().parse::<crate::types::OcDate>().is_ok())
+        });
+    }
+    check("children_count", "entero no negativo".to_string(), &|v| v.as_u64().is_some());
+    for field in ["tags", "actors"] {
+        check(field, "lista".to_string(), &|v| v.is_sequence());
+    }
+    if !priorities.is_empty() {
+        check("priority", format!("uno de [{}]", priorities.join(", ")), &|v| {
+            let value = yaml_display(v).to_lowercase();
+            priorities.iter().any(|p| p.to_lowercase() == value)
+        });
+    }
+
+    errors.sort_by_key(|e| e.line);
+    errors
+}
+
 /// Carga esquema desde archivo YAML.
 pub fn load_schema(path: impl AsRef<Path>) -> OcResult<SchemaDefinition> {
     let content = std::fs::read_to_string(path.as_ref()).map_err(|e| OcError::FileRead {
@@ -413,6 +504,35 @@ mod tests {
         assert!(!result.defaults_applicable.is_empty());
     }
 
+    #[test]
+    fn test_typed_field_errors() {
+        let content = "---\nid: \"1\"\ncreated: 2024-13-01\nlast_updated: \"2024-05-01T10:00:00\"\nchildren_count: tres\ntags: api\nactors: [cliente]\npriority: urgente\n---\nCuerpo\n";
+        let priorities = vec!["alta".to_string(), "media".to_string(), "baja".to_string()];
+        let errors: Vec<String> = typed_field_errors(content, &priorities)
+            .iter()
+            .map(|e| format!("{}: {}", e.line, e))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "3: created: se esperaba fecha (YYYY-MM-DD o ISO 8601), valor '2024-13-01'",
+                "5: children_count: se esperaba entero no negativo, valor 'tres'",
+                "6: tags: se esperaba lista, valor 'api'",
+                "8: priority: se esperaba uno de [alta, media, baja], valor 'urgente'",
+            ]
+        );
+
+        let fixed = content
+            .replace("2024-13-01", "2024-12-01")
+            .replace("tres", "3")
+            .replace("tags: api", "tags: [api]")
+            .replace("urgente", "Alta");
+        assert!(typed_field_errors(&fixed, &priorities).is_empty());
+        assert!(typed_field_errors(content, &[]).iter().all(|e| e.field != "priority"));
+        assert_eq!(field_line(content, "tags"), Some(6));
+        assert_eq!(field_line("Cuerpo\ntags: x\n", "tags"), None);
+    }
+
     #[test]
     fn test_suggest_fixes() {
         let violations = vec![SchemaViolation {
@@ -0,0 +1,158 @@
+//! Sitemap y navegación para la web pública.
+//!
+//! `export --sitemap --base-url https://docs.x.mx` escribe junto al export un
+//! `sitemap.xml` con una URL por documento (`<base>/<slug>`, con `lastmod`
+//! desde `last_updated`) y un `nav.json` con la jerarquía por `parent`
+//! (id, título, slug, url y children) para el frontend. El slug es el campo
+//! `slug:` del documento (ver [`crate::core::slugs`]) o, si falta, el nombre
+//! de archivo.
+
+use crate::types::breadcrumb::frontmatter_value;
+use crate::types::lifetimes::split_frontmatter;
+use crate::types::OcDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Nombre del sitemap dentro del directorio de salida.
+pub const SITEMAP_FILE: &str = "sitemap.xml";
+
+/// Nombre de la navegación dentro del directorio de salida.
+pub const NAV_FILE: &str = "nav.json";
+
+/// Página publicada.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SitePage {
+    pub id: String,
+    pub title: String,
+    pub slug: String,
+    pub parent: Option<String>,
+    /// Fecha `YYYY-MM-DD` de `last_updated`, si se entiende.
+    pub lastmod: Option<String>,
+}
+
+impl SitePage {
+    /// Página de un documento (`stem` sin `.md`).
+    pub fn from_doc(stem: &str, content: &str) -> Self {
+        let metadata = split_frontmatter(content).map(|fm| fm.metadata).unwrap_or_default();
+        let field = |name: &str| frontmatter_value(metadata, name).filter(|v| v != "null");
+        Self {
+            id: field("id").unwrap_or_else(|| stem.to_string()),
+            title: field("title").unwrap_or_else(|| stem.to_string()),
+            slug: field("slug").unwrap_or_else(|| crate::core::slugs::slug_for(stem)),
+            parent: field("parent").or_else(|| field("parent_id")).filter(|p| p != "0"),
+            lastmod: field("last_updated")
+                .and_then(|d| d.parse::<OcDate>().ok())
+                .map(|d| d.to_date()),
+        }
+    }
+
+    pub fn url(&self, base_url: &str) -> String {
+        format!("{}/{}", base_url.trim_end_matches('/'), self.slug)
+    }
+}
+
+/// Nodo de `nav.json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct NavNode {
+    pub id: String,
+    pub title: String,
+    pub slug: String,
+    pub url: String,
+    pub children: Vec<NavNode>,
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// `sitemap.xml` (protocolo sitemaps.org 0.9) con las páginas en orden.
+pub fn sitemap_xml(pages: &[SitePage], base_url: &str) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for page in pages {
+        out.push_str(&format!("  <url>\n    <loc>{}</loc>\n", xml_escape(&page.url(base_url))));
+        if let Some(lastmod) = &page.lastmod {
+            out.push_str(&format!("    <lastmod>{}</lastmod>\n", lastmod));
+        }
+        out.push_str("  </url>\n");
+    }
+    out.push_str("</urlset>\n");
+    out
+}
+
+/// Árbol de navegación: raíces son las páginas sin `parent` o cuyo padre no
+/// se publica (y, en un ciclo de `parent`, la primera página del ciclo); los
+/// hijos conservan el orden de `pages`.
+pub fn nav_tree(pages: &[SitePage], base_url: &str) -> Vec<NavNode> {
+    let ids: HashMap<&str, usize> = pages.iter().enumerate().map(|(i, p)| (p.id.as_str(), i)).collect();
+    let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut roots = Vec::new();
+    for (i, page) in pages.iter().enumerate() {
+        match page.parent.as_deref().and_then(|p| ids.get(p)).filter(|&&parent| parent != i) {
+            Some(&parent) => children.entry(parent).or_default().push(i),
+            None => roots.push(i),
+        }
+    }
+
+    fn build(i: usize, pages: &[SitePage], children: &HashMap<usize, Vec<usize>>, base_url: &str, visited: &mut [bool]) -> NavNode {
+        visited[i] = true;
+        let page = &pages[i];
+        let kids: Vec<usize> = children.get(&i).into_iter().flatten().copied().filter(|&c| !visited[c]).collect();
+        NavNode {
+            id: page.id.clone(),
+            title: page.title.clone(),
+            slug: page.slug.clone(),
+            url: page.url(base_url),
+            children: kids.into_iter().map(|c| build(c, pages, children, base_url, visited)).collect(),
+        }
+    }
+
+    let mut visited = vec![false; pages.len()];
+    let mut nav: Vec<NavNode> = roots.into_iter().map(|i| build(i, pages, &children, base_url, &mut visited)).collect();
+    while let Some(i) = visited.iter().position(|v| !v) {
+        nav.push(build(i, pages, &children, base_url, &mut visited));
+    }
+    nav
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sitemap_and_nav() {
+        let doc = |id: &str, parent: &str, extra: &str| {
+            format!("---\nid: \"{}\"\ntitle: \"Doc {}\"\nparent: {}\n{}---\n", id, id, parent, extra)
+        };
+        let pages = vec![
+            SitePage::from_doc("1 Pagos & Cobros", &doc("1", "null", "last_updated: \"2026-03-01 10:00\"\n")),
+            SitePage::from_doc("1.1 Tarjeta", &doc("1.1", "\"1\"", "")),
+            SitePage::from_doc("1.1.1 Débito", &doc("1.1.1", "\"1.1\"", "")),
+            SitePage::from_doc("2 Soporte", &doc("2", "\"9\"", "slug: \"soporte\"\n")),
+        ];
+        assert_eq!(pages[0].slug, "1-pagos-cobros");
+        assert_eq!(pages[2].slug, "1-1-1-debito");
+        assert_eq!(pages[3].slug, "soporte");
+
+        let xml = sitemap_xml(&pages, "https://docs.x.mx/");
+        assert!(xml.contains("<loc>https://docs.x.mx/1-pagos-cobros</loc>\n    <lastmod>2026-03-01</lastmod>"));
+        assert_eq!(xml.matches("<url>").count(), 4);
+
+        let nav = nav_tree(&pages, "https://docs.x.mx");
+        assert_eq!(nav.iter().map(|n| n.id.as_str()).collect::<Vec<_>>(), vec!["1", "2"]);
+        assert_eq!(nav[0].children[0].children[0].url, "https://docs.x.mx/1-1-1-debito");
+        assert!(nav[1].children.is_empty());
+
+        let cycle = vec![
+            SitePage::from_doc("3 A", &doc("3", "\"4\"", "")),
+            SitePage::from_doc("4 B", &doc("4", "\"3\"", "")),
+        ];
+        let nav = nav_tree(&cycle, "https://docs.x.mx");
+        assert_eq!((nav.len(), nav[0].children.len()), (1, 1));
+    }
+}
@@ -0,0 +1,113 @@
+//! Slugs URL estables por documento.
+//!
+//! `gen slugs` fija un campo `slug:` único en cada documento (derivado del
+//! título la primera vez) y lo registra en `_index/slugs.json`. El slug no
+//! cambia al renombrar el título; si cambia (a mano o con `gen slugs
+//! --force`), el registro guarda un redirect del slug anterior al nuevo, que
+//! `export --sitemap` publica en `redirects.json`. La fase 3 de verify
+//! reporta slugs duplicados o inválidos.
+
+use crate::errors::{OcError, OcResult};
+use crate::types::path::slugify;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// Registro de slugs dentro del data_dir.
+pub const SLUG_REGISTRY: &str = "_index/slugs.json";
+
+/// Slug asignado a cada documento y redirects de slugs anteriores.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlugRegistry {
+    /// Documento (`id` o nombre de archivo) → slug.
+    pub slugs: BTreeMap<String, String>,
+    /// Slug anterior → slug actual.
+    pub redirects: BTreeMap<String, String>,
+}
+
+impl SlugRegistry {
+    /// Registro del proyecto; vacío si falta o no se entiende.
+    pub fn load(data_dir: &Path) -> Self {
+        std::fs::read_to_string(data_dir.join(SLUG_REGISTRY))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, data_dir: &Path) -> OcResult<()> {
+        let path = data_dir.join(SLUG_REGISTRY);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(self)?).map_err(|e| OcError::FileWrite { path, source: e })
+    }
+
+    /// Registra el slug actual de un documento. Si antes tenía otro, agrega
+    /// el redirect (y reapunta los que llevaban al anterior); devuelve el
+    /// slug anterior en ese caso.
+    pub fn record(&mut self, doc: &str, slug: &str) -> Option<String> {
+        // Un slug vuelto a usar deja de ser origen de redirect
+        self.redirects.remove(slug);
+        let previous = self.slugs.insert(doc.to_string(), slug.to_string())?;
+        if previous == slug {
+            return None;
+        }
+        for target in self.redirects.values_mut().filter(|t| **t == previous) {
+            *target = slug.to_string();
+        }
+        self.redirects.insert(previous.clone(), slug.to_string());
+        Some(previous)
+    }
+}
+
+/// Slug de un título: minúsculas, sin tildes, palabras unidas por `-`.
+pub fn slug_for(title: &str) -> String {
+    slugify(title).replace('_', "-")
+}
+
+/// ¿Solo minúsculas ASCII, dígitos y guiones simples entre palabras?
+pub fn is_valid(slug: &str) -> bool {
+    !slug.is_empty()
+        && slug.split('-').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()))
+}
+
+/// `base` o, si ya está tomado, `base-2`, `base-3`…
+pub fn unique(base: &str, taken: &HashSet<String>) -> String {
+    if !taken.contains(base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugs_unique_and_redirects() {
+        assert_eq!(slug_for("Políticas de Seguridad"), "politicas-de-seguridad");
+        assert!(is_valid("pagos-2") && !is_valid("Pagos") && !is_valid("a--b") && !is_valid(""));
+        let taken: HashSet<String> = ["pagos".to_string(), "pagos-2".to_string()].into();
+        assert_eq!(unique("pagos", &taken), "pagos-3");
+        assert_eq!(unique("cobros", &taken), "cobros");
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut registry = SlugRegistry::load(dir.path());
+        assert_eq!(registry.record("1.2", "pagos"), None);
+        assert_eq!(registry.record("1.2", "pagos"), None);
+        assert_eq!(registry.record("1.2", "cobros").as_deref(), Some("pagos"));
+        registry.record("1.2", "cobros-online");
+        assert_eq!(registry.redirects["pagos"], "cobros-online");
+        assert_eq!(registry.redirects["cobros"], "cobros-online");
+
+        // Otro documento retoma un slug viejo: ya no redirige
+        registry.record("3", "pagos");
+        assert!(!registry.redirects.contains_key("pagos"));
+        registry.save(dir.path()).unwrap();
+        assert_eq!(SlugRegistry::load(dir.path()), registry);
+    }
+}
@@ -0,0 +1,257 @@
+//! Plantillas de proyecto embebidas (starters).
+//!
+//! Cada starter trae un árbol de ejemplo, los tipos y statuses que usa y
+//! las reglas de lint que no aplican a su dominio. `init --template <nombre>`
+//! lo escribe en `Datos/` junto con la config del proyecto.
+
+use std::path::PathBuf;
+
+/// Documento del árbol de ejemplo. El padre sale del id (`2.1` → `2`, `2` → `0`).
+#[derive(Debug, Clone, Copy)]
+pub struct StarterDoc {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub doc_type: &'static str,
+    pub summary: &'static str,
+}
+
+/// Plantilla de proyecto.
+#[derive(Debug, Clone, Copy)]
+pub struct Starter {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Tipos de documento del dominio (todos válidos para `verify`).
+    pub types: &'static [&'static str],
+    /// Statuses en orden de ciclo de vida; el primero es el inicial.
+    pub statuses: &'static [&'static str],
+    /// Reglas de lint omitidas en todo el proyecto.
+    pub skip_rules: &'static [&'static str],
+    /// Schema estricto en la fase 2 de verify.
+    pub strict_schema: bool,
+    pub docs: &'static [StarterDoc],
+}
+
+const fn doc(id: &'static str, title: &'static str, doc_type: &'static str, summary: &'static str) -> StarterDoc {
+    StarterDoc {
+        id,
+        title,
+        doc_type,
+        summary,
+    }
+}
+
+/// Starters disponibles.
+pub static STARTERS: &[Starter] = &[
+    Starter {
+        name: "software-spec",
+        description: "Especificación de software: visión, requisitos, arquitectura y pruebas",
+        types: &["vision", "especificacion", "arquitectura", "componente", "flujo", "testing", "glosario"],
+        statuses: &["borrador", "activo", "aceptado", "deprecado"],
+        skip_rules: &[],
+        strict_schema: false,
+        docs: &[
+            doc("1", "Visión y alcance", "vision", "Problema que resuelve el sistema, usuarios y límites del alcance."),
+            doc("1.1", "Objetivos", "vision", "Objetivos medibles del producto y criterios de éxito."),
+            doc("1.2", "Glosario", "glosario", "Términos del dominio con su definición acordada."),
+            doc("2", "Requisitos", "especificacion", "Requisitos del sistema agrupados por tipo."),
+            doc("2.1", "Requisitos funcionales", "especificacion", "Qué debe hacer el sistema, uno por requisito con su id."),
+            doc("2.2", "Requisitos no funcionales", "especificacion", "Rendimiento, seguridad, disponibilidad y otras restricciones."),
+            doc("3", "Arquitectura", "arquitectura", "Vista general de la solución y decisiones de diseño."),
+            doc("3.1", "Componentes", "componente", "Responsabilidades e interfaces de cada componente."),
+            doc("3.2", "Flujos principales", "flujo", "Secuencia de los casos de uso críticos."),
+            doc("4", "Pruebas", "testing", "Estrategia de verificación del sistema."),
+            doc("4.1", "Plan de pruebas", "testing", "Casos de prueba por requisito y criterios de aceptación."),
+        ],
+    },
+    Starter {
+        name: "legal",
+        description: "Documentación legal: términos, privacidad y cumplimiento",
+        types: &["legal", "glosario", "privacidad", "politica", "proceso"],
+        statuses: &["borrador", "aceptado", "deprecado"],
+        skip_rules: &[],
+        strict_schema: true,
+        docs: &[
+            doc("1", "Términos y condiciones", "legal", "Condiciones de uso del servicio."),
+            doc("1.1", "Definiciones", "glosario", "Términos definidos que se usan en todos los documentos."),
+            doc("1.2", "Obligaciones de las partes", "legal", "Obligaciones del proveedor y del usuario."),
+            doc("2", "Privacidad", "privacidad", "Tratamiento de datos personales."),
+            doc("2.1", "Política de privacidad", "politica", "Qué datos se recogen, con qué fin y por cuánto tiempo."),
+            doc("2.2", "Tratamiento de datos", "proceso", "Procedimiento ante solicitudes de acceso, rectificación y borrado."),
+            doc("3", "Cumplimiento", "politica", "Normativa aplicable y responsables."),
+            doc("3.1", "Registro de cambios normativos", "proceso", "Cambios de normativa y su impacto en los documentos."),
+        ],
+    },
+    Starter {
+        name: "api-docs",
+        description: "Documentación de API: guías, referencia de endpoints y errores",
+        types: &["guia", "referencia", "api", "esquema", "manejo_errores"],
+        statuses: &["borrador", "activo", "deprecado"],
+        // Endpoints, URLs y ejemplos de payload rompen el largo de línea
+        skip_rules: &["L005"],
+        strict_schema: false,
+        docs: &[
+            doc("1", "Introducción", "guia", "Qué ofrece la API y a quién está dirigida."),
+            doc("1.1", "Autenticación", "guia", "Obtención y uso de credenciales."),
+            doc("1.2", "Primeros pasos", "guia", "Primera llamada a la API de punta a punta."),
+            doc("2", "Referencia", "referencia", "Referencia completa de la API."),
+            doc("2.1", "Endpoints", "api", "Método, ruta, parámetros y respuestas de cada endpoint."),
+            doc("2.2", "Esquemas", "esquema", "Modelos de datos de requests y responses."),
+            doc("2.3", "Errores", "manejo_errores", "Códigos de error, causas y cómo resolverlos."),
+            doc("3", "Versiones", "referencia", "Cambios por versión de la API y política de deprecación."),
+        ],
+    },
+];
+
+/// Starter por nombre.
+pub fn find(name: &str) -> Option<&'static Starter> {
+    STARTERS.iter().find(|s| s.name.eq_ignore_ascii_case(name))
+}
+
+/// Nombres de los starters, para mensajes de ayuda.
+pub fn names() -> Vec<&'static str> {
+    STARTERS.iter().map(|s| s.name).collect()
+}
+
+impl StarterDoc {
+    /// Id del padre (`0` para las raíces de módulo).
+    pub fn parent(&self) -> &'static str {
+        self.id.rsplit_once('.').map_or("0", |(parent, _)| parent)
+    }
+
+    /// Nombre de archivo según la convención (`2. Referencia`, `2.1 Endpoints`).
+    pub fn file_stem(&self) -> String {
+        if self.id.contains('.') {
+            format!("{} {}", self.id, self.title)
+        } else {
+            format!("{}. {}", self.id, self.title)
+        }
+    }
+}
+
+impl Starter {
+    fn children(&self, parent: &str) -> impl Iterator<Item = &StarterDoc> + '_ {
+        let parent = parent.to_string();
+        self.docs.iter().filter(move |d| d.parent() == parent)
+    }
+
+    fn breadcrumb(&self, doc: &StarterDoc) -> String {
+        let mut titles = vec![doc.title];
+        let mut parent = doc.parent();
+        while let Some(p) = self.docs.iter().find(|d| d.id == parent) {
+            titles.push(p.title);
+            parent = p.parent();
+        }
+        titles.reverse();
+        titles.join(crate::types::breadcrumb::BREADCRUMB_SEPARATOR)
+    }
+
+    fn index_table(&self, parent: &str) -> String {
+        let mut table = String::from("| ID | Documento | Tipo |\n|----|-----------|------|\n");
+        for child in self.children(parent) {
+            table.push_str(&format!("| {} | [[{}]] | {} |\n", child.id, child.file_stem(), child.doc_type));
+        }
+        table
+    }
+
+    /// Documentos del starter, relativos al data_dir: Contextualizador y árbol.
+    pub fn files(&self, project: &str, now: &str) -> Vec<(PathBuf, String)> {
+        let status = self.statuses[0];
+        let list = |values: &[&str]| values.iter().map(|v| format!("`{}`", v)).collect::<Vec<_>>().join(", ");
+        let lint = if self.skip_rules.is_empty() {
+            "todas las reglas activas".to_string()
+        } else {
+            format!("se omiten {}", list(self.skip_rules))
+        };
+
+        let mut files = vec![(
+            PathBuf::from("0. Contextualizador.md"),
+            format!(
+                "---\nid: \"0\"\ntitle: \"Contextualizador\"\nparent: \"0\"\nbreadcrumb: \"Contextualizador\"\n\
+                 type: indice_maestro\nstatus: {status}\ncreated: \"{now}\"\nlast_updated: \"{now}\"\n---\n\n\
+                 # {project}\n\n## Descripción general\n\n{description}.\n\n## Índice de módulos\n\n{index}\n\
+                 ## Convenciones\n\n- Tipos: {types}\n- Statuses: {statuses}\n- Lint: {lint}\n",
+                status = status,
+                now = now,
+                project = project,
+                description = self.description,
+                index = self.index_table("0"),
+                types = list(self.types),
+                statuses = list(self.statuses),
+                lint = lint,
+            ),
+        )];
+
+        for doc in self.docs {
+            let children = self.children(doc.id).count();
+            let parent_link = match self.docs.iter().find(|d| d.id == doc.parent()) {
+                Some(parent) => parent.file_stem(),
+                None => "0. Contextualizador".to_string(),
+            };
+            let body = if children > 0 {
+                format!("## Documentos\n\n{}", self.index_table(doc.id))
+            } else {
+                "## Contenido\n\n[Completar]\n".to_string()
+            };
+            files.push((
+                PathBuf::from(format!("{}.md", doc.file_stem())),
+                format!(
+                    "---\nid: \"{id}\"\ntitle: \"{title}\"\nparent: \"{parent}\"\nbreadcrumb: \"{breadcrumb}\"\n\
+                     type: {doc_type}\nstatus: {status}\ncreated: \"{now}\"\nlast_updated: \"{now}\"\n\
+                     children_count: {children}\n---\n\n# {title}\n\nVolver a: [[{parent_link}]]\n\n{summary}\n\n{body}",
+                    id = doc.id,
+                    title = doc.title,
+                    parent = doc.parent(),
+                    breadcrumb = self.breadcrumb(doc),
+                    doc_type = doc.doc_type,
+                    status = status,
+                    now = now,
+                    children = children,
+                    parent_link = parent_link,
+                    summary = doc.summary,
+                    body = body,
+                ),
+            ));
+        }
+        files
+    }
+
+    /// Líneas de config propias del starter (perfil de todo el data_dir).
+    pub fn config(&self) -> String {
+        let mut out = format!("profiles:\n  - path: \".\"\n    strict_schema: {}\n", self.strict_schema);
+        if !self.skip_rules.is_empty() {
+            out.push_str(&format!("    skip_rules: [{}]\n", self.skip_rules.join(", ")));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starters_tree_is_consistent() {
+        assert_eq!(names(), vec!["software-spec", "legal", "api-docs"]);
+        for starter in STARTERS {
+            for doc in starter.docs {
+                assert!(
+                    doc.parent() == "0" || starter.docs.iter().any(|d| d.id == doc.parent()),
+                    "{}: {} sin padre",
+                    starter.name,
+                    doc.id
+                );
+                assert!(starter.types.contains(&doc.doc_type), "{}: tipo {}", starter.name, doc.doc_type);
+            }
+        }
+
+        let api = find("API-DOCS").unwrap();
+        let files = api.files("Pagos", "2026-01-01 10:00");
+        assert_eq!(files.len(), api.docs.len() + 1);
+        let (path, endpoints) = files.iter().find(|(p, _)| p.ends_with("2.1 Endpoints.md")).unwrap();
+        assert_eq!(path, &PathBuf::from("2.1 Endpoints.md"));
+        assert!(endpoints.contains("breadcrumb: \"Referencia > Endpoints\""));
+        assert!(endpoints.contains("Volver a: [[2. Referencia]]"));
+        assert!(files[0].1.contains("| 2 | [[2. Referencia]] | referencia |"));
+        assert_eq!(api.config(), "profiles:\n  - path: \".\"\n    strict_schema: false\n    skip_rules: [L005]\n");
+    }
+}
@@ -0,0 +1,302 @@
+//! Guía de estilo: terminología prohibida y mayúsculas de marca.
+//!
+//! Se carga de `styleguide.yaml` en la raíz del proyecto (o en
+//! `.oc_diagdoc/`) y la aplica la regla de lint L018:
+//!
+//! ```yaml
+//! forbidden:
+//!   app móvil: aplicación
+//!   click: clic
+//!   master: ~          # prohibido, sin reemplazo sugerido
+//! brands: [OnlyCar, GitHub]
+//! autofix: true        # lint --fix reemplaza términos y corrige marcas
+//! ```
+//!
+//! Las coincidencias son por palabra completa y sin distinguir mayúsculas;
+//! se ignoran frontmatter, bloques y spans de código, wiki-links y URLs.
+
+use crate::errors::{OcError, OcResult};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::path::Path;
+
+/// Nombre del archivo de la guía de estilo.
+pub const STYLEGUIDE_FILE: &str = "styleguide.yaml";
+
+/// Tramos que la guía no toca: código inline, wiki-links, destinos de
+/// links markdown y URLs.
+static RE_PROTECTED: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"`[^`]*`|!?\[\[[^\]]*\]\]|\]\([^)]*\)|https?://\S+").unwrap());
+
+/// Contenido de `styleguide.yaml`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct StyleGuideFile {
+    forbidden: BTreeMap<String, Option<String>>,
+    brands: Vec<String>,
+    autofix: bool,
+}
+
+/// Tipo de infracción.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StyleKind {
+    /// Término prohibido.
+    Forbidden,
+    /// Marca con mayúsculas incorrectas.
+    Brand,
+}
+
+/// Infracción de la guía en un documento.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleViolation {
+    /// Línea (1-based).
+    pub line: usize,
+    pub kind: StyleKind,
+    /// Texto tal como aparece.
+    pub found: String,
+    /// Reemplazo sugerido (ya con la capitalización del original).
+    pub suggestion: Option<String>,
+}
+
+impl StyleViolation {
+    pub fn message(&self) -> String {
+        let suggestion = self
+            .suggestion
+            .as_ref()
+            .map(|s| format!(" (usar '{}')", s))
+            .unwrap_or_default();
+        match self.kind {
+            StyleKind::Forbidden => format!("Término prohibido '{}'{}", self.found, suggestion),
+            StyleKind::Brand => format!("Marca mal escrita '{}'{}", self.found, suggestion),
+        }
+    }
+}
+
+/// Regla compilada: patrón, tipo y reemplazo.
+#[derive(Debug, Clone)]
+struct StyleRule {
+    pattern: Regex,
+    kind: StyleKind,
+    replacement: Option<String>,
+}
+
+/// Guía de estilo compilada.
+#[derive(Debug, Clone, Default)]
+pub struct StyleGuide {
+    rules: Vec<StyleRule>,
+    /// ¿`lint --fix` aplica los reemplazos?
+    pub autofix: bool,
+}
+
+/// Patrón de palabra completa, sin distinguir mayúsculas.
+fn word_pattern(term: &str) -> OcResult<Regex> {
+    let escaped = regex::escape(term.trim()).replace(r"\ ", r"\s+").replace(' ', r"\s+");
+    Regex::new(&format!(r"(?i)\b{}\b", escaped))
+        .map_err(|e| OcError::InvalidArgument(format!("término '{}' inválido: {}", term, e)))
+}
+
+/// Reemplazo con la mayúscula inicial del texto original (`App móvil` →
+/// `Aplicación`).
+fn match_case(found: &str, replacement: &str) -> String {
+    let starts_upper = found.chars().next().is_some_and(char::is_uppercase);
+    let mut chars = replacement.chars();
+    match chars.next() {
+        Some(first) if starts_upper && first.is_lowercase() => first.to_uppercase().chain(chars).collect(),
+        _ => replacement.to_string(),
+    }
+}
+
+impl StyleGuide {
+    /// Parsea el YAML de una guía.
+    pub fn parse(yaml: &str) -> OcResult<Self> {
+        let file: StyleGuideFile = serde_yaml::from_str(yaml)?;
+        let mut rules = Vec::new();
+        for (term, replacement) in &file.forbidden {
+            if term.trim().is_empty() {
+                continue;
+            }
+            rules.push(StyleRule {
+                pattern: word_pattern(term)?,
+                kind: StyleKind::Forbidden,
+                replacement: replacement.clone().filter(|r| !r.trim().is_empty()),
+            });
+        }
+        for brand in file.brands.iter().filter(|b| !b.trim().is_empty()) {
+            rules.push(StyleRule {
+                pattern: word_pattern(brand)?,
+                kind: StyleKind::Brand,
+                replacement: Some(brand.trim().to_string()),
+            });
+        }
+        Ok(Self {
+            rules,
+            autofix: file.autofix,
+        })
+    }
+
+    /// Carga la guía de un archivo.
+    pub fn load(path: &Path) -> OcResult<Self> {
+        let yaml = std::fs::read_to_string(path).map_err(|e| OcError::FileRead {
+            path: path.to_path_buf(),
+            source: e,
+        })?;
+        Self::parse(&yaml).map_err(|e| OcError::YamlParse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Guía del directorio de trabajo (`styleguide.yaml` o
+    /// `.oc_diagdoc/styleguide.yaml`); vacía si no existe o es inválida.
+    pub fn from_cwd() -> Self {
+        let candidates = [
+            Path::new(STYLEGUIDE_FILE).to_path_buf(),
+            Path::new(crate::core::config::CONFIG_DIR).join(STYLEGUIDE_FILE),
+        ];
+        let Some(path) = candidates.iter().find(|p| p.exists()) else {
+            return Self::default();
+        };
+        Self::load(path).unwrap_or_else(|e| {
+            eprintln!("⚠️ guía de estilo ignorada: {}", e);
+            Self::default()
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Coincidencias de una línea fuera de los tramos protegidos: (rango,
+    /// reemplazo sugerido, tipo). Un término no se reporta dos veces en el
+    /// mismo tramo.
+    fn line_matches(&self, line: &str) -> Vec<(Range<usize>, Option<String>, StyleKind)> {
+        let protected: Vec<Range<usize>> = RE_PROTECTED.find_iter(line).map(|m| m.range()).collect();
+        let mut found: Vec<(Range<usize>, Option<String>, StyleKind)> = Vec::new();
+        for rule in &self.rules {
+            for m in rule.pattern.find_iter(line) {
+                let range = m.range();
+                let overlaps = |r: &Range<usize>| r.start < range.end && range.start < r.end;
+                if protected.iter().any(overlaps) || found.iter().any(|(r, ..)| overlaps(r)) {
+                    continue;
+                }
+                let suggestion = rule.replacement.as_deref().map(|r| match rule.kind {
+                    StyleKind::Forbidden => match_case(m.as_str(), r),
+                    StyleKind::Brand => r.to_string(),
+                });
+                // Marca ya bien escrita
+                if rule.kind == StyleKind::Brand && suggestion.as_deref() == Some(m.as_str()) {
+                    continue;
+                }
+                found.push((range, suggestion, rule.kind));
+            }
+        }
+        found.sort_by_key(|(r, ..)| r.start);
+        found
+    }
+
+    /// Líneas del cuerpo (1-based) fuera de bloques de código.
+    fn prose_lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+        let mut in_frontmatter = content.starts_with("---");
+        let mut in_code = false;
+        content.lines().enumerate().filter_map(move |(idx, line)| {
+            let trimmed = line.trim();
+            if in_frontmatter {
+                in_frontmatter = idx == 0 || trimmed != "---";
+                return None;
+            }
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code = !in_code;
+                return None;
+            }
+            (!in_code).then_some((idx + 1, line))
+        })
+    }
+
+    /// Infracciones de la guía en un documento.
+    pub fn check(&self, content: &str) -> Vec<StyleViolation> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        Self::prose_lines(content)
+            .flat_map(|(line_no, line)| {
+                self.line_matches(line)
+                    .into_iter()
+                    .map(move |(range, suggestion, kind)| StyleViolation {
+                        line: line_no,
+                        kind,
+                        found: line[range].to_string(),
+                        suggestion,
+                    })
+            })
+            .collect()
+    }
+
+    /// Aplica los reemplazos sugeridos; `None` si no hay nada que cambiar.
+    pub fn fix(&self, content: &str) -> Option<String> {
+        let mut replacements: BTreeMap<usize, Vec<(Range<usize>, String)>> = BTreeMap::new();
+        for (line_no, line) in Self::prose_lines(content) {
+            let fixes: Vec<(Range<usize>, String)> = self
+                .line_matches(line)
+                .into_iter()
+                .filter_map(|(range, suggestion, _)| Some((range, suggestion?)))
+                .collect();
+            if !fixes.is_empty() {
+                replacements.insert(line_no, fixes);
+            }
+        }
+        if replacements.is_empty() {
+            return None;
+        }
+
+        let mut out = String::with_capacity(content.len());
+        for (idx, line) in content.split_inclusive('\n').enumerate() {
+            let Some(fixes) = replacements.get(&(idx + 1)) else {
+                out.push_str(line);
+                continue;
+            };
+            let mut last = 0;
+            for (range, replacement) in fixes {
+                out.push_str(&line[last..range.start]);
+                out.push_str(replacement);
+                last = range.end;
+            }
+            out.push_str(&line[last..]);
+        }
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GUIDE: &str = "forbidden:\n  app móvil: aplicación\n  master: ~\nbrands: [OnlyCar]\nautofix: true\n";
+
+    #[test]
+    fn test_check_styleguide() {
+        let guide = StyleGuide::parse(GUIDE).unwrap();
+        let content = "---\ntitle: app móvil\n---\n# Onlycar\n\nLa App  móvil de OnlyCar usa `master`.\n\
+                       ```bash\ngit push master\n```\nVer [[app móvil]] y master.\n";
+        let found: Vec<(usize, String)> = guide.check(content).iter().map(|v| (v.line, v.message())).collect();
+        assert_eq!(
+            found,
+            vec![
+                (4, "Marca mal escrita 'Onlycar' (usar 'OnlyCar')".to_string()),
+                (6, "Término prohibido 'App  móvil' (usar 'Aplicación')".to_string()),
+                (10, "Término prohibido 'master'".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fix_styleguide() {
+        let guide = StyleGuide::parse(GUIDE).unwrap();
+        let fixed = guide.fix("# onlycar\n\nUna app móvil, [[app móvil]].\n").unwrap();
+        assert_eq!(fixed, "# OnlyCar\n\nUna aplicación, [[app móvil]].\n");
+        assert_eq!(guide.fix("Sin cambios en OnlyCar.\n"), None);
+        assert!(StyleGuide::parse("brands: OnlyCar").is_err());
+    }
+}
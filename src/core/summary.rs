@@ -0,0 +1,170 @@
+//! Resúmenes extractivos de documentos.
+//!
+//! Extrae las primeras oraciones de prosa y los headings de un documento,
+//! sin reescribir texto: lo que aparece en el resumen está en la fuente.
+
+use crate::types::lifetimes::split_frontmatter;
+
+/// Resumen extractivo de un documento hoja.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeafSummary {
+    pub id: String,
+    pub title: String,
+    pub sentences: Vec<String>,
+    pub headings: Vec<String>,
+}
+
+impl LeafSummary {
+    /// Extrae hasta `max_sentences` oraciones y los headings `##` del documento.
+    pub fn extract(id: &str, title: &str, content: &str, max_sentences: usize) -> Self {
+        let body = body_of(content);
+        Self {
+            id: id.to_string(),
+            title: title.to_string(),
+            sentences: first_sentences(body, max_sentences),
+            headings: headings(body, 2),
+        }
+    }
+
+    /// Bloque Markdown con `sentences` oraciones.
+    pub fn render(&self, sentences: usize) -> String {
+        let mut out = format!("### {} {}\n\n", self.id, self.title);
+        let text: Vec<&str> = self
+            .sentences
+            .iter()
+            .take(sentences)
+            .map(String::as_str)
+            .collect();
+        if !text.is_empty() {
+            out.push_str(&text.join(" "));
+            out.push_str("\n\n");
+        }
+        if !self.headings.is_empty() {
+            out.push_str(&format!("*Secciones:* {}\n\n", self.headings.join(" · ")));
+        }
+        out
+    }
+}
+
+/// Body sin frontmatter.
+pub fn body_of(content: &str) -> &str {
+    if content.trim_start().starts_with("---") {
+        split_frontmatter(content)
+            .map(|split| split.content)
+            .unwrap_or(content)
+    } else {
+        content
+    }
+}
+
+/// Headings de nivel exacto `level`, fuera de bloques de código.
+pub fn headings(body: &str, level: usize) -> Vec<String> {
+    let marker = format!("{} ", "#".repeat(level));
+    let mut in_code = false;
+    body.lines()
+        .filter_map(|line| {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") {
+                in_code = !in_code;
+                return None;
+            }
+            if in_code {
+                return None;
+            }
+            trimmed
+                .strip_prefix(&marker)
+                .map(|h| h.trim().to_string())
+                .filter(|h| !h.is_empty())
+        })
+        .collect()
+}
+
+/// Primeras `max` oraciones de prosa (ignora headings, código, tablas, listas y citas).
+pub fn first_sentences(body: &str, max: usize) -> Vec<String> {
+    let mut prose = String::new();
+    let mut in_code = false;
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        let skip = in_code
+            || trimmed.is_empty()
+            || trimmed.starts_with('#')
+            || trimmed.starts_with('|')
+            || trimmed.starts_with('>')
+            || trimmed.starts_with("- ")
+            || trimmed.starts_with("* ")
+            || trimmed.starts_with("---")
+            || trimmed.starts_with("![");
+        if skip {
+            continue;
+        }
+        if !prose.is_empty() {
+            prose.push(' ');
+        }
+        prose.push_str(trimmed);
+    }
+
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+    let mut chars = prose.chars().peekable();
+    while let Some(c) = chars.next() {
+        current.push(c);
+        let at_end = matches!(c, '.' | '!' | '?')
+            && chars.peek().map(|n| n.is_whitespace()).unwrap_or(true);
+        if at_end {
+            let sentence = current.trim().to_string();
+            current.clear();
+            // Ignorar placeholders tipo "[Contenido principal]" y fragmentos mínimos
+            if sentence.split_whitespace().count() >= 3 {
+                sentences.push(sentence);
+                if sentences.len() >= max {
+                    return sentences;
+                }
+            }
+        }
+    }
+    let rest = current.trim();
+    if sentences.len() < max && rest.split_whitespace().count() >= 3 && !rest.starts_with('[') {
+        sentences.push(rest.to_string());
+    }
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = "---\ntitle: X\n---\n\n# X\n\nPrimera oración del texto. Segunda oración aquí!\n\n```\nno. es. prosa.\n```\n\n## Alcance\n\n- item\n\nTercera oración final del documento.\n\n## Riesgos\n";
+
+    #[test]
+    fn test_first_sentences() {
+        let body = body_of(DOC);
+        assert_eq!(
+            first_sentences(body, 5),
+            vec![
+                "Primera oración del texto.",
+                "Segunda oración aquí!",
+                "Tercera oración final del documento."
+            ]
+        );
+        assert_eq!(first_sentences(body, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_headings() {
+        assert_eq!(headings(body_of(DOC), 2), vec!["Alcance", "Riesgos"]);
+    }
+
+    #[test]
+    fn test_leaf_render() {
+        let leaf = LeafSummary::extract("1.1", "X", DOC, 3);
+        let md = leaf.render(1);
+        assert!(md.starts_with("### 1.1 X"));
+        assert!(md.contains("Primera oración del texto."));
+        assert!(!md.contains("Segunda"));
+        assert!(md.contains("*Secciones:* Alcance · Riesgos"));
+    }
+}
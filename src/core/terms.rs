@@ -0,0 +1,99 @@
+//! Frecuencia de términos (sin stopwords español/inglés).
+//!
+//! Base de `stats --wordcloud` y de las sugerencias de tags de `audit`.
+
+use crate::core::summary::body_of;
+use std::collections::BTreeMap;
+
+/// Conteo término → ocurrencias.
+pub type TermCounts = BTreeMap<String, usize>;
+
+/// Stopwords en español (minúsculas, con tildes).
+const STOPWORDS_ES: &[&str] = &[
+    "del", "las", "los", "una", "uno", "unos", "unas", "por", "para", "con", "sin", "que", "como",
+    "más", "mas", "pero", "sus", "este", "esta", "estos", "estas", "ese", "esa", "esos", "esas",
+    "son", "ser", "será", "sera", "fue", "han", "hay", "muy", "también", "tambien", "entre",
+    "cuando", "donde", "desde", "hasta", "sobre", "todo", "todos", "toda", "todas", "cada", "otro",
+    "otra", "otros", "otras", "debe", "deben", "puede", "pueden", "según", "segun", "sólo", "solo",
+    "está", "están", "esto", "eso", "así", "asi", "porque", "tiene", "tienen", "mismo", "misma",
+    "ver", "cual", "cuál", "les", "nos", "ante", "bajo", "tras", "durante", "mediante", "dos",
+];
+
+/// Stopwords en inglés.
+const STOPWORDS_EN: &[&str] = &[
+    "the", "and", "for", "with", "that", "this", "from", "are", "was", "were", "has", "have",
+    "not", "but", "all", "any", "can", "will", "its", "into", "than", "then", "them", "they",
+    "their", "there", "which", "when", "where", "what", "who", "how", "also", "each", "other",
+    "such", "only", "more", "most", "should", "would", "could", "been", "being", "about", "you",
+    "your", "our",
+];
+
+/// true si `word` (en minúsculas) es stopword.
+pub fn is_stopword(word: &str) -> bool {
+    STOPWORDS_ES.contains(&word) || STOPWORDS_EN.contains(&word)
+}
+
+/// Palabras significativas del cuerpo: minúsculas, ≥3 letras, sin
+/// stopwords, fuera de frontmatter, bloques de código y URLs.
+pub fn significant_words(content: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut in_code = false;
+    for line in body_of(content).lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+        for token in line.split_whitespace().filter(|t| !t.contains("://")) {
+            for word in token.split(|c: char| !c.is_alphabetic()) {
+                let word = word.to_lowercase();
+                if word.chars().count() >= 3 && !is_stopword(&word) {
+                    words.push(word);
+                }
+            }
+        }
+    }
+    words
+}
+
+/// Cuenta términos de un documento y los suma a `counts`.
+pub fn count_terms(content: &str, counts: &mut TermCounts) {
+    for word in significant_words(content) {
+        *counts.entry(word).or_default() += 1;
+    }
+}
+
+/// Los `n` términos más frecuentes (empates por orden alfabético).
+pub fn top_terms(counts: &TermCounts, n: usize) -> Vec<(String, usize)> {
+    let mut sorted: Vec<(String, usize)> = counts.iter().map(|(t, c)| (t.clone(), *c)).collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    sorted.truncate(n);
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_significant_words_skip_noise() {
+        let content = "---\ntitle: Pagos\n---\nLos pagos y las comisiones del operador.\n```\ncodigo ignorado\n```\nVer https://example.com para pagos.\n";
+        assert_eq!(
+            significant_words(content),
+            vec!["pagos", "comisiones", "operador", "pagos"]
+        );
+    }
+
+    #[test]
+    fn test_top_terms_order() {
+        let mut counts = TermCounts::new();
+        count_terms("beta alfa beta gamma alfa beta", &mut counts);
+        assert_eq!(
+            top_terms(&counts, 2),
+            vec![("beta".to_string(), 3), ("alfa".to_string(), 2)]
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! Instrumentación de rendimiento para `--timings`.
+//!
+//! `Profiler` acumula tiempos por etapa (fase o regla) y por archivo, cuenta
+//! lecturas de disco y produce un `TimingReport` con los archivos más lentos
+//! y la memoria pico del proceso.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROFILER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Colector de tiempos; seguro entre hilos.
+#[derive(Debug, Default)]
+pub struct Profiler {
+    reads: AtomicUsize,
+    bytes_read: AtomicU64,
+    stages: Mutex<Vec<(String, Duration)>>,
+    files: Mutex<HashMap<PathBuf, Duration>>,
+    /// Archivo en curso: su tiempo corre hasta la siguiente lectura.
+    current: Mutex<Option<(PathBuf, Instant)>>,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lee un archivo contando la lectura.
+    pub fn read(&self, path: &Path) -> std::io::Result<String> {
+        let content = std::fs::read_to_string(path)?;
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        self.bytes_read.fetch_add(content.len() as u64, Ordering::Relaxed);
+        Ok(content)
+    }
+
+    /// Empieza a medir un archivo; cierra el anterior.
+    pub fn begin_file(&self, path: &Path) {
+        let previous = self
+            .current
+            .lock()
+            .unwrap()
+            .replace((path.to_path_buf(), Instant::now()));
+        if let Some((prev, since)) = previous {
+            self.record_file(&prev, since.elapsed());
+        }
+    }
+
+    /// Cierra el archivo en curso, si lo hay.
+    pub fn end_file(&self) {
+        let previous = self.current.lock().unwrap().take();
+        if let Some((prev, since)) = previous {
+            self.record_file(&prev, since.elapsed());
+        }
+    }
+
+    /// Acumula tiempo de un archivo.
+    pub fn record_file(&self, path: &Path, elapsed: Duration) {
+        *self
+            .files
+            .lock()
+            .unwrap()
+            .entry(path.to_path_buf())
+            .or_default() += elapsed;
+    }
+
+    /// Acumula tiempo de una etapa (fase o regla), en orden de aparición.
+    pub fn record_stage(&self, name: &str, elapsed: Duration) {
+        let mut stages = self.stages.lock().unwrap();
+        match stages.iter_mut().find(|(n, _)| n == name) {
+            Some((_, total)) => *total += elapsed,
+            None => stages.push((name.to_string(), elapsed)),
+        }
+    }
+
+    /// Mide `f` como parte de la etapa `name`.
+    pub fn time<T>(&self, name: &str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let value = f();
+        self.record_stage(name, start.elapsed());
+        value
+    }
+
+    /// Genera el reporte con los `top` archivos más lentos.
+    pub fn report(&self, top: usize) -> TimingReport {
+        self.end_file();
+
+        let stages = self
+            .stages
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, d)| StageTiming {
+                name: name.clone(),
+                ms: as_ms(*d),
+            })
+            .collect();
+
+        let mut slowest: Vec<FileTiming> = self
+            .files
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(path, d)| FileTiming {
+                path: path.clone(),
+                ms: as_ms(*d),
+            })
+            .collect();
+        slowest.sort_by(|a, b| b.ms.total_cmp(&a.ms).then_with(|| a.path.cmp(&b.path)));
+        slowest.truncate(top);
+
+        TimingReport {
+            stages,
+            slowest_files: slowest,
+            disk_reads: self.reads.load(Ordering::Relaxed),
+            bytes_read: self.bytes_read.load(Ordering::Relaxed),
+            peak_memory_kb: peak_memory_kb(),
+        }
+    }
+}
+
+fn as_ms(d: Duration) -> f64 {
+    (d.as_secs_f64() * 1_000_000.0).round() / 1000.0
+}
+
+/// Memoria residente pico del proceso (VmHWM); solo Linux.
+pub fn peak_memory_kb() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))
+        .and_then(|v| v.split_whitespace().next())
+        .and_then(|v| v.parse().ok())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REPORT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Tiempo acumulado de una etapa.
+#[derive(Debug, Clone, Serialize)]
+pub struct StageTiming {
+    pub name: String,
+    pub ms: f64,
+}
+
+/// Tiempo acumulado de un archivo.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileTiming {
+    pub path: PathBuf,
+    pub ms: f64,
+}
+
+/// Reporte de `--timings`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimingReport {
+    pub stages: Vec<StageTiming>,
+    pub slowest_files: Vec<FileTiming>,
+    pub disk_reads: usize,
+    pub bytes_read: u64,
+    pub peak_memory_kb: Option<u64>,
+}
+
+impl TimingReport {
+    /// Imprime el reporte en stderr para no mezclarlo con la salida normal.
+    pub fn print(&self, stage_label: &str) {
+        eprintln!();
+        eprintln!("⏱️  TIMINGS");
+        eprintln!("   Por {}:", stage_label);
+        for stage in &self.stages {
+            eprintln!("     {:>10.3} ms  {}", stage.ms, stage.name);
+        }
+        if !self.slowest_files.is_empty() {
+            eprintln!("   Archivos más lentos:");
+            for file in &self.slowest_files {
+                eprintln!("     {:>10.3} ms  {}", file.ms, file.path.display());
+            }
+        }
+        eprintln!(
+            "   Lecturas de disco: {} ({} KB)",
+            self.disk_reads,
+            self.bytes_read / 1024
+        );
+        if let Some(kb) = self.peak_memory_kb {
+            eprintln!("   Memoria pico: {:.1} MB", kb as f64 / 1024.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_profiler_counts_reads_and_files() {
+        let dir = tempdir().unwrap();
+        let a = dir.path().join("a.md");
+        let b = dir.path().join("b.md");
+        std::fs::write(&a, "hola").unwrap();
+        std::fs::write(&b, "mundo!").unwrap();
+
+        let profiler = Profiler::new();
+        for path in [&a, &b, &a] {
+            profiler.begin_file(path);
+            profiler.read(path).unwrap();
+        }
+        profiler.time("L001", || std::thread::sleep(Duration::from_millis(2)));
+        profiler.time("L001", || ());
+
+        let report = profiler.report(1);
+        assert_eq!(report.disk_reads, 3);
+        assert_eq!(report.bytes_read, 14);
+        assert_eq!(report.slowest_files.len(), 1);
+        assert_eq!(report.stages.len(), 1);
+        assert!(report.stages[0].ms >= 2.0);
+    }
+}
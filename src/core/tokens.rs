@@ -0,0 +1,119 @@
+//! Conteo de tokens para presupuestos de contexto LLM.
+//!
+//! `cl100k` es una estimación: replica la pre-tokenización de cl100k_base
+//! (palabras, números de hasta 3 dígitos, puntuación, espacios) y estima los
+//! sub-tokens BPE por longitud, sin cargar el vocabulario. Sirve para
+//! presupuestar; conviene dejar margen si el límite del modelo es estricto.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// Pre-tokenizador de cl100k_base (sin lookahead, no soportado por `regex`).
+static RE_CL100K: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i:'s|'t|'re|'ve|'m|'ll|'d)|[^\r\n\p{L}\p{N}]?\p{L}+|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n]*|\s*[\r\n]+|\s+",
+    )
+    .unwrap()
+});
+
+/// Tokenizador usado para medir presupuestos.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Tokenizer {
+    /// Estimación compatible con cl100k_base (GPT-4/GPT-3.5).
+    #[default]
+    Cl100k,
+    /// Palabras separadas por espacios.
+    Words,
+    /// Caracteres / 4 (regla general).
+    Chars,
+}
+
+impl Tokenizer {
+    /// Cuenta tokens de `text`.
+    pub fn count(&self, text: &str) -> usize {
+        match self {
+            Self::Cl100k => RE_CL100K
+                .find_iter(text)
+                .map(|m| piece_tokens(m.as_str()))
+                .sum(),
+            Self::Words => text.split_whitespace().count(),
+            Self::Chars => text.chars().count().div_ceil(4),
+        }
+    }
+}
+
+/// Sub-tokens BPE estimados para una pieza pre-tokenizada.
+fn piece_tokens(piece: &str) -> usize {
+    let chars = piece.chars().count();
+    if piece.trim().is_empty() {
+        return 1;
+    }
+    if piece.is_ascii() {
+        // Palabras ASCII cortas suelen ser 1 token; las largas se parten cada ~6 chars
+        if chars <= 8 {
+            1
+        } else {
+            chars.div_ceil(6)
+        }
+    } else {
+        // Acentos y ñ fragmentan más el vocabulario
+        chars.div_ceil(4)
+    }
+}
+
+impl FromStr for Tokenizer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cl100k" | "cl100k_base" => Ok(Self::Cl100k),
+            "words" => Ok(Self::Words),
+            "chars" => Ok(Self::Chars),
+            other => Err(format!(
+                "tokenizador desconocido: '{}' (use cl100k, words o chars)",
+                other
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Tokenizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Cl100k => "cl100k",
+            Self::Words => "words",
+            Self::Chars => "chars",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tokenizer() {
+        assert_eq!("cl100k_base".parse::<Tokenizer>(), Ok(Tokenizer::Cl100k));
+        assert!("gpt2".parse::<Tokenizer>().is_err());
+    }
+
+    #[test]
+    fn test_count_words_and_chars() {
+        assert_eq!(Tokenizer::Words.count("uno dos  tres"), 3);
+        assert_eq!(Tokenizer::Chars.count("abcdefgh"), 2);
+        assert_eq!(Tokenizer::Chars.count(""), 0);
+    }
+
+    #[test]
+    fn test_cl100k_estimate() {
+        assert_eq!(Tokenizer::Cl100k.count(""), 0);
+        // "Hello", " world", "!" → 3
+        assert_eq!(Tokenizer::Cl100k.count("Hello world!"), 3);
+        let es = Tokenizer::Cl100k.count("La documentación técnica del módulo");
+        assert!((6..=14).contains(&es), "estimación fuera de rango: {}", es);
+    }
+}
@@ -0,0 +1,273 @@
+//! Transclusión de embeds para export y compress.
+//!
+//! `![[Doc]]` se reemplaza por el cuerpo del documento (sin frontmatter) y
+//! `![[Doc#Sección]]` por esa sección, hasta el siguiente heading del mismo
+//! nivel o superior; `![[Doc^id]]` (o `![[Doc#^id]]`) solo por el bloque
+//! marcado con `^id`. Los fragmentos se expanden recursivamente hasta
+//! `max_depth`, cortando ciclos, y quedan marcados con comentarios
+//! `<!-- embed: ... -->` para conservar el origen. Los embeds de imágenes y
+//! otros adjuntos no se tocan.
+
+use crate::core::anchors::{anchors, block_fragment, resolve_section, split_target, Fragment};
+use crate::core::files::read_file_content;
+use crate::core::summary::body_of;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Profundidad de anidamiento por defecto.
+pub const DEFAULT_EMBED_DEPTH: usize = 5;
+
+static RE_EMBED: Lazy<Regex> = Lazy::new(|| Regex::new(r"!\[\[([^\]]+)\]\]").unwrap());
+
+/// Conteo de embeds procesados.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TranscludeStats {
+    pub expanded: usize,
+    /// Documento o sección inexistente (el embed queda tal cual).
+    pub unresolved: usize,
+    /// Omitidos por ciclo o por superar la profundidad máxima.
+    pub skipped: usize,
+}
+
+impl TranscludeStats {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Índice de documentos embebibles por nombre e ID.
+#[derive(Debug, Clone)]
+pub struct Transcluder {
+    docs: HashMap<String, String>,
+    max_depth: usize,
+}
+
+/// Clave de un documento: nombre sin ruta ni `.md`, en minúsculas.
+fn doc_key(name: &str) -> String {
+    let file = name.rsplit('/').next().unwrap_or(name).trim();
+    file.trim_end_matches(".md").to_lowercase()
+}
+
+/// ¿Es un adjunto (`foto.png`, `plano.pdf`)? Ojo: "3.1 Glosario" no lo es.
+fn is_attachment(name: &str) -> bool {
+    Path::new(name.trim())
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| {
+            e != "md" && e.chars().all(|c| c.is_ascii_alphanumeric()) && e.chars().any(|c| c.is_ascii_alphabetic())
+        })
+}
+
+impl Transcluder {
+    /// Índice a partir de (ruta, contenido); cada documento responde a su
+    /// nombre y a su ID numérico.
+    pub fn new(docs: impl IntoIterator<Item = (PathBuf, String)>, max_depth: usize) -> Self {
+        let mut index = HashMap::new();
+        let mut by_id = Vec::new();
+        for (path, content) in docs {
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if let Some(id) = stem.split_whitespace().next() {
+                by_id.push((id.trim_end_matches('.').to_lowercase(), content.clone()));
+            }
+            index.insert(doc_key(stem), content);
+        }
+        for (id, content) in by_id {
+            index.entry(id).or_insert(content);
+        }
+        Self { docs: index, max_depth }
+    }
+
+    /// Índice leyendo los archivos (los ilegibles se omiten).
+    pub fn from_files(files: &[PathBuf], max_depth: usize) -> Self {
+        Self::new(
+            files
+                .iter()
+                .filter_map(|f| read_file_content(f).ok().map(|c| (f.clone(), c))),
+            max_depth,
+        )
+    }
+
+    /// Expande los embeds de `content`; `origin` es el nombre del documento
+    /// (para detectar que se embeba a sí mismo).
+    pub fn expand(&self, content: &str, origin: &str, stats: &mut TranscludeStats) -> String {
+        let mut stack = vec![doc_key(origin)];
+        self.expand_inner(content, &mut stack, stats)
+    }
+
+    /// Contenidos de los documentos que `content` embebe, directa o
+    /// indirectamente hasta `max_depth`, ordenados por nombre. Junto con el
+    /// propio documento determinan su salida (builds incrementales).
+    pub fn sources(&self, content: &str, origin: &str) -> Vec<&str> {
+        self.embedded(content, origin).into_iter().map(|(_, doc)| doc).collect()
+    }
+
+    /// Como [`Self::sources`], con la clave (nombre en minúsculas) de cada
+    /// documento embebido.
+    pub fn embedded(&self, content: &str, origin: &str) -> Vec<(String, &str)> {
+        let mut seen = std::collections::BTreeMap::new();
+        let mut frontier = vec![content];
+        let origin = doc_key(origin);
+        for _ in 0..self.max_depth {
+            let mut next = Vec::new();
+            for text in frontier {
+                for caps in RE_EMBED.captures_iter(text) {
+                    let target = split_target(caps[1].split('|').next().unwrap_or_default()).0;
+                    let key = doc_key(target);
+                    if is_attachment(target) || key == origin || seen.contains_key(&key) {
+                        continue;
+                    }
+                    if let Some(doc) = self.docs.get(&key) {
+                        seen.insert(key, doc.as_str());
+                        next.push(doc.as_str());
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        seen.into_iter().collect()
+    }
+
+    fn expand_inner(&self, content: &str, stack: &mut Vec<String>, stats: &mut TranscludeStats) -> String {
+        let mut out = String::with_capacity(content.len());
+        let mut in_code = false;
+        for line in content.split_inclusive('\n') {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code = !in_code;
+            }
+            if in_code || !line.contains("![[") {
+                out.push_str(line);
+                continue;
+            }
+            let replaced = RE_EMBED.replace_all(line, |caps: &regex::Captures| {
+                self.embed(&caps[0], &caps[1], stack, stats)
+            });
+            out.push_str(&replaced);
+        }
+        out
+    }
+
+    /// Reemplazo de un embed (o el embed original si no aplica).
+    fn embed(&self, raw: &str, inner: &str, stack: &mut Vec<String>, stats: &mut TranscludeStats) -> String {
+        let target = inner.split('|').next().unwrap_or(inner).trim();
+        let (name, section) = split_target(target);
+        if is_attachment(name) {
+            return raw.to_string();
+        }
+        let key = doc_key(name);
+        let Some(content) = self.docs.get(&key) else {
+            stats.unresolved += 1;
+            return raw.to_string();
+        };
+        if stack.contains(&key) {
+            stats.skipped += 1;
+            return format!("> ⚠️ Embed circular omitido: `{}`", raw);
+        }
+        if stack.len() > self.max_depth {
+            stats.skipped += 1;
+            return format!("> ⚠️ Embed omitido por profundidad (máx {}): `{}`", self.max_depth, raw);
+        }
+        let Some(fragment) = fragment(content, section) else {
+            stats.unresolved += 1;
+            return raw.to_string();
+        };
+
+        stats.expanded += 1;
+        stack.push(key);
+        let body = self.expand_inner(fragment.trim(), stack, stats);
+        stack.pop();
+        format!("<!-- embed: {} -->\n{}\n<!-- /embed: {} -->", target, body.trim_end(), target)
+    }
+}
+
+/// Cuerpo completo, sección (desde su heading hasta el siguiente del mismo
+/// nivel o superior) o bloque `^id`.
+fn fragment<'a>(content: &'a str, section: Fragment) -> Option<Cow<'a, str>> {
+    let section = match section {
+        Fragment::Whole => return Some(Cow::Borrowed(body_of(content))),
+        Fragment::Block(id) => return block_fragment(content, id).map(Cow::Owned),
+        Fragment::Section(section) => section,
+    };
+    let headings = anchors(content);
+    let start = resolve_section(&headings, section)?;
+    let end = headings
+        .iter()
+        .find(|h| h.line > start.line && h.level <= start.level)
+        .map(|h| h.line);
+
+    let mut offset = 0;
+    let (mut from, mut to) = (None, content.len());
+    for (idx, line) in content.split_inclusive('\n').enumerate() {
+        if idx + 1 == start.line {
+            from = Some(offset);
+        }
+        if Some(idx + 1) == end {
+            to = offset;
+            break;
+        }
+        offset += line.len();
+    }
+    from.map(|from| Cow::Borrowed(&content[from..to]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transcluder() -> Transcluder {
+        Transcluder::new(
+            vec![
+                (
+                    PathBuf::from("1 clientes.md"),
+                    "---\nid: \"1\"\n---\n# Clientes\n\n## Requisitos\n\nDNI y domicilio.\n\n### Extra\n\nCUIT. ^cuit\n\n## Bajas\n\nNo.\n".to_string(),
+                ),
+                (PathBuf::from("2 ciclo.md"), "Antes ![[3 otro]]".to_string()),
+                (PathBuf::from("3 otro.md"), "Vuelta ![[2 ciclo]]".to_string()),
+            ],
+            DEFAULT_EMBED_DEPTH,
+        )
+    }
+
+    #[test]
+    fn test_expand_section_and_document() {
+        let t = transcluder();
+        let mut stats = TranscludeStats::default();
+        let out = t.expand("Ver:\n![[1 clientes#requisitos]]\n![[foto.png]] ![[9 nada]]\n", "4 host", &mut stats);
+        assert!(out.contains("<!-- embed: 1 clientes#requisitos -->\n## Requisitos\n\nDNI y domicilio.\n\n### Extra\n\nCUIT. ^cuit\n<!-- /embed: 1 clientes#requisitos -->"));
+        assert!(!out.contains("Bajas"));
+        assert!(out.contains("![[foto.png]] ![[9 nada]]"));
+        assert_eq!(stats, TranscludeStats { expanded: 1, unresolved: 1, skipped: 0 });
+
+        let out = t.expand("![[1]]", "4 host", &mut stats);
+        assert!(out.starts_with("<!-- embed: 1 -->\n# Clientes"));
+        assert!(!out.contains("id:"));
+
+        let out = t.expand("![[1 clientes^cuit]] ![[1 clientes#^nada]]", "4 host", &mut stats);
+        assert!(out.starts_with("<!-- embed: 1 clientes^cuit -->\nCUIT.\n<!-- /embed: 1 clientes^cuit -->"));
+        assert!(out.ends_with("![[1 clientes#^nada]]"));
+    }
+
+    #[test]
+    fn test_expand_stops_cycles() {
+        let t = transcluder();
+        let mut stats = TranscludeStats::default();
+        let out = t.expand("![[3 otro]]", "2 ciclo", &mut stats);
+        assert!(out.contains("Vuelta > ⚠️ Embed circular omitido: `![[2 ciclo]]`"));
+        assert_eq!(stats.expanded, 1);
+        assert_eq!(stats.skipped, 1);
+
+        let shallow = Transcluder { max_depth: 0, ..t };
+        let mut stats = TranscludeStats::default();
+        shallow.expand("![[2 ciclo]]", "host", &mut stats);
+        assert_eq!(stats.skipped, 1);
+    }
+}
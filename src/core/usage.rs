@@ -0,0 +1,248 @@
+//! Telemetría local de uso (opt-in).
+//!
+//! Con `telemetry.enabled: true` en la config (o `OC_TELEMETRY=1`), cada
+//! ejecución agrega una línea a `.oc_diagdoc/usage.jsonl`: comando, nombres
+//! de los flags usados (nunca sus valores), duración y si terminó bien. Nada
+//! sale de la máquina; `OC_TELEMETRY=0` la apaga aunque la config la active.
+//! `stats --usage` resume el archivo para ver qué conviene optimizar.
+
+use crate::core::config::{OcConfig, TelemetryConfig, CONFIG_DIR};
+use crate::errors::{OcError, OcResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Archivo de eventos dentro de `.oc_diagdoc/`.
+pub const USAGE_FILE: &str = "usage.jsonl";
+
+/// Una ejecución registrada.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageEvent {
+    pub timestamp: String,
+    /// Comando y subcomando (`verify`, `index build`).
+    pub command: String,
+    /// Flags pasados en la línea de comandos (`--json`), sin valores.
+    pub flags: Vec<String>,
+    pub duration_ms: u64,
+    pub ok: bool,
+}
+
+/// Ejecución en curso, entre [`start`] y [`finish`].
+struct Session {
+    command: String,
+    flags: Vec<String>,
+    started: Instant,
+    path: PathBuf,
+}
+
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+
+/// Ruta del archivo de eventos del proyecto actual.
+pub fn usage_path() -> PathBuf {
+    Path::new(CONFIG_DIR).join(USAGE_FILE)
+}
+
+/// ¿Está activa? La variable `OC_TELEMETRY` manda sobre la config.
+pub fn enabled(config: &TelemetryConfig) -> bool {
+    match std::env::var("OC_TELEMETRY") {
+        Ok(value) => matches!(value.trim().to_lowercase().as_str(), "1" | "true" | "on" | "yes"),
+        Err(_) => config.enabled,
+    }
+}
+
+/// Empieza a medir la ejecución si la telemetría está activa.
+pub fn start(command: impl Into<String>, flags: Vec<String>) {
+    let config = OcConfig::from_cwd().unwrap_or_default();
+    if !enabled(&config.telemetry) {
+        return;
+    }
+    *SESSION.lock().unwrap() = Some(Session {
+        command: command.into(),
+        flags,
+        started: Instant::now(),
+        path: usage_path(),
+    });
+}
+
+/// Registra la ejecución en curso (una sola vez). Los errores de escritura
+/// se ignoran: la telemetría nunca hace fallar un comando.
+pub fn finish(ok: bool) {
+    let Some(session) = SESSION.lock().unwrap().take() else {
+        return;
+    };
+    let event = UsageEvent {
+        timestamp: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        command: session.command,
+        flags: session.flags,
+        duration_ms: session.started.elapsed().as_millis() as u64,
+        ok,
+    };
+    let _ = append(&session.path, &event);
+}
+
+/// Agrega un evento al archivo (lo crea si falta).
+pub fn append(path: &Path, event: &UsageEvent) -> OcResult<()> {
+    let write_err = |e: std::io::Error| OcError::FileWrite {
+        path: path.to_path_buf(),
+        source: e,
+    };
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(write_err)?;
+    }
+    let line = serde_json::to_string(event)?;
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path).map_err(write_err)?;
+    writeln!(file, "{}", line).map_err(write_err)
+}
+
+/// Eventos registrados; sin archivo, ninguno. Las líneas ilegibles se omiten.
+pub fn load(path: &Path) -> OcResult<Vec<UsageEvent>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(path).map_err(|e| OcError::FileRead {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    Ok(content.lines().filter_map(|line| serde_json::from_str(line).ok()).collect())
+}
+
+/// Uso acumulado de un comando.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandUsage {
+    pub command: String,
+    pub runs: usize,
+    pub failures: usize,
+    pub total_ms: u64,
+    pub avg_ms: u64,
+    pub max_ms: u64,
+    /// Flags por frecuencia de uso.
+    pub flags: Vec<(String, usize)>,
+}
+
+/// Resumen de `stats --usage`.
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageSummary {
+    pub events: usize,
+    pub since: Option<String>,
+    /// Comandos por tiempo total, el mayor primero.
+    pub commands: Vec<CommandUsage>,
+}
+
+/// Agrupa los eventos por comando.
+pub fn summarize(events: &[UsageEvent]) -> UsageSummary {
+    let mut by_command: BTreeMap<&str, Vec<&UsageEvent>> = BTreeMap::new();
+    for event in events {
+        by_command.entry(event.command.as_str()).or_default().push(event);
+    }
+    let mut commands: Vec<CommandUsage> = by_command
+        .into_iter()
+        .map(|(command, runs)| {
+            let total_ms: u64 = runs.iter().map(|e| e.duration_ms).sum();
+            let mut flags: BTreeMap<&str, usize> = BTreeMap::new();
+            for flag in runs.iter().flat_map(|e| &e.flags) {
+                *flags.entry(flag.as_str()).or_default() += 1;
+            }
+            let mut flags: Vec<(String, usize)> = flags.into_iter().map(|(f, n)| (f.to_string(), n)).collect();
+            flags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            CommandUsage {
+                command: command.to_string(),
+                runs: runs.len(),
+                failures: runs.iter().filter(|e| !e.ok).count(),
+                total_ms,
+                avg_ms: total_ms / runs.len() as u64,
+                max_ms: runs.iter().map(|e| e.duration_ms).max().unwrap_or(0),
+                flags,
+            }
+        })
+        .collect();
+    commands.sort_by(|a, b| b.total_ms.cmp(&a.total_ms).then_with(|| a.command.cmp(&b.command)));
+    UsageSummary {
+        events: events.len(),
+        since: events.iter().map(|e| e.timestamp.clone()).min(),
+        commands,
+    }
+}
+
+impl UsageSummary {
+    /// Tabla por comando (tiempo total descendente).
+    pub fn render_table(&self) -> String {
+        let mut out = format!(
+            "📈 Uso local: {} ejecuciones{}\n",
+            self.events,
+            self.since.as_deref().map(|s| format!(" desde {}", s)).unwrap_or_default()
+        );
+        out.push_str(&format!(
+            "  {:20} {:>6} {:>6} {:>10} {:>9} {:>9}  flags\n",
+            "comando", "runs", "fallos", "total ms", "prom ms", "máx ms"
+        ));
+        for c in &self.commands {
+            let flags: Vec<String> = c.flags.iter().take(5).map(|(f, n)| format!("{} ({})", f, n)).collect();
+            out.push_str(&format!(
+                "  {:20} {:>6} {:>6} {:>10} {:>9} {:>9}  {}\n",
+                c.command,
+                c.runs,
+                c.failures,
+                c.total_ms,
+                c.avg_ms,
+                c.max_ms,
+                flags.join(", ")
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(command: &str, flags: &[&str], duration_ms: u64, ok: bool) -> UsageEvent {
+        UsageEvent {
+            timestamp: format!("2026-01-0{}T10:00:00Z", duration_ms % 9 + 1),
+            command: command.to_string(),
+            flags: flags.iter().map(|f| f.to_string()).collect(),
+            duration_ms,
+            ok,
+        }
+    }
+
+    #[test]
+    fn test_append_load_and_summarize() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".oc_diagdoc").join(USAGE_FILE);
+        assert!(load(&path).unwrap().is_empty());
+
+        for e in [
+            event("verify", &["--json"], 900, true),
+            event("lint", &["--fix", "--rule"], 120, true),
+            event("verify", &["--json", "--fix"], 1500, false),
+        ] {
+            append(&path, &e).unwrap();
+        }
+        std::fs::OpenOptions::new().append(true).open(&path).unwrap().write_all(b"basura\n").unwrap();
+        let events = load(&path).unwrap();
+        assert_eq!(events.len(), 3);
+
+        let summary = summarize(&events);
+        assert_eq!(summary.since.as_deref(), Some("2026-01-01T10:00:00Z"));
+        let verify = &summary.commands[0];
+        assert_eq!(verify.command, "verify");
+        assert_eq!((verify.runs, verify.failures, verify.avg_ms, verify.max_ms), (2, 1, 1200, 1500));
+        assert_eq!(verify.flags[0], ("--json".to_string(), 2));
+        assert!(summary.render_table().contains("lint"));
+    }
+
+    #[test]
+    fn test_env_overrides_config() {
+        // Solo este test toca OC_TELEMETRY
+        std::env::set_var("OC_TELEMETRY", "0");
+        assert!(!enabled(&TelemetryConfig { enabled: true }));
+        std::env::set_var("OC_TELEMETRY", "1");
+        assert!(enabled(&TelemetryConfig::default()));
+        std::env::remove_var("OC_TELEMETRY");
+        assert!(!enabled(&TelemetryConfig::default()));
+    }
+}
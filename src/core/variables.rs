@@ -0,0 +1,171 @@
+//! Variables de proyecto para export y compress.
+//!
+//! `{{project.name}}`, `{{version}}`… se definen en la sección `variables`
+//! de config (los mapas anidados se aplanan con `.`) y se sustituyen al
+//! exportar. `{{today}}` es la fecha actual salvo que la config lo defina.
+//! Los bloques de código no se tocan; las variables sin valor quedan tal
+//! cual y las reporta la regla de lint L017.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+
+static RE_VARIABLE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{\{\s*([A-Za-z_][\w.-]*)\s*\}\}").unwrap());
+
+/// Valores de variables por nombre completo (`project.name`).
+#[derive(Debug, Clone, Default)]
+pub struct Variables {
+    values: BTreeMap<String, String>,
+}
+
+/// Variable encontrada en un documento.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableRef {
+    /// Línea (1-based).
+    pub line: usize,
+    pub name: String,
+}
+
+/// Aplana `project: {name: X}` a `project.name = X`.
+fn flatten(prefix: &str, value: &serde_yaml::Value, out: &mut BTreeMap<String, String>) {
+    use serde_yaml::Value;
+
+    match value {
+        Value::Mapping(map) => {
+            for (key, value) in map {
+                let key = match key {
+                    Value::String(s) => s.clone(),
+                    other => serde_yaml::to_string(other).unwrap_or_default().trim().to_string(),
+                };
+                let name = if prefix.is_empty() { key } else { format!("{}.{}", prefix, key) };
+                flatten(&name, value, out);
+            }
+        }
+        Value::Sequence(items) => {
+            let parts: Vec<String> = items
+                .iter()
+                .map(|v| serde_yaml::to_string(v).unwrap_or_default().trim().to_string())
+                .collect();
+            out.insert(prefix.to_string(), parts.join(", "));
+        }
+        Value::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        Value::Null => {}
+        other => {
+            out.insert(prefix.to_string(), serde_yaml::to_string(other).unwrap_or_default().trim().to_string());
+        }
+    }
+}
+
+impl Variables {
+    /// Variables de la sección `variables` más `today`.
+    pub fn new(config: &BTreeMap<String, serde_yaml::Value>) -> Self {
+        let mut values = BTreeMap::new();
+        values.insert("today".to_string(), chrono::Local::now().format("%Y-%m-%d").to_string());
+        for (key, value) in config {
+            flatten(key, value, &mut values);
+        }
+        Self { values }
+    }
+
+    /// Variables de la config del directorio de trabajo.
+    pub fn from_cwd() -> Self {
+        let config = crate::core::config::OcConfig::from_cwd().unwrap_or_default();
+        Self::new(&config.variables)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    /// Variables usadas fuera de bloques de código.
+    pub fn references(content: &str) -> Vec<VariableRef> {
+        let mut found = Vec::new();
+        let mut in_code = false;
+        for (idx, line) in content.lines().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code = !in_code;
+                continue;
+            }
+            if in_code {
+                continue;
+            }
+            for cap in RE_VARIABLE.captures_iter(line) {
+                found.push(VariableRef { line: idx + 1, name: cap[1].to_string() });
+            }
+        }
+        found
+    }
+
+    /// Variables usadas sin valor definido.
+    pub fn unresolved(&self, content: &str) -> Vec<VariableRef> {
+        Self::references(content)
+            .into_iter()
+            .filter(|v| self.get(&v.name).is_none())
+            .collect()
+    }
+
+    /// Sustituye las variables definidas; agrega a `missing` las que no lo están.
+    pub fn resolve(&self, content: &str, missing: &mut BTreeSet<String>) -> String {
+        let mut out = String::with_capacity(content.len());
+        let mut in_code = false;
+        for line in content.split_inclusive('\n') {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code = !in_code;
+            }
+            if in_code || !line.contains("{{") {
+                out.push_str(line);
+                continue;
+            }
+            let replaced = RE_VARIABLE.replace_all(line, |caps: &regex::Captures| match self.get(&caps[1]) {
+                Some(value) => value.to_string(),
+                None => {
+                    missing.insert(caps[1].to_string());
+                    caps[0].to_string()
+                }
+            });
+            out.push_str(&replaced);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variables() -> Variables {
+        let config: BTreeMap<String, serde_yaml::Value> =
+            serde_yaml::from_str("project:\n  name: OnlyCar\nversion: 2.1\n").unwrap();
+        Variables::new(&config)
+    }
+
+    #[test]
+    fn test_resolve() {
+        let vars = variables();
+        let mut missing = BTreeSet::new();
+        let out = vars.resolve(
+            "# {{project.name}} v{{ version }}\n```\n{{version}}\n```\nAl {{today}}: {{cliente}}\n",
+            &mut missing,
+        );
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        assert_eq!(out, format!("# OnlyCar v2.1\n```\n{{{{version}}}}\n```\nAl {}: {{{{cliente}}}}\n", today));
+        assert_eq!(missing.into_iter().collect::<Vec<_>>(), vec!["cliente".to_string()]);
+    }
+
+    #[test]
+    fn test_unresolved() {
+        let found = variables().unresolved("{{project.name}}\n\n{{project.owner}} y {{TITLE}}\n");
+        assert_eq!(
+            found,
+            vec![
+                VariableRef { line: 3, name: "project.owner".to_string() },
+                VariableRef { line: 3, name: "TITLE".to_string() },
+            ]
+        );
+    }
+}
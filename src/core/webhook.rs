@@ -0,0 +1,371 @@
+//! Notificaciones a webhooks (Slack, Teams o JSON genérico).
+//!
+//! `ci` y `watch` construyen un [`NotifySummary`] (salud, errores nuevos,
+//! documentos cambiados) y lo envían al webhook configurado en
+//! `.oc_diagdoc/config.yaml` (`notify.webhook_url`).
+//!
+//! El envío usa [`crate::core::http`] (vía `curl`).
+
+use crate::core::files::{get_all_md_files, ScanOptions};
+use crate::core::hash::compute_file_hash;
+use crate::core::http::post_json;
+use crate::errors::{OcError, OcResult};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Archivo de estado entre ejecuciones (errores y hashes de la última notificación).
+pub const DEFAULT_STATE_FILE: &str = ".oc_diagdoc/notify_state.json";
+
+/// Máximo de elementos listados por sección en el mensaje.
+const MAX_LISTED: usize = 10;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FORMATO
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Formato del payload enviado al webhook.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    /// Slack Block Kit.
+    Slack,
+    /// Microsoft Teams (MessageCard).
+    Teams,
+    /// JSON genérico con el resumen tal cual.
+    #[default]
+    Json,
+}
+
+impl FromStr for WebhookFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "slack" => Ok(Self::Slack),
+            "teams" => Ok(Self::Teams),
+            "json" => Ok(Self::Json),
+            other => Err(format!(
+                "formato de webhook desconocido: '{}' (use slack, teams o json)",
+                other
+            )),
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// RESUMEN
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Resumen del estado de la documentación a notificar.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NotifySummary {
+    /// Comando origen (`ci`, `watch`).
+    pub source: String,
+    /// ¿Pasó la verificación?
+    pub passed: bool,
+    /// Score de salud (0-100), si se calculó.
+    pub health: Option<u8>,
+    /// Total de errores actuales.
+    pub total_errors: usize,
+    /// Total de warnings actuales.
+    pub total_warnings: usize,
+    /// Errores que no estaban en la notificación anterior.
+    pub new_errors: Vec<String>,
+    /// Documentos cambiados desde la notificación anterior.
+    pub changed_docs: Vec<String>,
+}
+
+impl NotifySummary {
+    pub fn new(source: &str) -> Self {
+        Self {
+            source: source.to_string(),
+            passed: true,
+            ..Default::default()
+        }
+    }
+
+    /// ¿La documentación se degradó (fallo o errores nuevos)?
+    pub fn is_degraded(&self) -> bool {
+        !self.passed || !self.new_errors.is_empty()
+    }
+
+    fn title(&self) -> String {
+        let status = if self.passed { "✅ OK" } else { "❌ FAILED" };
+        format!("oc_diagdoc {} · {}", self.source, status)
+    }
+
+    fn stats_line(&self) -> String {
+        let health = self
+            .health
+            .map(|h| format!("salud {}/100 · ", h))
+            .unwrap_or_default();
+        format!(
+            "{}{} errores · {} warnings · {} nuevos · {} docs cambiados",
+            health,
+            self.total_errors,
+            self.total_warnings,
+            self.new_errors.len(),
+            self.changed_docs.len()
+        )
+    }
+
+    /// Payload listo para enviar en el formato indicado.
+    pub fn payload(&self, format: WebhookFormat) -> Value {
+        match format {
+            WebhookFormat::Slack => self.slack_payload(),
+            WebhookFormat::Teams => self.teams_payload(),
+            WebhookFormat::Json => json!(self),
+        }
+    }
+
+    fn slack_payload(&self) -> Value {
+        let mut blocks = vec![
+            json!({"type": "header", "text": {"type": "plain_text", "text": self.title()}}),
+            json!({"type": "section", "text": {"type": "mrkdwn", "text": self.stats_line()}}),
+        ];
+        if !self.new_errors.is_empty() {
+            blocks.push(json!({"type": "section", "text": {
+                "type": "mrkdwn",
+                "text": format!("*Errores nuevos*\n{}", bullet_list(&self.new_errors)),
+            }}));
+        }
+        if !self.changed_docs.is_empty() {
+            blocks.push(json!({"type": "section", "text": {
+                "type": "mrkdwn",
+                "text": format!("*Documentos cambiados*\n{}", bullet_list(&self.changed_docs)),
+            }}));
+        }
+        json!({"text": self.title(), "blocks": blocks})
+    }
+
+    fn teams_payload(&self) -> Value {
+        let mut sections = vec![json!({"activityTitle": self.title(), "text": self.stats_line()})];
+        if !self.new_errors.is_empty() {
+            sections.push(json!({"title": "Errores nuevos", "text": bullet_list(&self.new_errors)}));
+        }
+        if !self.changed_docs.is_empty() {
+            sections.push(
+                json!({"title": "Documentos cambiados", "text": bullet_list(&self.changed_docs)}),
+            );
+        }
+        json!({
+            "@type": "MessageCard",
+            "@context": "https://schema.org/extensions",
+            "summary": self.title(),
+            "themeColor": if self.passed { "2EB67D" } else { "E01E5A" },
+            "sections": sections,
+        })
+    }
+}
+
+/// Lista con viñetas truncada a [`MAX_LISTED`] elementos.
+fn bullet_list(items: &[String]) -> String {
+    let mut lines: Vec<String> = items
+        .iter()
+        .take(MAX_LISTED)
+        .map(|i| format!("• {}", i))
+        .collect();
+    if items.len() > MAX_LISTED {
+        lines.push(format!("… y {} más", items.len() - MAX_LISTED));
+    }
+    lines.join("\n")
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ESTADO ENTRE EJECUCIONES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Estado de la última notificación: errores vistos y hashes de documentos.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotifyState {
+    pub errors: BTreeSet<String>,
+    pub hashes: BTreeMap<String, String>,
+}
+
+impl NotifyState {
+    /// Carga el estado; si no existe o está corrupto, parte de vacío.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|c| serde_json::from_str(&c).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> OcResult<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| OcError::FileWrite {
+                path: parent.to_path_buf(),
+                source: e,
+            })?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content).map_err(|e| OcError::FileWrite {
+            path: path.to_path_buf(),
+            source: e,
+        })
+    }
+
+    /// Errores de `current` que no estaban en el estado.
+    pub fn new_errors(&self, current: &[String]) -> Vec<String> {
+        let mut seen = BTreeSet::new();
+        current
+            .iter()
+            .filter(|e| !self.errors.contains(*e) && seen.insert(e.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    /// Hashes actuales de los `.md` bajo `data_dir` (ruta relativa → hash).
+    pub fn scan_hashes(data_dir: &Path) -> BTreeMap<String, String> {
+        get_all_md_files(data_dir, &ScanOptions::new())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|file| {
+                let hash = compute_file_hash(&file).ok()?;
+                let rel = file.strip_prefix(data_dir).unwrap_or(&file);
+                Some((rel.display().to_string(), hash.to_string()))
+            })
+            .collect()
+    }
+
+    /// Documentos nuevos o modificados respecto al estado.
+    pub fn changed_docs(&self, current: &BTreeMap<String, String>) -> Vec<String> {
+        current
+            .iter()
+            .filter(|(path, hash)| self.hashes.get(*path) != Some(*hash))
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// NOTIFIER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Envía resúmenes a un webhook.
+#[derive(Debug, Clone)]
+pub struct Notifier {
+    pub url: String,
+    pub format: WebhookFormat,
+    /// Solo notificar si la documentación se degradó.
+    pub only_on_degradation: bool,
+}
+
+impl Notifier {
+    /// Construye el notifier desde config, con overrides de CLI.
+    /// Devuelve `None` si no hay webhook configurado.
+    pub fn from_config(
+        config: &crate::core::config::NotifyConfig,
+        url: Option<&str>,
+        format: Option<WebhookFormat>,
+    ) -> Option<Self> {
+        let url = url
+            .map(str::to_string)
+            .or_else(|| config.webhook_url.clone())?;
+        Some(Self {
+            url,
+            format: format.unwrap_or(config.format),
+            only_on_degradation: config.only_on_degradation,
+        })
+    }
+
+    /// Envía el resumen si corresponde. Devuelve `true` si se envió.
+    pub fn notify(&self, summary: &NotifySummary) -> OcResult<bool> {
+        if self.only_on_degradation && !summary.is_degraded() {
+            return Ok(false);
+        }
+        send(&self.url, &summary.payload(self.format))?;
+        Ok(true)
+    }
+}
+
+/// POST del payload al webhook.
+pub fn send(url: &str, payload: &Value) -> OcResult<()> {
+    post_json(url, &[], payload)
+        .map(|_| ())
+        .map_err(|e| OcError::Custom(format!("Webhook falló: {}", e)))
+}
+
+/// Ruta por defecto del estado de notificaciones.
+pub fn default_state_path() -> PathBuf {
+    PathBuf::from(DEFAULT_STATE_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> NotifySummary {
+        NotifySummary {
+            passed: false,
+            health: Some(62),
+            total_errors: 2,
+            new_errors: vec!["Módulo 1: enlace roto".into()],
+            changed_docs: vec!["1. inicio.md".into()],
+            ..NotifySummary::new("ci")
+        }
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("Slack".parse::<WebhookFormat>(), Ok(WebhookFormat::Slack));
+        assert!("xml".parse::<WebhookFormat>().is_err());
+    }
+
+    #[test]
+    fn test_slack_payload_blocks() {
+        let payload = sample().payload(WebhookFormat::Slack);
+        let blocks = payload["blocks"].as_array().unwrap();
+        assert_eq!(blocks.len(), 4);
+        assert!(blocks[1]["text"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("salud 62/100"));
+    }
+
+    #[test]
+    fn test_teams_and_json_payload() {
+        let teams = sample().payload(WebhookFormat::Teams);
+        assert_eq!(teams["@type"], "MessageCard");
+        assert_eq!(teams["themeColor"], "E01E5A");
+
+        let generic = sample().payload(WebhookFormat::Json);
+        assert_eq!(generic["source"], "ci");
+        assert_eq!(generic["new_errors"][0], "Módulo 1: enlace roto");
+    }
+
+    #[test]
+    fn test_state_diff() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.md"), "# A").unwrap();
+        let hashes = NotifyState::scan_hashes(dir.path());
+
+        let mut state = NotifyState::default();
+        assert_eq!(state.changed_docs(&hashes), vec!["a.md".to_string()]);
+        state.hashes = hashes.clone();
+        state.errors.insert("viejo".into());
+        assert!(state.changed_docs(&hashes).is_empty());
+
+        let current = vec!["viejo".to_string(), "nuevo".to_string(), "nuevo".to_string()];
+        assert_eq!(state.new_errors(&current), vec!["nuevo".to_string()]);
+
+        let path = dir.path().join("state/notify.json");
+        state.save(&path).unwrap();
+        assert_eq!(NotifyState::load(&path).errors.len(), 1);
+    }
+
+    #[test]
+    fn test_only_on_degradation() {
+        let notifier = Notifier {
+            url: "http://127.0.0.1:9/hook".into(),
+            format: WebhookFormat::Json,
+            only_on_degradation: true,
+        };
+        let healthy = NotifySummary::new("watch");
+        assert!(!notifier.notify(&healthy).unwrap());
+    }
+}
@@ -347,6 +347,24 @@ pub struct ParsedDocument {
 
 /// Parsea el frontmatter YAML de un contenido markdown.
 pub fn parse_frontmatter(content: &str) -> OcResult<ParsedDocument> {
+    let (yaml_content, body) = split_document(content)?;
+
+    // Parsear YAML
+    let frontmatter: YamlFrontmatter =
+        serde_yaml::from_str(yaml_content).map_err(|e| OcError::YamlParse {
+            path: std::path::PathBuf::new(),
+            message: e.to_string(),
+        })?;
+
+    Ok(ParsedDocument {
+        frontmatter,
+        yaml_raw: yaml_content.to_string(),
+        body,
+    })
+}
+
+/// Separa el YAML crudo (sin parsear) y el body de un contenido markdown.
+pub fn split_document(content: &str) -> OcResult<(&str, String)> {
     let content = content.trim_start();
 
     // Verificar que empiece con ---
@@ -360,26 +378,14 @@ pub fn parse_frontmatter(content: &str) -> OcResult<ParsedDocument> {
         .find(FRONTMATTER_DELIMITER)
         .ok_or_else(|| OcError::MissingFrontmatter(std::path::PathBuf::new()))?;
 
-    let yaml_content = &after_first[..end_pos].trim();
+    let yaml_content = after_first[..end_pos].trim();
     let body_start = 3 + end_pos + 3; // Primer --- + yaml + segundo ---
     let body = if body_start < content.len() {
         content[body_start..].trim_start().to_string()
     } else {
         String::new()
     };
-
-    // Parsear YAML
-    let frontmatter: YamlFrontmatter =
-        serde_yaml::from_str(yaml_content).map_err(|e| OcError::YamlParse {
-            path: std::path::PathBuf::new(),
-            message: e.to_string(),
-        })?;
-
-    Ok(ParsedDocument {
-        frontmatter,
-        yaml_raw: yaml_content.to_string(),
-        body,
-    })
+    Ok((yaml_content, body))
 }
 
 /// Parsea frontmatter de un archivo.
@@ -406,6 +412,13 @@ pub fn parse_frontmatter_from_file(path: impl AsRef<Path>) -> OcResult<ParsedDoc
     Ok(result)
 }
 
+/// Bloque YAML crudo entre los delimitadores `---` (sin parsear).
+pub fn frontmatter_block(content: &str) -> Option<&str> {
+    let rest = content.strip_prefix(FRONTMATTER_DELIMITER)?;
+    let end = rest.find("\n---")?;
+    Some(&rest[..end])
+}
+
 /// Extrae solo el body de un documento (sin frontmatter).
 pub fn extract_body(content: &str) -> OcResult<String> {
     let parsed = parse_frontmatter(content)?;
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
 use crate::core::links::{extract_links, Link};
-use crate::core::yaml::{count_words, parse_frontmatter, YamlFrontmatter};
+use crate::core::yaml::{count_words, parse_frontmatter, ParsedDocument, YamlFrontmatter};
 use crate::errors::{OcError, OcResult};
 use crate::types::{ContentHash, DataPath, DocumentId};
 
@@ -33,7 +33,8 @@ pub struct Document {
 }
 
 impl Document {
-    /// Crea un documento desde un archivo.
+    /// Crea un documento desde un archivo (frontmatter vía la caché activa,
+    /// ver [`crate::core::frontmatter_cache`]).
     pub fn from_file(path: impl AsRef<Path>) -> OcResult<Self> {
         let path = path.as_ref();
         let content = std::fs::read_to_string(path).map_err(|e| OcError::FileRead {
@@ -41,24 +42,30 @@ impl Document {
             source: e,
         })?;
 
-        Self::from_content(path.to_path_buf(), &content)
+        let parsed = crate::core::frontmatter_cache::parse(path, &content)?;
+        Ok(Self::from_parsed(path.to_path_buf(), parsed))
     }
 
     /// Crea un documento desde contenido.
     pub fn from_content(path: PathBuf, content: &str) -> OcResult<Self> {
-        let parsed = parse_frontmatter(content)?;
+        Ok(Self::from_parsed(path, parse_frontmatter(content)?))
+    }
+
+    /// Crea un documento desde un frontmatter ya parseado (ver
+    /// [`crate::core::frontmatter_cache`]).
+    pub fn from_parsed(path: PathBuf, parsed: ParsedDocument) -> Self {
         let word_count = count_words(&parsed.body);
         let content_hash = ContentHash::compute(&parsed.body);
         let links = extract_links(&parsed.body);
 
-        Ok(Self {
+        Self {
             path,
             frontmatter: parsed.frontmatter,
             body: parsed.body,
             word_count,
             content_hash,
             links,
-        })
+        }
     }
 
     /// ID del documento.
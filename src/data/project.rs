@@ -6,7 +6,9 @@ use crate::core::config::OcConfig;
 use crate::data::document::{Document, DocumentCollection};
 use crate::data::hierarchy::HierarchyTree;
 use crate::data::module::ModuleRegistry;
-use crate::types::DocumentId;
+use crate::traits::Cacheable;
+use crate::types::{ContentHash, DocumentId};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -129,6 +131,50 @@ impl ProjectState {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// CACHE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Se persisten el directorio y los documentos; jerarquía, módulos y
+/// estadísticas se recalculan al restaurar con [`ProjectState::load_documents`].
+impl Serialize for ProjectState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let documents: Vec<&Document> = self.documents.iter().collect();
+        (&self.data_dir, documents).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProjectState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (data_dir, documents): (PathBuf, Vec<Document>) = Deserialize::deserialize(deserializer)?;
+        let config = OcConfig {
+            data_dir,
+            ..OcConfig::default()
+        };
+        let mut state = Self::new(config);
+        state.load_documents(documents);
+        Ok(state)
+    }
+}
+
+impl Cacheable for ProjectState {
+    type Key = PathBuf;
+    const CACHE_NAME: &'static str = "project";
+
+    fn cache_key(&self) -> PathBuf {
+        self.data_dir.clone()
+    }
+
+    fn content_hash(&self) -> ContentHash {
+        let hashes: Vec<String> = self
+            .documents
+            .iter()
+            .map(|d| format!("{}:{}", d.path.display(), d.content_hash.full()))
+            .collect();
+        ContentHash::compute(&hashes.join("\n"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
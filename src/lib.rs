@@ -19,13 +19,14 @@
 #![allow(clippy::manual_is_ascii_check)] // char comparison manual más clara
 #![allow(clippy::collapsible_str_replace)]  // replace en cadena legible
 #![allow(clippy::io_other_error)]         // std::io::Error::other pendiente
+#![allow(clippy::items_after_test_module)] // run() de CLI va después de los tests por convención
 //!
 //! Biblioteca Rust de alto rendimiento para gestión, validación y análisis
 //! de documentación estructurada en formato Markdown con frontmatter YAML.
 //!
 //! ## Características principales
 //!
-//! - 🔍 **Verificación**: 21 fases de validación automática
+//! - 🔍 **Verificación**: 28 fases de validación automática
 //! - 📊 **Estadísticas**: Métricas detalladas por módulo
 //! - 🔗 **Links**: Resolución y validación de wiki-links
 //! - 🌳 **Árbol**: Visualización jerárquica de documentos
@@ -79,6 +80,7 @@ pub mod macros;
 
 // Re-exports principales
 pub use core::config::OcConfig;
+pub use core::loader::Project;
 pub use data::document::Document;
 pub use data::project::ProjectState;
 pub use errors::{OcError, OcResult};
@@ -126,6 +126,62 @@ macro_rules! hashset {
     }};
 }
 
+/// Declara una regla de lint por patrón (ver `core::lint_rules`).
+///
+/// El patrón se aplica línea a línea; cada match es un issue con `message`,
+/// donde `$0`, `$1` o `${nombre}` se expanden con los grupos del match. La
+/// regla solo existe si se agrega a `DECLARED_RULES`: desde ahí la ejecuta
+/// `lint`, la documenta `--explain` y la registra `RuleRegistry::builtin`.
+///
+/// ```rust,ignore
+/// lint_rule! {
+///     code: "L021",
+///     name: "Generic Link Text",
+///     severity: Warning,
+///     category: "a11y",
+///     since: "3.2.0",
+///     pattern: r"\[\s*(aquí|here)\s*\]\(",
+///     message: "Texto de enlace genérico: \"$1\"",
+///     description: "...",
+///     impact: "...",
+///     example_bad: "...",
+///     example_good: "...",
+///     suggestion: "...",
+/// }
+/// ```
+#[macro_export]
+macro_rules! lint_rule {
+    {
+        code: $code:literal,
+        name: $name:literal,
+        severity: $severity:ident,
+        category: $category:literal,
+        since: $since:literal,
+        pattern: $pattern:literal,
+        message: $message:literal,
+        description: $description:literal,
+        impact: $impact:literal,
+        example_bad: $bad:literal,
+        example_good: $good:literal,
+        suggestion: $suggestion:literal $(,)?
+    } => {
+        $crate::core::lint_rules::DeclaredRule {
+            code: $code,
+            name: $name,
+            severity: $crate::types::Severity::$severity,
+            category: $category,
+            since: $since,
+            pattern: $pattern,
+            message: $message,
+            description: $description,
+            impact: $impact,
+            example_bad: $bad,
+            example_good: $good,
+            suggestion: $suggestion,
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -5,7 +5,7 @@
 use anyhow::Result;
 
 #[cfg(feature = "cli")]
-use clap::Parser;
+use clap::{parser::ValueSource, ArgMatches, CommandFactory, FromArgMatches, Parser};
 
 use oc_diagdoc_lib::{commands, CliConfig};
 
@@ -16,7 +16,8 @@ use oc_diagdoc_lib::{commands, CliConfig};
     version = env!("CARGO_PKG_VERSION"),
     author = "enerbydev <dev@onlycar.mx>",
     about = "Motor algorítmico nuclear para documentación OnlyCarNLD",
-    long_about = None
+    long_about = None,
+    disable_help_subcommand = true
 )]
 pub struct Cli {
     #[command(subcommand)]
@@ -165,7 +166,8 @@ oc_diagdoc sync --dry-run --fix-descendants --path ./Datos
 
 #[cfg(feature = "cli")]
 fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
     // F7: Generar README si se pide
     if cli.readme {
@@ -194,12 +196,58 @@ fn main() -> Result<()> {
         }
     };
 
+    // Telemetría local opt-in (ver core::usage)
+    let (name, flags) = command_usage(&matches);
+    oc_diagdoc_lib::core::usage::start(name, flags);
+
+    // Caché de frontmatter entre comandos (ver core::frontmatter_cache)
+    oc_diagdoc_lib::core::frontmatter_cache::enable();
+
     // Ejecutar comando
-    commands::execute(command, &config)?;
+    let result = commands::execute(command, &config);
+    oc_diagdoc_lib::core::frontmatter_cache::flush();
+    oc_diagdoc_lib::core::usage::finish(result.is_ok());
+    if let Some(status) = result.as_ref().err().and_then(|e| e.downcast_ref::<commands::ExitStatus>()) {
+        std::process::exit(status.0);
+    }
+    result?;
 
     Ok(())
 }
 
+/// Comando (con subcomandos) y flags pasados en la línea de comandos, sin
+/// valores ni argumentos posicionales.
+#[cfg(feature = "cli")]
+fn command_usage(matches: &ArgMatches) -> (String, Vec<String>) {
+    let mut names: Vec<String> = Vec::new();
+    let mut flags: Vec<String> = Vec::new();
+    let mut definition = Cli::command();
+    let globals: Vec<clap::Arg> = definition.get_arguments().cloned().collect();
+    let mut current = matches;
+    loop {
+        for id in current.ids() {
+            let arg = definition
+                .get_arguments()
+                .chain(globals.iter())
+                .find(|a| a.get_id() == id);
+            let from_cli = arg.is_some_and(|a| !a.is_positional())
+                && current.value_source(id.as_str()) == Some(ValueSource::CommandLine);
+            let flag = format!("--{}", id.as_str().replace('_', "-"));
+            if from_cli && !flags.contains(&flag) {
+                flags.push(flag);
+            }
+        }
+        let Some((name, sub)) = current.subcommand() else {
+            break;
+        };
+        names.push(name.to_string());
+        definition = definition.find_subcommand(name).cloned().unwrap_or_default();
+        current = sub;
+    }
+    flags.sort();
+    (names.join(" "), flags)
+}
+
 #[cfg(not(feature = "cli"))]
 fn main() {
     eprintln!("oc_diagdoc requiere feature 'cli'. Compila con: cargo build --features cli");
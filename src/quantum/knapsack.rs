@@ -0,0 +1,103 @@
+//! Selección de subconjunto bajo presupuesto (mochila 0/1).
+//!
+//! Elige los elementos que maximizan el valor total sin pasarse del
+//! presupuesto de peso, por programación dinámica. Con presupuestos
+//! grandes los pesos se escalan (redondeando hacia arriba, así la selección
+//! nunca se pasa) y el espacio que sobra se completa por valor/peso. Base de
+//! `compress --budget-words`.
+
+/// Celdas máximas de la tabla de programación dinámica.
+const MAX_CELLS: usize = 4_000_000;
+
+/// Elemento candidato.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item {
+    pub weight: usize,
+    pub value: u64,
+}
+
+/// Elementos elegidos.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    pub chosen: Vec<bool>,
+    pub weight: usize,
+    pub value: u64,
+}
+
+/// Subconjunto de `items` de mayor valor con peso total ≤ `budget`.
+pub fn knapsack(items: &[Item], budget: usize) -> Selection {
+    let n = items.len();
+    let scale = (n.max(1) * (budget + 1)).div_ceil(MAX_CELLS).max(1);
+    let capacity = budget / scale;
+    let width = capacity + 1;
+    let scaled: Vec<usize> = items.iter().map(|item| item.weight.div_ceil(scale)).collect();
+
+    let mut best = vec![0u64; width];
+    let mut take = vec![false; n * width];
+    for (i, item) in items.iter().enumerate() {
+        let w = scaled[i];
+        if w > capacity {
+            continue;
+        }
+        for c in (w..=capacity).rev() {
+            let with = best[c - w] + item.value;
+            if with > best[c] {
+                best[c] = with;
+                take[i * width + c] = true;
+            }
+        }
+    }
+
+    let mut chosen = vec![false; n];
+    let mut c = capacity;
+    for i in (0..n).rev() {
+        if take[i * width + c] {
+            chosen[i] = true;
+            c -= scaled[i];
+        }
+    }
+
+    // El redondeo de pesos puede dejar lugar: completar por valor/peso
+    let mut weight: usize = (0..n).filter(|&i| chosen[i]).map(|i| items[i].weight).sum();
+    let mut rest: Vec<usize> = (0..n).filter(|&i| !chosen[i]).collect();
+    rest.sort_by(|&a, &b| {
+        let ratio = |i: usize| items[i].value as f64 / items[i].weight.max(1) as f64;
+        ratio(b).total_cmp(&ratio(a))
+    });
+    for i in rest {
+        if weight + items[i].weight <= budget {
+            chosen[i] = true;
+            weight += items[i].weight;
+        }
+    }
+
+    let value = (0..n).filter(|&i| chosen[i]).map(|i| items[i].value).sum();
+    Selection { chosen, weight, value }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(weight: usize, value: u64) -> Item {
+        Item { weight, value }
+    }
+
+    #[test]
+    fn test_knapsack_beats_greedy() {
+        // Por valor/peso se elegiría solo el primero (30); lo óptimo son los otros dos
+        let selection = knapsack(&[item(6, 30), item(5, 20), item(5, 20)], 10);
+        assert_eq!(selection.chosen, vec![false, true, true]);
+        assert_eq!((selection.weight, selection.value), (10, 40));
+        assert_eq!(knapsack(&[item(11, 5)], 10).value, 0);
+    }
+
+    #[test]
+    fn test_knapsack_scaled_budget() {
+        let items: Vec<Item> = (0..200).map(|i| item(900 + i * 7, 1 + (i % 5) as u64)).collect();
+        let selection = knapsack(&items, 50_000);
+        assert!(selection.weight <= 50_000);
+        // Con pesos escalados, igual se llena casi todo el presupuesto
+        assert!(selection.weight > 48_000, "{}", selection.weight);
+    }
+}
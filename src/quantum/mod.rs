@@ -2,6 +2,9 @@
 
 pub mod cache;
 pub mod healer;
+pub mod knapsack;
 pub mod memory;
 pub mod oracle;
+pub mod partition;
+pub mod planner;
 pub mod telemetry;
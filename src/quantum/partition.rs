@@ -0,0 +1,185 @@
+//! Particionado balanceado de un grafo de documentos.
+//!
+//! Divide los nodos en N lotes de peso (palabras) parejo cortando la menor
+//! cantidad posible de links entre lotes. Arranca con cortes contiguos en el
+//! orden recibido (el jerárquico, así cada módulo queda junto) y refina
+//! moviendo nodos sueltos (Fiduccia-Mattheyses) o intercambiando pares
+//! (Kernighan-Lin) mientras bajen el corte sin romper la tolerancia de
+//! balance. Base de `quantum partition`.
+
+/// Desvío máximo del peso de un lote respecto del ideal (±10%).
+pub const BALANCE_TOLERANCE: f64 = 0.10;
+
+/// Pasadas máximas de refinamiento.
+const MAX_PASSES: usize = 50;
+
+/// Reparto de los nodos en lotes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Partition {
+    /// Lote de cada nodo.
+    pub assignment: Vec<usize>,
+    /// Peso total de cada lote.
+    pub loads: Vec<usize>,
+    /// Peso de los links entre lotes distintos.
+    pub cut: usize,
+}
+
+/// Reparte los nodos (`weights`, en orden jerárquico) en `parts` lotes.
+/// `edges` son `(a, b, peso)` no dirigidos; se ignoran los lazos.
+pub fn partition(weights: &[usize], edges: &[(usize, usize, usize)], parts: usize) -> Partition {
+    let parts = parts.max(1);
+    let n = weights.len();
+    // Sin palabras, se balancea por cantidad de documentos
+    let weights: Vec<usize> = if weights.iter().all(|&w| w == 0) {
+        vec![1; n]
+    } else {
+        weights.to_vec()
+    };
+
+    let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    for &(a, b, w) in edges {
+        if a != b && a < n && b < n {
+            adjacency[a].push((b, w));
+            adjacency[b].push((a, w));
+        }
+    }
+
+    // Cortes contiguos: cada nodo al lote de su punto medio acumulado
+    let total: usize = weights.iter().sum();
+    let mut assignment = Vec::with_capacity(n);
+    let mut prefix = 0;
+    for &w in &weights {
+        let middle = prefix as f64 + w as f64 / 2.0;
+        assignment.push(((middle * parts as f64 / total.max(1) as f64) as usize).min(parts - 1));
+        prefix += w;
+    }
+
+    let mut loads = vec![0; parts];
+    let mut counts = vec![0; parts];
+    for (v, &lot) in assignment.iter().enumerate() {
+        loads[lot] += weights[v];
+        counts[lot] += 1;
+    }
+
+    let target = total as f64 / parts as f64;
+    let heaviest = weights.iter().copied().max().unwrap_or(0);
+    let upper = ((target * (1.0 + BALANCE_TOLERANCE)).ceil() as usize).max(heaviest);
+    let lower = (target * (1.0 - BALANCE_TOLERANCE)).floor() as usize;
+
+    let links_of = |v: usize, assignment: &[usize]| {
+        let mut links = vec![0i64; parts];
+        for &(u, w) in &adjacency[v] {
+            links[assignment[u]] += w as i64;
+        }
+        links
+    };
+
+    for _ in 0..MAX_PASSES {
+        let mut moved = false;
+        for v in 0..n {
+            let from = assignment[v];
+            if counts[from] <= 1 {
+                continue;
+            }
+            let links = links_of(v, &assignment);
+            let w = weights[v];
+            // Mejor destino: más ganancia de corte y, a igual ganancia, el más liviano
+            let best = (0..parts)
+                .filter(|&to| to != from && loads[to] + w <= upper)
+                .map(|to| (links[to] - links[from], to))
+                .max_by_key(|&(gain, to)| (gain, std::cmp::Reverse(loads[to])));
+            let Some((gain, to)) = best else {
+                continue;
+            };
+            // Baja el corte sin vaciar el lote, o lo deja igual y mejora el balance
+            let improves = (gain > 0 && loads[from] - w >= lower) || (gain == 0 && loads[from] > loads[to] + w);
+            if improves {
+                assignment[v] = to;
+                loads[from] -= w;
+                loads[to] += w;
+                counts[from] -= 1;
+                counts[to] += 1;
+                moved = true;
+            }
+        }
+        if moved {
+            continue;
+        }
+
+        // Sin movimientos sueltos: el mejor intercambio de dos nodos (Kernighan-Lin)
+        let table: Vec<Vec<i64>> = (0..n).map(|v| links_of(v, &assignment)).collect();
+        let mut best: Option<(i64, usize, usize)> = None;
+        for v in 0..n {
+            let a = assignment[v];
+            for u in v + 1..n {
+                let b = assignment[u];
+                if a == b {
+                    continue;
+                }
+                // El link entre ambos, si existe, sigue cortado tras el intercambio
+                let upper_bound = table[v][b] - table[v][a] + table[u][a] - table[u][b];
+                if upper_bound <= best.map_or(0, |(g, ..)| g) {
+                    continue;
+                }
+                let (load_a, load_b) = (loads[a] + weights[u] - weights[v], loads[b] + weights[v] - weights[u]);
+                if ![load_a, load_b].iter().all(|&l| l <= upper && l >= lower) {
+                    continue;
+                }
+                let shared: i64 = adjacency[v].iter().filter(|&&(x, _)| x == u).map(|&(_, w)| w as i64).sum();
+                let gain = upper_bound - 2 * shared;
+                if gain > best.map_or(0, |(g, ..)| g) {
+                    best = Some((gain, v, u));
+                }
+            }
+        }
+        let Some((_, v, u)) = best else {
+            break;
+        };
+        let (a, b) = (assignment[v], assignment[u]);
+        loads[a] = loads[a] + weights[u] - weights[v];
+        loads[b] = loads[b] + weights[v] - weights[u];
+        assignment.swap(v, u);
+    }
+
+    let cut = edges
+        .iter()
+        .filter(|&&(a, b, _)| a < n && b < n && assignment[a] != assignment[b])
+        .map(|&(_, _, w)| w)
+        .sum();
+    Partition { assignment, loads, cut }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition_min_cut() {
+        // Dos grupos densos {0,1,2} y {3,4,5} unidos por un link; 0 y 3
+        // están cruzados en el orden recibido
+        let weights = [10, 10, 10, 10, 10, 10];
+        let order = [3, 1, 2, 0, 4, 5];
+        let edges: Vec<(usize, usize, usize)> = [(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5), (2, 3)]
+            .iter()
+            .map(|&(a, b)| {
+                let pos = |x: usize| order.iter().position(|&o| o == x).unwrap();
+                (pos(a), pos(b), 1)
+            })
+            .collect();
+        let result = partition(&weights, &edges, 2);
+        assert_eq!(result.cut, 1);
+        assert_eq!(result.loads, vec![30, 30]);
+        assert_ne!(result.assignment[0], result.assignment[3]);
+    }
+
+    #[test]
+    fn test_partition_balances_words() {
+        let result = partition(&[500, 100, 100, 100, 100, 100], &[], 2);
+        assert_eq!(result.loads, vec![500, 500]);
+        assert_eq!(result.cut, 0);
+        // Más lotes que nodos y pesos en cero
+        let result = partition(&[0, 0], &[(0, 1, 2)], 3);
+        assert_eq!(result.assignment.len(), 2);
+        assert_eq!(result.loads.len(), 3);
+    }
+}
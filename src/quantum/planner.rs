@@ -0,0 +1,200 @@
+//! Planificador del orden de reparación.
+//!
+//! Ordena los fixes propuestos para minimizar re-trabajo: por etapa
+//! ([`FixStage`]) y, dentro de cada una, en orden topológico de sus
+//! dependencias: un renombre antes de los enlaces que lo apuntan, el padre
+//! antes que sus hijos y los fixes de un mismo documento en el orden en que
+//! se propusieron. `fix --plan` muestra el plan antes de ejecutar y
+//! [`run_fixes`](crate::core::fixes::run_fixes) aplica cada fuente en este
+//! orden.
+
+use crate::traits::{Fix, FixStage};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::path::Path;
+
+static RE_DOC_ID: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d+(?:\.\d+)*)").unwrap());
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REPAIR PLAN
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Paso del plan.
+#[derive(Debug, Clone)]
+pub struct PlanStep {
+    pub fix: Fix,
+    /// Posición del fix en la lista propuesta.
+    pub proposed: usize,
+    /// Pasos (posiciones en el plan) que deben aplicarse antes.
+    pub depends_on: Vec<usize>,
+}
+
+/// Orden de aplicación de un conjunto de fixes.
+#[derive(Debug, Clone, Default)]
+pub struct RepairPlan {
+    pub steps: Vec<PlanStep>,
+}
+
+/// ID del documento que toca el fix ("1.2.3 nombre.md" → "1.2.3").
+fn document_id(fix: &Fix) -> Option<String> {
+    let name = fix.file.as_deref()?.file_name()?.to_str()?;
+    RE_DOC_ID.captures(name).map(|cap| cap[1].to_string())
+}
+
+/// Identidad del documento: su ID (estable ante renombres) o su ruta.
+fn document_key(fix: &Fix) -> Option<String> {
+    document_id(fix).or_else(|| fix.file.as_ref().map(|f| f.display().to_string()))
+}
+
+/// Grupo de aplicación: dentro de cada etapa, los renombres antes que las
+/// ediciones.
+fn phase(fix: &Fix) -> (FixStage, bool) {
+    (fix.stage, !fix.is_rename())
+}
+
+/// ¿El fix reescribe un enlace al archivo que renombra `rename`?
+fn links_to(fix: &Fix, rename: &Fix) -> bool {
+    let Some(stem) = rename.file.as_deref().and_then(Path::file_stem).and_then(|s| s.to_str()) else {
+        return false;
+    };
+    fix.before.as_deref().is_some_and(|before| before.contains(&format!("[[{}", stem)))
+}
+
+impl RepairPlan {
+    /// Planifica `fixes` (en el orden en que se propusieron).
+    pub fn new(fixes: Vec<Fix>) -> Self {
+        let ids: Vec<Option<String>> = fixes.iter().map(document_id).collect();
+        let keys: Vec<Option<String>> = fixes.iter().map(document_key).collect();
+        let phases: Vec<(FixStage, bool)> = fixes.iter().map(phase).collect();
+        let order_key = |j: usize| (phases[j], j);
+
+        let mut deps: Vec<Vec<usize>> = vec![Vec::new(); fixes.len()];
+        for (i, fix) in fixes.iter().enumerate() {
+            // Mismo documento: el fix inmediatamente anterior
+            if let Some(key) = &keys[i] {
+                let previous = (0..fixes.len())
+                    .filter(|&j| keys[j].as_ref() == Some(key) && order_key(j) < order_key(i))
+                    .max_by_key(|&j| order_key(j));
+                deps[i].extend(previous);
+            }
+            // Ancestro más cercano con fixes en el mismo grupo
+            let mut id = ids[i].as_deref();
+            while let Some((parent, _)) = id.and_then(|id| id.rsplit_once('.')) {
+                let last = (0..fixes.len())
+                    .filter(|&j| phases[j] == phases[i] && ids[j].as_deref() == Some(parent))
+                    .last();
+                if let Some(j) = last {
+                    deps[i].push(j);
+                    break;
+                }
+                id = Some(parent);
+            }
+            // Enlaces a un archivo renombrado
+            if fix.stage == FixStage::Rename && !fix.is_rename() {
+                deps[i].extend((0..fixes.len()).filter(|&j| fixes[j].is_rename() && links_to(fix, &fixes[j])));
+            }
+            deps[i].sort_unstable();
+            deps[i].dedup();
+        }
+
+        // Kahn por grupo (las dependencias entre grupos siempre apuntan a
+        // uno anterior); entre fixes independientes, el orden propuesto
+        let mut groups = phases.clone();
+        groups.sort();
+        groups.dedup();
+        let mut order: Vec<usize> = Vec::with_capacity(fixes.len());
+        for group in groups {
+            let in_group = |j: usize| phases[j] == group;
+            let mut pending: Vec<usize> = (0..fixes.len())
+                .map(|i| deps[i].iter().filter(|&&j| in_group(j)).count())
+                .collect();
+            let mut ready: BinaryHeap<Reverse<usize>> =
+                (0..fixes.len()).filter(|&i| in_group(i) && pending[i] == 0).map(Reverse).collect();
+            while let Some(Reverse(i)) = ready.pop() {
+                order.push(i);
+                for next in (0..fixes.len()).filter(|&n| in_group(n) && deps[n].contains(&i)) {
+                    pending[next] -= 1;
+                    if pending[next] == 0 {
+                        ready.push(Reverse(next));
+                    }
+                }
+            }
+        }
+
+        let mut position = vec![0; fixes.len()];
+        for (pos, &i) in order.iter().enumerate() {
+            position[i] = pos;
+        }
+        let mut slots: Vec<Option<Fix>> = fixes.into_iter().map(Some).collect();
+        let steps = order
+            .iter()
+            .map(|&i| {
+                let mut depends_on: Vec<usize> = deps[i].iter().map(|&j| position[j]).collect();
+                depends_on.sort_unstable();
+                PlanStep {
+                    fix: slots[i].take().expect("cada fix se planifica una vez"),
+                    proposed: i,
+                    depends_on,
+                }
+            })
+            .collect();
+        Self { steps }
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Pasos que el plan adelanta o atrasa respecto del orden propuesto
+    /// dentro de su etapa.
+    pub fn reordered(&self) -> usize {
+        self.steps
+            .iter()
+            .enumerate()
+            .filter(|(pos, step)| {
+                let before = self.steps[..*pos].iter().filter(|s| s.fix.stage == step.fix.stage);
+                before.filter(|s| s.proposed > step.proposed).count() > 0
+            })
+            .count()
+    }
+
+    /// Fixes en el orden del plan.
+    pub fn into_fixes(self) -> Vec<Fix> {
+        self.steps.into_iter().map(|s| s.fix).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(file: &str, before: &str) -> Fix {
+        Fix::edit("T", file, file, before, "x")
+    }
+
+    #[test]
+    fn test_plan_parents_and_renames_first() {
+        let fixes = vec![
+            edit("1.2.1 hijo.md", "").in_stage(FixStage::Structure),
+            edit("1.2 padre.md", "").in_stage(FixStage::Structure),
+            edit("1 raíz.md", "ver [[1.2 padre]]").in_stage(FixStage::Rename),
+            Fix::rename("T", "renombrar", "1.2 padre.md", "1.2 padre nuevo.md"),
+            edit("1.2 padre.md", "").in_stage(FixStage::Metadata),
+            edit("9 otro.md", "").in_stage(FixStage::Structure),
+        ];
+        let plan = RepairPlan::new(fixes);
+        let order: Vec<usize> = plan.steps.iter().map(|s| s.proposed).collect();
+        assert_eq!(order, vec![3, 2, 1, 0, 5, 4]);
+        // Enlace tras el renombre; hijo tras el padre; metadatos tras la estructura del mismo documento
+        let deps: Vec<&[usize]> = plan.steps.iter().map(|s| s.depends_on.as_slice()).collect();
+        assert_eq!(deps, vec![&[][..], &[0], &[0], &[2], &[], &[2]]);
+        assert_eq!(plan.reordered(), 2);
+        assert!(RepairPlan::new(Vec::new()).is_empty());
+    }
+}
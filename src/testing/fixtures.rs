@@ -2,7 +2,10 @@
 //!
 //! Fixtures, mocks y helpers para tests.
 
-use std::path::PathBuf;
+use crate::types::{Breadcrumb, DocumentId};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -45,6 +48,26 @@ impl TestProject {
     pub fn path(&self) -> &std::path::Path {
         self.temp_dir.path()
     }
+
+    /// Escribe `content` en `data_dir/name`, creando los directorios intermedios.
+    pub fn write(&self, name: &str, content: &str) -> PathBuf {
+        let path = self.data_dir.join(name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("Failed to create fixture dir");
+        }
+        std::fs::write(&path, content).expect("Failed to write fixture");
+        path
+    }
+
+    /// Lee `data_dir/name`.
+    pub fn read(&self, name: &str) -> String {
+        std::fs::read_to_string(self.data_dir.join(name)).expect("Failed to read fixture")
+    }
+
+    /// Llena `data_dir` con un proyecto sintético según `spec`.
+    pub fn generate(&self, spec: &ProjectSpec) -> std::io::Result<GeneratedProject> {
+        spec.generate(&self.data_dir)
+    }
 }
 
 impl Default for TestProject {
@@ -105,6 +128,284 @@ pub fn generate_module_docs(
     Ok(paths)
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// SYNTHETIC CORPUS
+// ═══════════════════════════════════════════════════════════════════════════
+
+const SYNTHETIC_WORDS: &[&str] = &[
+    "operador", "cliente", "vehículo", "reserva", "pago", "contrato", "flota", "tarifa",
+    "seguro", "reclamo", "factura", "proceso", "validación", "registro", "estado", "alta",
+];
+
+/// Parámetros de un proyecto sintético. Los porcentajes (0-100) indican
+/// qué parte de los documentos recibe cada defecto; con la misma semilla el
+/// proyecto generado es idéntico.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectSpec {
+    pub docs: usize,
+    /// Módulos de primer nivel.
+    pub modules: usize,
+    /// Niveles de la jerarquía.
+    pub depth: usize,
+    /// Documentos con un wiki-link a un archivo inexistente.
+    pub broken_links_pct: f64,
+    /// Hojas sin parent a las que nadie enlaza.
+    pub orphans_pct: f64,
+    /// Documentos con un `TODO` pendiente.
+    pub placeholders_pct: f64,
+    pub seed: u64,
+}
+
+impl Default for ProjectSpec {
+    fn default() -> Self {
+        Self {
+            docs: 100,
+            modules: 10,
+            depth: 4,
+            broken_links_pct: 0.0,
+            orphans_pct: 0.0,
+            placeholders_pct: 0.0,
+            seed: 42,
+        }
+    }
+}
+
+/// Proyecto generado y los defectos que se le inyectaron.
+#[derive(Debug, Clone, Default)]
+pub struct GeneratedProject {
+    pub files: Vec<PathBuf>,
+    pub broken_links: Vec<PathBuf>,
+    pub orphans: Vec<PathBuf>,
+    pub placeholders: Vec<PathBuf>,
+}
+
+/// SplitMix64: basta para elegir documentos de forma reproducible.
+struct SeededRng(u64);
+
+impl SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `pct` por ciento de `candidates`, elegidos al azar.
+    fn pick(&mut self, mut candidates: Vec<usize>, total: usize, pct: f64) -> HashSet<usize> {
+        let wanted = ((total as f64 * pct.clamp(0.0, 100.0) / 100.0).round() as usize).min(candidates.len());
+        for i in 0..wanted {
+            let j = i + (self.next_u64() % (candidates.len() - i) as u64) as usize;
+            candidates.swap(i, j);
+        }
+        candidates.truncate(wanted);
+        candidates.into_iter().collect()
+    }
+}
+
+/// Nombre de archivo (sin `.md`) de un documento sintético.
+fn synthetic_stem(id: &DocumentId) -> String {
+    format!("{} Documento {}", id, id)
+}
+
+impl ProjectSpec {
+    pub fn new(docs: usize, depth: usize) -> Self {
+        Self {
+            docs,
+            depth,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_modules(mut self, modules: usize) -> Self {
+        self.modules = modules;
+        self
+    }
+
+    pub fn with_broken_links(mut self, pct: f64) -> Self {
+        self.broken_links_pct = pct;
+        self
+    }
+
+    pub fn with_orphans(mut self, pct: f64) -> Self {
+        self.orphans_pct = pct;
+        self
+    }
+
+    pub fn with_placeholders(mut self, pct: f64) -> Self {
+        self.placeholders_pct = pct;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// IDs en orden de anchura: `modules` módulos con el mismo número de
+    /// hijos por nodo, el mínimo que alcanza `docs` sin pasar de `depth`.
+    fn ids(&self) -> Vec<Vec<u32>> {
+        let depth = self.depth.max(1);
+        let modules = if depth == 1 {
+            self.docs
+        } else {
+            self.docs.min(self.modules.max(1))
+        };
+        let capacity = |k: usize| {
+            (0..depth as u32)
+                .map(|d| modules.saturating_mul(k.saturating_pow(d)))
+                .fold(0usize, usize::saturating_add)
+        };
+        let mut fanout = 1;
+        while capacity(fanout) < self.docs {
+            fanout += 1;
+        }
+
+        let mut ids = Vec::with_capacity(self.docs);
+        let mut frontier: Vec<Vec<u32>> = (1..=modules as u32).map(|m| vec![m]).collect();
+        while ids.len() < self.docs && !frontier.is_empty() {
+            let mut next = Vec::new();
+            for id in frontier {
+                if ids.len() >= self.docs {
+                    break;
+                }
+                if id.len() < depth {
+                    for child in 1..=fanout as u32 {
+                        let mut child_id = id.clone();
+                        child_id.push(child);
+                        next.push(child_id);
+                    }
+                }
+                ids.push(id);
+            }
+            frontier = next;
+        }
+        ids
+    }
+
+    /// Escribe el proyecto en `data_dir`, plano como lo deja Obsidian
+    /// (`1.2 Documento 1.2.md`).
+    pub fn generate(&self, data_dir: &Path) -> std::io::Result<GeneratedProject> {
+        std::fs::create_dir_all(data_dir)?;
+        let ids: Vec<DocumentId> = self.ids().into_iter().map(DocumentId::new).collect();
+        let existing: HashSet<&DocumentId> = ids.iter().collect();
+        let parents: HashSet<DocumentId> = ids.iter().filter_map(DocumentId::parent).collect();
+
+        let mut rng = SeededRng(self.seed);
+        let all: Vec<usize> = (0..ids.len()).collect();
+        let leaves: Vec<usize> = all
+            .iter()
+            .copied()
+            .filter(|&i| ids[i].depth() > 1 && !parents.contains(&ids[i]))
+            .collect();
+        let orphans = rng.pick(leaves, ids.len(), self.orphans_pct);
+        let broken = rng.pick(all.clone(), ids.len(), self.broken_links_pct);
+        let placeholders = rng.pick(all, ids.len(), self.placeholders_pct);
+        let orphan_ids: HashSet<&DocumentId> = orphans.iter().map(|&i| &ids[i]).collect();
+
+        let mut project = GeneratedProject::default();
+        for (i, id) in ids.iter().enumerate() {
+            let sibling = id.parts().last().filter(|n| **n > 1).map(|last| {
+                let mut parts = id.parts().to_vec();
+                *parts.last_mut().unwrap() = last - 1;
+                DocumentId::new(parts)
+            });
+            // nadie enlaza a un huérfano: tampoco su hermano siguiente
+            let sibling = sibling.filter(|s| existing.contains(s) && !orphan_ids.contains(s));
+            let doc = SyntheticDoc {
+                id,
+                orphan: orphans.contains(&i),
+                sibling,
+                broken_link: broken.contains(&i),
+                placeholder: placeholders.contains(&i),
+            };
+            let path = data_dir.join(format!("{}.md", synthetic_stem(id)));
+            std::fs::write(&path, doc.render(self.seed))?;
+
+            if doc.orphan {
+                project.orphans.push(path.clone());
+            }
+            if doc.broken_link {
+                project.broken_links.push(path.clone());
+            }
+            if doc.placeholder {
+                project.placeholders.push(path.clone());
+            }
+            project.files.push(path);
+        }
+        Ok(project)
+    }
+}
+
+/// Un documento del corpus con sus defectos.
+struct SyntheticDoc<'a> {
+    id: &'a DocumentId,
+    orphan: bool,
+    sibling: Option<DocumentId>,
+    broken_link: bool,
+    placeholder: bool,
+}
+
+impl SyntheticDoc<'_> {
+    /// Frontmatter completo (breadcrumb canónico incluido), enlaces al padre
+    /// y al hermano anterior y un párrafo de relleno.
+    fn render(&self, seed: u64) -> String {
+        let parts = self.id.parts();
+        let parent = self.id.parent().filter(|_| !self.orphan);
+        let breadcrumb = if self.orphan {
+            Breadcrumb::from_segments([format!("Documento {}", self.id)])
+        } else {
+            Breadcrumb::from_segments(
+                (1..=parts.len()).map(|n| format!("Documento {}", DocumentId::new(parts[..n].to_vec()))),
+            )
+        };
+        let doc_type = if parts.len() == 1 { "modulo_padre" } else { "hoja" };
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        let mut rng = SeededRng(parts.iter().fold(seed, |acc, p| acc.wrapping_mul(31).wrapping_add(*p as u64)));
+        let words: Vec<&str> = (0..60)
+            .map(|_| SYNTHETIC_WORDS[(rng.next_u64() % SYNTHETIC_WORDS.len() as u64) as usize])
+            .collect();
+
+        let mut links = Vec::new();
+        if let Some(parent) = &parent {
+            links.push(format!("[[{}]]", synthetic_stem(parent)));
+        }
+        if let Some(sibling) = &self.sibling {
+            links.push(format!("[[{}]]", synthetic_stem(sibling)));
+        }
+        if self.broken_link {
+            links.push(format!("[[{} Inexistente]]", self.id));
+        }
+
+        let mut content = format!(
+            "---\nid: \"{id}\"\ntitle: \"Documento {id}\"\nparent: \"{parent}\"\nbreadcrumb: \"{breadcrumb}\"\ntype: \"{doc_type}\"\nstatus: \"activo\"\ncreated: \"{today}\"\nlast_updated: \"{today}\"\n---\n\n# Documento {id}\n\n{body}.\n",
+            id = self.id,
+            parent = parent.map_or_else(|| "0".to_string(), |p| p.to_string()),
+            breadcrumb = breadcrumb,
+            doc_type = doc_type,
+            today = today,
+            body = words.join(" ")
+        );
+        if !links.is_empty() {
+            content.push_str(&format!("\nVer también: {}\n", links.join(", ")));
+        }
+        if self.placeholder {
+            content.push_str("\nTODO: completar esta sección.\n");
+        }
+        content
+    }
+}
+
+/// Proyecto sintético sin defectos de `docs` documentos y hasta `depth`
+/// niveles (ver [`ProjectSpec`]).
+pub fn generate_synthetic_project(
+    data_dir: &Path,
+    docs: usize,
+    depth: usize,
+) -> std::io::Result<Vec<PathBuf>> {
+    Ok(ProjectSpec::new(docs, depth).generate(data_dir)?.files)
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // ASSERTIONS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -163,4 +464,48 @@ mod tests {
         let docs = generate_module_docs(&project, 1, 3).unwrap();
         assert_eq!(docs.len(), 3);
     }
+
+    #[test]
+    fn test_generate_synthetic_project() {
+        let project = TestProject::new().unwrap();
+        let docs = generate_synthetic_project(&project.data_dir, 45, 3).unwrap();
+        assert_eq!(docs.len(), 45);
+
+        let ids = ProjectSpec::new(45, 3).ids();
+        assert_eq!(ids.iter().map(Vec::len).max(), Some(3));
+        assert!(ids.iter().filter(|id| id.len() > 1).all(|id| ids.contains(&id[..id.len() - 1].to_vec())));
+
+        let content = std::fs::read_to_string(project.data_dir.join("1.2 Documento 1.2.md")).unwrap();
+        assert!(content.contains("parent: \"1\""));
+        assert!(content.contains("[[1 Documento 1]], [[1.1 Documento 1.1]]"));
+    }
+
+    #[test]
+    fn test_project_spec_defects_are_reproducible() {
+        let spec = ProjectSpec::new(50, 3)
+            .with_modules(3)
+            .with_broken_links(10.0)
+            .with_orphans(10.0)
+            .with_placeholders(20.0)
+            .with_seed(7);
+        let project = TestProject::new().unwrap();
+        let generated = project.generate(&spec).unwrap();
+        assert_eq!(generated.files.len(), 50);
+        assert_eq!(generated.broken_links.len(), 5);
+        assert_eq!(generated.orphans.len(), 5);
+        assert_eq!(generated.placeholders.len(), 10);
+        assert!(!generated.files.iter().any(|f| f.ends_with("4 Documento 4.md")));
+
+        let orphan = std::fs::read_to_string(&generated.orphans[0]).unwrap();
+        assert!(orphan.contains("parent: \"0\""));
+        let stem = generated.orphans[0].file_stem().unwrap().to_str().unwrap().to_string();
+        for file in &generated.files {
+            assert!(!std::fs::read_to_string(file).unwrap().contains(&format!("[[{}]]", stem)));
+        }
+
+        let again = TestProject::new().unwrap().generate(&spec).unwrap();
+        let names = |paths: &[PathBuf]| paths.iter().map(|p| p.file_name().unwrap().to_owned()).collect::<Vec<_>>();
+        assert_eq!(names(&again.orphans), names(&generated.orphans));
+        assert_eq!(names(&again.placeholders), names(&generated.placeholders));
+    }
 }
@@ -6,5 +6,8 @@ pub mod fixtures;
 pub mod mocks;
 
 pub use fixtures::{assert_file_contains, assert_file_exists};
-pub use fixtures::{generate_module_docs, minimal_document, sample_frontmatter, TestProject};
+pub use fixtures::{
+    generate_module_docs, generate_synthetic_project, minimal_document, sample_frontmatter, GeneratedProject,
+    ProjectSpec, TestProject,
+};
 pub use mocks::{MockCache, MockConfig, MockFileSystem, MockLogger};
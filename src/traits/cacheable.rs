@@ -8,10 +8,19 @@ use std::hash::Hash;
 // ═══════════════════════════════════════════════════════════════════════════
 
 /// Trait para objetos que pueden ser cacheados.
+///
+/// Los implementadores se persisten con [`crate::core::project_cache::CacheStore`]
+/// en `.oc_diagdoc/cache/state/<CACHE_NAME>.bin`.
 pub trait Cacheable {
     /// Tipo de clave para el cache.
     type Key: Hash + Eq + Clone;
 
+    /// Nombre del archivo de caché (sin extensión).
+    const CACHE_NAME: &'static str;
+
+    /// Versión del formato serializado; una entrada de otra versión se descarta.
+    const FORMAT_VERSION: u32 = 1;
+
     /// Genera la clave de cache.
     fn cache_key(&self) -> Self::Key;
 
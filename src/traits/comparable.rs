@@ -1,6 +1,8 @@
 //! Trait para comparación semántica.
 //!
-//! Comparación que va más allá de igualdad simple.
+//! Comparación que va más allá de igualdad simple. Los strings se comparan
+//! con Jaro-Winkler, que premia prefijos comunes: es lo que usa
+//! [`closest_match`] para las sugerencias "¿quisiste decir…?" de verify.
 
 // ═══════════════════════════════════════════════════════════════════════════
 // SIMILARITY SCORE
@@ -38,6 +40,72 @@ impl SimilarityScore {
     pub fn as_percent(&self) -> f64 {
         self.0 * 100.0
     }
+
+    /// Similitud Jaro-Winkler entre dos strings (por caracteres).
+    pub fn jaro_winkler(a: &str, b: &str) -> Self {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        let jaro = jaro(&a, &b);
+        let prefix = a.iter().zip(&b).take(4).take_while(|(x, y)| x == y).count();
+        Self::new(jaro + prefix as f64 * 0.1 * (1.0 - jaro))
+    }
+}
+
+/// Similitud Jaro: caracteres coincidentes dentro de una ventana y
+/// transposiciones entre ellos.
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::new();
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        if let Some(j) = (start..end).find(|&j| !b_matched[j] && b[j] == *ca) {
+            b_matched[j] = true;
+            a_matches.push(*ca);
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let b_matches = b.iter().zip(&b_matched).filter(|(_, m)| **m).map(|(c, _)| c);
+    let transpositions = a_matches.iter().zip(b_matches).filter(|(x, y)| x != y).count() / 2;
+    let m = a_matches.len() as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// Umbral por defecto para sugerir un candidato parecido.
+pub const SUGGESTION_THRESHOLD: f64 = 0.85;
+
+/// Candidato más parecido a `target` (sin distinguir mayúsculas) con
+/// similitud de al menos `threshold`. Un candidato idéntico no se sugiere.
+pub fn closest_match<'a>(
+    target: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+    threshold: f64,
+) -> Option<&'a str> {
+    let target = target.to_lowercase();
+    let mut best: Option<(SimilarityScore, &'a str)> = None;
+    for candidate in candidates {
+        let lower = candidate.to_lowercase();
+        if lower == target {
+            continue;
+        }
+        let score = target.as_str().similarity(lower.as_str());
+        // En empate gana el primero: el orden de los candidatos decide
+        if score.is_similar(threshold) && best.map_or(true, |(b, _)| score > b) {
+            best = Some((score, candidate));
+        }
+    }
+    best.map(|(_, candidate)| candidate)
 }
 
 impl From<f64> for SimilarityScore {
@@ -51,7 +119,7 @@ impl From<f64> for SimilarityScore {
 // ═══════════════════════════════════════════════════════════════════════════
 
 /// Trait para comparación semántica.
-pub trait Comparable<T = Self> {
+pub trait Comparable<T: ?Sized = Self> {
     /// Calcula similitud con otro objeto.
     fn similarity(&self, other: &T) -> SimilarityScore;
 
@@ -71,28 +139,19 @@ pub trait Comparable<T = Self> {
     }
 }
 
-/// Implementación para strings usando Levenshtein-like ratio.
-impl Comparable for String {
-    fn similarity(&self, other: &String) -> SimilarityScore {
+/// Implementación para strings usando Jaro-Winkler.
+impl Comparable for str {
+    fn similarity(&self, other: &str) -> SimilarityScore {
         if self == other {
             return SimilarityScore::exact();
         }
+        SimilarityScore::jaro_winkler(self, other)
+    }
+}
 
-        let len1 = self.len();
-        let len2 = other.len();
-
-        if len1 == 0 && len2 == 0 {
-            return SimilarityScore::exact();
-        }
-
-        if len1 == 0 || len2 == 0 {
-            return SimilarityScore::none();
-        }
-
-        // Simple character overlap ratio
-        let common = self.chars().filter(|c| other.contains(*c)).count();
-
-        SimilarityScore::new(common as f64 / len1.max(len2) as f64)
+impl Comparable for String {
+    fn similarity(&self, other: &String) -> SimilarityScore {
+        self.as_str().similarity(other.as_str())
     }
 }
 
@@ -123,4 +182,22 @@ mod tests {
         let sim = s1.similarity(&s2);
         assert!(sim.value() > 0.5);
     }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let score = |a, b| (SimilarityScore::jaro_winkler(a, b).value() * 1000.0).round() / 1000.0;
+        assert_eq!(score("MARTHA", "MARHTA"), 0.961);
+        assert_eq!(score("DIXON", "DICKSONX"), 0.813);
+        assert_eq!(score("abc", "xyz"), 0.0);
+        assert_eq!(score("", ""), 1.0);
+    }
+
+    #[test]
+    fn test_closest_match() {
+        let types = ["hoja", "modulo_padre", "seccion", "documento"];
+        assert_eq!(closest_match("modulo_padr", types, SUGGESTION_THRESHOLD), Some("modulo_padre"));
+        assert_eq!(closest_match("Secion", types, SUGGESTION_THRESHOLD), Some("seccion"));
+        assert_eq!(closest_match("glosario", types, SUGGESTION_THRESHOLD), None);
+        assert_eq!(closest_match("hoja", types, SUGGESTION_THRESHOLD), None);
+    }
 }
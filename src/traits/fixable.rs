@@ -1,7 +1,12 @@
 //! Trait para reparación automática.
+//!
+//! Cada subsistema (fix, lint, sync) propone [`Fix`]es sobre el estado
+//! actual del disco; `core::fixes` los aplica etapa por etapa ([`FixStage`])
+//! para que, por ejemplo, los renombres ocurran antes de recalcular hashes.
 
 use crate::errors::OcResult;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // FIX RESULT
@@ -34,7 +39,37 @@ impl FixResult {
 // FIX
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Etapa de un fix. Se aplican en este orden y cada etapa se recolecta
+/// después de aplicar la anterior.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FixStage {
+    /// Renombres de archivos y los enlaces que los apuntan.
+    Rename,
+    /// Cuerpo del documento: whitespace, tablas, secciones.
+    #[default]
+    Content,
+    /// Campos derivados de la jerarquía: breadcrumbs, descendants_count.
+    Structure,
+    /// Fechas, hashes y contadores: dependen del contenido final.
+    Metadata,
+}
+
+impl FixStage {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Rename => "renombres",
+            Self::Content => "contenido",
+            Self::Structure => "estructura",
+            Self::Metadata => "metadatos",
+        }
+    }
+}
+
 /// Representa un fix propuesto.
+///
+/// Un fix de edición lleva el contenido completo antes y después (`before`
+/// / `after`, compartidos para que clonarlo sea barato); uno de renombre
+/// lleva `rename_to`.
 #[derive(Debug, Clone)]
 pub struct Fix {
     /// Código del fix.
@@ -46,9 +81,14 @@ pub struct Fix {
     /// ¿Es automático o requiere confirmación?
     pub auto_apply: bool,
     /// Contenido antes del fix (para preview).
-    pub before: Option<String>,
+    pub before: Option<Arc<str>>,
     /// Contenido después del fix (para preview).
-    pub after: Option<String>,
+    pub after: Option<Arc<str>>,
+    /// Nuevo nombre de `file`, si el fix es un renombre.
+    pub rename_to: Option<PathBuf>,
+    pub stage: FixStage,
+    /// Cambios que aplica (filas, campos, enlaces).
+    pub changes: usize,
 }
 
 impl Fix {
@@ -60,9 +100,35 @@ impl Fix {
             auto_apply: true,
             before: None,
             after: None,
+            rename_to: None,
+            stage: FixStage::default(),
+            changes: 1,
         }
     }
 
+    /// Fix que reescribe `file` de `before` a `after`.
+    pub fn edit(
+        code: impl Into<String>,
+        description: impl Into<String>,
+        file: impl Into<PathBuf>,
+        before: impl Into<Arc<str>>,
+        after: impl Into<Arc<str>>,
+    ) -> Self {
+        Self::new(code, description).with_file(file).with_preview(before, after)
+    }
+
+    /// Fix que renombra `from` a `to`.
+    pub fn rename(
+        code: impl Into<String>,
+        description: impl Into<String>,
+        from: impl Into<PathBuf>,
+        to: impl Into<PathBuf>,
+    ) -> Self {
+        let mut fix = Self::new(code, description).with_file(from).in_stage(FixStage::Rename);
+        fix.rename_to = Some(to.into());
+        fix
+    }
+
     pub fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
         self.file = Some(file.into());
         self
@@ -73,11 +139,25 @@ impl Fix {
         self
     }
 
-    pub fn with_preview(mut self, before: impl Into<String>, after: impl Into<String>) -> Self {
+    pub fn with_preview(mut self, before: impl Into<Arc<str>>, after: impl Into<Arc<str>>) -> Self {
         self.before = Some(before.into());
         self.after = Some(after.into());
         self
     }
+
+    pub fn in_stage(mut self, stage: FixStage) -> Self {
+        self.stage = stage;
+        self
+    }
+
+    pub fn with_changes(mut self, changes: usize) -> Self {
+        self.changes = changes;
+        self
+    }
+
+    pub fn is_rename(&self) -> bool {
+        self.rename_to.is_some()
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -86,6 +166,11 @@ impl Fix {
 
 /// Trait para objetos que pueden ser reparados automáticamente.
 pub trait Fixable {
+    /// Etapa de sus fixes; ordena los subsistemas en `fix --all`.
+    fn fix_stage(&self) -> FixStage {
+        FixStage::Content
+    }
+
     /// Sugiere fixes disponibles.
     fn suggest_fixes(&self) -> Vec<Fix>;
 
@@ -133,6 +218,17 @@ mod tests {
         assert!(fix.auto_apply);
     }
 
+    #[test]
+    fn test_fix_edit_and_rename() {
+        let edit = Fix::edit("L003", "espacios", "a.md", "a \n", "a\n").in_stage(FixStage::Content);
+        assert_eq!(edit.after.as_deref(), Some("a\n"));
+        assert!(!edit.is_rename());
+
+        let rename = Fix::rename("FIX-NAME", "renombrar", "a.md", "1 a.md").with_changes(0);
+        assert!(rename.is_rename());
+        assert!(FixStage::Rename < edit.stage && edit.stage < FixStage::Metadata);
+    }
+
     #[test]
     fn test_fix_manual() {
         let fix = Fix::new("F001", "Manual fix").manual();
@@ -26,12 +26,12 @@ pub mod serializable;
 pub mod validatable;
 
 pub use cacheable::Cacheable;
-pub use comparable::{Comparable, SimilarityScore};
+pub use comparable::{closest_match, Comparable, SimilarityScore, SUGGESTION_THRESHOLD};
 pub use conversions::{
     DataPath, DocId, HasChildren, HasModule, HashString, LeavesIter, ModuleIter,
 };
 pub use diagnosable::{Diagnosable, Diagnostic, DiagnosticSeverity};
-pub use fixable::{Fix, FixResult, Fixable};
+pub use fixable::{Fix, FixResult, FixStage, Fixable};
 pub use hashable::Hashable;
 pub use iterators::{FilteredIter, IteratorExt, LevelOrderIter, PostOrderIter, PreOrderIter};
 pub use renderable::{OutputFormat, Renderable};
@@ -1,6 +1,16 @@
 //! Breadcrumb jerárquico.
+//!
+//! El breadcrumb canónico de un documento es la cadena de títulos de sus
+//! ancestros (siguiendo `parent`) desde la raíz hasta el propio documento.
 
+use crate::errors::OcError;
+use crate::types::lifetimes::split_frontmatter;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Separador canónico entre segmentos.
+pub const BREADCRUMB_SEPARATOR: &str = " > ";
 
 /// Breadcrumb como lista de segmentos.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -24,6 +34,33 @@ impl Breadcrumb {
         }
     }
 
+    /// Crea desde segmentos, con separador canónico.
+    pub fn from_segments<I, S>(segments: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let segments: Vec<String> = segments
+            .into_iter()
+            .map(|s| s.into().trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        Self {
+            raw: segments.join(BREADCRUMB_SEPARATOR),
+            segments,
+        }
+    }
+
+    /// Breadcrumb de un hijo con el título dado.
+    pub fn child(&self, title: &str) -> Self {
+        Self::from_segments(self.segments.iter().map(String::as_str).chain([title]))
+    }
+
+    /// Compara por segmentos (ignora espaciado alrededor de ">").
+    pub fn matches(&self, other: &Breadcrumb) -> bool {
+        self.segments == other.segments
+    }
+
     /// Número de segmentos.
     pub fn depth(&self) -> usize {
         self.segments.len()
@@ -68,6 +105,95 @@ impl From<String> for Breadcrumb {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// BREADCRUMB CANÓNICO
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Índice id → (parent, título) para generar breadcrumbs canónicos.
+#[derive(Debug, Clone, Default)]
+pub struct BreadcrumbIndex {
+    nodes: HashMap<String, (Option<String>, String)>,
+}
+
+impl BreadcrumbIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registra un documento. `parent` vacío, `0` o ausente indica raíz.
+    pub fn insert(&mut self, id: &str, parent: Option<&str>, title: &str) {
+        let parent = parent
+            .map(normalize_id)
+            .filter(|p| !p.is_empty() && p != "0" && p != "null" && p != "~");
+        self.nodes
+            .insert(normalize_id(id), (parent, title.trim().to_string()));
+    }
+
+    /// Registra un documento desde su contenido. El ID sale del campo `id`
+    /// o del nombre de archivo; el título de `title` o del nombre sin ID.
+    /// Devuelve el ID registrado.
+    pub fn insert_document(&mut self, path: &Path, content: &str) -> Option<String> {
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        let (file_id, file_title) = match stem.split_once(' ') {
+            Some((id, rest)) if id.starts_with(|c: char| c.is_ascii_digit()) => (Some(id), rest),
+            _ => (None, stem),
+        };
+        let metadata = split_frontmatter(content).map(|s| s.metadata).unwrap_or("");
+        let id = frontmatter_value(metadata, "id").or_else(|| file_id.map(String::from))?;
+        let title =
+            frontmatter_value(metadata, "title").unwrap_or_else(|| file_title.trim().to_string());
+        let parent = frontmatter_value(metadata, "parent");
+        self.insert(&id, parent.as_deref(), &title);
+        Some(normalize_id(&id))
+    }
+
+    /// Cantidad de documentos registrados.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Breadcrumb canónico de `id`. Falla si un ancestro no existe o si la
+    /// cadena de parents tiene un ciclo.
+    pub fn canonical(&self, id: &str) -> Result<Breadcrumb, OcError> {
+        let mut titles = Vec::new();
+        let mut visited = HashSet::new();
+        let mut current = normalize_id(id);
+        loop {
+            if !visited.insert(current.clone()) {
+                return Err(OcError::InvalidBreadcrumb(format!("ciclo de parents en '{}'", current)));
+            }
+            let (parent, title) = self.nodes.get(&current).ok_or_else(|| {
+                OcError::InvalidBreadcrumb(format!("ancestro '{}' no existe", current))
+            })?;
+            titles.push(title.as_str());
+            match parent {
+                Some(p) => current = p.clone(),
+                None => break,
+            }
+        }
+        titles.reverse();
+        Ok(Breadcrumb::from_segments(titles))
+    }
+}
+
+fn normalize_id(id: &str) -> String {
+    id.trim().trim_matches(|c| c == '"' || c == '\'').trim_end_matches('.').to_string()
+}
+
+/// Valor escalar de un campo del frontmatter (sin comillas).
+pub(crate) fn frontmatter_value(metadata: &str, field: &str) -> Option<String> {
+    let prefix = format!("{}:", field);
+    metadata
+        .lines()
+        .find_map(|l| l.strip_prefix(&prefix))
+        .map(|v| v.trim().trim_matches(|c| c == '"' || c == '\'').trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,4 +205,28 @@ mod tests {
         assert_eq!(bc.current(), Some("Subpágina"));
         assert_eq!(bc.root(), Some("OnlyCar"));
     }
+
+    #[test]
+    fn test_breadcrumb_from_segments_and_child() {
+        let bc = Breadcrumb::from_segments(["OnlyCar", " Pagos "]);
+        assert_eq!(bc.to_string(), "OnlyCar > Pagos");
+        assert_eq!(bc.child("Tarjeta").to_string(), "OnlyCar > Pagos > Tarjeta");
+        assert!(bc.matches(&Breadcrumb::parse("OnlyCar>Pagos")));
+    }
+
+    #[test]
+    fn test_canonical_breadcrumb() {
+        let mut index = BreadcrumbIndex::new();
+        index.insert_document(Path::new("1 Pagos.md"), "---\nid: \"1\"\ntitle: \"Pagos\"\nparent: \"0\"\n---\n");
+        index.insert_document(Path::new("1.2 Tarjeta.md"), "---\nparent: \"1\"\n---\n");
+        index.insert("1.2.1", Some("1.2"), "Débito");
+        index.insert("9.1", Some("9"), "Huérfano");
+        index.insert("5", Some("5.1"), "A");
+        index.insert("5.1", Some("5"), "B");
+
+        assert_eq!(index.canonical("1.2.1").unwrap().to_string(), "Pagos > Tarjeta > Débito");
+        assert_eq!(index.canonical("1.2.").unwrap().depth(), 2);
+        assert!(index.canonical("9.1").is_err());
+        assert!(index.canonical("5").is_err());
+    }
 }
@@ -2,8 +2,16 @@
 //!
 //! Minimiza clonaciones mediante semántica Cow.
 
-use std::borrow::Cow;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::{Borrow, Cow};
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // R43: COW STRING UTILITIES
@@ -217,6 +225,182 @@ where
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// R46: STRING INTERNING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// String compartido del arena de interning.
+///
+/// Clonar es un incremento de contador y dos strings internados por el
+/// mismo arena se comparan por puntero antes de mirar el contenido.
+#[derive(Clone, Eq)]
+pub struct InternedStr(Arc<str>);
+
+impl InternedStr {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// ¿Comparten el mismo buffer?
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl PartialEq for InternedStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || self.0 == other.0
+    }
+}
+
+impl Hash for InternedStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl PartialOrd for InternedStr {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InternedStr {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl Deref for InternedStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for InternedStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for InternedStr {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for InternedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for InternedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for InternedStr {
+    fn from(s: &str) -> Self {
+        intern(s)
+    }
+}
+
+impl Serialize for InternedStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for InternedStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Ok(intern(&s))
+    }
+}
+
+/// Arena de strings: cada contenido distinto se guarda una sola vez.
+///
+/// El arena no mantiene vivos los strings: los que solo siguen en el arena
+/// se descartan al purgar, y la purga corre sola cada vez que el arena
+/// duplica su tamaño desde la anterior. Así un proceso largo (daemon, watch,
+/// repl) no acumula los IDs y destinos de cargas anteriores.
+#[derive(Debug, Default)]
+pub struct StringInterner {
+    arena: Mutex<Arena>,
+}
+
+#[derive(Debug, Default)]
+struct Arena {
+    strings: HashSet<Arc<str>>,
+    /// Tamaño a partir del cual `intern` purga.
+    purge_at: usize,
+}
+
+impl Arena {
+    /// Descarta los strings que nadie más referencia.
+    fn purge(&mut self) -> usize {
+        let before = self.strings.len();
+        // Con el lock tomado, un contador en 1 es definitivo: solo se puede
+        // obtener otra referencia pasando por `intern`.
+        self.strings.retain(|s| Arc::strong_count(s) > 1);
+        self.purge_at = (self.strings.len() * 2).max(MIN_PURGE_SIZE);
+        before - self.strings.len()
+    }
+}
+
+/// Tamaño mínimo del arena antes de la primera purga automática.
+const MIN_PURGE_SIZE: usize = 1024;
+
+static GLOBAL_INTERNER: Lazy<StringInterner> = Lazy::new(StringInterner::new);
+
+impl StringInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Arena compartido por IDs y destinos de enlaces.
+    pub fn global() -> &'static StringInterner {
+        &GLOBAL_INTERNER
+    }
+
+    /// Devuelve el string ya guardado o lo agrega.
+    pub fn intern(&self, s: &str) -> InternedStr {
+        let mut arena = self.arena.lock();
+        if let Some(existing) = arena.strings.get(s) {
+            return InternedStr(Arc::clone(existing));
+        }
+        if arena.strings.len() >= arena.purge_at {
+            arena.purge();
+        }
+        let shared: Arc<str> = Arc::from(s);
+        arena.strings.insert(Arc::clone(&shared));
+        InternedStr(shared)
+    }
+
+    /// Descarta los strings que ya no se usan; devuelve cuántos.
+    pub fn purge(&self) -> usize {
+        self.arena.lock().purge()
+    }
+
+    /// Strings distintos guardados (incluye los no usados aún sin purgar).
+    pub fn len(&self) -> usize {
+        self.arena.lock().strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Interna `s` en el arena global.
+pub fn intern(s: &str) -> InternedStr {
+    StringInterner::global().intern(s)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,4 +461,37 @@ mod tests {
         let result = process_without_clone(&items, |&x| x > 2);
         assert!(matches!(result, Cow::Owned(_)));
     }
+
+    #[test]
+    fn test_interner_dedup() {
+        let interner = StringInterner::new();
+        let a = interner.intern("3.1.2");
+        let b = interner.intern("3.1.2");
+        let c = interner.intern("3.1");
+        assert!(a.ptr_eq(&b));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(interner.len(), 2);
+
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, "\"3.1.2\"");
+        let back: InternedStr = serde_json::from_str(&json).unwrap();
+        assert!(back.ptr_eq(&intern("3.1.2")));
+    }
+
+    #[test]
+    fn test_interner_releases_unused() {
+        let interner = StringInterner::new();
+        let kept = interner.intern("1.1");
+        drop(interner.intern("1.2"));
+        assert_eq!(interner.purge(), 1);
+        assert_eq!(interner.len(), 1);
+        assert!(kept.ptr_eq(&interner.intern("1.1")));
+
+        // Sin purgas manuales el arena no crece con strings descartados
+        for i in 0..10 * MIN_PURGE_SIZE {
+            interner.intern(&i.to_string());
+        }
+        assert!(interner.len() <= MIN_PURGE_SIZE + 1, "{}", interner.len());
+    }
 }
@@ -1,10 +1,25 @@
 //! Wrapper para fechas con parsing flexible.
 
 use crate::errors::OcError;
-use chrono::{DateTime, NaiveDate, Utc};
+use crate::types::OcTimezone;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::str::FromStr;
 
+/// Fechas con hora sin zona horaria aceptadas al leer (ISO y variantes
+/// locales día/mes/año).
+const DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%d/%m/%Y %H:%M:%S",
+    "%d/%m/%Y %H:%M",
+    "%d-%m-%Y %H:%M",
+];
+
+/// Fechas sin hora aceptadas al leer.
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d", "%d/%m/%Y", "%d-%m-%Y", "%d.%m.%Y"];
+
 /// Fecha con parsing flexible.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct OcDate(DateTime<Utc>);
@@ -35,6 +50,62 @@ impl OcDate {
         self.0.format("%Y-%m-%d").to_string()
     }
 
+    /// Parsea una fecha; las que no traen offset (ISO sin zona, variantes
+    /// locales) son horas locales de `tz`.
+    pub fn parse_in(s: &str, tz: &OcTimezone) -> Result<Self, OcError> {
+        let s = s.trim();
+        // Intenta varios formatos
+        if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+            return Ok(Self(dt.with_timezone(&Utc)));
+        }
+
+        // chrono acepta años de un dígito: "1.2.3" no es una fecha
+        let has_year = s.as_bytes().windows(4).any(|w| w.iter().all(u8::is_ascii_digit));
+        if !has_year {
+            return Err(OcError::InvalidDate(s.to_string()));
+        }
+
+        for format in DATETIME_FORMATS {
+            if let Ok(dt) = NaiveDateTime::parse_from_str(s, format) {
+                return Ok(Self(tz.to_utc(&dt)));
+            }
+        }
+
+        for format in DATE_FORMATS {
+            if let Ok(dt) = NaiveDate::parse_from_str(s, format) {
+                let dt = dt
+                    .and_hms_opt(0, 0, 0)
+                    .ok_or_else(|| OcError::InvalidDate(format!("{}: invalid time components", s)))?;
+                return Ok(Self(tz.to_utc(&dt)));
+            }
+        }
+
+        Err(OcError::InvalidDate(s.to_string()))
+    }
+
+    /// Formatea en la hora local de `tz`.
+    pub fn format_in(&self, format: &str, tz: &OcTimezone) -> Result<String, OcError> {
+        Self::check_format(format)?;
+        Ok(tz.from_utc(&self.0).format(format).to_string())
+    }
+
+    /// Formatea con un formato strftime, validándolo antes (un formato
+    /// inválido haría fallar a chrono al escribir).
+    pub fn format_with(&self, format: &str) -> Result<String, OcError> {
+        Self::check_format(format)?;
+        Ok(self.0.format(format).to_string())
+    }
+
+    /// Valida un formato strftime.
+    pub fn check_format(format: &str) -> Result<(), OcError> {
+        use chrono::format::{Item, StrftimeItems};
+
+        if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+            return Err(OcError::InvalidArgument(format!("formato de fecha inválido: '{}'", format)));
+        }
+        Ok(())
+    }
+
     /// Días desde esta fecha.
     pub fn days_ago(&self) -> i64 {
         (Utc::now() - self.0).num_days()
@@ -55,20 +126,9 @@ impl Default for OcDate {
 impl FromStr for OcDate {
     type Err = OcError;
 
+    /// Las fechas sin offset se asumen UTC; ver [`OcDate::parse_in`].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Intenta varios formatos
-        if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
-            return Ok(Self(dt.with_timezone(&Utc)));
-        }
-
-        if let Ok(dt) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
-            let dt = dt
-                .and_hms_opt(0, 0, 0)
-                .ok_or_else(|| OcError::InvalidDate(format!("{}: invalid time components", s)))?;
-            return Ok(Self(DateTime::from_naive_utc_and_offset(dt, Utc)));
-        }
-
-        Err(OcError::InvalidDate(s.to_string()))
+        Self::parse_in(s, &OcTimezone::Utc)
     }
 }
 
@@ -107,5 +167,24 @@ mod tests {
     fn test_date_parsing() {
         let d: OcDate = "2026-01-30".parse().unwrap();
         assert_eq!(d.to_date(), "2026-01-30");
+
+        let iso: OcDate = d.to_iso().parse().unwrap();
+        assert_eq!(iso, d);
+        assert!("2026-13-01".parse::<OcDate>().is_err());
+    }
+
+    #[test]
+    fn test_local_variants_and_format() {
+        let canonical = |s: &str| s.parse::<OcDate>().unwrap().format_with("%Y-%m-%d %H:%M").unwrap();
+        assert_eq!(canonical("30/01/2026"), "2026-01-30 00:00");
+        assert_eq!(canonical("30-01-2026 14:05"), "2026-01-30 14:05");
+        assert_eq!(canonical("2026-01-30T14:05:00-03:00"), "2026-01-30 17:05");
+        assert!(OcDate::now().format_with("%Y-%Q").is_err());
+        assert!("1.2.3".parse::<OcDate>().is_err());
+
+        let mexico = OcTimezone::parse("-06:00").unwrap();
+        let d = OcDate::parse_in("2026-01-30 14:05", &mexico).unwrap();
+        assert_eq!(d.to_iso(), "2026-01-30T20:05:00");
+        assert_eq!(d.format_in("%Y-%m-%d %H:%M", &mexico).unwrap(), "2026-01-30 14:05");
     }
 }
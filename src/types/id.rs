@@ -1,16 +1,51 @@
 //! Identificadores únicos para documentos y módulos.
 
 use crate::errors::OcError;
+use crate::types::cow::{intern, InternedStr};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
+use std::sync::Arc;
 
 /// Identificador de documento jerárquico.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// El texto se interna en el arena global: clonar no reserva memoria y la
+/// igualdad entre IDs del mismo proyecto es una comparación de punteros.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocumentId {
-    parts: Vec<u32>,
-    raw: String,
+    #[serde(with = "shared_parts")]
+    parts: Arc<[u32]>,
+    raw: InternedStr,
+}
+
+/// Serde de `Arc<[u32]>` como lista (serde no trae la feature `rc`).
+mod shared_parts {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::sync::Arc;
+
+    pub fn serialize<S: Serializer>(parts: &Arc<[u32]>, serializer: S) -> Result<S::Ok, S::Error> {
+        parts.as_ref().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Arc<[u32]>, D::Error> {
+        Vec::<u32>::deserialize(deserializer).map(Arc::from)
+    }
+}
+
+impl PartialEq for DocumentId {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for DocumentId {}
+
+impl Hash for DocumentId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
 }
 
 impl DocumentId {
@@ -21,7 +56,10 @@ impl DocumentId {
             .map(|p| p.to_string())
             .collect::<Vec<_>>()
             .join(".");
-        Self { parts, raw }
+        Self {
+            parts: parts.into(),
+            raw: intern(&raw),
+        }
     }
 
     /// Retorna el módulo padre (primera parte).
@@ -62,6 +100,11 @@ impl DocumentId {
     pub fn as_str(&self) -> &str {
         &self.raw
     }
+
+    /// String raw internado (clonarlo no copia el texto).
+    pub fn interned(&self) -> &InternedStr {
+        &self.raw
+    }
 }
 
 impl FromStr for DocumentId {
@@ -72,8 +115,8 @@ impl FromStr for DocumentId {
 
         match parts {
             Ok(p) if !p.is_empty() => Ok(Self {
-                parts: p,
-                raw: s.to_string(),
+                parts: p.into(),
+                raw: intern(s),
             }),
             _ => Err(OcError::InvalidId(s.to_string())),
         }
@@ -165,4 +208,17 @@ mod tests {
         let parent = id.parent().unwrap();
         assert_eq!(parent.to_string(), "3.1");
     }
+
+    #[test]
+    fn test_interned_ids() {
+        let a: DocumentId = "4.2.7".parse().unwrap();
+        let b = DocumentId::new(vec![4, 2, 7]);
+        assert_eq!(a, b);
+        assert!(a.interned().ptr_eq(b.interned()));
+
+        let json = serde_json::to_string(&a).unwrap();
+        let back: DocumentId = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.parts(), &[4, 2, 7]);
+        assert!(back.interned().ptr_eq(a.interned()));
+    }
 }
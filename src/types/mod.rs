@@ -9,6 +9,7 @@
 //! | [`DocumentId`] | Identificador jerárquico (ej: "1.2.3") |
 //! | [`ModuleId`] | Identificador de módulo (primer nivel del ID) |
 //! | [`OcDate`] | Fecha con parsing flexible (ISO/RFC3339) |
+//! | [`OcTimezone`] | Zona horaria del proyecto (UTC, local, offset o IANA) |
 //! | [`DocumentStatus`] | Estado del documento (activo, borrador, etc.) |
 //! | [`DocumentType`] | Tipo de documento (hoja, sección, módulo) |
 //! | [`Breadcrumb`] | Ruta de navegación jerárquica |
@@ -20,6 +21,7 @@
 //! |------|-------------|
 //! | [`SmartString`] | Copy-on-write string optimizado |
 //! | [`SmartPath`] | Path con cow semántica |
+//! | [`InternedStr`] | String compartido del arena de interning |
 //! | [`CacheEntry`] | Entrada de caché con lifetime |
 //! | [`DocumentView`] | Vista inmutable de documento |
 //!
@@ -42,11 +44,12 @@ pub mod lifetimes;
 pub mod metrics;
 pub mod path;
 pub mod status;
+pub mod timezone;
 pub mod severity;  // ADD#2
 
 // Re-exports
-pub use breadcrumb::Breadcrumb;
-pub use cow::{SmartPath, SmartString, SmartVec};
+pub use breadcrumb::{Breadcrumb, BreadcrumbIndex};
+pub use cow::{intern, InternedStr, SmartPath, SmartString, SmartVec, StringInterner};
 pub use date::OcDate;
 pub use doc_type::DocumentType;
 pub use hash::ContentHash;
@@ -55,5 +58,6 @@ pub use lifetimes::{CacheEntry, DocumentView, LazyString, SplitBorrow};
 pub use metrics::{Counters, CoverageStats};
 pub use path::DataPath;
 pub use status::DocumentStatus;
+pub use timezone::OcTimezone;
 pub use severity::{Severity, Issue};  // ADD#2
 
@@ -43,6 +43,56 @@ impl DataPath {
             .and_then(|s| s.split_whitespace().next())
             .map(|s| s.to_string())
     }
+
+    /// Parte del nombre tras el ID (ej: "3.1.2 Título.md" -> "Título").
+    pub fn name_part(&self) -> Option<&str> {
+        self.stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.split_once(char::is_whitespace))
+            .map(|(_, name)| name.trim())
+    }
+
+    /// ¿El nombre sigue `"{id} {slug}.md"`? El título se compara por slug:
+    /// mayúsculas, tildes y separadores no cuentan.
+    pub fn matches_convention(&self, id: &str, title: Option<&str>) -> bool {
+        let id_ok = self
+            .extract_id()
+            .is_some_and(|found| found.trim_end_matches('.') == id.trim_end_matches('.'));
+        let title_ok = title.map_or(true, |t| slugify(self.name_part().unwrap_or("")) == slugify(t));
+        id_ok && title_ok
+    }
+}
+
+/// Slug de un título para nombres de archivo: minúsculas, sin tildes y
+/// palabras unidas por `_` ("Políticas de Seguridad" → "politicas_de_seguridad").
+pub fn slugify(title: &str) -> String {
+    let folded: String = title
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'ä' => 'a',
+            'é' | 'è' | 'ë' => 'e',
+            'í' | 'ì' | 'ï' => 'i',
+            'ó' | 'ò' | 'ö' => 'o',
+            'ú' | 'ù' | 'ü' => 'u',
+            'ñ' => 'n',
+            other => other,
+        })
+        .collect();
+    folded
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Nombre de archivo canónico `"{id} {slug}.md"` (solo `"{id}.md"` si el slug queda vacío).
+pub fn canonical_file_name(id: &str, title: &str) -> String {
+    let id = id.trim_end_matches('.');
+    match slugify(title) {
+        slug if slug.is_empty() => format!("{}.md", id),
+        slug => format!("{} {}.md", id, slug),
+    }
 }
 
 impl std::ops::Deref for DataPath {
@@ -111,6 +161,22 @@ mod tests {
         assert_eq!(p.extract_id(), Some("3.1.2".to_string()));
     }
 
+    #[test]
+    fn test_file_name_convention() {
+        assert_eq!(slugify("Políticas de Seguridad"), "politicas_de_seguridad");
+        assert_eq!(slugify("  Año / Ñandú: v2 "), "ano_nandu_v2");
+        assert_eq!(canonical_file_name("2.8.1", "Políticas Seguridad"), "2.8.1 politicas_seguridad.md");
+        assert_eq!(canonical_file_name("3.", "¿?"), "3.md");
+
+        let p = DataPath::new("Datos/2.8.1 Politicas_Seguridad.md");
+        assert_eq!(p.name_part(), Some("Politicas_Seguridad"));
+        assert!(p.matches_convention("2.8.1", Some("Políticas Seguridad")));
+        assert!(!p.matches_convention("2.8.1", Some("Políticas de Seguridad")));
+        assert!(!p.matches_convention("2.8.2", Some("Políticas Seguridad")));
+        assert!(DataPath::new("1.1. identidad.md").matches_convention("1.1", Some("Identidad")));
+        assert!(!DataPath::new("notas.md").matches_convention("4", None));
+    }
+
     #[test]
     fn test_is_markdown() {
         let p = DataPath::new("test.md");
@@ -109,7 +109,7 @@ pub struct Issue {
     pub severity: Severity,
     /// Si es corregible automáticamente
     pub fixable: bool,
-    /// Fase de verificación (1-21)
+    /// Fase de verificación (1-28)
     pub phase: u8,
     /// Archivo relacionado (opcional)
     pub file: Option<String>,
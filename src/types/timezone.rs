@@ -0,0 +1,157 @@
+//! Zona horaria del proyecto.
+//!
+//! Las fechas sin offset del frontmatter (`2026-01-30 14:05`) se interpretan
+//! en la zona de `timezone` de la config, así `dates_sync`, `fix --dates` y
+//! `sync` dan lo mismo en máquinas con husos distintos. Acepta `UTC`,
+//! `local` (la del sistema, el default), offsets fijos (`-06:00`) y nombres
+//! IANA (`America/Mexico_City`) de la base de `chrono-tz`, que viaja con el
+//! binario y no depende de la del sistema.
+
+use crate::errors::{OcError, OcResult};
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// Zona horaria con la que se leen y escriben las fechas del proyecto.
+#[derive(Debug, Clone, Default)]
+pub enum OcTimezone {
+    Utc,
+    /// Zona del sistema (comportamiento histórico).
+    #[default]
+    Local,
+    Fixed(FixedOffset),
+    Named(Tz),
+}
+
+impl OcTimezone {
+    /// Interpreta `UTC`, `local`, `±HH:MM` (también `UTC-06:00`) o un nombre IANA.
+    pub fn parse(name: &str) -> OcResult<Self> {
+        let name = name.trim();
+        match name.to_lowercase().as_str() {
+            "utc" | "z" | "etc/utc" => return Ok(Self::Utc),
+            "" | "local" => return Ok(Self::Local),
+            _ => {}
+        }
+        let offset = name.strip_prefix("UTC").or_else(|| name.strip_prefix("GMT")).unwrap_or(name);
+        if offset.starts_with(['+', '-']) {
+            let seconds = parse_hms(offset)
+                .ok_or_else(|| OcError::InvalidArgument(format!("offset inválido: '{}'", name)))?;
+            return FixedOffset::east_opt(seconds)
+                .map(Self::Fixed)
+                .ok_or_else(|| OcError::InvalidArgument(format!("offset fuera de rango: '{}'", name)));
+        }
+        name.parse::<Tz>()
+            .map(Self::Named)
+            .map_err(|_| OcError::InvalidArgument(format!("zona horaria desconocida: '{}'", name)))
+    }
+
+    /// Zona de la config del directorio de trabajo; `local` si no hay o no
+    /// se reconoce (con aviso).
+    pub fn from_cwd() -> Self {
+        let config = crate::core::config::OcConfig::from_cwd().unwrap_or_default();
+        match config.timezone.as_deref().map(Self::parse) {
+            Some(Ok(tz)) => tz,
+            Some(Err(e)) => {
+                eprintln!("⚠️ timezone de la config ignorada: {}", e);
+                Self::Local
+            }
+            None => Self::Local,
+        }
+    }
+
+    /// Offset vigente en un instante.
+    pub fn offset_at(&self, utc: &DateTime<Utc>) -> FixedOffset {
+        match self {
+            Self::Utc => Utc.fix(),
+            Self::Local => Local.offset_from_utc_datetime(&utc.naive_utc()).fix(),
+            Self::Fixed(offset) => *offset,
+            Self::Named(tz) => tz.offset_from_utc_datetime(&utc.naive_utc()).fix(),
+        }
+    }
+
+    /// Offset de una hora local de la zona (en ambigüedades, el primero; en
+    /// el salto de horario, el vigente antes del cambio).
+    pub fn offset_for_local(&self, local: &NaiveDateTime) -> FixedOffset {
+        let earliest = match self {
+            Self::Utc => Some(Utc.fix()),
+            Self::Local => Local.offset_from_local_datetime(local).earliest().map(|o| o.fix()),
+            Self::Fixed(offset) => Some(*offset),
+            Self::Named(tz) => tz.offset_from_local_datetime(local).earliest().map(|o| o.fix()),
+        };
+        earliest.unwrap_or_else(|| self.offset_at(&local.and_utc()))
+    }
+
+    /// Instante de una hora local de la zona.
+    pub fn to_utc(&self, local: &NaiveDateTime) -> DateTime<Utc> {
+        let offset = self.offset_for_local(local);
+        (*local - chrono::Duration::seconds(offset.local_minus_utc() as i64)).and_utc()
+    }
+
+    /// Hora local de la zona para un instante.
+    pub fn from_utc(&self, utc: &DateTime<Utc>) -> DateTime<FixedOffset> {
+        utc.with_timezone(&self.offset_at(utc))
+    }
+
+    /// Ahora, en la zona.
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        self.from_utc(&Utc::now())
+    }
+}
+
+/// `±HH[:MM[:SS]]` (o sin signo) a segundos.
+fn parse_hms(s: &str) -> Option<i32> {
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'-' => (-1, &s[1..]),
+        b'+' => (1, &s[1..]),
+        _ => (1, s),
+    };
+    let mut parts = rest.split(':');
+    let hours: i32 = parts.next()?.parse().ok()?;
+    let minutes: i32 = parts.next().map_or(Some(0), |m| m.parse().ok())?;
+    let seconds: i32 = parts.next().map_or(Some(0), |s| s.parse().ok())?;
+    if parts.next().is_some() || minutes >= 60 || seconds >= 60 {
+        return None;
+    }
+    let total = hours.checked_mul(3600)?.checked_add(minutes * 60 + seconds)?;
+    Some(sign * total)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn test_fixed_and_utc() {
+        let tz = OcTimezone::parse("UTC-06:00").unwrap();
+        assert_eq!(tz.to_utc(&local("2026-01-30 14:05")).to_rfc3339(), "2026-01-30T20:05:00+00:00");
+        assert!(matches!(OcTimezone::parse("utc").unwrap(), OcTimezone::Utc));
+        assert!(OcTimezone::parse("+25:00").is_err());
+        assert!(OcTimezone::parse("../etc/passwd").is_err());
+    }
+
+    #[test]
+    fn test_offset_overflow() {
+        assert!(OcTimezone::parse("+9999999").is_err());
+        assert!(OcTimezone::parse("-2147483647:59").is_err());
+        assert_eq!(parse_hms("-05:30"), Some(-(5 * 3600 + 30 * 60)));
+    }
+
+    #[test]
+    fn test_named_zone() {
+        let tz = OcTimezone::parse("Europe/Madrid").unwrap();
+        assert_eq!(tz.to_utc(&local("2024-01-15 12:00")).to_rfc3339(), "2024-01-15T11:00:00+00:00");
+        assert_eq!(tz.to_utc(&local("2024-07-01 12:00")).to_rfc3339(), "2024-07-01T10:00:00+00:00");
+        // El cambio es el último domingo de marzo a la 01:00 UTC
+        let at = |s: &str| tz.from_utc(&local(s).and_utc()).offset().local_minus_utc() / 3600;
+        assert_eq!(at("2024-03-31 00:59"), 1);
+        assert_eq!(at("2024-03-31 01:00"), 2);
+
+        let south = OcTimezone::parse("America/Santiago").unwrap();
+        assert_eq!(south.from_utc(&local("2024-01-15 12:00").and_utc()).to_rfc3339(), "2024-01-15T09:00:00-03:00");
+        assert!(OcTimezone::parse("Marte/Olympus").is_err());
+    }
+}